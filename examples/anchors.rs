@@ -89,6 +89,7 @@ fn setup(
                     anchor,
                     ..default()
                 },
+                ..default()
             },
             material: mat.clone(),
             transform: Transform::from_translation(pos),
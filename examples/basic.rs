@@ -54,6 +54,7 @@ fn setup(
                 subdivision: 20,
                 ..default()
             },
+            ..default()
         },
         material: MeshMaterial3d(materials.add(StandardMaterial {
             base_color: Color::srgb(0.2, 0.3, 0.8), // Blueish metallic
@@ -48,7 +48,9 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Left,
+                ..default()
             },
+            ..default()
         },
         material: base_material.clone(),
         transform: Transform::from_xyz(-5.0, 3.0, 0.0),
@@ -65,7 +67,9 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Center,
+                ..default()
             },
+            ..default()
         },
         material: base_material.clone(),
         transform: Transform::from_xyz(0.0, 3.0, 0.0),
@@ -82,7 +86,9 @@ fn setup(
                 subdivision: 20,
                 anchor: TextAnchor::Center,
                 justify: JustifyText::Right,
+                ..default()
             },
+            ..default()
         },
         material: base_material.clone(),
         transform: Transform::from_xyz(5.0, 3.0, 0.0),
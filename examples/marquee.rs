@@ -0,0 +1,78 @@
+//! Marquee lights example
+//!
+//! This example demonstrates the TextMeshMarquee preset, which lines the outline
+//! of the text with chasing light bulbs like a casino sign.
+
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(FontMeshPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Camera
+    commands
+        .spawn(Camera3d::default())
+        .insert(Transform::from_xyz(0.0, 0.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y));
+
+    // Key Light
+    commands
+        .spawn(PointLight {
+            intensity: 3000.0,
+            ..default()
+        })
+        .insert(Transform::from_xyz(2.0, 3.0, 4.0));
+
+    // Dark red sign body
+    let body = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.35, 0.03, 0.05),
+        perceptual_roughness: 0.5,
+        ..default()
+    });
+
+    // Glowing and dimmed bulbs
+    let lit = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.9, 0.6),
+        emissive: LinearRgba::rgb(10.0, 7.0, 2.5),
+        ..default()
+    });
+    let unlit = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.25, 0.2, 0.15),
+        ..default()
+    });
+
+    commands.spawn((
+        TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "CASINO".to_string(),
+                font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+                style: TextMeshStyle {
+                    depth: 0.2,
+                    anchor: TextAnchor::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            material: MeshMaterial3d(body),
+            ..default()
+        },
+        TextMeshMarquee {
+            lit,
+            unlit,
+            blink: MarqueeBlink::Chase {
+                period: 0.12,
+                spacing: 3,
+            },
+            ..default()
+        },
+    ));
+}
@@ -57,6 +57,7 @@ fn setup(
                     anchor,
                     ..default()
                 },
+                ..default()
             },
             material: base_material.clone(),
             transform: Transform::from_translation(pos),
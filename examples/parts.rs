@@ -0,0 +1,81 @@
+//! Per-surface materials example
+//!
+//! This example demonstrates the TextMeshParts component which splits the
+//! extruded text into front, back, and side surfaces with their own materials.
+
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(FontMeshPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, rotate_text)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Camera
+    commands
+        .spawn(Camera3d::default())
+        .insert(Transform::from_xyz(0.0, 0.0, 6.0).looking_at(Vec3::ZERO, Vec3::Y));
+
+    // Key Light
+    commands
+        .spawn(PointLight {
+            intensity: 5000.0,
+            shadows_enabled: true,
+            ..default()
+        })
+        .insert(Transform::from_xyz(4.0, 8.0, 4.0));
+
+    // Ambient Light
+    commands.insert_resource(AmbientLight {
+        color: Color::WHITE,
+        brightness: 500.0,
+        affects_lightmapped_meshes: true,
+    });
+
+    // Gold front and back, dark extrusion sides
+    let gold = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.78, 0.25),
+        metallic: 0.9,
+        perceptual_roughness: 0.3,
+        ..default()
+    });
+    let dark = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.08, 0.06, 0.05),
+        perceptual_roughness: 0.6,
+        ..default()
+    });
+
+    commands.spawn(TextMeshPartsBundle {
+        text_parts: TextMeshParts {
+            text: "GOLD".to_string(),
+            font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+            style: TextMeshStyle {
+                depth: 0.5,
+                anchor: TextAnchor::Center,
+                ..default()
+            },
+        },
+        materials: TextMeshPartMaterials {
+            front: gold.clone(),
+            back: gold,
+            sides: dark,
+        },
+        ..default()
+    });
+}
+
+/// Slowly rotate the text so the sides are visible
+fn rotate_text(time: Res<Time>, mut query: Query<&mut Transform, With<TextMeshParts>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotation = Quat::from_rotation_y(time.elapsed_secs().sin() * 0.6);
+    }
+}
@@ -52,6 +52,7 @@ fn setup(
                 subdivision: 20,
                 ..default()
             },
+            ..default()
         },
         material: MeshMaterial3d(materials.add(StandardMaterial {
             base_color: Color::WHITE,
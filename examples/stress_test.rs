@@ -73,6 +73,7 @@ fn setup(
                     anchor: TextAnchor::TopLeft,
                     ..default()
                 },
+                ..default()
             },
             material: MeshMaterial3d(materials.add(StandardMaterial {
                 base_color: Color::BLACK, // Black FPS text
@@ -125,6 +126,7 @@ fn spawn_stress_text(
                             anchor: TextAnchor::Center,
                             ..default()
                         },
+                        ..default()
                     },
                     material: text_material, // Use generated random material
                     transform: Transform::from_translation(pos).with_scale(Vec3::splat(scale)),
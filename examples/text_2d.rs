@@ -0,0 +1,70 @@
+//! 2D text rendering example
+//!
+//! This example demonstrates the TextMesh2d component, which renders
+//! vector-quality text through Bevy's 2D pipeline with a ColorMaterial.
+
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(FontMeshPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, spin)
+        .run();
+}
+
+#[derive(Component)]
+struct Spin;
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    // Camera
+    commands.spawn(Camera2d);
+
+    // Title - glyphs are one unit per em, so scale to the font size in pixels
+    commands.spawn(TextMesh2dBundle {
+        text_mesh: TextMesh2d {
+            text: "FontMesh 2D".to_string(),
+            font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+            style: TextMeshStyle {
+                subdivision: 20,
+                anchor: TextAnchor::Center,
+                ..default()
+            },
+        },
+        material: MeshMaterial2d(materials.add(Color::srgb(0.9, 0.6, 0.2))),
+        transform: Transform::from_xyz(0.0, 120.0, 0.0).with_scale(Vec3::splat(96.0)),
+        ..default()
+    });
+
+    // Vector text stays sharp at any scale and rotation
+    commands.spawn((
+        TextMesh2dBundle {
+            text_mesh: TextMesh2d {
+                text: "Sharp at\nany angle".to_string(),
+                font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+                style: TextMeshStyle {
+                    subdivision: 20,
+                    anchor: TextAnchor::Center,
+                    justify: JustifyText::Center,
+                    ..default()
+                },
+            },
+            material: MeshMaterial2d(materials.add(Color::WHITE)),
+            transform: Transform::from_xyz(0.0, -80.0, 0.0).with_scale(Vec3::splat(48.0)),
+            ..default()
+        },
+        Spin,
+    ));
+}
+
+fn spin(time: Res<Time>, mut query: Query<&mut Transform, With<Spin>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotate_z(time.delta_secs() * 0.5);
+    }
+}
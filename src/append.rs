@@ -0,0 +1,242 @@
+use crate::component::{GlyphMesh, TextMesh, TextMeshGlyphs};
+use crate::core::fallback::FontChain;
+use crate::core::layout::{calculate_line_step, paragraph_lines};
+use crate::glyph_pool::{GlyphPool, GlyphShape, ParkedGlyph};
+use crate::system::{
+    spawn_line_glyphs, GlyphAnchorOffset, GlyphMeshesReady, TextMeshGlyphsComputed,
+};
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// Grows a text line by line, like a log, console, or chat.
+///
+/// Put it next to a [`TextMesh`] or [`TextMeshGlyphs`] and call
+/// [`TextMeshAppend::push_line`] instead of editing the text. Lines that are already
+/// on screen keep their geometry: a [`TextMesh`] only tessellates the new lines, and a
+/// [`TextMeshGlyphs`] only spawns glyph entities for them. With
+/// [`TextMeshAppend::max_lines`] set, the oldest lines are dropped once the text grows
+/// past it, and the remaining ones move up without being rebuilt.
+///
+/// Use a top anchor such as [`TextAnchor::TopLeft`](crate::TextAnchor::TopLeft) so new
+/// lines grow downwards without moving the existing ones.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # #[derive(Component)]
+/// # struct Console;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TextMeshBundle {
+///             text_mesh: TextMesh {
+///                 font: asset_server.load("fonts/font.ttf"),
+///                 style: TextMeshStyle {
+///                     anchor: TextAnchor::TopLeft,
+///                     ..default()
+///                 },
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         TextMeshAppend::with_max_lines(20),
+///         Console,
+///     ));
+/// }
+///
+/// fn log_message(mut console: Single<&mut TextMeshAppend, With<Console>>) {
+///     console.push_line("> player joined");
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshAppend {
+    /// Largest number of lines to keep, or `None` to keep every line.
+    pub max_lines: Option<usize>,
+    /// Lines pushed since the text was last updated.
+    pending: Vec<String>,
+}
+
+impl TextMeshAppend {
+    /// Append to the text, dropping the oldest lines beyond `max_lines`.
+    pub fn with_max_lines(max_lines: usize) -> Self {
+        Self {
+            max_lines: Some(max_lines),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue a line to be appended to the text.
+    ///
+    /// Line breaks in `line` start further lines. The text is updated the next time
+    /// the plugin's systems run.
+    pub fn push_line(&mut self, line: impl Into<String>) {
+        let line = line.into();
+        self.pending.extend(line.split('\n').map(str::to_string));
+    }
+
+    /// Lines queued by [`TextMeshAppend::push_line`] that aren't in the text yet.
+    pub fn pending(&self) -> &[String] {
+        &self.pending
+    }
+
+    /// Append the pending lines to `text` and trim it to `max_lines`.
+    ///
+    /// Returns the number of lines dropped from the start of the text and the number
+    /// of characters, including line breaks, they took up.
+    fn apply(&mut self, text: &mut String) -> (usize, usize) {
+        for line in self.pending.drain(..) {
+            // An empty text is a single empty line, which the first line replaces
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&line);
+        }
+
+        let Some(max_lines) = self.max_lines else {
+            return (0, 0);
+        };
+        let line_count = text.split('\n').count();
+        let dropped = line_count.saturating_sub(max_lines.max(1));
+        if dropped == 0 {
+            return (0, 0);
+        }
+
+        let cut = text
+            .match_indices('\n')
+            .nth(dropped - 1)
+            .map_or(text.len(), |(index, _)| index + 1);
+        let dropped_chars = text[..cut].chars().count();
+        text.drain(..cut);
+        (dropped, dropped_chars)
+    }
+}
+
+type AppendGlyphsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut TextMeshAppend,
+        &'static mut TextMeshGlyphs,
+        &'static MeshMaterial3d<StandardMaterial>,
+        Option<&'static mut GlyphPool>,
+        Option<&'static GlyphAnchorOffset>,
+        Has<TextMeshGlyphsComputed>,
+    ),
+>;
+
+/// System that moves the lines pushed to [`TextMeshAppend`]s into their text.
+///
+/// [`TextMesh`] text is edited normally and its line cache reuses the existing lines.
+/// Per-character text that is already generated is extended in place: glyph entities
+/// are shown for the new lines only, and glyphs of dropped lines are hidden for reuse.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_text_appends(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    mut texts: Query<(&mut TextMeshAppend, &mut TextMesh), Without<TextMeshGlyphs>>,
+    mut glyph_texts: AppendGlyphsQuery,
+    children: Query<&Children>,
+    mut glyphs: Query<(&mut GlyphMesh, &mut Transform, &Mesh3d, Option<&GlyphShape>)>,
+    parked: Query<(), With<ParkedGlyph>>,
+    mut ready: MessageWriter<GlyphMeshesReady>,
+) {
+    for (mut append, mut text_mesh) in texts.iter_mut() {
+        if !append.pending.is_empty() {
+            append.apply(&mut text_mesh.text);
+        }
+    }
+
+    for (entity, mut append, mut text_glyphs, material, pool, anchor, computed) in
+        glyph_texts.iter_mut()
+    {
+        if append.pending.is_empty() {
+            continue;
+        }
+
+        // 1. Glyphs that aren't generated yet, or are regenerated anyway, are built normally
+        let fonts = FontChain::for_style(&font_assets, &text_glyphs.font, &text_glyphs.style);
+        let Some(fonts) = fonts.filter(|_| computed && !text_glyphs.is_changed()) else {
+            append.apply(&mut text_glyphs.text);
+            continue;
+        };
+
+        // 2. Update the text without triggering a full regeneration
+        let text = &mut text_glyphs.bypass_change_detection().text;
+        let first_new_line = if text.is_empty() {
+            0
+        } else {
+            text.split('\n').count()
+        };
+        let (dropped, dropped_chars) = append.apply(text);
+        let text = &text_glyphs.text;
+
+        // 3. Hide glyphs of dropped lines and move the others up, back to where they
+        // were laid out so the grown text is anchored again
+        let style = &text_glyphs.style;
+        let line_step = calculate_line_step(&fonts, style);
+        let mut new_pool = None;
+        let pool = match pool {
+            Some(pool) => pool.into_inner(),
+            None => new_pool.insert(GlyphPool::new(text_glyphs.font.id(), style)),
+        };
+        pool.retain(|glyph_entity| parked.contains(glyph_entity));
+        let mut glyph_count = 0;
+        for glyph_entity in children
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter())
+        {
+            let Ok((mut glyph, mut transform, mesh, shape)) = glyphs.get_mut(glyph_entity) else {
+                continue;
+            };
+            if glyph.line_index < dropped {
+                pool.park(&mut commands, glyph_entity, shape.copied(), mesh);
+                continue;
+            }
+            glyph_count += 1;
+            glyph.line_index -= dropped;
+            glyph.char_index -= dropped_chars;
+            let shift = -line_step * dropped as f32 - anchor.map_or(Vec3::ZERO, |a| a.0);
+            glyph.origin += shift;
+            transform.translation += shift;
+        }
+
+        // 4. Show glyphs for the new lines
+        let lines: Vec<&str> = text.split('\n').collect();
+        let first_line = first_new_line.saturating_sub(dropped);
+        let mut char_index: usize = lines[..first_line]
+            .iter()
+            .map(|line| line.chars().count() + 1)
+            .sum();
+        for (line_index, (line, ends_paragraph)) in
+            paragraph_lines(text).enumerate().skip(first_line)
+        {
+            glyph_count += spawn_line_glyphs(
+                &mut commands,
+                &mut meshes,
+                pool,
+                entity,
+                line,
+                ends_paragraph,
+                line_index,
+                char_index,
+                line_step,
+                &fonts,
+                &text_glyphs,
+                material,
+            );
+            char_index += line.chars().count() + 1;
+        }
+        ready.write(GlyphMeshesReady {
+            entity,
+            glyph_count,
+        });
+        if let Some(pool) = new_pool {
+            commands.entity(entity).insert(pool);
+        }
+    }
+}
@@ -1,207 +1,151 @@
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::FontChain;
+use crate::core::mesh::{glyph_uvs, TextMeshData};
+use crate::core::style::TextMeshStyle;
+use crate::core::FontMesh;
 use bevy::asset::{io::Reader, AssetLoader, LoadContext};
 use bevy::prelude::*;
+use std::sync::Arc;
 use thiserror::Error;
-
-/// Asset containing font data for 3D text mesh generation.
-///
-/// This asset type holds the raw bytes of a TrueType font file.
-/// Fonts are automatically loaded from the asset server and used by the [`TextMesh`](crate::TextMesh)
-/// component to generate 3D mesh geometry.
-///
-/// # Loading Fonts
-///
-/// Fonts are loaded like any other Bevy asset:
-///
-/// ```no_run
-/// # use bevy::prelude::*;
-/// # use bevy_fontmesh::FontMesh;
-/// # fn example(asset_server: Res<AssetServer>) {
-/// let font: Handle<FontMesh> = asset_server.load("fonts/MyFont.ttf");
-/// # }
-/// ```
-///
-/// Place your font files in the `assets/fonts/` directory (or any subdirectory of `assets/`).
-///
-/// # Supported Formats
-///
-/// - TrueType (`.ttf`)
-///
-/// **Note**: Some OpenType fonts (`.otf`) with TrueType outlines are supported,
-/// but OpenType fonts with CFF/PostScript outlines are not (limitation of ttf-parser).
-#[derive(Asset, TypePath, Debug)]
-pub struct FontMesh {
-    /// Raw font file data in TTF or OTF format.
-    pub data: Vec<u8>,
-}
-
-/// Metrics for a single glyph
-#[derive(Debug, Clone, Copy)]
-pub struct GlyphMetrics {
-    /// Horizontal advance width (how far to move cursor after this glyph)
-    pub advance: f32,
-    /// Whether the glyph has visible geometry (some chars like space don't)
-    pub has_outline: bool,
-}
-
-/// Font-level metrics
-#[derive(Debug, Clone, Copy)]
-pub struct FontMetrics {
-    /// Distance from baseline to top of tallest glyph
-    pub ascender: f32,
-    /// Distance from baseline to bottom of lowest glyph (typically negative)
-    pub descender: f32,
-    /// Extra space between lines
-    pub line_gap: f32,
-    /// Total line height (ascender - descender + line_gap)
-    pub line_height: f32,
-}
+use ttf_parser::GlyphId;
 
 impl FontMesh {
-    /// Get metrics for a specific character.
+    /// Handle of the font embedded with the `default-font` feature.
     ///
-    /// Returns `None` if the character is not in the font.
+    /// [`FontMeshPlugin`](crate::FontMeshPlugin) adds the embedded font under this
+    /// handle. It is the default `Handle<FontMesh>`, so texts spawned without a font
+    /// use it, which is enough for prototypes and examples without any font assets.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use bevy::prelude::*;
-    /// # use bevy_fontmesh::FontMesh;
-    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
-    /// if let Some(font) = font_assets.get(&font_handle) {
-    ///     if let Some(metrics) = font.glyph_metrics('A') {
-    ///         println!("Advance width of 'A': {}", metrics.advance);
-    ///     }
-    /// }
+    /// # use bevy_fontmesh::prelude::*;
+    /// # fn example(mut commands: Commands) {
+    /// commands.spawn(TextMeshBundle {
+    ///     text_mesh: TextMesh {
+    ///         text: "No assets needed".to_string(),
+    ///         font: FontMesh::default_handle(),
+    ///         ..default()
+    ///     },
+    ///     ..default()
+    /// });
     /// # }
     /// ```
-    pub fn glyph_metrics(&self, character: char) -> Option<GlyphMetrics> {
-        let face = fontmesh::parse_font(&self.data).ok()?;
-        let advance = fontmesh::glyph_advance(&face, character)?;
-
-        // Check if glyph has outline by trying to generate a mesh
-        let has_outline = fontmesh::char_to_mesh_2d(&face, character, 1).is_ok();
-
-        Some(GlyphMetrics {
-            advance,
-            has_outline,
-        })
+    #[cfg(feature = "default-font")]
+    pub fn default_handle() -> Handle<FontMesh> {
+        Handle::default()
     }
 
-    /// Get font-level metrics (ascender, descender, line height, etc.)
+    /// Generate the mesh of a single glyph by its id in the font.
+    ///
+    /// The glyph is extruded, bevelled, and tessellated according to `style`, with its
+    /// origin on the baseline at `(0, 0)` and sized by the style's font size. Layout
+    /// settings of the style, such as the anchor and spacing, don't apply to a single
+    /// glyph. Use this to build meshes from shaped text or to reach glyphs that no
+    /// character maps to.
     ///
-    /// Returns `None` if the font data is invalid.
+    /// Returns `None` if the font has no glyph with this id or the glyph has no outline.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use bevy::prelude::*;
-    /// # use bevy_fontmesh::FontMesh;
-    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// # use bevy_fontmesh::{FontMesh, GlyphId, TextMeshStyle};
+    /// # fn example(
+    /// #     font_assets: Res<Assets<FontMesh>>,
+    /// #     font_handle: Handle<FontMesh>,
+    /// #     mut meshes: ResMut<Assets<Mesh>>,
+    /// # ) {
     /// if let Some(font) = font_assets.get(&font_handle) {
-    ///     if let Some(metrics) = font.font_metrics() {
-    ///         println!("Line height: {}", metrics.line_height);
+    ///     if let Some(mesh) = font.glyph_by_id(GlyphId(42), &TextMeshStyle::default()) {
+    ///         let handle = meshes.add(mesh);
     ///     }
     /// }
     /// # }
     /// ```
-    pub fn font_metrics(&self) -> Option<FontMetrics> {
-        let face = fontmesh::parse_font(&self.data).ok()?;
-
-        let ascender = fontmesh::ascender(&face);
-        let descender = fontmesh::descender(&face);
-        let line_gap = fontmesh::line_gap(&face);
-
-        Some(FontMetrics {
-            ascender,
-            descender,
-            line_gap,
-            line_height: ascender - descender + line_gap,
-        })
+    pub fn glyph_by_id(&self, glyph_id: GlyphId, style: &TextMeshStyle) -> Option<Mesh> {
+        let face = self.face()?;
+        let glyph = GlyphGeometry::by_id(&face, glyph_id, style)?;
+
+        let mut data = TextMeshData {
+            vertices: glyph.vertices.iter().map(|v| v.to_array()).collect(),
+            normals: glyph.normals.iter().map(|n| n.to_array()).collect(),
+            uvs: if style.has_uvs() {
+                glyph_uvs(&glyph)
+            } else {
+                Vec::new()
+            },
+            indices: glyph.indices,
+            ..default()
+        };
+        if style.generate_tangents {
+            data.generate_tangents();
+        }
+        Some(data.into_mesh())
     }
+}
 
-    /// Calculate the width of a text string.
-    ///
-    /// This sums the advance widths of all characters. Does not account for kerning.
-    ///
-    /// # Example
+impl<'a> FontChain<'a> {
+    /// Parse the primary font and its fallbacks from loaded assets.
     ///
-    /// ```no_run
-    /// # use bevy::prelude::*;
-    /// # use bevy_fontmesh::FontMesh;
-    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
-    /// if let Some(font) = font_assets.get(&font_handle) {
-    ///     let width = font.text_width("Hello");
-    ///     println!("Text width: {}", width);
-    /// }
-    /// # }
-    /// ```
-    pub fn text_width(&self, text: &str) -> f32 {
-        let face = match fontmesh::parse_font(&self.data) {
-            Ok(f) => f,
-            Err(_) => return 0.0,
-        };
-
-        text.chars()
-            .map(|ch| {
-                fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
-                    if ch.is_whitespace() {
-                        // Use font metrics for a proportional fallback space width
-                        (fontmesh::ascender(&face) - fontmesh::descender(&face)) * 0.25
-                    } else {
-                        0.0
-                    }
-                })
+    /// Returns `None` while any of the fonts is still loading, so text is only generated
+    /// once the complete chain is available.
+    pub fn from_assets(
+        assets: &'a Assets<FontMesh>,
+        font: &Handle<FontMesh>,
+        fallback_fonts: &[Handle<FontMesh>],
+    ) -> Option<Self> {
+        let data = std::iter::once(font)
+            .chain(fallback_fonts)
+            .map(|handle| {
+                assets
+                    .get(handle)
+                    .map(|asset| (&*asset.data, asset.face_index))
             })
-            .sum()
+            .collect::<Option<Vec<_>>>()?;
+        Self::parse(data)
     }
 
-    /// Get character positions for a line of text.
+    /// Parse the fonts of a style from loaded assets and apply its font features.
     ///
-    /// Returns a vector of (char_index, x_position) pairs for each character.
-    /// Useful for cursor positioning in text editors.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # use bevy::prelude::*;
-    /// # use bevy_fontmesh::FontMesh;
-    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
-    /// if let Some(font) = font_assets.get(&font_handle) {
-    ///     let positions = font.char_positions("Hello");
-    ///     for (idx, x) in positions {
-    ///         println!("Char {} at x={}", idx, x);
-    ///     }
-    /// }
-    /// # }
-    /// ```
-    pub fn char_positions(&self, text: &str) -> Vec<(usize, f32)> {
-        let face = match fontmesh::parse_font(&self.data) {
-            Ok(f) => f,
-            Err(_) => return Vec::new(),
-        };
+    /// See [`from_assets`](Self::from_assets) and [`with_style`](Self::with_style).
+    pub fn for_style(
+        assets: &'a Assets<FontMesh>,
+        font: &Handle<FontMesh>,
+        style: &TextMeshStyle,
+    ) -> Option<Self> {
+        Self::from_assets(assets, font, &style.fallback_fonts).map(|fonts| fonts.with_style(style))
+    }
+}
 
-        text.chars()
-            .enumerate()
-            .scan(0.0, |x, (idx, ch)| {
-                let current_x = *x;
-                *x += fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
-                    if ch.is_whitespace() {
-                        // Use font metrics for a proportional fallback space width
-                        (fontmesh::ascender(&face) - fontmesh::descender(&face)) * 0.25
-                    } else {
-                        0.0
-                    }
-                });
-                Some((idx, current_x))
-            })
-            .collect()
+/// The font that keeps [`FontChain::from_assets`] from building a chain, if any.
+///
+/// This is the first font that isn't loaded yet, or the primary font if every font
+/// is loaded but the primary fails to parse.
+pub(crate) fn unavailable_font(
+    assets: &Assets<FontMesh>,
+    font: &Handle<FontMesh>,
+    fallback_fonts: &[Handle<FontMesh>],
+) -> Option<AssetId<FontMesh>> {
+    let unloaded = std::iter::once(font)
+        .chain(fallback_fonts)
+        .map(Handle::id)
+        .find(|&id| !assets.contains(id));
+    if unloaded.is_some() {
+        return unloaded;
     }
+    FontChain::from_assets(assets, font, fallback_fonts)
+        .is_none()
+        .then(|| font.id())
 }
 
 /// Asset loader for TrueType and OpenType font files.
 ///
 /// This loader is registered automatically by [`FontMeshPlugin`](crate::FontMeshPlugin)
-/// and handles `.ttf` and `.otf` file extensions.
+/// and handles `.ttf`, `.otf`, `.ttc`, and `.otc` file extensions, plus `.woff` and
+/// `.woff2` with the `woff` feature. Every face of a font collection is also added as a
+/// labeled asset named by its index, so `font.ttc#2` loads the third face.
 #[derive(Default)]
 pub struct FontMeshLoader;
 
@@ -212,6 +156,25 @@ pub enum FontMeshLoaderError {
     /// Failed to read the font file from disk.
     #[error("Could not load font file: {0}")]
     Io(#[from] std::io::Error),
+    /// The file is a WOFF or WOFF2 font that could not be decompressed.
+    #[cfg(feature = "woff")]
+    #[error("Could not decode web font: {0}")]
+    Woff(#[from] wuff::WuffErr),
+}
+
+#[cfg(not(feature = "woff"))]
+const EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc"];
+#[cfg(feature = "woff")]
+const EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc", "woff", "woff2"];
+
+/// Decompress WOFF and WOFF2 web fonts into plain font data, leaving other data as is.
+#[cfg(feature = "woff")]
+fn decode_web_font(data: Vec<u8>) -> Result<Vec<u8>, wuff::WuffErr> {
+    match data.get(..4) {
+        Some(b"wOFF") => wuff::decompress_woff1(&data),
+        Some(b"wOF2") => wuff::decompress_woff2(&data),
+        _ => Ok(data),
+    }
 }
 
 impl AssetLoader for FontMeshLoader {
@@ -223,14 +186,34 @@ impl AssetLoader for FontMeshLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut data = Vec::new();
         reader.read_to_end(&mut data).await?;
-        Ok(FontMesh { data })
+        #[cfg(feature = "woff")]
+        let data = decode_web_font(data)?;
+
+        // The faces of a collection all share the data
+        let data: Arc<[u8]> = data.into();
+        if let Some(count) = ttf_parser::fonts_in_collection(&data) {
+            for face_index in 0..count {
+                load_context.add_labeled_asset(
+                    face_index.to_string(),
+                    FontMesh {
+                        data: data.clone(),
+                        face_index,
+                    },
+                );
+            }
+        }
+
+        Ok(FontMesh {
+            data,
+            face_index: 0,
+        })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["ttf", "otf"]
+        EXTENSIONS
     }
 }
@@ -0,0 +1,257 @@
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{calculate_line_step, paragraph_lines};
+use crate::core::mesh::{
+    build_flat_line_data, build_line_data, lines_anchor_offset, stack_lines, LineMeshData,
+    TextMeshData,
+};
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// Settings for [`bake_bevel_normal_map`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BevelBakeSettings {
+    /// Texels per em of text, in both directions.
+    ///
+    /// The normal map is sized to the bounds of the text, clamped to
+    /// [`BevelBakeSettings::max_size`].
+    pub texels_per_em: f32,
+    /// Largest width or height of the normal map, in texels.
+    pub max_size: u32,
+    /// Curve subdivision of the cheap flat mesh, used even when the style has a curve
+    /// tolerance.
+    ///
+    /// The bevel detail comes from the normal map, so this can be far lower than the
+    /// [`TextMeshStyle::subdivision`](crate::TextMeshStyle::subdivision) used for the bake.
+    pub low_subdivision: u8,
+}
+
+impl Default for BevelBakeSettings {
+    fn default() -> Self {
+        Self {
+            texels_per_em: 256.0,
+            max_size: 4096,
+            low_subdivision: 4,
+        }
+    }
+}
+
+/// A flat text mesh with the bevel of the detailed mesh baked into a normal map.
+///
+/// Returned by [`bake_bevel_normal_map`].
+#[derive(Clone, Debug)]
+pub struct BakedTextMesh {
+    /// Flat, zero-depth text facing `+Z`, with UVs spanning the normal map and tangents
+    /// along `+X`.
+    pub mesh: Mesh,
+    /// Tangent-space normal map in Bevy's expected (Y+) convention.
+    ///
+    /// Use it as the [`StandardMaterial::normal_map_texture`] of the flat mesh.
+    pub normal_map: Image,
+}
+
+/// Bake the bevelled front of a text mesh into a normal map for a cheap flat mesh.
+///
+/// The text is generated twice: once with the full style, including its
+/// [`bevel`](crate::TextMeshStyle::bevel) and subdivision, and once as flat, zero-depth
+/// glyphs with [`BevelBakeSettings::low_subdivision`]. The front-facing surface of the
+/// detailed mesh is rasterized into a tangent-space normal map covering the text
+/// bounds, and the flat mesh gets UVs and tangents to match. Both meshes share the
+/// anchor of the detailed one, so the baked mesh can replace it in place.
+///
+/// This is meant for offline or load-time use; it runs on the calling thread. Without
+/// a bevel (or with a depth of `0.0`) the map is flat.
+///
+/// Returns `None` while the font or one of its fallbacks is not loaded, or if the
+/// text has no visible glyphs.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn bake_title(
+///     mut commands: Commands,
+///     fonts: Res<Assets<FontMesh>>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut images: ResMut<Assets<Image>>,
+///     mut materials: ResMut<Assets<StandardMaterial>>,
+///     font: Local<Handle<FontMesh>>,
+/// ) {
+///     let text_mesh = TextMesh {
+///         text: "TITLE".to_string(),
+///         font: font.clone(),
+///         style: TextMeshStyle {
+///             depth: 0.2,
+///             bevel: Some(BevelStyle::default()),
+///             subdivision: 40,
+///             ..default()
+///         },
+///         ..default()
+///     };
+///
+///     let settings = BevelBakeSettings::default();
+///     if let Some(baked) = bake_bevel_normal_map(&text_mesh, &fonts, &settings) {
+///         commands.spawn((
+///             Mesh3d(meshes.add(baked.mesh)),
+///             MeshMaterial3d(materials.add(StandardMaterial {
+///                 normal_map_texture: Some(images.add(baked.normal_map)),
+///                 ..default()
+///             })),
+///         ));
+///     }
+/// }
+/// ```
+pub fn bake_bevel_normal_map(
+    text_mesh: &TextMesh,
+    fonts: &Assets<FontMesh>,
+    settings: &BevelBakeSettings,
+) -> Option<BakedTextMesh> {
+    // 1. Parse the font and its fallbacks
+    let fonts = FontChain::for_style(fonts, &text_mesh.font, &text_mesh.style)?;
+    let style = &text_mesh.style;
+    let low_style = TextMeshStyle {
+        subdivision: settings.low_subdivision,
+        curve_tolerance: None,
+        ..style.clone()
+    };
+
+    // 2. Lay out both meshes, anchored by the detailed one
+    let lines: Vec<(&str, bool)> = paragraph_lines(&text_mesh.text).collect();
+    let high_lines: Vec<LineMeshData> = lines
+        .iter()
+        .map(|&(line, ends_paragraph)| build_line_data(line, ends_paragraph, &fonts, style))
+        .collect();
+    let low_lines: Vec<LineMeshData> = lines
+        .iter()
+        .map(|&(line, ends_paragraph)| {
+            build_flat_line_data(line, ends_paragraph, &fonts, &low_style)
+        })
+        .collect();
+
+    let line_step = calculate_line_step(&fonts, style);
+    let anchor_offset = lines_anchor_offset(&high_lines, line_step, style.anchor);
+    let high = stack_lines(&high_lines, line_step, anchor_offset);
+    let low = stack_lines(&low_lines, line_step, anchor_offset);
+
+    // 3. Size the normal map to the text bounds
+    let (min, max) = bounds_2d(&high)?;
+    let size = max - min;
+    let texels = (size * settings.texels_per_em)
+        .ceil()
+        .as_uvec2()
+        .clamp(UVec2::ONE, UVec2::splat(settings.max_size.max(1)));
+
+    // 4. Rasterize the front-facing surface of the detailed mesh
+    let normals = rasterize_front_normals(&high, min, max, texels);
+    let data = normals
+        .iter()
+        .flat_map(|normal| {
+            let [r, g, b] = (*normal * 0.5 + 0.5)
+                .to_array()
+                .map(|c| (c * 255.0).round() as u8);
+            [r, g, b, 255]
+        })
+        .collect();
+    let normal_map = Image::new(
+        Extent3d {
+            width: texels.x,
+            height: texels.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::default(),
+    );
+
+    // 5. Map the flat mesh onto the normal map
+    let uvs: Vec<[f32; 2]> = low
+        .vertices
+        .iter()
+        .map(|v| [(v[0] - min.x) / size.x, (max.y - v[1]) / size.y])
+        .collect();
+    let tangents = vec![[1.0, 0.0, 0.0, 1.0]; low.vertices.len()];
+    let mut mesh = TextMeshData { uvs, ..low }.into_mesh();
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_TANGENT,
+        VertexAttributeValues::Float32x4(tangents),
+    );
+
+    Some(BakedTextMesh { mesh, normal_map })
+}
+
+/// XY bounds of a mesh buffer, or `None` if it has no area.
+fn bounds_2d(data: &TextMeshData) -> Option<(Vec2, Vec2)> {
+    let (min, max) = data.vertices.iter().map(|v| Vec2::new(v[0], v[1])).fold(
+        None,
+        |bounds: Option<(Vec2, Vec2)>, v| {
+            Some(bounds.map_or((v, v), |(min, max)| (min.min(v), max.max(v))))
+        },
+    )?;
+    (max.x > min.x && max.y > min.y).then_some((min, max))
+}
+
+/// Interpolated normals of the nearest front-facing triangle at every texel center.
+///
+/// Rows run from the top of the bounds (`max.y`) down. Texels not covered by any
+/// front-facing triangle keep the flat `+Z` normal.
+fn rasterize_front_normals(data: &TextMeshData, min: Vec2, max: Vec2, texels: UVec2) -> Vec<Vec3> {
+    let (width, height) = (texels.x as usize, texels.y as usize);
+    let texel_size = (max - min) / texels.as_vec2();
+    let mut normals = vec![Vec3::Z; width * height];
+    let mut depths = vec![f32::NEG_INFINITY; width * height];
+
+    for triangle in data.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| Vec3::from_array(data.vertices[triangle[i] as usize]));
+        let [na, nb, nc] = [0, 1, 2].map(|i| Vec3::from_array(data.normals[triangle[i] as usize]));
+
+        // Only the surface seen from the front contributes
+        let [a2, b2, c2] = [a, b, c].map(Vec3::truncate);
+        let area = (b2 - a2).perp_dot(c2 - a2);
+        if area <= f32::EPSILON {
+            continue;
+        }
+
+        // Texel range covered by the triangle, in texel space with y pointing down
+        let to_texel = |p: Vec2| Vec2::new(p.x - min.x, max.y - p.y) / texel_size;
+        let lo = to_texel(a2).min(to_texel(b2)).min(to_texel(c2));
+        let hi = to_texel(a2).max(to_texel(b2)).max(to_texel(c2));
+        let (x0, y0) = (
+            lo.x.floor().max(0.0) as usize,
+            lo.y.floor().max(0.0) as usize,
+        );
+        let (x1, y1) = (
+            (hi.x.ceil() as usize).min(width),
+            (hi.y.ceil() as usize).min(height),
+        );
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let p = Vec2::new(
+                    min.x + (x as f32 + 0.5) * texel_size.x,
+                    max.y - (y as f32 + 0.5) * texel_size.y,
+                );
+                let wa = (b2 - p).perp_dot(c2 - p) / area;
+                let wb = (c2 - p).perp_dot(a2 - p) / area;
+                let wc = 1.0 - wa - wb;
+                if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                    continue;
+                }
+
+                let texel = y * width + x;
+                let depth = a.z * wa + b.z * wb + c.z * wc;
+                if depth > depths[texel] {
+                    depths[texel] = depth;
+                    normals[texel] = (na * wa + nb * wb + nc * wc).normalize_or(Vec3::Z);
+                }
+            }
+        }
+    }
+
+    normals
+}
@@ -0,0 +1,222 @@
+use crate::component::{TextMesh, TextMesh2d};
+use crate::core::fallback::FontChain;
+use crate::core::layout::{calculate_line_step, paragraph_lines};
+use crate::core::mesh::{build_flat_line_data, build_line_data, lines_anchor_offset, LineMeshData};
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Aligns separately spawned texts on a common baseline.
+///
+/// Give every text of a group, for example a label and its value, the same
+/// [`TextBaselineGroup::id`]. The first text that joins a group leads it: the others are
+/// moved vertically so the baseline of their first line lines up with the leader's,
+/// whatever their anchors, fonts, and sizes. With [`TextBaselineGroup::line_grid`] set
+/// on the leader, the shared baseline also snaps to a grid of that spacing.
+///
+/// Texts are aligned in their parent's space by changing the y translation of their
+/// [`Transform`], so members of a group should share a parent (or have none). Works on
+/// [`TextMesh`] and [`TextMesh2d`] entities.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let font = asset_server.load("fonts/font.ttf");
+///     commands.spawn((
+///         TextMeshBundle {
+///             text_mesh: TextMesh {
+///                 text: "Score:".to_string(),
+///                 font: font.clone(),
+///                 ..default()
+///             },
+///             transform: Transform::from_xyz(-2.0, 1.0, 0.0),
+///             ..default()
+///         },
+///         TextBaselineGroup::new(0),
+///     ));
+///     // Spawned with a different size and anchor, but still sits on the label's baseline
+///     commands.spawn((
+///         TextMeshBundle {
+///             text_mesh: TextMesh {
+///                 text: "1200".to_string(),
+///                 font,
+///                 style: TextMeshStyle {
+///                     anchor: TextAnchor::Center,
+///                     ..default()
+///                 },
+///                 ..default()
+///             },
+///             transform: Transform::from_xyz(1.0, 1.0, 0.0).with_scale(Vec3::splat(1.5)),
+///             ..default()
+///         },
+///         TextBaselineGroup::new(0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextBaselineGroup {
+    /// Texts with the same id are aligned to each other.
+    pub id: u32,
+    /// Spacing of the line grid the shared baseline snaps to, in the parent's space, or
+    /// `None` to keep the leader where it is. Only the leader's value is used.
+    pub line_grid: Option<f32>,
+}
+
+impl TextBaselineGroup {
+    /// Join the group with the given id.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            line_grid: None,
+        }
+    }
+
+    /// Snap the group's baseline to a grid of `spacing`, if this text leads the group.
+    pub fn with_line_grid(mut self, spacing: f32) -> Self {
+        self.line_grid = Some(spacing);
+        self
+    }
+}
+
+/// Height of the first baseline above the origin of a text, in its mesh space.
+#[derive(Component)]
+pub(crate) struct TextBaselineOffset(f32);
+
+/// Differences below this are left alone, so aligned texts don't change every frame.
+const BASELINE_EPSILON: f32 = 1e-4;
+
+/// Lays out one line of text, extruded or flat.
+type LineBuilder = fn(&str, bool, &FontChain, &TextMeshStyle) -> LineMeshData;
+
+fn baseline_offset(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    build_line: LineBuilder,
+) -> f32 {
+    let lines: Vec<LineMeshData> = paragraph_lines(text)
+        .map(|(line, ends_paragraph)| build_line(line, ends_paragraph, fonts, style))
+        .collect();
+    lines_anchor_offset(&lines, calculate_line_step(fonts, style), style.anchor).y
+}
+
+type GroupedTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<Ref<'static, TextMesh>>,
+        Option<Ref<'static, TextMesh2d>>,
+    ),
+    (
+        With<TextBaselineGroup>,
+        Or<(With<TextMesh>, With<TextMesh2d>)>,
+    ),
+>;
+
+type BaselineGroupQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TextBaselineGroup,
+        &'static mut Transform,
+        Option<&'static TextBaselineOffset>,
+    ),
+>;
+
+/// System that measures the baselines of grouped texts and lines them up.
+pub fn align_text_baselines(
+    mut commands: Commands,
+    font_assets: Res<Assets<FontMesh>>,
+    mut leaders: Local<HashMap<u32, Entity>>,
+    texts: GroupedTextQuery,
+    mut members: BaselineGroupQuery,
+) {
+    // 1. Measure texts that are new to a group or changed
+    let mut measured: HashMap<Entity, f32> = HashMap::default();
+    for (entity, text_mesh, text_mesh_2d) in texts.iter() {
+        let has_offset = members
+            .get(entity)
+            .is_ok_and(|(_, _, _, offset)| offset.is_some());
+        let (text, font, style, changed, build_line) = match (&text_mesh, &text_mesh_2d) {
+            (Some(text_mesh), _) => (
+                &text_mesh.text,
+                &text_mesh.font,
+                &text_mesh.style,
+                text_mesh.is_changed(),
+                build_line_data as LineBuilder,
+            ),
+            (None, Some(text_mesh)) => (
+                &text_mesh.text,
+                &text_mesh.font,
+                &text_mesh.style,
+                text_mesh.is_changed(),
+                build_flat_line_data as LineBuilder,
+            ),
+            (None, None) => continue,
+        };
+        if has_offset && !changed {
+            continue;
+        }
+        let Some(fonts) = FontChain::for_style(&font_assets, font, style) else {
+            continue;
+        };
+        let offset = baseline_offset(text, &fonts, style, build_line);
+        measured.insert(entity, offset);
+        commands.entity(entity).insert(TextBaselineOffset(offset));
+    }
+    let offset_of = |entity: Entity, offset: Option<&TextBaselineOffset>| {
+        measured
+            .get(&entity)
+            .copied()
+            .or(offset.map(|offset| offset.0))
+    };
+
+    // 2. Keep each group's leader, handing the lead on when it leaves the group
+    let mut groups: HashMap<u32, Vec<Entity>> = HashMap::default();
+    for (entity, group, _, offset) in members.iter() {
+        if offset_of(entity, offset).is_some() {
+            groups.entry(group.id).or_default().push(entity);
+        }
+    }
+    leaders.retain(|id, leader| groups.get(id).is_some_and(|group| group.contains(leader)));
+    for (id, group) in &groups {
+        leaders.entry(*id).or_insert(group[0]);
+    }
+
+    // 3. Move every member onto its leader's baseline
+    let baseline =
+        |transform: &Transform, offset: f32| transform.transform_point(Vec3::Y * offset).y;
+    for (id, group) in &groups {
+        let Ok((entity, leader, transform, offset)) = members.get(leaders[id]) else {
+            continue;
+        };
+        let Some(offset) = offset_of(entity, offset) else {
+            continue;
+        };
+        let leader_baseline = baseline(transform, offset);
+        let target = match leader.line_grid {
+            Some(spacing) if spacing > 0.0 => (leader_baseline / spacing).round() * spacing,
+            _ => leader_baseline,
+        };
+
+        for &entity in group {
+            let Ok((_, _, mut transform, offset)) = members.get_mut(entity) else {
+                continue;
+            };
+            let Some(offset) = offset_of(entity, offset) else {
+                continue;
+            };
+            let shift = target - baseline(&transform, offset);
+            if shift.abs() > BASELINE_EPSILON {
+                transform.translation.y += shift;
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+/// Turns the entity to face the nearest active camera every frame.
+///
+/// The entity is turned parallel to the camera's image plane, with the text's front,
+/// which faces `+Z`, towards the camera and its up axis along the camera's, so the text
+/// reads straight on from anywhere. Rotations of parent entities are undone, while the
+/// entity's translation and scale are kept. [`FloatingText3d`](crate::FloatingText3d)s
+/// require it.
+///
+/// The rotation is computed from the camera transforms of the previous frame, before
+/// transform propagation.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Shopkeeper".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextBillboard,
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct TextBillboard;
+
+/// System that turns [`TextBillboard`]s to face the nearest active camera.
+pub(crate) fn face_text_billboards(
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut billboards: Query<(&GlobalTransform, &mut Transform), With<TextBillboard>>,
+) {
+    let cameras: Vec<(Vec3, Quat)> = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .map(|(_, transform)| {
+            let (_, rotation, translation) = transform.to_scale_rotation_translation();
+            (translation, rotation)
+        })
+        .collect();
+
+    for (global, mut transform) in billboards.iter_mut() {
+        let position = global.translation();
+        let Some(&(_, camera)) = cameras.iter().min_by(|(a, _), (b, _)| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        }) else {
+            return;
+        };
+        // The parent's rotation is what the global rotation adds to the local one
+        let (_, global_rotation, _) = global.to_scale_rotation_translation();
+        let parent = global_rotation * transform.rotation.inverse();
+        let rotation = (parent.inverse() * camera).normalize();
+        // Only touch the component on a turn, so change detection stays quiet
+        if !transform.rotation.abs_diff_eq(rotation, 1e-6) {
+            transform.rotation = rotation;
+        }
+    }
+}
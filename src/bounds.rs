@@ -0,0 +1,118 @@
+use crate::core::mesh::{line_offset, lines_bounds, LineMeshData};
+use crate::core::style::TextOrientation;
+use bevy::camera::primitives::MeshAabb;
+use bevy::prelude::*;
+
+/// Measured extent of a generated [`TextMesh`](crate::TextMesh) or
+/// [`TextMesh2d`](crate::TextMesh2d), in the entity's local space.
+///
+/// Inserted and kept up to date by the plugin whenever it generates the text, so
+/// backplates, selection highlights, and cameras can be fitted to the text without
+/// measuring it again.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # #[derive(Component)]
+/// # struct Backplate;
+/// // Stretch a unit quad child behind its text
+/// fn fit_backplates(
+///     texts: Query<(&TextMeshBounds, &Children), Changed<TextMeshBounds>>,
+///     mut plates: Query<&mut Transform, With<Backplate>>,
+/// ) {
+///     for (bounds, children) in texts.iter() {
+///         for child in children.iter() {
+///             if let Ok(mut transform) = plates.get_mut(child) {
+///                 let size = bounds.size().truncate() + Vec2::splat(0.2);
+///                 transform.translation = bounds.center().with_z(-0.1);
+///                 transform.scale = size.extend(1.0);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshBounds {
+    /// Minimum corner of the generated geometry.
+    pub min: Vec3,
+    /// Maximum corner of the generated geometry.
+    pub max: Vec3,
+    /// Box of every line, from its start to its end and from the font's ascender down
+    /// to its descender, so lines of different content get boxes of the same height.
+    /// Columns of vertical text get boxes as wide as the font is high, centered on the
+    /// column.
+    ///
+    /// Empty for text laid out along a [`TextPath`](crate::TextPath).
+    pub line_rects: Vec<Rect>,
+}
+
+impl TextMeshBounds {
+    /// Size of the generated geometry.
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Center of the generated geometry.
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Bounds of anchored lines, given the vertical extent of the primary font.
+    pub(crate) fn from_lines<'a>(
+        lines: impl IntoIterator<Item = &'a LineMeshData> + Clone,
+        orientation: TextOrientation,
+        line_height: f32,
+        offset: Vec3,
+        (ascender, descender): (f32, f32),
+    ) -> Self {
+        let line_step = orientation.line_step(line_height);
+        let (min, max) = lines_bounds(lines.clone(), line_step)
+            .map(|(min, max)| (min + offset, max + offset))
+            .unwrap_or_default();
+        let half_width = (ascender - descender) * 0.5;
+        let line_rects = lines
+            .into_iter()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let origin = line_offset(line_index, line_step) + offset;
+                if orientation.is_vertical() {
+                    Rect::new(
+                        origin.x - half_width,
+                        origin.y + line.start - line.width,
+                        origin.x + half_width,
+                        origin.y + line.start,
+                    )
+                } else {
+                    Rect::new(
+                        origin.x + line.start,
+                        origin.y + descender,
+                        origin.x + line.start + line.width,
+                        origin.y + ascender,
+                    )
+                }
+            })
+            .collect();
+
+        Self {
+            min,
+            max,
+            line_rects,
+        }
+    }
+
+    /// Bounds of a generated mesh without line boxes.
+    pub(crate) fn from_mesh(mesh: &Mesh) -> Self {
+        let (min, max) = mesh
+            .compute_aabb()
+            .map(|aabb| (aabb.min().into(), aabb.max().into()))
+            .unwrap_or_default();
+        Self {
+            min,
+            max,
+            line_rects: Vec::new(),
+        }
+    }
+}
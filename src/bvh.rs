@@ -0,0 +1,221 @@
+use crate::silhouette::TextSilhouette;
+use bevy::math::Ray3d;
+use bevy::prelude::*;
+
+/// Most polygons kept in a single leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+/// Bounding volume hierarchy over the polygons of a [`TextSilhouette`].
+///
+/// Stored in [`TextMeshLayout`](crate::TextMeshLayout) and rebuilt with it, so ray casts
+/// and closest-point queries only look at the few polygons near the query instead of
+/// every polygon of the text. The hierarchy is built top-down by splitting along the
+/// longer side of the bounds: nodes near the root cover groups of lines, and deeper
+/// nodes cover runs of glyphs within a line. Paragraph-scale text with thousands of
+/// glyphs is searched in a logarithmic number of steps.
+///
+/// Bounds are 2D, in the local space of the text entity; the extrusion spans the same
+/// depth for every polygon.
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+pub struct TextMeshBvh {
+    nodes: Vec<BvhNode>,
+    polygons: Vec<BvhPolygon>,
+}
+
+/// A node of the hierarchy.
+///
+/// Leaves own `count` polygons starting at `first`. Inner nodes have a `count` of `0`
+/// and their two children at `first` and `first + 1`.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+struct BvhNode {
+    bounds: Rect,
+    first: u32,
+    count: u32,
+}
+
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+struct BvhPolygon {
+    bounds: Rect,
+    line: u32,
+    polygon: u32,
+}
+
+impl TextMeshBvh {
+    /// Build the hierarchy over every polygon of `silhouette`.
+    pub fn new(silhouette: &TextSilhouette) -> Self {
+        let mut polygons: Vec<BvhPolygon> = silhouette
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(|(line, silhouette)| {
+                silhouette
+                    .polygons
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, points)| !points.is_empty())
+                    .map(move |(polygon, points)| BvhPolygon {
+                        bounds: points
+                            .iter()
+                            .fold(Rect::from_center_size(points[0], Vec2::ZERO), |rect, &p| {
+                                rect.union_point(p)
+                            }),
+                        line: line as u32,
+                        polygon: polygon as u32,
+                    })
+            })
+            .collect();
+        if polygons.is_empty() {
+            return Self::default();
+        }
+
+        let mut nodes = vec![BvhNode {
+            bounds: Rect::default(),
+            first: 0,
+            count: 0,
+        }];
+        split(&mut nodes, 0, &mut polygons, 0);
+        Self { nodes, polygons }
+    }
+
+    /// Bounds of the whole text, or `None` if it has no polygons.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.nodes.first().map(|root| root.bounds)
+    }
+
+    /// Number of nodes in the hierarchy.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The `(line, polygon)` indices of every polygon whose bounds overlap `rect`.
+    ///
+    /// Indices refer to [`TextSilhouette::lines`] and
+    /// [`LineSilhouette::polygons`](crate::LineSilhouette::polygons).
+    pub fn polygons_in(&self, rect: Rect) -> Vec<(usize, usize)> {
+        let mut found = Vec::new();
+        self.search(
+            f32::INFINITY,
+            |bounds| {
+                (bounds.min.cmple(rect.max).all() && rect.min.cmple(bounds.max).all())
+                    .then_some(0.0)
+            },
+            |line, polygon| {
+                found.push((line, polygon));
+                None
+            },
+        );
+        found
+    }
+
+    /// Best-first search through the hierarchy.
+    ///
+    /// `lower_bound` returns the smallest distance any polygon within the given bounds
+    /// can have, or `None` to skip them. Nodes closer than `limit` are visited nearest
+    /// first, calling `visit` for each of their polygons; a distance returned by `visit`
+    /// tightens the limit, pruning the nodes that can't get any closer.
+    pub(crate) fn search(
+        &self,
+        mut limit: f32,
+        lower_bound: impl Fn(Rect) -> Option<f32>,
+        mut visit: impl FnMut(usize, usize) -> Option<f32>,
+    ) {
+        let Some(root) = self.nodes.first() else {
+            return;
+        };
+        let Some(distance) = lower_bound(root.bounds) else {
+            return;
+        };
+
+        let mut stack = vec![(0, distance)];
+        while let Some((index, distance)) = stack.pop() {
+            if distance > limit {
+                continue;
+            }
+            let node = self.nodes[index];
+            let first = node.first as usize;
+            if node.count > 0 {
+                for polygon in &self.polygons[first..first + node.count as usize] {
+                    if let Some(distance) = visit(polygon.line as usize, polygon.polygon as usize) {
+                        limit = limit.min(distance);
+                    }
+                }
+                continue;
+            }
+
+            let mut children: Vec<(usize, f32)> = [first, first + 1]
+                .into_iter()
+                .filter_map(|child| {
+                    lower_bound(self.nodes[child].bounds).map(|distance| (child, distance))
+                })
+                .collect();
+            // Push the farther child first, so the nearer one is searched first
+            children.sort_by(|a, b| b.1.total_cmp(&a.1));
+            stack.extend(children);
+        }
+    }
+}
+
+/// Fill in the node at `index` for `polygons`, which start at `offset` in the
+/// polygon list, splitting it until the leaves are small enough.
+fn split(nodes: &mut Vec<BvhNode>, index: usize, polygons: &mut [BvhPolygon], offset: usize) {
+    let bounds = polygons.iter().fold(polygons[0].bounds, |rect, polygon| {
+        rect.union(polygon.bounds)
+    });
+    if polygons.len() <= LEAF_SIZE {
+        nodes[index] = BvhNode {
+            bounds,
+            first: offset as u32,
+            count: polygons.len() as u32,
+        };
+        return;
+    }
+
+    // Split at the median along the longer side
+    let axis = if bounds.width() >= bounds.height() {
+        0
+    } else {
+        1
+    };
+    let middle = polygons.len() / 2;
+    polygons.select_nth_unstable_by(middle, |a, b| {
+        a.bounds.center()[axis].total_cmp(&b.bounds.center()[axis])
+    });
+
+    let first = nodes.len();
+    nodes[index] = BvhNode {
+        bounds,
+        first: first as u32,
+        count: 0,
+    };
+    nodes.extend([nodes[index]; 2]);
+    let (left, right) = polygons.split_at_mut(middle);
+    split(nodes, first, left, offset);
+    split(nodes, first + 1, right, offset + middle);
+}
+
+/// Squared distance from `point` to the box spanned by `rect` and the extrusion depth.
+pub(crate) fn box_distance_squared(rect: Rect, depth: f32, point: Vec3) -> f32 {
+    let min = rect.min.extend(-depth);
+    let max = rect.max.extend(0.0);
+    (min - point)
+        .max(point - max)
+        .max(Vec3::ZERO)
+        .length_squared()
+}
+
+/// Distance along `ray` at which it enters the box spanned by `rect` and the extrusion
+/// depth, or `None` if it misses the box.
+pub(crate) fn box_ray_distance(rect: Rect, depth: f32, ray: Ray3d) -> Option<f32> {
+    let min = rect.min.extend(-depth);
+    let max = rect.max.extend(0.0);
+    let inverse = ray.direction.recip();
+    let a = (min - ray.origin) * inverse;
+    let b = (max - ray.origin) * inverse;
+    // Axes the ray runs parallel to give infinite distances, or NaN if the origin lies on
+    // a face; `min` and `max` skip the NaN
+    let near = a.min(b);
+    let far = a.max(b);
+    let enter = near.max_element().max(0.0);
+    let exit = far.min_element();
+    (enter <= exit).then_some(enter)
+}
@@ -0,0 +1,304 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{layout_text_in, TextLayout};
+use crate::core::mesh::TextMeshData;
+use crate::FontMesh;
+use bevy::prelude::*;
+use std::ops::Range;
+
+/// Distance of carets in front of the text and of selections behind it.
+const GAP: f32 = 0.01;
+
+/// Shows a caret in a [`TextMesh`], for in-world text editors.
+///
+/// Put it on an entity with a [`TextMesh`] to get a [`TextCursor3dQuad`] child: a flat
+/// quad in front of the text, as high as the line box, in front of the character at
+/// [`byte_index`](Self::byte_index). It follows the index and the text as they change,
+/// and is despawned when the component is removed. The caret is placed by
+/// [`TextLayout::caret_transform`], which gives the same placement for custom caret
+/// meshes, before the anchor is applied.
+///
+/// Text laid out along a [`TextPath`](crate::TextPath) has no caret.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn type_text(
+///     mut keys: MessageReader<bevy::input::keyboard::KeyboardInput>,
+///     mut editors: Query<(&mut TextMesh, &mut TextCursor3d)>,
+/// ) {
+///     for key in keys.read() {
+///         let bevy::input::keyboard::Key::Character(typed) = &key.logical_key else {
+///             continue;
+///         };
+///         for (mut text_mesh, mut cursor) in editors.iter_mut() {
+///             text_mesh.text.insert_str(cursor.byte_index, typed);
+///             cursor.byte_index += typed.len();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TextCursor3d {
+    /// Byte index in the text of the character the caret stands in front of. The length
+    /// of the text puts it at the end.
+    pub byte_index: usize,
+    /// Width of the caret, in mesh units. Default: `0.05`.
+    pub width: f32,
+    /// Material of the caret. The default handle uses the material of the text.
+    pub material: Handle<StandardMaterial>,
+}
+
+impl Default for TextCursor3d {
+    fn default() -> Self {
+        Self {
+            byte_index: 0,
+            width: 0.05,
+            material: Handle::default(),
+        }
+    }
+}
+
+/// Highlights a range of a [`TextMesh`], for in-world text editors.
+///
+/// Put it on an entity with a [`TextMesh`] to get a [`TextSelection3dQuads`] child with a
+/// flat quad behind every line the selected bytes cover, as high as the line box. The
+/// quads follow the range and the text as they change, and are despawned when the
+/// component is removed. They are the boxes of [`TextLayout::selection_rects`].
+///
+/// Text laid out along a [`TextPath`](crate::TextPath) has no highlight.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Hello\nWorld".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     // Select "lo\nWo"
+///     TextSelection3d {
+///         range: 3..8,
+///         material: materials.add(Color::srgba(0.2, 0.4, 1.0, 0.5)),
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct TextSelection3d {
+    /// Byte range of the selected text. Empty ranges highlight nothing.
+    pub range: Range<usize>,
+    /// Material of the highlight. The default handle uses the material of the text.
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Marker for the caret quad of a [`TextCursor3d`].
+///
+/// Spawned as a child of the text entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextCursor3dQuad;
+
+/// Marker for the highlight quads of a [`TextSelection3d`].
+///
+/// Spawned as a child of the text entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextSelection3dQuads;
+
+type TextCaretQuery<'w, 's, C> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, C>,
+        Ref<'static, TextMesh>,
+        Ref<'static, TextMeshBounds>,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+        Option<&'static Children>,
+    ),
+>;
+
+/// System that places the caret quads of text with a [`TextCursor3d`].
+///
+/// Runs after mesh generation, so carets always match the current layout.
+pub fn update_text_cursors(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    texts: TextCaretQuery<TextCursor3d>,
+    quads: Query<(), With<TextCursor3dQuad>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextCursor3d>,
+) {
+    // 1. Despawn the carets of text that no longer has one
+    despawn_children(&mut commands, removed.read(), &children, &quads);
+
+    // 2. Move carets; the line cache edits meshes in place, so text changes count
+    for (entity, cursor, text_mesh, bounds, text_material, children) in texts.iter() {
+        if !(cursor.is_changed() || text_mesh.is_changed() || bounds.is_changed()) {
+            continue;
+        }
+        let existing =
+            children.and_then(|children| children.iter().find(|&child| quads.contains(child)));
+        let Some(transform) = anchored_layout(&font_assets, &text_mesh, &bounds)
+            .and_then(|(layout, offset)| {
+                let mut transform = layout.caret_transform(cursor.byte_index)?;
+                transform.translation += offset.extend(bounds.max.z + GAP);
+                Some(transform)
+            })
+            .map(|transform| {
+                transform.with_scale(transform.scale * Vec3::new(cursor.width, 1.0, 1.0))
+            })
+        else {
+            // Keep the caret for when the text can be laid out again
+            if let Some(child) = existing {
+                commands.entity(child).insert(Visibility::Hidden);
+            }
+            continue;
+        };
+
+        let caret = (
+            transform,
+            MeshMaterial3d(material_or_text(&cursor.material, text_material)),
+            Visibility::Inherited,
+        );
+        match existing {
+            Some(child) => {
+                commands.entity(child).insert(caret);
+            }
+            None => {
+                commands.entity(entity).with_child((
+                    TextCursor3dQuad,
+                    Mesh3d(meshes.add(Rectangle::new(1.0, 1.0))),
+                    caret,
+                ));
+            }
+        }
+    }
+}
+
+/// System that builds the highlight quads of text with a [`TextSelection3d`].
+///
+/// Runs after mesh generation, so highlights always match the current layout.
+pub fn update_text_selections(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    texts: TextCaretQuery<TextSelection3d>,
+    quads: Query<(), With<TextSelection3dQuads>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextSelection3d>,
+) {
+    // 1. Despawn the highlights of text that no longer has a selection
+    despawn_children(&mut commands, removed.read(), &children, &quads);
+
+    // 2. Rebuild highlights; the line cache edits meshes in place, so text changes count
+    for (entity, selection, text_mesh, bounds, text_material, children) in texts.iter() {
+        if !(selection.is_changed() || text_mesh.is_changed() || bounds.is_changed()) {
+            continue;
+        }
+        let existing =
+            children.and_then(|children| children.iter().find(|&child| quads.contains(child)));
+        let Some((layout, offset)) = anchored_layout(&font_assets, &text_mesh, &bounds) else {
+            continue;
+        };
+
+        let mut data = TextMeshData::default();
+        for rect in layout.selection_rects(selection.range.clone()) {
+            add_quad(&mut data, rect.min + offset, rect.max + offset);
+        }
+        let highlight = (
+            Mesh3d(meshes.add(data.into_mesh())),
+            MeshMaterial3d(material_or_text(&selection.material, text_material)),
+            Transform::from_xyz(0.0, 0.0, bounds.min.z - GAP),
+        );
+        match existing {
+            Some(child) => {
+                commands.entity(child).insert(highlight);
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .with_child((TextSelection3dQuads, highlight));
+            }
+        }
+    }
+}
+
+/// Layout of the text of `text_mesh`, and the offset its anchored mesh was moved by.
+///
+/// Returns `None` while the fonts aren't loaded, and for text without line boxes, such
+/// as text on a path.
+fn anchored_layout(
+    font_assets: &Assets<FontMesh>,
+    text_mesh: &TextMesh,
+    bounds: &TextMeshBounds,
+) -> Option<(TextLayout, Vec2)> {
+    let fonts = FontChain::for_style(font_assets, &text_mesh.font, &text_mesh.style)?;
+    let layout = layout_text_in(&fonts, &text_mesh.text, &text_mesh.style);
+
+    // The mesh is anchored by its geometry, so take the offset from its first line box
+    let offset = bounds.line_rects.first()?.min - layout.line_rect(0)?.min;
+    Some((layout, offset))
+}
+
+/// `material`, or the material of the text for the default handle.
+fn material_or_text(
+    material: &Handle<StandardMaterial>,
+    text_material: Option<&MeshMaterial3d<StandardMaterial>>,
+) -> Handle<StandardMaterial> {
+    if material.id() == AssetId::default() {
+        text_material
+            .map(|material| material.0.clone())
+            .unwrap_or_default()
+    } else {
+        material.clone()
+    }
+}
+
+/// Despawn the children of `entities` that match `quads`.
+fn despawn_children<F: bevy::ecs::query::QueryFilter>(
+    commands: &mut Commands,
+    entities: impl Iterator<Item = Entity>,
+    children: &Query<&Children>,
+    quads: &Query<(), F>,
+) {
+    for entity in entities {
+        let Ok(children) = children.get(entity) else {
+            continue;
+        };
+        for child in children.iter() {
+            if quads.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+}
+
+/// An axis-aligned rectangle facing the front.
+fn add_quad(data: &mut TextMeshData, min: Vec2, max: Vec2) {
+    let first = data.vertices.len() as u32;
+    for corner in [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)] {
+        data.vertices.push([corner.x, corner.y, 0.0]);
+        data.normals.push([0.0, 0.0, 1.0]);
+    }
+    data.indices
+        .extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+}
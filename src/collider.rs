@@ -0,0 +1,239 @@
+use crate::component::{GlyphMesh, TextMesh};
+use bevy::mesh::Indices;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Collision shape built for a [`TextMeshCollider`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextColliderShape {
+    /// The exact triangles of the mesh.
+    ///
+    /// Matches the letters precisely, but physics engines treat triangle meshes as
+    /// hollow, which suits static text best.
+    #[default]
+    TriMesh,
+    /// The convex hull of the mesh vertices.
+    ///
+    /// A cheap, solid shape. Fills the gaps between letters, so it works best on
+    /// individual glyphs of a [`TextMeshGlyphs`](crate::TextMeshGlyphs).
+    ConvexHull,
+}
+
+/// Adds collision geometry to generated text.
+///
+/// Put it on an entity with a [`TextMesh`] to get one collider for the whole text, or
+/// on a [`TextMeshGlyphs`](crate::TextMeshGlyphs) to get one per glyph entity. Every
+/// generated mesh entity receives a [`TextColliderData`] with the raw vertices and
+/// triangles, kept in sync as the text changes.
+///
+/// With the `avian3d` or `bevy_rapier3d` feature, the matching `Collider` component of
+/// that engine is inserted alongside the raw data. Add rigid bodies and other physics
+/// components yourself.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// // Clickable letters, each with its own solid hull
+/// commands.spawn((
+///     TextMeshGlyphsBundle {
+///         text_glyphs: TextMeshGlyphs {
+///             text: "PLAY".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshCollider {
+///         shape: TextColliderShape::ConvexHull,
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshCollider {
+    /// Shape of the generated colliders.
+    pub shape: TextColliderShape,
+}
+
+/// Raw collision geometry of a generated text or glyph mesh.
+///
+/// Vertices shared by several faces are welded, so the data is suitable for any
+/// physics engine's triangle mesh or convex hull constructor. Positions are in the
+/// local space of the entity.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct TextColliderData {
+    /// The shape requested by the [`TextMeshCollider`].
+    pub shape: TextColliderShape,
+    /// Unique vertex positions.
+    pub vertices: Vec<Vec3>,
+    /// Triangles as indices into `vertices`.
+    pub indices: Vec<[u32; 3]>,
+}
+
+impl TextColliderData {
+    /// Extract welded collision geometry from a triangle mesh.
+    ///
+    /// Returns `None` if the mesh has no positions or no triangles.
+    pub fn from_mesh(mesh: &Mesh, shape: TextColliderShape) -> Option<Self> {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+        let mesh_indices: Vec<usize> = match mesh.indices()? {
+            Indices::U16(indices) => indices.iter().map(|&i| i as usize).collect(),
+            Indices::U32(indices) => indices.iter().map(|&i| i as usize).collect(),
+        };
+
+        // Weld vertices that only differ in their normal or UV
+        let mut welded: HashMap<[u32; 3], u32> = HashMap::default();
+        let mut vertices = Vec::new();
+        let remap: Vec<u32> = positions
+            .iter()
+            .map(|position| {
+                *welded.entry(position.map(f32::to_bits)).or_insert_with(|| {
+                    vertices.push(Vec3::from_array(*position));
+                    vertices.len() as u32 - 1
+                })
+            })
+            .collect();
+
+        let indices: Vec<[u32; 3]> = mesh_indices
+            .chunks_exact(3)
+            .map(|triangle| [remap[triangle[0]], remap[triangle[1]], remap[triangle[2]]])
+            .filter(|[a, b, c]| a != b && b != c && a != c)
+            .collect();
+        if indices.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            shape,
+            vertices,
+            indices,
+        })
+    }
+
+    /// Build an avian3d collider of the requested shape.
+    ///
+    /// Returns `None` if a convex hull can't be built from the vertices.
+    #[cfg(feature = "avian3d")]
+    pub fn to_avian(&self) -> Option<avian3d::prelude::Collider> {
+        use avian3d::prelude::Collider;
+        match self.shape {
+            TextColliderShape::TriMesh => Some(Collider::trimesh(
+                self.vertices.clone(),
+                self.indices.clone(),
+            )),
+            TextColliderShape::ConvexHull => Collider::convex_hull(self.vertices.clone()),
+        }
+    }
+
+    /// Build a Rapier collider of the requested shape.
+    ///
+    /// Returns `None` if Rapier rejects the triangles or the convex hull.
+    #[cfg(feature = "bevy_rapier3d")]
+    pub fn to_rapier(&self) -> Option<bevy_rapier3d::prelude::Collider> {
+        use bevy_rapier3d::prelude::Collider;
+        match self.shape {
+            TextColliderShape::TriMesh => {
+                Collider::trimesh(self.vertices.clone(), self.indices.clone()).ok()
+            }
+            TextColliderShape::ConvexHull => Collider::convex_hull(&self.vertices),
+        }
+    }
+}
+
+/// Insert the collider data, and the physics engine colliders if enabled, on `entity`.
+fn insert_collider(commands: &mut Commands, entity: Entity, data: TextColliderData) {
+    let mut entity = commands.entity(entity);
+
+    #[cfg(feature = "avian3d")]
+    match data.to_avian() {
+        Some(collider) => entity.insert(collider),
+        None => entity.remove::<avian3d::prelude::Collider>(),
+    };
+    #[cfg(feature = "bevy_rapier3d")]
+    match data.to_rapier() {
+        Some(collider) => entity.insert(collider),
+        None => entity.remove::<bevy_rapier3d::prelude::Collider>(),
+    };
+
+    entity.insert(data);
+}
+
+/// Remove the collider data, and the physics engine colliders if enabled, from `entity`.
+fn remove_collider(commands: &mut Commands, entity: Entity) {
+    let Ok(mut entity) = commands.get_entity(entity) else {
+        return;
+    };
+
+    entity.remove::<TextColliderData>();
+    #[cfg(feature = "avian3d")]
+    entity.remove::<avian3d::prelude::Collider>();
+    #[cfg(feature = "bevy_rapier3d")]
+    entity.remove::<bevy_rapier3d::prelude::Collider>();
+}
+
+type TextColliderQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshCollider>,
+        Ref<'static, TextMesh>,
+        Ref<'static, Mesh3d>,
+    ),
+>;
+
+/// System that builds [`TextColliderData`] for text with a [`TextMeshCollider`].
+///
+/// Runs after mesh generation, so colliders always match the current meshes.
+pub fn update_text_colliders(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    colliders: Query<Ref<TextMeshCollider>>,
+    texts: TextColliderQuery,
+    glyphs: Query<(Entity, &ChildOf, Ref<Mesh3d>), With<GlyphMesh>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextMeshCollider>,
+) {
+    // 1. Drop colliders from text that no longer asks for them
+    for entity in removed.read() {
+        remove_collider(&mut commands, entity);
+        for child in children.iter_descendants(entity) {
+            if glyphs.contains(child) {
+                remove_collider(&mut commands, child);
+            }
+        }
+    }
+
+    // 2. Whole-text colliders; the line cache edits meshes in place, so text changes count
+    for (entity, collider, text_mesh, mesh) in texts.iter() {
+        if !(collider.is_changed() || text_mesh.is_changed() || mesh.is_changed()) {
+            continue;
+        }
+        if let Some(data) = meshes
+            .get(&mesh.0)
+            .and_then(|mesh| TextColliderData::from_mesh(mesh, collider.shape))
+        {
+            insert_collider(&mut commands, entity, data);
+        }
+    }
+
+    // 3. Per-glyph colliders
+    for (entity, child_of, mesh) in glyphs.iter() {
+        let Ok(collider) = colliders.get(child_of.parent()) else {
+            continue;
+        };
+        if !(collider.is_changed() || mesh.is_changed()) {
+            continue;
+        }
+        if let Some(data) = meshes
+            .get(&mesh.0)
+            .and_then(|mesh| TextColliderData::from_mesh(mesh, collider.shape))
+        {
+            insert_collider(&mut commands, entity, data);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::component::{TextMesh, TextMeshBundle};
+use bevy::prelude::*;
+
+/// Extension of [`Commands`] for spawning text without filling in a bundle.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands
+///     .spawn_text_mesh(TextMesh::new("Score").font(asset_server.load("fonts/font.ttf")))
+///     .insert(Transform::from_xyz(0.0, 2.0, 0.0));
+/// # }
+/// ```
+pub trait SpawnTextMeshExt {
+    /// Spawn a [`TextMeshBundle`] showing `text_mesh`, with the default material and
+    /// transform.
+    ///
+    /// Insert a [`Transform`] or [`MeshMaterial3d`] on the returned commands to place
+    /// or color the text.
+    fn spawn_text_mesh(&mut self, text_mesh: TextMesh) -> EntityCommands<'_>;
+}
+
+impl SpawnTextMeshExt for Commands<'_, '_> {
+    fn spawn_text_mesh(&mut self, text_mesh: TextMesh) -> EntityCommands<'_> {
+        self.spawn(TextMeshBundle {
+            text_mesh,
+            ..default()
+        })
+    }
+}
@@ -1,51 +1,9 @@
-use crate::asset::FontMesh;
+use crate::core::style::{JustifyText, TextAnchor, TextMeshStyle};
+use crate::core::FontMesh;
+use crate::gradient::TextGradient;
+use bevy::ecs::component::Mutable;
 use bevy::prelude::*;
-
-/// Determines where the text mesh is positioned relative to its transform origin.
-///
-/// The anchor point acts as a pivot for positioning the text. For example, [`TextAnchor::Center`]
-/// places the transform at the center of the text bounds, while [`TextAnchor::BottomLeft`]
-/// places it at the bottom-left corner.
-///
-/// # Examples
-///
-/// ```
-/// # use bevy_fontmesh::prelude::*;
-/// # use bevy::prelude::*;
-/// # use bevy::prelude::default;
-/// // Text centered on its transform
-/// let style = TextMeshStyle {
-///     anchor: TextAnchor::Center,
-///     ..default()
-/// };
-///
-/// // Text positioned by its top-left corner
-/// let style = TextMeshStyle {
-///     anchor: TextAnchor::TopLeft,
-///     ..default()
-/// };
-///
-/// // Custom pivot point at 25% from left, 75% from bottom
-/// let style = TextMeshStyle {
-///     anchor: TextAnchor::Custom(Vec2::new(0.25, 0.75)),
-///     ..default()
-/// };
-/// ```
-#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
-pub enum TextAnchor {
-    #[default]
-    TopLeft,
-    TopCenter,
-    TopRight,
-    CenterLeft,
-    Center,
-    CenterRight,
-    BottomLeft,
-    BottomCenter,
-    BottomRight,
-    /// Custom anchor point (0.0-1.0), where (0,0) is BottomLeft and (1,1) is TopRight
-    Custom(Vec2),
-}
+use std::ops::Range;
 
 /// Component for generating 3D text meshes from fonts.
 ///
@@ -53,6 +11,11 @@ pub enum TextAnchor {
 /// based on the specified text, font, and style. The mesh is regenerated whenever the
 /// component changes.
 ///
+/// The component brings its [`Mesh3d`], [`Transform`], and [`Visibility`] as required
+/// components, along with an empty material slot that
+/// [`FontMeshSettings`](crate::FontMeshSettings) fills with its default material, so
+/// spawning a bare `TextMesh` displays text; [`TextMeshBundle`] only sets them up front.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -68,7 +31,9 @@ pub enum TextAnchor {
 ///             subdivision: 25,
 ///             anchor: TextAnchor::Center,
 ///             justify: JustifyText::Center,
+///             ..default()
 ///         },
+///         ..default()
 ///     },
 ///     ..default()
 /// });
@@ -93,129 +58,157 @@ pub enum TextAnchor {
 /// });
 /// # }
 /// ```
-#[derive(Component, Reflect, Default)]
-#[reflect(Component)]
-pub struct TextMesh {
-    /// The text to display. Use `\n` for line breaks.
-    pub text: String,
-    /// Handle to the font asset (TTF or OTF file).
-    pub font: Handle<FontMesh>,
-    /// Visual style configuration for the text mesh.
-    pub style: TextMeshStyle,
-}
-
-/// Controls horizontal alignment of multiline text.
 ///
-/// This determines how multiple lines of text are aligned relative to each other.
-/// For single-line text, justification has no visual effect.
+/// # Per-Character Colors
 ///
-/// # Examples
+/// [`char_colors`](Self::char_colors) tints letters through the mesh's vertex colors,
+/// without an entity per glyph:
 ///
-/// ```
+/// ```no_run
+/// # use bevy::prelude::*;
 /// # use bevy_fontmesh::prelude::*;
-/// # use bevy::prelude::default;
-/// // Left-aligned text (default)
-/// let style = TextMeshStyle {
-///     justify: JustifyText::Left,
-///     ..default()
-/// };
-///
-/// // Centered text
-/// let style = TextMeshStyle {
-///     justify: JustifyText::Center,
-///     ..default()
-/// };
-///
-/// // Right-aligned text
-/// let style = TextMeshStyle {
-///     justify: JustifyText::Right,
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// let text = "Rainbow";
+/// let count = text.chars().count();
+/// commands.spawn(TextMeshBundle {
+///     text_mesh: TextMesh {
+///         text: text.to_string(),
+///         font: asset_server.load("fonts/font.ttf"),
+///         char_colors: Some(
+///             (0..count)
+///                 .map(|i| Color::hsl(360.0 * i as f32 / count as f32, 0.9, 0.6))
+///                 .collect(),
+///         ),
+///         ..default()
+///     },
 ///     ..default()
-/// };
+/// });
+/// # }
 /// ```
-#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub enum JustifyText {
-    /// Align text to the left edge.
-    #[default]
-    Left,
-    /// Center text horizontally.
-    Center,
-    /// Align text to the right edge.
-    Right,
-}
-
-/// Visual styling parameters for generated text meshes.
 ///
-/// Controls the 3D extrusion depth, curve smoothness, positioning, and alignment
-/// of the generated mesh geometry.
+/// # Shared Meshes
 ///
-/// # Examples
+/// Texts with the same string, font, style, and colors display the same mesh asset,
+/// so spawning many copies of a label only generates and uploads it once. Editing one
+/// of them gives it a mesh of its own again. To modify a generated mesh yourself, clone
+/// it into a new asset first so the other texts aren't affected, or give the text a
+/// [`TextMeshCachePolicy`](crate::TextMeshCachePolicy) that keeps its mesh unique.
 ///
-/// ```
-/// # use bevy_fontmesh::prelude::*;
-/// # use bevy::prelude::default;
-/// // Flat 2D-style text
-/// let flat = TextMeshStyle {
-///     depth: 0.0,
-///     subdivision: 15,
-///     ..default()
-/// };
-///
-/// // Deep 3D text with high quality curves
-/// let deep = TextMeshStyle {
-///     depth: 1.0,
-///     subdivision: 30,
-///     anchor: TextAnchor::Center,
-///     justify: JustifyText::Center,
-/// };
-///
-/// // Low-poly stylized text
-/// let lowpoly = TextMeshStyle {
-///     depth: 0.2,
-///     subdivision: 5,
-///     ..default()
-/// };
-/// ```
-#[derive(Reflect, Clone, Debug)]
-pub struct TextMeshStyle {
-    /// Extrusion depth of the 3D mesh.
+/// # Scenes
+///
+/// Text components are reflected, so they can be saved in Bevy scenes and edited in
+/// inspectors. Bevy only writes asset handles that are ids, such as the handle of the
+/// embedded default font, to scene files; give text whose font is loaded through the
+/// asset server a [`TextMeshFontPaths`](crate::TextMeshFontPaths) to load it again by
+/// path after the scene is loaded.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
+#[require(Mesh3d, MeshMaterial3d<StandardMaterial>, Transform, Visibility)]
+pub struct TextMesh {
+    /// The text to display. Use `\n` for line breaks.
+    pub text: String,
+    /// Handle to the font asset (TTF or OTF file).
+    pub font: Handle<FontMesh>,
+    /// Visual style configuration for the text mesh.
+    pub style: TextMeshStyle,
+    /// Per-character vertex colors, written to the mesh's `ATTRIBUTE_COLOR`.
     ///
-    /// Controls how far the text is extruded in the Z direction. A value of `0.0`
-    /// produces flat, 2D-style text. Higher values create more pronounced 3D geometry.
-    /// The depth is measured in font units (typically relative to the font's em height).
+    /// The n-th color tints the glyph of the n-th character of `text`, counting line
+    /// breaks; characters past the end of the list are white. Bevy's
+    /// [`StandardMaterial`] multiplies its `base_color` by the vertex color, so keep it
+    /// white to see the colors as given. `None` leaves the mesh without vertex colors.
     ///
-    /// Recommended range: `0.0` to `2.0`.
-    pub depth: f32,
+    /// Editing text with colors re-assembles the whole mesh, since inserting a
+    /// character shifts the colors of all that follow, but still only re-tessellates
+    /// the edited lines. Not applied to [`TextTicker`](crate::TextTicker) text.
+    pub char_colors: Option<Vec<Color>>,
+    /// Vertex-color gradient across the text, multiplied with the
+    /// [`char_colors`](Self::char_colors). Edits re-assemble the whole mesh like those
+    /// do, and it isn't applied to [`TextTicker`](crate::TextTicker) text either.
+    pub gradient: Option<TextGradient>,
+}
 
-    /// Number of segments used to approximate curved glyph outlines.
+impl TextMesh {
+    /// Text showing `text` in the default font and style.
     ///
-    /// Higher values produce smoother curves but increase vertex count and memory usage.
-    /// Lower values create a more angular, low-poly appearance.
+    /// Chain the setters below to fill in the rest, instead of writing out the whole
+    /// struct:
     ///
-    /// Recommended range: `5` (low-poly) to `30` (very smooth).
-    /// Default: `20`.
-    pub subdivision: u8,
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::prelude::*;
+    /// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+    /// commands.spawn_text_mesh(
+    ///     TextMesh::new("Hello")
+    ///         .font(asset_server.load("fonts/font.ttf"))
+    ///         .depth(0.2)
+    ///         .anchor(TextAnchor::Center)
+    ///         .justify(JustifyText::Center),
+    /// );
+    /// # }
+    /// ```
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..default()
+        }
+    }
 
-    /// Position of the text mesh relative to its transform origin.
-    ///
-    /// Determines which point of the text bounds is placed at the entity's transform position.
-    /// See [`TextAnchor`] for available options.
-    pub anchor: TextAnchor,
+    /// Set the font.
+    pub fn font(mut self, font: Handle<FontMesh>) -> Self {
+        self.font = font;
+        self
+    }
 
-    /// Horizontal alignment for multiline text.
-    ///
-    /// Controls how multiple lines of text are aligned relative to each other.
-    /// Has no effect on single-line text. See [`JustifyText`] for options.
-    pub justify: JustifyText,
-}
+    /// Set the whole style.
+    pub fn style(mut self, style: TextMeshStyle) -> Self {
+        self.style = style;
+        self
+    }
 
-impl Default for TextMeshStyle {
-    fn default() -> Self {
-        Self {
-            depth: 0.1,
-            subdivision: 20, // Default low poly-ish but smooth enough
-            anchor: TextAnchor::TopLeft,
-            justify: JustifyText::Left,
-        }
+    /// Set the extrusion depth of the style.
+    pub fn depth(mut self, depth: f32) -> Self {
+        self.style.depth = depth;
+        self
+    }
+
+    /// Set the curve subdivision of the style.
+    pub fn subdivision(mut self, subdivision: u8) -> Self {
+        self.style.subdivision = subdivision;
+        self
+    }
+
+    /// Set the anchor of the style.
+    pub fn anchor(mut self, anchor: TextAnchor) -> Self {
+        self.style.anchor = anchor;
+        self
+    }
+
+    /// Set the line justification of the style.
+    pub fn justify(mut self, justify: JustifyText) -> Self {
+        self.style.justify = justify;
+        self
+    }
+
+    /// Set the per-character colors.
+    pub fn char_colors(mut self, colors: impl Into<Vec<Color>>) -> Self {
+        self.char_colors = Some(colors.into());
+        self
+    }
+
+    /// Set the color gradient.
+    pub fn gradient(mut self, gradient: TextGradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// [`char_colors`](Self::char_colors) as linear RGBA, empty without colors.
+    pub(crate) fn vertex_colors(&self) -> Vec<[f32; 4]> {
+        self.char_colors
+            .iter()
+            .flatten()
+            .map(|color| color.to_linear().to_f32_array())
+            .collect()
     }
 }
 
@@ -229,6 +222,13 @@ impl Default for TextMeshStyle {
 /// - Individual character picking/interaction
 ///
 /// Each child entity will have a [`GlyphMesh`] component with its character index.
+/// Glyphs get the material of the parent entity, unless
+/// [`material_overrides`](Self::material_overrides) has a range containing them or
+/// [`materials`](Self::materials) has a slot for their character. The style's anchor
+/// places the glyphs as a whole, the same way it places a [`TextMesh`]. Glyphs of the
+/// same character share one mesh asset, so repeated characters cost a single mesh and
+/// can be drawn instanced. Like a [`TextMesh`], the component requires a [`Transform`],
+/// a [`Visibility`], and a material slot for the default material.
 ///
 /// # Examples
 ///
@@ -245,6 +245,11 @@ impl Default for TextMeshStyle {
 ///         text: "Hello".to_string(),
 ///         font: asset_server.load("fonts/font.ttf"),
 ///         style: TextMeshStyle::default(),
+///         // Numbers in gold
+///         materials: GlyphMaterials::default()
+///             .with(CharClass::Digit, materials.add(Color::srgb(1.0, 0.8, 0.2))),
+///         // The first letter in red
+///         material_overrides: vec![(0..1, materials.add(Color::srgb(0.9, 0.1, 0.1)))],
 ///     },
 ///     // Default material for all glyphs (can be overridden per-glyph)
 ///     material: MeshMaterial3d(materials.add(StandardMaterial::default())),
@@ -253,7 +258,8 @@ impl Default for TextMeshStyle {
 /// # }
 /// ```
 #[derive(Component, Reflect, Default)]
-#[reflect(Component)]
+#[reflect(Component, Default)]
+#[require(MeshMaterial3d<StandardMaterial>, Transform, Visibility)]
 pub struct TextMeshGlyphs {
     /// The text to display. Use `\n` for line breaks.
     pub text: String,
@@ -261,6 +267,90 @@ pub struct TextMeshGlyphs {
     pub font: Handle<FontMesh>,
     /// Visual style configuration for the glyph meshes.
     pub style: TextMeshStyle,
+    /// Materials of glyphs by character class, applied when glyphs are spawned.
+    pub materials: GlyphMaterials,
+    /// Materials of glyphs by range of [`char_index`](GlyphMesh::char_index), such as a
+    /// highlighted keyword, applied when glyphs are spawned.
+    ///
+    /// The first range containing a glyph wins over [`materials`](Self::materials).
+    /// Glyphs kept through an edit of the text or of the ranges switch to the material
+    /// of their new position, unless their material was replaced after they got it.
+    pub material_overrides: Vec<(Range<usize>, Handle<StandardMaterial>)>,
+}
+
+impl TextMeshGlyphs {
+    /// The material a glyph of `character` at `char_index` is spawned with, or `None`
+    /// for the material of the parent entity.
+    pub fn glyph_material(
+        &self,
+        char_index: usize,
+        character: char,
+    ) -> Option<&Handle<StandardMaterial>> {
+        self.material_overrides
+            .iter()
+            .find(|(range, _)| range.contains(&char_index))
+            .map(|(_, material)| material)
+            .or_else(|| self.materials.get(character))
+    }
+}
+
+/// A set of characters a [`GlyphMaterials`] slot applies to.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub enum CharClass {
+    /// Decimal digits `0`-`9`.
+    Digit,
+    /// Uppercase letters.
+    Uppercase,
+    /// Lowercase letters.
+    Lowercase,
+    /// Letters of any case or script.
+    Alphabetic,
+    /// ASCII punctuation, such as `.`, `,`, `!`, or `(`.
+    Punctuation,
+    /// Any of the given characters.
+    Chars(String),
+}
+
+impl CharClass {
+    /// Whether `character` belongs to the class.
+    pub fn contains(&self, character: char) -> bool {
+        match self {
+            Self::Digit => character.is_ascii_digit(),
+            Self::Uppercase => character.is_uppercase(),
+            Self::Lowercase => character.is_lowercase(),
+            Self::Alphabetic => character.is_alphabetic(),
+            Self::Punctuation => character.is_ascii_punctuation(),
+            Self::Chars(chars) => chars.contains(character),
+        }
+    }
+}
+
+/// Materials of [`TextMeshGlyphs`] glyphs by [`CharClass`].
+///
+/// Each glyph takes the material of the first slot whose class contains its character,
+/// or the material of the parent entity if there is none. Slots are applied when a
+/// glyph is spawned; glyphs kept across edits keep the material they have. A
+/// [`MaterialFactory`](crate::MaterialFactory) still overrides them.
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+pub struct GlyphMaterials {
+    /// Classes and their materials, in the order they are tried.
+    pub slots: Vec<(CharClass, Handle<StandardMaterial>)>,
+}
+
+impl GlyphMaterials {
+    /// Add a slot, tried after the existing ones.
+    pub fn with(mut self, class: CharClass, material: Handle<StandardMaterial>) -> Self {
+        self.slots.push((class, material));
+        self
+    }
+
+    /// The material of the first slot containing `character`.
+    pub fn get(&self, character: char) -> Option<&Handle<StandardMaterial>> {
+        self.slots
+            .iter()
+            .find(|(class, _)| class.contains(character))
+            .map(|(_, material)| material)
+    }
 }
 
 /// Marker component for individual glyph mesh entities.
@@ -273,6 +363,7 @@ pub struct TextMeshGlyphs {
 /// - `char_index`: The index of this character in the original text string
 /// - `line_index`: The line number (0-indexed) this character appears on
 /// - `character`: The actual character this glyph represents
+/// - `origin`: The glyph's laid-out position relative to the parent, at its pivot
 #[derive(Component, Reflect, Clone, Debug)]
 #[reflect(Component)]
 pub struct GlyphMesh {
@@ -282,6 +373,13 @@ pub struct GlyphMesh {
     pub line_index: usize,
     /// The character this glyph represents
     pub character: char,
+    /// Position the glyph was laid out at, relative to the parent: the point its
+    /// [`glyph_pivot`](crate::TextMeshStyle::glyph_pivot) picks, by default its origin
+    /// on the baseline.
+    ///
+    /// The glyph's [`Transform`] starts here; animations such as [`GlyphWave`](crate::GlyphWave)
+    /// move the glyph relative to it.
+    pub origin: Vec3,
 }
 
 /// Convenience bundle for spawning 3D text with per-character entities.
@@ -307,6 +405,7 @@ pub struct GlyphMesh {
 ///             depth: 0.1,
 ///             ..default()
 ///         },
+///         ..default()
 ///     },
 ///     material: MeshMaterial3d(materials.add(StandardMaterial {
 ///         base_color: Color::WHITE,
@@ -335,6 +434,207 @@ pub struct TextMeshGlyphsBundle {
     pub view_visibility: ViewVisibility,
 }
 
+/// Component for generating separate meshes for each surface of extruded text.
+///
+/// `TextMeshParts` splits the extruded text into its front cap, back cap, and the
+/// extrusion side walls, spawning one child entity per surface. Each child gets the
+/// matching material from [`TextMeshPartMaterials`], which makes the classic
+/// "gold face with dark sides" look possible without custom shaders.
+///
+/// Each child entity carries a [`TextMeshPart`] marker identifying its surface.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// let gold = materials.add(StandardMaterial {
+///     base_color: Color::srgb(1.0, 0.8, 0.2),
+///     metallic: 1.0,
+///     ..default()
+/// });
+/// let dark = materials.add(StandardMaterial {
+///     base_color: Color::srgb(0.1, 0.1, 0.1),
+///     ..default()
+/// });
+///
+/// commands.spawn(TextMeshPartsBundle {
+///     text_parts: TextMeshParts {
+///         text: "Gold".to_string(),
+///         font: asset_server.load("fonts/font.ttf"),
+///         style: TextMeshStyle {
+///             depth: 0.4,
+///             ..default()
+///         },
+///     },
+///     materials: TextMeshPartMaterials {
+///         front: gold.clone(),
+///         back: gold,
+///         sides: dark,
+///     },
+///     ..default()
+/// });
+/// # }
+/// ```
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+#[require(TextMeshPartMaterials, Transform, Visibility)]
+pub struct TextMeshParts {
+    /// The text to display. Use `\n` for line breaks.
+    pub text: String,
+    /// Handle to the font asset (TTF or OTF file).
+    pub font: Handle<FontMesh>,
+    /// Visual style configuration for the text mesh.
+    pub style: TextMeshStyle,
+}
+
+/// Identifies which surface of the extruded text a [`TextMeshParts`] child renders.
+///
+/// The front cap faces `+Z` (towards a default camera), the back cap faces `-Z`,
+/// and the sides are the walls produced by the extrusion.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum TextMeshPart {
+    /// The front cap of the text.
+    Front,
+    /// The back cap of the text.
+    Back,
+    /// The extrusion side walls connecting the front and back caps.
+    Sides,
+}
+
+/// Materials applied to each surface of a [`TextMeshParts`] entity.
+///
+/// Changing this component re-applies the materials to the spawned surface entities.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct TextMeshPartMaterials {
+    /// Material for the front cap.
+    pub front: Handle<StandardMaterial>,
+    /// Material for the back cap.
+    pub back: Handle<StandardMaterial>,
+    /// Material for the extrusion side walls.
+    pub sides: Handle<StandardMaterial>,
+}
+
+impl TextMeshPartMaterials {
+    /// Use the same material for every surface.
+    pub fn uniform(material: Handle<StandardMaterial>) -> Self {
+        Self {
+            front: material.clone(),
+            back: material.clone(),
+            sides: material,
+        }
+    }
+
+    /// Get the material for a given surface.
+    pub fn get(&self, part: TextMeshPart) -> &Handle<StandardMaterial> {
+        match part {
+            TextMeshPart::Front => &self.front,
+            TextMeshPart::Back => &self.back,
+            TextMeshPart::Sides => &self.sides,
+        }
+    }
+}
+
+/// Convenience bundle for spawning 3D text with a separate material per surface.
+///
+/// See [`TextMeshParts`] for an example.
+#[derive(Bundle, Default)]
+pub struct TextMeshPartsBundle {
+    /// The text parts component that drives per-surface mesh generation.
+    pub text_parts: TextMeshParts,
+    /// Materials for the front cap, back cap, and extrusion sides.
+    pub materials: TextMeshPartMaterials,
+    /// Local transform of the parent entity.
+    pub transform: Transform,
+    /// Global transform (computed automatically).
+    pub global_transform: GlobalTransform,
+    /// Visibility of the entity.
+    pub visibility: Visibility,
+    /// Inherited visibility (computed automatically).
+    pub inherited_visibility: InheritedVisibility,
+    /// View visibility (computed automatically).
+    pub view_visibility: ViewVisibility,
+}
+
+/// Component for generating flat text meshes for Bevy's 2D pipeline.
+///
+/// `TextMesh2d` lays out text exactly like [`TextMesh`], but tessellates the glyphs with
+/// zero depth into a [`Mesh2d`], so it can be drawn with a [`ColorMaterial`] next to
+/// sprites. The extrusion settings of the style (`depth` and `bevel`) are ignored.
+///
+/// Glyphs are generated at a size of one unit per em, so scale the [`Transform`] to the
+/// desired font size in pixels. The component requires a [`Transform`], a
+/// [`Visibility`], and a [`ColorMaterial`] slot, which draws the text white until it is
+/// given a material of its own.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<ColorMaterial>>,
+/// # ) {
+/// commands.spawn(TextMesh2dBundle {
+///     text_mesh: TextMesh2d {
+///         text: "Score: 42".to_string(),
+///         font: asset_server.load("fonts/font.ttf"),
+///         style: TextMeshStyle {
+///             anchor: TextAnchor::Center,
+///             ..default()
+///         },
+///     },
+///     material: MeshMaterial2d(materials.add(Color::WHITE)),
+///     // 64 pixels per em
+///     transform: Transform::from_scale(Vec3::splat(64.0)),
+///     ..default()
+/// });
+/// # }
+/// ```
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+#[require(Mesh2d, MeshMaterial2d<ColorMaterial>, Transform, Visibility)]
+pub struct TextMesh2d {
+    /// The text to display. Use `\n` for line breaks.
+    pub text: String,
+    /// Handle to the font asset (TTF or OTF file).
+    pub font: Handle<FontMesh>,
+    /// Layout and tessellation settings. `depth` and `bevel` have no effect in 2D.
+    pub style: TextMeshStyle,
+}
+
+/// Convenience bundle for spawning flat 2D text entities.
+///
+/// See [`TextMesh2d`] for an example.
+#[derive(Bundle, Default)]
+pub struct TextMesh2dBundle {
+    /// The text mesh component that drives mesh generation.
+    pub text_mesh: TextMesh2d,
+    /// The 2D mesh handle (automatically populated by the plugin system).
+    pub mesh: Mesh2d,
+    /// Material applied to the text mesh.
+    pub material: MeshMaterial2d<ColorMaterial>,
+    /// Local transform of the entity.
+    pub transform: Transform,
+    /// Global transform (computed automatically).
+    pub global_transform: GlobalTransform,
+    /// Visibility of the entity.
+    pub visibility: Visibility,
+    /// Inherited visibility (computed automatically).
+    pub inherited_visibility: InheritedVisibility,
+    /// View visibility (computed automatically).
+    pub view_visibility: ViewVisibility,
+}
+
 /// Convenience bundle for spawning 3D text entities.
 ///
 /// This bundle includes all necessary components for rendering 3D text in Bevy:
@@ -360,6 +660,7 @@ pub struct TextMeshGlyphsBundle {
 ///             anchor: TextAnchor::Center,
 ///             ..default()
 ///         },
+///         ..default()
 ///     },
 ///     material: MeshMaterial3d(materials.add(StandardMaterial {
 ///         base_color: Color::srgb(1.0, 0.5, 0.2),
@@ -389,3 +690,52 @@ pub struct TextMeshBundle {
     /// View visibility (computed automatically).
     pub view_visibility: ViewVisibility,
 }
+
+/// Text components drawn in a single font and style.
+pub(crate) trait FontAndStyle: Component<Mutability = Mutable> {
+    /// The font and style of the text.
+    fn font_and_style(&self) -> (&Handle<FontMesh>, &TextMeshStyle);
+
+    /// The font and style of the text, to change them.
+    fn font_and_style_mut(&mut self) -> (&mut Handle<FontMesh>, &mut TextMeshStyle);
+}
+
+impl FontAndStyle for TextMesh {
+    fn font_and_style(&self) -> (&Handle<FontMesh>, &TextMeshStyle) {
+        (&self.font, &self.style)
+    }
+
+    fn font_and_style_mut(&mut self) -> (&mut Handle<FontMesh>, &mut TextMeshStyle) {
+        (&mut self.font, &mut self.style)
+    }
+}
+
+impl FontAndStyle for TextMeshGlyphs {
+    fn font_and_style(&self) -> (&Handle<FontMesh>, &TextMeshStyle) {
+        (&self.font, &self.style)
+    }
+
+    fn font_and_style_mut(&mut self) -> (&mut Handle<FontMesh>, &mut TextMeshStyle) {
+        (&mut self.font, &mut self.style)
+    }
+}
+
+impl FontAndStyle for TextMeshParts {
+    fn font_and_style(&self) -> (&Handle<FontMesh>, &TextMeshStyle) {
+        (&self.font, &self.style)
+    }
+
+    fn font_and_style_mut(&mut self) -> (&mut Handle<FontMesh>, &mut TextMeshStyle) {
+        (&mut self.font, &mut self.style)
+    }
+}
+
+impl FontAndStyle for TextMesh2d {
+    fn font_and_style(&self) -> (&Handle<FontMesh>, &TextMeshStyle) {
+        (&self.font, &self.style)
+    }
+
+    fn font_and_style_mut(&mut self) -> (&mut Handle<FontMesh>, &mut TextMeshStyle) {
+        (&mut self.font, &mut self.style)
+    }
+}
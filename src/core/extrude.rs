@@ -0,0 +1,962 @@
+use crate::core::fallback::GlyphSource;
+use crate::core::outline::{edge_inward_normal, fill_boundary, vertex_inward_offset, GlyphOutline};
+#[cfg(feature = "bevy")]
+use crate::core::style::GlyphPivot;
+use crate::core::style::{
+    BevelProfile, BevelStyle, ExtrusionProfile, Handcrafted, SideShading, TextMeshStyle,
+    TextRenderMode,
+};
+use bevy_math::{Quat, Vec2, Vec3};
+use lyon_tessellation::math::point;
+use lyon_tessellation::path::Path;
+use lyon_tessellation::{
+    BuffersBuilder, FillOptions, FillRule, FillTessellator, FillVertex, VertexBuffers,
+};
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
+
+/// Triangle data for a single glyph, positioned relative to the glyph origin.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GlyphGeometry {
+    pub vertices: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    /// Texture coordinates, only present for geometry built from an outline.
+    ///
+    /// Caps are mapped to the glyph plane. Side walls use the arc length along their
+    /// contour for `u` and the distance behind the front face for `v`, both in mesh units.
+    pub uvs: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+impl GlyphGeometry {
+    /// Generate the extruded geometry for a glyph using the given style.
+    ///
+    /// Plain extrusions are delegated to fontmesh. Bevelled extrusions, extrusions with
+    /// side wall UVs, smooth side walls, a curve tolerance, or a curved
+    /// [`ExtrusionProfile`], faux bold and italic, stylistic alternates, and the
+    /// `.notdef` box are built from the glyph outline directly, since fontmesh neither
+    /// bevels, tracks, nor reshapes contours, only splits curves evenly, and can only
+    /// look glyphs up by character. Outlines
+    /// are stroked first in [`TextRenderMode::Outline`]. The glyph is sized by the
+    /// style's font size, while the extrusion keeps its depth. With
+    /// [`TextMeshStyle::optimize`], identical vertices are welded.
+    pub fn new(face: &fontmesh::Face, glyph: GlyphSource, style: &TextMeshStyle) -> Option<Self> {
+        match style.render_mode {
+            TextRenderMode::Fill => {}
+            TextRenderMode::Outline { width } => {
+                let outline = GlyphOutline::for_style(face, glyph, style)?;
+                let geometry = Self::from_styled_outline(&outline.stroke(width), style);
+                return Some(geometry.optimized(style));
+            }
+            TextRenderMode::Engraved { depth, .. } => {
+                let outline = GlyphOutline::for_style(face, glyph, style)?;
+                let outline = GlyphOutline {
+                    contours: fill_boundary(&outline),
+                };
+                let geometry = Self::engraved(
+                    &outline,
+                    depth.min(style.depth),
+                    style.side_shading.unwrap_or_default(),
+                );
+                return Some(geometry.optimized(style));
+            }
+        }
+        let geometry = match glyph {
+            GlyphSource::Char(character) | GlyphSource::SmallCap(character) if is_plain(style) => {
+                let mut geometry = Self::extruded(face, character, style.depth, style.subdivision)?;
+                geometry.scale_glyph_plane(style.font_size * glyph.scale(style));
+                geometry.apply_profile(&style.extrusion_profile);
+                geometry
+            }
+            GlyphSource::Glyph(glyph_id) => return Self::by_id(face, glyph_id, style),
+            _ => {
+                let outline = GlyphOutline::for_style(face, glyph, style)?;
+                Self::from_styled_outline(&outline, style)
+            }
+        };
+        Some(geometry.optimized(style))
+    }
+
+    /// Generate the extruded geometry for a glyph by its id, built from its outline.
+    ///
+    /// Returns `None` if the font has no glyph with this id or the glyph has no outline.
+    pub fn by_id(
+        face: &fontmesh::Face,
+        glyph_id: ttf_parser::GlyphId,
+        style: &TextMeshStyle,
+    ) -> Option<Self> {
+        let outline = GlyphOutline::for_style(face, GlyphSource::Glyph(glyph_id), style)?;
+        Some(Self::from_styled_outline(&outline, style).optimized(style))
+    }
+
+    /// Extrusion of the decoration bars of a line, stroked in
+    /// [`TextRenderMode::Outline`] and level with the glyphs of `style`.
+    ///
+    /// Engraved decorations are cut with the glyphs instead.
+    pub fn decoration(outline: &GlyphOutline, style: &TextMeshStyle) -> Self {
+        let mut geometry = match style.render_mode {
+            TextRenderMode::Outline { width } => {
+                Self::from_styled_outline(&outline.stroke(width), style)
+            }
+            _ => Self::from_styled_outline(outline, style),
+        };
+        // Plain glyphs come from fontmesh, which centers them on `z = 0`
+        if is_plain(style) && matches!(style.render_mode, TextRenderMode::Fill) {
+            for vertex in &mut geometry.vertices {
+                vertex.z += style.depth * 0.5;
+            }
+        }
+        geometry.optimized(style)
+    }
+
+    /// Flat, zero-depth decoration bars of a line in the `z = 0` plane facing `+Z`.
+    #[cfg(feature = "bevy")]
+    pub fn flat_decoration(outline: &GlyphOutline, style: &TextMeshStyle) -> Self {
+        let geometry = match style.render_mode {
+            TextRenderMode::Outline { width } => {
+                Self::from_outline(&outline.stroke(width), 0.0, None, &[], SideShading::Flat)
+            }
+            _ => Self::from_outline(outline, 0.0, None, &[], SideShading::Flat),
+        };
+        geometry.optimized(style)
+    }
+
+    /// Cavity of an outline cut `depth` deep behind `z = 0`: a floor facing `+Z` and
+    /// walls facing into the cavity, without a front cap.
+    ///
+    /// The outline's filled area has to lie to the left of its contours, as
+    /// [`fill_boundary`] returns them. The walls are shaded following `shading`.
+    pub fn engraved(outline: &GlyphOutline, depth: f32, shading: SideShading) -> Self {
+        let mut geometry = Self::default();
+        geometry.push_cap(outline, -depth, Vec3::Z);
+        if depth <= 0.0 {
+            return geometry;
+        }
+
+        for contour in &outline.contours {
+            let normals = wall_normals(contour, false, shading);
+            let mut arc_length = 0.0;
+            for j in 0..contour.len() {
+                let k = (j + 1) % contour.len();
+                let [inward, at_j, at_k] = normals[j].map(|normal| normal.extend(0.0));
+                let (u0, u1) = (arc_length, arc_length + contour[j].distance(contour[k]));
+                arc_length = u1;
+
+                let a0 = geometry.push_vertex(contour[j].extend(0.0), at_j, Vec2::new(u0, 0.0));
+                let a1 = geometry.push_vertex(contour[k].extend(0.0), at_k, Vec2::new(u1, 0.0));
+                let b0 =
+                    geometry.push_vertex(contour[j].extend(-depth), at_j, Vec2::new(u0, depth));
+                let b1 =
+                    geometry.push_vertex(contour[k].extend(-depth), at_k, Vec2::new(u1, depth));
+                geometry.push_triangle([a0, b0, a1], inward);
+                geometry.push_triangle([a1, b0, b1], inward);
+            }
+        }
+        geometry
+    }
+
+    /// Slab from `min` to `max` with its front at `max.z`, and `holes` cut through the
+    /// front.
+    ///
+    /// Holes inside holes, like the counters of engraved glyphs, are part of the front
+    /// again. The holes must not overlap.
+    pub fn plaque(min: Vec3, max: Vec3, holes: Vec<Vec<Vec2>>) -> Self {
+        let corners = vec![
+            Vec2::new(min.x, min.y),
+            Vec2::new(max.x, min.y),
+            Vec2::new(max.x, max.y),
+            Vec2::new(min.x, max.y),
+        ];
+        let mut geometry = Self::default();
+        let front = GlyphOutline {
+            contours: std::iter::once(corners.clone()).chain(holes).collect(),
+        };
+        geometry.push_fill(&front, max.z, Vec3::Z, FillRule::EvenOdd);
+        if min.z >= max.z {
+            return geometry;
+        }
+        let back = GlyphOutline {
+            contours: vec![corners.clone()],
+        };
+        geometry.push_cap(&back, min.z, Vec3::NEG_Z);
+
+        // Rim around the slab
+        for j in 0..corners.len() {
+            let k = (j + 1) % corners.len();
+            let outward = -edge_inward_normal(corners[j], corners[k], false).extend(0.0);
+            let a0 = geometry.push_vertex(corners[j].extend(max.z), outward, corners[j]);
+            let a1 = geometry.push_vertex(corners[k].extend(max.z), outward, corners[k]);
+            let b0 = geometry.push_vertex(corners[j].extend(min.z), outward, corners[j]);
+            let b1 = geometry.push_vertex(corners[k].extend(min.z), outward, corners[k]);
+            geometry.push_triangle([a0, b0, a1], outward);
+            geometry.push_triangle([a1, b0, b1], outward);
+        }
+        geometry
+    }
+
+    /// Extrusion of an outline with the bevel and extrusion profile of `style`.
+    fn from_styled_outline(outline: &GlyphOutline, style: &TextMeshStyle) -> Self {
+        let kinks: Vec<f32> = style
+            .extrusion_profile
+            .kinks()
+            .iter()
+            .map(|t| t * style.depth)
+            .collect();
+        let mut geometry = Self::from_outline(
+            outline,
+            style.depth,
+            style_bevel(style),
+            &kinks,
+            style.side_shading.unwrap_or_default(),
+        );
+        geometry.apply_profile(&style.extrusion_profile);
+        geometry
+    }
+
+    /// Scale the glyph around its center along the extrusion, following `profile`.
+    ///
+    /// The extrusion is taken from the vertices, since fontmesh centers its extrusions
+    /// on `z = 0` where outlines are extruded behind it.
+    fn apply_profile(&mut self, profile: &ExtrusionProfile) {
+        if profile.is_straight() {
+            return;
+        }
+        let Some(center) = self.center() else {
+            return;
+        };
+        let (back, front) = self
+            .vertices
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(back, front), v| {
+                (back.min(v.z), front.max(v.z))
+            });
+        let depth = front - back;
+        if depth <= 0.0 {
+            return;
+        }
+
+        for (vertex, normal) in self.vertices.iter_mut().zip(&mut self.normals) {
+            let (scale, slope) = profile.scale_and_slope((front - vertex.z) / depth);
+            let offset = vertex.truncate() - center;
+
+            // Normals follow the inverse transpose of the deformation's Jacobian, scaled
+            // by the scale so that a scale of zero stays defined
+            let dscale_dz = -slope / depth;
+            let tilted = Vec3::new(
+                normal.x,
+                normal.y,
+                normal.z * scale - dscale_dz * offset.dot(normal.truncate()),
+            );
+            *normal = tilted.normalize_or(*normal);
+            *vertex = (center + offset * scale).extend(vertex.z);
+        }
+    }
+
+    /// Center of the glyph's bounds in the glyph plane.
+    fn center(&self) -> Option<Vec2> {
+        let (min, max) = self.bounds()?;
+        Some((min + max) * 0.5)
+    }
+
+    /// Corners of the glyph's bounds in the glyph plane.
+    fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.vertices.iter().fold(None, |bounds, v| {
+            let v = v.truncate();
+            Some(match bounds {
+                Some((min, max)) => (Vec2::min(min, v), Vec2::max(max, v)),
+                None => (v, v),
+            })
+        })
+    }
+
+    /// The point of the glyph that `pivot` picks, relative to the glyph origin.
+    #[cfg(feature = "bevy")]
+    pub fn pivot(&self, pivot: GlyphPivot) -> Vec2 {
+        let fraction = match pivot {
+            GlyphPivot::Baseline => return Vec2::ZERO,
+            GlyphPivot::Center => Vec2::splat(0.5),
+            GlyphPivot::Custom(fraction) => fraction,
+        };
+        self.bounds()
+            .map_or(Vec2::ZERO, |(min, max)| min + (max - min) * fraction)
+    }
+
+    /// Apply the style's [`Handcrafted`] variation, if any, to the glyph for `character`
+    /// at `index` in its line.
+    pub fn handcraft(&mut self, style: &TextMeshStyle, character: char, index: usize) {
+        let Some(handcrafted) = &style.handcrafted else {
+            return;
+        };
+        // Engraved glyphs have to stay where their holes in the plaque are
+        if matches!(style.render_mode, TextRenderMode::Engraved { .. }) {
+            return;
+        }
+        let Some(center) = self.center() else {
+            return;
+        };
+
+        // 1. Turn and scale around the glyph center, stretch the extrusion from the front
+        let [rotation, scale, depth] = handcraft_variation(handcrafted, character, index);
+        let rotation = Quat::from_rotation_z(rotation * handcrafted.rotation);
+        let scale = 1.0 + scale * handcrafted.scale;
+        let depth = (1.0 + depth * handcrafted.depth).max(0.0);
+        let center = center.extend(0.0);
+        for vertex in &mut self.vertices {
+            let offset = *vertex - center;
+            *vertex =
+                center + rotation * Vec3::new(offset.x * scale, offset.y * scale, offset.z * depth);
+        }
+
+        // 2. Normals follow the inverse transpose of the scale
+        if depth > 0.0 {
+            for normal in &mut self.normals {
+                let scaled = Vec3::new(normal.x / scale, normal.y / scale, normal.z / depth);
+                *normal = rotation * scaled.normalize_or(*normal);
+            }
+        }
+    }
+
+    /// Plain extrusion as produced by fontmesh.
+    pub fn extruded(
+        face: &fontmesh::Face,
+        character: char,
+        depth: f32,
+        subdivision: u8,
+    ) -> Option<Self> {
+        let mesh = fontmesh::char_to_mesh_3d(face, character, depth, subdivision).ok()?;
+
+        Some(Self {
+            vertices: mesh
+                .vertices
+                .iter()
+                .map(|v| Vec3::new(v.x, v.y, v.z))
+                .collect(),
+            normals: mesh
+                .normals
+                .iter()
+                .map(|n| Vec3::new(n.x, n.y, n.z))
+                .collect(),
+            uvs: Vec::new(),
+            indices: mesh.indices,
+        })
+    }
+
+    /// Flat, zero-depth glyph in the `z = 0` plane facing `+Z`, sized by the style's
+    /// font size.
+    #[cfg(feature = "bevy")]
+    pub fn flat(face: &fontmesh::Face, glyph: GlyphSource, style: &TextMeshStyle) -> Option<Self> {
+        if let TextRenderMode::Outline { width } = style.render_mode {
+            let outline = GlyphOutline::for_style(face, glyph, style)?;
+            let geometry =
+                Self::from_outline(&outline.stroke(width), 0.0, None, &[], SideShading::Flat);
+            return Some(geometry.optimized(style));
+        }
+        let (GlyphSource::Char(character), None, false) =
+            (glyph, style.curve_tolerance, style.synthesizes())
+        else {
+            let outline = GlyphOutline::for_style(face, glyph, style)?;
+            return Some(
+                Self::from_outline(&outline, 0.0, None, &[], SideShading::Flat).optimized(style),
+            );
+        };
+        let mesh = fontmesh::char_to_mesh_2d(face, character, style.subdivision).ok()?;
+
+        let mut geometry = Self {
+            vertices: mesh
+                .vertices
+                .iter()
+                .map(|v| Vec3::new(v.x, v.y, 0.0))
+                .collect(),
+            normals: vec![Vec3::Z; mesh.vertices.len()],
+            uvs: Vec::new(),
+            indices: mesh.indices,
+        };
+        geometry.scale_glyph_plane(style.font_size);
+        Some(geometry.optimized(style))
+    }
+
+    /// Scale the glyph around its origin in the glyph plane, leaving its depth alone.
+    fn scale_glyph_plane(&mut self, factor: f32) {
+        if factor == 1.0 {
+            return;
+        }
+        for vertex in &mut self.vertices {
+            *vertex *= Vec3::new(factor, factor, 1.0);
+        }
+        // Normals follow the inverse transpose of the scale
+        for normal in &mut self.normals {
+            *normal = Vec3::new(normal.x, normal.y, normal.z * factor).normalize_or(*normal);
+        }
+    }
+
+    /// Extrusion of an outline, with optional bevelled front and back edges.
+    ///
+    /// The front cap sits at `z = 0` facing `+Z` and the back cap at `z = -depth` facing
+    /// `-Z`. With a bevel, both caps are inset by the bevel width, and the bevel rings
+    /// blend them into the straight side walls. The side walls get an extra ring of
+    /// vertices at every distance in `rings` behind the front face, and are shaded
+    /// following `shading`. A depth of `0.0` produces only the front cap.
+    pub fn from_outline(
+        outline: &GlyphOutline,
+        depth: f32,
+        bevel: Option<&BevelStyle>,
+        rings: &[f32],
+        shading: SideShading,
+    ) -> Self {
+        let mut geometry = Self::default();
+
+        // Caps use the fully inset outline
+        let cap = outline.inset(bevel.map_or(0.0, |bevel| bevel.width));
+        geometry.push_cap(&cap, 0.0, Vec3::Z);
+        if depth <= 0.0 {
+            return geometry;
+        }
+        geometry.push_cap(&cap, -depth, Vec3::NEG_Z);
+
+        // Bevel rings and side walls
+        let bands = split_walls(
+            match bevel {
+                Some(bevel) => profile_bands(depth, bevel),
+                None => vec![[ProfileRing::wall(0.0), ProfileRing::wall(-depth)]],
+            },
+            rings,
+        );
+        let fill_on_right = outline.fill_on_right();
+
+        for contour in &outline.contours {
+            let offsets: Vec<Vec2> = (0..contour.len())
+                .map(|i| vertex_inward_offset(contour, i, fill_on_right))
+                .collect();
+            let normals = wall_normals(contour, fill_on_right, shading);
+
+            // Arc length along the contour, for the wall UVs
+            let mut arc_length = 0.0;
+
+            for j in 0..contour.len() {
+                let k = (j + 1) % contour.len();
+                let [outward, at_j, at_k] = normals[j].map(|normal| -normal);
+                let (u0, u1) = (arc_length, arc_length + contour[j].distance(contour[k]));
+                arc_length = u1;
+
+                for [ring_a, ring_b] in &bands {
+                    let position = |ring: &ProfileRing, i: usize| {
+                        (contour[i] + offsets[i] * ring.inset).extend(ring.z)
+                    };
+                    let normal = |ring: &ProfileRing, outward: Vec2| {
+                        (outward * ring.normal.x).extend(ring.normal.y).normalize()
+                    };
+
+                    let (va, vb) = (-ring_a.z, -ring_b.z);
+                    let a0 = geometry.push_vertex(
+                        position(ring_a, j),
+                        normal(ring_a, at_j),
+                        Vec2::new(u0, va),
+                    );
+                    let a1 = geometry.push_vertex(
+                        position(ring_a, k),
+                        normal(ring_a, at_k),
+                        Vec2::new(u1, va),
+                    );
+                    let b0 = geometry.push_vertex(
+                        position(ring_b, j),
+                        normal(ring_b, at_j),
+                        Vec2::new(u0, vb),
+                    );
+                    let b1 = geometry.push_vertex(
+                        position(ring_b, k),
+                        normal(ring_b, at_k),
+                        Vec2::new(u1, vb),
+                    );
+
+                    let facing = normal(ring_a, outward) + normal(ring_b, outward);
+                    geometry.push_triangle([a0, b0, a1], facing);
+                    geometry.push_triangle([a1, b0, b1], facing);
+                }
+            }
+        }
+
+        geometry
+    }
+
+    /// The geometry with its vertices welded if the style asks for it.
+    pub(crate) fn optimized(mut self, style: &TextMeshStyle) -> Self {
+        if style.optimize {
+            self.weld();
+        }
+        self
+    }
+
+    /// Merge vertices with the same position, normal, and texture coordinates, and point
+    /// the indices at the ones kept.
+    ///
+    /// Only identical vertices are merged, so hard edges keep their split vertices and
+    /// the geometry looks the same.
+    pub(crate) fn weld(&mut self) {
+        // `-0.0 + 0.0` is `0.0`, so both zeros get the same key
+        let bits = |value: f32| (value + 0.0).to_bits();
+        let has_uvs = self.uvs.len() == self.vertices.len();
+        let mut welded = Self::default();
+        let mut kept = HashMap::with_capacity(self.vertices.len());
+        let remap: Vec<u32> = (0..self.vertices.len())
+            .map(|i| {
+                let (position, normal) = (self.vertices[i], self.normals[i]);
+                let uv = if has_uvs { self.uvs[i] } else { Vec2::ZERO };
+                let key = [
+                    position.x, position.y, position.z, normal.x, normal.y, normal.z, uv.x, uv.y,
+                ]
+                .map(bits);
+                *kept.entry(key).or_insert_with(|| {
+                    welded.vertices.push(position);
+                    welded.normals.push(normal);
+                    if has_uvs {
+                        welded.uvs.push(uv);
+                    }
+                    (welded.vertices.len() - 1) as u32
+                })
+            })
+            .collect();
+        welded.indices = self.indices.iter().map(|&i| remap[i as usize]).collect();
+        *self = welded;
+    }
+
+    fn push_vertex(&mut self, position: Vec3, normal: Vec3, uv: Vec2) -> u32 {
+        self.vertices.push(position);
+        self.normals.push(normal);
+        self.uvs.push(uv);
+        (self.vertices.len() - 1) as u32
+    }
+
+    /// Push a triangle, flipping its winding if needed so it faces along `facing`.
+    fn push_triangle(&mut self, [a, b, c]: [u32; 3], facing: Vec3) {
+        let pa = self.vertices[a as usize];
+        let pb = self.vertices[b as usize];
+        let pc = self.vertices[c as usize];
+
+        if (pb - pa).cross(pc - pa).dot(facing) < 0.0 {
+            self.indices.extend([a, c, b]);
+        } else {
+            self.indices.extend([a, b, c]);
+        }
+    }
+
+    /// Triangulate a flat cap from an outline and push it at depth `z`.
+    fn push_cap(&mut self, outline: &GlyphOutline, z: f32, normal: Vec3) {
+        self.push_fill(outline, z, normal, FillRule::NonZero);
+    }
+
+    /// Triangulate an outline with `fill_rule` and push it at depth `z`.
+    fn push_fill(&mut self, outline: &GlyphOutline, z: f32, normal: Vec3, fill_rule: FillRule) {
+        let Some(buffers) = tessellate_outline_with(outline, fill_rule) else {
+            return;
+        };
+
+        let base = self.vertices.len() as u32;
+        for vertex in &buffers.vertices {
+            self.push_vertex(vertex.extend(z), normal, *vertex);
+        }
+        for triangle in buffers.indices.chunks_exact(3) {
+            self.push_triangle(
+                [base + triangle[0], base + triangle[1], base + triangle[2]],
+                normal,
+            );
+        }
+    }
+}
+
+/// Fill-tessellate an outline using the non-zero winding rule.
+pub(crate) fn tessellate_outline(outline: &GlyphOutline) -> Option<VertexBuffers<Vec2, u32>> {
+    tessellate_outline_with(outline, FillRule::NonZero)
+}
+
+/// Fill-tessellate an outline using `fill_rule`.
+fn tessellate_outline_with(
+    outline: &GlyphOutline,
+    fill_rule: FillRule,
+) -> Option<VertexBuffers<Vec2, u32>> {
+    let mut builder = Path::builder();
+    for contour in &outline.contours {
+        builder.begin(point(contour[0].x, contour[0].y));
+        for p in &contour[1..] {
+            builder.line_to(point(p.x, p.y));
+        }
+        builder.end(true);
+    }
+    let path = builder.build();
+
+    let mut buffers: VertexBuffers<Vec2, u32> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_path(
+            &path,
+            &FillOptions::default().with_fill_rule(fill_rule),
+            &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+                let p = vertex.position();
+                Vec2::new(p.x, p.y)
+            }),
+        )
+        .ok()?;
+
+    Some(buffers)
+}
+
+/// One cross-section ring of the extrusion profile.
+///
+/// `inset` moves the ring into the glyph, `z` is its depth, and `normal` is the
+/// profile normal expressed as (outward, z) components.
+#[derive(Clone, Copy, Debug)]
+struct ProfileRing {
+    inset: f32,
+    z: f32,
+    normal: Vec2,
+}
+
+impl ProfileRing {
+    /// Ring on the straight side wall at depth `z`.
+    fn wall(z: f32) -> Self {
+        Self {
+            inset: 0.0,
+            z,
+            normal: Vec2::X,
+        }
+    }
+
+    /// Mirror a front-bevel ring onto the back of an extrusion of the given depth.
+    fn mirrored(self, depth: f32) -> Self {
+        Self {
+            inset: self.inset,
+            z: -depth - self.z,
+            normal: Vec2::new(self.normal.x, -self.normal.y),
+        }
+    }
+}
+
+/// Build the ring pairs from the front cap edge, down the side wall, to the back cap edge.
+fn profile_bands(depth: f32, bevel: &BevelStyle) -> Vec<[ProfileRing; 2]> {
+    let width = bevel.width;
+    let bevel_depth = width.min(depth * 0.5);
+    let segments = bevel.segments.max(1) as usize;
+
+    let ring = |t: f32| match bevel.profile {
+        BevelProfile::Round => {
+            let (sin, cos) = (t * FRAC_PI_2).sin_cos();
+            ProfileRing {
+                inset: width * (1.0 - sin),
+                z: -bevel_depth * (1.0 - cos),
+                normal: Vec2::new(bevel_depth * sin, width * cos).normalize(),
+            }
+        }
+        BevelProfile::Chamfer => ProfileRing {
+            inset: width * (1.0 - t),
+            z: -bevel_depth * t,
+            normal: Vec2::new(bevel_depth, width).normalize(),
+        },
+    };
+
+    let front: Vec<[ProfileRing; 2]> = (0..segments)
+        .map(|i| {
+            [
+                ring(i as f32 / segments as f32),
+                ring((i + 1) as f32 / segments as f32),
+            ]
+        })
+        .collect();
+
+    let mut bands = front.clone();
+
+    let wall_length = depth - 2.0 * bevel_depth;
+    if wall_length > f32::EPSILON {
+        bands.push([
+            ProfileRing::wall(-bevel_depth),
+            ProfileRing::wall(-bevel_depth - wall_length),
+        ]);
+    }
+
+    bands.extend(
+        front
+            .iter()
+            .rev()
+            .map(|[a, b]| [b.mirrored(depth), a.mirrored(depth)]),
+    );
+
+    bands
+}
+
+/// Split the straight side wall bands at the distances in `rings` behind the front face.
+fn split_walls(bands: Vec<[ProfileRing; 2]>, rings: &[f32]) -> Vec<[ProfileRing; 2]> {
+    if rings.is_empty() {
+        return bands;
+    }
+    let mut split = Vec::with_capacity(bands.len() + rings.len());
+    for [a, b] in bands {
+        if a.normal != Vec2::X || b.normal != Vec2::X {
+            split.push([a, b]);
+            continue;
+        }
+        let mut top = a;
+        for &ring in rings {
+            let z = -ring;
+            if z < top.z && z > b.z {
+                let ring = ProfileRing::wall(z);
+                split.push([top, ring]);
+                top = ring;
+            }
+        }
+        split.push([top, b]);
+    }
+    split
+}
+
+/// The style's bevel, if it has any effect.
+/// Normals of the wall along each edge of `contour` in the glyph plane, pointing into
+/// the filled area: the edge's own normal, then the normals at its start and end.
+///
+/// With [`SideShading::Smooth`], the corner between two edges that meet at less than the
+/// threshold gets the average of their normals on both sides, so the walls shade as one
+/// rounded surface.
+fn wall_normals(contour: &[Vec2], fill_on_right: bool, shading: SideShading) -> Vec<[Vec2; 3]> {
+    let n = contour.len();
+    let edges: Vec<Vec2> = (0..n)
+        .map(|j| edge_inward_normal(contour[j], contour[(j + 1) % n], fill_on_right))
+        .collect();
+    let corner = |edge: Vec2, neighbour: Vec2| match shading {
+        SideShading::Smooth { angle_threshold } if edge.dot(neighbour) >= angle_threshold.cos() => {
+            (edge + neighbour).normalize_or(edge)
+        }
+        _ => edge,
+    };
+    (0..n)
+        .map(|j| {
+            let edge = edges[j];
+            [
+                edge,
+                corner(edge, edges[(j + n - 1) % n]),
+                corner(edge, edges[(j + 1) % n]),
+            ]
+        })
+        .collect()
+}
+
+/// Whether filled glyphs in `style` are plain extrusions that fontmesh builds.
+fn is_plain(style: &TextMeshStyle) -> bool {
+    style_bevel(style).is_none()
+        && !style.has_uvs()
+        && style.extrusion_profile.kinks().is_empty()
+        && style.side_shading.is_none()
+        && style.curve_tolerance.is_none()
+        && !style.synthesizes()
+}
+
+fn style_bevel(style: &TextMeshStyle) -> Option<&BevelStyle> {
+    style
+        .bevel
+        .as_ref()
+        .filter(|bevel| style.depth > 0.0 && bevel.width > 0.0)
+}
+
+/// Rotation, scale, and depth variation of a glyph, each between `-1.0` and `1.0`.
+fn handcraft_variation(handcrafted: &Handcrafted, character: char, index: usize) -> [f32; 3] {
+    // SplitMix64, seeded by everything that identifies the glyph
+    let mut state = (handcrafted.seed as u64) << 32
+        ^ (character as u64) << 8
+        ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    };
+    [next(), next(), next()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::mesh::{build_text_mesh, TextMeshData};
+    use crate::core::style::{ExtrusionProfile, SideShading, TextMeshStyle, TextRenderMode};
+    use crate::core::test_font;
+    use bevy_math::Vec3;
+
+    const DEPTH: f32 = 0.5;
+
+    fn build_profile(text: &str, extrusion_profile: ExtrusionProfile) -> TextMeshData {
+        let style = TextMeshStyle {
+            depth: DEPTH,
+            extrusion_profile,
+            ..Default::default()
+        };
+        build_text_mesh(&test_font(), &[], text, &style).unwrap()
+    }
+
+    /// Depths of the front and back faces.
+    fn faces(data: &TextMeshData) -> (f32, f32) {
+        data.vertices
+            .iter()
+            .fold((f32::MIN, f32::MAX), |(front, back), v| {
+                (front.max(v[2]), back.min(v[2]))
+            })
+    }
+
+    /// Width of the vertices at depth `z`.
+    fn width_at(data: &TextMeshData, z: f32) -> f32 {
+        let xs = data
+            .vertices
+            .iter()
+            .filter(|v| (v[2] - z).abs() < 1e-4)
+            .map(|v| v[0]);
+        let (min, max) = xs.fold((f32::MAX, f32::MIN), |(min, max), x| {
+            (min.min(x), max.max(x))
+        });
+        max - min
+    }
+
+    #[test]
+    fn test_taper_shrinks_the_back_face() {
+        let straight = build_profile("-", ExtrusionProfile::Straight);
+        let tapered = build_profile("-", ExtrusionProfile::Taper(0.5));
+
+        // The front stays, the back is half as wide
+        let (front_z, back_z) = faces(&tapered);
+        assert!((front_z - back_z - DEPTH).abs() < 1e-4);
+        let front = width_at(&straight, front_z);
+        assert!((width_at(&tapered, front_z) - front).abs() < 1e-4);
+        assert!((width_at(&tapered, back_z) - front * 0.5).abs() < 1e-4);
+        assert_eq!(tapered.indices.len(), straight.indices.len());
+    }
+
+    #[test]
+    fn test_taper_tilts_side_normals_back() {
+        let tapered = build_profile("-", ExtrusionProfile::Taper(0.5));
+
+        // Sides of a wedge narrowing to the back face partly backward
+        let sides: Vec<Vec3> = tapered
+            .normals
+            .iter()
+            .map(|n| Vec3::from_array(*n))
+            .filter(|n| n.x.abs() > 0.5)
+            .collect();
+        assert!(!sides.is_empty());
+        assert!(sides.iter().all(|n| n.z < -0.1), "{sides:?}");
+    }
+
+    #[test]
+    fn test_curve_adds_rings_at_its_scales() {
+        let straight = build_profile("-", ExtrusionProfile::Straight);
+        let pillow = build_profile("-", ExtrusionProfile::Curve(vec![1.0, 1.5, 1.0]));
+
+        let (front_z, back_z) = faces(&pillow);
+        let front = width_at(&straight, faces(&straight).0);
+        assert!((width_at(&pillow, front_z) - front).abs() < 1e-4);
+        let middle = (front_z + back_z) * 0.5;
+        assert!((width_at(&pillow, middle) - front * 1.5).abs() < 1e-4);
+        assert!((width_at(&pillow, back_z) - front).abs() < 1e-4);
+        assert!(pillow.indices.len() > straight.indices.len());
+    }
+
+    #[test]
+    fn test_profile_is_ignored_without_depth() {
+        let style = |extrusion_profile| TextMeshStyle {
+            depth: 0.0,
+            extrusion_profile,
+            ..Default::default()
+        };
+        let font = test_font();
+        let straight = build_text_mesh(&font, &[], "Hi", &style(ExtrusionProfile::Straight));
+        let tapered = build_text_mesh(&font, &[], "Hi", &style(ExtrusionProfile::Taper(0.0)));
+        assert_eq!(straight.unwrap().vertices, tapered.unwrap().vertices);
+    }
+
+    fn build_shaded(
+        text: &str,
+        side_shading: SideShading,
+        render_mode: TextRenderMode,
+    ) -> TextMeshData {
+        let style = TextMeshStyle {
+            depth: 0.5,
+            side_shading: Some(side_shading),
+            render_mode,
+            ..Default::default()
+        };
+        build_text_mesh(&test_font(), &[], text, &style).unwrap()
+    }
+
+    fn smooth(degrees: f32) -> SideShading {
+        SideShading::Smooth {
+            angle_threshold: degrees.to_radians(),
+        }
+    }
+
+    /// Number of distinct wall normals at each position on the front edge of the walls.
+    fn normals_per_corner(data: &TextMeshData) -> Vec<usize> {
+        let front = data
+            .vertices
+            .iter()
+            .fold(f32::MIN, |front, v| front.max(v[2]));
+        let mut walls: Vec<([f32; 2], Vec3)> = data
+            .vertices
+            .iter()
+            .zip(&data.normals)
+            .filter(|(v, n)| (v[2] - front).abs() < 1e-4 && n[2].abs() < 1e-4)
+            .map(|(v, n)| ([v[0], v[1]], Vec3::from_array(*n)))
+            .collect();
+        walls.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        walls
+            .chunk_by(|a, b| a.0 == b.0)
+            .map(|corner| {
+                let mut normals: Vec<Vec3> = Vec::new();
+                for (_, normal) in corner {
+                    if normals.iter().all(|n| n.distance(*normal) > 1e-4) {
+                        normals.push(*normal);
+                    }
+                }
+                normals.len()
+            })
+            .collect()
+    }
+
+    fn most_normals_per_corner(data: &TextMeshData) -> usize {
+        normals_per_corner(data).into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn test_smooth_sides_share_normals_on_curves() {
+        let fill = TextRenderMode::Fill;
+        assert!(most_normals_per_corner(&build_shaded("o", SideShading::Flat, fill)) > 1);
+        assert_eq!(
+            most_normals_per_corner(&build_shaded("o", smooth(45.0), fill)),
+            1
+        );
+
+        // Walls stay perpendicular to the glyph plane and of unit length
+        let data = build_shaded("o", smooth(45.0), fill);
+        for normal in &data.normals {
+            let normal = Vec3::from_array(*normal);
+            assert!((normal.length() - 1.0).abs() < 1e-4);
+            assert!(normal.z.abs() < 1e-4 || (normal.z.abs() - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_smooth_sides_keep_sharp_corners() {
+        let fill = TextRenderMode::Fill;
+        assert_eq!(
+            most_normals_per_corner(&build_shaded("L", smooth(45.0), fill)),
+            2
+        );
+        assert_eq!(
+            most_normals_per_corner(&build_shaded("L", smooth(100.0), fill)),
+            1
+        );
+
+        // Engraved walls are shaded the same way, while the plaque keeps its square edges
+        let engraved = TextRenderMode::Engraved {
+            depth: 0.2,
+            margin: 0.1,
+        };
+        let count = |shading| -> usize {
+            normals_per_corner(&build_shaded("o", shading, engraved))
+                .iter()
+                .sum()
+        };
+        assert!(count(smooth(45.0)) < count(SideShading::Flat));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::core::style::{CaseTransform, MissingGlyphPolicy, TextMeshStyle};
+use crate::core::stylistic_set::{stylistic_lookups, substitute_glyph};
+use ttf_parser::Tag;
+
+/// The glyph drawn for a character once fallbacks and the missing-glyph policy apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum GlyphSource {
+    /// The glyph the face maps the character to.
+    Char(char),
+    /// A glyph by its id, such as a stylistic alternate or a shaped ligature.
+    Glyph(ttf_parser::GlyphId),
+    /// The face's `.notdef` box.
+    Notdef,
+    /// The glyph the face maps a capital to, shrunk to a small capital for a lowercase
+    /// character.
+    SmallCap(char),
+}
+
+impl GlyphSource {
+    /// Size of the glyph relative to the style's font size.
+    pub fn scale(self, style: &TextMeshStyle) -> f32 {
+        match self {
+            Self::SmallCap(_) => style.transform_case.small_caps_scale(),
+            _ => 1.0,
+        }
+    }
+}
+
+/// A primary font face followed by its fallbacks, in lookup order.
+///
+/// Each character is taken from the first face that maps it. Vertical metrics such as
+/// the line height always come from the primary face.
+pub(crate) struct FontChain<'a> {
+    faces: Vec<fontmesh::Face<'a>>,
+    /// GSUB lookups of the selected stylistic sets, per face.
+    stylistic_lookups: Vec<Vec<u16>>,
+    /// Letter case characters are drawn in.
+    case: CaseTransform,
+}
+
+impl<'a> FontChain<'a> {
+    /// Parse a chain from raw font data and face indices, primary font first.
+    ///
+    /// Returns `None` if the primary font fails to parse. Fallback fonts that fail to
+    /// parse are left out of the chain.
+    pub fn parse(data: impl IntoIterator<Item = (&'a [u8], u32)>) -> Option<Self> {
+        let mut data = data.into_iter();
+        let (primary, index) = data.next()?;
+        let mut fonts = Self::new(fontmesh::Face::parse(primary, index).ok()?);
+        for (data, index) in data {
+            fonts.push_fallback(data, index);
+        }
+        Some(fonts)
+    }
+
+    /// A chain of only the primary face.
+    pub fn new(primary: fontmesh::Face<'a>) -> Self {
+        Self {
+            faces: vec![primary],
+            stylistic_lookups: vec![Vec::new()],
+            case: CaseTransform::None,
+        }
+    }
+
+    /// Parse a fallback font and add it to the end of the chain, or leave it out if it
+    /// fails to parse.
+    pub fn push_fallback(&mut self, data: &'a [u8], index: u32) {
+        if let Ok(face) = fontmesh::Face::parse(data, index) {
+            self.faces.push(face);
+            self.stylistic_lookups.push(Vec::new());
+        }
+    }
+
+    /// Apply the variations, stylistic sets, and case transform of a style to every face
+    /// of the chain.
+    pub fn with_style(self, style: &TextMeshStyle) -> Self {
+        let mut fonts = self.with_variations(&style.variations);
+        fonts.case = style.transform_case;
+        fonts.stylistic_lookups = fonts
+            .faces
+            .iter()
+            .map(|face| stylistic_lookups(face, style))
+            .collect();
+        fonts
+    }
+
+    /// Set variation axes on every face of the chain.
+    ///
+    /// Axes a face doesn't have, including every axis of a static font, are ignored.
+    fn with_variations(mut self, variations: &[(Tag, f32)]) -> Self {
+        for face in &mut self.faces {
+            for &(axis, value) in variations {
+                face.set_variation(axis, value);
+            }
+        }
+        self
+    }
+
+    /// The primary font face.
+    pub fn primary(&self) -> &fontmesh::Face<'a> {
+        &self.faces[0]
+    }
+
+    /// The first face that has a glyph for `ch`, or the primary face if none does.
+    pub fn face_for(&self, ch: char) -> &fontmesh::Face<'a> {
+        self.find(ch).unwrap_or(self.primary())
+    }
+
+    /// The character drawn for `ch` in the chain's letter case, and whether it is a small
+    /// capital. Characters no face has in the other case are drawn as written.
+    pub fn cased(&self, ch: char) -> (char, bool) {
+        match self.case.apply(ch) {
+            (cased, small) if cased != ch && self.find(cased).is_some() => (cased, small),
+            _ => (ch, false),
+        }
+    }
+
+    /// The face and glyph to draw for `ch`, or `None` if it should be skipped.
+    ///
+    /// Characters are drawn in the chain's letter case. Characters missing from every
+    /// face are handled according to `policy`.
+    pub fn resolve(
+        &self,
+        ch: char,
+        policy: MissingGlyphPolicy,
+    ) -> Option<(&fontmesh::Face<'a>, GlyphSource)> {
+        let ch = match self.cased(ch) {
+            (upper, true) => return Some((self.face_for(upper), GlyphSource::SmallCap(upper))),
+            (ch, false) => ch,
+        };
+        if let Some((face, lookups)) = self.find_with_lookups(ch) {
+            let substitute = face
+                .glyph_index(ch)
+                .and_then(|glyph| substitute_glyph(face, lookups, glyph));
+            return Some((
+                face,
+                substitute.map_or(GlyphSource::Char(ch), GlyphSource::Glyph),
+            ));
+        }
+
+        match policy {
+            MissingGlyphPolicy::Skip => None,
+            MissingGlyphPolicy::Tofu => Some((self.primary(), GlyphSource::Notdef)),
+            MissingGlyphPolicy::ReplacementChar => Some(
+                self.find(char::REPLACEMENT_CHARACTER)
+                    .map(|face| (face, GlyphSource::Char(char::REPLACEMENT_CHARACTER)))
+                    .unwrap_or((self.primary(), GlyphSource::Notdef)),
+            ),
+        }
+    }
+
+    fn find(&self, ch: char) -> Option<&fontmesh::Face<'a>> {
+        self.find_with_lookups(ch).map(|(face, _)| face)
+    }
+
+    fn find_with_lookups(&self, ch: char) -> Option<(&fontmesh::Face<'a>, &[u16])> {
+        self.faces
+            .iter()
+            .zip(&self.stylistic_lookups)
+            .find(|(face, _)| face.glyph_index(ch).is_some())
+            .map(|(face, lookups)| (face, lookups.as_slice()))
+    }
+}
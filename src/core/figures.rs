@@ -0,0 +1,156 @@
+/// A space as wide as a digit, U+2007 FIGURE SPACE.
+pub const FIGURE_SPACE: char = '\u{2007}';
+
+/// A space as wide as a period, U+2008 PUNCTUATION SPACE.
+pub const PUNCTUATION_SPACE: char = '\u{2008}';
+
+/// The character whose advance a figure or punctuation space takes, if `ch` is one.
+///
+/// Fonts often have no glyph for these spaces, or give them the width of a proportional
+/// digit, so they are measured by the digit or period the text would draw instead.
+pub(crate) fn space_stand_in(ch: char) -> Option<char> {
+    match ch {
+        FIGURE_SPACE => Some('0'),
+        PUNCTUATION_SPACE => Some('.'),
+        _ => None,
+    }
+}
+
+/// Whether `ch` is drawn about as wide as a period rather than a digit.
+fn is_punctuation(ch: char) -> bool {
+    matches!(ch, '.' | ',' | '\'' | '\u{2019}')
+}
+
+/// Pad numbers with figure and punctuation spaces so they line up on their decimal
+/// `separator` in any alignment.
+///
+/// The part before the first separator is padded on the left, the part after it on the
+/// right, so every number has as many digits and group separators on each side as the
+/// longest one. Numbers without a separator get a [`PUNCTUATION_SPACE`] in its place
+/// when any other number has one. Digits and [`FIGURE_SPACE`]s only have the same
+/// width with tabular figures, so the text should use
+/// [`TextMeshStyle::tabular_figures`](crate::TextMeshStyle::tabular_figures) unless its
+/// font has them by default. Signs and other characters count as digits.
+///
+/// Useful for columns of numbers in a single text or in sections, where a
+/// [`TextTable`](crate::TextTable) would be too much.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_fontmesh::core::{pad_figures, FIGURE_SPACE, PUNCTUATION_SPACE};
+///
+/// let padded = pad_figures(&["12", "3.5", "1,024.25"], '.');
+/// let (f, p) = (FIGURE_SPACE, PUNCTUATION_SPACE);
+/// assert_eq!(padded[0], format!("{p}{f}{f}12{p}{f}{f}"));
+/// assert_eq!(padded[1], format!("{p}{f}{f}{f}3.5{f}"));
+/// assert_eq!(padded[2], "1,024.25");
+/// ```
+pub fn pad_figures<S: AsRef<str>>(numbers: &[S], separator: char) -> Vec<String> {
+    // Digits and punctuation before the separator, and characters after it
+    let parts: Vec<(&str, Option<&str>)> = numbers
+        .iter()
+        .map(|number| match number.as_ref().split_once(separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number.as_ref(), None),
+        })
+        .collect();
+    let count = |integer: &str| {
+        let punctuation = integer.chars().filter(|&ch| is_punctuation(ch)).count();
+        (integer.chars().count() - punctuation, punctuation)
+    };
+    let (digits, punctuation) = parts
+        .iter()
+        .map(|(integer, _)| count(integer))
+        .fold((0, 0), |(a, b), (c, d)| (a.max(c), b.max(d)));
+    let fraction_width = parts
+        .iter()
+        .filter_map(|(_, fraction)| fraction.map(|fraction| fraction.chars().count()));
+    let has_separator = parts.iter().any(|(_, fraction)| fraction.is_some());
+    let fraction_width = fraction_width.max().unwrap_or(0);
+
+    parts
+        .into_iter()
+        .map(|(integer, fraction)| {
+            let (own_digits, own_punctuation) = count(integer);
+            let mut padded = String::new();
+            padded.extend(std::iter::repeat_n(
+                PUNCTUATION_SPACE,
+                punctuation - own_punctuation,
+            ));
+            padded.extend(std::iter::repeat_n(FIGURE_SPACE, digits - own_digits));
+            padded.push_str(integer);
+            let fraction = match fraction {
+                Some(fraction) => {
+                    padded.push(separator);
+                    fraction
+                }
+                None if has_separator => {
+                    padded.push(PUNCTUATION_SPACE);
+                    ""
+                }
+                None => "",
+            };
+            padded.push_str(fraction);
+            let missing = fraction_width - fraction.chars().count();
+            padded.extend(std::iter::repeat_n(FIGURE_SPACE, missing));
+            padded
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layout::layout_text;
+    use crate::core::style::TextMeshStyle;
+    use crate::core::test_font;
+    use crate::core::FontMesh;
+
+    fn width(font: &FontMesh, text: &str, style: &TextMeshStyle) -> f32 {
+        layout_text(font, text, style).lines[0].width
+    }
+
+    #[test]
+    fn test_figure_spaces_are_as_wide_as_digits() {
+        let font = test_font();
+        let style = TextMeshStyle {
+            tabular_figures: true,
+            ..Default::default()
+        };
+        let digit = width(&font, "0", &style);
+        let period = width(&font, ".", &style);
+        assert!((width(&font, &FIGURE_SPACE.to_string(), &style) - digit).abs() < 1e-5);
+        assert!((width(&font, &PUNCTUATION_SPACE.to_string(), &style) - period).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_padded_numbers_line_up_on_their_separator() {
+        let font = test_font();
+        let style = TextMeshStyle {
+            tabular_figures: true,
+            ..Default::default()
+        };
+        let padded = pad_figures(&["12", "3.5", "1,024.25", "7.125"], '.');
+
+        // Every integer part ends at the same offset from the start of its line
+        let separator = |number: &str| {
+            let layout = layout_text(&font, number, &style);
+            let chars: Vec<char> = number.chars().collect();
+            let last_digit = chars.iter().rposition(|ch| ch.is_ascii_digit()).unwrap();
+            let integer = chars
+                .iter()
+                .position(|&ch| ch == '.')
+                .unwrap_or(last_digit + 1);
+            let digit = layout
+                .glyphs
+                .iter()
+                .find(|glyph| glyph.char_index == integer - 1);
+            let digit = digit.expect("integer part ends with a digit");
+            digit.position.x + digit.advance - layout.lines[0].start
+        };
+        for number in &padded[1..] {
+            assert!((separator(number) - separator(&padded[2])).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use crate::core::layout::space_advance;
+use crate::core::outline::{sample_contour, GlyphOutline};
+use crate::core::style::TextMeshStyle;
+use crate::core::stylistic_set::{font_stylistic_sets, StylisticSet};
+use bevy_math::Vec2;
+use std::sync::Arc;
+use ttf_parser::GlyphId;
+
+/// Asset containing font data for 3D text mesh generation.
+///
+/// This asset type holds the raw bytes of a TrueType font file.
+/// Fonts are automatically loaded from the asset server and used by the [`TextMesh`](crate::TextMesh)
+/// component to generate 3D mesh geometry.
+///
+/// # Loading Fonts
+///
+/// Fonts are loaded like any other Bevy asset:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::FontMesh;
+/// # fn example(asset_server: Res<AssetServer>) {
+/// let font: Handle<FontMesh> = asset_server.load("fonts/MyFont.ttf");
+/// # }
+/// ```
+///
+/// Place your font files in the `assets/fonts/` directory (or any subdirectory of `assets/`).
+/// Outside of Bevy, such as in build scripts, create the font from its bytes and pass
+/// it to the functions of the [`core`](crate::core) module instead.
+///
+/// # Font Collections
+///
+/// TrueType and OpenType collections (`.ttc`, `.otc`) bundle several faces in one
+/// file. Loading a collection gives its first face; add the index of another face as
+/// the label of the asset path to load that one instead:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::FontMesh;
+/// # fn example(asset_server: Res<AssetServer>) {
+/// let gothic: Handle<FontMesh> = asset_server.load("fonts/msgothic.ttc");
+/// let ui_gothic: Handle<FontMesh> = asset_server.load("fonts/msgothic.ttc#1");
+/// # }
+/// ```
+///
+/// # Supported Formats
+///
+/// - TrueType (`.ttf`) and TrueType collections (`.ttc`)
+/// - WOFF (`.woff`) and WOFF2 (`.woff2`) web fonts with the `woff` feature, decompressed
+///   into [`data`](Self::data) when they are loaded
+///
+/// **Note**: Some OpenType fonts (`.otf`, `.otc`) with TrueType outlines are supported,
+/// but OpenType fonts with CFF/PostScript outlines are not (limitation of ttf-parser).
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
+#[derive(Debug)]
+pub struct FontMesh {
+    /// Raw font file data in TTF or OTF format, or a whole font collection.
+    ///
+    /// The faces of a collection share the same data, and cloning it doesn't copy the
+    /// bytes.
+    pub data: Arc<[u8]>,
+    /// Index of the face to use when `data` is a font collection. `0` for single fonts.
+    pub face_index: u32,
+}
+
+/// Data of the font embedded with the `default-font` feature, a subset of Fira Mono
+/// covering ASCII.
+#[cfg(feature = "default-font")]
+pub const DEFAULT_FONT_DATA: &[u8] = include_bytes!("../fonts/FiraMono-subset.ttf");
+
+/// Metrics for a single glyph
+#[derive(Debug, Clone, Copy)]
+pub struct GlyphMetrics {
+    /// Horizontal advance width (how far to move cursor after this glyph)
+    pub advance: f32,
+    /// Whether the glyph has visible geometry (some chars like space don't)
+    pub has_outline: bool,
+}
+
+/// Font-level metrics
+#[derive(Debug, Clone, Copy)]
+pub struct FontMetrics {
+    /// Distance from baseline to top of tallest glyph
+    pub ascender: f32,
+    /// Distance from baseline to bottom of lowest glyph (typically negative)
+    pub descender: f32,
+    /// Extra space between lines
+    pub line_gap: f32,
+    /// Total line height (ascender - descender + line_gap)
+    pub line_height: f32,
+}
+
+impl FontMesh {
+    /// Number of faces in the font data: the size of a font collection, or `1` for a
+    /// single font.
+    pub fn face_count(&self) -> u32 {
+        ttf_parser::fonts_in_collection(&self.data).unwrap_or(1)
+    }
+
+    /// Parse the selected face of the font data.
+    pub(crate) fn face(&self) -> Option<fontmesh::Face<'_>> {
+        fontmesh::Face::parse(&self.data, self.face_index).ok()
+    }
+
+    /// Get metrics for a specific character.
+    ///
+    /// Returns `None` if the character is not in the font.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     if let Some(metrics) = font.glyph_metrics('A') {
+    ///         println!("Advance width of 'A': {}", metrics.advance);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn glyph_metrics(&self, character: char) -> Option<GlyphMetrics> {
+        let face = self.face()?;
+        let advance = fontmesh::glyph_advance(&face, character)?;
+
+        // Check if glyph has outline by trying to generate a mesh
+        let has_outline = fontmesh::char_to_mesh_2d(&face, character, 1).is_ok();
+
+        Some(GlyphMetrics {
+            advance,
+            has_outline,
+        })
+    }
+
+    /// Get metrics for a glyph by its id in the font.
+    ///
+    /// Unlike [`glyph_metrics`](Self::glyph_metrics), this also reaches glyphs that no
+    /// character maps to, such as stylistic alternates, and takes the glyph ids produced
+    /// by a text shaper like rustybuzz as they are.
+    ///
+    /// Returns `None` if the font has no glyph with this id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::{FontMesh, GlyphId};
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     if let Some(metrics) = font.glyph_metrics_by_id(GlyphId(42)) {
+    ///         println!("Advance width of glyph 42: {}", metrics.advance);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn glyph_metrics_by_id(&self, glyph_id: GlyphId) -> Option<GlyphMetrics> {
+        let face = self.face()?;
+        if glyph_id.0 >= face.number_of_glyphs() {
+            return None;
+        }
+        let advance =
+            face.glyph_hor_advance(glyph_id).unwrap_or(0) as f32 / face.units_per_em() as f32;
+
+        Some(GlyphMetrics {
+            advance,
+            has_outline: face.glyph_bounding_box(glyph_id).is_some(),
+        })
+    }
+
+    /// Get font-level metrics (ascender, descender, line height, etc.)
+    ///
+    /// Returns `None` if the font data is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     if let Some(metrics) = font.font_metrics() {
+    ///         println!("Line height: {}", metrics.line_height);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn font_metrics(&self) -> Option<FontMetrics> {
+        let face = self.face()?;
+
+        let ascender = fontmesh::ascender(&face);
+        let descender = fontmesh::descender(&face);
+        let line_gap = fontmesh::line_gap(&face);
+
+        Some(FontMetrics {
+            ascender,
+            descender,
+            line_gap,
+            line_height: ascender - descender + line_gap,
+        })
+    }
+
+    /// List the stylistic sets the font offers, with the name the font gives each one.
+    ///
+    /// Numbered sets often carry a descriptive name such as "Single-story a". Sets
+    /// without one, including [`StylisticSet::Alternates`], have a name of `None`.
+    /// Returns an empty vector if the font has no stylistic sets or is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     for (set, name) in font.stylistic_sets() {
+    ///         println!("{set:?}: {}", name.unwrap_or_default());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn stylistic_sets(&self) -> Vec<(StylisticSet, Option<String>)> {
+        self.face()
+            .map(|face| font_stylistic_sets(&face))
+            .unwrap_or_default()
+    }
+
+    /// Get the flattened outline of a character as closed polylines.
+    ///
+    /// Returns one polyline per contour, in ems with the glyph origin on the
+    /// baseline at `(0, 0)`. Curves are split into `subdivision` straight segments, as
+    /// with [`TextMeshStyle::subdivision`], so the polylines match the edges of generated
+    /// meshes. The closing point of a contour is not repeated. Contours run
+    /// counter-clockwise around the filled area, so outer contours are counter-clockwise
+    /// and holes clockwise, whatever the font's own winding.
+    ///
+    /// Useful for drawing glyphs with gizmos, exporting paths for cutting machines, or
+    /// building custom geometry without generating a mesh.
+    ///
+    /// Returns an empty vector if the character is missing from the font or has no
+    /// outline.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     // An SVG path for a cutting machine, flipped to SVG's downward y axis
+    ///     let mut path = String::new();
+    ///     for contour in font.glyph_contours('A', 8) {
+    ///         for (i, point) in contour.iter().enumerate() {
+    ///             let command = if i == 0 { 'M' } else { 'L' };
+    ///             path += &format!("{command}{} {} ", point.x, -point.y);
+    ///         }
+    ///         path += "Z ";
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn glyph_contours(&self, character: char, subdivision: u8) -> Vec<Vec<Vec2>> {
+        self.face()
+            .and_then(|face| GlyphOutline::new(&face, character, subdivision))
+            .map(|outline| outline.fill_on_left().contours)
+            .unwrap_or_default()
+    }
+
+    /// Sample points spaced evenly along the outline of a character.
+    ///
+    /// Returns `(position, tangent)` pairs in ems, with the glyph origin on the
+    /// baseline at `(0, 0)`. Each contour of the glyph is sampled separately, with
+    /// `spacing` rounded so the samples divide the contour evenly. Tangents are unit
+    /// length and run counter-clockwise around the filled area, so the outward normal
+    /// of a sample is `Vec2::new(tangent.y, -tangent.x)`.
+    ///
+    /// Returns an empty vector if the character is missing from the font, has no
+    /// outline, or `spacing` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     // Rivets every 0.05 em, pushed slightly outside the outline
+    ///     for (position, tangent) in font.glyph_contour_samples('A', 0.05) {
+    ///         let outward = Vec2::new(tangent.y, -tangent.x);
+    ///         let rivet = position + outward * 0.01;
+    ///         println!("Rivet at {rivet}");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn glyph_contour_samples(&self, character: char, spacing: f32) -> Vec<(Vec2, Vec2)> {
+        let Some(face) = self.face() else {
+            return Vec::new();
+        };
+        let subdivision = TextMeshStyle::default().subdivision;
+        let Some(outline) = GlyphOutline::new(&face, character, subdivision) else {
+            return Vec::new();
+        };
+
+        outline
+            .fill_on_left()
+            .contours
+            .iter()
+            .flat_map(|contour| sample_contour(contour, spacing))
+            .collect()
+    }
+
+    /// Calculate the width of a text string.
+    ///
+    /// This sums the advance widths of all characters. Does not account for kerning.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     let width = font.text_width("Hello");
+    ///     println!("Text width: {}", width);
+    /// }
+    /// # }
+    /// ```
+    pub fn text_width(&self, text: &str) -> f32 {
+        let Some(face) = self.face() else {
+            return 0.0;
+        };
+
+        text.chars()
+            .map(|ch| {
+                fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
+                    if ch.is_whitespace() {
+                        space_advance(&face)
+                    } else {
+                        0.0
+                    }
+                })
+            })
+            .sum()
+    }
+
+    /// Get character positions for a line of text.
+    ///
+    /// Returns a vector of (char_index, x_position) pairs for each character.
+    /// Useful for cursor positioning in text editors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::FontMesh;
+    /// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+    /// if let Some(font) = font_assets.get(&font_handle) {
+    ///     let positions = font.char_positions("Hello");
+    ///     for (idx, x) in positions {
+    ///         println!("Char {} at x={}", idx, x);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn char_positions(&self, text: &str) -> Vec<(usize, f32)> {
+        let Some(face) = self.face() else {
+            return Vec::new();
+        };
+
+        text.chars()
+            .enumerate()
+            .scan(0.0, |x, (idx, ch)| {
+                let current_x = *x;
+                *x += fontmesh::glyph_advance(&face, ch).unwrap_or_else(|| {
+                    if ch.is_whitespace() {
+                        space_advance(&face)
+                    } else {
+                        0.0
+                    }
+                });
+                Some((idx, current_x))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,1202 @@
+use crate::core::fallback::{FontChain, GlyphSource};
+use crate::core::figures::space_stand_in;
+use crate::core::outline::notdef_advance;
+#[cfg(feature = "shaping")]
+use crate::core::shaping::shaped_items;
+use crate::core::style::{JustifyText, MissingGlyphPolicy, TextMeshStyle, TextOverflow};
+use crate::core::FontMesh;
+use bevy_math::{Rect, Vec2, Vec3};
+use std::ops::Range;
+
+/// Glyph placements of a text, computed without generating any geometry.
+///
+/// Returned by [`layout_text`]. Positions follow the same rules as the generated
+/// meshes: the origin is the start of the first baseline, lines go down by
+/// [`TextLayout::line_height`], and every line is already shifted by its
+/// justification. [Vertical](crate::TextOrientation) text starts at the top of the
+/// first column instead, centered on it, and its columns go sideways. The [`TextAnchor`](crate::TextAnchor) is not applied, because meshes
+/// are anchored by the bounds of their tessellated geometry.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    /// Every visible glyph, in text order. Whitespace takes up space but has no glyph.
+    pub glyphs: Vec<PositionedGlyph>,
+    /// One entry per line of the text.
+    pub lines: Vec<LayoutLine>,
+    /// Box from the ascender of the first line to the descender of the last one,
+    /// spanning the widest line. For vertical text, the box spans the longest column
+    /// and is as wide as the font is high on either side.
+    pub bounds: Rect,
+    /// Distance between two consecutive baselines, or between the center lines of two
+    /// consecutive columns of vertical text.
+    pub line_height: f32,
+    /// Height of the primary font's ascender above the baseline.
+    pub ascender: f32,
+    /// Height of the primary font's descender, negative below the baseline.
+    pub descender: f32,
+    /// Whether the lines are columns of [vertical](crate::TextOrientation) text.
+    pub vertical: bool,
+}
+
+/// A glyph placed by [`layout_text`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionedGlyph {
+    /// The character in the text.
+    pub character: char,
+    /// The character whose glyph is drawn: `character` itself, also when a
+    /// [`StylisticSet`](crate::StylisticSet) swaps in an alternate form, `U+FFFD` under
+    /// [`MissingGlyphPolicy::ReplacementChar`](crate::MissingGlyphPolicy::ReplacementChar),
+    /// or `None` for the font's `.notdef` box.
+    pub glyph: Option<char>,
+    /// Index of the character in the text, counting line breaks.
+    pub char_index: usize,
+    /// Line the glyph is on.
+    pub line_index: usize,
+    /// Origin of the glyph on its baseline.
+    pub position: Vec2,
+    /// Horizontal advance of the glyph, without letter spacing. Vertical text is still
+    /// measured horizontally here.
+    pub advance: f32,
+}
+
+/// A line of a [`TextLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutLine {
+    /// Range of the line's glyphs in [`TextLayout::glyphs`].
+    pub glyphs: Range<usize>,
+    /// Height of the line's baseline, or the X of the center line of a vertical column.
+    pub baseline: f32,
+    /// Where the line starts after justification, the top of a vertical column.
+    pub start: f32,
+    /// Width of the line, including spacing between its characters, or the height of
+    /// a vertical column.
+    pub width: f32,
+    /// Byte range of the line in the text, without its line break.
+    pub bytes: Range<usize>,
+    /// Caret stops of the line: the byte index of every character on it and of the end
+    /// of the line, each with the X of the caret in front of it, or the Y in a vertical
+    /// column. Characters that take up no space of their own, such as the later
+    /// characters of a ligature, share the stop of the character after them.
+    pub carets: Vec<(usize, f32)>,
+}
+
+/// Place the glyphs of `text` without spawning entities or building meshes.
+///
+/// This is the layout the plugin's systems use, for custom renderers and tooling that
+/// only need glyph positions. The style's fallback fonts are asset handles and can't be
+/// resolved here, so characters missing from `font` follow the style's
+/// [`MissingGlyphPolicy`](crate::MissingGlyphPolicy). With the `shaping` feature, lines
+/// are shaped first, so a ligature is a single glyph for its first character and
+/// right-to-left text comes in visual order. Positions aren't anchored; the style's
+/// anchor moves the text as a whole afterwards. Returns an empty layout if the font
+/// can't be parsed.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(font_assets: Res<Assets<FontMesh>>, font_handle: Handle<FontMesh>) {
+/// if let Some(font) = font_assets.get(&font_handle) {
+///     let layout = layout_text(font, "Hello\nWorld", &TextMeshStyle::default());
+///     for glyph in &layout.glyphs {
+///         println!("{} at {}", glyph.character, glyph.position);
+///     }
+/// }
+/// # }
+/// ```
+pub fn layout_text(font: &FontMesh, text: &str, style: &TextMeshStyle) -> TextLayout {
+    FontChain::parse([(&*font.data, font.face_index)])
+        .map(|fonts| fonts.with_style(style))
+        .map(|fonts| layout_text_in(&fonts, text, style))
+        .unwrap_or_default()
+}
+
+/// Place the glyphs of `text` using a parsed font chain.
+pub(crate) fn layout_text_in(fonts: &FontChain, text: &str, style: &TextMeshStyle) -> TextLayout {
+    let line_height = calculate_line_height(fonts, style);
+    let line_step = style.orientation.line_step(line_height);
+    let mut glyphs = Vec::new();
+    let mut lines = Vec::new();
+    let mut char_index = 0;
+    let mut byte_index = 0;
+
+    for (line_index, (line, ends_paragraph)) in paragraph_lines(text).enumerate() {
+        let width = calculate_line_width(line, ends_paragraph, fonts, style);
+        let origin = line_step.truncate() * line_index as f32;
+        let first = glyphs.len();
+        glyphs.extend(
+            line_glyphs(line, ends_paragraph, fonts, style).map(|glyph| PositionedGlyph {
+                character: glyph.character,
+                glyph: match glyph.source {
+                    GlyphSource::Char(ch) | GlyphSource::SmallCap(ch) => Some(ch),
+                    GlyphSource::Glyph(_) => Some(glyph.character),
+                    GlyphSource::Notdef => None,
+                },
+                char_index: char_index + glyph.index,
+                line_index,
+                position: origin + Vec2::new(glyph.x, glyph.y),
+                advance: glyph.advance,
+            }),
+        );
+        lines.push(LayoutLine {
+            glyphs: first..glyphs.len(),
+            baseline: if style.orientation.is_vertical() {
+                origin.x
+            } else {
+                origin.y
+            },
+            start: calculate_line_start(style, width),
+            width,
+            bytes: byte_index..byte_index + line.len(),
+            carets: line
+                .char_indices()
+                .map(|(byte, _)| byte_index + byte)
+                .chain([byte_index + line.len()])
+                .zip(line_carets(line, ends_paragraph, fonts, style))
+                .collect(),
+        });
+        char_index += line.chars().count() + 1;
+        byte_index += line.len() + 1;
+    }
+
+    let (ascender, descender) = vertical_extent(fonts, style);
+    let bounds = if style.orientation.is_vertical() {
+        let half_width = (ascender - descender) * 0.5;
+        lines
+            .iter()
+            .map(|line| {
+                Rect::new(
+                    line.baseline - half_width,
+                    line.start - line.width,
+                    line.baseline + half_width,
+                    line.start,
+                )
+            })
+            .reduce(|a, b| a.union(b))
+            .unwrap_or_default()
+    } else {
+        let left = lines.iter().map(|line| line.start).fold(f32::MAX, f32::min);
+        let right = lines
+            .iter()
+            .map(|line| line.start + line.width)
+            .fold(f32::MIN, f32::max);
+        let bottom = lines.last().map_or(0.0, |line| line.baseline) + descender;
+        Rect::new(left, bottom, right, ascender)
+    };
+
+    TextLayout {
+        glyphs,
+        lines,
+        bounds,
+        line_height,
+        ascender,
+        descender,
+        vertical: style.orientation.is_vertical(),
+    }
+}
+
+impl TextLayout {
+    /// Box of the line at `index`, from its start to its end and from the ascender down
+    /// to the descender. Columns of vertical text get boxes as wide as the font is high,
+    /// centered on the column.
+    pub fn line_rect(&self, index: usize) -> Option<Rect> {
+        let line = self.lines.get(index)?;
+        Some(if self.vertical {
+            let half_width = (self.ascender - self.descender) * 0.5;
+            Rect::new(
+                line.baseline - half_width,
+                line.start - line.width,
+                line.baseline + half_width,
+                line.start,
+            )
+        } else {
+            Rect::new(
+                line.start,
+                line.baseline + self.descender,
+                line.start + line.width,
+                line.baseline + self.ascender,
+            )
+        })
+    }
+
+    /// The caret in front of the character at `byte_index` of the text, as a box without
+    /// width spanning its line box, or without height in a vertical column.
+    ///
+    /// Byte indices of line breaks and of the end of the text place the caret at the end
+    /// of their line, and indices inside a character place it in front of that
+    /// character. Returns `None` for indices past the end of the text.
+    pub fn caret_rect(&self, byte_index: usize) -> Option<Rect> {
+        let (index, line) =
+            self.lines.iter().enumerate().find(|(_, line)| {
+                line.bytes.contains(&byte_index) || line.bytes.end == byte_index
+            })?;
+        let position = caret_position(line, byte_index);
+        let rect = self.line_rect(index)?;
+        Some(if self.vertical {
+            Rect::new(rect.min.x, position, rect.max.x, position)
+        } else {
+            Rect::new(position, rect.min.y, position, rect.max.y)
+        })
+    }
+
+    /// Highlight boxes of the text in the byte `range`, one per line it covers, spanning
+    /// the line box.
+    ///
+    /// Lines the range only touches, and empty lines, get no box.
+    pub fn selection_rects(&self, range: Range<usize>) -> Vec<Rect> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let start = range.start.max(line.bytes.start);
+                let end = range.end.min(line.bytes.end);
+                if start >= end {
+                    return None;
+                }
+                let (a, b) = (caret_position(line, start), caret_position(line, end));
+                let (low, high) = (a.min(b), a.max(b));
+                let rect = self.line_rect(index)?;
+                (high > low).then(|| {
+                    if self.vertical {
+                        Rect::new(rect.min.x, low, rect.max.x, high)
+                    } else {
+                        Rect::new(low, rect.min.y, high, rect.max.y)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Transform that places a caret in front of the character at `byte_index`, see
+    /// [`caret_rect`](Self::caret_rect).
+    ///
+    /// Turns a unit-high shape centered on its origin, such as
+    /// `Rectangle::new(width, 1.0)`, into the caret: it is moved to the middle of the
+    /// caret, stretched to the height of the line box, and laid on its side in vertical
+    /// text.
+    #[cfg(feature = "bevy")]
+    pub fn caret_transform(&self, byte_index: usize) -> Option<bevy::prelude::Transform> {
+        use bevy::prelude::{Quat, Transform};
+
+        let rect = self.caret_rect(byte_index)?;
+        let (length, rotation) = if self.vertical {
+            (
+                rect.width(),
+                Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
+            )
+        } else {
+            (rect.height(), Quat::IDENTITY)
+        };
+        Some(Transform {
+            translation: rect.center().extend(0.0),
+            rotation,
+            scale: Vec3::new(1.0, length, 1.0),
+        })
+    }
+}
+
+/// Position of the caret stop of `line` at or before `byte_index`.
+fn caret_position(line: &LayoutLine, byte_index: usize) -> f32 {
+    let stop = line
+        .carets
+        .partition_point(|&(byte, _)| byte <= byte_index)
+        .saturating_sub(1);
+    line.carets
+        .get(stop)
+        .map_or(line.start, |&(_, position)| position)
+}
+
+/// A visible glyph of a line, as placed by [`line_glyphs`].
+pub(crate) struct LineGlyph<'a, 'f> {
+    /// The face the glyph is taken from.
+    pub face: &'a fontmesh::Face<'f>,
+    /// The glyph drawn for the character.
+    pub source: GlyphSource,
+    /// The character in the text.
+    pub character: char,
+    /// Index of the character in the line.
+    pub index: usize,
+    /// Index of the [`LineSpan`] the character is in.
+    #[cfg(feature = "bevy")]
+    pub span: usize,
+    /// X offset of the glyph origin.
+    pub x: f32,
+    /// Y offset of the glyph origin from the baseline, only set by shaping.
+    pub y: f32,
+    /// Advance of the glyph, without letter spacing.
+    pub advance: f32,
+}
+
+/// A character of a line and the space it takes up, before justification and spacing.
+///
+/// With the `shaping` feature, an item is a shaped glyph instead, and characters merged
+/// into a ligature have no item of their own.
+pub(crate) struct LineItem<'a, 'f> {
+    /// The face and glyph drawn, or `None` for whitespace.
+    pub glyph: Option<(&'a fontmesh::Face<'f>, GlyphSource)>,
+    /// The character in the text.
+    pub character: char,
+    /// Index of the character in the line.
+    pub index: usize,
+    /// Index of the [`LineSpan`] the item is in.
+    pub span: usize,
+    /// Advance of the item, including word spacing.
+    pub advance: f32,
+    /// Offset of the glyph from its pen position.
+    pub offset: Vec2,
+    /// Space added after the item to justify its line.
+    pub stretch: f32,
+}
+
+/// The lines of `text`, each with whether it ends its paragraph.
+///
+/// Paragraphs are separated by empty lines, so a line ends its paragraph when it is
+/// followed by an empty line or is the last line of the text.
+pub(crate) fn paragraph_lines(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut lines = text.split('\n').peekable();
+    std::iter::from_fn(move || {
+        let line = lines.next()?;
+        let ends_paragraph = lines.peek().is_none_or(|next| next.trim().is_empty());
+        Some((line, ends_paragraph))
+    })
+}
+
+/// A run of a line drawn with its own fonts and style, like a section of rich text.
+#[derive(Clone, Copy)]
+pub(crate) struct LineSpan<'a, 'f> {
+    /// The text of the run, without line breaks.
+    pub text: &'a str,
+    /// Fonts the run is drawn with.
+    pub fonts: &'a FontChain<'f>,
+    /// Style the glyphs and advances of the run are measured with.
+    pub style: &'a TextMeshStyle,
+}
+
+impl<'a, 'f> LineSpan<'a, 'f> {
+    /// A span of `text` drawn with `fonts` and `style`.
+    pub(crate) fn new(text: &'a str, fonts: &'a FontChain<'f>, style: &'a TextMeshStyle) -> Self {
+        Self { text, fonts, style }
+    }
+}
+
+/// Every character of a line made of `spans` that takes up space, in order, cut off as
+/// the style's [`overflow`](TextMeshStyle::overflow) asks.
+///
+/// Each span is shaped and measured with its own fonts and style, while `style` spaces,
+/// cuts off, and justifies the line as a whole. An ellipsis is drawn like the first
+/// span. Lines that end their paragraph, see [`paragraph_lines`], aren't justified.
+pub(crate) fn span_items<'a, 'f>(
+    spans: &[LineSpan<'a, 'f>],
+    ends_paragraph: bool,
+    style: &TextMeshStyle,
+) -> Vec<LineItem<'a, 'f>> {
+    if spans.is_empty() {
+        return Vec::new();
+    }
+    let mut items = Vec::new();
+    let mut chars = 0;
+    for (span_index, span) in spans.iter().enumerate() {
+        #[cfg(feature = "shaping")]
+        let span_items = shaped_items(span.text, span.fonts, span.style);
+        #[cfg(not(feature = "shaping"))]
+        let span_items = char_items(span.text, span.fonts, span.style);
+        items.extend(span_items.into_iter().map(|item| LineItem {
+            index: chars + item.index,
+            span: span_index,
+            ..item
+        }));
+        chars += span.text.chars().count();
+    }
+    let mut items = fit_items(items, chars, spans, style);
+    justify_items(&mut items, ends_paragraph, spans, style);
+    items
+}
+
+/// Every character of a line that takes up space, in order.
+#[cfg(not(feature = "shaping"))]
+fn char_items<'a, 'f>(
+    line: &str,
+    fonts: &'a FontChain<'f>,
+    style: &TextMeshStyle,
+) -> Vec<LineItem<'a, 'f>> {
+    line.chars()
+        .enumerate()
+        .filter_map(|(index, ch)| {
+            let advance = get_char_advance(ch, fonts, style)?;
+            Some(LineItem {
+                glyph: (!ch.is_whitespace())
+                    .then(|| fonts.resolve(ch, style.missing_glyph))
+                    .flatten(),
+                character: ch,
+                index,
+                span: 0,
+                advance,
+                offset: Vec2::ZERO,
+                stretch: 0.0,
+            })
+        })
+        .collect()
+}
+
+/// Cut the items of a line of `chars` characters off at the style's
+/// [`max_width`](TextMeshStyle::max_width) and [`max_chars`](TextMeshStyle::max_chars).
+fn fit_items<'a, 'f>(
+    mut items: Vec<LineItem<'a, 'f>>,
+    chars: usize,
+    spans: &[LineSpan<'a, 'f>],
+    style: &TextMeshStyle,
+) -> Vec<LineItem<'a, 'f>> {
+    let max_chars = style.max_chars.unwrap_or(usize::MAX);
+    let max_width = style.max_width.unwrap_or(f32::INFINITY);
+    if style.overflow == TextOverflow::None
+        || (chars <= max_chars && items_width(&items, spans, style) <= max_width)
+    {
+        return items;
+    }
+
+    let mut tail = match style.overflow {
+        TextOverflow::Ellipsis => ellipsis_items(spans[0].fonts, spans[0].style),
+        _ => Vec::new(),
+    };
+    let max_chars = max_chars.saturating_sub(tail.len().min(1));
+    let tail_width = items_width(&tail, spans, style);
+    let spacing = style.letter_spacing;
+
+    // The longest start of the line that leaves room for the tail
+    let (mut kept, mut width) = (0, 0.0);
+    for item in &items {
+        let end =
+            width + if kept > 0 { spacing } else { 0.0 } + item_length(item, &spans[item.span]);
+        let total = if tail.is_empty() {
+            end
+        } else {
+            end + spacing + tail_width
+        };
+        if item.index >= max_chars || total > max_width {
+            break;
+        }
+        (kept, width) = (kept + 1, end);
+    }
+
+    if !tail.is_empty() {
+        while kept > 0 && items[kept - 1].character.is_whitespace() {
+            kept -= 1;
+        }
+        // The ellipsis stands in for the first character it hides
+        let index = items.get(kept).map_or(chars, |item| item.index);
+        for item in &mut tail {
+            item.index = index;
+        }
+    }
+    items.truncate(kept);
+    items.extend(tail);
+    items
+}
+
+/// Widen the spaces between the words of a line until it is as wide as the style's
+/// [`max_width`](TextMeshStyle::max_width), for [`JustifyText::Justified`].
+///
+/// The last line of a paragraph keeps its natural width, like in CSS.
+fn justify_items(
+    items: &mut [LineItem],
+    ends_paragraph: bool,
+    spans: &[LineSpan],
+    style: &TextMeshStyle,
+) {
+    let (JustifyText::Justified, Some(max_width), false) =
+        (style.justify, style.max_width, ends_paragraph)
+    else {
+        return;
+    };
+    // Whitespace at either end of the line is no gap between words
+    let is_word = |item: &LineItem| !item.character.is_whitespace();
+    let (Some(first), Some(last)) = (
+        items.iter().position(is_word),
+        items.iter().rposition(is_word),
+    ) else {
+        return;
+    };
+    let gaps = items[first..last]
+        .iter()
+        .filter(|item| !is_word(item))
+        .count();
+    let free = max_width - items_width(items, spans, style);
+    if gaps == 0 || free <= 0.0 {
+        return;
+    }
+    for item in &mut items[first..last] {
+        if !is_word(item) {
+            item.stretch = free / gaps as f32;
+        }
+    }
+}
+
+/// The font's ellipsis, or three periods if no font has one.
+fn ellipsis_items<'a, 'f>(
+    fonts: &'a FontChain<'f>,
+    style: &TextMeshStyle,
+) -> Vec<LineItem<'a, 'f>> {
+    let ellipsis = if fonts.resolve('…', MissingGlyphPolicy::Skip).is_some() {
+        "…"
+    } else {
+        "..."
+    };
+    ellipsis
+        .chars()
+        .filter_map(|ch| {
+            Some(LineItem {
+                glyph: fonts.resolve(ch, style.missing_glyph),
+                character: ch,
+                index: 0,
+                span: 0,
+                advance: get_char_advance(ch, fonts, style)?,
+                offset: Vec2::ZERO,
+                stretch: 0.0,
+            })
+        })
+        .collect()
+}
+
+/// Helper function to calculate the width of a line of text, or the height of a
+/// vertical column
+#[inline]
+pub(crate) fn calculate_line_width(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> f32 {
+    calculate_spans_width(&[LineSpan::new(line, fonts, style)], ends_paragraph, style)
+}
+
+/// Width of a line made of `spans`, laid out like [`span_items`].
+#[inline]
+pub(crate) fn calculate_spans_width(
+    spans: &[LineSpan],
+    ends_paragraph: bool,
+    style: &TextMeshStyle,
+) -> f32 {
+    items_width(&span_items(spans, ends_paragraph, style), spans, style)
+}
+
+/// Width of the items of a line, with letter spacing between them.
+fn items_width(items: &[LineItem], spans: &[LineSpan], style: &TextMeshStyle) -> f32 {
+    let width: f32 = items
+        .iter()
+        .map(|item| item_length(item, &spans[item.span]))
+        .sum();
+
+    // Letter spacing goes between characters, not after the last one
+    width + style.letter_spacing * items.len().saturating_sub(1) as f32
+}
+
+/// Space an item takes up along its line: its advance, or in vertical text the
+/// vertical advance of its glyph.
+fn item_length(item: &LineItem, span: &LineSpan) -> f32 {
+    let (fonts, style) = (span.fonts, span.style);
+    if !style.orientation.is_vertical() {
+        return item.advance + item.stretch;
+    }
+
+    let (face, glyph_id) = match item.glyph {
+        Some((face, GlyphSource::Char(ch) | GlyphSource::SmallCap(ch))) => {
+            (face, face.glyph_index(ch))
+        }
+        Some((face, GlyphSource::Glyph(glyph_id))) => (face, Some(glyph_id)),
+        Some((face, GlyphSource::Notdef)) => (face, Some(ttf_parser::GlyphId(0))),
+        None => {
+            let face = fonts.face_for(item.character);
+            (face, face.glyph_index(item.character))
+        }
+    };
+    let spacing = if item.character.is_whitespace() {
+        style.word_spacing
+    } else {
+        0.0
+    };
+    let scale = item.glyph.map_or(1.0, |(_, source)| source.scale(style));
+    vertical_advance(face, glyph_id) * style.font_size * scale + spacing + item.stretch
+}
+
+/// Vertical advance of a glyph from the font's `vmtx` table, or the height of the font
+/// for fonts without vertical metrics.
+fn vertical_advance(face: &fontmesh::Face, glyph_id: Option<ttf_parser::GlyphId>) -> f32 {
+    glyph_id
+        .and_then(|glyph_id| face.glyph_ver_advance(glyph_id))
+        .map(|advance| advance as f32 / face.units_per_em() as f32)
+        .unwrap_or_else(|| fontmesh::ascender(face) - fontmesh::descender(face))
+}
+
+/// Helper function to get the advance width for a character from the font chain,
+/// applying the missing-glyph policy and word spacing
+///
+/// Returns `None` for characters the policy skips, which take up no space at all.
+#[inline]
+pub(crate) fn get_char_advance(ch: char, fonts: &FontChain, style: &TextMeshStyle) -> Option<f32> {
+    // Figure and punctuation spaces are as wide as the digit or period they stand in for
+    if let Some(advance) = space_stand_in(ch).and_then(|ch| get_char_advance(ch, fonts, style)) {
+        return Some(advance);
+    }
+    if ch.is_whitespace() {
+        let face = fonts.face_for(ch);
+        let advance = fontmesh::glyph_advance(face, ch)
+            .map(|advance| advance * style.font_size)
+            .or(style.whitespace_advance)
+            .unwrap_or_else(|| space_advance(face) * style.font_size);
+        return Some(advance + style.word_spacing);
+    }
+
+    let (face, source) = fonts.resolve(ch, style.missing_glyph)?;
+    let advance = match source {
+        GlyphSource::Char(ch) | GlyphSource::SmallCap(ch) => get_glyph_advance(ch, face),
+        GlyphSource::Glyph(glyph_id) => get_glyph_id_advance(glyph_id, face),
+        GlyphSource::Notdef => notdef_advance(face),
+    };
+    Some(advance * style.font_size * source.scale(style))
+}
+
+/// Helper function to get the advance width for a character
+#[inline]
+fn get_glyph_advance(ch: char, face: &fontmesh::Face) -> f32 {
+    fontmesh::glyph_advance(face, ch).unwrap_or(0.0)
+}
+
+/// Helper function to get the advance width for a glyph by its id
+#[inline]
+fn get_glyph_id_advance(glyph_id: ttf_parser::GlyphId, face: &fontmesh::Face) -> f32 {
+    face.glyph_hor_advance(glyph_id).unwrap_or(0) as f32 / face.units_per_em() as f32
+}
+
+/// Width of a space in `face`, used for whitespace characters it has no glyph for.
+///
+/// Takes the advance of the font's space, then of its `n`, and only guesses from the
+/// font height if it has neither.
+pub(crate) fn space_advance(face: &fontmesh::Face) -> f32 {
+    fontmesh::glyph_advance(face, ' ')
+        .or_else(|| fontmesh::glyph_advance(face, 'n'))
+        .unwrap_or_else(|| (fontmesh::ascender(face) - fontmesh::descender(face)) * 0.25)
+}
+
+/// Helper function to calculate the X offset based on text justification
+#[inline]
+pub(crate) fn calculate_justification_offset(justify: JustifyText, line_width: f32) -> f32 {
+    match justify {
+        JustifyText::Left | JustifyText::Justified => 0.0,
+        JustifyText::Center => -line_width * 0.5,
+        JustifyText::Right => -line_width,
+    }
+}
+
+/// Where a line of `line_width` starts after justification: the X of its left edge,
+/// or the Y of the top of a vertical column.
+#[inline]
+pub(crate) fn calculate_line_start(style: &TextMeshStyle, line_width: f32) -> f32 {
+    let offset = calculate_justification_offset(style.justify, line_width);
+    if style.orientation.is_vertical() {
+        -offset
+    } else {
+        offset
+    }
+}
+
+/// Offset from the origin of one line to the next.
+#[inline]
+pub(crate) fn calculate_line_step(fonts: &FontChain, style: &TextMeshStyle) -> Vec3 {
+    style
+        .orientation
+        .line_step(calculate_line_height(fonts, style))
+}
+
+/// Distance between two consecutive baselines.
+///
+/// Uses the style's absolute override if set, otherwise the primary font's natural
+/// line height at the style's font size, scaled by its line spacing.
+#[inline]
+pub(crate) fn calculate_line_height(fonts: &FontChain, style: &TextMeshStyle) -> f32 {
+    if let Some(line_height) = style.line_height_override {
+        return line_height;
+    }
+
+    let face = fonts.primary();
+    let natural = fontmesh::ascender(face) - fontmesh::descender(face) + fontmesh::line_gap(face);
+    natural * style.font_size * style.line_spacing
+}
+
+/// Every visible glyph in a line, after justification and spacing.
+///
+/// Yields the face and glyph that the font chain and the style's missing-glyph policy
+/// resolve each character to, together with the offset of the glyph origin.
+pub(crate) fn line_glyphs<'a, 'f>(
+    line: &'a str,
+    ends_paragraph: bool,
+    fonts: &'a FontChain<'f>,
+    style: &'a TextMeshStyle,
+) -> impl Iterator<Item = LineGlyph<'a, 'f>> + 'a {
+    span_glyphs(
+        vec![LineSpan::new(line, fonts, style)],
+        ends_paragraph,
+        style,
+    )
+}
+
+/// Every visible glyph in a line made of `spans`, like [`line_glyphs`].
+///
+/// Each glyph records the span it's in, whose style it should be built with.
+pub(crate) fn span_glyphs<'a, 'f>(
+    spans: Vec<LineSpan<'a, 'f>>,
+    ends_paragraph: bool,
+    style: &'a TextMeshStyle,
+) -> impl Iterator<Item = LineGlyph<'a, 'f>> + 'a {
+    placed_spans(spans, ends_paragraph, style).filter_map(|(item, origin)| {
+        let (face, source) = item.glyph?;
+        Some(LineGlyph {
+            face,
+            source,
+            character: item.character,
+            index: item.index,
+            #[cfg(feature = "bevy")]
+            span: item.span,
+            x: origin.x + item.offset.x,
+            y: origin.y + item.offset.y,
+            advance: item.advance,
+        })
+    })
+}
+
+/// Every whitespace character in a line that takes up space, after justification and
+/// spacing.
+///
+/// Yields the character, the offset of its origin, and its advance including word
+/// spacing and justification.
+#[cfg(feature = "bevy")]
+pub(crate) fn line_whitespace<'a>(
+    line: &'a str,
+    ends_paragraph: bool,
+    fonts: &'a FontChain,
+    style: &'a TextMeshStyle,
+) -> impl Iterator<Item = (char, Vec2, f32)> + 'a {
+    placed_spans(
+        vec![LineSpan::new(line, fonts, style)],
+        ends_paragraph,
+        style,
+    )
+    .filter(|(item, _)| item.glyph.is_none() && item.character.is_whitespace())
+    .map(|(item, origin)| (item.character, origin, item.advance + item.stretch))
+}
+
+/// Position of the caret in front of every character of a line and at its end, after
+/// justification and spacing: an X, or a Y in a vertical column.
+fn line_carets(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> Vec<f32> {
+    let span = LineSpan::new(line, fonts, style);
+    let items = span_items(&[span], ends_paragraph, style);
+    let line_width = items_width(&items, &[span], style);
+    let start = calculate_line_start(style, line_width);
+    let vertical = style.orientation.is_vertical();
+    let direction = if vertical { -1.0 } else { 1.0 };
+
+    let mut carets = vec![None; line.chars().count() + 1];
+    let mut cursor = start;
+    for item in &items {
+        if let Some(caret) = carets.get_mut(item.index) {
+            caret.get_or_insert(cursor);
+        }
+        cursor += direction * (item_length(item, &span) + style.letter_spacing);
+    }
+
+    // Characters without an item of their own stop where the next one starts
+    let mut next = start + direction * line_width;
+    for caret in carets.iter_mut().rev() {
+        next = *caret.get_or_insert(next);
+    }
+    carets.into_iter().flatten().collect()
+}
+
+/// Every item of a line made of `spans` with the offset of its pen position, after
+/// justification and spacing.
+fn placed_spans<'a, 'f>(
+    spans: Vec<LineSpan<'a, 'f>>,
+    ends_paragraph: bool,
+    style: &'a TextMeshStyle,
+) -> impl Iterator<Item = (LineItem<'a, 'f>, Vec2)> + 'a {
+    let items = span_items(&spans, ends_paragraph, style);
+    let line_width = items_width(&items, &spans, style);
+    let mut cursor = calculate_line_start(style, line_width);
+    let vertical = style.orientation.is_vertical();
+
+    items.into_iter().map(move |item| {
+        let span = &spans[item.span];
+        let origin = if vertical {
+            // Glyphs hang from the pen, centered on the column
+            let face = item.glyph.map_or(span.fonts.primary(), |(face, _)| face);
+            let ascender = fontmesh::ascender(face) * span.style.font_size;
+            Vec2::new(-item.advance * 0.5, cursor - ascender)
+        } else {
+            Vec2::new(cursor, 0.0)
+        };
+        let step = item_length(&item, span) + style.letter_spacing;
+        cursor += if vertical { -step } else { step };
+        (item, origin)
+    })
+}
+
+/// Ascender and descender of the primary font at the style's font size, which bound
+/// every line box.
+pub(crate) fn vertical_extent(fonts: &FontChain, style: &TextMeshStyle) -> (f32, f32) {
+    let face = fonts.primary();
+    (
+        fontmesh::ascender(face) * style.font_size,
+        fontmesh::descender(face) * style.font_size,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::style::TextOrientation;
+    use crate::core::test_font;
+
+    fn vertical(orientation: TextOrientation) -> TextMeshStyle {
+        TextMeshStyle {
+            orientation,
+            ..Default::default()
+        }
+    }
+
+    fn shown(text: &str, style: &TextMeshStyle) -> Vec<String> {
+        let font = test_font();
+        let layout = layout_text(&font, text, style);
+        layout
+            .lines
+            .iter()
+            .map(|line| {
+                layout.glyphs[line.glyphs.clone()]
+                    .iter()
+                    .map(|glyph| glyph.character)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layout_places_glyphs_on_lines() {
+        let font = test_font();
+        let layout = layout_text(&font, "Hi there\nok", &TextMeshStyle::default());
+
+        // Whitespace takes up space but has no glyph
+        let characters: String = layout.glyphs.iter().map(|glyph| glyph.character).collect();
+        assert_eq!(characters, "Hithereok");
+        assert_eq!(layout.lines.len(), 2);
+        assert_eq!(layout.lines[0].glyphs, 0..7);
+        assert_eq!(layout.lines[1].glyphs, 7..9);
+
+        // Indices count the space and the line break
+        let o = &layout.glyphs[7];
+        assert_eq!((o.char_index, o.line_index), (9, 1));
+        assert_eq!(o.position, Vec2::new(0.0, -layout.line_height));
+        assert_eq!(layout.lines[1].baseline, -layout.line_height);
+
+        // Glyphs advance left to right
+        let first_line = &layout.glyphs[layout.lines[0].glyphs.clone()];
+        assert!(first_line
+            .windows(2)
+            .all(|pair| pair[1].position.x > pair[0].position.x));
+        assert_eq!(
+            layout.glyphs[0].advance,
+            font.glyph_metrics('H').unwrap().advance
+        );
+    }
+
+    #[test]
+    fn test_layout_bounds_and_justification() {
+        let font = test_font();
+        let style = TextMeshStyle {
+            justify: JustifyText::Center,
+            ..Default::default()
+        };
+        let layout = layout_text(&font, "wide line\nshort", &style);
+
+        for line in &layout.lines {
+            assert!((line.start + line.width * 0.5).abs() < 1e-5);
+        }
+        let widest = layout.lines[0].width;
+        assert!(widest > layout.lines[1].width);
+        assert!((layout.bounds.width() - widest).abs() < 1e-5);
+        assert!(layout.bounds.min.y < -layout.line_height);
+        assert!(layout.bounds.max.y > 0.0);
+    }
+
+    #[test]
+    fn test_layout_missing_glyphs_follow_the_policy() {
+        let font = test_font();
+        let skip = layout_text(&font, "a\u{E000}b", &TextMeshStyle::default());
+        assert_eq!(skip.glyphs.len(), 2);
+
+        let tofu = layout_text(
+            &font,
+            "a\u{E000}b",
+            &TextMeshStyle {
+                missing_glyph: MissingGlyphPolicy::Tofu,
+                ..Default::default()
+            },
+        );
+        assert_eq!(tofu.glyphs.len(), 3);
+        assert_eq!(tofu.glyphs[1].character, '\u{E000}');
+        assert_eq!(tofu.glyphs[1].glyph, None);
+        assert_eq!(tofu.glyphs[0].glyph, Some('a'));
+    }
+
+    #[test]
+    fn test_layout_of_invalid_font_is_empty() {
+        let font = FontMesh {
+            data: vec![0, 1, 2, 3].into(),
+            face_index: 0,
+        };
+        assert_eq!(
+            layout_text(&font, "Hello", &TextMeshStyle::default()),
+            TextLayout::default()
+        );
+    }
+
+    #[test]
+    fn test_layout_carets_and_selections() {
+        let font = test_font();
+        let layout = layout_text(&font, "Hé x\nok", &TextMeshStyle::default());
+        assert_eq!(layout.lines[0].bytes, 0..5);
+        assert_eq!(layout.lines[1].bytes, 6..8);
+
+        // Carets stand at glyph origins, spanning the line box
+        let e = layout.caret_rect(1).unwrap();
+        assert_eq!(e.min.x, layout.glyphs[1].position.x);
+        assert_eq!(e.width(), 0.0);
+        assert_eq!(e.max.y, layout.ascender);
+        assert_eq!(e.min.y, layout.descender);
+
+        // Inside the two-byte `é`, in front of it
+        assert_eq!(layout.caret_rect(2), Some(e));
+
+        // The line break and the end of the text end their lines
+        let line_end = layout.caret_rect(5).unwrap();
+        assert_eq!(
+            line_end.min.x,
+            layout.lines[0].start + layout.lines[0].width
+        );
+        let text_end = layout.caret_rect(8).unwrap();
+        assert_eq!(text_end.min.y, -layout.line_height + layout.descender);
+        assert_eq!(layout.caret_rect(9), None);
+
+        // A box per covered line, from caret to caret
+        let rects = layout.selection_rects(1..7);
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[0].min.x, e.min.x);
+        assert_eq!(rects[0].max.x, line_end.min.x);
+        assert_eq!(rects[1].min.x, layout.lines[1].start);
+        assert_eq!(rects[1].max.x, layout.caret_rect(7).unwrap().min.x);
+        assert!(layout.selection_rects(3..3).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "bevy")]
+    fn test_layout_caret_transform_stretches_a_unit_caret() {
+        let font = test_font();
+        let layout = layout_text(&font, "ab", &TextMeshStyle::default());
+        let rect = layout.caret_rect(1).unwrap();
+        let transform = layout.caret_transform(1).unwrap();
+        assert_eq!(transform.translation, rect.center().extend(0.0));
+        assert_eq!(transform.scale.y, rect.height());
+
+        // Vertical carets lie across their column
+        let vertical = TextMeshStyle {
+            orientation: TextOrientation::VerticalRightToLeft,
+            ..Default::default()
+        };
+        let layout = layout_text(&font, "ab", &vertical);
+        let rect = layout.caret_rect(1).unwrap();
+        assert_eq!(rect.height(), 0.0);
+        assert!(rect.min.y < 0.0);
+        let transform = layout.caret_transform(1).unwrap();
+        let across = transform.transform_point(Vec3::new(0.0, 0.5, 0.0))
+            - transform.transform_point(Vec3::new(0.0, -0.5, 0.0));
+        assert!((across - Vec3::X * rect.width()).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_layout_justified_lines_fill_the_max_width() {
+        let font = test_font();
+        let style = TextMeshStyle {
+            justify: JustifyText::Justified,
+            max_width: Some(10.0),
+            ..Default::default()
+        };
+        let layout = layout_text(&font, "a few words\nshort\n one gap \nlast line", &style);
+
+        // Lines with gaps between words reach both edges
+        assert_eq!(layout.lines[0].start, 0.0);
+        assert!((layout.lines[0].width - 10.0).abs() < 1e-4);
+        let last_glyph = &layout.glyphs[layout.lines[0].glyphs.end - 1];
+        assert!((last_glyph.position.x + last_glyph.advance - 10.0).abs() < 1e-4);
+        let gaps: Vec<f32> = layout.glyphs[layout.lines[0].glyphs.clone()]
+            .windows(2)
+            .filter(|pair| pair[1].char_index > pair[0].char_index + 1)
+            .map(|pair| pair[1].position.x - (pair[0].position.x + pair[0].advance))
+            .collect();
+        assert_eq!(gaps.len(), 2);
+        assert!((gaps[0] - gaps[1]).abs() < 1e-4, "gaps widen evenly");
+
+        // Single words stay on the left, and spaces at the ends aren't stretched
+        assert!(layout.lines[1].width < 10.0);
+        let third = &layout.glyphs[layout.lines[2].glyphs.clone()];
+        let space = font.glyph_metrics(' ').unwrap().advance;
+        assert!((third[0].position.x - space).abs() < 1e-4);
+        assert!((layout.lines[2].width - 10.0).abs() < 1e-4);
+
+        // The last line of the paragraph keeps its natural width, like single lines
+        let natural = |text| layout_text(&font, text, &TextMeshStyle::default()).lines[0].width;
+        assert_eq!(layout.lines[3].width, natural("last line"));
+        assert_eq!(
+            layout_text(&font, "a few words", &style).lines[0].width,
+            natural("a few words")
+        );
+
+        // An empty line ends a paragraph too
+        let paragraphs = layout_text(&font, "one two\nthree four\n\nfive six", &style);
+        assert!((paragraphs.lines[0].width - 10.0).abs() < 1e-4);
+        assert_eq!(paragraphs.lines[1].width, natural("three four"));
+        assert_eq!(paragraphs.lines[3].width, natural("five six"));
+
+        // Without a width to fill, justified text is left aligned
+        let unbounded = TextMeshStyle {
+            justify: JustifyText::Justified,
+            ..Default::default()
+        };
+        let plain = layout_text(&font, "a few words", &TextMeshStyle::default());
+        assert_eq!(layout_text(&font, "a few words", &unbounded), plain);
+    }
+
+    #[test]
+    fn test_glyphs_stack_down_a_centered_column() {
+        let font = test_font();
+        let metrics = font.font_metrics().unwrap();
+        let layout = layout_text(
+            &font,
+            "AWi",
+            &vertical(TextOrientation::VerticalRightToLeft),
+        );
+
+        let em_height = metrics.ascender - metrics.descender;
+        for (row, glyph) in layout.glyphs.iter().enumerate() {
+            // Every glyph is centered on the column, one em box below the previous one
+            assert!((glyph.position.x + glyph.advance * 0.5).abs() < 1e-5);
+            let top = glyph.position.y + metrics.ascender;
+            assert!((top + row as f32 * em_height).abs() < 1e-5);
+        }
+        assert!((layout.lines[0].width - 3.0 * em_height).abs() < 1e-5);
+        assert_eq!(layout.lines[0].start, 0.0);
+    }
+
+    #[test]
+    fn test_columns_advance_sideways() {
+        let font = test_font();
+        let right_to_left = layout_text(
+            &font,
+            "AB\nCD",
+            &vertical(TextOrientation::VerticalRightToLeft),
+        );
+        let left_to_right = layout_text(
+            &font,
+            "AB\nCD",
+            &vertical(TextOrientation::VerticalLeftToRight),
+        );
+
+        let line_height = right_to_left.line_height;
+        assert_eq!(right_to_left.lines[1].baseline, -line_height);
+        assert_eq!(left_to_right.lines[1].baseline, line_height);
+
+        // Both columns start at the same height
+        let (a, c) = (&right_to_left.glyphs[0], &right_to_left.glyphs[2]);
+        assert_eq!(c.position.y, a.position.y);
+        assert!(c.position.x < a.position.x);
+        let (a, c) = (&left_to_right.glyphs[0], &left_to_right.glyphs[2]);
+        assert!(c.position.x > a.position.x);
+
+        // The layout bounds span both columns
+        assert!(right_to_left.bounds.min.x < -line_height);
+        assert!(right_to_left.bounds.max.x > 0.0);
+        let column = right_to_left.lines[0].width;
+        assert!((right_to_left.bounds.height() - column).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_justification_aligns_columns_vertically() {
+        let font = test_font();
+        let style = |justify| TextMeshStyle {
+            justify,
+            ..vertical(TextOrientation::VerticalRightToLeft)
+        };
+        let top = layout_text(&font, "ABC\nD", &style(JustifyText::Left));
+        let center = layout_text(&font, "ABC\nD", &style(JustifyText::Center));
+        let bottom = layout_text(&font, "ABC\nD", &style(JustifyText::Right));
+
+        // The short column moves down next to the long one
+        let d = |layout: &TextLayout| layout.glyphs[3].position.y - layout.glyphs[0].position.y;
+        assert!(d(&top) > d(&center) && d(&center) > d(&bottom));
+        let line = &center.lines[1];
+        assert!((line.start - line.width / 2.0).abs() < 1e-5);
+        let line = &bottom.lines[1];
+        assert!((line.start - line.width).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_overflow_cuts_lines_at_their_character_limit() {
+        let truncate = TextMeshStyle {
+            overflow: TextOverflow::Truncate,
+            max_chars: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(shown("Hello world\nHi", &truncate), ["Hello", "Hi"]);
+
+        // The ellipsis counts as a character, and whitespace before it is dropped
+        let ellipsis = TextMeshStyle {
+            overflow: TextOverflow::Ellipsis,
+            max_chars: Some(7),
+            ..Default::default()
+        };
+        assert_eq!(shown("Hello world", &ellipsis), ["Hello…"]);
+        assert_eq!(shown("Hello", &ellipsis), ["Hello"]);
+
+        // Limits need an overflow to apply
+        let unlimited = TextMeshStyle {
+            max_chars: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(shown("Hello", &unlimited), ["Hello"]);
+    }
+
+    #[test]
+    fn test_overflow_fits_lines_and_their_ellipsis_into_the_width() {
+        let font = test_font();
+        let style = TextMeshStyle {
+            overflow: TextOverflow::Ellipsis,
+            max_width: Some(3.0),
+            justify: JustifyText::Right,
+            ..Default::default()
+        };
+        let layout = layout_text(&font, "PlayerWithALongName", &style);
+        let line = &layout.lines[0];
+        assert!(line.width <= 3.0 && line.width > 2.0);
+        assert_eq!(layout.glyphs.last().unwrap().character, '…');
+
+        // Cut lines are justified by their new width
+        assert_eq!(line.start, -line.width);
+        let characters = layout.glyphs.len();
+        assert!(characters < "PlayerWithALongName".len());
+
+        // The end of the text still has a caret, at the end of the ellipsis
+        let end = layout.caret_rect(19).unwrap();
+        assert!((end.min.x - (line.start + line.width)).abs() < 1e-4);
+    }
+}
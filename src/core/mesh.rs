@@ -0,0 +1,1042 @@
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::{FontChain, GlyphSource};
+use crate::core::layout::{
+    calculate_line_start, calculate_line_step, calculate_line_width, line_glyphs, paragraph_lines,
+};
+use crate::core::outline::{fill_boundary, GlyphOutline};
+use crate::core::style::{TextAnchor, TextMeshStyle, TextRenderMode};
+use crate::core::FontMesh;
+#[cfg(feature = "bevy")]
+use bevy::tasks::ComputeTaskPool;
+use bevy_math::{Vec2, Vec3};
+
+/// Helper function to calculate anchor offset for text positioning
+pub(crate) fn calculate_anchor_offset(
+    anchor: TextAnchor,
+    min_bound: Vec3,
+    max_bound: Vec3,
+) -> Vec3 {
+    let size = max_bound - min_bound;
+    let center = min_bound + size * 0.5;
+
+    match anchor {
+        TextAnchor::TopLeft => Vec3::new(-min_bound.x, -max_bound.y, 0.0),
+        TextAnchor::TopCenter => Vec3::new(-center.x, -max_bound.y, 0.0),
+        TextAnchor::TopRight => Vec3::new(-max_bound.x, -max_bound.y, 0.0),
+
+        TextAnchor::CenterLeft => Vec3::new(-min_bound.x, -center.y, 0.0),
+        TextAnchor::Center => Vec3::new(-center.x, -center.y, 0.0),
+        TextAnchor::CenterRight => Vec3::new(-max_bound.x, -center.y, 0.0),
+
+        TextAnchor::BottomLeft => Vec3::new(-min_bound.x, -min_bound.y, 0.0),
+        TextAnchor::BottomCenter => Vec3::new(-center.x, -min_bound.y, 0.0),
+        TextAnchor::BottomRight => Vec3::new(-max_bound.x, -min_bound.y, 0.0),
+
+        // Layouts put the first baseline at `y = 0`
+        TextAnchor::BaselineLeft => Vec3::new(-min_bound.x, 0.0, 0.0),
+        TextAnchor::BaselineCenter => Vec3::new(-center.x, 0.0, 0.0),
+        TextAnchor::BaselineRight => Vec3::new(-max_bound.x, 0.0, 0.0),
+
+        TextAnchor::Custom(pivot) => {
+            let pivot_pos = min_bound.truncate() + size.truncate() * pivot;
+            Vec3::new(-pivot_pos.x, -pivot_pos.y, 0.0)
+        }
+    }
+}
+
+/// Vertex buffers of generated text, independent of any engine.
+///
+/// Returned by [`build_text_mesh`] for use outside of Bevy, such as baking meshes in a
+/// build script. With the `bevy` feature, [`TextMeshData::into_mesh`] uploads them as
+/// a Bevy `Mesh`, the way the plugin does.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextMeshData {
+    /// Vertex positions.
+    pub vertices: Vec<[f32; 3]>,
+    /// Vertex normals, one per vertex.
+    pub normals: Vec<[f32; 3]>,
+    /// Texture coordinates, empty unless the style asks for side wall UVs or tangents.
+    pub uvs: Vec<[f32; 2]>,
+    /// Tangents with the handedness of the bitangent in `w`, empty unless the style asks
+    /// for them.
+    pub tangents: Vec<[f32; 4]>,
+    /// Linear RGBA vertex colors, empty unless the text has per-character colors.
+    pub colors: Vec<[f32; 4]>,
+    /// Triangle list indices into the vertices.
+    pub indices: Vec<u32>,
+}
+
+impl TextMeshData {
+    /// Append another buffer, translating its vertices by `offset`.
+    fn append(&mut self, other: &TextMeshData, offset: Vec3) {
+        let index_offset = self.vertices.len() as u32;
+        self.vertices.extend(
+            other
+                .vertices
+                .iter()
+                .map(|v| [v[0] + offset.x, v[1] + offset.y, v[2] + offset.z]),
+        );
+        self.normals.extend_from_slice(&other.normals);
+        self.uvs.extend_from_slice(&other.uvs);
+        self.tangents.extend_from_slice(&other.tangents);
+        self.colors.extend_from_slice(&other.colors);
+        self.indices
+            .extend(other.indices.iter().map(|i| i + index_offset));
+    }
+
+    /// Fill in [`tangents`](Self::tangents) from the positions, normals, and UVs.
+    ///
+    /// Tangents point along increasing `u`, averaged over the triangles sharing a vertex
+    /// by their area in UV space and made perpendicular to its normal. `w` is `-1.0` where the bitangent,
+    /// `normal.cross(tangent) * w`, has to be flipped to point along increasing `v`.
+    /// Buffers without UVs get no tangents.
+    pub(crate) fn generate_tangents(&mut self) {
+        self.tangents.clear();
+        if self.uvs.len() != self.vertices.len() {
+            return;
+        }
+
+        let mut u_directions = vec![Vec3::ZERO; self.vertices.len()];
+        let mut v_directions = vec![Vec3::ZERO; self.vertices.len()];
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| triangle[i] as usize);
+            let position = |i: usize| Vec3::from_array(self.vertices[i]);
+            let uv = |i: usize| Vec2::from_array(self.uvs[i]);
+            let (edge1, edge2) = (position(b) - position(a), position(c) - position(a));
+            let (uv1, uv2) = (uv(b) - uv(a), uv(c) - uv(a));
+
+            let determinant = uv1.perp_dot(uv2);
+            if determinant == 0.0 {
+                continue;
+            }
+            // Weighted by the triangle's UV area, so slivers barely count but still count
+            let orientation = determinant.signum();
+            let u_direction = (edge1 * uv2.y - edge2 * uv1.y) * orientation;
+            let v_direction = (edge2 * uv1.x - edge1 * uv2.x) * orientation;
+            for i in [a, b, c] {
+                u_directions[i] += u_direction;
+                v_directions[i] += v_direction;
+            }
+        }
+
+        self.tangents = self
+            .normals
+            .iter()
+            .zip(u_directions.iter().zip(&v_directions))
+            .map(|(normal, (&u_direction, &v_direction))| {
+                let normal = Vec3::from_array(*normal);
+                // Triangles without a UV gradient still need a tangent on their plane
+                let tangent = (u_direction - normal * normal.dot(u_direction))
+                    .try_normalize()
+                    .unwrap_or_else(|| normal.any_orthonormal_vector());
+                let handedness = if normal.cross(tangent).dot(v_direction) < 0.0 {
+                    -1.0
+                } else {
+                    1.0
+                };
+                tangent.extend(handedness).to_array()
+            })
+            .collect();
+    }
+}
+
+/// Mesh data for a single line of text, laid out on a baseline at `y = 0`.
+///
+/// The justification offset is already applied, so lines only need to be moved
+/// down by their line index and shifted by the text anchor. Vertical lines are columns
+/// centered on `x = 0` instead, moved sideways by their line index.
+#[derive(Clone, Default)]
+pub(crate) struct LineMeshData {
+    pub data: TextMeshData,
+    /// Bounds of the line geometry, or `None` if the line has no visible glyphs.
+    pub bounds: Option<(Vec3, Vec3)>,
+    /// Where the line starts after justification, the top of a vertical column.
+    #[cfg(feature = "bevy")]
+    pub start: f32,
+    /// Width of the line, including spacing between its characters, or the height of
+    /// a vertical column.
+    #[cfg(feature = "bevy")]
+    pub width: f32,
+    /// Index in the line of the character each glyph is drawn for, with the glyph's
+    /// number of vertices, in buffer order.
+    pub glyphs: Vec<(usize, usize)>,
+    /// Boundaries of the glyphs cut into the plaque of engraved text, in line space, or
+    /// `None` unless the text is [engraved](TextRenderMode::Engraved).
+    pub engraved: Option<Vec<Vec<Vec2>>>,
+}
+
+/// Offset of the origin of line `line_index`, for lines `line_step` apart.
+#[inline]
+pub(crate) fn line_offset(line_index: usize, line_step: Vec3) -> Vec3 {
+    line_step * line_index as f32
+}
+
+/// Lays out a single line of text and merges its extruded glyph meshes.
+pub(crate) fn build_line_data(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> LineMeshData {
+    if let TextRenderMode::Engraved { depth, margin } = style.render_mode {
+        return build_engraved_line_data(line, ends_paragraph, fonts, style, depth, margin);
+    }
+    layout_line(
+        line,
+        ends_paragraph,
+        fonts,
+        style,
+        |face, source| GlyphGeometry::new(face, source, style),
+        |bars| GlyphGeometry::decoration(bars, style),
+    )
+}
+
+/// Lays out a single line of engraved text as the cavity its merged glyphs cut.
+///
+/// The bounds reach `margin` past the glyphs and through the plaque, which
+/// [`stack_lines`] adds once the lines are stacked.
+fn build_engraved_line_data(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    depth: f32,
+    margin: f32,
+) -> LineMeshData {
+    let mut outline = GlyphOutline::default();
+    for line_glyph in line_glyphs(line, ends_paragraph, fonts, style) {
+        let origin = Vec2::new(line_glyph.x, line_glyph.y);
+        let Some(glyph) = GlyphOutline::for_style(line_glyph.face, line_glyph.source, style) else {
+            continue;
+        };
+        outline.contours.extend(
+            glyph
+                .contours
+                .into_iter()
+                .map(|contour| contour.into_iter().map(|point| point + origin).collect()),
+        );
+    }
+    let width = calculate_line_width(line, ends_paragraph, fonts, style);
+    if let Some(bars) = decoration_outline(fonts, style, calculate_line_start(style, width), width)
+    {
+        // Wind the bars like the glyphs, so the fill merges them instead of cutting
+        let reverse = outline.fill_on_right() != bars.fill_on_right();
+        outline
+            .contours
+            .extend(bars.contours.into_iter().map(|mut bar| {
+                if reverse {
+                    bar.reverse();
+                }
+                bar
+            }));
+    }
+    let outline = GlyphOutline {
+        contours: fill_boundary(&outline),
+    };
+
+    let thickness = style.depth.max(0.0);
+    let cavity = GlyphGeometry::engraved(
+        &outline,
+        depth.clamp(0.0, thickness),
+        style.side_shading.unwrap_or_default(),
+    )
+    .optimized(style);
+    let mut data = TextMeshData {
+        vertices: cavity.vertices.iter().map(|v| v.to_array()).collect(),
+        normals: cavity.normals.iter().map(|n| n.to_array()).collect(),
+        uvs: if style.has_uvs() {
+            glyph_uvs(&cavity)
+        } else {
+            Vec::new()
+        },
+        indices: cavity.indices,
+        ..Default::default()
+    };
+    if style.generate_tangents {
+        data.generate_tangents();
+    }
+
+    let bounds = outline
+        .contours
+        .iter()
+        .flatten()
+        .fold(None, |bounds: Option<(Vec2, Vec2)>, &point| {
+            Some(bounds.map_or((point, point), |(min, max)| {
+                (min.min(point), max.max(point))
+            }))
+        })
+        .map(|(min, max)| {
+            (
+                (min - margin).extend(-thickness),
+                (max + margin).extend(0.0),
+            )
+        });
+    LineMeshData {
+        data,
+        bounds,
+        #[cfg(feature = "bevy")]
+        start: calculate_line_start(style, width),
+        #[cfg(feature = "bevy")]
+        width,
+        glyphs: Vec::new(),
+        engraved: Some(outline.contours),
+    }
+}
+
+/// Lays out a single line of text as flat, zero-depth glyphs.
+#[cfg(feature = "bevy")]
+pub(crate) fn build_flat_line_data(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> LineMeshData {
+    layout_line(
+        line,
+        ends_paragraph,
+        fonts,
+        style,
+        |face, source| GlyphGeometry::flat(face, source, style),
+        |bars| GlyphGeometry::flat_decoration(bars, style),
+    )
+}
+
+/// Lays out a single line of text, merging the geometry `glyph` produces per character.
+///
+/// `glyph` is called with the face and glyph that the font chain and the style's
+/// missing-glyph policy resolve the character to. Long lines are tessellated in
+/// parallel, see [`build_glyphs`]. The style's decoration bars are built by `bars` and
+/// merged after the glyphs, tinted like the first character.
+fn layout_line(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    glyph: impl Fn(&fontmesh::Face, GlyphSource) -> Option<GlyphGeometry> + Sync,
+    bars: impl Fn(&GlyphOutline) -> GlyphGeometry,
+) -> LineMeshData {
+    let mut data = TextMeshData::default();
+    let mut glyphs = Vec::new();
+    let mut index_offset = 0;
+
+    // Bounds tracking
+    let mut min_bound = Vec3::splat(f32::MAX);
+    let mut max_bound = Vec3::splat(f32::MIN);
+    let mut append = |mesh: &GlyphGeometry, cursor_x: f32, cursor_y: f32| {
+        // Extend vertices and update bounds
+        data.vertices.extend(mesh.vertices.iter().map(|v| {
+            let pos = Vec3::new(v.x + cursor_x, v.y + cursor_y, v.z);
+            min_bound = min_bound.min(pos);
+            max_bound = max_bound.max(pos);
+            [pos.x, pos.y, pos.z]
+        }));
+
+        // Extend normals
+        data.normals
+            .extend(mesh.normals.iter().map(|n| [n.x, n.y, n.z]));
+
+        // Extend UVs, mapping glyphs built without them onto the glyph plane
+        if style.has_uvs() {
+            data.uvs.extend(glyph_uvs(mesh));
+        }
+
+        // Extend indices with offset
+        data.indices
+            .extend(mesh.indices.iter().map(|i| i + index_offset));
+
+        index_offset += mesh.vertices.len() as u32;
+    };
+
+    // Generate mesh for line
+    let placed: Vec<_> = line_glyphs(line, ends_paragraph, fonts, style).collect();
+    let meshes = build_glyphs(&placed, |line_glyph| {
+        glyph(line_glyph.face, line_glyph.source)
+    });
+    for (line_glyph, mesh) in placed.iter().zip(meshes) {
+        if let Some(mut mesh) = mesh {
+            mesh.handcraft(style, line_glyph.character, line_glyph.index);
+            append(&mesh, line_glyph.x, line_glyph.y);
+            glyphs.push((line_glyph.index, mesh.vertices.len()));
+        }
+    }
+
+    let width = calculate_line_width(line, ends_paragraph, fonts, style);
+    let start = calculate_line_start(style, width);
+    if let Some(outline) = decoration_outline(fonts, style, start, width) {
+        let mesh = bars(&outline);
+        append(&mesh, 0.0, 0.0);
+        glyphs.push((0, mesh.vertices.len()));
+    }
+
+    // Glyphs never share vertices, so each line can get its tangents on its own
+    if style.generate_tangents {
+        data.generate_tangents();
+    }
+
+    let bounds = (!data.vertices.is_empty()).then_some((min_bound, max_bound));
+    LineMeshData {
+        data,
+        bounds,
+        #[cfg(feature = "bevy")]
+        start,
+        #[cfg(feature = "bevy")]
+        width,
+        glyphs,
+        engraved: None,
+    }
+}
+
+/// Bars of the style's decorations along a horizontal line that starts at `start` and
+/// is `width` long, or `None` if the line has no decorations.
+///
+/// Bars are placed by the primary font's underline and strikeout metrics, falling back
+/// to common proportions for fonts without them.
+fn decoration_outline(
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    start: f32,
+    width: f32,
+) -> Option<GlyphOutline> {
+    let decorations = &style.decorations;
+    if !decorations.any() || width <= 0.0 || style.orientation.is_vertical() {
+        return None;
+    }
+
+    // Top and thickness of each bar, in ems
+    let face = fonts.primary();
+    let em = |units: i16| units as f32 / face.units_per_em() as f32;
+    let metrics = |metrics: Option<ttf_parser::LineMetrics>| {
+        metrics.map(|metrics| (em(metrics.position), em(metrics.thickness)))
+    };
+    let (underline_top, underline) = metrics(face.underline_metrics()).unwrap_or((-0.1, 0.05));
+    let (strikeout_top, strikeout) = metrics(face.strikeout_metrics()).unwrap_or_else(|| {
+        let middle = face.x_height().map_or(0.25, |height| em(height) * 0.5);
+        (middle + underline * 0.5, underline)
+    });
+    let ascender = fontmesh::ascender(face);
+
+    let mut contours = Vec::new();
+    let mut bar = |enabled: bool, top: f32, font_thickness: f32| {
+        let thickness = decorations
+            .thickness
+            .unwrap_or(font_thickness * style.font_size);
+        if !enabled || thickness <= 0.0 {
+            return;
+        }
+        let middle = (top - font_thickness * 0.5) * style.font_size;
+        let (bottom, top) = (middle - thickness * 0.5, middle + thickness * 0.5);
+        contours.push(vec![
+            Vec2::new(start, bottom),
+            Vec2::new(start + width, bottom),
+            Vec2::new(start + width, top),
+            Vec2::new(start, top),
+        ]);
+    };
+    bar(decorations.underline, underline_top, underline);
+    bar(decorations.strikethrough, strikeout_top, strikeout);
+    bar(decorations.overline, ascender, underline);
+    Some(GlyphOutline { contours })
+}
+
+/// Glyphs a line needs before it is tessellated in parallel.
+#[cfg(feature = "bevy")]
+const PARALLEL_GLYPHS: usize = 64;
+
+/// Build the geometry of every glyph of a line, in order.
+///
+/// With the `bevy` feature, long lines are split into one chunk per thread of Bevy's
+/// compute task pool, and the chunks are tessellated at the same time. Short lines, and
+/// apps without the pool, build their glyphs one after another. This is the only use of
+/// Bevy's runtime in [`core`](crate::core), see its module docs.
+fn build_glyphs<T: Sync>(
+    glyphs: &[T],
+    build: impl Fn(&T) -> Option<GlyphGeometry> + Sync,
+) -> Vec<Option<GlyphGeometry>> {
+    #[cfg(feature = "bevy")]
+    if let Some(pool) = ComputeTaskPool::try_get()
+        .filter(|pool| pool.thread_num() > 1 && glyphs.len() >= PARALLEL_GLYPHS)
+    {
+        let chunk_size = glyphs.len().div_ceil(pool.thread_num());
+        let build = &build;
+        return pool
+            .scope(|scope| {
+                for chunk in glyphs.chunks(chunk_size) {
+                    scope.spawn(async move { chunk.iter().map(build).collect::<Vec<_>>() });
+                }
+            })
+            .into_iter()
+            .flatten()
+            .collect();
+    }
+    glyphs.iter().map(build).collect()
+}
+
+/// Vertex colors of stacked lines, giving each glyph the color of its character.
+///
+/// `lines` are the text of each line with its geometry. `colors` holds one color per
+/// character of the whole text, counting line breaks; characters past its end are
+/// white.
+pub(crate) fn line_colors<'a>(
+    lines: impl IntoIterator<Item = (&'a str, &'a LineMeshData)>,
+    colors: &[[f32; 4]],
+) -> Vec<[f32; 4]> {
+    let mut vertex_colors = Vec::new();
+    let mut line_start = 0;
+    for (text, line) in lines {
+        for &(index, vertex_count) in &line.glyphs {
+            let color = colors.get(line_start + index).copied().unwrap_or([1.0; 4]);
+            vertex_colors.extend(std::iter::repeat_n(color, vertex_count));
+        }
+        // The line break counts as a character too
+        line_start += text.chars().count() + 1;
+    }
+    vertex_colors
+}
+
+/// Texture coordinates of a glyph, falling back to its XY position when it has none.
+pub(crate) fn glyph_uvs(glyph: &GlyphGeometry) -> Vec<[f32; 2]> {
+    if glyph.uvs.len() == glyph.vertices.len() {
+        glyph.uvs.iter().map(|uv| [uv.x, uv.y]).collect()
+    } else {
+        glyph.vertices.iter().map(|v| [v.x, v.y]).collect()
+    }
+}
+
+/// Combined bounds of laid-out lines, or `None` if none of them has geometry.
+pub(crate) fn lines_bounds<'a>(
+    lines: impl IntoIterator<Item = &'a LineMeshData>,
+    line_step: Vec3,
+) -> Option<(Vec3, Vec3)> {
+    lines
+        .into_iter()
+        .enumerate()
+        .filter_map(|(line_index, line)| {
+            let offset = line_offset(line_index, line_step);
+            line.bounds.map(|(min, max)| (min + offset, max + offset))
+        })
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+}
+
+/// Offset that moves the text so the anchor point lands on the origin.
+pub(crate) fn lines_anchor_offset<'a>(
+    lines: impl IntoIterator<Item = &'a LineMeshData>,
+    line_step: Vec3,
+    anchor: TextAnchor,
+) -> Vec3 {
+    lines_bounds(lines, line_step)
+        .map(|(min_bound, max_bound)| calculate_anchor_offset(anchor, min_bound, max_bound))
+        .unwrap_or(Vec3::ZERO)
+}
+
+/// Merges laid-out lines into one buffer, stacking them and applying the anchor offset.
+pub(crate) fn assemble_lines<'a>(
+    lines: impl IntoIterator<Item = &'a LineMeshData> + Clone,
+    line_step: Vec3,
+    anchor: TextAnchor,
+) -> TextMeshData {
+    let anchor_offset = lines_anchor_offset(lines.clone(), line_step, anchor);
+    stack_lines(lines, line_step, anchor_offset)
+}
+
+/// Merges laid-out lines into one buffer, stacking them and moving them by `offset`.
+///
+/// Engraved lines get the plaque they are cut into, spanning the bounds of all lines.
+pub(crate) fn stack_lines<'a>(
+    lines: impl IntoIterator<Item = &'a LineMeshData>,
+    line_step: Vec3,
+    offset: Vec3,
+) -> TextMeshData {
+    let mut data = TextMeshData::default();
+    let mut bounds: Option<(Vec3, Vec3)> = None;
+    let mut holes = Vec::new();
+    let mut engraved = false;
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line_offset = line_offset(line_index, line_step) + offset;
+        data.append(&line.data, line_offset);
+
+        let Some(contours) = &line.engraved else {
+            continue;
+        };
+        engraved = true;
+        if let Some((min, max)) = line.bounds {
+            let (min, max) = (min + line_offset, max + line_offset);
+            bounds = Some(bounds.map_or((min, max), |(a, b)| (a.min(min), b.max(max))));
+        }
+        let line_offset = line_offset.truncate();
+        holes.extend(
+            contours
+                .iter()
+                .map(|contour| contour.iter().map(|point| *point + line_offset).collect()),
+        );
+    }
+
+    if let (true, Some((min, max))) = (engraved, bounds) {
+        let plaque = GlyphGeometry::plaque(min, max, holes);
+        let mut plaque = TextMeshData {
+            vertices: plaque.vertices.iter().map(|v| v.to_array()).collect(),
+            normals: plaque.normals.iter().map(|n| n.to_array()).collect(),
+            uvs: if data.uvs.is_empty() {
+                Vec::new()
+            } else {
+                glyph_uvs(&plaque)
+            },
+            indices: plaque.indices,
+            ..Default::default()
+        };
+        if !data.tangents.is_empty() {
+            plaque.generate_tangents();
+        }
+        if !data.colors.is_empty() {
+            plaque.colors = vec![[1.0; 4]; plaque.vertices.len()];
+        }
+        data.append(&plaque, Vec3::ZERO);
+    }
+    data
+}
+
+/// Generate the mesh of `text` without Bevy.
+///
+/// Runs the same layout, tessellation, and extrusion as the plugin, so the buffers
+/// match the mesh a [`TextMesh`](crate::TextMesh) with this text and style would get.
+/// Characters missing from `font` are looked up in `fallback_fonts`, in order, instead
+/// of the style's [`fallback_fonts`](TextMeshStyle::fallback_fonts). Returns `None` if
+/// `font` can't be parsed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy_fontmesh::core::{build_text_mesh, FontMesh, TextMeshStyle};
+///
+/// // In a build script: bake a title mesh next to the game's assets
+/// let font = FontMesh {
+///     data: std::fs::read("assets/fonts/font.ttf").unwrap().into(),
+///     face_index: 0,
+/// };
+/// let mesh = build_text_mesh(&font, &[], "Game Over", &TextMeshStyle::default()).unwrap();
+/// println!("{} triangles", mesh.indices.len() / 3);
+/// ```
+pub fn build_text_mesh(
+    font: &FontMesh,
+    fallback_fonts: &[&FontMesh],
+    text: &str,
+    style: &TextMeshStyle,
+) -> Option<TextMeshData> {
+    let fonts = FontChain::parse(
+        std::iter::once(font)
+            .chain(fallback_fonts.iter().copied())
+            .map(|font| (&*font.data, font.face_index)),
+    )?
+    .with_style(style);
+    Some(build_text_mesh_data(text, &fonts, style, &[]))
+}
+
+/// Lays out every line of `text` and merges the glyph meshes into one anchored buffer.
+///
+/// Glyphs are tinted with `colors`, one per character, unless it is empty.
+pub(crate) fn build_text_mesh_data(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    colors: &[[f32; 4]],
+) -> TextMeshData {
+    // Split text into lines for justification
+    let lines: Vec<LineMeshData> = paragraph_lines(text)
+        .map(|(line, ends_paragraph)| build_line_data(line, ends_paragraph, fonts, style))
+        .collect();
+
+    let mut data = assemble_lines(&lines, calculate_line_step(fonts, style), style.anchor);
+    if !colors.is_empty() {
+        data.colors = line_colors(text.split('\n').zip(&lines), colors);
+    }
+    data
+}
+
+/// Lays out every line of `text` as flat, zero-depth glyphs for the 2D pipeline.
+///
+/// Uses the same justification and anchoring as the extruded path; only the glyph
+/// geometry differs.
+#[cfg(feature = "bevy")]
+pub(crate) fn build_text_mesh_2d_data(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> TextMeshData {
+    let lines: Vec<LineMeshData> = paragraph_lines(text)
+        .map(|(line, ends_paragraph)| build_flat_line_data(line, ends_paragraph, fonts, style))
+        .collect();
+
+    assemble_lines(&lines, calculate_line_step(fonts, style), style.anchor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layout::layout_text;
+    use crate::core::style::{BevelStyle, CaseTransform, TextDecorations, TextOrientation};
+    use crate::core::{extent, test_font};
+
+    /// Underline and strikeout metrics of the test font, in ems: `(top, thickness)`.
+    const UNDERLINE: (f32, f32) = (-40.0 / 2048.0, 90.0 / 2048.0);
+
+    const STRIKEOUT: (f32, f32) = (530.0 / 2048.0, 102.0 / 2048.0);
+
+    fn build(text: &str, style: TextMeshStyle) -> TextMeshData {
+        let style = TextMeshStyle {
+            anchor: TextAnchor::BaselineLeft,
+            ..style
+        };
+        build_text_mesh(&test_font(), &[], text, &style).unwrap()
+    }
+
+    fn decorated(decorations: TextDecorations) -> TextMeshStyle {
+        TextMeshStyle {
+            decorations,
+            ..Default::default()
+        }
+    }
+
+    fn points(data: &TextMeshData) -> Vec<Vec3> {
+        data.vertices
+            .iter()
+            .copied()
+            .map(Vec3::from_array)
+            .collect()
+    }
+
+    /// Whether some vertex lies at height `y`.
+    fn has_edge_at(data: &TextMeshData, y: f32) -> bool {
+        data.vertices.iter().any(|v| (v[1] - y).abs() < 1e-4)
+    }
+
+    const THICKNESS: f32 = 0.2;
+
+    const DEPTH: f32 = 0.05;
+
+    const MARGIN: f32 = 0.3;
+
+    fn engraved_style() -> TextMeshStyle {
+        TextMeshStyle {
+            depth: THICKNESS,
+            render_mode: TextRenderMode::Engraved {
+                depth: DEPTH,
+                margin: MARGIN,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Whether a triangle facing `+Z` at depth `z` covers `point` in the XY plane.
+    fn covers(data: &TextMeshData, z: f32, point: Vec2) -> bool {
+        let points = points(data);
+        data.indices.chunks_exact(3).any(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| triangle[k] as usize);
+            let flat = [a, b, c]
+                .iter()
+                .all(|&i| data.normals[i][2] > 0.99 && (points[i].z - z).abs() < 1e-4);
+            let [a, b, c] = [a, b, c].map(|i| points[i].truncate());
+            let sides = [(a, b), (b, c), (c, a)].map(|(p, q)| (q - p).perp_dot(point - p));
+            flat && (sides.iter().all(|&s| s >= 0.0) || sides.iter().all(|&s| s <= 0.0))
+        })
+    }
+
+    fn cased(transform_case: CaseTransform) -> TextMeshStyle {
+        TextMeshStyle {
+            transform_case,
+            anchor: TextAnchor::BaselineLeft,
+            ..Default::default()
+        }
+    }
+
+    const SMALL_CAPS: CaseTransform = CaseTransform::SmallCaps { scale: 0.7 };
+
+    #[test]
+    fn test_underline_follows_the_font_metrics() {
+        // Letters without descenders, so the underline is the lowest geometry
+        let plain = build("ace", TextMeshStyle::default());
+        let underlined = build(
+            "ace",
+            decorated(TextDecorations {
+                underline: true,
+                ..Default::default()
+            }),
+        );
+        assert!(underlined.vertices.len() > plain.vertices.len());
+        assert_eq!(underlined.vertices.len(), underlined.normals.len());
+
+        let (min, max) = extent(&points(&underlined));
+        let (plain_min, plain_max) = extent(&points(&plain));
+        let (top, thickness) = UNDERLINE;
+        assert!((min.y - (top - thickness)).abs() < 1e-4);
+        assert!(has_edge_at(&underlined, top));
+        // The bar spans the glyphs and is extruded as deep as they are
+        assert!(min.x <= plain_min.x + 1e-4 && max.x >= plain_max.x - 1e-4);
+        assert!((min.z - plain_min.z).abs() < 1e-4 && (max.z - plain_max.z).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_strikethrough_overline_and_thickness() {
+        let struck = build(
+            "ace",
+            decorated(TextDecorations {
+                strikethrough: true,
+                ..Default::default()
+            }),
+        );
+        let (top, thickness) = STRIKEOUT;
+        assert!(has_edge_at(&struck, top) && has_edge_at(&struck, top - thickness));
+
+        let overlined = build(
+            "ace",
+            decorated(TextDecorations {
+                overline: true,
+                ..Default::default()
+            }),
+        );
+        let ascender = test_font().font_metrics().unwrap().ascender;
+        assert!((extent(&points(&overlined)).1.y - ascender).abs() < 1e-4);
+
+        // A thicker underline stays centered where the font puts it
+        let thick = build(
+            "ace",
+            decorated(TextDecorations {
+                underline: true,
+                thickness: Some(0.2),
+                ..Default::default()
+            }),
+        );
+        let (top, thickness) = UNDERLINE;
+        let middle = top - thickness * 0.5;
+        assert!(has_edge_at(&thick, middle + 0.1) && has_edge_at(&thick, middle - 0.1));
+    }
+
+    #[test]
+    fn test_decorations_match_the_glyph_geometry() {
+        let underline = TextDecorations {
+            underline: true,
+            ..Default::default()
+        };
+        for style in [
+            TextMeshStyle {
+                depth: 0.3,
+                bevel: Some(BevelStyle::default()),
+                ..Default::default()
+            },
+            TextMeshStyle {
+                side_uvs: true,
+                ..Default::default()
+            },
+            TextMeshStyle {
+                depth: 0.0,
+                ..Default::default()
+            },
+        ] {
+            let plain = build("ace", style.clone());
+            let underlined = build(
+                "ace",
+                TextMeshStyle {
+                    decorations: underline,
+                    ..style
+                },
+            );
+            let ((min, max), (plain_min, plain_max)) =
+                (extent(&points(&underlined)), extent(&points(&plain)));
+            assert!((min.z - plain_min.z).abs() < 1e-4 && (max.z - plain_max.z).abs() < 1e-4);
+            assert_eq!(underlined.uvs.is_empty(), plain.uvs.is_empty());
+        }
+
+        // Engraved bars are cut with the glyphs, so the plaque reaches past them
+        let engraved = |decorations| {
+            build(
+                "ace",
+                TextMeshStyle {
+                    depth: 0.2,
+                    render_mode: TextRenderMode::Engraved {
+                        depth: 0.1,
+                        margin: 0.05,
+                    },
+                    decorations,
+                    ..Default::default()
+                },
+            )
+        };
+        let (top, thickness) = UNDERLINE;
+        let (min, _) = extent(&points(&engraved(underline)));
+        assert!((min.y - (top - thickness - 0.05)).abs() < 1e-4);
+        assert!(has_edge_at(&engraved(underline), top - thickness));
+        assert!(!has_edge_at(
+            &engraved(TextDecorations::default()),
+            top - thickness
+        ));
+    }
+
+    #[test]
+    fn test_decorations_skip_empty_lines_and_vertical_text() {
+        let underline = TextDecorations {
+            underline: true,
+            ..Default::default()
+        };
+        let empty = build("", decorated(underline));
+        assert!(empty.vertices.is_empty());
+
+        let vertical = |decorations| {
+            build(
+                "ace",
+                TextMeshStyle {
+                    orientation: TextOrientation::VerticalRightToLeft,
+                    decorations,
+                    ..Default::default()
+                },
+            )
+        };
+        assert_eq!(vertical(underline), vertical(TextDecorations::default()));
+    }
+
+    #[test]
+    fn test_glyphs_are_cut_into_the_plaque() {
+        let font = test_font();
+        let filled = build_text_mesh(&font, &[], "O", &TextMeshStyle::default()).unwrap();
+        let engraved = build_text_mesh(&font, &[], "O", &engraved_style()).unwrap();
+
+        // The plaque reaches the margin past the glyph, and through the whole thickness
+        let (glyph_min, glyph_max) = extent(&points(&filled));
+        let (min, max) = extent(&points(&engraved));
+        let size = (max - min).truncate();
+        let expected = (glyph_max - glyph_min).truncate() + Vec2::splat(2.0 * MARGIN);
+        assert!(
+            (size - expected).abs().max_element() < 1e-3,
+            "{size} {expected}"
+        );
+        assert!((min.z + THICKNESS).abs() < 1e-4);
+        assert!(max.z.abs() < 1e-4);
+
+        // Top-left anchored plaque: the glyph sits a margin in from the corner
+        let glyph = |point: Vec2| {
+            point - glyph_min.truncate()
+                + Vec2::new(MARGIN, -MARGIN)
+                + Vec2::new(0.0, -(glyph_max.y - glyph_min.y))
+        };
+        let middle = (glyph_min.y + glyph_max.y) * 0.5;
+        let stroke = glyph(Vec2::new(glyph_min.x + 0.01, middle));
+        let counter = glyph(Vec2::new((glyph_min.x + glyph_max.x) * 0.5, middle));
+        let border = Vec2::new(MARGIN * 0.5, -MARGIN * 0.5);
+
+        assert!(covers(&engraved, 0.0, border));
+        assert!(covers(&engraved, 0.0, counter));
+        assert!(!covers(&engraved, 0.0, stroke));
+        assert!(covers(&engraved, -DEPTH, stroke));
+        assert!(!covers(&engraved, -DEPTH, counter));
+    }
+
+    #[test]
+    fn test_cavity_walls_face_into_the_glyph() {
+        let font = test_font();
+        let engraved = build_text_mesh(&font, &[], "-", &engraved_style()).unwrap();
+        let (min, max) = extent(&points(&engraved));
+        let center = ((min + max) * 0.5).truncate();
+
+        // Walls inside the plaque face the center of the dash
+        let walls: Vec<(Vec3, Vec3)> = points(&engraved)
+            .into_iter()
+            .zip(&engraved.normals)
+            .map(|(position, normal)| (position, Vec3::from_array(*normal)))
+            .filter(|(position, normal)| {
+                normal.z.abs() < 1e-4 && position.z > -DEPTH - 1e-4 && position.z < -1e-4
+            })
+            .collect();
+        assert!(!walls.is_empty());
+        for (position, normal) in walls {
+            assert!(
+                normal.truncate().dot(center - position.truncate()) > 0.0,
+                "{position} {normal}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_uppercase_and_lowercase_draw_the_other_case() {
+        let font = test_font();
+        let build = |text, style| build_text_mesh(&font, &[], text, &style).unwrap();
+        let plain = TextMeshStyle::default;
+        assert_eq!(
+            build("Hello", cased(CaseTransform::Uppercase)),
+            build("HELLO", cased(CaseTransform::None))
+        );
+        assert_eq!(
+            build("Hello", cased(CaseTransform::Lowercase)),
+            build("hello", cased(CaseTransform::None))
+        );
+        assert_ne!(build("Hello", plain()), build("HELLO", plain()));
+
+        // The text keeps its characters, only the glyphs change
+        let layout = layout_text(&font, "Hi!", &cased(CaseTransform::Uppercase));
+        let glyphs: Vec<_> = layout
+            .glyphs
+            .iter()
+            .map(|glyph| (glyph.character, glyph.glyph, glyph.char_index))
+            .collect();
+        assert_eq!(
+            glyphs,
+            vec![
+                ('H', Some('H'), 0),
+                ('i', Some('I'), 1),
+                ('!', Some('!'), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_characters_without_a_single_other_case_stay() {
+        let font = test_font();
+        // `ß` capitalizes to two letters, which would shift every index after it
+        let layout = layout_text(&font, "ßa", &cased(CaseTransform::Uppercase));
+        let glyphs: Vec<_> = layout.glyphs.iter().map(|glyph| glyph.glyph).collect();
+        assert_eq!(glyphs, vec![Some('ß'), Some('A')]);
+        assert_eq!(layout.glyphs[1].char_index, 1);
+    }
+
+    #[test]
+    fn test_small_caps_shrink_lowercase_letters() {
+        let font = test_font();
+        let layout = layout_text(&font, "Ab", &cased(SMALL_CAPS));
+        let capitals = layout_text(&font, "AB", &cased(CaseTransform::None));
+        assert_eq!(layout.glyphs[1].glyph, Some('B'));
+        assert_eq!(layout.glyphs[0].advance, capitals.glyphs[0].advance);
+        assert!((layout.glyphs[1].advance - capitals.glyphs[1].advance * 0.7).abs() < 1e-5);
+        assert_eq!(
+            layout.lines[0].width,
+            layout.glyphs[0].advance + layout.glyphs[1].advance
+        );
+
+        // The small capital is the capital scaled around its origin, and stays as deep
+        let glyph = |text, style| {
+            let data = build_text_mesh(&font, &[], text, &style).unwrap();
+            let points: Vec<Vec3> = data.vertices.into_iter().map(Vec3::from_array).collect();
+            extent(&points)
+        };
+        let (min, max) = glyph("b", cased(SMALL_CAPS));
+        let (full_min, full_max) = glyph("B", cased(CaseTransform::None));
+        assert!((max.y - full_max.y * 0.7).abs() < 1e-4);
+        assert!(((max.x - min.x) - (full_max.x - full_min.x) * 0.7).abs() < 1e-4);
+        assert!((max.z - min.z - (full_max.z - full_min.z)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_core_handles_fonts_that_fail_to_parse() {
+        let style = TextMeshStyle::default();
+        let font = test_font();
+        let broken = FontMesh {
+            data: Vec::new().into(),
+            face_index: 0,
+        };
+
+        // Unparseable fallbacks are left out, an unparseable primary font gives no mesh
+        let alone = build_text_mesh(&font, &[], "Hi", &style).unwrap();
+        let with_broken = build_text_mesh(&font, &[&broken], "Hi", &style).unwrap();
+        assert_eq!(alone, with_broken);
+        assert!(build_text_mesh(&broken, &[&font], "Hi", &style).is_none());
+
+        // Characters the primary font has never come from a fallback
+        let with_fallback = build_text_mesh(&font, &[&test_font()], "Hi", &style).unwrap();
+        assert_eq!(alone, with_fallback);
+    }
+}
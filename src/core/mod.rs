@@ -0,0 +1,101 @@
+//! Engine-agnostic text layout, tessellation, and font metrics.
+//!
+//! Everything that turns a string into vertex buffers lives here, without the ECS,
+//! assets, or rendering: fonts parsed from their bytes, the glyph layout, and the
+//! extruded and bevelled geometry. The Bevy components and systems of this crate are
+//! built on the same functions, so meshes baked ahead of time in a build script or an
+//! asset pipeline match the ones the plugin generates at runtime.
+//!
+//! This module is the only part of the crate that is built without the default `bevy`
+//! feature, depending on `bevy_math` for its vector types alone. Other engines and
+//! tools can use it with:
+//!
+//! ```toml
+//! [dependencies]
+//! bevy_fontmesh = { version = "0.1", default-features = false }
+//! ```
+//!
+//! With the feature, the module reaches into Bevy in one place besides its types: long
+//! lines are tessellated in parallel on Bevy's `ComputeTaskPool` when an app, or the
+//! caller, has initialized it. The meshes are the same either way, and without the
+//! pool or the feature every glyph is built on the calling thread.
+//!
+//! Style fields that refer to Bevy assets, such as
+//! [`TextMeshStyle::fallback_fonts`], stay empty without the feature; fallback fonts
+//! are passed to [`build_text_mesh`] directly instead.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use bevy_fontmesh::core::{build_text_mesh, layout_text, FontMesh, TextMeshStyle};
+//!
+//! let font = FontMesh {
+//!     data: std::fs::read("assets/fonts/font.ttf").unwrap().into(),
+//!     face_index: 0,
+//! };
+//! let style = TextMeshStyle::default();
+//!
+//! // Glyph positions only
+//! let layout = layout_text(&font, "Score", &style);
+//! println!("{} glyphs, {} high", layout.glyphs.len(), layout.bounds.height());
+//!
+//! // The extruded mesh, as the plugin would build it
+//! let mesh = build_text_mesh(&font, &[], "Score", &style).unwrap();
+//! println!("{} vertices", mesh.vertices.len());
+//! ```
+
+pub(crate) mod extrude;
+pub(crate) mod fallback;
+mod figures;
+mod font;
+pub(crate) mod layout;
+pub(crate) mod mesh;
+pub(crate) mod outline;
+#[cfg(feature = "shaping")]
+mod shaping;
+pub(crate) mod style;
+pub(crate) mod stylistic_set;
+
+pub use figures::{pad_figures, FIGURE_SPACE, PUNCTUATION_SPACE};
+#[cfg(feature = "default-font")]
+pub use font::DEFAULT_FONT_DATA;
+pub use font::{FontMesh, FontMetrics, GlyphMetrics};
+pub use layout::{layout_text, LayoutLine, PositionedGlyph, TextLayout};
+pub use mesh::{build_text_mesh, TextMeshData};
+pub use style::{
+    BevelProfile, BevelStyle, CaseTransform, ExtrusionProfile, FallbackFonts, GlyphPivot,
+    Handcrafted, JustifyText, MissingGlyphPolicy, SideShading, TextAnchor, TextDecorations,
+    TextMeshStyle, TextOrientation, TextOverflow, TextRenderMode,
+};
+pub use stylistic_set::StylisticSet;
+/// Id of a glyph in a font, as used by [`FontMesh::glyph_metrics_by_id`] and text shapers.
+pub use ttf_parser::GlyphId;
+/// Tag of a variable font axis, as used by [`TextMeshStyle::variations`].
+pub use ttf_parser::Tag;
+
+/// The font the unit tests lay out and tessellate.
+#[cfg(test)]
+pub(crate) fn test_font() -> FontMesh {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/fonts/FiraMono-Medium.ttf"
+    );
+    FontMesh {
+        data: std::fs::read(path)
+            .expect("The test font should exist")
+            .into(),
+        face_index: 0,
+    }
+}
+
+/// Smallest and largest corner of the box around `points`.
+#[cfg(test)]
+pub(crate) fn extent(points: &[bevy_math::Vec3]) -> (bevy_math::Vec3, bevy_math::Vec3) {
+    points.iter().fold(
+        (
+            bevy_math::Vec3::splat(f32::MAX),
+            bevy_math::Vec3::splat(f32::MIN),
+        ),
+        |(min, max), &point| (min.min(point), max.max(point)),
+    )
+}
@@ -0,0 +1,621 @@
+use crate::core::extrude::tessellate_outline;
+use crate::core::fallback::GlyphSource;
+use crate::core::style::TextMeshStyle;
+use bevy_math::Vec2;
+use std::collections::HashMap;
+
+/// Flattened outline of a single glyph, in em-normalized units.
+///
+/// Each contour is a closed polyline (the closing point is not repeated). Curves are
+/// approximated with `subdivision` straight segments, matching the meaning of
+/// [`TextMeshStyle::subdivision`](crate::TextMeshStyle::subdivision), or with as many
+/// as they need to stay within a `tolerance` when one is given.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GlyphOutline {
+    pub contours: Vec<Vec<Vec2>>,
+}
+
+impl GlyphOutline {
+    /// Extract and flatten the outline of `character`.
+    ///
+    /// Returns `None` if the character is missing from the font or has no outline.
+    pub fn new(face: &fontmesh::Face, character: char, subdivision: u8) -> Option<Self> {
+        Self::from_glyph(face, face.glyph_index(character)?, subdivision, None)
+    }
+
+    /// Extract and flatten the outline a [`GlyphSource`] resolves to.
+    pub fn from_source(
+        face: &fontmesh::Face,
+        glyph: GlyphSource,
+        subdivision: u8,
+        tolerance: Option<f32>,
+    ) -> Option<Self> {
+        match glyph {
+            GlyphSource::Char(character) | GlyphSource::SmallCap(character) => {
+                Self::from_glyph(face, face.glyph_index(character)?, subdivision, tolerance)
+            }
+            GlyphSource::Glyph(glyph_id) => {
+                Self::from_glyph(face, glyph_id, subdivision, tolerance)
+            }
+            GlyphSource::Notdef => Some(Self::notdef(face, subdivision, tolerance)),
+        }
+    }
+
+    /// Extract and flatten the outline a [`GlyphSource`] resolves to with the style's
+    /// subdivision or [`curve_tolerance`](TextMeshStyle::curve_tolerance), sized by its
+    /// [`font_size`](TextMeshStyle::font_size) or shrunk to a small capital, and
+    /// emboldened and slanted as it asks.
+    pub fn for_style(
+        face: &fontmesh::Face,
+        glyph: GlyphSource,
+        style: &TextMeshStyle,
+    ) -> Option<Self> {
+        let outline = Self::from_source(face, glyph, style.subdivision, style.curve_tolerance)?;
+        let size = style.font_size * glyph.scale(style);
+        Some(outline.scaled(size).synthesized(style, size))
+    }
+
+    /// Apply the style's [`synthetic_bold`](TextMeshStyle::synthetic_bold) and
+    /// [`synthetic_italic`](TextMeshStyle::synthetic_italic) to an outline drawn at `size`.
+    ///
+    /// The outline is pushed outward first, by the emboldening in ems of the glyph's own
+    /// size, so shrunk small capitals keep the stroke weight of the letters around them.
+    /// It is then sheared around the baseline.
+    fn synthesized(mut self, style: &TextMeshStyle, size: f32) -> Self {
+        if style.synthetic_bold != 0.0 {
+            self = self.inset(-style.synthetic_bold * size);
+        }
+        if style.synthetic_italic != 0.0 {
+            for point in self.contours.iter_mut().flatten() {
+                point.x += point.y * style.synthetic_italic;
+            }
+        }
+        self
+    }
+
+    /// Scale every contour around the glyph origin.
+    pub fn scaled(mut self, factor: f32) -> Self {
+        if factor != 1.0 {
+            for point in self.contours.iter_mut().flatten() {
+                *point *= factor;
+            }
+        }
+        self
+    }
+
+    /// Outline of the font's `.notdef` glyph, the box drawn for unmapped characters.
+    ///
+    /// Fonts whose `.notdef` glyph is empty get a hollow rectangle spanning
+    /// [`notdef_advance`] instead.
+    pub fn notdef(face: &fontmesh::Face, subdivision: u8, tolerance: Option<f32>) -> Self {
+        Self::from_glyph(face, ttf_parser::GlyphId(0), subdivision, tolerance)
+            .unwrap_or_else(|| Self::tofu_box(face))
+    }
+
+    /// Extract and flatten the outline of a glyph by its id.
+    pub fn from_glyph(
+        face: &fontmesh::Face,
+        glyph_id: ttf_parser::GlyphId,
+        subdivision: u8,
+        tolerance: Option<f32>,
+    ) -> Option<Self> {
+        let mut builder = OutlineFlattener {
+            scale: 1.0 / face.units_per_em() as f32,
+            segments: subdivision.max(1) as usize,
+            tolerance: tolerance.filter(|tolerance| *tolerance > 0.0),
+            contours: Vec::new(),
+            current: Vec::new(),
+        };
+        face.outline_glyph(glyph_id, &mut builder)?;
+        builder.finish_contour();
+
+        if builder.contours.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            contours: builder.contours,
+        })
+    }
+
+    /// Hollow rectangle used when the font has no `.notdef` outline.
+    fn tofu_box(face: &fontmesh::Face) -> Self {
+        let advance = notdef_advance(face);
+        let (x0, x1) = (advance * 0.1, advance * 0.9);
+        let (y0, y1) = (0.0, fontmesh::ascender(face) * 0.8);
+        let stroke = advance * 0.08;
+
+        // Clockwise outer contour and counter-clockwise hole, like TrueType outlines
+        let outer = vec![
+            Vec2::new(x0, y0),
+            Vec2::new(x0, y1),
+            Vec2::new(x1, y1),
+            Vec2::new(x1, y0),
+        ];
+        let (x0, x1, y0, y1) = (x0 + stroke, x1 - stroke, y0 + stroke, y1 - stroke);
+        let inner = vec![
+            Vec2::new(x0, y0),
+            Vec2::new(x1, y0),
+            Vec2::new(x1, y1),
+            Vec2::new(x0, y1),
+        ];
+
+        Self {
+            contours: vec![outer, inner],
+        }
+    }
+
+    /// Whether the filled area lies to the right of each contour's direction of travel.
+    ///
+    /// TrueType outlines wind outer contours clockwise (fill on the right), while
+    /// PostScript-style outlines use the opposite convention. The dominant winding of
+    /// the whole glyph tells us which one this font follows.
+    pub fn fill_on_right(&self) -> bool {
+        self.contours.iter().map(|c| signed_area(c)).sum::<f32>() < 0.0
+    }
+
+    /// Reverse contours as needed so the filled area always lies to their left.
+    ///
+    /// Outer contours then run counter-clockwise and holes clockwise, whatever
+    /// convention the font uses.
+    pub fn fill_on_left(mut self) -> Self {
+        if self.fill_on_right() {
+            for contour in &mut self.contours {
+                contour.reverse();
+            }
+        }
+        self
+    }
+
+    /// Offset every contour towards the filled area by `distance`.
+    ///
+    /// Vertices are moved along the miter direction of their two adjacent edges.
+    /// The miter length is clamped so sharp corners don't produce long spikes.
+    pub fn inset(&self, distance: f32) -> Self {
+        if distance == 0.0 {
+            return self.clone();
+        }
+
+        let fill_on_right = self.fill_on_right();
+        let contours = self
+            .contours
+            .iter()
+            .map(|contour| {
+                contour
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &point)| {
+                        point + vertex_inward_offset(contour, i, fill_on_right) * distance
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { contours }
+    }
+
+    /// Band of `width` centered on every contour, as closed contours of its own.
+    ///
+    /// Each contour becomes a ring between its outward and inward offsets. Rings run
+    /// counter-clockwise on the outside and clockwise on the inside, so overlapping
+    /// bands merge when filled with the non-zero rule.
+    pub fn stroke(&self, width: f32) -> Self {
+        let half = width.max(0.0) * 0.5;
+        let outer = self.inset(-half);
+        let inner = self.inset(half);
+        let contours = outer
+            .contours
+            .into_iter()
+            .zip(inner.contours)
+            .flat_map(|(a, b)| {
+                let (mut outside, mut inside) = if signed_area(&a).abs() >= signed_area(&b).abs() {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                if signed_area(&outside) < 0.0 {
+                    outside.reverse();
+                }
+                if signed_area(&inside) > 0.0 {
+                    inside.reverse();
+                }
+                [outside, inside]
+            })
+            .collect();
+
+        Self { contours }
+    }
+}
+
+/// Advance width of the `.notdef` glyph in ems.
+///
+/// Falls back to 0.6 em for fonts that give `.notdef` no advance.
+pub(crate) fn notdef_advance(face: &fontmesh::Face) -> f32 {
+    face.glyph_hor_advance(ttf_parser::GlyphId(0))
+        .filter(|&advance| advance > 0)
+        .map_or(0.6, |advance| advance as f32 / face.units_per_em() as f32)
+}
+
+/// Maximum miter length (relative to the offset distance) used when insetting corners.
+const MAX_MITER: f32 = 2.0;
+
+/// Direction and relative length for moving vertex `i` of `contour` into the filled area.
+pub(crate) fn vertex_inward_offset(contour: &[Vec2], i: usize, fill_on_right: bool) -> Vec2 {
+    let n = contour.len();
+    let prev = contour[(i + n - 1) % n];
+    let point = contour[i];
+    let next = contour[(i + 1) % n];
+
+    let n0 = edge_inward_normal(prev, point, fill_on_right);
+    let n1 = edge_inward_normal(point, next, fill_on_right);
+    let miter = (n0 + n1).normalize_or(n0);
+
+    let cos = miter.dot(n0).max(1.0 / MAX_MITER);
+    miter / cos
+}
+
+/// Unit normal of the edge `a -> b` pointing into the filled area.
+pub(crate) fn edge_inward_normal(a: Vec2, b: Vec2, fill_on_right: bool) -> Vec2 {
+    let dir = (b - a).normalize_or_zero();
+    if fill_on_right {
+        Vec2::new(dir.y, -dir.x)
+    } else {
+        Vec2::new(-dir.y, dir.x)
+    }
+}
+
+/// Points spaced evenly by arc length along a closed polyline, with their unit tangents.
+///
+/// The polyline gets `round(perimeter / spacing)` points (at least one), starting at
+/// its first vertex. Tangents follow the direction of travel along the polyline.
+pub(crate) fn sample_contour(contour: &[Vec2], spacing: f32) -> Vec<(Vec2, Vec2)> {
+    let edges: Vec<(Vec2, Vec2)> = contour
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| (a, contour[(i + 1) % contour.len()]))
+        .filter(|(a, b)| a != b)
+        .collect();
+    let perimeter: f32 = edges.iter().map(|(a, b)| a.distance(*b)).sum();
+    if perimeter <= 0.0 || spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    let count = ((perimeter / spacing).round() as usize).max(1);
+    let step = perimeter / count as f32;
+
+    let mut samples = Vec::with_capacity(count);
+    let mut edges = edges.iter();
+    let mut edge_start = 0.0;
+    let mut current = edges.next();
+    for k in 0..count {
+        let distance = k as f32 * step;
+        while let Some((a, b)) = current {
+            let length = a.distance(*b);
+            if distance <= edge_start + length {
+                let t = (distance - edge_start) / length;
+                samples.push((a.lerp(*b, t), (*b - *a) / length));
+                break;
+            }
+            edge_start += length;
+            current = edges.next();
+        }
+    }
+    samples
+}
+
+/// Boundary of the area an outline fills, with overlapping contours merged.
+///
+/// The outline is filled with the non-zero rule, and the boundary is traced back from
+/// the edges that belong to only one triangle of the fill. Outer boundaries wind
+/// counter-clockwise and the boundaries of counters clockwise, so the filled area
+/// always lies to the left.
+pub(crate) fn fill_boundary(outline: &GlyphOutline) -> Vec<Vec<Vec2>> {
+    if outline.contours.is_empty() {
+        return Vec::new();
+    }
+    let Some(buffers) = tessellate_outline(outline) else {
+        return Vec::new();
+    };
+
+    // The tessellator may emit the same position more than once, so weld vertices first
+    let mut welded: HashMap<[u32; 2], u32> = HashMap::new();
+    let vertex_ids: Vec<u32> = buffers
+        .vertices
+        .iter()
+        .map(|vertex| {
+            let next_id = welded.len() as u32;
+            *welded
+                .entry(vertex.to_array().map(f32::to_bits))
+                .or_insert(next_id)
+        })
+        .collect();
+    let mut positions = vec![Vec2::ZERO; welded.len()];
+    for (vertex, &id) in buffers.vertices.iter().zip(&vertex_ids) {
+        positions[id as usize] = *vertex;
+    }
+
+    // Directed edges of counter-clockwise triangles; interior edges appear in both directions
+    let mut edges: HashMap<(u32, u32), usize> = HashMap::new();
+    for triangle in buffers.indices.chunks_exact(3) {
+        let [mut a, mut b, c] = [0, 1, 2].map(|i| vertex_ids[triangle[i] as usize]);
+        if a == b || b == c || c == a {
+            continue;
+        }
+        let (pa, pb, pc) = (
+            positions[a as usize],
+            positions[b as usize],
+            positions[c as usize],
+        );
+        if (pb - pa).perp_dot(pc - pa) < 0.0 {
+            std::mem::swap(&mut a, &mut b);
+        }
+        for edge in [(a, b), (b, c), (c, a)] {
+            *edges.entry(edge).or_default() += 1;
+        }
+    }
+
+    let mut outgoing: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&(from, to), &count) in &edges {
+        let reverse = edges.get(&(to, from)).copied().unwrap_or(0);
+        for _ in reverse..count {
+            outgoing.entry(from).or_default().push(to);
+        }
+    }
+
+    // Chain the boundary edges into loops
+    let mut starts: Vec<u32> = outgoing.keys().copied().collect();
+    starts.sort_unstable();
+    let mut loops = Vec::new();
+    for start in starts {
+        while let Some(mut current) = outgoing.get_mut(&start).and_then(Vec::pop) {
+            let mut polygon = vec![positions[start as usize]];
+            while current != start {
+                polygon.push(positions[current as usize]);
+                match outgoing.get_mut(&current).and_then(Vec::pop) {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+
+            if polygon.len() >= 3 && signed_area(&polygon) != 0.0 {
+                loops.push(polygon);
+            }
+        }
+    }
+    loops
+}
+
+/// Signed area of a closed polyline (positive for counter-clockwise winding).
+pub(crate) fn signed_area(contour: &[Vec2]) -> f32 {
+    let n = contour.len();
+    (0..n)
+        .map(|i| contour[i].perp_dot(contour[(i + 1) % n]))
+        .sum::<f32>()
+        * 0.5
+}
+
+/// `ttf_parser` outline sink that flattens curves into polylines.
+/// Most segments a single curve is split into with a curve tolerance.
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+struct OutlineFlattener {
+    scale: f32,
+    segments: usize,
+    /// Largest distance between a curve and its segments, replacing the fixed segment
+    /// count when set.
+    tolerance: Option<f32>,
+    contours: Vec<Vec<Vec2>>,
+    current: Vec<Vec2>,
+}
+
+impl OutlineFlattener {
+    fn point(&self, x: f32, y: f32) -> Vec2 {
+        Vec2::new(x, y) * self.scale
+    }
+
+    fn last(&self) -> Vec2 {
+        self.current.last().copied().unwrap_or(Vec2::ZERO)
+    }
+
+    /// Segments for a curve whose second derivative is at most `curvature` long.
+    ///
+    /// A segment spanning `h` of the curve parameter strays at most `curvature * h² / 8`
+    /// from the curve, which the segment count keeps within the tolerance.
+    fn segments_for(&self, curvature: f32) -> usize {
+        let Some(tolerance) = self.tolerance else {
+            return self.segments;
+        };
+        ((curvature / (8.0 * tolerance)).sqrt().ceil() as usize).clamp(1, MAX_CURVE_SEGMENTS)
+    }
+
+    fn push(&mut self, point: Vec2) {
+        if self.current.last() != Some(&point) {
+            self.current.push(point);
+        }
+    }
+
+    fn finish_contour(&mut self) {
+        let mut contour = std::mem::take(&mut self.current);
+        if contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+        if contour.len() >= 3 {
+            self.contours.push(contour);
+        }
+    }
+}
+
+impl ttf_parser::OutlineBuilder for OutlineFlattener {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish_contour();
+        let p = self.point(x, y);
+        self.current.push(p);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.push(p);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last();
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x, y);
+        let segments = self.segments_for(2.0 * (p0 - 2.0 * p1 + p2).length());
+        for step in 1..=segments {
+            let t = step as f32 / segments as f32;
+            let mt = 1.0 - t;
+            self.push(p0 * (mt * mt) + p1 * (2.0 * mt * t) + p2 * (t * t));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last();
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x2, y2);
+        let p3 = self.point(x, y);
+        let curvature = (p0 - 2.0 * p1 + p2)
+            .length()
+            .max((p1 - 2.0 * p2 + p3).length());
+        let segments = self.segments_for(6.0 * curvature);
+        for step in 1..=segments {
+            let t = step as f32 / segments as f32;
+            let mt = 1.0 - t;
+            self.push(
+                p0 * (mt * mt * mt)
+                    + p1 * (3.0 * mt * mt * t)
+                    + p2 * (3.0 * mt * t * t)
+                    + p3 * (t * t * t),
+            );
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish_contour();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::mesh::{build_text_mesh, TextMeshData};
+    use crate::core::style::{CaseTransform, TextAnchor, TextMeshStyle};
+    use crate::core::{extent, test_font};
+    use bevy_math::{Vec2, Vec3};
+
+    fn build(text: &str, style: TextMeshStyle) -> TextMeshData {
+        let style = TextMeshStyle {
+            anchor: TextAnchor::BaselineLeft,
+            ..style
+        };
+        build_text_mesh(&test_font(), &[], text, &style).unwrap()
+    }
+
+    fn detail(subdivision: u8, curve_tolerance: Option<f32>) -> TextMeshStyle {
+        TextMeshStyle {
+            subdivision,
+            curve_tolerance,
+            ..Default::default()
+        }
+    }
+
+    fn bounds(data: &TextMeshData) -> (Vec3, Vec3) {
+        let points: Vec<Vec3> = data.vertices.iter().map(|v| Vec3::from_array(*v)).collect();
+        extent(&points)
+    }
+
+    #[test]
+    fn test_curve_tolerance_sets_the_detail() {
+        // The tolerance replaces the subdivision
+        assert_eq!(
+            build("Hello", detail(4, Some(0.002))),
+            build("Hello", detail(40, Some(0.002)))
+        );
+
+        // Tighter tolerances split curves into more segments
+        let coarse = build("Hello", detail(20, Some(0.01)));
+        let fine = build("Hello", detail(20, Some(0.0005)));
+        assert!(fine.vertices.len() > coarse.vertices.len());
+
+        // Outlines stay within the tolerance of the curves
+        let exact = bounds(&build("Hello", detail(20, Some(0.00001))));
+        for tolerance in [0.01, 0.002] {
+            let (min, max) = bounds(&build("Hello", detail(20, Some(tolerance))));
+            assert!((min - exact.0).truncate().abs().max_element() <= tolerance);
+            assert!((max - exact.1).truncate().abs().max_element() <= tolerance);
+        }
+    }
+
+    #[test]
+    fn test_curve_tolerance_spends_segments_on_curves() {
+        // Glyphs made of straight lines don't get any more vertices
+        let straight = |tolerance| build("LT", detail(20, tolerance)).vertices.len();
+        assert_eq!(straight(Some(0.01)), straight(Some(0.0001)));
+    }
+
+    #[test]
+    fn test_synthetic_bold_thickens_glyphs_in_place() {
+        let bold = |synthetic_bold| TextMeshStyle {
+            synthetic_bold,
+            ..Default::default()
+        };
+        let size = |(min, max): (Vec3, Vec3)| max - min;
+        let plain = size(bounds(&build("I", bold(0.0))));
+        let thick = size(bounds(&build("I", bold(0.03))));
+        // Straight edges move out by the emboldening on both sides, the depth stays
+        assert!((thick.truncate() - (plain.truncate() + 0.06)).length() < 1e-3);
+        assert!((thick.z - plain.z).abs() < 1e-4);
+
+        // Advances are unchanged, so the letters grow into the gap between them
+        let plain = size(bounds(&build("II", bold(0.0))));
+        let thick = size(bounds(&build("II", bold(0.03))));
+        assert!((thick.x - (plain.x + 0.06)).abs() < 1e-3);
+
+        // The emboldening is in ems, scaled with the font size
+        let big = |synthetic_bold| TextMeshStyle {
+            font_size: 2.0,
+            ..bold(synthetic_bold)
+        };
+        let plain = size(bounds(&build("I", big(0.0))));
+        let thick = size(bounds(&build("I", big(0.03))));
+        assert!((thick.x - (plain.x + 0.12)).abs() < 1e-3);
+
+        // Small capitals are emboldened in ems of their own, smaller size
+        let small_caps = |synthetic_bold| TextMeshStyle {
+            transform_case: CaseTransform::SmallCaps { scale: 0.5 },
+            ..bold(synthetic_bold)
+        };
+        let plain = size(bounds(&build("i", small_caps(0.0))));
+        let thick = size(bounds(&build("i", small_caps(0.03))));
+        assert!((thick.x - (plain.x + 0.03)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_synthetic_italic_shears_around_the_baseline() {
+        let italic = |synthetic_italic, side_uvs| TextMeshStyle {
+            synthetic_italic,
+            side_uvs,
+            ..Default::default()
+        };
+        for side_uvs in [false, true] {
+            let upright = build("l", italic(0.0, side_uvs));
+            let slanted = build("l", italic(0.2, side_uvs));
+            let (min, max) = bounds(&upright);
+            let (slanted_min, slanted_max) = bounds(&slanted);
+
+            // Heights stay, and every point moves right by a fifth of its height
+            assert!((slanted_min.y - min.y).abs() < 1e-4 && (slanted_max.y - max.y).abs() < 1e-4);
+            assert!((slanted_max.x - (max.x + max.y * 0.2)).abs() < 1e-3);
+            for vertex in &slanted.vertices {
+                let unsheared = Vec2::new(vertex[0] - vertex[1] * 0.2, vertex[1]);
+                assert!(unsheared.cmpge(min.truncate() - 1e-3).all());
+                assert!(unsheared.cmple(max.truncate() + 1e-3).all());
+            }
+            // Side walls lean with the glyph, so their normals aren't horizontal anymore
+            let leaning = slanted
+                .normals
+                .iter()
+                .any(|n| n[2].abs() < 1e-4 && n[0].abs() > 1e-3 && n[1].abs() > 1e-3);
+            assert!(leaning);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::core::fallback::{FontChain, GlyphSource};
+use crate::core::layout::{get_char_advance, LineItem};
+use crate::core::style::TextMeshStyle;
+use crate::core::stylistic_set::feature_tags;
+use bevy_math::Vec2;
+use rustybuzz::{Feature, UnicodeBuffer};
+
+/// Every glyph of a line as shaped by rustybuzz, in visual order.
+///
+/// The line is shaped with the primary face, so ligatures, kerning, contextual forms,
+/// and mark positioning follow its GSUB and GPOS tables, with the style's stylistic sets
+/// turned on as features, in the letter case the style draws it in. Whitespace, small
+/// capitals, and characters the primary face doesn't have are placed one by one from the
+/// fallback fonts, as without shaping.
+pub(crate) fn shaped_items<'a, 'f>(
+    line: &str,
+    fonts: &'a FontChain<'f>,
+    style: &TextMeshStyle,
+) -> Vec<LineItem<'a, 'f>> {
+    let face = fonts.primary();
+    let shaper = rustybuzz::Face::from_face(face.clone());
+    let features: Vec<Feature> = feature_tags(style)
+        .into_iter()
+        .map(|tag| Feature::new(tag, 1, ..))
+        .collect();
+
+    // Case transforms map every character to one character, so indices carry over
+    let cased: String = line.chars().map(|ch| fonts.cased(ch).0).collect();
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(&cased);
+    let shaped = rustybuzz::shape(&shaper, &features, buffer);
+
+    let scale = style.font_size / face.units_per_em() as f32;
+    shaped
+        .glyph_infos()
+        .iter()
+        .zip(shaped.glyph_positions())
+        .filter_map(|(info, position)| {
+            // Clusters are byte offsets of the first character of each glyph
+            let cluster = info.cluster as usize;
+            let drawn = cased.get(cluster..)?.chars().next()?;
+            let index = cased[..cluster].chars().count();
+            let character = line.chars().nth(index)?;
+
+            let glyph_id = ttf_parser::GlyphId(info.glyph_id as u16);
+            if character.is_whitespace() || glyph_id.0 == 0 || fonts.cased(character).1 {
+                return Some(LineItem {
+                    advance: get_char_advance(character, fonts, style)?,
+                    glyph: (!character.is_whitespace())
+                        .then(|| fonts.resolve(character, style.missing_glyph))
+                        .flatten(),
+                    character,
+                    index,
+                    span: 0,
+                    offset: Vec2::ZERO,
+                    stretch: 0.0,
+                });
+            }
+
+            // Keep the character lookup for plain glyphs so they use fontmesh's mesher
+            let source = if face.glyph_index(drawn) == Some(glyph_id) {
+                GlyphSource::Char(drawn)
+            } else {
+                GlyphSource::Glyph(glyph_id)
+            };
+            Some(LineItem {
+                glyph: Some((face, source)),
+                character,
+                index,
+                span: 0,
+                advance: position.x_advance as f32 * scale,
+                offset: Vec2::new(position.x_offset as f32, position.y_offset as f32) * scale,
+                stretch: 0.0,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::layout::layout_text;
+    use crate::core::style::TextMeshStyle;
+    use crate::core::stylistic_set::StylisticSet;
+    use crate::core::test_font;
+
+    #[test]
+    fn test_ligatures_replace_their_characters() {
+        let font = test_font();
+        let layout = layout_text(&font, "fix", &TextMeshStyle::default());
+
+        // "fi" becomes one glyph, and the glyph after it keeps its character index
+        let characters: Vec<(char, usize)> = layout
+            .glyphs
+            .iter()
+            .map(|glyph| (glyph.character, glyph.char_index))
+            .collect();
+        assert_eq!(characters, vec![('f', 0), ('x', 2)]);
+        assert_eq!(layout.glyphs[1].position.x, layout.glyphs[0].advance);
+    }
+
+    #[test]
+    fn test_combining_marks_are_composed() {
+        let font = test_font();
+        let layout = layout_text(&font, "e\u{301}", &TextMeshStyle::default());
+        assert_eq!(layout.glyphs.len(), 1);
+    }
+
+    #[test]
+    fn test_right_to_left_text_is_in_visual_order() {
+        let font = test_font();
+        let text = "مرحبا";
+        let layout = layout_text(&font, text, &TextMeshStyle::default());
+
+        let characters: Vec<char> = layout.glyphs.iter().map(|glyph| glyph.character).collect();
+        let reversed: Vec<char> = text.chars().rev().collect();
+        assert_eq!(characters, reversed);
+        assert!(layout
+            .glyphs
+            .windows(2)
+            .all(|pair| pair[0].position.x < pair[1].position.x));
+    }
+
+    #[test]
+    fn test_stylistic_sets_are_shaping_features() {
+        let font = test_font();
+        let plain = layout_text(&font, "a", &TextMeshStyle::default());
+        let alternate = layout_text(
+            &font,
+            "a",
+            &TextMeshStyle {
+                stylistic_sets: vec![StylisticSet::Alternates],
+                ..Default::default()
+            },
+        );
+        assert!(alternate.glyphs[0].advance > plain.glyphs[0].advance);
+    }
+}
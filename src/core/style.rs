@@ -0,0 +1,1128 @@
+use crate::core::stylistic_set::StylisticSet;
+#[cfg(feature = "bevy")]
+use crate::core::FontMesh;
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+use bevy_math::{Vec2, Vec3};
+use ttf_parser::Tag;
+
+/// The fallback fonts of a [`TextMeshStyle`], see [`TextMeshStyle::fallback_fonts`].
+///
+/// With the `bevy` feature this is a list of font handles, used like a
+/// `Vec<Handle<FontMesh>>`. Without it there are no handles, and it is always empty,
+/// so the style has the same fields with and without the feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(asset_server: Res<AssetServer>) {
+/// let style = TextMeshStyle {
+///     fallback_fonts: vec![asset_server.load("fonts/emoji.ttf")].into(),
+///     ..default()
+/// };
+/// # }
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FallbackFonts {
+    #[cfg(feature = "bevy")]
+    fonts: Vec<Handle<FontMesh>>,
+}
+
+#[cfg(feature = "bevy")]
+impl std::ops::Deref for FallbackFonts {
+    type Target = Vec<Handle<FontMesh>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.fonts
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl std::ops::DerefMut for FallbackFonts {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.fonts
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl From<Vec<Handle<FontMesh>>> for FallbackFonts {
+    fn from(fonts: Vec<Handle<FontMesh>>) -> Self {
+        Self { fonts }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl FromIterator<Handle<FontMesh>> for FallbackFonts {
+    fn from_iter<I: IntoIterator<Item = Handle<FontMesh>>>(iter: I) -> Self {
+        Self {
+            fonts: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<'a> IntoIterator for &'a FallbackFonts {
+    type Item = &'a Handle<FontMesh>;
+    type IntoIter = std::slice::Iter<'a, Handle<FontMesh>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fonts.iter()
+    }
+}
+
+/// Determines where the text mesh is positioned relative to its transform origin.
+///
+/// The anchor point acts as a pivot for positioning the text. For example, [`TextAnchor::Center`]
+/// places the transform at the center of the text bounds, while [`TextAnchor::BottomLeft`]
+/// places it at the bottom-left corner. The baseline anchors put the transform on the
+/// baseline of the first line instead, so texts in different fonts and sizes line up.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::prelude::default;
+/// // Text centered on its transform
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::Center,
+///     ..default()
+/// };
+///
+/// // Text positioned by its top-left corner
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::TopLeft,
+///     ..default()
+/// };
+///
+/// // Text sitting on its transform, like words on a ruled line, whatever the font
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::BaselineLeft,
+///     ..default()
+/// };
+///
+/// // Custom pivot point at 25% from left, 75% from bottom
+/// let style = TextMeshStyle {
+///     anchor: TextAnchor::Custom(Vec2::new(0.25, 0.75)),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAnchor {
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+    /// Left edge of the text, on the baseline of its first line.
+    BaselineLeft,
+    /// Middle of the text, on the baseline of its first line.
+    BaselineCenter,
+    /// Right edge of the text, on the baseline of its first line.
+    BaselineRight,
+    /// Custom anchor point (0.0-1.0), where (0,0) is BottomLeft and (1,1) is TopRight
+    Custom(Vec2),
+}
+
+/// Point of every glyph of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) that its
+/// `Transform` is placed at, and that it rotates and scales around.
+///
+/// Each glyph mesh is moved so the pivot sits at its own origin, and the glyph entity
+/// is moved by the same amount, so the text looks the same with every pivot. The
+/// [`GlyphMesh::origin`](crate::GlyphMesh::origin) of a glyph is its pivot.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::prelude::default;
+/// // Letters that spin in place
+/// let style = TextMeshStyle {
+///     glyph_pivot: GlyphPivot::Center,
+///     ..default()
+/// };
+///
+/// // Letters that pop up from the middle of their bottom edge
+/// let style = TextMeshStyle {
+///     glyph_pivot: GlyphPivot::Custom(Vec2::new(0.5, 0.0)),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GlyphPivot {
+    /// The glyph's origin on the baseline, where the pen put it.
+    #[default]
+    Baseline,
+    /// The center of the glyph's bounds in the glyph plane.
+    Center,
+    /// Point in the glyph's bounds (0.0-1.0), where (0,0) is the bottom left corner and
+    /// (1,1) the top right one.
+    Custom(Vec2),
+}
+
+/// Controls horizontal alignment of multiline text.
+///
+/// This determines how multiple lines of text are aligned relative to each other.
+/// For single-line text, justification has no visual effect.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Left-aligned text (default)
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Left,
+///     ..default()
+/// };
+///
+/// // Centered text
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Center,
+///     ..default()
+/// };
+///
+/// // Right-aligned text
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Right,
+///     ..default()
+/// };
+///
+/// // A paragraph flush with both edges of a 12 unit wide page
+/// let style = TextMeshStyle {
+///     justify: JustifyText::Justified,
+///     max_width: Some(12.0),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum JustifyText {
+    /// Align text to the left edge.
+    #[default]
+    Left,
+    /// Center text horizontally.
+    Center,
+    /// Align text to the right edge.
+    Right,
+    /// Align text to both edges, widening the spaces between words until every line
+    /// is [`TextMeshStyle::max_width`] wide.
+    ///
+    /// Like in CSS, the last line of a paragraph keeps its natural width. Paragraphs are
+    /// separated by empty lines, so a line is widened only when the next line continues
+    /// its paragraph, and single-line text is never widened. Last lines, lines without
+    /// spaces between words, lines already as wide as the limit, and text without a
+    /// `max_width` are aligned to the left edge. Combine with an
+    /// [`overflow`](TextMeshStyle::overflow) to cut off longer lines.
+    Justified,
+}
+
+/// Direction in which the characters of a line follow each other.
+///
+/// Vertical text stacks the glyphs of every line downwards in a column, each glyph
+/// upright and centered on the column, and places the columns of consecutive lines
+/// side by side, one line height apart. [`JustifyText`] then aligns the columns to
+/// their top, center, or bottom.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Traditional CJK signage, read from the rightmost column
+/// let style = TextMeshStyle {
+///     orientation: TextOrientation::VerticalRightToLeft,
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextOrientation {
+    /// Lines run left to right and stack downwards.
+    #[default]
+    Horizontal,
+    /// Lines run top to bottom, and each line is a column to the left of the previous
+    /// one.
+    VerticalRightToLeft,
+    /// Lines run top to bottom, and each line is a column to the right of the previous
+    /// one.
+    VerticalLeftToRight,
+}
+
+impl TextOrientation {
+    /// Whether lines run top to bottom.
+    pub fn is_vertical(self) -> bool {
+        self != Self::Horizontal
+    }
+
+    /// Offset from one line to the next, for lines `line_height` apart.
+    pub(crate) fn line_step(self, line_height: f32) -> Vec3 {
+        match self {
+            Self::Horizontal => Vec3::new(0.0, -line_height, 0.0),
+            Self::VerticalRightToLeft => Vec3::new(-line_height, 0.0, 0.0),
+            Self::VerticalLeftToRight => Vec3::new(line_height, 0.0, 0.0),
+        }
+    }
+}
+
+/// Shape of the bevel applied to the edges of extruded text.
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum BevelProfile {
+    /// Quarter-circle profile that rounds the edges smoothly.
+    #[default]
+    Round,
+    /// Straight 45-degree-style cut between the cap and the side wall.
+    Chamfer,
+}
+
+/// Bevel applied to the front and back edges of extruded text.
+///
+/// The caps are inset by `width`, and `segments` rings of geometry blend them into the
+/// side walls following the chosen [`BevelProfile`]. The bevel never takes up more than
+/// half of the extrusion depth on each side.
+///
+/// Large widths can make thin strokes fold over themselves, so keep the width small
+/// relative to the font's stroke thickness (around `0.01` to `0.04` for most fonts).
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// let style = TextMeshStyle {
+///     depth: 0.3,
+///     bevel: Some(BevelStyle {
+///         width: 0.02,
+///         segments: 4,
+///         profile: BevelProfile::Round,
+///     }),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BevelStyle {
+    /// How far the bevel cuts into the glyph outline, in mesh units.
+    pub width: f32,
+    /// Number of segments used to approximate the bevel profile.
+    ///
+    /// Only a single segment is needed for [`BevelProfile::Chamfer`].
+    pub segments: u8,
+    /// Shape of the bevel profile.
+    pub profile: BevelProfile,
+}
+
+impl Default for BevelStyle {
+    fn default() -> Self {
+        Self {
+            width: 0.02,
+            segments: 4,
+            profile: BevelProfile::Round,
+        }
+    }
+}
+
+/// Small per-glyph irregularities that make text look carved or cut by hand.
+///
+/// Every glyph is turned, scaled, and extruded a little differently, up to the given
+/// amounts. The variation is derived from `seed`, the character, and its position in
+/// its line, so the same text always looks the same, however often it is rebuilt.
+/// Change the seed to get a different but equally stable look.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// let sign = TextMeshStyle {
+///     depth: 0.3,
+///     handcrafted: Some(Handcrafted {
+///         seed: 7,
+///         ..default()
+///     }),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Handcrafted {
+    /// Seed of the variation.
+    pub seed: u32,
+    /// Largest rotation of a glyph around its center, in radians.
+    pub rotation: f32,
+    /// Largest change in glyph size, as a fraction of its size.
+    pub scale: f32,
+    /// Largest change in extrusion depth, as a fraction of the style's depth.
+    pub depth: f32,
+}
+
+impl Default for Handcrafted {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            rotation: 0.05,
+            scale: 0.04,
+            depth: 0.2,
+        }
+    }
+}
+
+/// Scale of the glyphs along their extrusion, from the front face to the back.
+///
+/// Glyphs are scaled around their centers in the glyph plane, so a back face smaller
+/// than the front gives wedge- or pyramid-like letters, and a curve gives them a bulge
+/// or a waist. The side wall normals follow the slope. Ignored when `depth` is `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Letters that narrow to half their size at the back
+/// let wedge = TextMeshStyle {
+///     depth: 0.5,
+///     extrusion_profile: ExtrusionProfile::Taper(0.5),
+///     ..default()
+/// };
+///
+/// // Letters that swell in the middle of their depth
+/// let pillow = TextMeshStyle {
+///     depth: 0.5,
+///     extrusion_profile: ExtrusionProfile::Curve(vec![1.0, 1.2, 1.0]),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtrusionProfile {
+    /// The same size all the way through, a straight prism.
+    #[default]
+    Straight,
+    /// Scale of the back face relative to the front, changing linearly in between.
+    /// `0.0` narrows every glyph to a point.
+    Taper(f32),
+    /// Scales at evenly spaced depths, the first at the front face and the last at the
+    /// back, changing linearly between them. The side walls get a ring of vertices at
+    /// every scale. Fewer than two scales keep the glyphs straight.
+    Curve(Vec<f32>),
+}
+
+impl ExtrusionProfile {
+    /// Whether the profile changes the size of the glyphs anywhere.
+    pub(crate) fn is_straight(&self) -> bool {
+        match self {
+            Self::Straight => true,
+            Self::Taper(scale) => *scale == 1.0,
+            Self::Curve(scales) => scales.len() < 2 || scales.iter().all(|scale| *scale == 1.0),
+        }
+    }
+
+    /// Fractions of the depth between the front and the back at which the scale changes
+    /// slope, where the side walls need vertices.
+    pub(crate) fn kinks(&self) -> Vec<f32> {
+        match self {
+            Self::Curve(scales) if scales.len() > 2 => {
+                let segments = (scales.len() - 1) as f32;
+                (1..scales.len() - 1).map(|i| i as f32 / segments).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Scale and its slope at `t`, the fraction of the depth behind the front face.
+    ///
+    /// At a kink of a curve, the slope is the average of the slopes on either side.
+    pub(crate) fn scale_and_slope(&self, t: f32) -> (f32, f32) {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Straight => (1.0, 0.0),
+            Self::Taper(back) => (1.0 + (back - 1.0) * t, back - 1.0),
+            Self::Curve(scales) if scales.len() >= 2 => {
+                let segments = (scales.len() - 1) as f32;
+                let slope = |i: usize| (scales[i + 1] - scales[i]) * segments;
+                let position = t * segments;
+                let i = (position.floor() as usize).min(scales.len() - 2);
+                let local = position - i as f32;
+                let scale = scales[i] + (scales[i + 1] - scales[i]) * local;
+                let slope = match (local, i) {
+                    (0.0, i) if i > 0 => (slope(i - 1) + slope(i)) * 0.5,
+                    _ => slope(i),
+                };
+                (scale, slope)
+            }
+            Self::Curve(_) => (1.0, 0.0),
+        }
+    }
+}
+
+/// How the side walls of extruded glyphs are shaded.
+///
+/// Curves in a glyph outline are split into straight segments, and flat walls show
+/// every one of them. Smooth walls blend the normals of neighbouring segments so that
+/// curved letters such as `o`, `e`, and `s` look round, while corners sharper than the
+/// threshold keep their crisp edge.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Round sides, with corners of more than 30 degrees kept sharp
+/// let style = TextMeshStyle {
+///     side_shading: Some(SideShading::Smooth {
+///         angle_threshold: 30f32.to_radians(),
+///     }),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SideShading {
+    /// Every wall segment faces its own way, showing the facets of curves.
+    #[default]
+    Flat,
+    /// Neighbouring wall segments share their normals where they meet at less than
+    /// `angle_threshold`, in radians.
+    Smooth {
+        /// Largest angle between two segments that is still shaded smoothly.
+        angle_threshold: f32,
+    },
+}
+
+/// What to draw for characters that none of the fonts contain.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Show a box for unmapped characters instead of dropping them
+/// let style = TextMeshStyle {
+///     missing_glyph: MissingGlyphPolicy::Tofu,
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum MissingGlyphPolicy {
+    /// Leave the character out of the mesh, taking up no space.
+    #[default]
+    Skip,
+    /// Draw the primary font's `.notdef` glyph, usually an empty box ("tofu").
+    Tofu,
+    /// Draw U+FFFD REPLACEMENT CHARACTER (`�`), falling back to the `.notdef` glyph if
+    /// no font contains it either.
+    ReplacementChar,
+}
+
+/// Letter case the text is drawn in, whatever case it was written in.
+///
+/// Applied during layout, character by character, so the text itself, character
+/// indices, and per-character colors and materials are unchanged. Characters whose
+/// other case takes more than one character, like `ß`, or that no font has in the other
+/// case, are drawn as written.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// let heading = TextMeshStyle {
+///     transform_case: CaseTransform::SmallCaps { scale: 0.75 },
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaseTransform {
+    /// Draw every character as written.
+    #[default]
+    None,
+    /// Draw lowercase letters as capitals.
+    Uppercase,
+    /// Draw capitals as lowercase letters.
+    Lowercase,
+    /// Draw lowercase letters as capitals shrunk by `scale`, with their advances shrunk
+    /// alike. Capitals are drawn as written.
+    SmallCaps {
+        /// Size of the small capitals relative to full ones, usually around `0.7`.
+        scale: f32,
+    },
+}
+
+impl CaseTransform {
+    /// The character drawn for `ch`, and whether it is a small capital.
+    pub(crate) fn apply(self, ch: char) -> (char, bool) {
+        // Mappings to several characters would shift every character index after them
+        fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+            let first = chars.next()?;
+            chars.next().is_none().then_some(first)
+        }
+        match self {
+            Self::None => (ch, false),
+            Self::Uppercase => (single(ch.to_uppercase()).unwrap_or(ch), false),
+            Self::Lowercase => (single(ch.to_lowercase()).unwrap_or(ch), false),
+            Self::SmallCaps { .. } if ch.is_lowercase() => match single(ch.to_uppercase()) {
+                Some(upper) if upper != ch => (upper, true),
+                _ => (ch, false),
+            },
+            Self::SmallCaps { .. } => (ch, false),
+        }
+    }
+
+    /// How much small capitals are shrunk, `1.0` without small caps.
+    pub(crate) fn small_caps_scale(self) -> f32 {
+        match self {
+            Self::SmallCaps { scale } => scale,
+            _ => 1.0,
+        }
+    }
+}
+
+/// What happens to lines longer than [`TextMeshStyle::max_width`] or
+/// [`TextMeshStyle::max_chars`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Player names that never run past their name plate
+/// let style = TextMeshStyle {
+///     overflow: TextOverflow::Ellipsis,
+///     max_width: Some(4.0),
+///     max_chars: Some(16),
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextOverflow {
+    /// Lay out every character, ignoring the limits.
+    #[default]
+    None,
+    /// Cut each line off at the last character that fits.
+    Truncate,
+    /// Cut each line off early enough to end it with an ellipsis, the font's `…` or
+    /// three periods if no font has one. Whitespace before the ellipsis is dropped.
+    Ellipsis,
+}
+
+/// How the glyphs of a text are drawn.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Neon tube lettering: a thin extruded band along every contour
+/// let style = TextMeshStyle {
+///     depth: 0.02,
+///     render_mode: TextRenderMode::Outline { width: 0.03 },
+///     ..default()
+/// };
+///
+/// // A plaque with the name cut into it
+/// let plaque = TextMeshStyle {
+///     depth: 0.2,
+///     anchor: TextAnchor::Center,
+///     render_mode: TextRenderMode::Engraved {
+///         depth: 0.05,
+///         margin: 0.3,
+///     },
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextRenderMode {
+    /// Solid glyphs with filled faces.
+    #[default]
+    Fill,
+    /// Only a band of `width` mesh units centered on the glyph contours, extruded by
+    /// the style's depth like filled glyphs. Counters get a band of their own, so an
+    /// `o` becomes two rings.
+    Outline {
+        /// Width of the band, in mesh units.
+        width: f32,
+    },
+    /// Glyphs carved into the front of a plaque, like an engraved sign.
+    ///
+    /// The plaque is as thick as the style's depth and reaches `margin` mesh units past
+    /// the glyphs on every side; the anchor and bounds of the text are those of the
+    /// plaque. The glyphs are cut `depth` mesh units deep, at most through the whole
+    /// plaque, with a floor and inner walls. Overlapping glyphs are merged into one
+    /// cavity. Glyphs that are meshed on their own, as in
+    /// [`TextMeshGlyphs`](crate::TextMeshGlyphs), get only their cavity, and
+    /// [`Handcrafted`] variation doesn't apply.
+    Engraved {
+        /// How deep the glyphs are cut into the plaque, in mesh units.
+        depth: f32,
+        /// Border of the plaque around the glyphs, in mesh units.
+        margin: f32,
+    },
+}
+
+/// Lines drawn along every line of text, such as underlines and strikethroughs.
+///
+/// Each decoration is a bar spanning the line from where it starts to where it ends,
+/// spaces included, placed and sized by the primary font's underline and strikeout
+/// metrics. The bars are extruded, bevelled, stroked, or engraved like the glyphs and
+/// merged into the same mesh. Vertical columns, per-character
+/// [`TextMeshGlyphs`](crate::TextMeshGlyphs), rich text, and tickers aren't decorated.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// let link = TextMeshStyle {
+///     decorations: TextDecorations {
+///         underline: true,
+///         ..default()
+///     },
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextDecorations {
+    /// A bar just below the baseline, where the font puts its underline.
+    pub underline: bool,
+    /// A bar through the middle of lowercase letters, where the font puts its strikeout.
+    pub strikethrough: bool,
+    /// A bar along the top of the line, at the font's ascender.
+    pub overline: bool,
+    /// Height of the bars, in mesh units. `None` takes the font's underline thickness,
+    /// or its strikeout thickness for the strikethrough. Bars stay centered where the
+    /// font puts them.
+    pub thickness: Option<f32>,
+}
+
+impl TextDecorations {
+    /// Whether any decoration is drawn.
+    pub fn any(&self) -> bool {
+        self.underline || self.strikethrough || self.overline
+    }
+}
+
+/// Visual styling parameters for generated text meshes.
+///
+/// Controls the 3D extrusion depth, curve smoothness, edge bevels, positioning, and
+/// alignment of the generated mesh geometry.
+///
+/// # Units
+///
+/// Lengths are in mesh units, the units of the generated mesh's vertex positions, in
+/// which an em is [`font_size`](Self::font_size) long. They keep their size whatever
+/// the font size. The only exceptions are [`curve_tolerance`](Self::curve_tolerance)
+/// and [`synthetic_bold`](Self::synthetic_bold), which tune the glyph outlines
+/// themselves and are given in ems, so they grow and shrink with the glyphs.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// // Flat 2D-style text
+/// let flat = TextMeshStyle {
+///     depth: 0.0,
+///     subdivision: 15,
+///     ..default()
+/// };
+///
+/// // Deep 3D text with high quality curves
+/// let deep = TextMeshStyle {
+///     depth: 1.0,
+///     subdivision: 30,
+///     anchor: TextAnchor::Center,
+///     justify: JustifyText::Center,
+///     bevel: Some(BevelStyle::default()),
+///     ..default()
+/// };
+///
+/// // Low-poly stylized text
+/// let lowpoly = TextMeshStyle {
+///     depth: 0.2,
+///     subdivision: 5,
+///     ..default()
+/// };
+///
+/// // Widely tracked title
+/// let title = TextMeshStyle {
+///     letter_spacing: 0.1,
+///     word_spacing: 0.2,
+///     ..default()
+/// };
+///
+/// // Tightly set multiline block
+/// let block = TextMeshStyle {
+///     line_spacing: 0.8,
+///     ..default()
+/// };
+///
+/// // Bold, slightly condensed instance of a variable font
+/// let heading = TextMeshStyle {
+///     variations: vec![
+///         (Tag::from_bytes(b"wght"), 700.0),
+///         (Tag::from_bytes(b"wdth"), 90.0),
+///     ],
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect), reflect(Default))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMeshStyle {
+    /// Size of an em, in mesh units.
+    ///
+    /// Everything taken from the font is scaled by it while the vertices are generated:
+    /// glyph outlines, advances, ascenders, and line heights. The lengths the style sets
+    /// itself, such as the depth, bevel, spacing, and widths, keep their size, see
+    /// [Units](Self#units). Unlike scaling the `Transform`, larger text then isn't
+    /// extruded deeper, and bounds and anchors match the mesh. Default: `1.0`.
+    pub font_size: f32,
+
+    /// Extrusion depth of the 3D mesh.
+    ///
+    /// Controls how far the text is extruded in the Z direction. A value of `0.0`
+    /// produces flat, 2D-style text. Higher values create more pronounced 3D geometry.
+    /// In mesh units, so larger [`font_size`](Self::font_size)s aren't extruded deeper.
+    ///
+    /// Recommended range: `0.0` to `2.0`.
+    pub depth: f32,
+
+    /// Number of segments used to approximate curved glyph outlines.
+    ///
+    /// Higher values produce smoother curves but increase vertex count and memory usage.
+    /// Lower values create a more angular, low-poly appearance.
+    ///
+    /// Recommended range: `5` (low-poly) to `30` (very smooth). Ignored when
+    /// [`curve_tolerance`](Self::curve_tolerance) is set.
+    /// Default: `20`.
+    pub subdivision: u8,
+
+    /// Largest distance, in ems, between a curved outline and the straight segments
+    /// that approximate it.
+    ///
+    /// When set, every curve is split into as many segments as it needs to stay this
+    /// close, so nearly straight curves get few and tight ones get more, instead of each
+    /// getting [`subdivision`](Self::subdivision) segments. `0.001` looks smooth at usual
+    /// text sizes. Default: `None`.
+    pub curve_tolerance: Option<f32>,
+
+    /// Position of the text mesh relative to its transform origin.
+    ///
+    /// Determines which point of the text bounds is placed at the entity's transform position.
+    /// Per-character text is anchored by the bounds of all its glyphs.
+    /// See [`TextAnchor`] for available options.
+    pub anchor: TextAnchor,
+
+    /// Point of each glyph of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) that its
+    /// `Transform` sits at, for animations that rotate or scale single glyphs.
+    ///
+    /// See [`GlyphPivot`]. Default: [`GlyphPivot::Baseline`].
+    pub glyph_pivot: GlyphPivot,
+
+    /// Horizontal alignment for multiline text.
+    ///
+    /// Controls how multiple lines of text are aligned relative to each other.
+    /// Has no effect on single-line text. See [`JustifyText`] for options. Vertical
+    /// columns are aligned to their top, center, or bottom instead.
+    pub justify: JustifyText,
+
+    /// Whether lines run horizontally or as vertical columns.
+    ///
+    /// See [`TextOrientation`]. Rich text [`TextMeshSections`](crate::TextMeshSections),
+    /// text on a [`TextPath`](crate::TextPath), and [`TextTicker`](crate::TextTicker)s are
+    /// always laid out horizontally.
+    /// Default: [`TextOrientation::Horizontal`].
+    pub orientation: TextOrientation,
+
+    /// Optional bevel on the front and back edges of the extrusion.
+    ///
+    /// `None` keeps the hard edges of a plain extrusion. Ignored when `depth` is `0.0`.
+    /// See [`BevelStyle`].
+    pub bevel: Option<BevelStyle>,
+
+    /// Scale of the glyphs along the extrusion, for tapered or curved sides.
+    ///
+    /// Ignored when `depth` is `0.0`. See [`ExtrusionProfile`].
+    /// Default: [`ExtrusionProfile::Straight`].
+    pub extrusion_profile: ExtrusionProfile,
+
+    /// Whether the side walls show the facets of curved outlines or shade them smoothly.
+    ///
+    /// `None` keeps the normals of a plain extrusion, which fontmesh averages at every
+    /// corner, and flat walls for outline-built ones. Ignored when `depth` is `0.0`.
+    /// See [`SideShading`]. Default: `None`.
+    pub side_shading: Option<SideShading>,
+
+    /// Whether glyphs are filled, drawn as a band along their contours, or carved into a
+    /// plaque.
+    ///
+    /// See [`TextRenderMode`]. Bevels apply to the band in outline mode, and not at all
+    /// to engraved text. Layout and silhouettes still follow the filled glyphs.
+    /// Default: [`TextRenderMode::Fill`].
+    pub render_mode: TextRenderMode,
+
+    /// Underline, strikethrough, and overline bars along every line.
+    ///
+    /// See [`TextDecorations`]. Default: no decorations.
+    pub decorations: TextDecorations,
+
+    /// Optional per-glyph rotation, scale, and depth variation for a hand-made look.
+    ///
+    /// `None` keeps every glyph exactly as the font draws it. See [`Handcrafted`].
+    pub handcrafted: Option<Handcrafted>,
+
+    /// Fonts searched, in order, for characters missing from the primary font.
+    ///
+    /// Each character is taken from the first font that contains it; characters found
+    /// in none of the fonts are handled by `missing_glyph`. The line height always comes
+    /// from the primary font. Text is generated once all of these fonts have loaded.
+    ///
+    /// Always empty without the `bevy` feature; fonts of the [`core`](crate::core) API
+    /// are passed to its functions directly. See [`FallbackFonts`].
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub fallback_fonts: FallbackFonts,
+
+    /// Faux bold: how far every glyph contour is pushed outward, in ems.
+    ///
+    /// Thickens the strokes of fonts that come without a bold face, like a variable
+    /// font's weight axis would. `0.02` gives a semibold look. Small capitals are pushed
+    /// out by ems of their shrunk size, in proportion to their thinner strokes. Advances
+    /// are unchanged, so heavy emboldening closes the gaps between letters; add some
+    /// [`letter_spacing`](Self::letter_spacing) to make up for it. Default: `0.0`.
+    pub synthetic_bold: f32,
+
+    /// Faux italic: how far glyphs lean to the right, as the horizontal shift per unit
+    /// of height above the baseline.
+    ///
+    /// Shears fonts that come without an italic face. `0.2` slants them by about 11°,
+    /// negative values lean them left. Default: `0.0`.
+    pub synthetic_italic: f32,
+
+    /// Positions on the variation axes of variable fonts, such as weight or width.
+    ///
+    /// Each entry sets one axis, by its tag, to a value in the axis's own units, for
+    /// example `(Tag::from_bytes(b"wght"), 700.0)` for bold or
+    /// `(Tag::from_bytes(b"wdth"), 85.0)` for condensed text. Values are clamped to the
+    /// range the font supports. The axes apply to the primary and fallback fonts alike,
+    /// and fonts without an axis ignore it. Default: empty, the font's default instance.
+    ///
+    /// Not reflected, so scenes and inspectors leave the axes out.
+    #[cfg_attr(feature = "bevy", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(with = "variation_tags"))]
+    pub variations: Vec<(Tag, f32)>,
+
+    /// Stylistic sets whose alternate letterforms replace the default glyphs.
+    ///
+    /// Sets are applied through the font's GSUB table, to the primary and fallback fonts
+    /// alike, and fonts without a set ignore it. With the `shaping` feature, they are
+    /// passed to the shaper as features instead. See [`StylisticSet`]. Default: empty.
+    pub stylistic_sets: Vec<StylisticSet>,
+
+    /// Draw digits that all have the same advance, the font's `tnum` feature, so
+    /// numbers of the same length line up in columns.
+    ///
+    /// Applied like [`stylistic_sets`](Self::stylistic_sets); fonts without tabular
+    /// figures, or whose figures are already tabular, are unaffected. Figure spaces
+    /// (U+2007) are as wide as a digit either way, see [`pad_figures`](crate::core::pad_figures).
+    /// Default: `false`.
+    pub tabular_figures: bool,
+
+    /// Letter case to draw the text in, such as all capitals or small caps for headings,
+    /// without changing the text itself. See [`CaseTransform`].
+    /// Default: [`CaseTransform::None`].
+    pub transform_case: CaseTransform,
+
+    /// What to draw for characters missing from the font and all fallback fonts.
+    ///
+    /// See [`MissingGlyphPolicy`]. Default: [`MissingGlyphPolicy::Skip`].
+    pub missing_glyph: MissingGlyphPolicy,
+
+    /// Extra space added between characters, in mesh units.
+    ///
+    /// Positive values track text out, negative values tighten it. The spacing goes
+    /// between characters only, so it doesn't shift justified or anchored text.
+    /// Default: `0.0`.
+    pub letter_spacing: f32,
+
+    /// Extra space added to every whitespace character, in mesh units.
+    ///
+    /// Applied on top of `letter_spacing`. Default: `0.0`.
+    pub word_spacing: f32,
+
+    /// Width of whitespace characters the font has no glyph for, in mesh units.
+    ///
+    /// Tabs, em spaces, and similar characters are often missing from fonts. `None`
+    /// gives them the width of the font's own space, or of its `n` if it has no space
+    /// glyph either. Default: `None`.
+    pub whitespace_advance: Option<f32>,
+
+    /// Multiplier for the distance between the baselines of consecutive lines.
+    ///
+    /// Scales the primary font's natural line height (ascender minus descender plus
+    /// line gap). Values below `1.0` tighten multiline text, values above loosen it.
+    /// Default: `1.0`.
+    pub line_spacing: f32,
+
+    /// Absolute distance between baselines, in mesh units.
+    ///
+    /// Overrides the font's line height when set, in which case `line_spacing` is
+    /// ignored. Default: `None`.
+    pub line_height_override: Option<f32>,
+
+    /// How lines that exceed [`max_width`](Self::max_width) or
+    /// [`max_chars`](Self::max_chars) are cut off.
+    ///
+    /// Every line is cut off on its own, before justification and anchoring, so the
+    /// shortened text is laid out as if it had been written that way. See
+    /// [`TextOverflow`]. Default: [`TextOverflow::None`].
+    pub overflow: TextOverflow,
+
+    /// Longest a line may be, in mesh units, or the tallest a column of vertical text
+    /// may be. Only applies with an [`overflow`](Self::overflow), and as the width
+    /// [`JustifyText::Justified`] lines are widened to. Default: `None`.
+    pub max_width: Option<f32>,
+
+    /// Most characters a line may hold, counting an ellipsis as one. Only applies with
+    /// an [`overflow`](Self::overflow). Default: `None`.
+    pub max_chars: Option<usize>,
+
+    /// Generate texture coordinates (`Mesh::ATTRIBUTE_UV_0`) for the mesh.
+    ///
+    /// Side walls are parameterized by arc length around each glyph contour (`u`) and
+    /// distance behind the front face (`v`), both in mesh units, so a texture scrolled
+    /// along `u` runs around the rim of every letter, like marquee lights. Front and back
+    /// caps are mapped to the glyph plane. Combine with
+    /// [`TextMeshParts`](crate::TextMeshParts) to give the sides their own material.
+    /// Default: `false`.
+    pub side_uvs: bool,
+
+    /// Generate tangents (`Mesh::ATTRIBUTE_TANGENT`) for normal-mapped materials.
+    ///
+    /// A `StandardMaterial` with a `normal_map_texture` needs tangents to shade
+    /// correctly. Tangents follow the texture coordinates, so UVs are generated as with
+    /// [`side_uvs`](Self::side_uvs) even when that is off. Default: `false`.
+    pub generate_tangents: bool,
+
+    /// Weld the vertices of each glyph that share a position, normal, and texture
+    /// coordinates, and reindex its triangles.
+    ///
+    /// Meshes get smaller and faster to upload, which adds up for long paragraphs. Only
+    /// identical vertices are merged, so hard edges stay sharp and the text looks the
+    /// same; it just takes a little longer to generate. Default: `false`.
+    pub optimize: bool,
+}
+
+impl Default for TextMeshStyle {
+    fn default() -> Self {
+        Self {
+            font_size: 1.0,
+            depth: 0.1,
+            subdivision: 20, // Default low poly-ish but smooth enough
+            curve_tolerance: None,
+            anchor: TextAnchor::TopLeft,
+            glyph_pivot: GlyphPivot::Baseline,
+            justify: JustifyText::Left,
+            orientation: TextOrientation::Horizontal,
+            bevel: None,
+            extrusion_profile: ExtrusionProfile::Straight,
+            side_shading: None,
+            render_mode: TextRenderMode::Fill,
+            decorations: TextDecorations::default(),
+            handcrafted: None,
+            fallback_fonts: FallbackFonts::default(),
+            synthetic_bold: 0.0,
+            synthetic_italic: 0.0,
+            variations: Vec::new(),
+            stylistic_sets: Vec::new(),
+            tabular_figures: false,
+            transform_case: CaseTransform::None,
+            missing_glyph: MissingGlyphPolicy::Skip,
+            letter_spacing: 0.0,
+            word_spacing: 0.0,
+            whitespace_advance: None,
+            line_spacing: 1.0,
+            line_height_override: None,
+            overflow: TextOverflow::None,
+            max_width: None,
+            max_chars: None,
+            side_uvs: false,
+            generate_tangents: false,
+            optimize: false,
+        }
+    }
+}
+
+impl TextMeshStyle {
+    /// Whether meshes in this style get texture coordinates.
+    pub(crate) fn has_uvs(&self) -> bool {
+        self.side_uvs || self.generate_tangents
+    }
+
+    /// Whether glyph outlines are emboldened or slanted.
+    pub(crate) fn synthesizes(&self) -> bool {
+        self.synthetic_bold != 0.0 || self.synthetic_italic != 0.0
+    }
+}
+
+/// Serializes variation axis tags as their four-character names, like `"wght"`.
+#[cfg(feature = "serialize")]
+mod variation_tags {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use ttf_parser::Tag;
+
+    pub fn serialize<S: Serializer>(
+        variations: &[(Tag, f32)],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            variations
+                .iter()
+                .map(|(axis, value)| (axis.to_string(), *value)),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<(Tag, f32)>, D::Error> {
+        Vec::<(String, f32)>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(axis, value)| {
+                let bytes = <[u8; 4]>::try_from(axis.as_bytes())
+                    .map_err(|_| D::Error::custom(format!("invalid variation axis {axis:?}")))?;
+                Ok((Tag::from_bytes(&bytes), value))
+            })
+            .collect()
+    }
+}
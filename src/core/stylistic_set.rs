@@ -0,0 +1,195 @@
+use crate::core::style::TextMeshStyle;
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+use ttf_parser::gsub::{AlternateSubstitution, SingleSubstitution, SubstitutionSubtable};
+use ttf_parser::{GlyphId, Tag};
+
+/// An OpenType feature that swaps letters for alternate designs from the same font.
+///
+/// Display fonts often ship alternate letterforms, like a single-story `a` or a
+/// straight-legged `R`, behind the `salt` feature or the numbered stylistic sets `ss01`
+/// to `ss20`. Select them with [`TextMeshStyle::stylistic_sets`](crate::TextMeshStyle::stylistic_sets)
+/// or per section with [`TextMeshSection::stylistic_sets`](crate::TextMeshSection::stylistic_sets),
+/// and list the ones a font has with [`FontMesh::stylistic_sets`](crate::FontMesh::stylistic_sets).
+/// Digits of equal width are a separate setting, see
+/// [`TextMeshStyle::tabular_figures`](crate::TextMeshStyle::tabular_figures).
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_fontmesh::prelude::*;
+/// # use bevy::prelude::default;
+/// let style = TextMeshStyle {
+///     stylistic_sets: vec![StylisticSet::Numbered(1), StylisticSet::Alternates],
+///     ..default()
+/// };
+/// ```
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum StylisticSet {
+    /// The font's stylistic alternates, the `salt` feature.
+    Alternates,
+    /// One of the numbered stylistic sets `ss01` to `ss20`.
+    ///
+    /// Numbers outside `1..=20` don't name a set and are ignored.
+    Numbered(u8),
+}
+
+impl StylisticSet {
+    /// The OpenType feature tag of the set, or `None` for an out-of-range number.
+    pub fn tag(self) -> Option<Tag> {
+        match self {
+            Self::Alternates => Some(Tag::from_bytes(b"salt")),
+            Self::Numbered(number @ 1..=20) => Some(Tag::from_bytes(&[
+                b's',
+                b's',
+                b'0' + number / 10,
+                b'0' + number % 10,
+            ])),
+            Self::Numbered(_) => None,
+        }
+    }
+
+    /// The set selected by an OpenType feature tag, if it is `salt` or `ss01` to `ss20`.
+    pub fn from_tag(tag: Tag) -> Option<Self> {
+        match &tag.to_bytes() {
+            b"salt" => Some(Self::Alternates),
+            [b's', b's', tens @ b'0'..=b'9', ones @ b'0'..=b'9'] => {
+                let number = (tens - b'0') * 10 + (ones - b'0');
+                (1..=20).contains(&number).then_some(Self::Numbered(number))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The OpenType features a style turns on: its stylistic sets, and `tnum` for
+/// [`tabular_figures`](TextMeshStyle::tabular_figures).
+pub(crate) fn feature_tags(style: &TextMeshStyle) -> Vec<Tag> {
+    let mut tags: Vec<Tag> = style
+        .stylistic_sets
+        .iter()
+        .filter_map(|set| set.tag())
+        .collect();
+    if style.tabular_figures {
+        tags.push(Tag::from_bytes(b"tnum"));
+    }
+    tags
+}
+
+/// The GSUB lookups that the features of `style` turn on, in the order the font
+/// applies them.
+pub(crate) fn stylistic_lookups(face: &fontmesh::Face, style: &TextMeshStyle) -> Vec<u16> {
+    let tags = feature_tags(style);
+    let Some(gsub) = face.tables().gsub.filter(|_| !tags.is_empty()) else {
+        return Vec::new();
+    };
+
+    // The same feature is listed once per script and language, so lookups repeat
+    let mut lookups: Vec<u16> = gsub
+        .features
+        .into_iter()
+        .filter(|feature| tags.contains(&feature.tag))
+        .flat_map(|feature| feature.lookup_indices)
+        .collect();
+    lookups.sort_unstable();
+    lookups.dedup();
+    lookups
+}
+
+/// The glyph that `lookups` substitute for `glyph`, or `None` if none of them apply.
+///
+/// Only one-to-one substitutions are applied, which is what stylistic sets are made of.
+/// Alternate substitutions take the first alternate.
+pub(crate) fn substitute_glyph(
+    face: &fontmesh::Face,
+    lookups: &[u16],
+    glyph: GlyphId,
+) -> Option<GlyphId> {
+    let gsub = face.tables().gsub?;
+    let mut current = glyph;
+    for &index in lookups {
+        let Some(lookup) = gsub.lookups.get(index) else {
+            continue;
+        };
+        if let Some(substitute) = lookup
+            .subtables
+            .into_iter::<SubstitutionSubtable>()
+            .find_map(|subtable| substitute_single(subtable, current))
+        {
+            current = substitute;
+        }
+    }
+    (current != glyph).then_some(current)
+}
+
+fn substitute_single(subtable: SubstitutionSubtable, glyph: GlyphId) -> Option<GlyphId> {
+    match subtable {
+        SubstitutionSubtable::Single(SingleSubstitution::Format1 { coverage, delta }) => {
+            coverage.get(glyph)?;
+            Some(GlyphId(glyph.0.wrapping_add_signed(delta)))
+        }
+        SubstitutionSubtable::Single(SingleSubstitution::Format2 {
+            coverage,
+            substitutes,
+        }) => substitutes.get(coverage.get(glyph)?),
+        SubstitutionSubtable::Alternate(AlternateSubstitution {
+            coverage,
+            alternate_sets,
+        }) => alternate_sets.get(coverage.get(glyph)?)?.alternates.get(0),
+        _ => None,
+    }
+}
+
+/// Every stylistic set in the font's GSUB table, with the name the font gives it.
+pub(crate) fn font_stylistic_sets(face: &fontmesh::Face) -> Vec<(StylisticSet, Option<String>)> {
+    let Some(gsub) = face.tables().gsub else {
+        return Vec::new();
+    };
+    let mut sets: Vec<StylisticSet> = gsub
+        .features
+        .into_iter()
+        .filter_map(|feature| StylisticSet::from_tag(feature.tag))
+        .collect();
+    sets.sort_by_key(|set| set.tag());
+    sets.dedup();
+
+    sets.into_iter()
+        .map(|set| (set, set.tag().and_then(|tag| feature_name(face, tag))))
+        .collect()
+}
+
+/// The UI name of a stylistic set feature from its feature parameters.
+///
+/// ttf-parser skips feature parameters, so the GSUB feature list is read directly.
+fn feature_name(face: &fontmesh::Face, tag: Tag) -> Option<String> {
+    let data = face.raw_face().table(Tag::from_bytes(b"GSUB"))?;
+    let read = |offset: usize| -> Option<u16> {
+        Some(u16::from_be_bytes(
+            data.get(offset..offset + 2)?.try_into().ok()?,
+        ))
+    };
+
+    // Header: version (4 bytes), script list offset, feature list offset, ...
+    let feature_list = read(6)? as usize;
+    let count = read(feature_list)? as usize;
+    let name_id = (0..count).find_map(|index| {
+        let record = feature_list + 2 + index * 6;
+        if data.get(record..record + 4)? != tag.to_bytes() {
+            return None;
+        }
+        let feature = feature_list + read(record + 4)? as usize;
+        let params = read(feature)? as usize;
+        if params == 0 {
+            return None;
+        }
+        // Stylistic set parameters: version, then the name id
+        read(feature + params + 2)
+    })?;
+
+    face.names()
+        .into_iter()
+        .filter(|name| name.name_id == name_id)
+        .find_map(|name| name.to_string())
+}
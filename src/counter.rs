@@ -0,0 +1,333 @@
+use crate::component::{GlyphMesh, TextMeshGlyphs};
+use crate::core::fallback::FontChain;
+use crate::core::layout::{line_glyphs, LineGlyph};
+use crate::core::style::TextMeshStyle;
+use crate::system::{
+    build_glyph_mesh, GlyphMeshesReady, GlyphSlotMaterial, TextMeshGlyphsComputed,
+};
+use crate::FontMesh;
+use bevy::camera::primitives::{Aabb, MeshAabb};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Shows a number that changes often, like a score, timer, or ammo count.
+///
+/// Put it next to a [`TextMeshGlyphs`], whose text it replaces with
+/// [`value`](Self::value) formatted by [`format`](Self::format). The digits `0`-`9` are
+/// tessellated once per font and style, and every glyph entity only swaps between those
+/// shared meshes as the value changes, so counting up every frame never tessellates.
+/// Other characters, such as separators and signs, are tessellated on first use and
+/// kept as well.
+///
+/// Glyph entities are reused position by position. A glyph whose character changes
+/// takes the material of its new character only when the
+/// [`GlyphMaterials`](crate::GlyphMaterials) of the text resolve the two characters
+/// differently. Every glyph of a character shares one mesh, so
+/// [`Handcrafted`](crate::Handcrafted) variation doesn't vary by position.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # #[derive(Component)]
+/// # struct Score;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TextMeshGlyphsBundle {
+///             text_glyphs: TextMeshGlyphs {
+///                 font: asset_server.load("fonts/font.ttf"),
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         TextMeshCounter {
+///             value: 0,
+///             format: CounterFormat {
+///                 min_digits: 6,
+///                 ..default()
+///             },
+///         },
+///         Score,
+///     ));
+/// }
+///
+/// fn add_points(mut score: Single<&mut TextMeshCounter, With<Score>>) {
+///     score.value += 10;
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshCounter {
+    /// The number to show.
+    pub value: i64,
+    /// How the number is written.
+    pub format: CounterFormat,
+}
+
+impl TextMeshCounter {
+    /// A counter showing `value` with the default format.
+    pub fn new(value: i64) -> Self {
+        Self { value, ..default() }
+    }
+}
+
+/// How a [`TextMeshCounter`] writes its value.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct CounterFormat {
+    /// Fewest digits to show, padding with leading zeros. Default: `1`.
+    pub min_digits: usize,
+    /// Character between groups of three digits, such as `,` in `1,000`. Default: `None`.
+    pub separator: Option<char>,
+    /// Show a `+` before positive values. Default: `false`.
+    pub plus_sign: bool,
+}
+
+impl Default for CounterFormat {
+    fn default() -> Self {
+        Self {
+            min_digits: 1,
+            separator: None,
+            plus_sign: false,
+        }
+    }
+}
+
+impl CounterFormat {
+    /// Write `value` in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_fontmesh::CounterFormat;
+    /// let format = CounterFormat {
+    ///     min_digits: 5,
+    ///     separator: Some(','),
+    ///     plus_sign: true,
+    /// };
+    /// assert_eq!(format.format(1234), "+01,234");
+    /// assert_eq!(format.format(-7), "-00,007");
+    /// ```
+    pub fn format(&self, value: i64) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let padding = self.min_digits.saturating_sub(digits.len());
+        let digits: Vec<char> = std::iter::repeat_n('0', padding)
+            .chain(digits.chars())
+            .collect();
+
+        let mut text = String::new();
+        if value < 0 {
+            text.push('-');
+        } else if value > 0 && self.plus_sign {
+            text.push('+');
+        }
+        for (index, &digit) in digits.iter().enumerate() {
+            let remaining = digits.len() - index;
+            if let Some(separator) = self
+                .separator
+                .filter(|_| index > 0 && remaining.is_multiple_of(3))
+            {
+                text.push(separator);
+            }
+            text.push(digit);
+        }
+        text
+    }
+}
+
+/// Meshes of the characters of a [`TextMeshCounter`], shared by its glyph entities.
+#[derive(Component)]
+pub(crate) struct CounterGlyphs {
+    font: AssetId<FontMesh>,
+    style: TextMeshStyle,
+    /// Mesh, bounds, and pivot of every character, or `None` for characters without
+    /// geometry.
+    meshes: HashMap<char, Option<(Handle<Mesh>, Aabb, Vec3)>>,
+}
+
+impl CounterGlyphs {
+    /// Tessellate the digits of `font` in `style`.
+    fn new(
+        font: AssetId<FontMesh>,
+        style: &TextMeshStyle,
+        fonts: &FontChain,
+        meshes: &mut Assets<Mesh>,
+    ) -> Self {
+        let mut glyphs = Self {
+            font,
+            style: style.clone(),
+            meshes: HashMap::default(),
+        };
+        for glyph in line_glyphs("0123456789", true, fonts, style) {
+            glyphs.mesh(&glyph, meshes);
+        }
+        glyphs
+    }
+
+    /// Whether the meshes were built with `font` and `style`.
+    fn is_compatible(&self, font: AssetId<FontMesh>, style: &TextMeshStyle) -> bool {
+        self.font == font && self.style == *style
+    }
+
+    /// Mesh of the character of `glyph`, tessellating it on first use.
+    fn mesh(
+        &mut self,
+        glyph: &LineGlyph,
+        meshes: &mut Assets<Mesh>,
+    ) -> Option<(Handle<Mesh>, Aabb, Vec3)> {
+        self.meshes
+            .entry(glyph.character)
+            .or_insert_with(|| {
+                let (mesh, pivot) = build_glyph_mesh(glyph, &self.style)?;
+                let aabb = mesh.compute_aabb().unwrap_or_default();
+                Some((meshes.add(mesh), aabb, pivot))
+            })
+            .clone()
+    }
+}
+
+type TextMeshCounterQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshCounter>,
+        &'static mut TextMeshGlyphs,
+        &'static MeshMaterial3d<StandardMaterial>,
+        Option<&'static mut CounterGlyphs>,
+    ),
+>;
+
+type CounterGlyphQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut GlyphMesh,
+        &'static mut Transform,
+        &'static mut Mesh3d,
+        &'static mut MeshMaterial3d<StandardMaterial>,
+    ),
+    Without<TextMeshCounter>,
+>;
+
+/// System that shows the values of [`TextMeshCounter`]s with their shared glyph meshes.
+///
+/// Runs instead of the regular glyph generation for counter text; text that stops being
+/// a counter is generated normally again.
+#[allow(clippy::too_many_arguments)]
+pub fn update_text_mesh_counters(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    mut counters: TextMeshCounterQuery,
+    children: Query<&Children>,
+    mut glyphs: CounterGlyphQuery,
+    mut removed: RemovedComponents<TextMeshCounter>,
+    mut ready: MessageWriter<GlyphMeshesReady>,
+) {
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<(CounterGlyphs, TextMeshGlyphsComputed)>();
+        }
+    }
+
+    for (entity, counter, mut text_glyphs, default_material, cache) in counters.iter_mut() {
+        // 1. Write the value as the text
+        if counter.is_changed() {
+            let text = counter.format.format(counter.value);
+            if text_glyphs.text != text {
+                text_glyphs.text = text;
+            }
+        }
+
+        // 2. Tessellate the digits again only when the font or style changed
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_glyphs.font, &text_glyphs.style)
+        else {
+            continue;
+        };
+        let font = text_glyphs.font.id();
+        let style = &text_glyphs.style;
+        let compatible = cache
+            .as_ref()
+            .is_some_and(|cache| cache.is_compatible(font, style));
+        if compatible && !text_glyphs.is_changed() {
+            continue;
+        }
+        let mut new_cache = None;
+        let cache = match cache {
+            Some(cache) if compatible => cache.into_inner(),
+            _ => new_cache.insert(CounterGlyphs::new(font, style, &fonts, &mut meshes)),
+        };
+
+        // 3. Reuse the glyph children in order, swapping their meshes
+        let mut existing: Vec<(Entity, usize)> = children
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| Some((child, glyphs.get(child).ok()?.0.char_index)))
+            .collect();
+        existing.sort_by_key(|&(_, char_index)| char_index);
+        let mut existing = existing.into_iter().map(|(child, _)| child);
+
+        let mut glyph_count = 0;
+        for glyph in line_glyphs(&text_glyphs.text, true, &fonts, style) {
+            let Some((mesh, aabb, pivot)) = cache.mesh(&glyph, &mut meshes) else {
+                continue;
+            };
+            let glyph_mesh = GlyphMesh {
+                char_index: glyph.index,
+                line_index: 0,
+                character: glyph.character,
+                origin: Vec3::new(glyph.x, glyph.y, 0.0) + pivot,
+            };
+            let slot = text_glyphs.glyph_material(glyph_mesh.char_index, glyph.character);
+            glyph_count += 1;
+
+            let Some(child) = existing.next() else {
+                let material = slot.unwrap_or(&default_material.0).clone();
+                commands.entity(entity).with_child((
+                    Transform::from_translation(glyph_mesh.origin),
+                    glyph_mesh,
+                    Mesh3d(mesh),
+                    GlyphSlotMaterial(material.id()),
+                    MeshMaterial3d(material),
+                    aabb,
+                    Visibility::Inherited,
+                ));
+                continue;
+            };
+            let Ok((mut old, mut transform, mut old_mesh, mut material)) = glyphs.get_mut(child)
+            else {
+                continue;
+            };
+            if text_glyphs.glyph_material(old.char_index, old.character) != slot {
+                material.0 = slot.unwrap_or(&default_material.0).clone();
+                commands
+                    .entity(child)
+                    .insert(GlyphSlotMaterial(material.id()));
+            }
+            if old_mesh.0 != mesh {
+                old_mesh.0 = mesh;
+                commands.entity(child).insert(aabb);
+            }
+            // Keep any offset on top of the origin, as edits of regular glyphs do
+            transform.translation += glyph_mesh.origin - old.origin;
+            *old = glyph_mesh;
+        }
+
+        // 4. Despawn glyphs of characters that are gone
+        for child in existing {
+            commands.entity(child).despawn();
+        }
+        ready.write(GlyphMeshesReady {
+            entity,
+            glyph_count,
+        });
+
+        if let Some(new_cache) = new_cache {
+            commands
+                .entity(entity)
+                .insert((new_cache, TextMeshGlyphsComputed));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::billboard::TextBillboard;
+use crate::component::TextMesh;
+use crate::core::style::TextMeshStyle;
+use crate::lifetime::{TextMeshFade, TextMeshLifetime};
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// Text that floats away and disappears, like damage numbers and pickup notices.
+///
+/// The entity gets a [`TextMesh`] showing [`text`](Self::text) and a
+/// [`TextMeshLifetime`], then drifts by [`velocity`](Self::velocity) while it grows to
+/// [`scale`](Self::scale) times its size, fades out, and despawns on its own. It faces
+/// the camera through a required [`TextBillboard`]. Editing the text, font, style, or
+/// material updates the text it shows, and only those edits rebuild its mesh.
+///
+/// With [`TextMeshFade::Scale`], the fade takes over the scale from where the growth
+/// left it. Needs Bevy's [`Time`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn show_damage(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         FloatingText3d {
+///             text: "-42".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             velocity: Vec3::new(0.0, 1.5, 0.0),
+///             scale: 1.5,
+///             ..default()
+///         },
+///         Transform::from_xyz(0.0, 2.0, 0.0).with_scale(Vec3::splat(0.3)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component, Default)]
+#[require(Transform, Visibility, TextBillboard)]
+pub struct FloatingText3d {
+    /// The text to show.
+    pub text: String,
+    /// Font of the text.
+    pub font: Handle<FontMesh>,
+    /// Style of the text.
+    pub style: TextMeshStyle,
+    /// Material of the text. The default handle gets the material of
+    /// [`FontMeshSettings`](crate::FontMeshSettings), like any other text.
+    pub material: Handle<StandardMaterial>,
+    /// Seconds the text is shown, including its fade.
+    pub lifetime: f32,
+    /// Seconds over which the text fades out at the end of its lifetime.
+    pub fade_out: f32,
+    /// How the text fades out.
+    pub fade: TextMeshFade,
+    /// Distance the text drifts per second, in its parent's space.
+    pub velocity: Vec3,
+    /// Size of the text at the end of its lifetime, relative to its size when spawned.
+    /// `1.0` keeps it the same size.
+    pub scale: f32,
+}
+
+impl Default for FloatingText3d {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            font: Handle::default(),
+            style: TextMeshStyle::default(),
+            material: Handle::default(),
+            lifetime: 1.0,
+            fade_out: 0.5,
+            fade: TextMeshFade::default(),
+            velocity: Vec3::Y,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Scale of a [`FloatingText3d`] when it was spawned, and the material it was last given.
+#[derive(Component)]
+pub(crate) struct FloatingStart {
+    scale: Vec3,
+    material: Handle<StandardMaterial>,
+}
+
+type FloatingTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, FloatingText3d>,
+        &'static Transform,
+        Option<&'static mut TextMesh>,
+        Option<&'static mut FloatingStart>,
+    ),
+    Changed<FloatingText3d>,
+>;
+
+/// System that gives new and edited [`FloatingText3d`]s the text and lifetime they show.
+pub(crate) fn spawn_floating_texts(mut commands: Commands, mut texts: FloatingTextQuery) {
+    for (entity, floating, transform, text_mesh, start) in texts.iter_mut() {
+        let mut entity = commands.entity(entity);
+        match text_mesh {
+            // Edits of the motion or lifetime leave the mesh alone
+            Some(mut text_mesh) => {
+                if text_mesh.text != floating.text {
+                    text_mesh.text = floating.text.clone();
+                }
+                if text_mesh.font != floating.font {
+                    text_mesh.font = floating.font.clone();
+                }
+                if text_mesh.style != floating.style {
+                    text_mesh.style = floating.style.clone();
+                }
+            }
+            None => {
+                entity.insert(TextMesh {
+                    text: floating.text.clone(),
+                    font: floating.font.clone(),
+                    style: floating.style.clone(),
+                    ..default()
+                });
+            }
+        }
+        match start {
+            // Only a new material replaces the one the fade or the settings gave it
+            Some(mut start) => {
+                if start.material != floating.material {
+                    start.material = floating.material.clone();
+                    entity.insert(MeshMaterial3d(floating.material.clone()));
+                }
+            }
+            None => {
+                entity.insert((
+                    Mesh3d::default(),
+                    MeshMaterial3d(floating.material.clone()),
+                    TextMeshLifetime::new(floating.lifetime, floating.fade_out)
+                        .with_fade(floating.fade),
+                    FloatingStart {
+                        scale: transform.scale,
+                        material: floating.material.clone(),
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// System that moves and grows [`FloatingText3d`]s over their lifetime.
+pub(crate) fn animate_floating_texts(
+    time: Res<Time>,
+    mut texts: Query<(
+        &FloatingText3d,
+        &FloatingStart,
+        &TextMeshLifetime,
+        &mut Transform,
+    )>,
+) {
+    for (floating, start, lifetime, mut transform) in texts.iter_mut() {
+        transform.translation += floating.velocity * time.delta_secs();
+        // Scale fades take over the scale once they start
+        if lifetime.fade == TextMeshFade::Scale && lifetime.opacity() < 1.0 {
+            continue;
+        }
+        let progress = (lifetime.elapsed / lifetime.duration.max(f32::EPSILON)).clamp(0.0, 1.0);
+        transform.scale = start.scale * (1.0 + (floating.scale - 1.0) * progress);
+    }
+}
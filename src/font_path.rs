@@ -0,0 +1,123 @@
+use crate::component::FontAndStyle;
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// Asset paths of a text's fonts, saved with the text so savegames and scenes can load
+/// the fonts again.
+///
+/// Font handles aren't stable across runs, and Bevy doesn't write handles of loaded
+/// assets to scene files, so a saved [`TextMesh`](crate::TextMesh) comes back without
+/// its font. Add this component to text that is saved: the plugin records the paths of
+/// the font and [fallback fonts](crate::TextMeshStyle::fallback_fonts) the text uses,
+/// and when the text is loaded again with handles that don't point to those paths, it
+/// loads the fonts by path and sets the handles, so the text rebuilds as it was.
+///
+/// Changing the text's fonts records their new paths, and changing the paths loads the
+/// fonts they name. Fonts added to [`Assets<FontMesh>`] directly have no path and are
+/// recorded as `None`. Works on [`TextMesh`](crate::TextMesh),
+/// [`TextMeshGlyphs`](crate::TextMeshGlyphs), [`TextMeshParts`](crate::TextMeshParts),
+/// and [`TextMesh2d`](crate::TextMesh2d), in worlds with an [`AssetServer`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh::new("Town hall").font(asset_server.load("fonts/font.ttf")),
+///         ..default()
+///     },
+///     // Filled in with "fonts/font.ttf" on the next update
+///     TextMeshFontPaths::default(),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct TextMeshFontPaths {
+    /// Asset path of the font.
+    pub font: Option<String>,
+    /// Asset paths of the fallback fonts, in order.
+    pub fallback_fonts: Vec<Option<String>>,
+}
+
+/// Whether the paths or the handles of a text are to be trusted.
+enum Source {
+    /// Record the paths of the handles.
+    Handles,
+    /// Load the fonts of the paths.
+    Paths,
+}
+
+/// Record the path of `handle` in `path`, or load the font of `path` into `handle`.
+fn sync(
+    asset_server: &AssetServer,
+    source: &Source,
+    handle: &mut Handle<FontMesh>,
+    path: &mut Option<String>,
+) {
+    let handle_path = asset_server.get_path(&*handle).map(|path| path.to_string());
+    match (source, handle_path) {
+        (_, Some(handle_path)) if path.as_ref() == Some(&handle_path) => {}
+        (Source::Handles, handle_path) => *path = handle_path,
+        // A loaded text's handle has no path, or one the saved text didn't have
+        (Source::Paths, _) => {
+            if let Some(path) = path {
+                *handle = asset_server.load(path.clone());
+            }
+        }
+    }
+}
+
+type FontPathQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (Mut<'static, T>, Mut<'static, TextMeshFontPaths>),
+    Or<(Changed<T>, Changed<TextMeshFontPaths>)>,
+>;
+
+/// System that keeps the [`TextMeshFontPaths`] of texts and their font handles in sync.
+///
+/// Handles that were changed are recorded as paths. Paths that were added or changed
+/// without the handles, as when a savegame is loaded, are loaded into the handles.
+pub(crate) fn sync_font_paths<T: FontAndStyle>(
+    asset_server: Res<AssetServer>,
+    mut texts: FontPathQuery<T>,
+) {
+    for (mut text, mut paths) in texts.iter_mut() {
+        // Spawned text has both, and keeps the fonts it was given when they have paths
+        let (font, style) = text.font_and_style();
+        let has_paths = asset_server.get_path(font).is_some()
+            || style
+                .fallback_fonts
+                .iter()
+                .any(|font| asset_server.get_path(font).is_some());
+        let source = match (text.is_changed(), paths.is_changed()) {
+            (true, true) if has_paths => Source::Handles,
+            (_, true) => Source::Paths,
+            _ => Source::Handles,
+        };
+
+        // Work on copies, so nothing is marked changed unless it differs
+        let (mut font, mut fallback_fonts) = (font.clone(), style.fallback_fonts.to_vec());
+        let mut new_paths = paths.clone();
+        sync(&asset_server, &source, &mut font, &mut new_paths.font);
+        match source {
+            Source::Handles => new_paths.fallback_fonts.resize(fallback_fonts.len(), None),
+            Source::Paths => fallback_fonts.resize(new_paths.fallback_fonts.len(), default()),
+        }
+        for (handle, path) in fallback_fonts.iter_mut().zip(&mut new_paths.fallback_fonts) {
+            sync(&asset_server, &source, handle, path);
+        }
+
+        paths.set_if_neq(new_paths);
+        let (text_font, text_style) = text.font_and_style();
+        if *text_font != font || *text_style.fallback_fonts != fallback_fonts {
+            let (text_font, text_style) = text.font_and_style_mut();
+            *text_font = font;
+            *text_style.fallback_fonts = fallback_fonts;
+        }
+    }
+}
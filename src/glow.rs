@@ -0,0 +1,188 @@
+use crate::component::TextMesh;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::render::render_resource::Face;
+
+/// Surrounds 3D text with a soft glow, without any post-processing.
+///
+/// Put it on an entity with a [`TextMesh`] to get a [`TextMeshGlowShell`] child: a copy
+/// of the text mesh with every vertex pushed out along its normal by [`inflate`], drawn
+/// with [`material`]. With an additive, unlit material, like the one built by
+/// [`TextMeshGlow::additive_material`], the shell reads as a halo around the letters.
+/// The shell follows the text as it changes, and is despawned when the component is
+/// removed.
+///
+/// Normals are averaged over vertices at the same position, so the corners of extruded
+/// letters stay closed when inflated. Flat text has no sides to push outward, so its
+/// shell only moves forward; give the text some [`depth`] for a visible halo.
+///
+/// [`inflate`]: TextMeshGlow::inflate
+/// [`material`]: TextMeshGlow::material
+/// [`depth`]: crate::TextMeshStyle::depth
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "NEON".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshGlow {
+///         inflate: 0.04,
+///         material: materials.add(TextMeshGlow::additive_material(Color::srgb(
+///             0.1, 0.3, 0.6,
+///         ))),
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TextMeshGlow {
+    /// Distance the shell is pushed out from the text surface, in mesh units.
+    /// Default: `0.02`.
+    pub inflate: f32,
+    /// Material of the shell.
+    pub material: Handle<StandardMaterial>,
+}
+
+impl Default for TextMeshGlow {
+    fn default() -> Self {
+        Self {
+            inflate: 0.02,
+            material: Handle::default(),
+        }
+    }
+}
+
+impl TextMeshGlow {
+    /// An unlit, additively blended material in `color`, for a glow shell.
+    ///
+    /// Only the back faces of the shell are drawn, so the front of the text isn't washed
+    /// out by the shell in front of it. Darker colors give a fainter glow.
+    pub fn additive_material(color: Color) -> StandardMaterial {
+        StandardMaterial {
+            base_color: color,
+            alpha_mode: AlphaMode::Add,
+            unlit: true,
+            cull_mode: Some(Face::Front),
+            ..default()
+        }
+    }
+
+    /// Build the inflated shell of `mesh`.
+    ///
+    /// Returns `None` if the mesh has no positions or normals.
+    pub fn inflate_mesh(mesh: &Mesh, inflate: f32) -> Option<Mesh> {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL)?.as_float3()?;
+
+        // Average the normals of the faces meeting at each position
+        let mut smoothed: HashMap<[u32; 3], Vec3> = HashMap::default();
+        for (position, normal) in positions.iter().zip(normals) {
+            *smoothed.entry(position.map(f32::to_bits)).or_default() += Vec3::from_array(*normal);
+        }
+
+        let (positions, normals): (Vec<[f32; 3]>, Vec<[f32; 3]>) = positions
+            .iter()
+            .map(|position| {
+                let normal = smoothed[&position.map(f32::to_bits)].normalize_or_zero();
+                let inflated = Vec3::from_array(*position) + normal * inflate;
+                (inflated.to_array(), normal.to_array())
+            })
+            .unzip();
+
+        let mut shell = mesh.clone();
+        shell.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        shell.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Some(shell)
+    }
+}
+
+/// Marker for the inflated shell of a [`TextMeshGlow`].
+///
+/// Spawned as a child of the text entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextMeshGlowShell;
+
+type TextGlowQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshGlow>,
+        Ref<'static, TextMesh>,
+        Ref<'static, Mesh3d>,
+        Option<&'static Children>,
+    ),
+>;
+
+/// System that builds the shells of text with a [`TextMeshGlow`].
+///
+/// Runs after mesh generation, so shells always match the current meshes.
+pub fn update_text_glow_shells(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    texts: TextGlowQuery,
+    shells: Query<(), With<TextMeshGlowShell>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextMeshGlow>,
+) {
+    // 1. Despawn the shells of text that no longer glows
+    for entity in removed.read() {
+        let Ok(children) = children.get(entity) else {
+            continue;
+        };
+        for child in children.iter() {
+            if shells.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+
+    // 2. Rebuild shells; the line cache edits meshes in place, so text changes count
+    for (entity, glow, text_mesh, mesh, children) in texts.iter() {
+        if !(glow.is_changed() || text_mesh.is_changed() || mesh.is_changed()) {
+            continue;
+        }
+        let Some(shell) = meshes
+            .get(&mesh.0)
+            .and_then(|mesh| TextMeshGlow::inflate_mesh(mesh, glow.inflate))
+        else {
+            continue;
+        };
+        let shell = (
+            Mesh3d(meshes.add(shell)),
+            MeshMaterial3d(glow.material.clone()),
+        );
+
+        let existing =
+            children.and_then(|children| children.iter().find(|&child| shells.contains(child)));
+        match existing {
+            Some(child) => {
+                commands.entity(child).insert(shell);
+            }
+            None => {
+                commands.entity(entity).with_child((
+                    TextMeshGlowShell,
+                    shell,
+                    Transform::default(),
+                    Visibility::default(),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::component::GlyphMesh;
+use bevy::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+/// Moves the glyphs of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) up and down in a
+/// travelling sine wave.
+///
+/// Add it to the same entity as the [`TextMeshGlyphs`](crate::TextMeshGlyphs). Each glyph
+/// is offset from its [`GlyphMesh::origin`] with a phase that depends on its character
+/// index, so the wave runs along the text. Can be combined with [`GlyphBounce`] and
+/// [`GlyphShake`]; their offsets add up.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshGlyphsBundle {
+///         text_glyphs: TextMeshGlyphs {
+///             text: "Wavy!".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     GlyphWave {
+///         amplitude: 0.2,
+///         ..default()
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct GlyphWave {
+    /// Height of the wave crests above the baseline, in mesh units.
+    pub amplitude: f32,
+    /// Seconds for a glyph to go through one full up-and-down cycle.
+    pub period: f32,
+    /// Number of characters per wave.
+    pub wavelength: f32,
+}
+
+impl Default for GlyphWave {
+    fn default() -> Self {
+        Self {
+            amplitude: 0.1,
+            period: 1.0,
+            wavelength: 8.0,
+        }
+    }
+}
+
+impl GlyphWave {
+    /// Offset of the glyph at `index` after `elapsed` seconds.
+    pub fn offset(&self, index: usize, elapsed: f32) -> Vec3 {
+        if self.period <= 0.0 || self.wavelength == 0.0 {
+            return Vec3::ZERO;
+        }
+        let phase = elapsed / self.period - index as f32 / self.wavelength;
+        Vec3::Y * self.amplitude * (phase * TAU).sin()
+    }
+}
+
+/// Makes the glyphs of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) hop off the baseline
+/// one after another.
+///
+/// Each glyph bounces with the same rhythm, delayed by [`GlyphBounce::delay`] per
+/// character index. See [`GlyphWave`] for how animations are attached and combined.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct GlyphBounce {
+    /// Height of each hop, in mesh units.
+    pub height: f32,
+    /// Seconds between two landings of the same glyph.
+    pub period: f32,
+    /// Seconds each glyph lags behind the previous one.
+    pub delay: f32,
+}
+
+impl Default for GlyphBounce {
+    fn default() -> Self {
+        Self {
+            height: 0.15,
+            period: 0.8,
+            delay: 0.06,
+        }
+    }
+}
+
+impl GlyphBounce {
+    /// Offset of the glyph at `index` after `elapsed` seconds.
+    pub fn offset(&self, index: usize, elapsed: f32) -> Vec3 {
+        if self.period <= 0.0 {
+            return Vec3::ZERO;
+        }
+        let phase = (elapsed - self.delay * index as f32) / self.period;
+        Vec3::Y * self.height * (phase * PI).sin().abs()
+    }
+}
+
+/// Jitters the glyphs of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) in the text plane.
+///
+/// Every glyph jumps to a new pseudo-random offset [`GlyphShake::rate`] times per
+/// second. Offsets are derived from the character index and time, so the shake is
+/// the same on every run. See [`GlyphWave`] for how animations are attached and
+/// combined.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct GlyphShake {
+    /// Largest offset along each axis, in mesh units.
+    pub intensity: f32,
+    /// New offsets per second.
+    pub rate: f32,
+}
+
+impl Default for GlyphShake {
+    fn default() -> Self {
+        Self {
+            intensity: 0.02,
+            rate: 20.0,
+        }
+    }
+}
+
+impl GlyphShake {
+    /// Offset of the glyph at `index` after `elapsed` seconds.
+    pub fn offset(&self, index: usize, elapsed: f32) -> Vec3 {
+        let step = (elapsed * self.rate).floor().max(0.0) as u64;
+        let x = unit_noise(index as u64, step, 0);
+        let y = unit_noise(index as u64, step, 1);
+        Vec3::new(x, y, 0.0) * self.intensity
+    }
+}
+
+/// Deterministic value in `[-1, 1]` for the given inputs.
+fn unit_noise(index: u64, step: u64, axis: u64) -> f32 {
+    // SplitMix64 finalizer over the combined inputs
+    let mut z = index
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        .wrapping_add(step.wrapping_mul(0xBF58_476D_1CE4_E5B9))
+        .wrapping_add(axis.wrapping_mul(0x94D0_49BB_1331_11EB));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+}
+
+/// System that moves [`GlyphMesh`] children according to the [`GlyphWave`],
+/// [`GlyphBounce`], and [`GlyphShake`] on their parent.
+///
+/// Glyphs of a parent that loses its last animation are put back at their origin.
+pub fn animate_glyphs(
+    time: Res<Time>,
+    animations: Query<(
+        Option<&GlyphWave>,
+        Option<&GlyphBounce>,
+        Option<&GlyphShake>,
+    )>,
+    mut glyphs: Query<(&GlyphMesh, &ChildOf, &mut Transform)>,
+    mut removed_waves: RemovedComponents<GlyphWave>,
+    mut removed_bounces: RemovedComponents<GlyphBounce>,
+    mut removed_shakes: RemovedComponents<GlyphShake>,
+) {
+    let elapsed = time.elapsed_secs();
+    let stopped: Vec<Entity> = removed_waves
+        .read()
+        .chain(removed_bounces.read())
+        .chain(removed_shakes.read())
+        .collect();
+
+    for (glyph, child_of, mut transform) in glyphs.iter_mut() {
+        let parent = child_of.parent();
+        let animation = animations
+            .get(parent)
+            .ok()
+            .filter(|(wave, bounce, shake)| wave.is_some() || bounce.is_some() || shake.is_some());
+
+        let Some((wave, bounce, shake)) = animation else {
+            if stopped.contains(&parent) {
+                transform.translation = glyph.origin;
+            }
+            continue;
+        };
+
+        let index = glyph.char_index;
+        let offset = wave.map_or(Vec3::ZERO, |wave| wave.offset(index, elapsed))
+            + bounce.map_or(Vec3::ZERO, |bounce| bounce.offset(index, elapsed))
+            + shake.map_or(Vec3::ZERO, |shake| shake.offset(index, elapsed));
+        transform.translation = glyph.origin + offset;
+    }
+}
@@ -0,0 +1,116 @@
+use crate::core::fallback::FontChain;
+use crate::core::layout::line_glyphs;
+use crate::core::style::TextMeshStyle;
+use crate::reload::uses_font;
+use crate::system::build_glyph_mesh;
+use crate::FontMesh;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+/// Meshes of single glyphs, generated once and shared by everything that asks for them.
+///
+/// For letter-based effects of your own, such as glyphs flying in one by one or
+/// scattered debris spelling a word: ask for the mesh of a character in a font and
+/// style, and every entity showing that glyph gets the same mesh asset. The mesh is
+/// around the style's [`glyph_pivot`](TextMeshStyle::glyph_pivot), by default the
+/// glyph's origin on the baseline, and sized by its font size, like the glyphs of a
+/// [`TextMeshGlyphs`](crate::TextMeshGlyphs). Characters missing from the font are
+/// looked up in the style's fallback fonts, and its missing-glyph policy applies.
+///
+/// The plugin adds the resource. A mesh is kept while anything else holds its handle,
+/// and generated again after its font was modified.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn spawn_letters(
+///     mut commands: Commands,
+///     mut glyph_meshes: ResMut<GlyphMeshes>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     fonts: Res<Assets<FontMesh>>,
+///     asset_server: Res<AssetServer>,
+/// ) {
+///     let font = asset_server.load("fonts/font.ttf");
+///     let style = TextMeshStyle::default();
+///     for (index, character) in "BOOM".chars().enumerate() {
+///         let Some(mesh) =
+///             glyph_meshes.get_or_create(&mut meshes, &fonts, &font, character, &style)
+///         else {
+///             continue;
+///         };
+///         // Both Os display one mesh
+///         commands.spawn((Mesh3d(mesh), Transform::from_xyz(index as f32, 0.0, 0.0)));
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct GlyphMeshes {
+    by_glyph: HashMap<(AssetId<FontMesh>, char), Vec<CachedGlyph>>,
+}
+
+struct CachedGlyph {
+    style: TextMeshStyle,
+    /// `None` for characters without geometry, like spaces.
+    mesh: Option<Handle<Mesh>>,
+}
+
+impl GlyphMeshes {
+    /// The mesh of `character` in `font` and `style`, generating it on first use.
+    ///
+    /// Returns `None` while the font or one of the style's fallback fonts isn't loaded,
+    /// and for characters without geometry, like spaces.
+    pub fn get_or_create(
+        &mut self,
+        meshes: &mut Assets<Mesh>,
+        fonts: &Assets<FontMesh>,
+        font: &Handle<FontMesh>,
+        character: char,
+        style: &TextMeshStyle,
+    ) -> Option<Handle<Mesh>> {
+        let entries = self.by_glyph.entry((font.id(), character)).or_default();
+        if let Some(cached) = entries.iter().find(|cached| cached.style == *style) {
+            return cached.mesh.clone();
+        }
+
+        let fonts = FontChain::for_style(fonts, font, style)?;
+        let text = character.to_string();
+        let mesh = line_glyphs(&text, true, &fonts, style)
+            .next()
+            .and_then(|glyph| build_glyph_mesh(&glyph, style))
+            .map(|(mesh, _)| meshes.add(mesh));
+        entries.push(CachedGlyph {
+            style: style.clone(),
+            mesh: mesh.clone(),
+        });
+        mesh
+    }
+
+    /// Forget every mesh built with one of the `fonts`, after the font assets were
+    /// modified.
+    pub(crate) fn forget_fonts(&mut self, fonts: &HashSet<AssetId<FontMesh>>) {
+        self.by_glyph.retain(|(font, _), entries| {
+            entries.retain(|cached| !uses_font(*font, &cached.style, fonts));
+            !entries.is_empty()
+        });
+    }
+
+    /// Drop the meshes that only the cache still refers to.
+    pub(crate) fn release_unused(&mut self) {
+        self.by_glyph.retain(|_, entries| {
+            entries.retain(|cached| match &cached.mesh {
+                Some(Handle::Strong(handle)) => std::sync::Arc::strong_count(handle) > 1,
+                Some(Handle::Uuid(..)) => false,
+                // Glyphs without geometry cost nothing to remember
+                None => true,
+            });
+            !entries.is_empty()
+        });
+    }
+}
+
+/// System that drops the [`GlyphMeshes`] nothing displays anymore.
+pub(crate) fn release_unused_glyph_meshes(mut glyph_meshes: ResMut<GlyphMeshes>) {
+    glyph_meshes.release_unused();
+}
@@ -0,0 +1,320 @@
+use crate::component::GlyphMesh;
+use crate::core::fallback::GlyphSource;
+use crate::core::layout::LineGlyph;
+use crate::core::style::TextMeshStyle;
+use crate::system::{build_glyph_mesh, GlyphSlotMaterial};
+use crate::FontMesh;
+use bevy::camera::primitives::{Aabb, MeshAabb};
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use bevy::render::sync_world::RenderEntity;
+
+/// Most hidden glyph entities a [`GlyphPool`] keeps for later edits.
+const MAX_PARKED_GLYPHS: usize = 64;
+
+/// The glyph a [`GlyphMesh`] draws, to tell whether its mesh still fits after an edit.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub(crate) struct GlyphShape {
+    source: GlyphSource,
+    character: char,
+    /// Index of the character in its line, which seeds [`Handcrafted`] variation.
+    ///
+    /// [`Handcrafted`]: crate::Handcrafted
+    index: usize,
+    /// Offset of the mesh's pivot from the glyph origin.
+    pub(crate) pivot: Vec3,
+}
+
+impl GlyphShape {
+    /// Shape of a laid-out glyph, drawn by a mesh around its origin.
+    pub(crate) fn of(glyph: &LineGlyph) -> Self {
+        Self {
+            source: glyph.source,
+            character: glyph.character,
+            index: glyph.index,
+            pivot: Vec3::ZERO,
+        }
+    }
+
+    /// The same shape, drawn by a mesh around the pivot at `pivot`.
+    pub(crate) fn with_pivot(self, pivot: Vec3) -> Self {
+        Self { pivot, ..self }
+    }
+
+    /// Whether a mesh built for this shape in `style` also draws `other`, whatever the
+    /// pivots.
+    pub(crate) fn fits(&self, other: &Self, style: &TextMeshStyle) -> bool {
+        self.key(style) == other.key(style)
+    }
+
+    /// What a mesh built for this shape in `style` depends on; shapes with the same key
+    /// share one mesh.
+    pub(crate) fn key(&self, style: &TextMeshStyle) -> ShapeKey {
+        ShapeKey {
+            source: self.source,
+            character: self.character,
+            index: style.handcrafted.is_some().then_some(self.index),
+        }
+    }
+}
+
+/// Key of the mesh shared by every glyph of a shape, see [`GlyphShape::key`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ShapeKey {
+    source: GlyphSource,
+    character: char,
+    index: Option<usize>,
+}
+
+/// A glyph mesh shared by the glyphs of one shape, with its bounds and pivot.
+#[derive(Clone)]
+pub(crate) struct SharedMesh {
+    pub(crate) mesh: Handle<Mesh>,
+    pub(crate) aabb: Aabb,
+    pub(crate) pivot: Vec3,
+}
+
+/// Marker for a hidden glyph entity waiting in a [`GlyphPool`].
+#[derive(Component)]
+pub(crate) struct ParkedGlyph;
+
+/// The components a parked glyph entity keeps, with their required components.
+///
+/// Everything else, such as components added by animations or user code, is removed
+/// when the glyph is parked, so a reused entity starts out like a freshly spawned one.
+type ParkedGlyphBundle = (
+    ParkedGlyph,
+    Mesh3d,
+    Aabb,
+    Transform,
+    Visibility,
+    RenderEntity,
+);
+
+/// A hidden glyph entity and the mesh it last drew.
+struct Parked {
+    entity: Entity,
+    /// Shape of the mesh, or `None` once the font or style changed.
+    shape: Option<GlyphShape>,
+    mesh: Handle<Mesh>,
+}
+
+/// Glyph entities of a [`TextMeshGlyphs`](crate::TextMeshGlyphs) that were removed by
+/// edits, kept hidden with their mesh assets so later edits can reuse them instead of
+/// despawning and spawning entities and allocating meshes.
+///
+/// Parked entities aren't children of the text, so they are despawned with the pool.
+///
+/// Also remembers the font and style the glyphs were generated with, and the mesh of
+/// every shape they draw, so repeated characters share one mesh asset.
+#[derive(Component)]
+#[component(on_remove = despawn_parked_glyphs)]
+pub(crate) struct GlyphPool {
+    font: AssetId<FontMesh>,
+    style: TextMeshStyle,
+    parked: Vec<Parked>,
+    /// Mesh of each shape, or `None` for shapes without geometry.
+    shared: HashMap<ShapeKey, Option<SharedMesh>>,
+}
+
+impl GlyphPool {
+    /// An empty pool for glyphs in `font` and `style`.
+    pub(crate) fn new(font: AssetId<FontMesh>, style: &TextMeshStyle) -> Self {
+        Self {
+            font,
+            style: style.clone(),
+            parked: Vec::new(),
+            shared: HashMap::default(),
+        }
+    }
+
+    /// Whether the glyphs were generated with `font` and `style`.
+    pub(crate) fn generated_with(&self, font: AssetId<FontMesh>, style: &TextMeshStyle) -> bool {
+        self.font == font && self.style == *style
+    }
+
+    /// Switch to `font` and `style`, after which no parked mesh fits any glyph.
+    pub(crate) fn restyle(&mut self, font: AssetId<FontMesh>, style: &TextMeshStyle) {
+        if !self.generated_with(font, style) {
+            self.font = font;
+            self.style = style.clone();
+            self.forget_shapes();
+        }
+    }
+
+    /// Forget that the glyphs match their font, after the font asset was modified, so
+    /// every glyph mesh is built again.
+    pub(crate) fn invalidate(&mut self) {
+        self.font = AssetId::invalid();
+        self.forget_shapes();
+    }
+
+    /// Forget the shared meshes and the shapes of parked meshes, which no longer fit.
+    fn forget_shapes(&mut self) {
+        self.shared.clear();
+        for parked in &mut self.parked {
+            parked.shape = None;
+        }
+    }
+
+    /// The mesh shared by every glyph of the shape of `glyph`, built on first use.
+    /// `None` if the glyph has no geometry.
+    pub(crate) fn mesh(
+        &mut self,
+        meshes: &mut Assets<Mesh>,
+        glyph: &LineGlyph,
+        style: &TextMeshStyle,
+    ) -> Option<SharedMesh> {
+        self.shared
+            .entry(GlyphShape::of(glyph).key(style))
+            .or_insert_with(|| {
+                let (mesh, pivot) = build_glyph_mesh(glyph, style)?;
+                Some(SharedMesh {
+                    aabb: mesh.compute_aabb().unwrap_or_default(),
+                    mesh: meshes.add(mesh),
+                    pivot,
+                })
+            })
+            .clone()
+    }
+
+    /// Drop the shared meshes of shapes that neither `shown` nor a parked glyph draws,
+    /// so their assets are freed once no glyph uses them.
+    pub(crate) fn release_unused(
+        &mut self,
+        shown: impl IntoIterator<Item = ShapeKey>,
+        style: &TextMeshStyle,
+    ) {
+        let mut used: HashSet<ShapeKey> = shown.into_iter().collect();
+        used.extend(
+            self.parked
+                .iter()
+                .filter_map(|parked| parked.shape.map(|shape| shape.key(style))),
+        );
+        self.shared.retain(|key, _| used.contains(key));
+    }
+
+    /// Forget parked entities that were despawned by something else.
+    pub(crate) fn retain(&mut self, alive: impl Fn(Entity) -> bool) {
+        self.parked.retain(|parked| alive(parked.entity));
+    }
+
+    /// Hide a glyph entity for later reuse, or despawn it when the pool is full.
+    ///
+    /// The entity leaves the children of the text and loses every component that isn't
+    /// needed to show it again.
+    pub(crate) fn park(
+        &mut self,
+        commands: &mut Commands,
+        entity: Entity,
+        shape: Option<GlyphShape>,
+        mesh: &Mesh3d,
+    ) {
+        if self.parked.len() >= MAX_PARKED_GLYPHS {
+            commands.entity(entity).despawn();
+            return;
+        }
+        commands
+            .entity(entity)
+            .remove::<ChildOf>()
+            .insert((ParkedGlyph, Visibility::Hidden))
+            .retain::<ParkedGlyphBundle>();
+        self.parked.push(Parked {
+            entity,
+            shape,
+            mesh: mesh.0.clone(),
+        });
+    }
+
+    /// Show a glyph at its pivot as a child of `parent`, unless it has no geometry.
+    /// Returns whether the glyph is shown.
+    ///
+    /// Takes a parked entity if there is one, preferring one whose mesh already draws
+    /// the glyph; other glyphs get the mesh shared by every glyph of their shape.
+    /// Parked entities are taken oldest first and added back to the children of `parent`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn place(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        parent: Entity,
+        glyph_mesh: GlyphMesh,
+        glyph: &LineGlyph,
+        style: &TextMeshStyle,
+        material: Handle<StandardMaterial>,
+    ) -> bool {
+        let shape = GlyphShape::of(glyph);
+        let fitting = self.parked.iter().position(|parked| {
+            parked
+                .shape
+                .is_some_and(|parked| parked.fits(&shape, style))
+        });
+        // The glyph entity starts at the pivot of its mesh
+        let glyph_bundle = |mut glyph_mesh: GlyphMesh, shape: GlyphShape| {
+            glyph_mesh.origin += shape.pivot;
+            (
+                Transform::from_translation(glyph_mesh.origin),
+                glyph_mesh,
+                shape,
+                GlyphSlotMaterial(material.id()),
+                MeshMaterial3d(material),
+                Visibility::Inherited,
+            )
+        };
+
+        // 1. A parked glyph of the same shape only needs to be shown again
+        if let Some(index) = fitting {
+            let parked = self.parked.remove(index);
+            let shape = shape.with_pivot(parked.shape.map_or(Vec3::ZERO, |shape| shape.pivot));
+            commands
+                .entity(parked.entity)
+                .remove::<ParkedGlyph>()
+                .insert((glyph_bundle(glyph_mesh, shape), Mesh3d(parked.mesh)));
+            commands.entity(parent).add_child(parked.entity);
+            return true;
+        }
+
+        // Share the mesh of this shape
+        let Some(shared) = self.mesh(meshes, glyph, style) else {
+            return false;
+        };
+        let glyph_bundle = glyph_bundle(glyph_mesh, shape.with_pivot(shared.pivot));
+
+        // 2. Any other parked glyph is shown with the shared mesh
+        if !self.parked.is_empty() {
+            let parked = self.parked.remove(0);
+            commands
+                .entity(parked.entity)
+                .remove::<ParkedGlyph>()
+                .insert((glyph_bundle, shared.aabb, Mesh3d(shared.mesh)));
+            commands.entity(parent).add_child(parked.entity);
+            return true;
+        }
+
+        // 3. Otherwise spawn glyph entity as child
+        commands.entity(parent).with_child((
+            glyph_bundle,
+            shared.aabb,
+            Mesh3d(shared.mesh),
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+        ));
+        true
+    }
+}
+
+/// Despawn the parked glyphs of a pool that is removed or despawned with its text.
+fn despawn_parked_glyphs(mut world: DeferredWorld, context: HookContext) {
+    let Some(pool) = world.get::<GlyphPool>(context.entity) else {
+        return;
+    };
+    let parked: Vec<Entity> = pool.parked.iter().map(|parked| parked.entity).collect();
+    let mut commands = world.commands();
+    for entity in parked {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.try_despawn();
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::core::mesh::TextMeshData;
+use bevy::prelude::*;
+
+/// Direction a [`TextGradient`] runs in.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GradientAxis {
+    /// From the left edge of the text to the right edge.
+    #[default]
+    Horizontal,
+    /// From the top of the text to the bottom.
+    Vertical,
+    /// From the front face of the text to the back of its extrusion.
+    Depth,
+}
+
+/// Vertex-color gradient across the bounding box of a [`TextMesh`](crate::TextMesh).
+///
+/// Set as [`TextMesh::gradient`](crate::TextMesh::gradient). The colors are blended in
+/// linear space and multiplied with the
+/// [`char_colors`](crate::TextMesh::char_colors), if any. Like those, Bevy's
+/// [`StandardMaterial`] multiplies its `base_color` by the result, so keep it white to
+/// see the colors as given.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn(TextMeshBundle {
+///     text_mesh: TextMesh {
+///         text: "GAME OVER".to_string(),
+///         font: asset_server.load("fonts/font.ttf"),
+///         gradient: Some(TextGradient::new(
+///             Color::srgb(1.0, 0.9, 0.2),
+///             Color::srgb(0.9, 0.1, 0.1),
+///             GradientAxis::Vertical,
+///         )),
+///         ..default()
+///     },
+///     ..default()
+/// });
+/// # }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct TextGradient {
+    /// Color at the start of the axis: the left edge, the top, or the front face.
+    pub start: Color,
+    /// Color at the end of the axis: the right edge, the bottom, or the back.
+    pub end: Color,
+    /// Direction the gradient runs in.
+    pub axis: GradientAxis,
+}
+
+impl TextGradient {
+    /// A gradient from `start` to `end` along `axis`.
+    pub fn new(start: Color, end: Color, axis: GradientAxis) -> Self {
+        Self { start, end, axis }
+    }
+
+    /// Position of `vertex` along the axis, growing from the start to the end.
+    fn coordinate(&self, vertex: &[f32; 3]) -> f32 {
+        match self.axis {
+            GradientAxis::Horizontal => vertex[0],
+            // Text is laid out towards -Y and extruded towards -Z
+            GradientAxis::Vertical => -vertex[1],
+            GradientAxis::Depth => -vertex[2],
+        }
+    }
+
+    /// Tint the vertices of `data` with the gradient across their bounds.
+    pub(crate) fn apply(&self, data: &mut TextMeshData) {
+        let (min, max) = data
+            .vertices
+            .iter()
+            .map(|vertex| self.coordinate(vertex))
+            .fold((f32::MAX, f32::MIN), |(min, max), x| {
+                (min.min(x), max.max(x))
+            });
+        let extent = max - min;
+        let start = self.start.to_linear().to_f32_array();
+        let end = self.end.to_linear().to_f32_array();
+
+        if data.colors.len() != data.vertices.len() {
+            data.colors = vec![[1.0; 4]; data.vertices.len()];
+        }
+        for (vertex, color) in data.vertices.iter().zip(&mut data.colors) {
+            let t = if extent > 0.0 {
+                (self.coordinate(vertex) - min) / extent
+            } else {
+                0.0
+            };
+            for ((channel, start), end) in color.iter_mut().zip(start).zip(end) {
+                *channel *= start + (end - start) * t;
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::caret::TextCursor3d;
+use crate::component::TextMesh;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+/// Makes a [`TextMesh`] an editable input field, for world-space UI and VR keyboards.
+///
+/// Put it on an entity with a [`TextMesh`]. While [`focused`](Self::focused), typed
+/// characters are inserted into [`value`](Self::value) at the [`cursor`](Self::cursor),
+/// and the usual editing keys work: Backspace and Delete, the left and right arrows,
+/// Home and End. Enter submits the value with a [`TextMeshInputSubmitted`] message, or
+/// starts a new line in [`multiline`](Self::multiline) inputs.
+///
+/// The value replaces the text of the [`TextMesh`], and only the edited line is
+/// tessellated again. A [`TextCursor3d`] on the same entity is kept at the cursor.
+/// Editing the value or cursor by hand, for example from the keys of a virtual
+/// keyboard, updates the text as well.
+///
+/// Reads Bevy's [`KeyboardInput`] messages, so the app needs the `InputPlugin`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn spawn_name_field(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TextMeshBundle {
+///             text_mesh: TextMesh {
+///                 font: asset_server.load("fonts/font.ttf"),
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         TextMeshInput {
+///             focused: true,
+///             max_chars: Some(16),
+///             ..default()
+///         },
+///         TextCursor3d::default(),
+///     ));
+/// }
+///
+/// fn greet(mut submitted: MessageReader<TextMeshInputSubmitted>) {
+///     for TextMeshInputSubmitted { value, .. } in submitted.read() {
+///         println!("Hello, {value}!");
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct TextMeshInput {
+    /// The text entered so far.
+    pub value: String,
+    /// Byte index in [`value`](Self::value) where typed text is inserted. Moved to the
+    /// closest character boundary if it lands inside a character.
+    pub cursor: usize,
+    /// Whether key presses go to this input.
+    pub focused: bool,
+    /// Whether Enter starts a new line instead of submitting the value.
+    pub multiline: bool,
+    /// Most characters the value may hold, counting line breaks. `None` for no limit.
+    pub max_chars: Option<usize>,
+}
+
+impl TextMeshInput {
+    /// An input holding `value`, with the cursor at its end.
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            cursor: value.len(),
+            value,
+            ..default()
+        }
+    }
+
+    /// Insert `text` at the cursor and move the cursor past it.
+    ///
+    /// Control characters are left out, and so are line breaks unless the input is
+    /// [`multiline`](Self::multiline). Characters past [`max_chars`](Self::max_chars)
+    /// are dropped.
+    pub fn insert(&mut self, text: &str) {
+        self.clamp_cursor();
+        let mut room = self.max_chars.map_or(usize::MAX, |max| {
+            max.saturating_sub(self.value.chars().count())
+        });
+        for character in text.chars() {
+            let allowed = if character == '\n' {
+                self.multiline
+            } else {
+                !character.is_control()
+            };
+            if !allowed || room == 0 {
+                continue;
+            }
+            self.value.insert(self.cursor, character);
+            self.cursor += character.len_utf8();
+            room -= 1;
+        }
+    }
+
+    /// Remove the character before the cursor.
+    pub fn backspace(&mut self) {
+        self.clamp_cursor();
+        if let Some(character) = self.value[..self.cursor].chars().next_back() {
+            self.cursor -= character.len_utf8();
+            self.value.remove(self.cursor);
+        }
+    }
+
+    /// Remove the character after the cursor.
+    pub fn delete(&mut self) {
+        self.clamp_cursor();
+        if self.cursor < self.value.len() {
+            self.value.remove(self.cursor);
+        }
+    }
+
+    /// Move the cursor one character to the left.
+    pub fn move_left(&mut self) {
+        self.clamp_cursor();
+        if let Some(character) = self.value[..self.cursor].chars().next_back() {
+            self.cursor -= character.len_utf8();
+        }
+    }
+
+    /// Move the cursor one character to the right.
+    pub fn move_right(&mut self) {
+        self.clamp_cursor();
+        if let Some(character) = self.value[self.cursor..].chars().next() {
+            self.cursor += character.len_utf8();
+        }
+    }
+
+    /// Move the cursor to the start of its line.
+    pub fn move_home(&mut self) {
+        self.clamp_cursor();
+        self.cursor = self.value[..self.cursor].rfind('\n').map_or(0, |i| i + 1);
+    }
+
+    /// Move the cursor to the end of its line.
+    pub fn move_end(&mut self) {
+        self.clamp_cursor();
+        self.cursor = self.value[self.cursor..]
+            .find('\n')
+            .map_or(self.value.len(), |i| self.cursor + i);
+    }
+
+    /// Move the cursor back onto a character boundary of the value.
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.value.len());
+        while !self.value.is_char_boundary(self.cursor) {
+            self.cursor -= 1;
+        }
+    }
+}
+
+/// Message sent when Enter is pressed in a focused single-line [`TextMeshInput`].
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub struct TextMeshInputSubmitted {
+    /// The input entity.
+    pub entity: Entity,
+    /// The value of the input.
+    pub value: String,
+}
+
+/// System that applies key presses to focused [`TextMeshInput`]s and shows their values.
+pub fn update_text_mesh_inputs(
+    mut keys: MessageReader<KeyboardInput>,
+    mut submitted: MessageWriter<TextMeshInputSubmitted>,
+    mut inputs: Query<(
+        Entity,
+        &mut TextMeshInput,
+        &mut TextMesh,
+        Option<&mut TextCursor3d>,
+    )>,
+) {
+    let presses: Vec<&KeyboardInput> = keys
+        .read()
+        .filter(|key| key.state == ButtonState::Pressed)
+        .collect();
+
+    for (entity, mut input, mut text_mesh, cursor) in inputs.iter_mut() {
+        // 1. Edit the value of inputs that have focus
+        if input.focused {
+            for key in &presses {
+                match &key.logical_key {
+                    Key::Backspace => input.backspace(),
+                    Key::Delete => input.delete(),
+                    Key::ArrowLeft => input.move_left(),
+                    Key::ArrowRight => input.move_right(),
+                    Key::Home => input.move_home(),
+                    Key::End => input.move_end(),
+                    Key::Enter if input.multiline => input.insert("\n"),
+                    Key::Enter => {
+                        submitted.write(TextMeshInputSubmitted {
+                            entity,
+                            value: input.value.clone(),
+                        });
+                    }
+                    _ => {
+                        if let Some(text) = &key.text {
+                            input.insert(text);
+                        }
+                    }
+                }
+            }
+        }
+
+        // 2. Show the value; unchanged text keeps its mesh
+        if !input.is_changed() {
+            continue;
+        }
+        input.bypass_change_detection().clamp_cursor();
+        if text_mesh.text != input.value {
+            text_mesh.text.clone_from(&input.value);
+        }
+        if let Some(mut cursor) = cursor {
+            if cursor.byte_index != input.cursor {
+                cursor.byte_index = input.cursor;
+            }
+        }
+    }
+}
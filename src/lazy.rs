@@ -0,0 +1,225 @@
+use crate::component::{TextMesh, TextMesh2d, TextMeshGlyphs, TextMeshParts};
+use crate::core::fallback::FontChain;
+use crate::core::layout::layout_text_in;
+use crate::core::mesh::calculate_anchor_offset;
+use crate::core::style::TextMeshStyle;
+use crate::path::TextPath;
+use crate::sections::{estimate_sections_bounds, TextMeshSections, TextMeshSectionsComputed};
+use crate::system::{
+    TextMesh2dComputed, TextMeshComputed, TextMeshGlyphsComputed, TextMeshPartsComputed,
+};
+use crate::FontMesh;
+use bevy::camera::primitives::{Aabb, Frustum};
+use bevy::prelude::*;
+
+/// Puts off generating text until it comes close to a camera's view.
+///
+/// Works on every generated text type: [`TextMesh`], [`TextMeshGlyphs`],
+/// [`TextMeshParts`], [`TextMeshSections`], and [`TextMesh2d`].
+///
+/// Before the text is tessellated, its extent is estimated from the font metrics. While
+/// that box, grown by [`margin`](Self::margin), is outside the frustum of every active
+/// camera, the text gets a [`TextMeshDeferred`] marker and no mesh is built. Once a
+/// camera turns towards it or it moves into view, the marker is removed and the mesh is
+/// generated on the same frame. Edits of deferred text are held back the same way. In a
+/// large world full of signs, only the ones near the cameras are tessellated at startup.
+///
+/// Text that was already generated keeps its mesh when it leaves the view. Worlds
+/// without cameras generate all text, as do texts laid out along a [`TextPath`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// for x in 0..1000 {
+///     commands.spawn((
+///         TextMeshBundle {
+///             text_mesh: TextMesh {
+///                 text: format!("Plot {x}"),
+///                 font: asset_server.load("fonts/font.ttf"),
+///                 ..default()
+///             },
+///             transform: Transform::from_xyz(x as f32 * 20.0, 0.0, 0.0),
+///             ..default()
+///         },
+///         TextMeshLazy { margin: 5.0 },
+///     ));
+/// }
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshLazy {
+    /// How far around the estimated bounds, in the text's local units, a camera's view
+    /// has to reach to generate the text. Larger values generate text earlier, before it
+    /// pops into view. Default: `1.0`.
+    pub margin: f32,
+}
+
+impl Default for TextMeshLazy {
+    fn default() -> Self {
+        Self { margin: 1.0 }
+    }
+}
+
+/// Present on a [`TextMeshLazy`] text whose generation is put off because it is out of
+/// view.
+///
+/// Query `With<TextMeshDeferred>` to find text that has no up-to-date mesh yet.
+#[derive(Component, Clone, Debug)]
+pub struct TextMeshDeferred {
+    /// Estimated bounds of the text, grown by the margin, in its local space.
+    estimate: Aabb,
+}
+
+/// Text components whose generation [`TextMeshLazy`] can put off.
+pub(crate) trait LazyText: Component {
+    /// Marker whose absence makes the generation system of the text build its mesh.
+    type Computed: Component;
+
+    /// Extent of the text estimated from its layout, in its local space, or `None`
+    /// while its fonts are loading.
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)>;
+}
+
+/// Anchored extent of `text` laid out in one font and style, `depth` deep.
+fn estimate_text_bounds(
+    font_assets: &Assets<FontMesh>,
+    text: &str,
+    font: &Handle<FontMesh>,
+    style: &TextMeshStyle,
+    depth: f32,
+) -> Option<(Vec3, Vec3)> {
+    let fonts = FontChain::for_style(font_assets, font, style)?;
+    let bounds = layout_text_in(&fonts, text, style).bounds;
+    let (min, max) = (bounds.min.extend(-depth), bounds.max.extend(0.0));
+    let offset = calculate_anchor_offset(style.anchor, min, max);
+    Some((min + offset, max + offset))
+}
+
+impl LazyText for TextMesh {
+    type Computed = TextMeshComputed;
+
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)> {
+        estimate_text_bounds(
+            font_assets,
+            &self.text,
+            &self.font,
+            &self.style,
+            self.style.depth,
+        )
+    }
+}
+
+impl LazyText for TextMeshGlyphs {
+    type Computed = TextMeshGlyphsComputed;
+
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)> {
+        estimate_text_bounds(
+            font_assets,
+            &self.text,
+            &self.font,
+            &self.style,
+            self.style.depth,
+        )
+    }
+}
+
+impl LazyText for TextMeshParts {
+    type Computed = TextMeshPartsComputed;
+
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)> {
+        estimate_text_bounds(
+            font_assets,
+            &self.text,
+            &self.font,
+            &self.style,
+            self.style.depth,
+        )
+    }
+}
+
+impl LazyText for TextMesh2d {
+    type Computed = TextMesh2dComputed;
+
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)> {
+        // Flat meshes have no extrusion
+        estimate_text_bounds(font_assets, &self.text, &self.font, &self.style, 0.0)
+    }
+}
+
+impl LazyText for TextMeshSections {
+    type Computed = TextMeshSectionsComputed;
+
+    fn estimate_bounds(&self, font_assets: &Assets<FontMesh>) -> Option<(Vec3, Vec3)> {
+        estimate_sections_bounds(self, font_assets)
+    }
+}
+
+type LazyTextQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, T>,
+        Ref<'static, TextMeshLazy>,
+        &'static GlobalTransform,
+        Option<&'static TextMeshDeferred>,
+        Has<<T as LazyText>::Computed>,
+    ),
+    Without<TextPath>,
+>;
+
+/// System that defers the generation of [`TextMeshLazy`] text out of every camera's
+/// view, and resumes it once the text comes close.
+pub(crate) fn defer_offscreen_text_meshes<T: LazyText>(
+    mut commands: Commands,
+    font_assets: Res<Assets<FontMesh>>,
+    cameras: Query<(&Camera, &Frustum)>,
+    texts: LazyTextQuery<T>,
+) {
+    let frusta: Vec<&Frustum> = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .map(|(_, frustum)| frustum)
+        .collect();
+
+    for (entity, text, lazy, transform, deferred, computed) in texts.iter() {
+        // 1. Only text waiting for a mesh can be deferred
+        let edited = text.is_changed() || lazy.is_changed();
+        if deferred.is_none() && computed && !edited {
+            continue;
+        }
+
+        // 2. Estimate the bounds again only when the text changed
+        let estimate = match deferred {
+            Some(deferred) if !edited => deferred.estimate,
+            _ => {
+                let Some((min, max)) = text.estimate_bounds(&font_assets) else {
+                    continue;
+                };
+                Aabb::from_min_max(min - lazy.margin, max + lazy.margin)
+            }
+        };
+
+        // 3. Generate text close to a view, and hold back the rest
+        let world_from_local = transform.affine();
+        let near_view = frusta.is_empty()
+            || frusta
+                .iter()
+                .any(|frustum| frustum.intersects_obb(&estimate, &world_from_local, true, true));
+        if near_view {
+            if deferred.is_some() {
+                commands.entity(entity).remove::<TextMeshDeferred>();
+            }
+        } else if deferred.is_none() || edited {
+            // Without the computed marker, the mesh is generated once the text is shown
+            commands
+                .entity(entity)
+                .insert(TextMeshDeferred { estimate })
+                .remove::<T::Computed>();
+        }
+    }
+}
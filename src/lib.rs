@@ -37,66 +37,319 @@
 //!
 //! # Features
 //!
-//! - Generates 3D mesh geometry from TrueType fonts
-//! - Supports multiline text with `\n` line breaks
-//! - Configurable text anchoring (9 presets + custom pivot points)
-//! - Text justification (left, center, right)
-//! - Adjustable extrusion depth and curve subdivision
-//! - Automatic mesh regeneration when text or style changes
+//! ## Layout
+//!
+//! - Multiline text with nine anchors, baseline anchors, and custom pivots via [`TextAnchor`]
+//! - Left, center, right, and justified lines via [`JustifyText`]
+//! - Letter, word, and line spacing, and font sizes in mesh units, via [`TextMeshStyle`]
+//! - Lines cut off at a width or character count, with an optional ellipsis, via [`TextOverflow`]
+//! - Vertical text in right-to-left or left-to-right columns via [`TextOrientation`]
+//! - Uppercase, lowercase, and small caps without changing the text via [`CaseTransform`]
+//! - Alternate letterforms from a font's stylistic sets via [`StylisticSet`]
+//! - Weight, width, and other variable font axes via [`TextMeshStyle::variations`]
+//! - Ligatures, kerning, and complex scripts shaped by rustybuzz (`shaping` feature)
+//! - Rich text mixing fonts, sizes, depths, and materials via [`TextMeshSections`] and [`Text3d`]
+//! - Text along arcs, circles, and splines via [`TextPath`]
+//! - Leaderboards and stat screens with aligned columns via [`TextTable`]
+//! - One font and style for a whole hierarchy of texts via [`TextStyleRoot`]
+//! - Common baselines for separately spawned texts via [`TextBaselineGroup`]
+//! - Measured text and line bounds for backplates and highlights via [`TextMeshBounds`]
+//!
+//! ## Geometry and materials
+//!
+//! - Rounded or chamfered bevels on the extrusion edges via [`BevelStyle`]
+//! - Tapered and curved extrusions via [`ExtrusionProfile`]
+//! - Flat or smoothly shaded side walls via [`SideShading`]
+//! - Outline-only, banded, and engraved lettering via [`TextRenderMode`]
+//! - Underline, strikethrough, and overline bars via [`TextDecorations`]
+//! - Faux bold and italic via [`TextMeshStyle::synthetic_bold`] and `synthetic_italic`
+//! - A seeded hand-carved look with small per-glyph variation via [`Handcrafted`]
+//! - Curve detail set by an error tolerance via [`TextMeshStyle::curve_tolerance`]
+//! - Separate materials for the front, back, and extrusion sides via [`TextMeshParts`]
+//! - Per-letter vertex colors via [`TextMesh::char_colors`] and gradients via [`TextGradient`]
+//! - A cheap glow shell around extruded text via [`TextMeshGlow`]
+//! - Bevels baked into normal maps for cheap flat meshes via [`bake_bevel_normal_map`]
+//! - Flat text for the 2D pipeline via [`TextMesh2d`]
+//! - Text rendered into an image for in-world screens via [`spawn_text_texture`]
+//!
+//! ## Glyph entities and effects
+//!
+//! - One entity per character to style or animate via [`TextMeshGlyphs`]
+//! - Wave, bounce, and shake animations via [`GlyphWave`], [`GlyphBounce`], and [`GlyphShake`]
+//! - Glyphs that spin and scale around their center or another [`GlyphPivot`]
+//! - Hover and click events on single glyphs via [`GlyphPointer`] (`picking` feature)
+//! - Cached single-glyph meshes for effects of your own via [`GlyphMeshes`]
+//! - Blinking marquee bulbs along the letter outlines via [`TextMeshMarquee`]
+//!
+//! ## Game and UI text
+//!
+//! - Scores and timers that swap pre-built digits via [`TextMeshCounter`]
+//! - Scrolling ticker text clipped or faded to a window via [`TextTicker`]
+//! - Log-style text that only builds appended lines via [`TextMeshAppend`]
+//! - Cross-fades, slides, and scales between old and new text via [`TextMeshTransition`]
+//! - Wipe and dissolve reveals via [`TextMeshReveal`]
+//! - Text that fades out and despawns itself via [`TextMeshLifetime`]
+//! - Damage numbers and pickup notices that float away and fade via [`FloatingText3d`]
+//! - Labels that turn to face the camera via [`TextBillboard`]
+//! - Stacks of world-space notifications via [`Toast3dStack`] and [`Toast3dPlugin`]
+//! - Carets and selections for in-world editors via [`TextCursor3d`] and [`TextSelection3d`]
+//! - Editable input fields for world-space UI and VR keyboards via [`TextMeshInput`]
+//! - Visible markers for spaces, tabs, and line breaks via [`TextMeshWhitespace`]
+//! - Closest points, ray casts, and hit-tests via [`TextMeshLayout`] and [`TextMeshBvh`]
+//! - Per-line 2D outlines for gameplay collision via [`TextSilhouette`]
+//! - Collision geometry via [`TextMeshCollider`], with `avian3d` and `bevy_rapier3d` colliders
+//!
+//! ## Performance
+//!
+//! - One shared mesh for identical texts, with an opt-out via [`TextMeshCachePolicy`]
+//! - Regeneration of only the edited lines when text or style changes
+//! - Background generation on the async compute pool via [`TextMeshAsync`]
+//! - Coarser meshes for distant text via [`TextMeshLod`]
+//! - Generation of offscreen text put off until it nears a camera via [`TextMeshLazy`]
+//! - Glyphs of long lines tessellated in parallel
+//! - Welded duplicate vertices for smaller meshes via [`TextMeshStyle::optimize`]
+//!
+//! ## Fonts and tooling
+//!
+//! - Fallback fonts and `.notdef` or replacement boxes via [`MissingGlyphPolicy`]
+//! - Placeholders and a [`TextMeshError`] for fonts that never load via [`FontLoadTimeout`]
+//! - An embedded default font for texts without one (`default-font` feature)
+//! - Regeneration of every text using a font when it is hot reloaded
+//! - [`TextMeshReady`] and [`GlyphMeshesReady`] messages when generation finishes
+//! - Plugin-wide default materials and a material factory via [`FontMeshSettings`]
+//! - Opt-in checks for common misconfigurations via [`TextMeshValidation`]
+//! - Recording and playback of text for replays via [`TextMeshRecorder`]
+//! - Golden snapshot tests for generated geometry via [`assert_mesh_snapshot`]
+//! - Benchmark workloads for target hardware in the [`stress`] module
+//! - Glyph placement without entities or meshes via [`layout_text`]
+//! - Layout and tessellation without Bevy for build scripts and other engines in [`core`]
 //!
 //! # Font Format Support
 //!
 //! - TrueType (`.ttf`) fonts are fully supported
 //! - OpenType (`.otf`) fonts with TrueType outlines work
+//! - Font collections (`.ttc`, `.otc`) load their first face, or the face given as the
+//!   asset label, e.g. `fonts/msgothic.ttc#1`; see [`FontMesh::face_index`]
+//! - WOFF and WOFF2 web fonts (`.woff`, `.woff2`) are decompressed on load with the
+//!   `woff` feature
 //! - OpenType fonts with CFF/PostScript outlines are not supported (ttf-parser limitation)
 
+#[cfg(feature = "bevy")]
+mod append;
+#[cfg(feature = "bevy")]
 mod asset;
+#[cfg(feature = "bevy")]
+mod bake;
+#[cfg(feature = "bevy")]
+mod baseline;
+#[cfg(feature = "bevy")]
+mod billboard;
+#[cfg(feature = "bevy")]
+mod bounds;
+#[cfg(feature = "bevy")]
+mod bvh;
+#[cfg(feature = "bevy")]
+mod caret;
+#[cfg(feature = "bevy")]
+mod collider;
+#[cfg(feature = "bevy")]
+mod commands;
+#[cfg(feature = "bevy")]
 mod component;
+pub mod core;
+#[cfg(feature = "bevy")]
+mod counter;
+#[cfg(feature = "bevy")]
+mod floating;
+#[cfg(feature = "bevy")]
+mod font_path;
+#[cfg(feature = "bevy")]
+mod glow;
+#[cfg(feature = "bevy")]
+mod glyph_animation;
+#[cfg(feature = "bevy")]
+mod glyph_meshes;
+#[cfg(feature = "bevy")]
+mod glyph_pool;
+#[cfg(feature = "bevy")]
+mod gradient;
+#[cfg(feature = "bevy")]
+mod input;
+#[cfg(feature = "bevy")]
+mod lazy;
+#[cfg(feature = "bevy")]
+mod lifetime;
+#[cfg(feature = "bevy")]
+mod line_cache;
+#[cfg(feature = "bevy")]
+mod lod;
+#[cfg(feature = "bevy")]
+mod marquee;
+#[cfg(feature = "bevy")]
+mod path;
+#[cfg(feature = "picking")]
+mod picking;
+#[cfg(feature = "bevy")]
+mod placeholder;
+#[cfg(feature = "bevy")]
+mod plugin;
 pub mod prelude;
+#[cfg(feature = "bevy")]
+mod reload;
+#[cfg(feature = "bevy")]
+mod replay;
+#[cfg(feature = "bevy")]
+mod reveal;
+#[cfg(feature = "bevy")]
+mod sections;
+#[cfg(feature = "bevy")]
+mod settings;
+#[cfg(feature = "bevy")]
+mod shared_mesh;
+#[cfg(feature = "bevy")]
+mod silhouette;
+#[cfg(feature = "bevy")]
+mod snapshot;
+#[cfg(feature = "bevy")]
+mod span;
+#[cfg(feature = "bevy")]
+pub mod stress;
+#[cfg(feature = "bevy")]
+mod style_root;
+#[cfg(feature = "bevy")]
 mod system;
+#[cfg(feature = "bevy")]
+mod table;
+#[cfg(feature = "bevy")]
+mod task;
+#[cfg(feature = "bevy")]
+mod texture;
+#[cfg(feature = "bevy")]
+mod ticker;
+#[cfg(feature = "bevy")]
+mod toast;
+#[cfg(feature = "bevy")]
+mod transition;
+#[cfg(feature = "bevy")]
+mod validation;
+#[cfg(feature = "bevy")]
+mod whitespace;
 
-pub use asset::{FontMesh, FontMetrics, GlyphMetrics};
+#[cfg(feature = "default-font")]
+pub use crate::core::DEFAULT_FONT_DATA;
+pub use crate::core::{
+    layout_text, pad_figures, BevelProfile, BevelStyle, CaseTransform, ExtrusionProfile,
+    FallbackFonts, FontMesh, FontMetrics, GlyphId, GlyphMetrics, GlyphPivot, Handcrafted,
+    JustifyText, LayoutLine, MissingGlyphPolicy, PositionedGlyph, SideShading, StylisticSet, Tag,
+    TextAnchor, TextDecorations, TextLayout, TextMeshStyle, TextOrientation, TextOverflow,
+    TextRenderMode,
+};
+#[cfg(feature = "bevy")]
+pub use append::TextMeshAppend;
+#[cfg(feature = "bevy")]
+pub use bake::{bake_bevel_normal_map, BakedTextMesh, BevelBakeSettings};
+#[cfg(feature = "bevy")]
+pub use baseline::TextBaselineGroup;
+#[cfg(feature = "bevy")]
+pub use billboard::TextBillboard;
+#[cfg(feature = "bevy")]
+pub use bounds::TextMeshBounds;
+#[cfg(feature = "bevy")]
+pub use bvh::TextMeshBvh;
+#[cfg(feature = "bevy")]
+pub use caret::{TextCursor3d, TextCursor3dQuad, TextSelection3d, TextSelection3dQuads};
+#[cfg(feature = "bevy")]
+pub use collider::{TextColliderData, TextColliderShape, TextMeshCollider};
+#[cfg(feature = "bevy")]
+pub use commands::SpawnTextMeshExt;
+#[cfg(feature = "bevy")]
 pub use component::{
-    GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshBundle, TextMeshGlyphs,
-    TextMeshGlyphsBundle, TextMeshStyle,
+    CharClass, GlyphMaterials, GlyphMesh, TextMesh, TextMesh2d, TextMesh2dBundle, TextMeshBundle,
+    TextMeshGlyphs, TextMeshGlyphsBundle, TextMeshPart, TextMeshPartMaterials, TextMeshParts,
+    TextMeshPartsBundle,
 };
-pub use system::{generate_glyph_mesh, TextMeshComputed, TextMeshGlyphsComputed};
-
-use asset::FontMeshLoader;
-use bevy::prelude::*;
-use system::{update_glyph_meshes, update_text_meshes};
-
-/// Plugin that enables 3D text mesh generation from fonts.
-///
-/// This plugin registers the necessary assets, loaders, and systems to automatically
-/// generate 3D mesh geometry from [`TextMesh`] components. Simply add this plugin to
-/// your Bevy app and spawn entities with [`TextMeshBundle`].
-///
-/// # Example
-///
-/// ```no_run
-/// use bevy::prelude::*;
-/// use bevy_fontmesh::FontMeshPlugin;
-///
-/// App::new()
-///     .add_plugins(DefaultPlugins)
-///     .add_plugins(FontMeshPlugin)
-///     .run();
-/// ```
-///
-/// The plugin automatically:
-/// - Registers the [`FontMesh`] asset type for loading TTF/OTF fonts
-/// - Adds a system that generates meshes when [`TextMesh`] components are added or changed
-/// - Enables reflection for [`TextMesh`] components for editor integration
-pub struct FontMeshPlugin;
-
-impl Plugin for FontMeshPlugin {
-    fn build(&self, app: &mut App) {
-        app.init_asset::<FontMesh>()
-            .init_asset_loader::<FontMeshLoader>()
-            .register_type::<TextMesh>()
-            .register_type::<TextMeshGlyphs>()
-            .register_type::<GlyphMesh>()
-            .add_systems(Update, (update_text_meshes, update_glyph_meshes));
-    }
-}
+#[cfg(feature = "bevy")]
+pub use counter::{CounterFormat, TextMeshCounter};
+#[cfg(feature = "bevy")]
+pub use floating::FloatingText3d;
+#[cfg(feature = "bevy")]
+pub use font_path::TextMeshFontPaths;
+#[cfg(feature = "bevy")]
+pub use glow::{TextMeshGlow, TextMeshGlowShell};
+#[cfg(feature = "bevy")]
+pub use glyph_animation::{GlyphBounce, GlyphShake, GlyphWave};
+#[cfg(feature = "bevy")]
+pub use glyph_meshes::GlyphMeshes;
+#[cfg(feature = "bevy")]
+pub use gradient::{GradientAxis, TextGradient};
+#[cfg(feature = "bevy")]
+pub use input::{TextMeshInput, TextMeshInputSubmitted};
+#[cfg(feature = "bevy")]
+pub use lazy::{TextMeshDeferred, TextMeshLazy};
+#[cfg(feature = "bevy")]
+pub use lifetime::{TextMeshExpiry, TextMeshFade, TextMeshLifetime};
+#[cfg(feature = "bevy")]
+pub use lod::{TextMeshLod, TextMeshLodLevel, TextMeshLodMeshes};
+#[cfg(feature = "bevy")]
+pub use marquee::{MarqueeBlink, MarqueeBulb, TextMeshMarquee, TextMeshMarqueeComputed};
+#[cfg(feature = "bevy")]
+pub use path::TextPath;
+#[cfg(feature = "picking")]
+pub use picking::GlyphPointer;
+#[cfg(feature = "bevy")]
+pub use placeholder::{FontLoadTimeout, TextMeshError};
+#[cfg(feature = "bevy")]
+pub use plugin::{FontMeshPlugin, FontMeshPluginConfig, FontMeshSystems};
+#[cfg(feature = "bevy")]
+pub use replay::{
+    ReplayFont, ReplayedText, TextMeshPlayback, TextMeshRecorder, TextReplayEvent,
+    TextReplayStream, TextSnapshot,
+};
+#[cfg(feature = "bevy")]
+pub use reveal::{TextMeshReveal, ATTRIBUTE_GLYPH_SEED};
+#[cfg(feature = "bevy")]
+pub use sections::{
+    TextMeshSection, TextMeshSectionMesh, TextMeshSections, TextMeshSectionsBundle,
+    TextMeshSectionsComputed,
+};
+#[cfg(feature = "bevy")]
+pub use settings::{FontMeshSettings, MaterialFactory, MaterialTarget};
+#[cfg(feature = "bevy")]
+pub use shared_mesh::TextMeshCachePolicy;
+#[cfg(feature = "bevy")]
+pub use silhouette::{
+    LineSilhouette, LineStripes, TextLineBox, TextMeshLayout, TextMeshRayHit, TextSilhouette,
+    TextSilhouetteComputed,
+};
+#[cfg(feature = "bevy")]
+pub use snapshot::{
+    assert_mesh_snapshot, MeshSnapshot, MeshSnapshotMismatch, MeshSnapshotParseError,
+};
+#[cfg(feature = "bevy")]
+pub use span::{Text3d, Text3dStyle, TextFont3d, TextSpan3d};
+#[cfg(feature = "bevy")]
+pub use style_root::{TextStyleOverride, TextStyleRoot};
+#[cfg(feature = "bevy")]
+pub use system::{
+    generate_glyph_mesh, GlyphMeshesReady, TextMesh2dComputed, TextMeshComputed,
+    TextMeshGlyphsComputed, TextMeshPartsComputed, TextMeshReady,
+};
+#[cfg(feature = "bevy")]
+pub use table::{
+    ColumnWidth, TableRules, TextTable, TextTableCell, TextTableColumn, TextTableLayout,
+    TextTableRules,
+};
+#[cfg(feature = "bevy")]
+pub use task::{TextMeshAsync, TextMeshTask};
+#[cfg(feature = "bevy")]
+pub use texture::{spawn_text_texture, TextTexture, TextTextureCamera, TextTextureSettings};
+#[cfg(feature = "bevy")]
+pub use ticker::{TextTicker, TickerEdge};
+#[cfg(feature = "bevy")]
+pub use toast::{Toast3d, Toast3dPlugin, Toast3dStack};
+#[cfg(feature = "bevy")]
+pub use transition::{TextMeshTransition, TextMeshTransitionGhost};
+#[cfg(feature = "bevy")]
+pub use validation::{TextMeshValidation, TextMeshWarning, TextMeshWarningKind};
+#[cfg(feature = "bevy")]
+pub use whitespace::{TextMeshWhitespace, TextMeshWhitespaceMarkers};
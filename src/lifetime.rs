@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+
+/// How a [`TextMeshLifetime`] fades its text out.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextMeshFade {
+    /// Lower the alpha of the text's materials to zero.
+    ///
+    /// Each mesh of the text gets its own copy of its material when the fade starts,
+    /// switched to [`AlphaMode::Blend`] if it was opaque, so other texts sharing the
+    /// material are left alone. Needs Bevy's PBR materials.
+    #[default]
+    Alpha,
+    /// Shrink the text towards its origin.
+    Scale,
+}
+
+/// What happens to text when its [`TextMeshLifetime`] is over.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextMeshExpiry {
+    /// Despawn the text together with its children.
+    #[default]
+    Despawn,
+    /// Hide the text and remove its lifetime, keeping the entity to be shown again.
+    ///
+    /// The fade is undone, so the text comes back with its scale and materials when it
+    /// is made visible. For pools of short-lived text, like the toasts of a
+    /// [`Toast3dStack`](crate::Toast3dStack).
+    Hide,
+}
+
+/// Despawns text after a while, fading it out first.
+///
+/// For popups, notifications, and damage numbers that should disappear on their own.
+/// The text lives for [`duration`](Self::duration) seconds and fades out during the
+/// last [`fade_out`](Self::fade_out) of them. It is then despawned together with its
+/// children, such as the glyphs of a [`TextMeshGlyphs`](crate::TextMeshGlyphs), and
+/// its generated meshes are freed once nothing else displays them. With
+/// [`TextMeshExpiry::Hide`] it is hidden instead, to be reused.
+///
+/// Works on any text entity with a mesh, and on the entities above its meshes such as
+/// [`TextMeshSections`](crate::TextMeshSections) and [`Text3d`](crate::Text3d) roots.
+/// Needs Bevy's [`Time`]. Setting [`elapsed`](Self::elapsed) back restarts the
+/// countdown, bringing a fading text back.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Checkpoint reached".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     // Visible for two seconds, then fading out over half a second
+///     TextMeshLifetime::new(2.5, 0.5),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshLifetime {
+    /// Seconds the text lives for, including the fade.
+    pub duration: f32,
+    /// Seconds at the end of the lifetime over which the text fades out, or `0.0` to
+    /// despawn it without fading.
+    pub fade_out: f32,
+    /// How the text fades out.
+    pub fade: TextMeshFade,
+    /// What happens to the text when its lifetime is over.
+    pub expiry: TextMeshExpiry,
+    /// Seconds the text has lived so far.
+    pub elapsed: f32,
+}
+
+impl TextMeshLifetime {
+    /// A lifetime of `duration` seconds, fading the alpha out over the last `fade_out`.
+    pub fn new(duration: f32, fade_out: f32) -> Self {
+        Self {
+            duration,
+            fade_out,
+            fade: TextMeshFade::default(),
+            expiry: TextMeshExpiry::default(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Set how the text fades out.
+    pub fn with_fade(mut self, fade: TextMeshFade) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Set what happens to the text when its lifetime is over.
+    pub fn with_expiry(mut self, expiry: TextMeshExpiry) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// How visible the text is, from `1.0` before the fade starts to `0.0` at the end.
+    pub fn opacity(&self) -> f32 {
+        let remaining = self.duration - self.elapsed;
+        if self.fade_out <= 0.0 {
+            return if remaining > 0.0 { 1.0 } else { 0.0 };
+        }
+        (remaining / self.fade_out).clamp(0.0, 1.0)
+    }
+
+    /// Whether the lifetime is over.
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Marks a [`TextMeshLifetime`] text whose fade has started, with the scale it had.
+#[derive(Component)]
+pub(crate) struct FadeStart {
+    scale: Vec3,
+}
+
+/// Material of a mesh and its alpha before a [`TextMeshFade::Alpha`] fade gave the mesh
+/// its own copy.
+#[derive(Component)]
+pub(crate) struct FadeStartAlpha {
+    material: Handle<StandardMaterial>,
+    alpha: f32,
+}
+
+type FadingMeshQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut MeshMaterial3d<StandardMaterial>,
+        Option<&'static FadeStartAlpha>,
+    ),
+    With<Mesh3d>,
+>;
+
+/// System that counts down [`TextMeshLifetime`]s, fades the text, and despawns or hides it.
+///
+/// Runs before transform propagation, so scaled text is drawn at its faded size on the
+/// same frame.
+pub fn update_text_mesh_lifetimes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut texts: Query<(
+        Entity,
+        &mut TextMeshLifetime,
+        Option<&mut Transform>,
+        Option<&FadeStart>,
+    )>,
+    children: Query<&Children>,
+    mut meshes: FadingMeshQuery,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut lifetime, transform, start) in texts.iter_mut() {
+        lifetime.elapsed += delta;
+        if lifetime.is_expired() {
+            match lifetime.expiry {
+                TextMeshExpiry::Despawn => commands.entity(entity).despawn(),
+                TextMeshExpiry::Hide => {
+                    // Undo the fade, so the text can be shown again as it was
+                    if let (Some(start), Some(mut transform)) = (start, transform) {
+                        transform.scale = start.scale;
+                    }
+                    let faded = std::iter::once(entity).chain(children.iter_descendants(entity));
+                    for mesh in faded {
+                        let Ok((mut material, Some(start))) = meshes.get_mut(mesh) else {
+                            continue;
+                        };
+                        material.0 = start.material.clone();
+                        commands.entity(mesh).remove::<FadeStartAlpha>();
+                    }
+                    commands
+                        .entity(entity)
+                        .remove::<(TextMeshLifetime, FadeStart)>()
+                        .insert(Visibility::Hidden);
+                }
+            }
+            continue;
+        }
+        let opacity = lifetime.opacity();
+        let start = match start {
+            Some(start) => start.scale,
+            // Leave text alone until its fade starts
+            None if opacity >= 1.0 => continue,
+            None => {
+                let scale = transform
+                    .as_ref()
+                    .map_or(Vec3::ONE, |transform| transform.scale);
+                commands.entity(entity).insert(FadeStart { scale });
+                scale
+            }
+        };
+
+        match lifetime.fade {
+            TextMeshFade::Scale => {
+                if let Some(mut transform) = transform {
+                    transform.scale = start * opacity;
+                }
+            }
+            TextMeshFade::Alpha => {
+                let Some(materials) = materials.as_deref_mut() else {
+                    continue;
+                };
+                // Generated children, such as glyphs and sections, fade with the text
+                let fading = std::iter::once(entity).chain(children.iter_descendants(entity));
+                for mesh in fading {
+                    let Ok((mut material, start_alpha)) = meshes.get_mut(mesh) else {
+                        continue;
+                    };
+                    let alpha = match start_alpha {
+                        Some(start) => start.alpha,
+                        None => {
+                            // Give the mesh its own copy before changing the alpha
+                            let mut copy = materials.get(&material.0).cloned().unwrap_or_default();
+                            if copy.alpha_mode == AlphaMode::Opaque {
+                                copy.alpha_mode = AlphaMode::Blend;
+                            }
+                            let alpha = copy.base_color.alpha();
+                            let original = std::mem::replace(&mut material.0, materials.add(copy));
+                            commands.entity(mesh).insert(FadeStartAlpha {
+                                material: original,
+                                alpha,
+                            });
+                            alpha
+                        }
+                    };
+                    if let Some(copy) = materials.get_mut(&material.0) {
+                        copy.base_color.set_alpha(alpha * opacity);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::layout::vertical_extent;
+use crate::core::layout::{calculate_line_height, paragraph_lines};
+use crate::core::mesh::{
+    assemble_lines, build_line_data, line_colors, line_offset, lines_anchor_offset, LineMeshData,
+    TextMeshData,
+};
+use crate::core::style::{TextMeshStyle, TextRenderMode};
+use crate::gradient::TextGradient;
+use crate::FontMesh;
+use bevy::mesh::{Indices, VertexAttributeValues};
+use bevy::prelude::*;
+
+/// Per-line geometry kept between rebuilds of a [`TextMesh`].
+///
+/// When only some lines of the text change, just those lines are re-tessellated and
+/// spliced into the existing mesh buffers. Changing the font, the style, the colors, or
+/// the gradient discards the cache and rebuilds the whole mesh.
+#[derive(Component)]
+pub(crate) struct TextMeshLineCache {
+    font: AssetId<FontMesh>,
+    style: TextMeshStyle,
+    colors: Vec<[f32; 4]>,
+    gradient: Option<TextGradient>,
+    mesh: AssetId<Mesh>,
+    line_height: f32,
+    vertical_extent: (f32, f32),
+    anchor_offset: Vec3,
+    lines: Vec<CachedLine>,
+}
+
+struct CachedLine {
+    text: String,
+    /// Whether the line ends its paragraph, which decides if it is justified.
+    ends_paragraph: bool,
+    mesh: LineMeshData,
+}
+
+impl CachedLine {
+    fn new(text: &str, ends_paragraph: bool, fonts: &FontChain, style: &TextMeshStyle) -> Self {
+        Self {
+            text: text.to_string(),
+            ends_paragraph,
+            mesh: build_line_data(text, ends_paragraph, fonts, style),
+        }
+    }
+
+    /// Whether the line was laid out as `line` of a new text.
+    fn is(&self, (text, ends_paragraph): (&str, bool)) -> bool {
+        self.text == text && self.ends_paragraph == ends_paragraph
+    }
+}
+
+impl TextMeshLineCache {
+    /// Lay out every line of the text mesh.
+    pub fn new(text_mesh: &TextMesh, fonts: &FontChain) -> Self {
+        let line_height = calculate_line_height(fonts, &text_mesh.style);
+        let lines: Vec<CachedLine> = paragraph_lines(&text_mesh.text)
+            .map(|(line, ends_paragraph)| {
+                CachedLine::new(line, ends_paragraph, fonts, &text_mesh.style)
+            })
+            .collect();
+        let anchor_offset = lines_anchor_offset(
+            lines.iter().map(|line| &line.mesh),
+            text_mesh.style.orientation.line_step(line_height),
+            text_mesh.style.anchor,
+        );
+
+        Self {
+            font: text_mesh.font.id(),
+            style: text_mesh.style.clone(),
+            colors: text_mesh.vertex_colors(),
+            gradient: text_mesh.gradient,
+            mesh: AssetId::default(),
+            line_height,
+            vertical_extent: vertical_extent(fonts, &text_mesh.style),
+            anchor_offset,
+            lines,
+        }
+    }
+
+    /// Associate the cache with the mesh asset it was uploaded to.
+    pub fn with_mesh(mut self, mesh: AssetId<Mesh>) -> Self {
+        self.mesh = mesh;
+        self
+    }
+
+    /// Whether the cached lines can be reused for `text_mesh` rendered into `mesh`.
+    pub fn is_compatible(&self, text_mesh: &TextMesh, mesh: AssetId<Mesh>) -> bool {
+        self.font == text_mesh.font.id()
+            && self.style == text_mesh.style
+            && self.colors == text_mesh.vertex_colors()
+            && self.gradient == text_mesh.gradient
+            && self.mesh == mesh
+    }
+
+    /// Bounds of the anchored text and its lines.
+    pub fn bounds(&self) -> TextMeshBounds {
+        TextMeshBounds::from_lines(
+            self.lines.iter().map(|line| &line.mesh),
+            self.style.orientation,
+            self.line_height,
+            self.anchor_offset,
+            self.vertical_extent,
+        )
+    }
+
+    /// Offset from the origin of one line to the next.
+    fn line_step(&self) -> Vec3 {
+        self.style.orientation.line_step(self.line_height)
+    }
+
+    /// The text the cached lines were laid out from.
+    pub fn text(&self) -> String {
+        let lines: Vec<&str> = self.lines.iter().map(|line| line.text.as_str()).collect();
+        lines.join("\n")
+    }
+
+    /// Merge the cached lines into one anchored buffer.
+    pub fn assemble(&self) -> TextMeshData {
+        let mut data = assemble_lines(
+            self.lines.iter().map(|line| &line.mesh),
+            self.line_step(),
+            self.style.anchor,
+        );
+        if !self.colors.is_empty() {
+            let lines = self
+                .lines
+                .iter()
+                .map(|line| (line.text.as_str(), &line.mesh));
+            data.colors = line_colors(lines, &self.colors);
+        }
+        if let Some(gradient) = &self.gradient {
+            gradient.apply(&mut data);
+        }
+        data
+    }
+
+    /// Re-tessellate the lines that differ from `text` and write the result into `mesh`.
+    ///
+    /// Changed lines are spliced into the existing buffers in place. If the edit moves
+    /// the text bounds enough to change the anchor offset, or drops lines from the start
+    /// of the text like a scrolling log, every vertex has to move, so the buffers are
+    /// rewritten from the cached lines instead (still without re-tessellating unchanged
+    /// lines). Colored text is always rewritten, since an edit can shift the colors of
+    /// every line after it, or change the bounds a gradient spans, and so is engraved
+    /// text, whose plaque follows the bounds.
+    pub fn update(&mut self, text: &str, fonts: &FontChain, mesh: &mut Mesh) {
+        let new_lines: Vec<(&str, bool)> = paragraph_lines(text).collect();
+
+        let dropped = self.dropped_lines(&new_lines);
+        self.lines.drain(..dropped);
+
+        let old_counts: Vec<(usize, usize)> = self
+            .lines
+            .iter()
+            .map(|line| (line.mesh.data.vertices.len(), line.mesh.data.indices.len()))
+            .collect();
+        let mut changed = vec![false; old_counts.len().max(new_lines.len())];
+
+        for (line_index, &(line, ends_paragraph)) in new_lines.iter().enumerate() {
+            match self.lines.get_mut(line_index) {
+                Some(cached) if cached.is((line, ends_paragraph)) => {}
+                Some(cached) => {
+                    *cached = CachedLine::new(line, ends_paragraph, fonts, &self.style);
+                    changed[line_index] = true;
+                }
+                None => {
+                    self.lines
+                        .push(CachedLine::new(line, ends_paragraph, fonts, &self.style));
+                    changed[line_index] = true;
+                }
+            }
+        }
+
+        // Removed trailing lines count as changed to an empty line
+        if self.lines.len() > new_lines.len() {
+            self.lines.truncate(new_lines.len());
+            changed[new_lines.len()..].fill(true);
+        }
+
+        let anchor_offset = lines_anchor_offset(
+            self.lines.iter().map(|line| &line.mesh),
+            self.line_step(),
+            self.style.anchor,
+        );
+
+        // The plaque of engraved text spans every line
+        let engraved = matches!(self.style.render_mode, TextRenderMode::Engraved { .. });
+        if dropped == 0
+            && anchor_offset == self.anchor_offset
+            && !engraved
+            && self.colors.is_empty()
+            && self.gradient.is_none()
+            && self.splice(mesh, &old_counts, &changed)
+        {
+            return;
+        }
+
+        self.anchor_offset = anchor_offset;
+        let data = self.assemble();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, data.vertices);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, data.normals);
+        if data.uvs.is_empty() {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_UV_0);
+        } else {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, data.uvs);
+        }
+        if data.tangents.is_empty() {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+        } else {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, data.tangents);
+        }
+        if data.colors.is_empty() {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_COLOR);
+        } else {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, data.colors);
+        }
+        mesh.insert_indices(Indices::U32(data.indices));
+    }
+
+    /// Number of cached lines removed from the start of the text, if `new_lines` begins
+    /// with all remaining cached lines.
+    fn dropped_lines(&self, new_lines: &[(&str, bool)]) -> usize {
+        let matches_from = |start: usize| {
+            self.lines[start..]
+                .iter()
+                .zip(new_lines)
+                .all(|(cached, &line)| cached.is(line))
+                && self.lines.len() - start <= new_lines.len()
+        };
+        if matches_from(0) {
+            return 0;
+        }
+        (1..self.lines.len())
+            .find(|&start| matches_from(start))
+            .unwrap_or(0)
+    }
+
+    /// Replace the vertex and index ranges of changed lines in `mesh`.
+    ///
+    /// Returns `false` without touching the mesh if its buffers don't have the layout
+    /// produced by this plugin.
+    fn splice(&self, mesh: &mut Mesh, old_counts: &[(usize, usize)], changed: &[bool]) -> bool {
+        let has_layout = matches!(
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION),
+            Some(VertexAttributeValues::Float32x3(_))
+        ) && matches!(
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL),
+            Some(VertexAttributeValues::Float32x3(_))
+        ) && matches!(mesh.indices(), Some(Indices::U32(_)))
+            && match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+                Some(VertexAttributeValues::Float32x2(_)) => self.style.has_uvs(),
+                None => !self.style.has_uvs(),
+                Some(_) => false,
+            }
+            && match mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+                Some(VertexAttributeValues::Float32x4(_)) => self.style.generate_tangents,
+                None => !self.style.generate_tangents,
+                Some(_) => false,
+            };
+        if !has_layout {
+            return false;
+        }
+
+        let line_data = |line_index: usize| -> Option<&TextMeshData> {
+            self.lines.get(line_index).map(|line| &line.mesh.data)
+        };
+
+        // Positions
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            let mut start = 0;
+            for (line_index, &is_changed) in changed.iter().enumerate() {
+                let old_len = old_counts.get(line_index).map_or(0, |counts| counts.0);
+                if !is_changed {
+                    start += old_len;
+                    continue;
+                }
+
+                let offset = line_offset(line_index, self.line_step()) + self.anchor_offset;
+                let new = line_data(line_index).map_or(&[][..], |data| &data.vertices);
+                positions.splice(
+                    start..start + old_len,
+                    new.iter()
+                        .map(|v| [v[0] + offset.x, v[1] + offset.y, v[2] + offset.z]),
+                );
+                start += new.len();
+            }
+        }
+
+        // Normals
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            let mut start = 0;
+            for (line_index, &is_changed) in changed.iter().enumerate() {
+                let old_len = old_counts.get(line_index).map_or(0, |counts| counts.0);
+                if !is_changed {
+                    start += old_len;
+                    continue;
+                }
+
+                let new = line_data(line_index).map_or(&[][..], |data| &data.normals);
+                normals.splice(start..start + old_len, new.iter().copied());
+                start += new.len();
+            }
+        }
+
+        // UVs
+        if let Some(VertexAttributeValues::Float32x2(uvs)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0)
+        {
+            let mut start = 0;
+            for (line_index, &is_changed) in changed.iter().enumerate() {
+                let old_len = old_counts.get(line_index).map_or(0, |counts| counts.0);
+                if !is_changed {
+                    start += old_len;
+                    continue;
+                }
+
+                let new = line_data(line_index).map_or(&[][..], |data| &data.uvs);
+                uvs.splice(start..start + old_len, new.iter().copied());
+                start += new.len();
+            }
+        }
+
+        // Tangents
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            let mut start = 0;
+            for (line_index, &is_changed) in changed.iter().enumerate() {
+                let old_len = old_counts.get(line_index).map_or(0, |counts| counts.0);
+                if !is_changed {
+                    start += old_len;
+                    continue;
+                }
+
+                let new = line_data(line_index).map_or(&[][..], |data| &data.tangents);
+                tangents.splice(start..start + old_len, new.iter().copied());
+                start += new.len();
+            }
+        }
+
+        // Indices, shifting unchanged lines that follow a line whose vertex count changed
+        if let Some(Indices::U32(indices)) = mesh.indices_mut() {
+            let mut start = 0;
+            let mut vertex_start = 0u32;
+            let mut old_vertex_start = 0u32;
+            for (line_index, &is_changed) in changed.iter().enumerate() {
+                let (old_vertices, old_indices) =
+                    old_counts.get(line_index).copied().unwrap_or_default();
+
+                if is_changed {
+                    let (new_indices, new_vertices) = line_data(line_index)
+                        .map_or((&[][..], 0), |data| {
+                            (&data.indices[..], data.vertices.len())
+                        });
+                    indices.splice(
+                        start..start + old_indices,
+                        new_indices.iter().map(|i| i + vertex_start),
+                    );
+                    start += new_indices.len();
+                    vertex_start += new_vertices as u32;
+                } else {
+                    if vertex_start != old_vertex_start {
+                        for index in &mut indices[start..start + old_indices] {
+                            *index = *index - old_vertex_start + vertex_start;
+                        }
+                    }
+                    start += old_indices;
+                    vertex_start += old_vertices as u32;
+                }
+
+                old_vertex_start += old_vertices as u32;
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,216 @@
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::mesh::build_text_mesh_data;
+use crate::core::style::TextMeshStyle;
+use crate::path::{build_text_path_data, TextPath};
+use crate::system::TextMeshComputed;
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// Swaps the mesh of a [`TextMesh`] for coarser ones as the camera moves away.
+///
+/// Curves tessellated with a high [`subdivision`] are wasted triangles on text that
+/// covers a few pixels. With this component, the plugin generates one extra mesh per
+/// [`TextMeshLodLevel`] and shows the coarsest level whose [`distance`] the nearest
+/// active camera is beyond, switching back to the full mesh up close. The meshes are
+/// regenerated with the text and listed in [`TextMeshLodMeshes`]; removing the component
+/// puts the full mesh back.
+///
+/// Levels belong to one entity each, even when the full mesh is shared between texts
+/// with the same content.
+///
+/// [`subdivision`]: crate::TextMeshStyle::subdivision
+/// [`distance`]: TextMeshLodLevel::distance
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Far away".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             style: TextMeshStyle {
+///                 subdivision: 20,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshLod::new([(15.0, 8), (40.0, 3)]),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextMeshLod {
+    /// Coarser levels, each shown from its distance onward.
+    pub levels: Vec<TextMeshLodLevel>,
+}
+
+/// One level of detail of a [`TextMeshLod`].
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct TextMeshLodLevel {
+    /// Distance between the text and the camera from which this level is shown.
+    pub distance: f32,
+    /// Curve subdivision of this level, used instead of the style's subdivision or
+    /// curve tolerance.
+    pub subdivision: u8,
+}
+
+impl TextMeshLod {
+    /// Levels from `(distance, subdivision)` pairs.
+    pub fn new(levels: impl IntoIterator<Item = (f32, u8)>) -> Self {
+        Self {
+            levels: levels
+                .into_iter()
+                .map(|(distance, subdivision)| TextMeshLodLevel {
+                    distance,
+                    subdivision,
+                })
+                .collect(),
+        }
+    }
+
+    /// Index into [`TextMeshLodMeshes::meshes`] of the mesh shown at `distance`.
+    ///
+    /// `0` is the full mesh; level `i` of [`TextMeshLod::levels`] is `i + 1`. Of the
+    /// levels whose distance is reached, the one with the largest distance wins.
+    pub fn select(&self, distance: f32) -> usize {
+        self.levels
+            .iter()
+            .enumerate()
+            .filter(|(_, level)| distance >= level.distance)
+            .max_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance))
+            .map_or(0, |(index, _)| index + 1)
+    }
+}
+
+/// Meshes generated for a [`TextMeshLod`], kept up to date by the plugin.
+#[derive(Component, Clone, Debug, Default)]
+pub struct TextMeshLodMeshes {
+    /// The full mesh, followed by one mesh per level of [`TextMeshLod::levels`].
+    pub meshes: Vec<Handle<Mesh>>,
+}
+
+impl TextMeshLodMeshes {
+    /// The full mesh generated from the text's own style.
+    pub fn full(&self) -> Option<&Handle<Mesh>> {
+        self.meshes.first()
+    }
+}
+
+type TextMeshLodQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshLod>,
+        Ref<'static, TextMesh>,
+        &'static Mesh3d,
+        Option<&'static TextPath>,
+        Option<&'static TextMeshLodMeshes>,
+    ),
+    With<TextMeshComputed>,
+>;
+
+/// System that generates the levels of text with a [`TextMeshLod`].
+///
+/// Runs after mesh generation and picks up the freshly generated full mesh.
+pub fn update_text_mesh_lods(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    texts: TextMeshLodQuery,
+    mut removed: RemovedComponents<TextMeshLod>,
+    mut restore: Query<(&mut Mesh3d, &TextMeshLodMeshes), Without<TextMeshLod>>,
+) {
+    // 1. Put the full mesh back on text that no longer has levels
+    for entity in removed.read() {
+        if let Ok((mut mesh, lods)) = restore.get_mut(entity) {
+            if let Some(full) = lods.full() {
+                mesh.0 = full.clone();
+            }
+            commands.entity(entity).remove::<TextMeshLodMeshes>();
+        }
+    }
+
+    for (entity, lod, text_mesh, mesh, text_path, lods) in texts.iter() {
+        // 2. A mesh that isn't one of the levels was just generated from the text; the
+        //    line cache edits meshes in place, so text changes count too
+        let regenerated = lods.is_none_or(|lods| !lods.meshes.contains(&mesh.0));
+        if !(regenerated || lod.is_changed() || text_mesh.is_changed()) {
+            continue;
+        }
+        let full = match lods.and_then(TextMeshLodMeshes::full) {
+            Some(full) if !regenerated => full.clone(),
+            _ => mesh.0.clone(),
+        };
+
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            continue;
+        };
+
+        // 3. Generate every level from the text with its subdivision
+        let colors = text_mesh.vertex_colors();
+        let mut levels = vec![full];
+        for level in &lod.levels {
+            let style = TextMeshStyle {
+                subdivision: level.subdivision,
+                curve_tolerance: None,
+                ..text_mesh.style.clone()
+            };
+            let mut data = match text_path {
+                Some(path) => build_text_path_data(&text_mesh.text, &fonts, &style, path, &colors),
+                None => build_text_mesh_data(&text_mesh.text, &fonts, &style, &colors),
+            };
+            if let Some(gradient) = &text_mesh.gradient {
+                gradient.apply(&mut data);
+            }
+            levels.push(meshes.add(data.into_mesh()));
+        }
+        commands
+            .entity(entity)
+            .insert(TextMeshLodMeshes { meshes: levels });
+    }
+}
+
+/// System that shows the level of each [`TextMeshLod`] matching its camera distance.
+pub fn select_text_mesh_lods(
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut texts: Query<(
+        &TextMeshLod,
+        &TextMeshLodMeshes,
+        &GlobalTransform,
+        &mut Mesh3d,
+    )>,
+) {
+    let cameras: Vec<Vec3> = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .map(|(_, transform)| transform.translation())
+        .collect();
+    if cameras.is_empty() {
+        return;
+    }
+
+    for (lod, lods, transform, mut mesh) in texts.iter_mut() {
+        let position = transform.translation();
+        let distance = cameras
+            .iter()
+            .map(|camera| camera.distance(position))
+            .fold(f32::MAX, f32::min);
+        let Some(selected) = lods.meshes.get(lod.select(distance)) else {
+            continue;
+        };
+        // Only touch the component on a switch, so change detection stays quiet
+        if mesh.0 != *selected {
+            mesh.0 = selected.clone();
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::outline::sample_contour;
+use crate::silhouette::build_text_silhouette;
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// How the bulbs of a [`TextMeshMarquee`] light up over time.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum MarqueeBlink {
+    /// Every bulb stays lit.
+    Steady,
+    /// All bulbs switch on and off together, every `period` seconds.
+    Flash {
+        /// Seconds between switches.
+        period: f32,
+    },
+    /// Neighboring bulbs alternate, swapping every `period` seconds.
+    Alternate {
+        /// Seconds between swaps.
+        period: f32,
+    },
+    /// Every `spacing`-th bulb is lit, and the lit bulbs move one step along the outline
+    /// every `period` seconds, like classic theater chaser lights.
+    Chase {
+        /// Seconds per step.
+        period: f32,
+        /// Distance between lit bulbs, in bulbs.
+        spacing: usize,
+    },
+}
+
+impl Default for MarqueeBlink {
+    fn default() -> Self {
+        Self::Chase {
+            period: 0.15,
+            spacing: 3,
+        }
+    }
+}
+
+impl MarqueeBlink {
+    /// Whether the bulb at `index` is lit `elapsed` seconds into the animation.
+    pub fn is_lit(&self, index: usize, elapsed: f32) -> bool {
+        let step = |period: f32| {
+            if period > 0.0 {
+                (elapsed / period) as usize
+            } else {
+                0
+            }
+        };
+
+        match *self {
+            Self::Steady => true,
+            Self::Flash { period } => step(period).is_multiple_of(2),
+            Self::Alternate { period } => (index + step(period)).is_multiple_of(2),
+            Self::Chase { period, spacing } => {
+                // Lit bulbs move forward along the outline by one bulb per step
+                let spacing = spacing.max(1);
+                (index + spacing - step(period) % spacing).is_multiple_of(spacing)
+            }
+        }
+    }
+}
+
+/// Preset that lines the outline of a [`TextMesh`] with blinking light bulbs.
+///
+/// Bulbs are spread evenly by arc length along the outer outline of each line of text
+/// (see [`TextSilhouette`](crate::TextSilhouette)) and spawned as child entities with a
+/// [`MarqueeBulb`] marker, a small sphere mesh, and either the lit or the unlit
+/// material, as chosen by the [`MarqueeBlink`] pattern. Give the lit material some
+/// emission for the classic casino-sign look.
+///
+/// Bulbs sit on the front face of the text and are regenerated whenever the text or
+/// this component changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// let lit = materials.add(StandardMaterial {
+///     base_color: Color::srgb(1.0, 0.9, 0.6),
+///     emissive: LinearRgba::rgb(8.0, 6.0, 2.0),
+///     ..default()
+/// });
+/// let unlit = materials.add(Color::srgb(0.3, 0.25, 0.2));
+///
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "CASINO".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshMarquee {
+///         lit,
+///         unlit,
+///         blink: MarqueeBlink::Alternate { period: 0.5 },
+///         ..default()
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TextMeshMarquee {
+    /// Distance between neighboring bulbs along the outline, in mesh units.
+    ///
+    /// Each closed outline gets a whole number of bulbs, so the actual spacing is
+    /// adjusted slightly to close the loop evenly.
+    pub spacing: f32,
+    /// Radius of each bulb, in mesh units.
+    pub bulb_radius: f32,
+    /// Material of lit bulbs.
+    pub lit: Handle<StandardMaterial>,
+    /// Material of unlit bulbs.
+    pub unlit: Handle<StandardMaterial>,
+    /// Blink pattern of the bulbs.
+    pub blink: MarqueeBlink,
+}
+
+impl Default for TextMeshMarquee {
+    fn default() -> Self {
+        Self {
+            spacing: 0.06,
+            bulb_radius: 0.012,
+            lit: Handle::default(),
+            unlit: Handle::default(),
+            blink: MarqueeBlink::default(),
+        }
+    }
+}
+
+/// Marker for the bulb entities spawned by a [`TextMeshMarquee`].
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct MarqueeBulb {
+    /// Position of the bulb in the marquee, counting along each outline in turn.
+    pub index: usize,
+}
+
+/// Marker component indicating that the bulbs of a [`TextMeshMarquee`] have been spawned.
+#[derive(Component)]
+pub struct TextMeshMarqueeComputed;
+
+type TextMeshMarqueeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static TextMesh, &'static TextMeshMarquee),
+    Or<(
+        Changed<TextMesh>,
+        Changed<TextMeshMarquee>,
+        Without<TextMeshMarqueeComputed>,
+    )>,
+>;
+
+/// System that spawns the bulbs of [`TextMeshMarquee`] entities.
+pub fn update_marquee_bulbs(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    query: TextMeshMarqueeQuery,
+    children_query: Query<&Children>,
+    bulb_query: Query<Entity, With<MarqueeBulb>>,
+) {
+    for (entity, text_mesh, marquee) in query.iter() {
+        // 1. Parse the font and its fallbacks
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
+
+        // 2. Despawn existing bulbs
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if bulb_query.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+
+        // 3. Sample bulb positions along the outline of every line
+        let silhouette = build_text_silhouette(&text_mesh.text, &fonts, &text_mesh.style);
+        let positions: Vec<Vec2> = silhouette
+            .polygons()
+            .flat_map(|polygon| sample_contour(polygon, marquee.spacing))
+            .map(|(position, _)| position)
+            .collect();
+
+        // 4. Spawn one child per bulb, sharing a single sphere mesh
+        if !positions.is_empty() {
+            let bulb_mesh = meshes.add(Sphere::new(marquee.bulb_radius));
+            commands.entity(entity).with_children(|parent| {
+                for (index, position) in positions.into_iter().enumerate() {
+                    let material = if marquee.blink.is_lit(index, 0.0) {
+                        marquee.lit.clone()
+                    } else {
+                        marquee.unlit.clone()
+                    };
+
+                    parent.spawn((
+                        MarqueeBulb { index },
+                        Mesh3d(bulb_mesh.clone()),
+                        MeshMaterial3d(material),
+                        Transform::from_translation(position.extend(0.0)),
+                        Visibility::default(),
+                        InheritedVisibility::default(),
+                        ViewVisibility::default(),
+                    ));
+                }
+            });
+        }
+
+        // 5. Mark as computed
+        commands.entity(entity).insert(TextMeshMarqueeComputed);
+    }
+}
+
+/// System that switches [`MarqueeBulb`] materials according to their [`MarqueeBlink`]
+/// pattern.
+pub fn animate_marquee_bulbs(
+    time: Res<Time>,
+    marquees: Query<&TextMeshMarquee>,
+    mut bulbs: Query<(
+        &MarqueeBulb,
+        &ChildOf,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    for (bulb, child_of, mut material) in bulbs.iter_mut() {
+        let Ok(marquee) = marquees.get(child_of.parent()) else {
+            continue;
+        };
+
+        let target = if marquee.blink.is_lit(bulb.index, elapsed) {
+            &marquee.lit
+        } else {
+            &marquee.unlit
+        };
+        // Only touch the material when it changes, to keep change detection quiet
+        if material.0 != *target {
+            material.0 = target.clone();
+        }
+    }
+}
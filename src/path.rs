@@ -0,0 +1,231 @@
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{calculate_line_step, line_glyphs, paragraph_lines};
+use crate::core::mesh::{calculate_anchor_offset, glyph_uvs, line_offset, TextMeshData};
+use crate::core::style::{TextMeshStyle, TextOrientation};
+use bevy::prelude::*;
+
+/// Lays a [`TextMesh`](crate::TextMesh) out along a curve.
+///
+/// Each glyph keeps its shape and is moved so the middle of its baseline sits on the
+/// path, rotated to follow the path's direction. The x position of a glyph in the laid
+/// out text (after justification and anchoring) becomes its distance along the path,
+/// measured from `start`, and its y position becomes an offset away from the path, so
+/// multiline text runs in parallel bands. Glyphs beyond either end of the path continue
+/// in a straight line.
+///
+/// The path is stored as a polyline in the local space of the text entity. Build it
+/// from any bounded Bevy [`Curve`], such as a [`CubicCurve`](bevy::math::cubic_splines::CubicCurve)
+/// or a [`FunctionCurve`](bevy::math::curve::FunctionCurve) wrapping a closure.
+///
+/// Text on a path is always generated in full on the main schedule, without the line
+/// cache or [`TextMeshAsync`](crate::TextMeshAsync).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::math::curve::FunctionCurve;
+/// # use bevy_fontmesh::prelude::*;
+/// # use std::f32::consts::PI;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// // Upper half of a circle with a radius of 3, running from left to right
+/// let arc = FunctionCurve::new(Interval::UNIT, |t: f32| {
+///     let angle = PI * (1.0 - t);
+///     Vec3::new(angle.cos(), angle.sin(), 0.0) * 3.0
+/// });
+/// let path = TextPath::from_curve(&arc, 64);
+///
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Grand Opening".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             style: TextMeshStyle {
+///                 anchor: TextAnchor::BottomCenter,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     // Center the text on the arc
+///     TextPath {
+///         start: path.length() * 0.5,
+///         ..path
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct TextPath {
+    /// Points of the path, in order.
+    pub points: Vec<Vec3>,
+    /// Direction the front of the glyphs faces.
+    ///
+    /// The glyph "up" direction is perpendicular to both this and the path direction.
+    /// Defaults to `+Z`, which suits paths in the XY plane.
+    pub normal: Vec3,
+    /// Distance along the path where the text's `x = 0` lands.
+    pub start: f32,
+}
+
+impl Default for TextPath {
+    fn default() -> Self {
+        Self::new(vec![Vec3::ZERO, Vec3::X])
+    }
+}
+
+impl TextPath {
+    /// A path through the given points.
+    pub fn new(points: Vec<Vec3>) -> Self {
+        Self {
+            points,
+            normal: Vec3::Z,
+            start: 0.0,
+        }
+    }
+
+    /// Sample a bounded curve at `samples` evenly spaced parameter values.
+    ///
+    /// Returns an empty path if the curve's domain is unbounded.
+    pub fn from_curve(curve: &impl Curve<Vec3>, samples: usize) -> Self {
+        let points = curve
+            .domain()
+            .spaced_points(samples.max(2))
+            .map(|times| times.map(|t| curve.sample_clamped(t)).collect())
+            .unwrap_or_default();
+        Self::new(points)
+    }
+
+    /// Total length of the path.
+    pub fn length(&self) -> f32 {
+        self.points.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+
+    /// Position and orientation at `distance` along the path.
+    ///
+    /// The local X axis of the result points along the path, Z along
+    /// [`TextPath::normal`], and Y is the "up" direction of the glyphs. Distances
+    /// outside the path extend its first or last segment.
+    pub fn transform_at(&self, distance: f32) -> Transform {
+        let segments: Vec<(Vec3, Vec3)> = self
+            .points
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .filter(|(a, b)| a.distance_squared(*b) > 0.0)
+            .collect();
+        if segments.is_empty() {
+            return Transform::from_translation(self.points.first().copied().unwrap_or_default());
+        }
+
+        // Distances before the start extend the first segment, past the end the last one
+        let mut along = distance;
+        let mut segment = segments[segments.len() - 1];
+        for (i, &(a, b)) in segments.iter().enumerate() {
+            let length = a.distance(b);
+            if along <= length || i == segments.len() - 1 {
+                segment = (a, b);
+                break;
+            }
+            along -= length;
+        }
+
+        let (a, b) = segment;
+        let tangent = (b - a).normalize();
+        let up = self.normal.cross(tangent).normalize_or(Vec3::Y);
+        let normal = tangent.cross(up);
+
+        Transform {
+            translation: a + tangent * along,
+            rotation: Quat::from_mat3(&Mat3::from_cols(tangent, up, normal)),
+            ..default()
+        }
+    }
+}
+
+/// Lays out `text` and places every glyph along `path`.
+///
+/// Glyphs are tinted with `colors`, one per character, unless it is empty.
+pub(crate) fn build_text_path_data(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    path: &TextPath,
+    colors: &[[f32; 4]],
+) -> TextMeshData {
+    // 1. Lay the glyphs out as straight, horizontal text
+    let style = &TextMeshStyle {
+        orientation: TextOrientation::Horizontal,
+        ..style.clone()
+    };
+    let line_step = calculate_line_step(fonts, style);
+    let mut glyphs = Vec::new();
+    let mut glyph_colors = Vec::new();
+    let mut line_start = 0;
+    for (line_index, (line, ends_paragraph)) in paragraph_lines(text).enumerate() {
+        let offset = line_offset(line_index, line_step);
+        for line_glyph in line_glyphs(line, ends_paragraph, fonts, style) {
+            if let Some(mut glyph) = GlyphGeometry::new(line_glyph.face, line_glyph.source, style) {
+                glyph.handcraft(style, line_glyph.character, line_glyph.index);
+                glyphs.push((glyph, offset + Vec3::new(line_glyph.x, line_glyph.y, 0.0)));
+                glyph_colors.push(colors.get(line_start + line_glyph.index).copied());
+            }
+        }
+        // The line break counts as a character too
+        line_start += line.chars().count() + 1;
+    }
+
+    // 2. Anchor the straight text
+    let bounds = glyphs
+        .iter()
+        .flat_map(|(glyph, origin)| glyph.vertices.iter().map(move |v| *v + *origin))
+        .fold(None, |bounds: Option<(Vec3, Vec3)>, v| {
+            Some(bounds.map_or((v, v), |(min, max)| (min.min(v), max.max(v))))
+        });
+    let anchor_offset = bounds
+        .map(|(min, max)| calculate_anchor_offset(style.anchor, min, max))
+        .unwrap_or(Vec3::ZERO);
+
+    // 3. Bend it onto the path, one glyph at a time
+    let mut data = TextMeshData::default();
+    for ((glyph, origin), color) in glyphs.iter().zip(glyph_colors) {
+        let (min_x, max_x) = glyph
+            .vertices
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(min, max), v| {
+                (min.min(v.x), max.max(v.x))
+            });
+        let origin = *origin + anchor_offset;
+        let center_x = origin.x + (min_x + max_x) * 0.5;
+        let frame = path.transform_at(path.start + center_x);
+
+        let index_offset = data.vertices.len() as u32;
+        data.vertices.extend(glyph.vertices.iter().map(|v| {
+            let local = *v + origin - Vec3::X * center_x;
+            frame.transform_point(local).to_array()
+        }));
+        data.normals.extend(
+            glyph
+                .normals
+                .iter()
+                .map(|n| (frame.rotation * *n).to_array()),
+        );
+        if style.has_uvs() {
+            data.uvs.extend(glyph_uvs(glyph));
+        }
+        if !colors.is_empty() {
+            let color = color.unwrap_or([1.0; 4]);
+            data.colors
+                .extend(std::iter::repeat_n(color, glyph.vertices.len()));
+        }
+        data.indices
+            .extend(glyph.indices.iter().map(|i| i + index_offset));
+    }
+
+    if style.generate_tangents {
+        data.generate_tangents();
+    }
+    data
+}
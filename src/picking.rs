@@ -0,0 +1,90 @@
+use crate::component::GlyphMesh;
+use bevy::picking::events::{Click, Out, Over, Pointer, Press, Release};
+use bevy::prelude::*;
+use std::fmt::Debug;
+
+/// Pointer event on a single glyph of a [`TextMeshGlyphs`](crate::TextMeshGlyphs).
+///
+/// With the `picking` feature, the glyph entities spawned by
+/// [`TextMeshGlyphs`](crate::TextMeshGlyphs) are [`Pickable`], and every [`Over`], [`Out`],
+/// [`Press`], [`Release`], and [`Click`] pointer event that hits one of them is also
+/// triggered as a `GlyphPointer` on the text entity, carrying the [`GlyphMesh`] that was
+/// hit. Observe it on the text entity to react to individual letters without looking
+/// the glyph up yourself.
+///
+/// Hits are reported by Bevy's picking backends, so add `MeshPickingPlugin` (or another
+/// backend that picks meshes) to the app.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::picking::events::Click;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands
+///     .spawn(TextMeshGlyphsBundle {
+///         text_glyphs: TextMeshGlyphs {
+///             text: "Click a letter".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     })
+///     .observe(|click: On<GlyphPointer<Click>>| {
+///         println!(
+///             "Clicked '{}' at index {}",
+///             click.glyph.character, click.glyph.char_index
+///         );
+///     });
+/// # }
+/// ```
+#[derive(EntityEvent, Clone, Debug)]
+pub struct GlyphPointer<E: Debug + Clone + Reflect> {
+    /// The [`TextMeshGlyphs`](crate::TextMeshGlyphs) entity the glyph belongs to.
+    pub entity: Entity,
+    /// The glyph entity that was hit.
+    pub glyph_entity: Entity,
+    /// Position of the hit glyph in the text.
+    pub glyph: GlyphMesh,
+    /// The original pointer event on the glyph entity.
+    pub pointer: Pointer<E>,
+}
+
+/// Observer that forwards pointer events on glyph entities as [`GlyphPointer`] events.
+fn forward_glyph_pointer<E: Debug + Clone + Reflect>(
+    event: On<Pointer<E>>,
+    glyphs: Query<(&GlyphMesh, &ChildOf)>,
+    mut commands: Commands,
+) {
+    // Pointer events bubble up the hierarchy; only forward the original hit
+    let glyph_entity = event.original_event_target();
+    if event.entity != glyph_entity {
+        return;
+    }
+    let Ok((glyph, child_of)) = glyphs.get(glyph_entity) else {
+        return;
+    };
+
+    commands.trigger(GlyphPointer {
+        entity: child_of.parent(),
+        glyph_entity,
+        glyph: glyph.clone(),
+        pointer: event.event().clone(),
+    });
+}
+
+/// Observer that makes every new glyph entity pickable.
+fn make_glyph_pickable(add: On<Add, GlyphMesh>, mut commands: Commands) {
+    commands.entity(add.entity).insert(Pickable::default());
+}
+
+/// Register the observers that make glyphs pickable and forward their pointer events.
+pub(crate) fn add_glyph_pointer_observers(app: &mut App) {
+    app.add_observer(make_glyph_pickable)
+        .add_observer(forward_glyph_pointer::<Over>)
+        .add_observer(forward_glyph_pointer::<Out>)
+        .add_observer(forward_glyph_pointer::<Press>)
+        .add_observer(forward_glyph_pointer::<Release>)
+        .add_observer(forward_glyph_pointer::<Click>);
+}
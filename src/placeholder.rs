@@ -0,0 +1,397 @@
+use crate::asset::unavailable_font;
+use crate::component::{TextMesh, TextMesh2d, TextMeshGlyphs};
+use crate::core::fallback::FontChain;
+use crate::core::layout::calculate_justification_offset;
+use crate::core::mesh::{
+    assemble_lines, build_text_mesh_2d_data, build_text_mesh_data, LineMeshData, TextMeshData,
+};
+use crate::core::style::{TextMeshStyle, TextOrientation};
+use crate::system::{update_aabb, TextMesh2dComputed, TextMeshComputed, TextMeshGlyphsComputed};
+use crate::FontMesh;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use thiserror::Error;
+
+/// Advance of a placeholder box in ems, the same as an empty `.notdef` glyph.
+const BOX_ADVANCE: f32 = 0.6;
+/// Width of a placeholder box in ems.
+const BOX_WIDTH: f32 = 0.5;
+/// Height of a placeholder box above the baseline in ems.
+const BOX_HEIGHT: f32 = 0.7;
+/// Distance between placeholder lines in ems, before the style's line spacing.
+const BOX_LINE_HEIGHT: f32 = 1.2;
+
+/// What to do with text whose font never becomes available.
+///
+/// A font that fails to load, fails to parse, or is still missing after
+/// [`FontLoadTimeout::timeout`] seconds would otherwise leave its text invisible
+/// forever. Instead, [`TextMesh`] and [`TextMesh2d`] entities then show a placeholder:
+/// the text set in [`FontLoadTimeout::placeholder_font`] if it is loaded, or one box
+/// per character otherwise. Every affected text, including [`TextMeshGlyphs`], also
+/// gets a [`TextMeshError::FontUnavailable`] message.
+///
+/// The real text replaces the placeholder as soon as its font becomes available.
+/// The plugin inserts the default settings; insert your own to change them, or
+/// remove the resource to leave unavailable text empty.
+///
+/// The [`timeout`](Self::timeout) is also when [`TextMeshValidation`] reports a font
+/// that hasn't loaded, and it keeps its default while the resource is removed.
+///
+/// [`TextMeshValidation`]: crate::TextMeshValidation
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     // Show a bundled font after two seconds instead of boxes after ten
+///     commands.insert_resource(FontLoadTimeout {
+///         timeout: 2.0,
+///         placeholder_font: Some(asset_server.load("fonts/font.ttf")),
+///     });
+/// }
+///
+/// fn report_missing_fonts(mut errors: MessageReader<TextMeshError>) {
+///     for error in errors.read() {
+///         eprintln!("{error}");
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct FontLoadTimeout {
+    /// Seconds a font may take to load before the placeholder is shown.
+    ///
+    /// Fonts that fail to load or parse are replaced right away. Needs Bevy's
+    /// [`Time`]; without it only those are replaced.
+    pub timeout: f32,
+    /// Font to set placeholder text in, or `None` to draw boxes.
+    pub placeholder_font: Option<Handle<FontMesh>>,
+}
+
+impl Default for FontLoadTimeout {
+    fn default() -> Self {
+        Self {
+            timeout: 10.0,
+            placeholder_font: None,
+        }
+    }
+}
+
+/// Errors reported while generating text meshes.
+#[non_exhaustive]
+#[derive(Message, Error, Clone, Debug, PartialEq)]
+pub enum TextMeshError {
+    /// A font of the text failed to load, failed to parse, or timed out loading.
+    ///
+    /// See [`FontLoadTimeout`].
+    #[error("font {font} of text entity {entity} is unavailable, showing a placeholder")]
+    FontUnavailable {
+        /// The text entity.
+        entity: Entity,
+        /// The font or fallback font that is unavailable.
+        font: AssetId<FontMesh>,
+    },
+}
+
+/// Placeholder geometry for text whose font is unavailable: one box per character.
+fn placeholder_box_data(text: &str, style: &TextMeshStyle, depth: f32) -> TextMeshData {
+    let size = style.font_size;
+    let lines: Vec<LineMeshData> = text
+        .split('\n')
+        .map(|line| {
+            let count = line.chars().count();
+            let line_width = count as f32 * BOX_ADVANCE * size
+                + count.saturating_sub(1) as f32 * style.letter_spacing;
+            let start = calculate_justification_offset(style.justify, line_width);
+
+            let mut line_data = LineMeshData {
+                start,
+                width: line_width,
+                ..default()
+            };
+            for (index, ch) in line.chars().enumerate() {
+                if ch.is_whitespace() {
+                    continue;
+                }
+                let x = start + index as f32 * (BOX_ADVANCE * size + style.letter_spacing);
+                let min = Vec3::new(x, 0.0, -depth);
+                let max = Vec3::new(x + BOX_WIDTH * size, BOX_HEIGHT * size, 0.0);
+                push_box(&mut line_data.data, min, max);
+                line_data.bounds = Some(match line_data.bounds {
+                    Some((bounds_min, bounds_max)) => (bounds_min.min(min), bounds_max.max(max)),
+                    None => (min, max),
+                });
+            }
+            line_data
+        })
+        .collect();
+
+    let line_height = style
+        .line_height_override
+        .unwrap_or(BOX_LINE_HEIGHT * size * style.line_spacing);
+    // The boxes are laid out in rows, also for vertical text
+    assemble_lines(
+        &lines,
+        TextOrientation::Horizontal.line_step(line_height),
+        style.anchor,
+    )
+}
+
+/// Push a box between `min` and `max`, or only its front face if it has no depth.
+fn push_box(data: &mut TextMeshData, min: Vec3, max: Vec3) {
+    // Front corners counter-clockwise from the bottom left
+    let front = [
+        Vec3::new(min.x, min.y, max.z),
+        Vec3::new(max.x, min.y, max.z),
+        Vec3::new(max.x, max.y, max.z),
+        Vec3::new(min.x, max.y, max.z),
+    ];
+    push_quad(data, front, Vec3::Z);
+    if min.z == max.z {
+        return;
+    }
+
+    let back = front.map(|corner| corner.with_z(min.z));
+    push_quad(data, [back[1], back[0], back[3], back[2]], Vec3::NEG_Z);
+    for (i, j, normal) in [
+        (0, 1, Vec3::NEG_Y),
+        (1, 2, Vec3::X),
+        (2, 3, Vec3::Y),
+        (3, 0, Vec3::NEG_X),
+    ] {
+        push_quad(data, [back[i], back[j], front[j], front[i]], normal);
+    }
+}
+
+/// Push a flat quad with corners in counter-clockwise order around `normal`.
+fn push_quad(data: &mut TextMeshData, corners: [Vec3; 4], normal: Vec3) {
+    let first = data.vertices.len() as u32;
+    data.vertices
+        .extend(corners.iter().map(|corner| corner.to_array()));
+    data.normals.extend([normal.to_array(); 4]);
+    data.indices
+        .extend([0, 1, 2, 0, 2, 3].iter().map(|index| first + index));
+}
+
+/// A text whose font or fallback font is unavailable.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FontWait {
+    /// The font the text waits on.
+    pub(crate) font: AssetId<FontMesh>,
+    /// When the text was first seen waiting, if Bevy's [`Time`] is available.
+    since: Option<f64>,
+    /// Whether the font failed to load or to parse, so waiting won't make it available.
+    pub(crate) failed: bool,
+    /// Whether the font failed, or the text waited longer than [`FontLoadTimeout::timeout`].
+    pub(crate) expired: bool,
+}
+
+/// Texts waiting on a font, tracked once per frame for both the placeholders and the
+/// [`TextMeshValidation`](crate::TextMeshValidation) checks.
+#[derive(Resource, Default)]
+pub(crate) struct FontWaits {
+    waits: HashMap<Entity, FontWait>,
+}
+
+impl FontWaits {
+    /// The font `entity` waits on, if any.
+    pub(crate) fn get(&self, entity: Entity) -> Option<&FontWait> {
+        self.waits.get(&entity)
+    }
+
+    /// The font `entity` waits on, if it has waited too long or the font failed.
+    pub(crate) fn expired(&self, entity: Entity) -> Option<&FontWait> {
+        self.get(entity).filter(|wait| wait.expired)
+    }
+}
+
+type FontWaitQuery<'w, 's, T, C> = Query<'w, 's, (Entity, &'static T, Has<C>)>;
+
+/// System that records which texts wait on an unavailable font, and for how long.
+///
+/// Runs before generation. Text that is already generated only waits on fonts that
+/// aren't loaded, so its fonts aren't parsed again every frame.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn track_font_waits(
+    fonts: Res<Assets<FontMesh>>,
+    settings: Option<Res<FontLoadTimeout>>,
+    asset_server: Option<Res<AssetServer>>,
+    time: Option<Res<Time>>,
+    mut waits: ResMut<FontWaits>,
+    texts: FontWaitQuery<TextMesh, TextMeshComputed>,
+    texts_2d: FontWaitQuery<TextMesh2d, TextMesh2dComputed>,
+    glyph_texts: FontWaitQuery<TextMeshGlyphs, TextMeshGlyphsComputed>,
+) {
+    let now = time.map(|time| time.elapsed_secs_f64());
+    let timeout = settings.map_or(FontLoadTimeout::default().timeout, |settings| {
+        settings.timeout
+    });
+    let texts = texts
+        .iter()
+        .map(|(entity, text, computed)| (entity, &text.font, &text.style, computed));
+    let texts_2d = texts_2d
+        .iter()
+        .map(|(entity, text, computed)| (entity, &text.font, &text.style, computed));
+    let glyph_texts = glyph_texts
+        .iter()
+        .map(|(entity, text, computed)| (entity, &text.font, &text.style, computed));
+
+    let mut waiting = HashMap::new();
+    for (entity, font, style, computed) in texts.chain(texts_2d).chain(glyph_texts) {
+        let unavailable = if computed {
+            std::iter::once(font)
+                .chain(&style.fallback_fonts)
+                .map(Handle::id)
+                .find(|&id| !fonts.contains(id))
+        } else {
+            unavailable_font(&fonts, font, &style.fallback_fonts)
+        };
+        let Some(unavailable) = unavailable else {
+            continue;
+        };
+
+        // Fonts that are loaded but unparsable, or failed to load, won't recover by waiting
+        let failed = fonts.contains(unavailable)
+            || asset_server
+                .as_ref()
+                .and_then(|server| server.get_load_state(unavailable))
+                .is_some_and(|state| state.is_failed());
+        let since = waits
+            .waits
+            .get(&entity)
+            .map_or(now, |wait| wait.since.or(now));
+        let waited = match (now, since) {
+            (Some(now), Some(since)) => now - since,
+            _ => 0.0,
+        };
+        waiting.insert(
+            entity,
+            FontWait {
+                font: unavailable,
+                since,
+                failed,
+                expired: failed || waited > timeout as f64,
+            },
+        );
+    }
+
+    // Texts whose font arrived, or that were despawned, stop waiting
+    waits.waits = waiting;
+}
+
+/// Texts showing a placeholder, and the unavailable fonts already reported for them.
+#[derive(Default)]
+pub(crate) struct Placeholders {
+    reported: HashSet<(Entity, AssetId<FontMesh>)>,
+    shown: HashSet<Entity>,
+}
+
+impl Placeholders {
+    /// Whether `entity` should show its placeholder, reporting its unavailable font
+    /// the first time it does.
+    fn expired(
+        &mut self,
+        entity: Entity,
+        waits: &FontWaits,
+        errors: &mut MessageWriter<TextMeshError>,
+    ) -> bool {
+        let Some(wait) = waits.expired(entity) else {
+            return false;
+        };
+        if self.reported.insert((entity, wait.font)) {
+            errors.write(TextMeshError::FontUnavailable {
+                entity,
+                font: wait.font,
+            });
+        }
+        true
+    }
+
+    /// Whether the placeholder of `entity` has to be (re)built.
+    fn needs_placeholder(&mut self, entity: Entity, changed: bool) -> bool {
+        self.shown.insert(entity) || changed
+    }
+
+    /// Forget texts that stopped waiting, because their font arrived or they were despawned.
+    fn end_frame(&mut self, waits: &FontWaits) {
+        self.reported
+            .retain(|&(entity, _)| waits.expired(entity).is_some());
+        self.shown.retain(|&entity| waits.expired(entity).is_some());
+    }
+}
+
+type PendingTextQuery<'w, 's, T, M, C> =
+    Query<'w, 's, (Entity, Ref<'static, T>, &'static mut M), Without<C>>;
+
+/// System that shows placeholders for text whose font is unavailable and reports it
+/// with [`TextMeshError::FontUnavailable`].
+///
+/// Runs after the generation systems, so only text they couldn't build is touched.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_font_placeholders(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    fonts: Res<Assets<FontMesh>>,
+    settings: Res<FontLoadTimeout>,
+    waits: Res<FontWaits>,
+    mut pending: Local<Placeholders>,
+    mut texts: PendingTextQuery<TextMesh, Mesh3d, TextMeshComputed>,
+    mut texts_2d: PendingTextQuery<TextMesh2d, Mesh2d, TextMesh2dComputed>,
+    glyph_texts: Query<Entity, (With<TextMeshGlyphs>, Without<TextMeshGlyphsComputed>)>,
+    mut errors: MessageWriter<TextMeshError>,
+) {
+    let placeholder_font = settings
+        .placeholder_font
+        .as_ref()
+        .and_then(|font| FontChain::from_assets(&fonts, font, &[]));
+
+    // 1. Extruded text
+    for (entity, text_mesh, mut mesh_handle) in texts.iter_mut() {
+        let style = &text_mesh.style;
+        if !pending.expired(entity, &waits, &mut errors)
+            || !pending.needs_placeholder(entity, text_mesh.is_changed())
+        {
+            continue;
+        }
+
+        let mut data = match &placeholder_font {
+            Some(font) => {
+                build_text_mesh_data(&text_mesh.text, font, style, &text_mesh.vertex_colors())
+            }
+            None => placeholder_box_data(&text_mesh.text, style, style.depth),
+        };
+        if let Some(gradient) = &text_mesh.gradient {
+            gradient.apply(&mut data);
+        }
+        let mesh = data.into_mesh();
+        update_aabb(&mut commands, entity, &mesh);
+        mesh_handle.0 = meshes.add(mesh);
+    }
+
+    // 2. Flat 2D text
+    for (entity, text_mesh, mut mesh_handle) in texts_2d.iter_mut() {
+        let style = &text_mesh.style;
+        if !pending.expired(entity, &waits, &mut errors)
+            || !pending.needs_placeholder(entity, text_mesh.is_changed())
+        {
+            continue;
+        }
+
+        let data = match &placeholder_font {
+            Some(font) => build_text_mesh_2d_data(&text_mesh.text, font, style),
+            None => placeholder_box_data(&text_mesh.text, style, 0.0),
+        };
+        let mesh = data.into_mesh();
+        update_aabb(&mut commands, entity, &mesh);
+        mesh_handle.0 = meshes.add(mesh);
+    }
+
+    // 3. Per-character text only reports the font
+    for entity in glyph_texts.iter() {
+        pending.expired(entity, &waits, &mut errors);
+    }
+
+    // 4. Forget texts that are no longer waiting
+    pending.end_frame(&waits);
+}
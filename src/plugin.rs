@@ -0,0 +1,603 @@
+use crate::append::apply_text_appends;
+use crate::asset::FontMeshLoader;
+use crate::baseline::align_text_baselines;
+use crate::billboard::face_text_billboards;
+use crate::caret::{update_text_cursors, update_text_selections};
+use crate::collider::update_text_colliders;
+use crate::counter::update_text_mesh_counters;
+use crate::floating::{animate_floating_texts, spawn_floating_texts};
+use crate::font_path::sync_font_paths;
+use crate::glow::update_text_glow_shells;
+use crate::glyph_animation::animate_glyphs;
+use crate::glyph_meshes::{release_unused_glyph_meshes, GlyphMeshes};
+use crate::input::update_text_mesh_inputs;
+use crate::lazy::defer_offscreen_text_meshes;
+use crate::lifetime::update_text_mesh_lifetimes;
+use crate::lod::{select_text_mesh_lods, update_text_mesh_lods};
+use crate::marquee::{animate_marquee_bulbs, update_marquee_bulbs};
+use crate::placeholder::{apply_font_placeholders, track_font_waits, FontWaits};
+use crate::reload::reload_modified_fonts;
+use crate::replay::{apply_text_mesh_playback, record_text_meshes};
+use crate::reveal::update_text_mesh_reveals;
+use crate::sections::update_section_meshes;
+use crate::settings::{add_default_material, apply_default_materials, apply_glyph_materials};
+use crate::shared_mesh::SharedTextMeshes;
+use crate::silhouette::update_text_silhouettes;
+use crate::span::update_text_spans;
+use crate::style_root::inherit_text_styles;
+use crate::system::{
+    anchor_glyph_meshes, sync_generated_transforms, update_glyph_meshes, update_part_meshes,
+    update_text_meshes, update_text_meshes_2d,
+};
+use crate::table::{layout_text_tables, update_text_table_cells};
+use crate::task::{apply_text_mesh_tasks, spawn_text_mesh_tasks};
+use crate::texture::fit_text_texture_cameras;
+use crate::ticker::update_text_tickers;
+use crate::transition::{start_text_mesh_transitions, update_text_mesh_transitions};
+use crate::validation::validate_text_meshes;
+use crate::whitespace::update_text_whitespace_markers;
+#[cfg(feature = "default-font")]
+use crate::DEFAULT_FONT_DATA;
+use crate::{
+    CaseTransform, FloatingText3d, FontLoadTimeout, FontMesh, FontMeshSettings, GlyphBounce,
+    GlyphMesh, GlyphMeshesReady, GlyphPivot, GlyphShake, GlyphWave, JustifyText, MarqueeBulb,
+    ReplayedText, SideShading, Text3d, Text3dStyle, TextAnchor, TextBaselineGroup, TextBillboard,
+    TextCursor3d, TextCursor3dQuad, TextDecorations, TextFont3d, TextMesh, TextMesh2d,
+    TextMeshAppend, TextMeshAsync, TextMeshBounds, TextMeshBvh, TextMeshCachePolicy,
+    TextMeshCollider, TextMeshCounter, TextMeshError, TextMeshFontPaths, TextMeshGlow,
+    TextMeshGlowShell, TextMeshGlyphs, TextMeshInput, TextMeshInputSubmitted, TextMeshLayout,
+    TextMeshLazy, TextMeshLifetime, TextMeshLod, TextMeshMarquee, TextMeshPart,
+    TextMeshPartMaterials, TextMeshParts, TextMeshPlayback, TextMeshReady, TextMeshRecorder,
+    TextMeshReveal, TextMeshSectionMesh, TextMeshSections, TextMeshStyle, TextMeshTransition,
+    TextMeshTransitionGhost, TextMeshValidation, TextMeshWarning, TextMeshWhitespace,
+    TextMeshWhitespaceMarkers, TextOverflow, TextPath, TextSelection3d, TextSelection3dQuads,
+    TextSilhouette, TextSpan3d, TextStyleOverride, TextStyleRoot, TextTable, TextTableCell,
+    TextTableLayout, TextTableRules, TextTexture, TextTextureCamera, TextTicker,
+};
+use bevy::camera::visibility::VisibilitySystems;
+use bevy::ecs::schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleLabel};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+/// Plugin that enables 3D text mesh generation from fonts.
+///
+/// This plugin registers the necessary assets, loaders, and systems to automatically
+/// generate 3D mesh geometry from [`TextMesh`] components. Simply add this plugin to
+/// your Bevy app and spawn entities with [`TextMeshBundle`](crate::TextMeshBundle).
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::FontMeshPlugin;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(FontMeshPlugin)
+///     .run();
+/// ```
+///
+/// The plugin automatically:
+/// - Registers the [`FontMesh`] asset type for loading TTF/OTF fonts
+/// - Adds the embedded font as `FontMesh::default_handle()` with the `default-font`
+///   feature
+/// - Adds a system that generates meshes when [`TextMesh`] components are added or changed,
+///   sharing one mesh between texts with identical content
+/// - Adds a system that generates flat 2D meshes for [`TextMesh2d`] components
+/// - Adds a system that regenerates text whose font asset was modified, e.g. by hot reloading
+/// - Adds a system that shows placeholders for text whose font never loads, see
+///   [`FontLoadTimeout`]
+/// - Adds systems that generate and swap the levels of [`TextMeshLod`] text
+/// - Adds a system that puts off generating [`TextMeshLazy`] text out of every camera's view
+/// - Gives text spawned without a material one from [`FontMeshSettings`]
+/// - Adds a system that scrolls and clips [`TextTicker`] text
+/// - Adds a system that generates one mesh per section of [`TextMeshSections`] components
+/// - Adds a system that turns [`Text3d`] and [`TextSpan3d`] hierarchies into sections
+/// - Adds a system that traces the outlines of text with a [`TextSilhouette`] or
+///   [`TextMeshLayout`] component
+/// - Adds a system that builds the inflated shells of [`TextMeshGlow`] components
+/// - Adds systems that spawn and blink the bulbs of [`TextMeshMarquee`] components
+/// - Adds a system that animates glyphs with [`GlyphWave`], [`GlyphBounce`], and [`GlyphShake`]
+/// - Adds a system that builds collision geometry for [`TextMeshCollider`] components
+/// - Adds systems that place the carets of [`TextCursor3d`] and highlights of [`TextSelection3d`]
+/// - Adds a system that types keyboard input into focused [`TextMeshInput`]s
+/// - Adds a system that frames the cameras of [`TextTexture`]s around their text
+/// - Adds a system that passes [`TextStyleRoot`] fonts and styles down to descendant texts
+/// - Adds a system that lines up the baselines of texts in a [`TextBaselineGroup`]
+/// - Adds a system that fades out and despawns text with a [`TextMeshLifetime`]
+/// - Adds systems that drift, grow, and fade out [`FloatingText3d`]s
+/// - Adds a system that turns [`TextBillboard`]s to face the camera
+/// - Adds systems that animate edited text with a [`TextMeshTransition`]
+/// - Adds a system that appends lines pushed to [`TextMeshAppend`] components
+/// - Adds a system that shows the values of [`TextMeshCounter`]s with shared digit meshes
+/// - Adds the [`GlyphMeshes`] resource, and a system that frees the glyph meshes nothing
+///   displays anymore
+/// - Adds systems that spawn the cells of [`TextTable`]s and line them up in columns
+/// - Adds a system that reports [`TextMeshWarning`]s while [`TextMeshValidation`] is present
+/// - Registers the text components and styles for reflection, so they show up in
+///   inspectors and round-trip through scenes
+///
+/// # Scheduling
+///
+/// Generation runs in [`Update`] by default. Use [`FontMeshPlugin::in_schedule`] to run
+/// it elsewhere, for example in [`PostUpdate`] so it sees the current frame's
+/// propagated transforms. Both it and [`FontMeshPlugin::in_set`] return a
+/// [`FontMeshPluginConfig`], which is added in place of the plugin:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::FontMeshPlugin;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(FontMeshPlugin.in_schedule(PostUpdate))
+///     .run();
+/// ```
+///
+/// Use [`FontMeshPlugin::in_set`] to put the [`FontMeshSystems`] set inside a set of
+/// your own, so text generation follows the ordering of the rest of your app:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::{FontMeshPlugin, FontMeshSystems};
+///
+/// #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Presentation;
+///
+/// fn update_score() {}
+/// fn frame_camera() {}
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(FontMeshPlugin.in_set(Presentation))
+///     .configure_sets(Update, Presentation.after(update_score))
+///     .add_systems(Update, (update_score, frame_camera.after(FontMeshSystems)))
+///     .run();
+/// ```
+///
+/// All generation systems belong to the [`FontMeshSystems`] set. In [`PostUpdate`] the
+/// set is ordered after [`TransformSystems::Propagate`] and before
+/// [`VisibilitySystems::CalculateBounds`]; other schedules don't contain those sets, so
+/// no ordering is added there. Generated meshes get an up-to-date
+/// [`Aabb`](bevy::camera::primitives::Aabb) in the same system that builds them, so
+/// frustum culling never sees stale bounds as long as the chosen schedule runs before
+/// visibility checks (any of `First` through `PostUpdate`). The generation systems
+/// don't depend on the fixed timestep, so they also behave correctly when `FixedUpdate`
+/// runs zero or several times in a frame.
+///
+/// # Multiple Worlds
+///
+/// The plugin keeps no global state: every cache lives on the entities or in the
+/// resources of the world it belongs to, so it can be added to several apps or sub-apps
+/// at once (for example an editor preview world next to the game world). When the
+/// target world has no
+/// [`AssetServer`], the plugin only creates empty [`Assets<FontMesh>`] and
+/// [`Assets<Mesh>`] storage; fonts can then be added to it directly:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::prelude::*;
+///
+/// let mut preview = App::new();
+/// preview.add_plugins(FontMeshPlugin);
+///
+/// let font = preview
+///     .world_mut()
+///     .resource_mut::<Assets<FontMesh>>()
+///     .add(FontMesh {
+///         data: std::fs::read("assets/fonts/font.ttf").unwrap().into(),
+///         face_index: 0,
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FontMeshPlugin;
+
+impl FontMeshPlugin {
+    /// Add the generation systems to the given schedule instead of [`Update`].
+    ///
+    /// Only in [`PostUpdate`] does generation run after transform propagation. In any
+    /// earlier schedule, including the default [`Update`], systems that read a
+    /// [`GlobalTransform`], like [`TextMeshLod`] selection and [`TextMeshLazy`] view
+    /// checks, see the transforms of the previous frame, so they lag one frame behind
+    /// moved text and cameras. Mesh bounds are local to the text and are never stale.
+    pub fn in_schedule(self, schedule: impl ScheduleLabel) -> FontMeshPluginConfig {
+        FontMeshPluginConfig::default().in_schedule(schedule)
+    }
+
+    /// Nest the [`FontMeshSystems`] set in `set`, so it runs wherever `set` is ordered.
+    ///
+    /// `set` has to be configured in the same schedule as the plugin's systems.
+    pub fn in_set(self, set: impl SystemSet) -> FontMeshPluginConfig {
+        FontMeshPluginConfig::default().in_set(set)
+    }
+}
+
+/// [`FontMeshPlugin`] with its generation systems placed in a chosen schedule and set.
+///
+/// Created with [`FontMeshPlugin::in_schedule`] and [`FontMeshPlugin::in_set`], and
+/// added instead of the plugin. The default places them like [`FontMeshPlugin`] does.
+#[derive(Clone, Debug)]
+pub struct FontMeshPluginConfig {
+    /// Schedule the mesh generation systems are added to.
+    pub schedule: InternedScheduleLabel,
+    /// Set the [`FontMeshSystems`] set is nested in, if any.
+    pub set: Option<InternedSystemSet>,
+}
+
+impl FontMeshPluginConfig {
+    /// Add the generation systems to the given schedule instead.
+    ///
+    /// See [`FontMeshPlugin::in_schedule`].
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Nest the [`FontMeshSystems`] set in `set`.
+    ///
+    /// See [`FontMeshPlugin::in_set`].
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.set = Some(set.intern());
+        self
+    }
+
+    /// Placement of the generation systems of the font mesh plugin added to `app`, or
+    /// the default one if neither [`FontMeshPlugin`] nor this was added.
+    pub(crate) fn of(app: &App) -> Self {
+        app.get_added_plugins::<Self>()
+            .first()
+            .map_or_else(Self::default, |config| (*config).clone())
+    }
+}
+
+impl Default for FontMeshPluginConfig {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            set: None,
+        }
+    }
+}
+
+/// System set containing every mesh generation system of [`FontMeshPlugin`].
+///
+/// Order your own systems against this set to observe freshly generated meshes,
+/// for example `.after(FontMeshSystems)` to read [`GlyphMesh`] children on the frame
+/// they are spawned.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontMeshSystems;
+
+impl Plugin for FontMeshPlugin {
+    fn build(&self, app: &mut App) {
+        FontMeshPluginConfig::default().build(app);
+    }
+
+    fn finish(&self, app: &mut App) {
+        FontMeshPluginConfig::default().finish(app);
+    }
+}
+
+impl Plugin for FontMeshPluginConfig {
+    fn build(&self, app: &mut App) {
+        // Worlds without an asset server (e.g. an editor preview sub-app) get standalone
+        // asset storage, so the generation systems still run on directly inserted fonts.
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<FontMesh>()
+                .init_asset_loader::<FontMeshLoader>();
+        } else {
+            app.init_resource::<Assets<FontMesh>>()
+                .init_resource::<Assets<Mesh>>();
+        }
+
+        #[cfg(feature = "default-font")]
+        app.world_mut()
+            .resource_mut::<Assets<FontMesh>>()
+            .insert(
+                FontMesh::default_handle().id(),
+                FontMesh {
+                    data: DEFAULT_FONT_DATA.into(),
+                    face_index: 0,
+                },
+            )
+            .expect("fonts with a UUID handle can always be inserted");
+
+        #[cfg(feature = "picking")]
+        crate::picking::add_glyph_pointer_observers(app);
+
+        app.register_type::<TextMesh>()
+            .register_type::<TextMesh2d>()
+            .register_type::<TextMeshGlyphs>()
+            .register_type::<GlyphMesh>()
+            .register_type::<TextMeshStyle>()
+            .register_type::<TextAnchor>()
+            .register_type::<GlyphPivot>()
+            .register_type::<SideShading>()
+            .register_type::<TextDecorations>()
+            .register_type::<CaseTransform>()
+            .register_type::<JustifyText>()
+            .register_type::<TextOverflow>()
+            .register_type::<TextStyleRoot>()
+            .register_type::<TextMeshFontPaths>()
+            .register_type::<TextStyleOverride>()
+            .register_type::<GlyphWave>()
+            .register_type::<GlyphBounce>()
+            .register_type::<GlyphShake>()
+            .register_type::<TextMeshParts>()
+            .register_type::<TextMeshPart>()
+            .register_type::<TextMeshPartMaterials>()
+            .register_type::<TextMeshAsync>()
+            .register_type::<ReplayedText>()
+            .register_type::<TextMeshSections>()
+            .register_type::<TextMeshSectionMesh>()
+            .register_type::<Text3d>()
+            .register_type::<TextSpan3d>()
+            .register_type::<TextFont3d>()
+            .register_type::<Text3dStyle>()
+            .register_type::<TextPath>()
+            .register_type::<TextTicker>()
+            .register_type::<TextBaselineGroup>()
+            .register_type::<TextMeshBounds>()
+            .register_type::<TextSilhouette>()
+            .register_type::<TextMeshGlow>()
+            .register_type::<TextMeshGlowShell>()
+            .register_type::<TextMeshWhitespace>()
+            .register_type::<TextMeshWhitespaceMarkers>()
+            .register_type::<TextCursor3d>()
+            .register_type::<TextCursor3dQuad>()
+            .register_type::<TextSelection3d>()
+            .register_type::<TextSelection3dQuads>()
+            .register_type::<TextMeshInput>()
+            .register_type::<TextTable>()
+            .register_type::<TextTableCell>()
+            .register_type::<TextTableRules>()
+            .register_type::<TextTableLayout>()
+            .register_type::<TextMeshMarquee>()
+            .register_type::<MarqueeBulb>()
+            .register_type::<TextMeshLod>()
+            .register_type::<TextMeshLazy>()
+            .register_type::<TextMeshLifetime>()
+            .register_type::<FloatingText3d>()
+            .register_type::<TextBillboard>()
+            .register_type::<TextMeshTransition>()
+            .register_type::<TextMeshTransitionGhost>()
+            .register_type::<TextMeshReveal>()
+            .register_type::<TextMeshLayout>()
+            .register_type::<TextMeshBvh>()
+            .register_type::<TextMeshCollider>()
+            .register_type::<TextMeshAppend>()
+            .register_type::<TextMeshCounter>()
+            .register_type::<TextMeshCachePolicy>()
+            .register_type::<TextTexture>()
+            .register_type::<TextTextureCamera>()
+            .init_resource::<FontLoadTimeout>()
+            .init_resource::<FontWaits>()
+            .init_resource::<FontMeshSettings>()
+            .init_resource::<SharedTextMeshes>()
+            .init_resource::<GlyphMeshes>()
+            .add_message::<TextMeshError>()
+            .add_message::<TextMeshWarning>()
+            .add_message::<TextMeshReady>()
+            .add_message::<GlyphMeshesReady>()
+            .add_message::<TextMeshInputSubmitted>()
+            .add_systems(
+                self.schedule,
+                (
+                    update_text_meshes,
+                    update_text_meshes_2d,
+                    // Counters run before `update_glyph_meshes`, so their glyphs are anchored too
+                    (update_glyph_meshes, anchor_glyph_meshes).chain(),
+                    update_part_meshes,
+                    update_section_meshes,
+                    // Tickers that are removed hand their text back to `update_text_meshes`
+                    update_text_tickers.before(update_text_meshes),
+                    // Counters that are removed hand their glyphs back to `update_glyph_meshes`
+                    update_text_mesh_counters.before(update_glyph_meshes),
+                    update_text_silhouettes,
+                    update_marquee_bulbs,
+                    animate_marquee_bulbs.run_if(resource_exists::<Time>),
+                    (spawn_text_mesh_tasks, apply_text_mesh_tasks).chain(),
+                    fit_text_texture_cameras
+                        .after(update_text_meshes)
+                        .after(apply_text_mesh_tasks),
+                    (update_text_mesh_lods, select_text_mesh_lods)
+                        .chain()
+                        .after(update_text_meshes)
+                        .after(apply_text_mesh_tasks),
+                    update_text_mesh_reveals
+                        .after(update_text_meshes)
+                        .after(apply_text_mesh_tasks)
+                        .after(apply_font_placeholders),
+                    apply_glyph_materials
+                        .run_if(resource_exists::<FontMeshSettings>)
+                        .after(update_glyph_meshes)
+                        .after(update_text_mesh_counters),
+                    update_text_glow_shells
+                        .after(update_text_meshes)
+                        .after(apply_text_mesh_tasks),
+                    (
+                        update_text_whitespace_markers,
+                        update_text_cursors,
+                        update_text_selections,
+                        layout_text_tables,
+                    )
+                        .after(update_text_meshes)
+                        .after(apply_text_mesh_tasks),
+                    update_text_colliders
+                        .after(update_text_meshes)
+                        .after(update_glyph_meshes)
+                        .after(update_text_mesh_counters)
+                        .after(apply_text_mesh_tasks),
+                    apply_font_placeholders
+                        .run_if(resource_exists::<FontLoadTimeout>)
+                        .after(update_text_meshes)
+                        .after(update_text_meshes_2d)
+                        .after(update_glyph_meshes)
+                        .after(spawn_text_mesh_tasks),
+                    record_text_meshes.run_if(resource_exists::<TextMeshRecorder>),
+                )
+                    .in_set(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                // Glyph animations move transforms, so they have to run before propagation
+                animate_glyphs
+                    .run_if(resource_exists::<Time>)
+                    .before(TransformSystems::Propagate),
+            )
+            .add_systems(
+                self.schedule,
+                (
+                    spawn_floating_texts
+                        .before(inherit_text_styles)
+                        .before(apply_default_materials)
+                        .before(FontMeshSystems),
+                    // Floating moves transforms, so it has to run before propagation
+                    animate_floating_texts
+                        .run_if(resource_exists::<Time>)
+                        .after(update_text_mesh_lifetimes)
+                        .before(TransformSystems::Propagate),
+                    // Billboards turn transforms, so they have to run before propagation
+                    face_text_billboards.before(TransformSystems::Propagate),
+                ),
+            )
+            .add_systems(
+                self.schedule,
+                // Scale fades move transforms, so they have to run before propagation
+                update_text_mesh_lifetimes
+                    .run_if(resource_exists::<Time>)
+                    .before(TransformSystems::Propagate),
+            )
+            .add_systems(
+                self.schedule,
+                (
+                    // The old mesh has to be copied after the last edit of the frame and
+                    // before it is regenerated
+                    start_text_mesh_transitions
+                        .after(inherit_text_styles)
+                        .after(apply_text_appends)
+                        .after(apply_text_mesh_playback)
+                        .before(FontMeshSystems),
+                    // Transitions move transforms, so they have to run before propagation
+                    update_text_mesh_transitions
+                        .run_if(resource_exists::<Time>)
+                        .after(start_text_mesh_transitions)
+                        .before(TransformSystems::Propagate),
+                ),
+            )
+            .add_systems(self.schedule, inherit_text_styles.before(FontMeshSystems))
+            .add_systems(
+                self.schedule,
+                // Aligning moves transforms, so it has to run before propagation
+                align_text_baselines
+                    .after(inherit_text_styles)
+                    .before(TransformSystems::Propagate),
+            )
+            .add_systems(self.schedule, apply_text_appends.before(FontMeshSystems))
+            .add_systems(self.schedule, release_unused_glyph_meshes)
+            .add_systems(
+                self.schedule,
+                // Only worlds with the input plugin send keyboard input
+                update_text_mesh_inputs
+                    .run_if(resource_exists::<Messages<KeyboardInput>>)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(self.schedule, update_text_spans.before(FontMeshSystems))
+            .add_systems(
+                self.schedule,
+                // Only worlds with an asset server send asset events
+                reload_modified_fonts
+                    .run_if(resource_exists::<Messages<AssetEvent<FontMesh>>>)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                (
+                    defer_offscreen_text_meshes::<TextMesh>,
+                    defer_offscreen_text_meshes::<TextMeshGlyphs>,
+                    defer_offscreen_text_meshes::<TextMeshParts>,
+                    defer_offscreen_text_meshes::<TextMeshSections>,
+                    defer_offscreen_text_meshes::<TextMesh2d>,
+                )
+                    .after(inherit_text_styles)
+                    .after(update_text_table_cells)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                update_text_table_cells
+                    .after(inherit_text_styles)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                apply_default_materials
+                    .run_if(resource_exists::<FontMeshSettings>)
+                    .before(validate_text_meshes)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                (
+                    sync_font_paths::<TextMesh>,
+                    sync_font_paths::<TextMeshGlyphs>,
+                    sync_font_paths::<TextMeshParts>,
+                    sync_font_paths::<TextMesh2d>,
+                )
+                    .run_if(resource_exists::<AssetServer>)
+                    .after(inherit_text_styles)
+                    .before(track_font_waits)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                track_font_waits
+                    .run_if(
+                        resource_exists::<FontLoadTimeout>
+                            .or(resource_exists::<TextMeshValidation>),
+                    )
+                    .after(inherit_text_styles)
+                    .before(validate_text_meshes)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                validate_text_meshes
+                    .run_if(resource_exists::<TextMeshValidation>)
+                    .after(inherit_text_styles)
+                    .before(FontMeshSystems),
+            )
+            .add_systems(
+                self.schedule,
+                apply_text_mesh_playback
+                    .run_if(resource_exists::<TextMeshPlayback>)
+                    .before(FontMeshSystems),
+            );
+
+        // Transform propagation and visibility bounds only run in `PostUpdate`
+        if self.schedule == PostUpdate.intern() {
+            app.configure_sets(
+                self.schedule,
+                FontMeshSystems
+                    .after(TransformSystems::Propagate)
+                    .before(VisibilitySystems::CalculateBounds),
+            )
+            // Children spawned or moved after propagation need their transforms too
+            .add_systems(
+                self.schedule,
+                sync_generated_transforms
+                    .after(FontMeshSystems)
+                    .before(VisibilitySystems::CalculateBounds),
+            );
+        }
+
+        if let Some(set) = self.set {
+            app.configure_sets(self.schedule, FontMeshSystems.in_set(set));
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        // PBR materials only exist once every plugin is built
+        add_default_material(app);
+    }
+}
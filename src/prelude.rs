@@ -1,9 +1,73 @@
+pub use crate::core::{
+    layout_text, pad_figures, BevelProfile, BevelStyle, CaseTransform, ExtrusionProfile,
+    FallbackFonts, FontMesh, FontMetrics, GlyphId, GlyphMetrics, GlyphPivot, Handcrafted,
+    JustifyText, LayoutLine, MissingGlyphPolicy, PositionedGlyph, SideShading, StylisticSet, Tag,
+    TextAnchor, TextDecorations, TextLayout, TextMeshStyle, TextOrientation, TextOverflow,
+    TextRenderMode,
+};
+#[cfg(feature = "picking")]
+pub use crate::picking::GlyphPointer;
+#[cfg(feature = "bevy")]
 pub use crate::{
-    asset::{FontMesh, FontMetrics, GlyphMetrics},
+    append::TextMeshAppend,
+    bake::{bake_bevel_normal_map, BakedTextMesh, BevelBakeSettings},
+    baseline::TextBaselineGroup,
+    billboard::TextBillboard,
+    bounds::TextMeshBounds,
+    bvh::TextMeshBvh,
+    caret::{TextCursor3d, TextCursor3dQuad, TextSelection3d, TextSelection3dQuads},
+    collider::{TextColliderData, TextColliderShape, TextMeshCollider},
+    commands::SpawnTextMeshExt,
     component::{
-        GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshBundle, TextMeshGlyphs,
-        TextMeshGlyphsBundle, TextMeshStyle,
+        CharClass, GlyphMaterials, GlyphMesh, TextMesh, TextMesh2d, TextMesh2dBundle,
+        TextMeshBundle, TextMeshGlyphs, TextMeshGlyphsBundle, TextMeshPart, TextMeshPartMaterials,
+        TextMeshParts, TextMeshPartsBundle,
+    },
+    counter::{CounterFormat, TextMeshCounter},
+    floating::FloatingText3d,
+    font_path::TextMeshFontPaths,
+    glow::{TextMeshGlow, TextMeshGlowShell},
+    glyph_animation::{GlyphBounce, GlyphShake, GlyphWave},
+    glyph_meshes::GlyphMeshes,
+    gradient::{GradientAxis, TextGradient},
+    input::{TextMeshInput, TextMeshInputSubmitted},
+    lazy::{TextMeshDeferred, TextMeshLazy},
+    lifetime::{TextMeshExpiry, TextMeshFade, TextMeshLifetime},
+    lod::{TextMeshLod, TextMeshLodLevel, TextMeshLodMeshes},
+    marquee::{MarqueeBlink, MarqueeBulb, TextMeshMarquee, TextMeshMarqueeComputed},
+    path::TextPath,
+    placeholder::{FontLoadTimeout, TextMeshError},
+    plugin::{FontMeshPlugin, FontMeshPluginConfig, FontMeshSystems},
+    replay::{
+        ReplayFont, ReplayedText, TextMeshPlayback, TextMeshRecorder, TextReplayEvent,
+        TextReplayStream, TextSnapshot,
+    },
+    reveal::{TextMeshReveal, ATTRIBUTE_GLYPH_SEED},
+    sections::{
+        TextMeshSection, TextMeshSectionMesh, TextMeshSections, TextMeshSectionsBundle,
+        TextMeshSectionsComputed,
+    },
+    settings::{FontMeshSettings, MaterialFactory, MaterialTarget},
+    shared_mesh::TextMeshCachePolicy,
+    silhouette::{
+        LineSilhouette, LineStripes, TextLineBox, TextMeshLayout, TextMeshRayHit, TextSilhouette,
+        TextSilhouetteComputed,
+    },
+    span::{Text3d, Text3dStyle, TextFont3d, TextSpan3d},
+    style_root::{TextStyleOverride, TextStyleRoot},
+    system::{
+        generate_glyph_mesh, GlyphMeshesReady, TextMesh2dComputed, TextMeshComputed,
+        TextMeshGlyphsComputed, TextMeshPartsComputed, TextMeshReady,
+    },
+    table::{
+        ColumnWidth, TableRules, TextTable, TextTableCell, TextTableColumn, TextTableLayout,
+        TextTableRules,
     },
-    system::{generate_glyph_mesh, TextMeshComputed, TextMeshGlyphsComputed},
-    FontMeshPlugin,
+    task::{TextMeshAsync, TextMeshTask},
+    texture::{spawn_text_texture, TextTexture, TextTextureCamera, TextTextureSettings},
+    ticker::{TextTicker, TickerEdge},
+    toast::{Toast3d, Toast3dPlugin, Toast3dStack},
+    transition::{TextMeshTransition, TextMeshTransitionGhost},
+    validation::{TextMeshValidation, TextMeshWarning, TextMeshWarningKind},
+    whitespace::{TextMeshWhitespace, TextMeshWhitespaceMarkers},
 };
@@ -0,0 +1,98 @@
+use crate::component::{TextMesh, TextMesh2d, TextMeshGlyphs, TextMeshParts};
+use crate::core::style::TextMeshStyle;
+use crate::counter::CounterGlyphs;
+use crate::glyph_meshes::GlyphMeshes;
+use crate::glyph_pool::GlyphPool;
+use crate::line_cache::TextMeshLineCache;
+use crate::sections::TextMeshSections;
+use crate::shared_mesh::SharedTextMeshes;
+use crate::ticker::TextTickerCache;
+use crate::FontMesh;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+
+/// Whether text in `font` and `style` uses any of the `modified` fonts, as its primary
+/// font or as a fallback.
+pub(crate) fn uses_font(
+    font: AssetId<FontMesh>,
+    style: &TextMeshStyle,
+    modified: &HashSet<AssetId<FontMesh>>,
+) -> bool {
+    modified.contains(&font)
+        || style
+            .fallback_fonts
+            .iter()
+            .any(|font| modified.contains(&font.id()))
+}
+
+/// System that regenerates all text whose font asset was modified, for example by
+/// hot reloading the font file.
+///
+/// Generation only follows changes of the text components, so the text of every entity
+/// using a modified font is marked changed, and the meshes, line geometry, and glyph
+/// meshes cached for the old font are dropped.
+#[allow(clippy::too_many_arguments)]
+pub fn reload_modified_fonts(
+    mut commands: Commands,
+    mut font_events: MessageReader<AssetEvent<FontMesh>>,
+    mut shared: ResMut<SharedTextMeshes>,
+    mut glyph_meshes: ResMut<GlyphMeshes>,
+    mut texts: Query<(Entity, &mut TextMesh)>,
+    mut texts_2d: Query<&mut TextMesh2d>,
+    mut glyph_texts: Query<(Entity, &mut TextMeshGlyphs, Option<&mut GlyphPool>)>,
+    mut part_texts: Query<&mut TextMeshParts>,
+    mut section_texts: Query<&mut TextMeshSections>,
+) {
+    let modified: HashSet<AssetId<FontMesh>> = font_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    if modified.is_empty() {
+        return;
+    }
+
+    // 1. Forget the shared meshes built with the old fonts
+    shared.forget_fonts(&modified);
+    glyph_meshes.forget_fonts(&modified);
+
+    // 2. Mark the text changed, dropping caches that would keep the old geometry
+    for (entity, mut text_mesh) in texts.iter_mut() {
+        if uses_font(text_mesh.font.id(), &text_mesh.style, &modified) {
+            text_mesh.set_changed();
+            commands
+                .entity(entity)
+                .remove::<(TextMeshLineCache, TextTickerCache)>();
+        }
+    }
+    for (entity, mut text_glyphs, pool) in glyph_texts.iter_mut() {
+        if uses_font(text_glyphs.font.id(), &text_glyphs.style, &modified) {
+            text_glyphs.set_changed();
+            if let Some(mut pool) = pool {
+                pool.invalidate();
+            }
+            commands.entity(entity).remove::<CounterGlyphs>();
+        }
+    }
+    for mut text_mesh in texts_2d.iter_mut() {
+        if uses_font(text_mesh.font.id(), &text_mesh.style, &modified) {
+            text_mesh.set_changed();
+        }
+    }
+    for mut text_parts in part_texts.iter_mut() {
+        if uses_font(text_parts.font.id(), &text_parts.style, &modified) {
+            text_parts.set_changed();
+        }
+    }
+    for mut sections in section_texts.iter_mut() {
+        let uses = sections
+            .sections
+            .iter()
+            .any(|section| uses_font(section.font.id(), &sections.style, &modified));
+        if uses {
+            sections.set_changed();
+        }
+    }
+}
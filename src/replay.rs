@@ -0,0 +1,333 @@
+use crate::component::{TextMesh, TextMeshBundle};
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+/// Recorded content and style of one text entity at a point in time.
+///
+/// Fonts are stored as indices into the font table of the owning
+/// [`TextReplayStream`], so the snapshot itself holds no asset handles.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextSnapshot {
+    /// The text at this point in time.
+    pub text: String,
+    /// Index of the primary font in [`TextReplayStream::fonts`].
+    pub font: usize,
+    /// Indices of the fallback fonts in [`TextReplayStream::fonts`].
+    pub fallback_fonts: Vec<usize>,
+    /// The style, with `fallback_fonts` left empty (see [`TextSnapshot::fallback_fonts`]).
+    pub style: TextMeshStyle,
+}
+
+/// A single change in a [`TextReplayStream`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextReplayEvent {
+    /// Seconds since the start of the recording.
+    pub time: f64,
+    /// Stable identifier of the recorded entity within the stream.
+    pub key: u64,
+    /// The new state, or `None` if the text was removed.
+    pub state: Option<TextSnapshot>,
+}
+
+/// Font referenced by a [`TextReplayStream`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayFont {
+    /// Asset path the font was loaded from, if it came from the asset server.
+    pub path: Option<String>,
+    /// Handle to the font in the recording session. Not serialized; deserialized
+    /// streams load fonts from `path` instead.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub handle: Option<Handle<FontMesh>>,
+}
+
+/// Compact, timestamped history of [`TextMesh`] content and style.
+///
+/// Only changes are stored: an event is added when a text is spawned, edited, or
+/// removed. Meshes are never recorded; playback regenerates them from the recorded
+/// state. With the `serialize` feature, the stream implements `serde::Serialize` and
+/// `serde::Deserialize`, so it can be saved in any serde format.
+///
+/// Streams are filled by a [`TextMeshRecorder`] and played back with a
+/// [`TextMeshPlayback`], or built and inspected by hand through this API.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextReplayStream {
+    fonts: Vec<ReplayFont>,
+    events: Vec<TextReplayEvent>,
+}
+
+impl TextReplayStream {
+    /// Create an empty stream.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fonts referenced by the recorded snapshots.
+    pub fn fonts(&self) -> &[ReplayFont] {
+        &self.fonts
+    }
+
+    /// All recorded events, ordered by time.
+    pub fn events(&self) -> &[TextReplayEvent] {
+        &self.events
+    }
+
+    /// Time of the last recorded event, in seconds.
+    pub fn duration(&self) -> f64 {
+        self.events.last().map_or(0.0, |event| event.time)
+    }
+
+    /// Record the state of a text mesh at `time`.
+    ///
+    /// Nothing is recorded if the state is identical to the last one recorded for `key`.
+    /// `time` must not be earlier than the last recorded event.
+    pub fn record(&mut self, time: f64, key: u64, text_mesh: &TextMesh) {
+        let mut style = text_mesh.style.clone();
+        let fallback_fonts = std::mem::take(&mut style.fallback_fonts)
+            .iter()
+            .map(|handle| self.font_index(handle))
+            .collect();
+
+        let snapshot = TextSnapshot {
+            text: text_mesh.text.clone(),
+            font: self.font_index(&text_mesh.font),
+            fallback_fonts,
+            style,
+        };
+        self.push(time, key, Some(snapshot));
+    }
+
+    /// Record that the text identified by `key` was removed at `time`.
+    pub fn record_removed(&mut self, time: f64, key: u64) {
+        self.push(time, key, None);
+    }
+
+    /// The state of every text that exists at `time`, keyed by entity key.
+    pub fn state_at(&self, time: f64) -> BTreeMap<u64, &TextSnapshot> {
+        let mut state = BTreeMap::new();
+        for event in self.events.iter().take_while(|event| event.time <= time) {
+            match &event.state {
+                Some(snapshot) => state.insert(event.key, snapshot),
+                None => state.remove(&event.key),
+            };
+        }
+        state
+    }
+
+    fn push(&mut self, time: f64, key: u64, state: Option<TextSnapshot>) {
+        let last = self.events.iter().rev().find(|event| event.key == key);
+        let unchanged = match last {
+            Some(last) => last.state == state,
+            // Removing a text the stream never saw is a no-op
+            None => state.is_none(),
+        };
+        if !unchanged {
+            self.events.push(TextReplayEvent { time, key, state });
+        }
+    }
+
+    fn font_index(&mut self, handle: &Handle<FontMesh>) -> usize {
+        let id = handle.id();
+        if let Some(index) = self
+            .fonts
+            .iter()
+            .position(|font| font.handle.as_ref().map(Handle::id) == Some(id))
+        {
+            return index;
+        }
+
+        self.fonts.push(ReplayFont {
+            path: handle.path().map(ToString::to_string),
+            handle: Some(handle.clone()),
+        });
+        self.fonts.len() - 1
+    }
+}
+
+/// Resource that records every [`TextMesh`] into a [`TextReplayStream`].
+///
+/// Insert it to start recording and remove it (or take its stream) to stop. Time is
+/// measured with [`Time`] from the first frame the recorder runs, so the app needs
+/// Bevy's time plugin (included in `DefaultPlugins` and `MinimalPlugins`). Text spawned
+/// by a [`TextMeshPlayback`] is never recorded.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn start_recording(mut commands: Commands) {
+///     commands.insert_resource(TextMeshRecorder::default());
+/// }
+///
+/// fn replay_last_seconds(mut commands: Commands, recorder: Res<TextMeshRecorder>) {
+///     let stream = recorder.stream.clone();
+///     let start = (stream.duration() - 5.0).max(0.0);
+///     commands.insert_resource(TextMeshPlayback::new(stream, start));
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct TextMeshRecorder {
+    /// The recorded history.
+    pub stream: TextReplayStream,
+    /// While `true`, changes are not recorded.
+    pub paused: bool,
+    start: Option<f64>,
+}
+
+/// Resource that reconstructs recorded text from a [`TextReplayStream`].
+///
+/// Every text that exists in the stream at [`TextMeshPlayback::time`] is spawned as an
+/// entity with a [`TextMesh`] and a [`ReplayedText`] marker, and kept in sync as the time
+/// changes. Only content and style are restored, so add materials and transforms to
+/// the spawned entities yourself, for example in a system that queries
+/// `Added<ReplayedText>`. Removing the resource leaves the spawned entities in place.
+#[derive(Resource)]
+pub struct TextMeshPlayback {
+    /// The history being played back.
+    pub stream: TextReplayStream,
+    /// Current playback time in seconds since the start of the recording.
+    pub time: f64,
+    entities: HashMap<u64, Entity>,
+    fonts: Vec<Option<Handle<FontMesh>>>,
+}
+
+impl TextMeshPlayback {
+    /// Play back `stream`, starting at `time`.
+    pub fn new(stream: TextReplayStream, time: f64) -> Self {
+        Self {
+            stream,
+            time,
+            entities: HashMap::default(),
+            fonts: Vec::new(),
+        }
+    }
+}
+
+/// Marker for text entities spawned by a [`TextMeshPlayback`].
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct ReplayedText {
+    /// Key of the recorded entity in the [`TextReplayStream`].
+    pub key: u64,
+}
+
+/// System that records [`TextMesh`] changes into the [`TextMeshRecorder`].
+pub fn record_text_meshes(
+    time: Res<Time>,
+    mut recorder: ResMut<TextMeshRecorder>,
+    query: Query<(Entity, Ref<TextMesh>), Without<ReplayedText>>,
+    mut removed: RemovedComponents<TextMesh>,
+) {
+    if recorder.paused {
+        removed.clear();
+        return;
+    }
+
+    let now = time.elapsed_secs_f64();
+    // The first frame captures all existing text, later frames only changes
+    let first_frame = recorder.start.is_none();
+    let elapsed = now - *recorder.start.get_or_insert(now);
+
+    for (entity, text_mesh) in query.iter() {
+        if first_frame || text_mesh.is_changed() {
+            recorder
+                .stream
+                .record(elapsed, entity.to_bits(), &text_mesh);
+        }
+    }
+
+    for entity in removed.read() {
+        recorder.stream.record_removed(elapsed, entity.to_bits());
+    }
+}
+
+/// System that spawns and updates [`ReplayedText`] entities for the [`TextMeshPlayback`].
+pub fn apply_text_mesh_playback(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+    playback: ResMut<TextMeshPlayback>,
+    mut texts: Query<&mut TextMesh, With<ReplayedText>>,
+) {
+    if !playback.is_changed() {
+        return;
+    }
+
+    let TextMeshPlayback {
+        stream,
+        time,
+        entities,
+        fonts,
+    } = playback.into_inner();
+    let state = stream.state_at(*time);
+
+    // Despawn text that doesn't exist at this point of the recording
+    entities.retain(|key, entity| {
+        let keep = state.contains_key(key);
+        if !keep {
+            commands.entity(*entity).despawn();
+        }
+        keep
+    });
+
+    let mut font = |index: usize| {
+        if fonts.len() <= index {
+            fonts.resize(index + 1, None);
+        }
+        if fonts[index].is_none() {
+            let recorded = stream.fonts.get(index)?;
+            fonts[index] = recorded.handle.clone().or_else(|| {
+                let path = recorded.path.clone()?;
+                Some(asset_server.as_ref()?.load(path))
+            });
+        }
+        fonts[index].clone()
+    };
+
+    for (key, snapshot) in state {
+        let Some(primary) = font(snapshot.font) else {
+            continue;
+        };
+        let mut style = snapshot.style.clone();
+        style.fallback_fonts = snapshot
+            .fallback_fonts
+            .iter()
+            .filter_map(|&index| font(index))
+            .collect();
+
+        if let Some(mut text_mesh) = entities.get(&key).and_then(|&e| texts.get_mut(e).ok()) {
+            // Only touch the component when the state differs, to avoid regenerating
+            if text_mesh.text != snapshot.text
+                || text_mesh.font != primary
+                || text_mesh.style != style
+            {
+                text_mesh.text.clone_from(&snapshot.text);
+                text_mesh.font = primary;
+                text_mesh.style = style;
+            }
+            continue;
+        }
+
+        let entity = commands
+            .spawn((
+                TextMeshBundle {
+                    text_mesh: TextMesh {
+                        text: snapshot.text.clone(),
+                        font: primary,
+                        style,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ReplayedText { key },
+            ))
+            .id();
+        entities.insert(key, entity);
+    }
+}
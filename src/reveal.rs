@@ -0,0 +1,398 @@
+use crate::system::TextMeshComputed;
+use bevy::mesh::{Indices, MeshVertexAttribute, VertexAttributeValues, VertexFormat};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Reveals the mesh of a [`TextMesh`](crate::TextMesh) gradually as its progress goes
+/// from `0.0` to `1.0`.
+///
+/// With this component, the plugin shows a copy of the generated mesh with only the
+/// revealed part left, for title cards and typewriter-free intros. Animate the progress
+/// with [`set_progress`](Self::set_progress) from your own systems; at `0.0` nothing is
+/// shown and at `1.0` the whole text. The copy belongs to the entity even when the full
+/// mesh is shared between texts, and removing the component puts the full mesh back.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Chapter One".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshReveal::Wipe {
+///         direction: Vec2::X,
+///         progress: 0.0,
+///     },
+/// ));
+///
+/// fn reveal(time: Res<Time>, mut reveals: Query<&mut TextMeshReveal>) {
+///     for mut reveal in reveals.iter_mut() {
+///         let progress = reveal.progress() + time.delta_secs() / 2.0;
+///         reveal.set_progress(progress.min(1.0));
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub enum TextMeshReveal {
+    /// Clip the text at a plane that sweeps across it, cutting through glyphs.
+    Wipe {
+        /// Direction the plane moves in, in the text's local XY plane; [`Vec2::X`]
+        /// reveals the text from left to right.
+        direction: Vec2,
+        /// How much of the text is revealed, from `0.0` to `1.0`.
+        progress: f32,
+    },
+    /// Show whole glyphs in a random order, each once the progress passes its
+    /// threshold.
+    ///
+    /// The thresholds are baked into the revealed mesh as [`ATTRIBUTE_GLYPH_SEED`].
+    /// Glyphs are found as the connected pieces of the mesh; pieces whose outlines
+    /// overlap, such as the dot and stem of an `i`, appear together.
+    Dissolve {
+        /// Seed of the glyph order; texts with the same seed dissolve alike.
+        seed: u32,
+        /// How much of the text is revealed, from `0.0` to `1.0`.
+        progress: f32,
+    },
+}
+
+impl TextMeshReveal {
+    /// How much of the text is revealed, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        match *self {
+            Self::Wipe { progress, .. } | Self::Dissolve { progress, .. } => progress,
+        }
+    }
+
+    /// Set how much of the text is revealed, from `0.0` to `1.0`.
+    pub fn set_progress(&mut self, value: f32) {
+        match self {
+            Self::Wipe { progress, .. } | Self::Dissolve { progress, .. } => *progress = value,
+        }
+    }
+}
+
+/// Per-vertex random threshold in `[0, 1)` of the glyph the vertex belongs to, baked into
+/// meshes revealed by [`TextMeshReveal::Dissolve`].
+///
+/// Glyphs appear once the progress passes their threshold, so custom materials can read
+/// it to make glyphs glow or flicker just before or after they appear.
+pub const ATTRIBUTE_GLYPH_SEED: MeshVertexAttribute = MeshVertexAttribute::new(
+    "Vertex_GlyphSeed",
+    0x5EED_F0A7_3E5A_0001,
+    VertexFormat::Float32,
+);
+
+/// The full and revealed meshes of a [`TextMeshReveal`], on the text.
+#[derive(Component)]
+pub(crate) struct RevealedMesh {
+    /// Mesh generated from the text.
+    full: Handle<Mesh>,
+    /// Copy of `full` with the revealed part left, shown instead of it.
+    revealed: Handle<Mesh>,
+    /// Glyph of each vertex of `full`, found on the first dissolve.
+    glyphs: Option<Vec<u32>>,
+}
+
+type TextMeshRevealQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshReveal>,
+        &'static mut Mesh3d,
+        Option<&'static mut RevealedMesh>,
+    ),
+    With<TextMeshComputed>,
+>;
+
+/// System that shows the revealed part of text with a [`TextMeshReveal`].
+///
+/// Runs after mesh generation and picks up the freshly generated full mesh.
+pub fn update_text_mesh_reveals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut texts: TextMeshRevealQuery,
+    mut removed: RemovedComponents<TextMeshReveal>,
+    mut restore: Query<(&mut Mesh3d, &RevealedMesh), Without<TextMeshReveal>>,
+) {
+    // 1. Put the full mesh back on text that is no longer revealed
+    for entity in removed.read() {
+        if let Ok((mut mesh, revealed)) = restore.get_mut(entity) {
+            meshes.remove(&revealed.revealed);
+            mesh.0 = revealed.full.clone();
+            commands.entity(entity).remove::<RevealedMesh>();
+        }
+    }
+
+    for (entity, reveal, mut mesh, revealed) in texts.iter_mut() {
+        // 2. A mesh other than the revealed one was just generated from the text; the line
+        //    cache doesn't recognize the revealed mesh, so edits always make a new one
+        let regenerated = revealed
+            .as_ref()
+            .is_none_or(|revealed| mesh.0 != revealed.revealed);
+        if !(regenerated || reveal.is_changed()) {
+            continue;
+        }
+        let full = match &revealed {
+            Some(revealed) if !regenerated => revealed.full.id(),
+            _ => mesh.0.id(),
+        };
+        let Some(full) = meshes.get(full) else {
+            continue;
+        };
+
+        // 3. Cut the revealed part out of a copy of the full mesh
+        let mut glyphs = revealed
+            .as_ref()
+            .filter(|_| !regenerated)
+            .and_then(|revealed| revealed.glyphs.clone());
+        let copy = match *reveal {
+            TextMeshReveal::Wipe {
+                direction,
+                progress,
+            } => wipe(full, direction, progress),
+            TextMeshReveal::Dissolve { seed, progress } => {
+                let glyphs = glyphs.get_or_insert_with(|| find_glyphs(full));
+                dissolve(full, glyphs, seed, progress)
+            }
+        };
+
+        match revealed {
+            Some(mut revealed) => {
+                if regenerated {
+                    revealed.full = mesh.0.clone();
+                }
+                revealed.glyphs = glyphs;
+                if let Some(target) = meshes.get_mut(&revealed.revealed) {
+                    *target = copy;
+                }
+                if mesh.0 != revealed.revealed {
+                    mesh.0 = revealed.revealed.clone();
+                }
+            }
+            None => {
+                let full = mesh.0.clone();
+                mesh.0 = meshes.add(copy);
+                commands.entity(entity).insert(RevealedMesh {
+                    full,
+                    revealed: mesh.0.clone(),
+                    glyphs,
+                });
+            }
+        }
+    }
+}
+
+/// Copy of `full` clipped to the part up to `progress` of the way along `direction`.
+fn wipe(full: &Mesh, direction: Vec2, progress: f32) -> Mesh {
+    let mut mesh = full.clone();
+    let direction = direction.try_normalize().unwrap_or(Vec2::X);
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        full.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return mesh;
+    };
+    let Some(indices) = full.indices() else {
+        return mesh;
+    };
+    let distances: Vec<f32> = positions
+        .iter()
+        .map(|&[x, y, _]| Vec2::new(x, y).dot(direction))
+        .collect();
+    let (min, max) = distances
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), &x| {
+            (min.min(x), max.max(x))
+        });
+    let cut = min + (max - min) * progress.clamp(0.0, 1.0);
+
+    // Sutherland–Hodgman against the plane, one triangle at a time; vertices on a cut edge
+    // are shared between the triangles on either side of it
+    let mut clipped = Vec::with_capacity(indices.len());
+    let mut splits: HashMap<(usize, usize), u32> = HashMap::default();
+    let indices: Vec<usize> = indices.iter().collect();
+    for triangle in indices.chunks_exact(3) {
+        let inside = |vertex: usize| distances[vertex] <= cut;
+        if progress >= 1.0 || triangle.iter().all(|&vertex| inside(vertex)) {
+            clipped.extend(triangle.iter().map(|&vertex| vertex as u32));
+            continue;
+        }
+        if progress <= 0.0 || !triangle.iter().any(|&vertex| inside(vertex)) {
+            continue;
+        }
+
+        let mut polygon = Vec::with_capacity(4);
+        for (i, &a) in triangle.iter().enumerate() {
+            let b = triangle[(i + 1) % 3];
+            if inside(a) {
+                polygon.push(a as u32);
+            }
+            if inside(a) != inside(b) {
+                let key = (a.min(b), a.max(b));
+                let split = *splits.entry(key).or_insert_with(|| {
+                    let (from, to) = key;
+                    let t = (cut - distances[from]) / (distances[to] - distances[from]);
+                    split_edge(&mut mesh, from, to, t)
+                });
+                polygon.push(split);
+            }
+        }
+        for i in 1..polygon.len() - 1 {
+            clipped.extend([polygon[0], polygon[i], polygon[i + 1]]);
+        }
+    }
+    mesh.insert_indices(Indices::U32(clipped));
+    mesh
+}
+
+/// Add a vertex to `mesh` at `t` of the way from vertex `from` to vertex `to`,
+/// interpolating its float attributes, and return its index.
+fn split_edge(mesh: &mut Mesh, from: usize, to: usize, t: f32) -> u32 {
+    let index = mesh.count_vertices() as u32;
+    for (_, values) in mesh.attributes_mut() {
+        match values {
+            VertexAttributeValues::Float32(values) => {
+                values.push(values[from].lerp(values[to], t));
+            }
+            VertexAttributeValues::Float32x2(values) => {
+                let value = Vec2::from(values[from]).lerp(Vec2::from(values[to]), t);
+                values.push(value.into());
+            }
+            VertexAttributeValues::Float32x3(values) => {
+                let value = Vec3::from(values[from]).lerp(Vec3::from(values[to]), t);
+                values.push(value.into());
+            }
+            VertexAttributeValues::Float32x4(values) => {
+                let value = Vec4::from(values[from]).lerp(Vec4::from(values[to]), t);
+                values.push(value.into());
+            }
+            _ => {}
+        }
+    }
+    index
+}
+
+/// Glyph of each vertex of `full`, numbered in the order the glyphs first appear.
+///
+/// Vertices of connected triangles belong to the same piece, and pieces whose bounds
+/// overlap in the XY plane to the same glyph, joining the caps and sides of a glyph as
+/// well as its separate strokes.
+fn find_glyphs(full: &Mesh) -> Vec<u32> {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        full.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return Vec::new();
+    };
+    let mut sets = DisjointSets::new(positions.len());
+    if let Some(indices) = full.indices() {
+        let indices: Vec<usize> = indices.iter().collect();
+        for triangle in indices.chunks_exact(3) {
+            sets.join(triangle[0], triangle[1]);
+            sets.join(triangle[0], triangle[2]);
+        }
+    }
+
+    // Bounds of each piece, keyed by its root vertex
+    let mut pieces: HashMap<usize, Rect> = HashMap::default();
+    for (vertex, &[x, y, _]) in positions.iter().enumerate() {
+        let point = Vec2::new(x, y);
+        pieces
+            .entry(sets.root(vertex))
+            .and_modify(|bounds| *bounds = bounds.union_point(point))
+            .or_insert(Rect::from_corners(point, point));
+    }
+    let pieces: Vec<(usize, Rect)> = pieces.into_iter().collect();
+    for (i, &(a, bounds_a)) in pieces.iter().enumerate() {
+        for &(b, bounds_b) in &pieces[i + 1..] {
+            if !bounds_a.intersect(bounds_b).is_empty() {
+                sets.join(a, b);
+            }
+        }
+    }
+
+    let mut numbers: HashMap<usize, u32> = HashMap::default();
+    (0..positions.len())
+        .map(|vertex| {
+            let next = numbers.len() as u32;
+            *numbers.entry(sets.root(vertex)).or_insert(next)
+        })
+        .collect()
+}
+
+/// Copy of `full` with only the glyphs whose threshold `progress` has passed.
+fn dissolve(full: &Mesh, glyphs: &[u32], seed: u32, progress: f32) -> Mesh {
+    let mut mesh = full.clone();
+    let thresholds: Vec<f32> = glyphs
+        .iter()
+        .map(|&glyph| glyph_threshold(seed, glyph))
+        .collect();
+    if let Some(indices) = full.indices() {
+        let indices: Vec<usize> = indices.iter().collect();
+        let visible = indices
+            .chunks_exact(3)
+            .filter(|triangle| {
+                progress >= 1.0
+                    || thresholds
+                        .get(triangle[0])
+                        .is_some_and(|&threshold| threshold < progress)
+            })
+            .flatten()
+            .map(|&vertex| vertex as u32)
+            .collect();
+        mesh.insert_indices(Indices::U32(visible));
+    }
+    if thresholds.len() == mesh.count_vertices() {
+        mesh.insert_attribute(ATTRIBUTE_GLYPH_SEED, thresholds);
+    }
+    mesh
+}
+
+/// Deterministic threshold in `[0, 1)` of glyph `glyph` for `seed`.
+fn glyph_threshold(seed: u32, glyph: u32) -> f32 {
+    // SplitMix64 finalizer over the combined inputs
+    let mut z = (glyph as u64)
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        .wrapping_add((seed as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Union–find over vertex indices.
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn root(&mut self, mut item: usize) -> usize {
+        while self.parents[item] != item {
+            self.parents[item] = self.parents[self.parents[item]];
+            item = self.parents[item];
+        }
+        item
+    }
+
+    fn join(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.root(a), self.root(b));
+        // The smaller index wins, so roots stay the first vertex of their set
+        self.parents[a.max(b)] = a.min(b);
+    }
+}
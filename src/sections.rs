@@ -0,0 +1,421 @@
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{
+    calculate_justification_offset, calculate_line_height, calculate_spans_width, span_glyphs,
+    LineSpan,
+};
+use crate::core::mesh::{calculate_anchor_offset, glyph_uvs, TextMeshData};
+use crate::core::style::TextMeshStyle;
+use crate::core::stylistic_set::StylisticSet;
+use crate::lazy::TextMeshDeferred;
+use crate::FontMesh;
+use bevy::camera::primitives::MeshAabb;
+use bevy::prelude::*;
+
+/// One run of text in a [`TextMeshSections`] block, with its own font, size, depth,
+/// stylistic sets, and material.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(asset_server: Res<AssetServer>, material: Handle<StandardMaterial>) {
+/// let section = TextMeshSection {
+///     depth: Some(0.3),
+///     scale: 1.5,
+///     ..TextMeshSection::new("Big", asset_server.load("fonts/bold.ttf"), material)
+/// };
+/// # }
+/// ```
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct TextMeshSection {
+    /// The text of this section. Use `\n` for line breaks.
+    pub text: String,
+    /// Handle to the font asset (TTF or OTF file) used for this section.
+    pub font: Handle<FontMesh>,
+    /// Extrusion depth of this section, or `None` to use the block's style depth.
+    ///
+    /// The depth is not affected by `scale`.
+    pub depth: Option<f32>,
+    /// Stylistic sets of this section, or `None` to use the block's style sets.
+    ///
+    /// See [`TextMeshStyle::stylistic_sets`].
+    pub stylistic_sets: Option<Vec<StylisticSet>>,
+    /// Whether this section draws tabular figures, or `None` to follow the block's style.
+    ///
+    /// See [`TextMeshStyle::tabular_figures`].
+    pub tabular_figures: Option<bool>,
+    /// Material of the mesh generated for this section.
+    pub material: Handle<StandardMaterial>,
+    /// Size of this section relative to the block, `1.0` being one unit per em. Must be
+    /// positive.
+    pub scale: f32,
+}
+
+impl TextMeshSection {
+    /// A section at the default scale and depth.
+    pub fn new(
+        text: impl Into<String>,
+        font: Handle<FontMesh>,
+        material: Handle<StandardMaterial>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            font,
+            depth: None,
+            stylistic_sets: None,
+            tabular_figures: None,
+            material,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Default for TextMeshSection {
+    fn default() -> Self {
+        Self::new(String::new(), Handle::default(), Handle::default())
+    }
+}
+
+/// Component for a block of rich text made of differently styled sections.
+///
+/// All sections are laid out together as one text: they share lines, justification,
+/// anchoring, and the other layout settings of `style`, while each section brings its
+/// own font, scale, depth, stylistic sets, and material. Lines are as tall as the
+/// tallest section on them. Each section is spawned as a child entity with a
+/// [`TextMeshSectionMesh`] marker and the section's material, like the surfaces of
+/// [`TextMeshParts`](crate::TextMeshParts).
+///
+/// The `style` fallback fonts apply to every section.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// let regular = asset_server.load("fonts/regular.ttf");
+/// let bold = asset_server.load("fonts/bold.ttf");
+/// let white = materials.add(Color::WHITE);
+/// let red = materials.add(Color::srgb(1.0, 0.2, 0.2));
+///
+/// commands.spawn(TextMeshSectionsBundle {
+///     sections: TextMeshSections {
+///         sections: vec![
+///             TextMeshSection::new("Game ", regular, white),
+///             TextMeshSection {
+///                 depth: Some(0.4),
+///                 scale: 1.5,
+///                 ..TextMeshSection::new("Over", bold, red)
+///             },
+///         ],
+///         style: TextMeshStyle {
+///             anchor: TextAnchor::Center,
+///             ..default()
+///         },
+///     },
+///     ..default()
+/// });
+/// # }
+/// ```
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+#[require(Transform, Visibility)]
+pub struct TextMeshSections {
+    /// The sections, in reading order.
+    pub sections: Vec<TextMeshSection>,
+    /// Layout and tessellation settings shared by all sections.
+    pub style: TextMeshStyle,
+}
+
+/// Marker for the mesh entity generated for one section of a [`TextMeshSections`].
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct TextMeshSectionMesh {
+    /// Index of the section in [`TextMeshSections::sections`].
+    pub section_index: usize,
+}
+
+/// Convenience bundle for spawning a block of rich text.
+///
+/// See [`TextMeshSections`] for an example.
+#[derive(Bundle, Default)]
+pub struct TextMeshSectionsBundle {
+    /// The sections component that drives mesh generation.
+    pub sections: TextMeshSections,
+    /// Local transform of the parent entity.
+    pub transform: Transform,
+    /// Global transform (computed automatically).
+    pub global_transform: GlobalTransform,
+    /// Visibility of the entity.
+    pub visibility: Visibility,
+    /// Inherited visibility (computed automatically).
+    pub inherited_visibility: InheritedVisibility,
+    /// View visibility (computed automatically).
+    pub view_visibility: ViewVisibility,
+}
+
+/// A section together with everything needed to lay it out.
+struct SectionLayout<'a> {
+    fonts: FontChain<'a>,
+    /// Block style with the section's size, depth, and font features.
+    style: TextMeshStyle,
+}
+
+/// Fonts and styles of every section, or `None` while any of the fonts is still loading.
+fn section_layouts<'a>(
+    sections: &TextMeshSections,
+    font_assets: &'a Assets<FontMesh>,
+) -> Option<Vec<SectionLayout<'a>>> {
+    let style = &sections.style;
+    sections
+        .sections
+        .iter()
+        .map(|section| {
+            let section_style = TextMeshStyle {
+                font_size: style.font_size * section.scale,
+                depth: section.depth.unwrap_or(style.depth),
+                stylistic_sets: section
+                    .stylistic_sets
+                    .clone()
+                    .unwrap_or_else(|| style.stylistic_sets.clone()),
+                tabular_figures: section.tabular_figures.unwrap_or(style.tabular_figures),
+                ..style.clone()
+            };
+            let fonts = FontChain::for_style(font_assets, &section.font, &section_style)?;
+            Some(SectionLayout {
+                fonts,
+                style: section_style,
+            })
+        })
+        .collect()
+}
+
+/// The lines of the sections, each a list of runs with the index of their section.
+///
+/// A section that breaks a line starts the next one with an empty run, so that the
+/// line is at least as tall as that section.
+fn section_lines(sections: &TextMeshSections) -> Vec<Vec<(usize, &str)>> {
+    let mut lines = vec![Vec::new()];
+    for (section_index, section) in sections.sections.iter().enumerate() {
+        for (piece_index, piece) in section.text.split('\n').enumerate() {
+            if piece_index > 0 {
+                lines.push(Vec::new());
+            }
+            lines.last_mut().unwrap().push((section_index, piece));
+        }
+    }
+    lines
+}
+
+/// The lines of the sections as runs of the shared line layout, together with whether
+/// each line ends its paragraph and how tall it is.
+///
+/// Like in [`paragraph_lines`](crate::core::layout::paragraph_lines), a line ends its
+/// paragraph when it is the last one or the next line is blank. A line is as tall as
+/// the tallest section on it.
+fn section_spans<'a, 'f>(
+    lines: &[Vec<(usize, &'a str)>],
+    layouts: &'a [SectionLayout<'f>],
+) -> Vec<(Vec<LineSpan<'a, 'f>>, bool, f32)> {
+    let is_blank = |line: &Vec<(usize, &str)>| line.iter().all(|(_, text)| text.trim().is_empty());
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let ends_paragraph = lines.get(line_index + 1).is_none_or(is_blank);
+            let spans: Vec<_> = line
+                .iter()
+                .map(|&(section_index, text)| {
+                    let layout = &layouts[section_index];
+                    LineSpan::new(text, &layout.fonts, &layout.style)
+                })
+                .collect();
+            let height = spans
+                .iter()
+                .map(|span| calculate_line_height(span.fonts, span.style))
+                .fold(0.0, f32::max);
+            (spans, ends_paragraph, height)
+        })
+        .collect()
+}
+
+/// Anchored extent of the sections estimated from their advances and line heights,
+/// without building any glyphs.
+///
+/// Returns `None` while any of the fonts is still loading.
+pub(crate) fn estimate_sections_bounds(
+    sections: &TextMeshSections,
+    font_assets: &Assets<FontMesh>,
+) -> Option<(Vec3, Vec3)> {
+    let style = &sections.style;
+    let layouts = section_layouts(sections, font_assets)?;
+    let lines = section_lines(sections);
+    let lines = section_spans(&lines, &layouts);
+    let depth = layouts
+        .iter()
+        .map(|layout| layout.style.depth)
+        .fold(0.0, f32::max);
+
+    // Lines go down from the first baseline. A line height above the first and below the
+    // last one covers ascenders and descenders.
+    let mut min = Vec3::new(f32::MAX, 0.0, -depth);
+    let mut max = Vec3::new(f32::MIN, lines[0].2, 0.0);
+    for (spans, ends_paragraph, height) in &lines {
+        let width = calculate_spans_width(spans, *ends_paragraph, style);
+        let x = calculate_justification_offset(style.justify, width);
+        min.x = min.x.min(x);
+        max.x = max.x.max(x + width);
+        min.y -= height;
+    }
+    let offset = calculate_anchor_offset(style.anchor, min, max);
+    Some((min + offset, max + offset))
+}
+
+/// Lay out all sections as one text block and return the mesh data of each section.
+///
+/// The lines go through the same layout as plain text, with each section shaped and
+/// measured in its own font and size, so shaping, overflow, justification, word spacing,
+/// and the missing-glyph policy apply to sections too.
+///
+/// Returns `None` while any of the fonts is still loading.
+pub(crate) fn build_sections_data(
+    sections: &TextMeshSections,
+    font_assets: &Assets<FontMesh>,
+) -> Option<Vec<TextMeshData>> {
+    let style = &sections.style;
+    let layouts = section_layouts(sections, font_assets)?;
+    let lines = section_lines(sections);
+
+    // 1. Lay out and stack the lines into one buffer per section
+    let mut data = vec![TextMeshData::default(); sections.sections.len()];
+    let mut origin = Vec3::ZERO;
+    let placed = lines.iter().zip(section_spans(&lines, &layouts));
+    for (line_index, (line, (spans, ends_paragraph, height))) in placed.enumerate() {
+        if line_index > 0 {
+            origin += style.orientation.line_step(height);
+        }
+
+        for line_glyph in span_glyphs(spans, ends_paragraph, style) {
+            let section_index = line[line_glyph.span].0;
+            let layout = &layouts[section_index];
+            let Some(mut glyph) =
+                GlyphGeometry::new(line_glyph.face, line_glyph.source, &layout.style)
+            else {
+                continue;
+            };
+            glyph.handcraft(&layout.style, line_glyph.character, line_glyph.index);
+
+            let offset = origin + Vec3::new(line_glyph.x, line_glyph.y, 0.0);
+            let section = &mut data[section_index];
+            let index_offset = section.vertices.len() as u32;
+            section
+                .vertices
+                .extend(glyph.vertices.iter().map(|v| (*v + offset).to_array()));
+            section
+                .normals
+                .extend(glyph.normals.iter().map(|n| n.to_array()));
+            if style.has_uvs() {
+                section.uvs.extend(glyph_uvs(&glyph));
+            }
+            section
+                .indices
+                .extend(glyph.indices.iter().map(|i| i + index_offset));
+        }
+    }
+
+    if style.generate_tangents {
+        for section in &mut data {
+            section.generate_tangents();
+        }
+    }
+
+    // 2. Anchor the block as a whole
+    let bounds = data
+        .iter()
+        .flat_map(|section| &section.vertices)
+        .map(|&v| Vec3::from_array(v))
+        .fold(None, |bounds: Option<(Vec3, Vec3)>, v| {
+            Some(bounds.map_or((v, v), |(min, max)| (min.min(v), max.max(v))))
+        });
+    if let Some((min, max)) = bounds {
+        let anchor_offset = calculate_anchor_offset(style.anchor, min, max);
+        for vertex in data.iter_mut().flat_map(|section| &mut section.vertices) {
+            *vertex = (Vec3::from_array(*vertex) + anchor_offset).to_array();
+        }
+    }
+
+    Some(data)
+}
+
+/// Marker component indicating that a [`TextMeshSections`] has been processed.
+#[derive(Component)]
+pub struct TextMeshSectionsComputed;
+
+type TextMeshSectionsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static TextMeshSections),
+    (
+        Without<TextMeshDeferred>,
+        Or<(Changed<TextMeshSections>, Without<TextMeshSectionsComputed>)>,
+    ),
+>;
+
+/// System to generate one mesh entity per section of [`TextMeshSections`] components.
+pub fn update_section_meshes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    query: TextMeshSectionsQuery,
+    children_query: Query<&Children>,
+    section_query: Query<Entity, With<TextMeshSectionMesh>>,
+) {
+    for (entity, sections) in query.iter() {
+        // 1. Lay out every section
+        let Some(data) = build_sections_data(sections, &font_assets) else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
+
+        // 2. Despawn existing section children
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if section_query.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+
+        // 3. Spawn one child per non-empty section
+        commands.entity(entity).with_children(|parent| {
+            for (section_index, section_data) in data.into_iter().enumerate() {
+                if section_data.indices.is_empty() {
+                    continue;
+                }
+
+                let mesh = section_data.into_mesh();
+                let aabb = mesh.compute_aabb().unwrap_or_default();
+
+                parent.spawn((
+                    TextMeshSectionMesh { section_index },
+                    aabb,
+                    Mesh3d(meshes.add(mesh)),
+                    MeshMaterial3d(sections.sections[section_index].material.clone()),
+                    Transform::default(),
+                    Visibility::default(),
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ));
+            }
+        });
+
+        // 4. Mark as computed
+        commands.entity(entity).insert(TextMeshSectionsComputed);
+    }
+}
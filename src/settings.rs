@@ -0,0 +1,222 @@
+use crate::component::{GlyphMesh, TextMesh, TextMeshGlyphs};
+use bevy::asset::uuid_handle;
+use bevy::prelude::*;
+use std::fmt;
+use std::sync::Arc;
+
+/// Builds the material of a text or glyph, see [`FontMeshSettings::material_factory`].
+///
+/// Returning `None` keeps the material the text or glyph would get without a factory.
+pub type MaterialFactory = Arc<
+    dyn Fn(&MaterialTarget, &mut Assets<StandardMaterial>) -> Option<Handle<StandardMaterial>>
+        + Send
+        + Sync,
+>;
+
+/// The text or glyph a [`MaterialFactory`] builds a material for.
+#[derive(Clone, Copy, Debug)]
+pub struct MaterialTarget<'a> {
+    /// The entity that gets the material: the text entity, or the glyph child.
+    pub entity: Entity,
+    /// The whole text of the entity, or of the parent of the glyph.
+    pub text: &'a str,
+    /// The glyph, for the children of [`TextMeshGlyphs`]. `None` for whole texts.
+    pub glyph: Option<&'a GlyphMesh>,
+}
+
+/// Plugin-wide materials for text spawned without one.
+///
+/// A [`TextMesh`] or [`TextMeshGlyphs`] without a material, or with the default
+/// `Handle<StandardMaterial>` left by `..default()` on its bundle, would render with
+/// Bevy's placeholder material. Instead, it gets a material from
+/// [`FontMeshSettings::material_factory`], falling back to
+/// [`FontMeshSettings::default_material`]. The factory is also called for every glyph
+/// spawned by [`TextMeshGlyphs`], so per-glyph materials can be derived in one place
+/// instead of in a system of your own.
+///
+/// The plugin inserts the default settings, whose default material is plain white.
+/// Insert your own to change them, or remove the resource to leave materials alone.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup(mut commands: Commands, mut materials: ResMut<Assets<StandardMaterial>>) {
+///     let gold = materials.add(StandardMaterial {
+///         base_color: Color::srgb(1.0, 0.8, 0.2),
+///         metallic: 1.0,
+///         ..default()
+///     });
+///     // Gold text, with red capitals in glyph mode
+///     commands.insert_resource(
+///         FontMeshSettings::new(gold).with_material_factory(|target, materials| {
+///             let glyph = target.glyph?;
+///             glyph
+///                 .character
+///                 .is_uppercase()
+///                 .then(|| materials.add(Color::srgb(0.9, 0.1, 0.1)))
+///         }),
+///     );
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct FontMeshSettings {
+    /// Material of text spawned without one, when the factory doesn't provide one.
+    pub default_material: Handle<StandardMaterial>,
+    /// Optional factory called for every text spawned without a material, and for every
+    /// glyph of a [`TextMeshGlyphs`]. Only called when the app has Bevy's PBR materials.
+    pub material_factory: Option<MaterialFactory>,
+}
+
+impl FontMeshSettings {
+    /// Handle of the plain white material the default settings use.
+    ///
+    /// The plugin adds the material when Bevy's PBR materials are available.
+    pub const DEFAULT_MATERIAL: Handle<StandardMaterial> =
+        uuid_handle!("8f3b6c2e-1d47-4a9e-b5c0-7e2d9f41a863");
+
+    /// Settings that give text without a material `default_material`.
+    pub fn new(default_material: Handle<StandardMaterial>) -> Self {
+        Self {
+            default_material,
+            material_factory: None,
+        }
+    }
+
+    /// Set the [`material_factory`](Self::material_factory).
+    pub fn with_material_factory(
+        mut self,
+        factory: impl Fn(&MaterialTarget, &mut Assets<StandardMaterial>) -> Option<Handle<StandardMaterial>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.material_factory = Some(Arc::new(factory));
+        self
+    }
+
+    /// Material for `target`, asking the factory first.
+    fn material_for(
+        &self,
+        target: &MaterialTarget,
+        materials: Option<&mut Assets<StandardMaterial>>,
+    ) -> Option<Handle<StandardMaterial>> {
+        let factory = self.material_factory.as_ref()?;
+        factory(target, materials?)
+    }
+}
+
+impl Default for FontMeshSettings {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MATERIAL)
+    }
+}
+
+impl fmt::Debug for FontMeshSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FontMeshSettings")
+            .field("default_material", &self.default_material)
+            .field("material_factory", &self.material_factory.is_some())
+            .finish()
+    }
+}
+
+/// Add the [`FontMeshSettings::DEFAULT_MATERIAL`] if the app has PBR materials.
+pub(crate) fn add_default_material(app: &mut App) {
+    let Some(mut materials) = app
+        .world_mut()
+        .get_resource_mut::<Assets<StandardMaterial>>()
+    else {
+        return;
+    };
+    let id = FontMeshSettings::DEFAULT_MATERIAL.id();
+    if !materials.contains(id) {
+        materials
+            .insert(id, StandardMaterial::default())
+            .expect("materials with a UUID handle can always be inserted");
+    }
+}
+
+type MissingMaterialQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static TextMesh>,
+        Option<&'static TextMeshGlyphs>,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+    ),
+    (
+        Or<(With<TextMesh>, With<TextMeshGlyphs>)>,
+        Or<(
+            Added<TextMesh>,
+            Added<TextMeshGlyphs>,
+            Changed<MeshMaterial3d<StandardMaterial>>,
+        )>,
+    ),
+>;
+
+/// System that gives [`TextMesh`] and [`TextMeshGlyphs`] entities without a material
+/// one from [`FontMeshSettings`].
+pub fn apply_default_materials(
+    mut commands: Commands,
+    settings: Res<FontMeshSettings>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    query: MissingMaterialQuery,
+) {
+    for (entity, text_mesh, text_glyphs, material) in query.iter() {
+        if material.is_some_and(|material| material.id() != AssetId::default()) {
+            continue;
+        }
+        let text = match (text_mesh, text_glyphs) {
+            (Some(text_mesh), _) => &text_mesh.text,
+            (None, Some(text_glyphs)) => &text_glyphs.text,
+            (None, None) => continue,
+        };
+
+        let target = MaterialTarget {
+            entity,
+            text,
+            glyph: None,
+        };
+        let material = settings
+            .material_for(&target, materials.as_deref_mut())
+            .unwrap_or_else(|| settings.default_material.clone());
+        commands.entity(entity).insert(MeshMaterial3d(material));
+    }
+}
+
+/// System that gives freshly spawned glyphs of [`TextMeshGlyphs`] their material from
+/// [`FontMeshSettings::material_factory`].
+pub fn apply_glyph_materials(
+    settings: Res<FontMeshSettings>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    texts: Query<&TextMeshGlyphs>,
+    mut glyphs: Query<
+        (
+            Entity,
+            &GlyphMesh,
+            &ChildOf,
+            &mut MeshMaterial3d<StandardMaterial>,
+        ),
+        Added<GlyphMesh>,
+    >,
+) {
+    if settings.material_factory.is_none() {
+        return;
+    }
+    for (entity, glyph, child_of, mut material) in glyphs.iter_mut() {
+        let Ok(text_glyphs) = texts.get(child_of.parent()) else {
+            continue;
+        };
+        let target = MaterialTarget {
+            entity,
+            text: &text_glyphs.text,
+            glyph: Some(glyph),
+        };
+        if let Some(handle) = settings.material_for(&target, materials.as_deref_mut()) {
+            material.0 = handle;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::style::TextMeshStyle;
+use crate::gradient::TextGradient;
+use crate::reload::uses_font;
+use crate::FontMesh;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+/// How a [`TextMesh`] caches its generated mesh.
+///
+/// By default, texts with the same string, font, and style display one shared mesh
+/// asset, and edits only rebuild the changed lines. Text whose mesh is jittered or
+/// deformed per entity after generation needs a mesh of its own instead, so the edits
+/// don't show up on every other copy of the label.
+///
+/// Changing the policy regenerates the mesh.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Wobbly".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshCachePolicy::Unique,
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum TextMeshCachePolicy {
+    /// Share one mesh asset between all texts with the same content, and rebuild only
+    /// the edited lines. The default when the component is missing.
+    #[default]
+    Shared,
+    /// Keep a mesh asset that no other text displays, still rebuilding only the edited
+    /// lines.
+    Unique,
+    /// Keep a mesh asset of its own and generate all of it again on every change,
+    /// without holding on to per-line geometry.
+    NoCache,
+}
+
+/// Mesh assets of [`TextMesh`]es, keyed by what they display.
+///
+/// Texts with the same string, font, style, and colors share one mesh asset instead
+/// of each generating and uploading its own copy. The registry holds a handle to every
+/// mesh it hands out and lets go of it once no text uses the mesh anymore.
+#[derive(Resource, Default)]
+pub(crate) struct SharedTextMeshes {
+    by_text: HashMap<String, Vec<SharedMesh>>,
+}
+
+struct SharedMesh {
+    font: AssetId<FontMesh>,
+    style: TextMeshStyle,
+    char_colors: Option<Vec<Color>>,
+    gradient: Option<TextGradient>,
+    mesh: Handle<Mesh>,
+    bounds: TextMeshBounds,
+}
+
+impl SharedMesh {
+    /// Whether the mesh shows the content of `text_mesh`, given its string matches.
+    fn displays(&self, text_mesh: &TextMesh) -> bool {
+        self.font == text_mesh.font.id()
+            && self.style == text_mesh.style
+            && self.char_colors == text_mesh.char_colors
+            && self.gradient == text_mesh.gradient
+    }
+}
+
+impl SharedTextMeshes {
+    /// The mesh already generated for the content of `text_mesh` and its bounds, if any.
+    pub fn get(&self, text_mesh: &TextMesh) -> Option<(&Handle<Mesh>, &TextMeshBounds)> {
+        self.by_text
+            .get(&text_mesh.text)?
+            .iter()
+            .find(|shared| shared.displays(text_mesh))
+            .map(|shared| (&shared.mesh, &shared.bounds))
+    }
+
+    /// Register `mesh` as the mesh for the content of `text_mesh`.
+    pub fn insert(&mut self, text_mesh: &TextMesh, mesh: Handle<Mesh>, bounds: TextMeshBounds) {
+        let entries = self.by_text.entry(text_mesh.text.clone()).or_default();
+        entries.retain(|shared| !shared.displays(text_mesh));
+        entries.push(SharedMesh {
+            font: text_mesh.font.id(),
+            style: text_mesh.style.clone(),
+            char_colors: text_mesh.char_colors.clone(),
+            gradient: text_mesh.gradient,
+            mesh,
+            bounds,
+        });
+    }
+
+    /// Unregister `mesh` as the mesh for `text`, before its content is edited in place.
+    pub fn remove(&mut self, text: &str, mesh: AssetId<Mesh>) {
+        if let Some(entries) = self.by_text.get_mut(text) {
+            entries.retain(|shared| shared.mesh.id() != mesh);
+            if entries.is_empty() {
+                self.by_text.remove(text);
+            }
+        }
+    }
+
+    /// Unregister every mesh built with one of the `fonts`, after the font assets were
+    /// modified.
+    pub fn forget_fonts(&mut self, fonts: &HashSet<AssetId<FontMesh>>) {
+        self.by_text.retain(|_, entries| {
+            entries.retain(|shared| !uses_font(shared.font, &shared.style, fonts));
+            !entries.is_empty()
+        });
+    }
+
+    /// Whether `mesh` is used by more than one text, so it must not be edited in place.
+    pub fn is_shared(mesh: &Handle<Mesh>) -> bool {
+        // One reference is the text's own, another one the registry's
+        match mesh {
+            Handle::Strong(handle) => std::sync::Arc::strong_count(handle) > 2,
+            Handle::Uuid(..) => true,
+        }
+    }
+
+    /// Drop the meshes that only the registry still refers to.
+    pub fn release_unused(&mut self) {
+        self.by_text.retain(|_, entries| {
+            entries.retain(|shared| match &shared.mesh {
+                Handle::Strong(handle) => std::sync::Arc::strong_count(handle) > 1,
+                Handle::Uuid(..) => false,
+            });
+            !entries.is_empty()
+        });
+    }
+}
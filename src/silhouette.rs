@@ -0,0 +1,671 @@
+use crate::bvh::{box_distance_squared, box_ray_distance, TextMeshBvh};
+use crate::component::{TextMesh, TextMesh2d};
+use crate::core::fallback::FontChain;
+use crate::core::layout::{
+    calculate_line_step, layout_text_in, line_glyphs, paragraph_lines, vertical_extent,
+};
+use crate::core::mesh::{calculate_anchor_offset, line_offset, TextMeshData};
+use crate::core::outline::{fill_boundary, signed_area, GlyphOutline};
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::math::Ray3d;
+use bevy::prelude::*;
+
+/// Opt-in component holding the 2D silhouette of every line of a text.
+///
+/// Add it next to a [`TextMesh`] or [`TextMesh2d`] and the plugin keeps it filled with
+/// the outer boundary of each line: overlapping and touching glyphs are merged, and
+/// counters such as the hole in `o` are dropped. The polygons are in the same local
+/// space as the generated mesh (the front face at `z = 0`, with the anchor applied), so
+/// they can be turned into colliders on the text entity directly, for example to let
+/// characters walk on top of the letters.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "JUMP".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextSilhouette::default(),
+/// ));
+///
+/// fn build_colliders(texts: Query<&TextSilhouette, Changed<TextSilhouette>>) {
+///     for silhouette in &texts {
+///         for polygon in silhouette.polygons() {
+///             // Create a polyline collider from `polygon`...
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct TextSilhouette {
+    /// Silhouette of each line of text, in line order.
+    pub lines: Vec<LineSilhouette>,
+}
+
+impl TextSilhouette {
+    /// All polygons of all lines.
+    pub fn polygons(&self) -> impl Iterator<Item = &Vec<Vec2>> {
+        self.lines.iter().flat_map(|line| &line.polygons)
+    }
+}
+
+/// Outer boundary of the glyphs on a single line of text.
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+pub struct LineSilhouette {
+    /// Closed polygons, wound counter-clockwise. The closing point is not repeated.
+    ///
+    /// Glyphs that don't touch each other produce separate polygons. Empty for lines
+    /// without visible glyphs.
+    pub polygons: Vec<Vec<Vec2>>,
+}
+
+/// Solid shape of a text, for keeping objects on its surface.
+///
+/// Add it next to a [`TextMesh`] or [`TextMesh2d`] and the plugin keeps it in sync with
+/// the text. The shape is the [`TextSilhouette`] extruded from `z = 0` back to
+/// `z = -depth`, matching the generated mesh except for bevels and
+/// [extrusion profiles](crate::ExtrusionProfile), which are ignored.
+/// Counters are filled in, so nothing can get stuck inside an `o`.
+///
+/// Queries go through a [`TextMeshBvh`] over the polygons of the silhouette, so they stay
+/// fast on paragraph-scale text.
+///
+/// The layout also keeps the box of every line in [`TextMeshLayout::lines`], from which
+/// [`TextMeshLayout::stripes_mesh`] builds backgrounds and zebra stripes for table-like
+/// displays and terminals.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// #[derive(Component)]
+/// struct Climber;
+///
+/// // Keep climbers glued to the text they are attached to
+/// fn stick_to_text(
+///     texts: Query<&TextMeshLayout>,
+///     mut climbers: Query<(&mut Transform, &ChildOf), With<Climber>>,
+/// ) {
+///     for (mut transform, child_of) in &mut climbers {
+///         let Ok(layout) = texts.get(child_of.parent()) else {
+///             continue;
+///         };
+///         if let Some((point, normal)) = layout.closest_point_on_text(transform.translation) {
+///             transform.translation = point + normal * 0.05;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct TextMeshLayout {
+    /// Outline of each line of text.
+    pub silhouette: TextSilhouette,
+    /// Extrusion depth of the text. `0.0` for flat text.
+    pub depth: f32,
+    /// Hierarchy over the polygons of `silhouette`, used by every query.
+    ///
+    /// Rebuild it with [`TextMeshBvh::new`] after editing `silhouette` by hand, or use
+    /// [`TextMeshLayout::new`].
+    pub bvh: TextMeshBvh,
+    /// Box of each line of text, in line order, in the same space as `silhouette`.
+    pub lines: Vec<TextLineBox>,
+}
+
+/// Extent of a line of text in a [`TextMeshLayout`], measured with the font's ascender
+/// and descender so lines of different content get boxes of the same height.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextLineBox {
+    /// Height of the baseline, or the X of the center line of a vertical column.
+    pub baseline: f32,
+    /// Where the line starts, or the top of a vertical column.
+    pub start: f32,
+    /// Width of the line, or the height of a vertical column.
+    pub width: f32,
+    /// Distance the line reaches above its baseline.
+    pub ascent: f32,
+    /// Distance the line reaches below its baseline, as a positive number.
+    pub descent: f32,
+    /// Whether the line is a column of vertical text, centered on its baseline.
+    pub vertical: bool,
+}
+
+impl TextLineBox {
+    /// The box of the line, as in [`TextMeshBounds::line_rects`](crate::TextMeshBounds::line_rects).
+    pub fn rect(&self) -> Rect {
+        if self.vertical {
+            let half_width = (self.ascent + self.descent) * 0.5;
+            Rect::new(
+                self.baseline - half_width,
+                self.start - self.width,
+                self.baseline + half_width,
+                self.start,
+            )
+        } else {
+            Rect::new(
+                self.start,
+                self.baseline - self.descent,
+                self.start + self.width,
+                self.baseline + self.ascent,
+            )
+        }
+    }
+}
+
+/// Which lines of a [`TextMeshLayout`] get a quad in [`TextMeshLayout::stripes_mesh`].
+///
+/// The default draws every second line, starting with the first, stretched to the
+/// widest line, like the rows of a striped table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineStripes {
+    /// Draw every `every`-th line. `1` puts a background behind every line.
+    pub every: usize,
+    /// Index of the first line to draw.
+    pub first: usize,
+    /// Space added around each line box, in mesh units.
+    pub padding: Vec2,
+    /// Stretch every quad along its line to cover the longest line.
+    pub full_width: bool,
+    /// Distance of the quads behind the back of the text.
+    pub gap: f32,
+}
+
+impl Default for LineStripes {
+    fn default() -> Self {
+        Self {
+            every: 2,
+            first: 0,
+            padding: Vec2::ZERO,
+            full_width: true,
+            gap: 0.01,
+        }
+    }
+}
+
+/// Where a ray hits the text, found by [`TextMeshLayout::ray_cast`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextMeshRayHit {
+    /// Distance along the ray to the hit.
+    pub distance: f32,
+    /// Hit point, in the local space of the text entity.
+    pub point: Vec3,
+    /// Outward surface normal at the hit point.
+    pub normal: Vec3,
+    /// Line of text that was hit.
+    pub line: usize,
+}
+
+impl TextMeshLayout {
+    /// Layout of `silhouette` extruded to `depth`, building its [`TextMeshBvh`].
+    pub fn new(silhouette: TextSilhouette, depth: f32) -> Self {
+        Self {
+            bvh: TextMeshBvh::new(&silhouette),
+            silhouette,
+            depth,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Flat quads facing the front behind the lines picked by `stripes`, to spawn as a
+    /// background with the text entity as parent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_fontmesh::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Stripes;
+    /// fn add_stripes(
+    ///     mut commands: Commands,
+    ///     mut meshes: ResMut<Assets<Mesh>>,
+    ///     mut materials: ResMut<Assets<StandardMaterial>>,
+    ///     texts: Query<(Entity, &TextMeshLayout), Changed<TextMeshLayout>>,
+    /// ) {
+    ///     for (entity, layout) in texts.iter() {
+    ///         commands.entity(entity).with_child((
+    ///             Stripes,
+    ///             Mesh3d(meshes.add(layout.stripes_mesh(&LineStripes::default()))),
+    ///             MeshMaterial3d(materials.add(Color::srgb(0.15, 0.15, 0.2))),
+    ///         ));
+    ///     }
+    /// }
+    /// ```
+    pub fn stripes_mesh(&self, stripes: &LineStripes) -> Mesh {
+        // The longest line, along the direction lines run in
+        let (low, high) = self
+            .lines
+            .iter()
+            .map(|line| {
+                let rect = line.rect();
+                if line.vertical {
+                    (rect.min.y, rect.max.y)
+                } else {
+                    (rect.min.x, rect.max.x)
+                }
+            })
+            .fold((f32::MAX, f32::MIN), |(low, high), (min, max)| {
+                (low.min(min), high.max(max))
+            });
+
+        let z = -self.depth - stripes.gap;
+        let mut data = TextMeshData::default();
+        let picked = self
+            .lines
+            .iter()
+            .skip(stripes.first)
+            .step_by(stripes.every.max(1));
+        for line in picked {
+            let mut rect = line.rect();
+            if stripes.full_width && line.vertical {
+                (rect.min.y, rect.max.y) = (low, high);
+            } else if stripes.full_width {
+                (rect.min.x, rect.max.x) = (low, high);
+            }
+            let (min, max) = (rect.min - stripes.padding, rect.max + stripes.padding);
+
+            let first = data.vertices.len() as u32;
+            for corner in [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)] {
+                data.vertices.push([corner.x, corner.y, z]);
+                data.normals.push([0.0, 0.0, 1.0]);
+            }
+            data.indices
+                .extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+        }
+        data.into_mesh()
+    }
+
+    /// The point on the text surface closest to `point`, and the outward surface
+    /// normal there.
+    ///
+    /// Both `point` and the result are in the local space of the text entity. Points
+    /// inside the text are moved out to the nearest surface. Flat text (a depth of
+    /// `0.0`, as for [`TextMesh2d`]) has no front or back face to stand on, so its surface
+    /// is the outline: the result always lies on an edge, at `z = 0`, with a normal in
+    /// the XY plane. Returns `None` if the text has no visible glyphs.
+    pub fn closest_point_on_text(&self, point: Vec3) -> Option<(Vec3, Vec3)> {
+        let mut best: Option<(f32, Vec3, Vec3)> = None;
+        self.bvh.search(
+            f32::INFINITY,
+            |bounds| Some(box_distance_squared(bounds, self.depth, point)),
+            |line, polygon| {
+                let candidate =
+                    closest_on_polygon(self.polygon(line, polygon)?, self.depth, point)?;
+                if best.is_none_or(|(distance, ..)| candidate.0 < distance) {
+                    best = Some(candidate);
+                }
+                Some(candidate.0)
+            },
+        );
+        best.map(|(_, point, normal)| (point, normal))
+    }
+
+    /// The first point where `ray` hits the text.
+    ///
+    /// The ray is in the local space of the text entity. Extruded text is hit on its
+    /// front and back faces and its side walls; flat text is hit on its face from either
+    /// side, with the normal facing the ray.
+    pub fn ray_cast(&self, ray: Ray3d) -> Option<TextMeshRayHit> {
+        let mut best: Option<TextMeshRayHit> = None;
+        self.bvh.search(
+            f32::INFINITY,
+            |bounds| box_ray_distance(bounds, self.depth, ray),
+            |line, polygon| {
+                let (distance, normal) =
+                    ray_cast_polygon(self.polygon(line, polygon)?, self.depth, ray)?;
+                if best.is_none_or(|hit| distance < hit.distance) {
+                    best = Some(TextMeshRayHit {
+                        distance,
+                        point: ray.get_point(distance),
+                        normal,
+                        line,
+                    });
+                }
+                Some(distance)
+            },
+        );
+        best
+    }
+
+    /// The line of text whose outline contains `point`, for hit-testing in the XY plane.
+    ///
+    /// `point` is in the local space of the text entity. Returns `None` between glyphs
+    /// and inside counters.
+    pub fn line_at(&self, point: Vec2) -> Option<usize> {
+        let mut found = None;
+        self.bvh.search(
+            f32::INFINITY,
+            |bounds| bounds.contains(point).then_some(0.0),
+            |line, polygon| {
+                if found.is_none()
+                    && self
+                        .polygon(line, polygon)
+                        .is_some_and(|p| contains(p, point))
+                {
+                    found = Some(line);
+                }
+                None
+            },
+        );
+        found
+    }
+
+    fn polygon(&self, line: usize, polygon: usize) -> Option<&[Vec2]> {
+        self.silhouette
+            .lines
+            .get(line)?
+            .polygons
+            .get(polygon)
+            .map(Vec::as_slice)
+    }
+}
+
+/// The point on the extruded `polygon` closest to `point`, as the squared distance to
+/// it, the point itself and the outward normal there.
+fn closest_on_polygon(polygon: &[Vec2], depth: f32, point: Vec3) -> Option<(f32, Vec3, Vec3)> {
+    let flat = point.truncate();
+    let z = point.z.clamp(-depth, 0.0);
+    let inside = contains(polygon, flat);
+
+    let mut best: Option<(f32, Vec3, Vec3)> = None;
+    let mut consider = |candidate: Vec3, normal: Vec3| {
+        let distance = candidate.distance_squared(point);
+        if best.is_none_or(|(best_distance, ..)| distance < best_distance) {
+            best = Some((distance, candidate, normal));
+        }
+    };
+
+    // Side walls
+    for (i, &a) in polygon.iter().enumerate() {
+        let edge = polygon[(i + 1) % polygon.len()] - a;
+        if edge.length_squared() <= f32::EPSILON {
+            continue;
+        }
+
+        let t = ((flat - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0);
+        let closest = a + edge * t;
+        // Polygons wind counter-clockwise, so the outward normal is on the right
+        let edge_normal = Vec2::new(edge.y, -edge.x).normalize();
+        let normal = if t > 0.0 && t < 1.0 {
+            edge_normal
+        } else {
+            // At a corner, point from the corner towards the query point
+            let away = (flat - closest).normalize_or(edge_normal);
+            if inside {
+                -away
+            } else {
+                away
+            }
+        };
+        consider(closest.extend(z), normal.extend(0.0));
+    }
+
+    // Front and back caps
+    if inside && depth > 0.0 {
+        consider(flat.extend(0.0), Vec3::Z);
+        consider(flat.extend(-depth), Vec3::NEG_Z);
+    }
+
+    best
+}
+
+/// The distance along `ray` to the extruded `polygon`, and the outward normal there.
+fn ray_cast_polygon(polygon: &[Vec2], depth: f32, ray: Ray3d) -> Option<(f32, Vec3)> {
+    let mut best: Option<(f32, Vec3)> = None;
+    let mut consider = |distance: f32, normal: Vec3| {
+        if distance >= 0.0 && best.is_none_or(|(best_distance, _)| distance < best_distance) {
+            best = Some((distance, normal));
+        }
+    };
+
+    // Front and back caps, or the single face of flat text
+    if ray.direction.z != 0.0 {
+        for (z, normal) in [(0.0, Vec3::Z), (-depth, Vec3::NEG_Z)] {
+            let distance = (z - ray.origin.z) / ray.direction.z;
+            if contains(polygon, ray.get_point(distance).truncate()) {
+                let normal = if depth > 0.0 {
+                    normal
+                } else {
+                    Vec3::Z * -ray.direction.z.signum()
+                };
+                consider(distance, normal);
+            }
+        }
+    }
+
+    // Side walls
+    let origin = ray.origin.truncate();
+    let direction = ray.direction.truncate();
+    if depth > 0.0 && direction != Vec2::ZERO {
+        for (i, &a) in polygon.iter().enumerate() {
+            let edge = polygon[(i + 1) % polygon.len()] - a;
+            let denominator = direction.perp_dot(edge);
+            if denominator.abs() <= f32::EPSILON {
+                continue;
+            }
+            let distance = (a - origin).perp_dot(edge) / denominator;
+            let along = (a - origin).perp_dot(direction) / denominator;
+            let z = ray.origin.z + ray.direction.z * distance;
+            if (0.0..=1.0).contains(&along) && (-depth..=0.0).contains(&z) {
+                consider(distance, Vec2::new(edge.y, -edge.x).normalize().extend(0.0));
+            }
+        }
+    }
+
+    best
+}
+
+/// Whether `point` lies inside the closed `polygon` (even-odd rule).
+fn contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Lay out every line of `text` and compute its silhouette.
+pub(crate) fn build_text_silhouette(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> TextSilhouette {
+    trace_text(text, fonts, style).0
+}
+
+/// Lay out every line of `text` and compute its silhouette and line boxes, extruded to
+/// `depth`.
+fn build_text_layout(
+    text: &str,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+    depth: f32,
+) -> TextMeshLayout {
+    let (silhouette, anchor_offset) = trace_text(text, fonts, style);
+    let (ascent, descent) = vertical_extent(fonts, style);
+    let descent = -descent;
+    let vertical = style.orientation.is_vertical();
+    let lines = layout_text_in(fonts, text, style)
+        .lines
+        .iter()
+        .map(|line| {
+            let (baseline, start) = if vertical {
+                (
+                    line.baseline + anchor_offset.x,
+                    line.start + anchor_offset.y,
+                )
+            } else {
+                (
+                    line.baseline + anchor_offset.y,
+                    line.start + anchor_offset.x,
+                )
+            };
+            TextLineBox {
+                baseline,
+                start,
+                width: line.width,
+                ascent,
+                descent,
+                vertical,
+            }
+        })
+        .collect();
+    TextMeshLayout {
+        lines,
+        ..TextMeshLayout::new(silhouette, depth)
+    }
+}
+
+/// The silhouette of `text`, and the offset its anchor moved it by.
+fn trace_text(text: &str, fonts: &FontChain, style: &TextMeshStyle) -> (TextSilhouette, Vec2) {
+    let line_step = calculate_line_step(fonts, style);
+    let mut lines: Vec<LineSilhouette> = paragraph_lines(text)
+        .enumerate()
+        .map(|(line_index, (line, ends_paragraph))| {
+            let offset = line_offset(line_index, line_step).truncate();
+            let mut silhouette = line_silhouette(line, ends_paragraph, fonts, style);
+            for point in silhouette.polygons.iter_mut().flatten() {
+                *point += offset;
+            }
+            silhouette
+        })
+        .collect();
+
+    // Anchor like the mesh, using the bounds of the silhouette itself
+    let bounds = lines
+        .iter()
+        .flat_map(|line| line.polygons.iter().flatten())
+        .fold(None, |bounds: Option<(Vec2, Vec2)>, &point| {
+            Some(bounds.map_or((point, point), |(min, max)| {
+                (min.min(point), max.max(point))
+            }))
+        });
+    let anchor_offset = bounds.map_or(Vec2::ZERO, |(min, max)| {
+        calculate_anchor_offset(style.anchor, min.extend(0.0), max.extend(0.0)).truncate()
+    });
+    for point in lines
+        .iter_mut()
+        .flat_map(|line| line.polygons.iter_mut().flatten())
+    {
+        *point += anchor_offset;
+    }
+
+    (TextSilhouette { lines }, anchor_offset)
+}
+
+/// Merge the outlines of a single line of text into its outer boundary.
+fn line_silhouette(
+    line: &str,
+    ends_paragraph: bool,
+    fonts: &FontChain,
+    style: &TextMeshStyle,
+) -> LineSilhouette {
+    let mut outline = GlyphOutline::default();
+    for line_glyph in line_glyphs(line, ends_paragraph, fonts, style) {
+        let (face, origin) = (line_glyph.face, Vec2::new(line_glyph.x, line_glyph.y));
+        let Some(glyph) = GlyphOutline::for_style(face, line_glyph.source, style) else {
+            continue;
+        };
+
+        outline
+            .contours
+            .extend(glyph.contours.into_iter().map(|contour| {
+                contour
+                    .into_iter()
+                    .map(|point| point + origin)
+                    .collect::<Vec<_>>()
+            }));
+    }
+
+    // Outer boundaries wind counter-clockwise, counters the other way
+    let polygons = fill_boundary(&outline)
+        .into_iter()
+        .filter(|polygon| signed_area(polygon) > 0.0)
+        .collect();
+    LineSilhouette { polygons }
+}
+
+/// Marker component indicating that a [`TextSilhouette`] or [`TextMeshLayout`] has been
+/// computed.
+#[derive(Component)]
+pub struct TextSilhouetteComputed;
+
+type TextSilhouetteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static TextMesh>,
+        Option<&'static TextMesh2d>,
+        Option<&'static mut TextSilhouette>,
+        Option<&'static mut TextMeshLayout>,
+    ),
+    (
+        Or<(With<TextSilhouette>, With<TextMeshLayout>)>,
+        Or<(
+            Changed<TextMesh>,
+            Changed<TextMesh2d>,
+            Added<TextSilhouette>,
+            Added<TextMeshLayout>,
+            Without<TextSilhouetteComputed>,
+        )>,
+    ),
+>;
+
+/// System that computes the [`TextSilhouette`] and [`TextMeshLayout`] of [`TextMesh`] and
+/// [`TextMesh2d`] entities.
+pub fn update_text_silhouettes(
+    mut commands: Commands,
+    font_assets: Res<Assets<FontMesh>>,
+    mut query: TextSilhouetteQuery,
+) {
+    for (entity, text_mesh, text_mesh_2d, silhouette, layout) in query.iter_mut() {
+        let (text, font, style, depth) = match (text_mesh, text_mesh_2d) {
+            (Some(text_mesh), _) => (
+                &text_mesh.text,
+                &text_mesh.font,
+                &text_mesh.style,
+                text_mesh.style.depth.max(0.0),
+            ),
+            (None, Some(text_mesh)) => (&text_mesh.text, &text_mesh.font, &text_mesh.style, 0.0),
+            (None, None) => continue,
+        };
+
+        // 1. Parse the font and its fallbacks
+        let Some(fonts) = FontChain::for_style(&font_assets, font, style) else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
+
+        // 2. Trace the outline of every line and store it in the requested components
+        if let Some(mut layout) = layout {
+            *layout = build_text_layout(text, &fonts, style, depth);
+            if let Some(mut silhouette) = silhouette {
+                *silhouette = layout.silhouette.clone();
+            }
+        } else if let Some(mut silhouette) = silhouette {
+            *silhouette = build_text_silhouette(text, &fonts, style);
+        }
+
+        // 3. Mark as computed
+        commands.entity(entity).insert(TextSilhouetteComputed);
+    }
+}
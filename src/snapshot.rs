@@ -0,0 +1,334 @@
+use bevy::mesh::{Indices, VertexAttributeValues};
+use bevy::prelude::*;
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+/// First line of every snapshot, identifying the format and its version.
+const HEADER: &str = "bevy_fontmesh mesh snapshot v1";
+
+/// Decimal places written for every coordinate.
+const PRECISION: usize = 5;
+
+/// Environment variable that makes [`assert_mesh_snapshot`] rewrite its goldens.
+const UPDATE_SNAPSHOTS_VAR: &str = "UPDATE_MESH_SNAPSHOTS";
+
+/// Geometry of a mesh in a stable text format, for golden tests.
+///
+/// A snapshot keeps the positions, normals, texture coordinates, and triangle indices
+/// of a mesh. Written with [`Display`](fmt::Display), it lists one vertex or triangle
+/// per line with coordinates rounded to five decimals, so goldens diff cleanly in
+/// version control. [`MeshSnapshot::compare`] checks a mesh against a golden with a
+/// tolerance, so tiny floating point differences between platforms or compiler
+/// versions don't fail a test, while any change to the topology does.
+///
+/// Most tests only need [`assert_mesh_snapshot`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshSnapshot {
+    /// Vertex positions.
+    pub positions: Vec<Vec3>,
+    /// Vertex normals, empty if the mesh has none.
+    pub normals: Vec<Vec3>,
+    /// Texture coordinates, empty if the mesh has none.
+    pub uvs: Vec<Vec2>,
+    /// Triangle indices, or `0..n` for meshes without an index buffer.
+    pub indices: Vec<u32>,
+}
+
+/// A difference between a mesh and its golden, found by [`MeshSnapshot::compare`].
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum MeshSnapshotMismatch {
+    /// The meshes have a different number of vertices.
+    #[error("expected {expected} vertices, got {actual}")]
+    VertexCount { expected: usize, actual: usize },
+    /// One mesh has normals or texture coordinates and the other doesn't.
+    #[error("{attribute} are present in only one of the meshes")]
+    MissingAttribute { attribute: &'static str },
+    /// The triangles differ.
+    #[error("triangle indices differ, first at index {index}")]
+    Indices { index: usize },
+    /// A vertex attribute differs by more than the tolerance.
+    #[error("{attribute} of vertex {vertex} differ: expected {expected:?}, got {actual:?}")]
+    Vertex {
+        attribute: &'static str,
+        vertex: usize,
+        expected: Vec<f32>,
+        actual: Vec<f32>,
+    },
+}
+
+/// An error reading a [`MeshSnapshot`] from text.
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("invalid mesh snapshot at line {line}: {message}")]
+pub struct MeshSnapshotParseError {
+    /// Line of the error, starting at `1`.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub message: String,
+}
+
+impl MeshSnapshot {
+    /// Take a snapshot of a mesh.
+    ///
+    /// Only `Float32x3` positions and normals and `Float32x2` texture coordinates are
+    /// recorded; other attributes are ignored.
+    pub fn from_mesh(mesh: &Mesh) -> Self {
+        let float3 = |attribute| match mesh.attribute(attribute) {
+            Some(VertexAttributeValues::Float32x3(values)) => {
+                values.iter().map(|&v| Vec3::from_array(v)).collect()
+            }
+            _ => Vec::new(),
+        };
+        let positions: Vec<Vec3> = float3(Mesh::ATTRIBUTE_POSITION);
+        let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(values)) => {
+                values.iter().map(|&v| Vec2::from_array(v)).collect()
+            }
+            _ => Vec::new(),
+        };
+        let indices = match mesh.indices() {
+            Some(Indices::U32(indices)) => indices.clone(),
+            Some(Indices::U16(indices)) => indices.iter().map(|&i| i as u32).collect(),
+            None => (0..positions.len() as u32).collect(),
+        };
+
+        Self {
+            normals: float3(Mesh::ATTRIBUTE_NORMAL),
+            positions,
+            uvs,
+            indices,
+        }
+    }
+
+    /// Read a snapshot written with [`Display`](fmt::Display).
+    pub fn parse(text: &str) -> Result<Self, MeshSnapshotParseError> {
+        let mut snapshot = Self::default();
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, line)) if line.trim() == HEADER => {}
+            _ => return Err(parse_error(0, format!("expected \"{HEADER}\""))),
+        }
+
+        for (index, line) in lines {
+            let mut fields = line.split_whitespace();
+            let Some(kind) = fields.next() else {
+                continue;
+            };
+            let values: Vec<&str> = fields.collect();
+            match kind {
+                "p" => snapshot
+                    .positions
+                    .push(Vec3::from_array(numbers(index, &values)?)),
+                "n" => snapshot
+                    .normals
+                    .push(Vec3::from_array(numbers(index, &values)?)),
+                "uv" => snapshot
+                    .uvs
+                    .push(Vec2::from_array(numbers(index, &values)?)),
+                "t" => snapshot.indices.extend(numbers::<u32, 3>(index, &values)?),
+                _ => return Err(parse_error(index, format!("unknown record {kind:?}"))),
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Compare this snapshot with a `golden` one.
+    ///
+    /// Passes if both have the same triangles and every position, normal, and texture
+    /// coordinate is within `tolerance` of the golden on each axis. Returns the first
+    /// difference otherwise.
+    pub fn compare(&self, golden: &Self, tolerance: f32) -> Result<(), MeshSnapshotMismatch> {
+        if self.positions.len() != golden.positions.len() {
+            return Err(MeshSnapshotMismatch::VertexCount {
+                expected: golden.positions.len(),
+                actual: self.positions.len(),
+            });
+        }
+        for (attribute, actual, expected) in [
+            (
+                "normals",
+                self.normals.is_empty(),
+                golden.normals.is_empty(),
+            ),
+            (
+                "texture coordinates",
+                self.uvs.is_empty(),
+                golden.uvs.is_empty(),
+            ),
+        ] {
+            if actual != expected {
+                return Err(MeshSnapshotMismatch::MissingAttribute { attribute });
+            }
+        }
+        if self.indices != golden.indices {
+            let index = self
+                .indices
+                .iter()
+                .zip(&golden.indices)
+                .position(|(a, b)| a != b)
+                .unwrap_or(self.indices.len().min(golden.indices.len()));
+            return Err(MeshSnapshotMismatch::Indices { index });
+        }
+
+        let within =
+            |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() <= tolerance);
+        let attributes = [
+            (
+                "positions",
+                flatten3(&self.positions),
+                flatten3(&golden.positions),
+                3,
+            ),
+            (
+                "normals",
+                flatten3(&self.normals),
+                flatten3(&golden.normals),
+                3,
+            ),
+            (
+                "texture coordinates",
+                flatten2(&self.uvs),
+                flatten2(&golden.uvs),
+                2,
+            ),
+        ];
+        for (attribute, actual, expected, width) in attributes {
+            for (vertex, (a, b)) in actual.chunks(width).zip(expected.chunks(width)).enumerate() {
+                if !within(a, b) {
+                    return Err(MeshSnapshotMismatch::Vertex {
+                        attribute,
+                        vertex,
+                        expected: b.to_vec(),
+                        actual: a.to_vec(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MeshSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for position in &self.positions {
+            writeln!(f, "p {}", coordinates(&position.to_array()))?;
+        }
+        for normal in &self.normals {
+            writeln!(f, "n {}", coordinates(&normal.to_array()))?;
+        }
+        for uv in &self.uvs {
+            writeln!(f, "uv {}", coordinates(&uv.to_array()))?;
+        }
+        for triangle in self.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| triangle.get(i).copied().unwrap_or_default());
+            writeln!(f, "t {a} {b} {c}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check `mesh` against the golden snapshot stored at `path`.
+///
+/// If the file doesn't exist yet, or the `UPDATE_MESH_SNAPSHOTS` environment variable
+/// is set, the snapshot of `mesh` is written to `path` instead, creating missing
+/// directories. Review and commit the written goldens with the test.
+///
+/// # Panics
+///
+/// Panics if the mesh differs from the golden by more than `tolerance` (see
+/// [`MeshSnapshot::compare`]), or if the golden can't be read or written.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::assert_mesh_snapshot;
+/// # fn example(mesh: &Mesh) {
+/// assert_mesh_snapshot(mesh, "tests/snapshots/title.mesh", 1e-4);
+/// # }
+/// ```
+#[track_caller]
+pub fn assert_mesh_snapshot(mesh: &Mesh, path: impl AsRef<Path>, tolerance: f32) {
+    let path = path.as_ref();
+    let snapshot = MeshSnapshot::from_mesh(mesh);
+
+    if !path.exists() || std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|error| panic!("could not create {}: {error}", parent.display()));
+        }
+        std::fs::write(path, snapshot.to_string())
+            .unwrap_or_else(|error| panic!("could not write {}: {error}", path.display()));
+        return;
+    }
+
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()));
+    let golden =
+        MeshSnapshot::parse(&text).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    if let Err(mismatch) = snapshot.compare(&golden, tolerance) {
+        panic!(
+            "mesh differs from snapshot {}: {mismatch}\n\
+             Rerun with {UPDATE_SNAPSHOTS_VAR}=1 to accept the new mesh.",
+            path.display()
+        );
+    }
+}
+
+fn parse_error(index: usize, message: String) -> MeshSnapshotParseError {
+    MeshSnapshotParseError {
+        line: index + 1,
+        message,
+    }
+}
+
+/// Parse exactly `N` numbers from the fields of a record.
+fn numbers<T: std::str::FromStr, const N: usize>(
+    index: usize,
+    values: &[&str],
+) -> Result<[T; N], MeshSnapshotParseError> {
+    if values.len() != N {
+        return Err(parse_error(
+            index,
+            format!("expected {N} values, got {}", values.len()),
+        ));
+    }
+    let parsed: Vec<T> = values
+        .iter()
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| parse_error(index, format!("invalid number {value:?}")))
+        })
+        .collect::<Result<_, _>>()?;
+    parsed
+        .try_into()
+        .map_err(|_| parse_error(index, "wrong number of values".to_string()))
+}
+
+/// Coordinates rounded to the snapshot precision, without negative zeros.
+fn coordinates(values: &[f32]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            let text = format!("{value:.PRECISION$}");
+            match text.strip_prefix('-') {
+                Some(magnitude) if magnitude.bytes().all(|b| b == b'0' || b == b'.') => {
+                    magnitude.to_string()
+                }
+                _ => text,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn flatten3(values: &[Vec3]) -> Vec<f32> {
+    values.iter().flat_map(|v| v.to_array()).collect()
+}
+
+fn flatten2(values: &[Vec2]) -> Vec<f32> {
+    values.iter().flat_map(|v| v.to_array()).collect()
+}
@@ -0,0 +1,246 @@
+use crate::core::style::TextMeshStyle;
+use crate::core::stylistic_set::StylisticSet;
+use crate::sections::{TextMeshSection, TextMeshSections};
+use crate::settings::FontMeshSettings;
+use crate::FontMesh;
+use bevy::prelude::*;
+
+/// Root of a 3D text built from child entities, mirroring Bevy's `Text` and `TextSpan`.
+///
+/// The root holds the first run of text, and every [`TextSpan3d`] below it adds another
+/// run in depth-first order, so labels written for `bevy_text` move into 3D by renaming
+/// their components. Each run takes its font, size, and depth from the [`TextFont3d`]
+/// and its material from the `MeshMaterial3d<StandardMaterial>` on its own entity or the
+/// nearest span or root above it, the way `TextFont` and `TextColor` are inherited.
+/// Layout settings shared by all runs come from a [`Text3dStyle`] on the root.
+///
+/// The runs are turned into a [`TextMeshSections`] on the root, which generates the
+/// meshes; roots without a material use [`FontMeshSettings::default_material`].
+/// Editing a span, adding one, or despawning one updates the text.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// commands
+///     .spawn((
+///         Text3d::new("Score: "),
+///         TextFont3d {
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         MeshMaterial3d(materials.add(Color::WHITE)),
+///         Transform::default(),
+///         Visibility::default(),
+///     ))
+///     .with_child((
+///         TextSpan3d::new("9000"),
+///         TextFont3d {
+///             font: asset_server.load("fonts/bold.ttf"),
+///             scale: 1.5,
+///             ..default()
+///         },
+///         MeshMaterial3d(materials.add(Color::srgb(1.0, 0.8, 0.2))),
+///     ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Text3d(pub String);
+
+impl Text3d {
+    /// A root with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self(text.into())
+    }
+}
+
+/// A run of text below a [`Text3d`], mirroring Bevy's `TextSpan`.
+///
+/// Spans can be nested; their text follows the root's in depth-first order. Entities
+/// without a `TextSpan3d` end the run of spans below them.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct TextSpan3d(pub String);
+
+impl TextSpan3d {
+    /// A span with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self(text.into())
+    }
+}
+
+/// Font of a [`Text3d`] or [`TextSpan3d`], mirroring Bevy's `TextFont`.
+///
+/// Spans without one use the font of the nearest span or root above them.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct TextFont3d {
+    /// Handle to the font asset (TTF or OTF file).
+    pub font: Handle<FontMesh>,
+    /// Size relative to the root's style, `1.0` being one unit per em. Must be positive.
+    pub scale: f32,
+    /// Extrusion depth, or `None` to use the depth of the root's [`Text3dStyle`].
+    pub depth: Option<f32>,
+    /// Stylistic sets, or `None` to use the sets of the root's [`Text3dStyle`].
+    pub stylistic_sets: Option<Vec<StylisticSet>>,
+    /// Tabular figures, or `None` to follow the root's [`Text3dStyle`].
+    pub tabular_figures: Option<bool>,
+}
+
+impl Default for TextFont3d {
+    fn default() -> Self {
+        Self {
+            font: Handle::default(),
+            scale: 1.0,
+            depth: None,
+            stylistic_sets: None,
+            tabular_figures: None,
+        }
+    }
+}
+
+impl TextFont3d {
+    /// The section for a run of `text` in this font.
+    fn section(&self, text: &str, material: &Handle<StandardMaterial>) -> TextMeshSection {
+        TextMeshSection {
+            depth: self.depth,
+            stylistic_sets: self.stylistic_sets.clone(),
+            tabular_figures: self.tabular_figures,
+            scale: self.scale,
+            ..TextMeshSection::new(text, self.font.clone(), material.clone())
+        }
+    }
+}
+
+/// Layout and tessellation settings of a [`Text3d`] and all of its spans.
+///
+/// Plays the part of Bevy's `TextLayout`. Roots without one use the default style.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct Text3dStyle(pub TextMeshStyle);
+
+type Text3dQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, Text3d>,
+        Option<Ref<'static, TextFont3d>>,
+        Option<Ref<'static, Text3dStyle>>,
+        Option<Ref<'static, MeshMaterial3d<StandardMaterial>>>,
+        Option<Ref<'static, Children>>,
+        Option<&'static TextMeshSections>,
+    ),
+>;
+
+type TextSpan3dQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, TextSpan3d>,
+        Option<Ref<'static, TextFont3d>>,
+        Option<Ref<'static, MeshMaterial3d<StandardMaterial>>>,
+        Option<Ref<'static, Children>>,
+    ),
+>;
+
+/// Whether any span below `children`, or the list of spans itself, has changed.
+fn spans_changed(children: Option<&Ref<Children>>, spans: &TextSpan3dQuery) -> bool {
+    let Some(children) = children else {
+        return false;
+    };
+    children.is_changed()
+        || spans
+            .iter_many(children.iter())
+            .any(|(span, font, material, children)| {
+                span.is_changed()
+                    || font.is_some_and(|font| font.is_changed())
+                    || material.is_some_and(|material| material.is_changed())
+                    || spans_changed(children.as_ref(), spans)
+            })
+}
+
+/// Append a section for every span below `children`, depth-first.
+fn collect_spans(
+    children: Option<&Children>,
+    font: &TextFont3d,
+    material: &Handle<StandardMaterial>,
+    spans: &TextSpan3dQuery,
+    sections: &mut Vec<TextMeshSection>,
+) {
+    let Some(children) = children else {
+        return;
+    };
+    for (span, span_font, span_material, span_children) in spans.iter_many(children.iter()) {
+        let font = span_font.as_deref().unwrap_or(font);
+        let material = span_material
+            .as_ref()
+            .map_or(material, |material| &material.0);
+        sections.push(font.section(&span.0, material));
+        collect_spans(span_children.as_deref(), font, material, spans, sections);
+    }
+}
+
+/// System that turns [`Text3d`] roots and their [`TextSpan3d`] descendants into
+/// [`TextMeshSections`].
+///
+/// Roots are only rebuilt when one of their components, a span, or the hierarchy below
+/// them changed, and the sections are only written when they differ, so the meshes are
+/// not regenerated for unrelated changes.
+pub fn update_text_spans(
+    mut commands: Commands,
+    settings: Option<Res<FontMeshSettings>>,
+    roots: Text3dQuery,
+    spans: TextSpan3dQuery,
+    mut removed_fonts: RemovedComponents<TextFont3d>,
+    mut removed_materials: RemovedComponents<MeshMaterial3d<StandardMaterial>>,
+    mut removed_styles: RemovedComponents<Text3dStyle>,
+) {
+    // Inherited values can't be traced back to the root of a removed component cheaply
+    let removed = removed_fonts.read().count()
+        + removed_materials.read().count()
+        + removed_styles.read().count()
+        > 0;
+    let default_material = settings
+        .map(|settings| settings.default_material.clone())
+        .unwrap_or_default();
+
+    for (entity, text, font, style, material, children, existing) in roots.iter() {
+        let changed = removed
+            || existing.is_none()
+            || text.is_changed()
+            || font.as_ref().is_some_and(|font| font.is_changed())
+            || style.as_ref().is_some_and(|style| style.is_changed())
+            || material
+                .as_ref()
+                .is_some_and(|material| material.is_changed())
+            || spans_changed(children.as_ref(), &spans);
+        if !changed {
+            continue;
+        }
+
+        let font = font.as_deref().cloned().unwrap_or_default();
+        let material = material.map_or(default_material.clone(), |material| material.0.clone());
+        let mut sections = vec![font.section(&text.0, &material)];
+        collect_spans(children.as_deref(), &font, &material, &spans, &mut sections);
+        let style = style.map(|style| style.0.clone()).unwrap_or_default();
+
+        // Only touch the sections when something differs, to avoid regenerating
+        if existing.is_some_and(|existing| existing.sections == sections && existing.style == style)
+        {
+            continue;
+        }
+        let mut root = commands.entity(entity);
+        root.insert(TextMeshSections { sections, style });
+        if existing.is_none() {
+            root.insert_if_new((Transform::default(), Visibility::default()));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Text workloads for benchmarking mesh generation on your own hardware.
+//!
+//! A [`StressScene`] describes a number of labels of a given length and style, and
+//! either spawns them as ordinary [`TextMesh`] entities for a real app to render, or
+//! generates them and reports how long it took in [`StressStats`]:
+//!
+//! - [`StressScene::measure`] builds every label with the [`core`](crate::core)
+//!   functions alone, timing tessellation without the ECS.
+//! - [`StressScene::run`] spawns the labels into an [`App`] with the [`FontMeshPlugin`]
+//!   and times the frames until every label has its mesh, the cost a game pays.
+//!
+//! The labels cycle through letters and digits, shifted for every label, so that
+//! caches of identical text don't hide the work.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use bevy_fontmesh::core::FontMesh;
+//! use bevy_fontmesh::stress::StressScene;
+//!
+//! let font = FontMesh {
+//!     data: std::fs::read("assets/fonts/font.ttf").unwrap().into(),
+//!     face_index: 0,
+//! };
+//! for subdivision in [5, 10, 20] {
+//!     let stats = StressScene::new(100, 16)
+//!         .subdivision(subdivision)
+//!         .measure(&font);
+//!     println!(
+//!         "subdivision {subdivision}: {:?} per label, {} triangles",
+//!         stats.per_label(),
+//!         stats.triangles,
+//!     );
+//! }
+//! ```
+//!
+//! [`FontMeshPlugin`]: crate::FontMeshPlugin
+
+use crate::component::{TextMesh, TextMeshBundle};
+use crate::core::{build_text_mesh, TextMeshStyle};
+use crate::FontMesh;
+use bevy::mesh::Indices;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Characters the labels are made of, all with geometry in common fonts.
+const GLYPHS: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l',
+    'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '0', '1', '2', '3', '4',
+    '5', '6', '7', '8', '9',
+];
+
+/// A workload of `labels` texts with `glyphs_per_label` glyphs each.
+///
+/// Labels are laid out in a square grid, [`spacing`](Self::spacing) apart, so a camera
+/// can frame them all.
+#[derive(Clone, Debug)]
+pub struct StressScene {
+    /// Number of texts.
+    pub labels: usize,
+    /// Number of glyphs in every text.
+    pub glyphs_per_label: usize,
+    /// Style of every text.
+    pub style: TextMeshStyle,
+    /// Distance between the origins of neighbouring labels, across and down.
+    pub spacing: Vec2,
+}
+
+impl StressScene {
+    /// A workload of `labels` texts with `glyphs_per_label` glyphs each, in the default
+    /// style.
+    pub fn new(labels: usize, glyphs_per_label: usize) -> Self {
+        Self {
+            labels,
+            glyphs_per_label,
+            style: TextMeshStyle::default(),
+            // Glyphs are somewhat narrower than the em they are sized by
+            spacing: Vec2::new(glyphs_per_label as f32 * 0.75 + 1.0, 1.5),
+        }
+    }
+
+    /// Set the [`style`](Self::style) of the texts.
+    pub fn style(mut self, style: TextMeshStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the [`subdivision`](TextMeshStyle::subdivision) of the texts' curves.
+    pub fn subdivision(mut self, subdivision: u8) -> Self {
+        self.style.subdivision = subdivision;
+        self
+    }
+
+    /// Set the extrusion [`depth`](TextMeshStyle::depth) of the texts.
+    pub fn depth(mut self, depth: f32) -> Self {
+        self.style.depth = depth;
+        self
+    }
+
+    /// Set the [`spacing`](Self::spacing) of the grid.
+    pub fn spacing(mut self, spacing: Vec2) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Text of the label at `index`.
+    pub fn label_text(&self, index: usize) -> String {
+        (0..self.glyphs_per_label)
+            .map(|glyph| GLYPHS[(index + glyph) % GLYPHS.len()])
+            .collect()
+    }
+
+    /// Position of the label at `index` in the grid, starting at the origin and growing
+    /// right and down.
+    pub fn label_position(&self, index: usize) -> Vec3 {
+        let columns = (self.labels as f32).sqrt().ceil().max(1.0) as usize;
+        let (column, row) = (index % columns, index / columns);
+        Vec3::new(
+            column as f32 * self.spacing.x,
+            -(row as f32) * self.spacing.y,
+            0.0,
+        )
+    }
+
+    /// Spawn every label as a [`TextMeshBundle`] in `font`, returning their entities.
+    pub fn spawn(&self, commands: &mut Commands, font: Handle<FontMesh>) -> Vec<Entity> {
+        (0..self.labels)
+            .map(|index| {
+                commands
+                    .spawn(self.bundle(index, font.clone()))
+                    .insert(Transform::from_translation(self.label_position(index)))
+                    .id()
+            })
+            .collect()
+    }
+
+    /// Build every label in `font` with the [`core`](crate::core) functions, and time it.
+    ///
+    /// Fallback fonts of the style are ignored; the labels only use characters most
+    /// fonts have.
+    pub fn measure(&self, font: &FontMesh) -> StressStats {
+        let mut stats = StressStats::default();
+        for index in 0..self.labels {
+            let text = self.label_text(index);
+            let start = Instant::now();
+            let data = build_text_mesh(font, &[], &text, &self.style);
+            stats.elapsed += start.elapsed();
+            if let Some(data) = data {
+                stats.add(
+                    self.glyphs_per_label,
+                    data.vertices.len(),
+                    data.indices.len(),
+                );
+            }
+        }
+        stats
+    }
+
+    /// Spawn every label into `app` in `font`, and time the updates until all of them
+    /// have their meshes.
+    ///
+    /// `app` needs the [`FontMeshPlugin`](crate::FontMeshPlugin) and `font` to be
+    /// loaded, or to load within `max_frames` updates. Labels still without a mesh after
+    /// that aren't counted in the [`labels`](StressStats::labels) of the result. The
+    /// labels stay in the world for the caller to render or despawn.
+    pub fn run(&self, app: &mut App, font: Handle<FontMesh>, max_frames: u32) -> StressStats {
+        let entities: Vec<Entity> = (0..self.labels)
+            .map(|index| {
+                app.world_mut()
+                    .spawn(self.bundle(index, font.clone()))
+                    .insert(Transform::from_translation(self.label_position(index)))
+                    .id()
+            })
+            .collect();
+
+        let mut stats = StressStats::default();
+        let mut pending = entities;
+        for _ in 0..max_frames {
+            let start = Instant::now();
+            app.update();
+            stats.elapsed += start.elapsed();
+            stats.frames += 1;
+
+            let world = app.world();
+            let meshes = world.resource::<Assets<Mesh>>();
+            pending.retain(|&entity| {
+                // Texts start out with the default handle, until their mesh is added
+                let Some(mesh) = world
+                    .get::<Mesh3d>(entity)
+                    .and_then(|mesh| meshes.get(&mesh.0))
+                else {
+                    return true;
+                };
+                let indices = mesh.indices().map_or(0, Indices::len);
+                stats.add(self.glyphs_per_label, mesh.count_vertices(), indices);
+                false
+            });
+            if pending.is_empty() {
+                break;
+            }
+        }
+        stats
+    }
+
+    fn bundle(&self, index: usize, font: Handle<FontMesh>) -> TextMeshBundle {
+        TextMeshBundle {
+            text_mesh: TextMesh {
+                text: self.label_text(index),
+                font,
+                style: self.style.clone(),
+                ..default()
+            },
+            ..default()
+        }
+    }
+}
+
+/// Sizes and timing of a generated [`StressScene`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StressStats {
+    /// Number of labels generated.
+    pub labels: usize,
+    /// Number of glyphs in the generated labels.
+    pub glyphs: usize,
+    /// Number of vertices of the generated meshes.
+    pub vertices: usize,
+    /// Number of triangles of the generated meshes.
+    pub triangles: usize,
+    /// Updates the labels took to generate, `0` when measured without an app.
+    pub frames: u32,
+    /// Time spent generating.
+    pub elapsed: Duration,
+}
+
+impl StressStats {
+    /// Average time per generated label.
+    pub fn per_label(&self) -> Duration {
+        match self.labels {
+            0 => Duration::ZERO,
+            labels => self.elapsed / labels as u32,
+        }
+    }
+
+    /// Generated glyphs per second.
+    pub fn glyphs_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            0.0 => 0.0,
+            seconds => self.glyphs as f64 / seconds,
+        }
+    }
+
+    fn add(&mut self, glyphs: usize, vertices: usize, indices: usize) {
+        self.labels += 1;
+        self.glyphs += glyphs;
+        self.vertices += vertices;
+        self.triangles += indices / 3;
+    }
+}
@@ -0,0 +1,208 @@
+use crate::component::{FontAndStyle, TextMesh, TextMeshGlyphs};
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::prelude::*;
+use bevy::reflect::{DynamicStruct, Struct};
+
+/// Shared font and style for every text in a hierarchy.
+///
+/// Every [`TextMesh`] and [`TextMeshGlyphs`] on this entity or one of its descendants
+/// takes its `style` from the nearest `TextStyleRoot` above it, and its `font` too
+/// if [`TextStyleRoot::font`] is set. Changing the root or moving a text to another
+/// root updates them, so a menu can set depth, subdivision, and font in one place.
+///
+/// Inheritance is per field: a text keeps every style field and its font where it set
+/// its own value, and takes the rest from the root. A value counts as the text's own
+/// when it differs from the default, or from what the text last inherited. Texts with
+/// a [`TextStyleOverride`] keep their whole font and style, also where it matches the
+/// default.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// let font = asset_server.load("fonts/font.ttf");
+///
+/// commands
+///     .spawn((
+///         Transform::default(),
+///         Visibility::default(),
+///         TextStyleRoot {
+///             font: Some(font.clone()),
+///             style: TextMeshStyle {
+///                 depth: 0.05,
+///                 subdivision: 10,
+///                 ..default()
+///             },
+///         },
+///     ))
+///     .with_children(|menu| {
+///         for (i, label) in ["Play", "Options", "Quit"].into_iter().enumerate() {
+///             menu.spawn(TextMeshBundle {
+///                 text_mesh: TextMesh {
+///                     text: label.to_string(),
+///                     ..default()
+///                 },
+///                 transform: Transform::from_xyz(0.0, -1.2 * i as f32, 0.0),
+///                 ..default()
+///             });
+///         }
+///     });
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct TextStyleRoot {
+    /// Font for the texts below this root, or `None` to let each text keep its own.
+    pub font: Option<Handle<FontMesh>>,
+    /// Style for the texts below this root.
+    pub style: TextMeshStyle,
+}
+
+/// Marker that keeps a text's own font and style below a [`TextStyleRoot`].
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextStyleOverride;
+
+/// Nearest [`TextStyleRoot`] on `entity` or one of its ancestors.
+fn nearest_root<'w>(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    roots: &'w Query<Ref<TextStyleRoot>>,
+) -> Option<(Entity, Ref<'w, TextStyleRoot>)> {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find_map(|ancestor| Some((ancestor, roots.get(ancestor).ok()?)))
+}
+
+/// What a text last took from its [`TextStyleRoot`], to tell inherited values from
+/// the text's own.
+#[derive(Component, Clone, Debug)]
+pub(crate) struct InheritedTextStyle {
+    root: Entity,
+    font: Option<Handle<FontMesh>>,
+    style: TextMeshStyle,
+}
+
+/// Merge `root` into `own` field by field, keeping the fields that differ from what
+/// the text last `inherited`.
+fn merge_styles(
+    own: &TextMeshStyle,
+    inherited: &TextMeshStyle,
+    root: &TextMeshStyle,
+) -> TextMeshStyle {
+    let mut merged: DynamicStruct = root.to_dynamic_struct();
+    for (index, field) in own.iter_fields().enumerate() {
+        let kept = inherited
+            .field_at(index)
+            .and_then(|inherited| field.reflect_partial_eq(inherited))
+            != Some(true);
+        if let (true, Some(name)) = (kept, own.name_at(index)) {
+            merged.insert_boxed(name, field.to_dynamic());
+        }
+    }
+    let mut merged = TextMeshStyle::from_reflect(&merged).unwrap_or_else(|| root.clone());
+    // Not reflected, so merged by hand
+    merged.variations = if own.variations != inherited.variations {
+        own.variations.clone()
+    } else {
+        root.variations.clone()
+    };
+    merged
+}
+
+type InheritingTextQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (Entity, &'static mut T, Option<&'static InheritedTextStyle>),
+    Without<TextStyleOverride>,
+>;
+
+/// Hierarchy and override changes since the last run.
+struct Changes {
+    /// Whether any entity was moved in the hierarchy or a root removed.
+    hierarchy: bool,
+    /// Texts whose [`TextStyleOverride`] was removed.
+    released: Vec<Entity>,
+}
+
+/// Merge the nearest root into every text of type `T` that may have changed.
+fn inherit<T: FontAndStyle>(
+    commands: &mut Commands,
+    texts: &mut InheritingTextQuery<T>,
+    roots: &Query<Ref<TextStyleRoot>>,
+    parents: &Query<&ChildOf>,
+    changes: &Changes,
+) {
+    for (entity, mut text, inherited) in texts.iter_mut() {
+        let Some((root_entity, root)) = nearest_root(entity, parents, roots) else {
+            continue;
+        };
+        // A moved ancestor can give a text another root without touching the text
+        let stale = text.is_added()
+            || root.is_changed()
+            || changes.hierarchy
+            || changes.released.contains(&entity)
+            || inherited.is_none_or(|inherited| inherited.root != root_entity);
+        if !stale {
+            continue;
+        }
+
+        let default_style = TextMeshStyle::default();
+        let (inherited_font, inherited_style) = match inherited {
+            Some(inherited) => (inherited.font.as_ref(), &inherited.style),
+            None => (None, &default_style),
+        };
+        let (font, style) = text.font_and_style();
+        let font_inherited = match inherited_font {
+            Some(inherited_font) => inherited_font == font,
+            None => *font == Handle::default(),
+        };
+        let font = match &root.font {
+            Some(root_font) if font_inherited => root_font.clone(),
+            _ => font.clone(),
+        };
+        let style = merge_styles(style, inherited_style, &root.style);
+
+        // Only touch the component when something differs, to avoid regenerating
+        let (text_font, text_style) = text.font_and_style();
+        if *text_font != font || *text_style != style {
+            let (text_font, text_style) = text.font_and_style_mut();
+            *text_font = font;
+            *text_style = style;
+        }
+        commands.entity(entity).insert(InheritedTextStyle {
+            root: root_entity,
+            font: root.font.clone(),
+            style: root.style.clone(),
+        });
+    }
+}
+
+/// System that merges [`TextStyleRoot`] fonts and styles into the texts below them.
+///
+/// Texts are updated when their root changes, when they or one of their ancestors are
+/// spawned or moved in the hierarchy, and when their [`TextStyleOverride`] is removed.
+#[allow(clippy::too_many_arguments)]
+pub fn inherit_text_styles(
+    mut commands: Commands,
+    roots: Query<Ref<TextStyleRoot>>,
+    parents: Query<&ChildOf>,
+    moved: Query<(), Changed<ChildOf>>,
+    mut unparented: RemovedComponents<ChildOf>,
+    mut removed_roots: RemovedComponents<TextStyleRoot>,
+    mut texts: InheritingTextQuery<TextMesh>,
+    mut glyph_texts: InheritingTextQuery<TextMeshGlyphs>,
+    mut removed_overrides: RemovedComponents<TextStyleOverride>,
+) {
+    let changes = Changes {
+        hierarchy: !moved.is_empty()
+            || unparented.read().count() > 0
+            || removed_roots.read().count() > 0,
+        released: removed_overrides.read().collect(),
+    };
+    inherit(&mut commands, &mut texts, &roots, &parents, &changes);
+    inherit(&mut commands, &mut glyph_texts, &roots, &parents, &changes);
+}
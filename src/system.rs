@@ -1,65 +1,40 @@
-use crate::component::{GlyphMesh, JustifyText, TextAnchor, TextMesh, TextMeshGlyphs};
+use crate::bounds::TextMeshBounds;
+use crate::caret::{TextCursor3dQuad, TextSelection3dQuads};
+use crate::component::{
+    GlyphMesh, TextMesh, TextMesh2d, TextMeshGlyphs, TextMeshPart, TextMeshPartMaterials,
+    TextMeshParts,
+};
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::FontChain;
+use crate::core::layout::vertical_extent;
+use crate::core::layout::{
+    calculate_line_height, calculate_line_step, line_glyphs, paragraph_lines, LineGlyph,
+};
+use crate::core::mesh::{
+    build_flat_line_data, build_text_mesh_data, calculate_anchor_offset, glyph_uvs, line_offset,
+    lines_anchor_offset, stack_lines, LineMeshData, TextMeshData,
+};
+use crate::core::style::TextMeshStyle;
+use crate::counter::TextMeshCounter;
+use crate::glow::TextMeshGlowShell;
+use crate::glyph_pool::{GlyphPool, GlyphShape, ParkedGlyph, ShapeKey};
+use crate::lazy::TextMeshDeferred;
+use crate::line_cache::TextMeshLineCache;
+use crate::marquee::MarqueeBulb;
+use crate::path::{build_text_path_data, TextPath};
+use crate::sections::TextMeshSectionMesh;
+use crate::shared_mesh::{SharedTextMeshes, TextMeshCachePolicy};
+use crate::table::{TextTableCell, TextTableRules};
+use crate::task::TextMeshAsync;
+use crate::ticker::TextTicker;
+use crate::whitespace::TextMeshWhitespaceMarkers;
 use crate::FontMesh;
 use bevy::asset::RenderAssetUsages;
+use bevy::camera::primitives::{Aabb, MeshAabb};
 use bevy::mesh::Indices;
 use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
 
-/// Helper function to calculate the width of a line of text
-#[inline]
-fn calculate_line_width(line: &str, face: &fontmesh::Face) -> f32 {
-    line.chars().map(|ch| get_glyph_advance(ch, face)).sum()
-}
-
-/// Helper function to get the advance width for a character
-#[inline]
-fn get_glyph_advance(ch: char, face: &fontmesh::Face) -> f32 {
-    fontmesh::glyph_advance(face, ch).unwrap_or_else(|| {
-        if ch.is_whitespace() {
-            // Use font metrics for a proportional fallback space width
-            // Typically ~25% of the font height is a good space width
-            (fontmesh::ascender(face) - fontmesh::descender(face)) * 0.25
-        } else {
-            0.0
-        }
-    })
-}
-
-/// Helper function to calculate the X offset based on text justification
-#[inline]
-fn calculate_justification_offset(justify: JustifyText, line_width: f32) -> f32 {
-    match justify {
-        JustifyText::Left => 0.0,
-        JustifyText::Center => -line_width * 0.5,
-        JustifyText::Right => -line_width,
-    }
-}
-
-/// Helper function to calculate anchor offset for text positioning
-fn calculate_anchor_offset(anchor: TextAnchor, min_bound: Vec3, max_bound: Vec3) -> Vec3 {
-    let size = max_bound - min_bound;
-    let center = min_bound + size * 0.5;
-
-    match anchor {
-        TextAnchor::TopLeft => Vec3::new(-min_bound.x, -max_bound.y, 0.0),
-        TextAnchor::TopCenter => Vec3::new(-center.x, -max_bound.y, 0.0),
-        TextAnchor::TopRight => Vec3::new(-max_bound.x, -max_bound.y, 0.0),
-
-        TextAnchor::CenterLeft => Vec3::new(-min_bound.x, -center.y, 0.0),
-        TextAnchor::Center => Vec3::new(-center.x, -center.y, 0.0),
-        TextAnchor::CenterRight => Vec3::new(-max_bound.x, -center.y, 0.0),
-
-        TextAnchor::BottomLeft => Vec3::new(-min_bound.x, -min_bound.y, 0.0),
-        TextAnchor::BottomCenter => Vec3::new(-center.x, -min_bound.y, 0.0),
-        TextAnchor::BottomRight => Vec3::new(-max_bound.x, -min_bound.y, 0.0),
-
-        TextAnchor::Custom(pivot) => {
-            let pivot_pos = min_bound.truncate() + size.truncate() * pivot;
-            Vec3::new(-pivot_pos.x, -pivot_pos.y, 0.0)
-        }
-    }
-}
-
 /// Helper function to create a Bevy mesh from vertex/normal/index data
 fn create_mesh_from_data(
     vertices: Vec<[f32; 3]>,
@@ -76,6 +51,86 @@ fn create_mesh_from_data(
     mesh
 }
 
+impl TextMeshData {
+    /// Upload the buffers as a Bevy [`Mesh`].
+    pub fn into_mesh(self) -> Mesh {
+        let mut mesh = create_mesh_from_data(self.vertices, self.normals, self.indices);
+        if !self.uvs.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        }
+        if !self.tangents.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, self.tangents);
+        }
+        if !self.colors.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, self.colors);
+        }
+        mesh
+    }
+}
+
+/// Normals with a Z component beyond this magnitude are treated as front/back cap faces.
+const CAP_NORMAL_THRESHOLD: f32 = 0.99;
+
+/// Splits a text mesh into its front cap, back cap, and extrusion side triangles.
+///
+/// Triangles are classified by their averaged vertex normal: faces pointing along `+Z`
+/// are the front cap, faces pointing along `-Z` are the back cap, and everything else
+/// belongs to the extrusion sides. Vertices are re-indexed per part.
+fn split_mesh_parts(data: &TextMeshData) -> [(TextMeshPart, TextMeshData); 3] {
+    let mut parts = [
+        (TextMeshPart::Front, TextMeshData::default()),
+        (TextMeshPart::Back, TextMeshData::default()),
+        (TextMeshPart::Sides, TextMeshData::default()),
+    ];
+    let mut remap = vec![[u32::MAX; 3]; data.vertices.len()];
+
+    for triangle in data.indices.chunks_exact(3) {
+        let normal_z: f32 = triangle
+            .iter()
+            .map(|&i| data.normals[i as usize][2])
+            .sum::<f32>()
+            / 3.0;
+
+        let slot = if normal_z > CAP_NORMAL_THRESHOLD {
+            0
+        } else if normal_z < -CAP_NORMAL_THRESHOLD {
+            1
+        } else {
+            2
+        };
+
+        let part = &mut parts[slot].1;
+        for &i in triangle {
+            let mapped = &mut remap[i as usize][slot];
+            if *mapped == u32::MAX {
+                *mapped = part.vertices.len() as u32;
+                part.vertices.push(data.vertices[i as usize]);
+                part.normals.push(data.normals[i as usize]);
+                if let Some(uv) = data.uvs.get(i as usize) {
+                    part.uvs.push(*uv);
+                }
+                if let Some(tangent) = data.tangents.get(i as usize) {
+                    part.tangents.push(*tangent);
+                }
+            }
+            part.indices.push(*mapped);
+        }
+    }
+
+    parts
+}
+
+/// Keep an entity's [`Aabb`] in sync with a freshly generated mesh.
+///
+/// Bevy only computes bounds for entities that don't have an `Aabb` yet, so without this
+/// regenerated text would keep the bounds of its first mesh and be culled incorrectly.
+pub(crate) fn update_aabb(commands: &mut Commands, entity: Entity, mesh: &Mesh) {
+    match mesh.compute_aabb() {
+        Some(aabb) => commands.entity(entity).insert(aabb),
+        None => commands.entity(entity).remove::<Aabb>(),
+    };
+}
+
 /// Marker component indicating that a [`TextMesh`] has been processed.
 #[derive(Component)]
 pub struct TextMeshComputed;
@@ -84,108 +139,341 @@ pub struct TextMeshComputed;
 #[derive(Component)]
 pub struct TextMeshGlyphsComputed;
 
+/// Offset the anchor of a [`TextMeshGlyphs`] last moved its glyphs by.
+#[derive(Component, Clone, Copy, Default)]
+pub(crate) struct GlyphAnchorOffset(pub(crate) Vec3);
+
+/// Material a glyph of a [`TextMeshGlyphs`] was last given by its slots and overrides.
+///
+/// Glyphs whose material still matches it follow edits of the text; materials set
+/// afterwards, by user code or [`FontMeshSettings::material_factory`], are left alone.
+///
+/// [`FontMeshSettings::material_factory`]: crate::FontMeshSettings::material_factory
+#[derive(Component, Clone, Copy)]
+pub(crate) struct GlyphSlotMaterial(pub(crate) AssetId<StandardMaterial>);
+
+/// Marker component indicating that a [`TextMeshParts`] has been processed.
+#[derive(Component)]
+pub struct TextMeshPartsComputed;
+
+/// Marker component indicating that a [`TextMesh2d`] has been processed.
+#[derive(Component)]
+pub struct TextMesh2dComputed;
+
+/// Message sent when the mesh of a [`TextMesh`] or [`TextMesh2d`] has been built or
+/// updated.
+///
+/// It is sent on the frame the new mesh, its [`Aabb`], and its [`TextMeshBounds`] are
+/// in place, including meshes generated in the background with [`TextMeshAsync`]. Read
+/// it in a system ordered after [`FontMeshSystems`](crate::FontMeshSystems) to react on
+/// the same frame, for example to frame a camera around new text, instead of polling
+/// for [`TextMeshComputed`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn announce_ready_text(mut ready: MessageReader<TextMeshReady>, bounds: Query<&TextMeshBounds>) {
+///     for TextMeshReady { entity } in ready.read() {
+///         if let Ok(bounds) = bounds.get(*entity) {
+///             println!("{entity} is {} units wide", bounds.size().x);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextMeshReady {
+    /// The text entity.
+    pub entity: Entity,
+}
+
+/// Message sent when the glyph entities of a [`TextMeshGlyphs`] have been built or
+/// updated, including by [`TextMeshAppend`](crate::TextMeshAppend) and
+/// [`TextMeshCounter`].
+///
+/// Like [`TextMeshReady`], it is sent on the frame the glyphs are in place; read it
+/// after [`FontMeshSystems`](crate::FontMeshSystems), for example to start an animation
+/// over the [`GlyphMesh`] children.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlyphMeshesReady {
+    /// The text entity.
+    pub entity: Entity,
+    /// Number of glyph entities shown, one per character with geometry.
+    pub glyph_count: usize,
+}
+
 type TextMeshQuery<'w, 's> = Query<
     'w,
     's,
-    (Entity, &'static TextMesh, &'static mut Mesh3d),
-    Or<(Changed<TextMesh>, Without<TextMeshComputed>)>,
+    (
+        Entity,
+        &'static TextMesh,
+        &'static mut Mesh3d,
+        Option<&'static mut TextMeshLineCache>,
+        Option<&'static TextPath>,
+        Option<&'static TextMeshCachePolicy>,
+    ),
+    (
+        Or<(Without<TextMeshAsync>, With<TextPath>)>,
+        Without<TextTicker>,
+        Without<TextMeshDeferred>,
+        Or<(
+            Changed<TextMesh>,
+            Changed<TextPath>,
+            Changed<TextMeshCachePolicy>,
+            Without<TextMeshComputed>,
+        )>,
+    ),
 >;
 
 pub fn update_text_meshes(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     font_assets: Res<Assets<FontMesh>>,
+    mut shared: ResMut<SharedTextMeshes>,
     mut query: TextMeshQuery,
+    mut removed_paths: RemovedComponents<TextPath>,
+    mut ready: MessageWriter<TextMeshReady>,
 ) {
-    for (entity, text_mesh, mut mesh_handle) in query.iter_mut() {
-        // 1. Try to get the font data
-        let Some(font_asset) = font_assets.get(&text_mesh.font) else {
-            // Font not loaded yet, skip this frame
+    // Text taken off its path has to be laid out straight again
+    for entity in removed_paths.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<TextMeshComputed>();
+        }
+    }
+
+    // Meshes of despawned or edited texts are freed once nothing else displays them
+    shared.release_unused();
+
+    for (entity, text_mesh, mut mesh_handle, line_cache, text_path, policy) in query.iter_mut() {
+        let policy = policy.copied().unwrap_or_default();
+        let shares = policy == TextMeshCachePolicy::Shared;
+
+        // 1. Parse the font and its fallbacks (no caching needed as parsing is lightweight)
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
             continue;
         };
 
-        // 2. Parse font directly (no caching needed as parsing is lightweight)
-        let Ok(face) = fontmesh::Face::parse(&font_asset.data, 0) else {
-            // Failed to parse font, skip this entity
+        // 2. Text on a path is placed glyph by glyph, without the line cache
+        if let Some(text_path) = text_path {
+            let mut data = build_text_path_data(
+                &text_mesh.text,
+                &fonts,
+                &text_mesh.style,
+                text_path,
+                &text_mesh.vertex_colors(),
+            );
+            if let Some(gradient) = &text_mesh.gradient {
+                gradient.apply(&mut data);
+            }
+            let mesh = data.into_mesh();
+            update_aabb(&mut commands, entity, &mesh);
+            let bounds = TextMeshBounds::from_mesh(&mesh);
+            mesh_handle.0 = meshes.add(mesh);
+            commands
+                .entity(entity)
+                .insert((TextMeshComputed, bounds))
+                .remove::<TextMeshLineCache>();
+            ready.write(TextMeshReady { entity });
             continue;
-        };
+        }
 
-        // 3. Generate combined mesh
-        let mut all_vertices = Vec::new();
-        let mut all_normals = Vec::new();
-        let mut all_indices = Vec::new();
+        // 3. Reuse the mesh of another text with the same content
+        if let Some((existing, bounds)) = shared.get(text_mesh).filter(|_| shares) {
+            if let Some(mesh) = meshes.get(existing) {
+                update_aabb(&mut commands, entity, mesh);
+                if mesh_handle.0 != *existing {
+                    mesh_handle.0 = existing.clone();
+                }
+                commands
+                    .entity(entity)
+                    .insert((TextMeshComputed, bounds.clone()))
+                    .remove::<TextMeshLineCache>();
+                ready.write(TextMeshReady { entity });
+                continue;
+            }
+        }
 
-        let mut cursor = Vec3::ZERO;
-        let mut index_offset = 0;
+        // 4. Rebuild only the edited lines when the font and style are unchanged and no
+        //    other text displays the mesh
+        if let Some(mut line_cache) = line_cache.filter(|_| policy != TextMeshCachePolicy::NoCache)
+        {
+            if line_cache.is_compatible(text_mesh, mesh_handle.id())
+                && !SharedTextMeshes::is_shared(&mesh_handle.0)
+            {
+                if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+                    shared.remove(&line_cache.text(), mesh_handle.id());
+                    line_cache.update(&text_mesh.text, &fonts, mesh);
+                    update_aabb(&mut commands, entity, mesh);
+                    let bounds = line_cache.bounds();
+                    if shares {
+                        shared.insert(text_mesh, mesh_handle.0.clone(), bounds.clone());
+                    }
+                    commands.entity(entity).insert(bounds);
+                    ready.write(TextMeshReady { entity });
+                    continue;
+                }
+            }
+        }
+
+        // 5. Generate every line and cache the per-line geometry
+        let line_cache = TextMeshLineCache::new(text_mesh, &fonts);
+        let data = line_cache.assemble();
+
+        // 6. Create and assign Bevy Mesh, sharing it with texts that show the same content
+        let mesh = data.into_mesh();
+        update_aabb(&mut commands, entity, &mesh);
+        mesh_handle.0 = meshes.add(mesh);
+        let bounds = line_cache.bounds();
+        if shares {
+            shared.insert(text_mesh, mesh_handle.0.clone(), bounds.clone());
+        }
 
-        let line_height =
-            fontmesh::ascender(&face) - fontmesh::descender(&face) + fontmesh::line_gap(&face);
+        // 7. Mark as computed
+        ready.write(TextMeshReady { entity });
+        let mut entity = commands.entity(entity);
+        entity.insert((TextMeshComputed, bounds));
+        if policy == TextMeshCachePolicy::NoCache {
+            entity.remove::<TextMeshLineCache>();
+        } else {
+            entity.insert(line_cache.with_mesh(mesh_handle.id()));
+        }
+    }
+}
 
-        // Bounds tracking
-        let mut min_bound = Vec3::splat(f32::MAX);
-        let mut max_bound = Vec3::splat(f32::MIN);
+type TextMesh2dQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static TextMesh2d, &'static mut Mesh2d),
+    (
+        Without<TextMeshDeferred>,
+        Or<(Changed<TextMesh2d>, Without<TextMesh2dComputed>)>,
+    ),
+>;
 
-        // Split text into lines for justification
-        for line in text_mesh.text.split('\n') {
-            // Calculate line width and X offset based on justification
-            let line_width = calculate_line_width(line, &face);
-            cursor.x = calculate_justification_offset(text_mesh.style.justify, line_width);
+/// System to generate flat 2D meshes for [`TextMesh2d`] components.
+pub fn update_text_meshes_2d(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    mut query: TextMesh2dQuery,
+    mut ready: MessageWriter<TextMeshReady>,
+) {
+    for (entity, text_mesh, mut mesh_handle) in query.iter_mut() {
+        // 1. Parse the font and its fallbacks (no caching needed as parsing is lightweight)
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
 
-            // Generate mesh for line
-            for ch in line.chars() {
-                if ch.is_whitespace() {
-                    cursor.x += get_glyph_advance(ch, &face);
-                    continue;
-                }
+        // 2. Generate the flat geometry
+        let style = &text_mesh.style;
+        let lines: Vec<LineMeshData> = paragraph_lines(&text_mesh.text)
+            .map(|(line, ends_paragraph)| build_flat_line_data(line, ends_paragraph, &fonts, style))
+            .collect();
+        let line_height = calculate_line_height(&fonts, style);
+        let line_step = style.orientation.line_step(line_height);
+        let anchor_offset = lines_anchor_offset(&lines, line_step, style.anchor);
+        let data = stack_lines(&lines, line_step, anchor_offset);
+        let bounds = TextMeshBounds::from_lines(
+            &lines,
+            style.orientation,
+            line_height,
+            anchor_offset,
+            vertical_extent(&fonts, style),
+        );
+
+        // 3. Create and assign Bevy Mesh
+        let mesh = data.into_mesh();
+        update_aabb(&mut commands, entity, &mesh);
+        mesh_handle.0 = meshes.add(mesh);
+
+        // 4. Mark as computed
+        commands.entity(entity).insert((TextMesh2dComputed, bounds));
+        ready.write(TextMeshReady { entity });
+    }
+}
 
-                // Use pure function to generate mesh
-                let mesh_res = fontmesh::char_to_mesh_3d(
-                    &face,
-                    ch,
-                    text_mesh.style.depth,
-                    text_mesh.style.subdivision,
-                );
-
-                if let Ok(mesh) = mesh_res {
-                    // Extend vertices and update bounds
-                    all_vertices.extend(mesh.vertices.iter().map(|v| {
-                        let pos = Vec3::new(v.x + cursor.x, v.y + cursor.y, v.z);
-                        min_bound = min_bound.min(pos);
-                        max_bound = max_bound.max(pos);
-                        [pos.x, pos.y, pos.z]
-                    }));
-
-                    // Extend normals
-                    all_normals.extend(mesh.normals.iter().map(|n| [n.x, n.y, n.z]));
-
-                    // Extend indices with offset
-                    all_indices.extend(mesh.indices.iter().map(|i| i + index_offset));
-
-                    index_offset += mesh.vertices.len() as u32;
-                    cursor.x += get_glyph_advance(ch, &face);
+type TextMeshPartsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TextMeshParts,
+        &'static TextMeshPartMaterials,
+    ),
+    (
+        Without<TextMeshDeferred>,
+        Or<(
+            Changed<TextMeshParts>,
+            Changed<TextMeshPartMaterials>,
+            Without<TextMeshPartsComputed>,
+        )>,
+    ),
+>;
+
+/// System to generate front, back, and side mesh entities for [`TextMeshParts`] components.
+///
+/// Each surface of the extruded text is spawned as its own child entity with a
+/// [`TextMeshPart`] marker and the matching material from [`TextMeshPartMaterials`].
+pub fn update_part_meshes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    query: TextMeshPartsQuery,
+    children_query: Query<&Children>,
+    part_query: Query<Entity, With<TextMeshPart>>,
+) {
+    for (entity, text_parts, materials) in query.iter() {
+        // 1. Parse the font and its fallbacks (no caching needed as parsing is lightweight)
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_parts.font, &text_parts.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
+
+        // 2. Despawn existing part children
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if part_query.contains(child) {
+                    commands.entity(child).despawn();
                 }
             }
-
-            // Move to next line
-            cursor.y -= line_height;
         }
 
-        // 4. Apply Anchor Offset
-        if !all_vertices.is_empty() {
-            let offset = calculate_anchor_offset(text_mesh.style.anchor, min_bound, max_bound);
-            all_vertices.iter_mut().for_each(|v| {
-                v[0] += offset.x;
-                v[1] += offset.y;
-                v[2] += offset.z;
-            });
-        }
+        // 3. Generate the combined mesh and split it by surface
+        let data = build_text_mesh_data(&text_parts.text, &fonts, &text_parts.style, &[]);
+        let parts = split_mesh_parts(&data);
 
-        // 5. Create and assign Bevy Mesh
-        let new_mesh = create_mesh_from_data(all_vertices, all_normals, all_indices);
-        mesh_handle.0 = meshes.add(new_mesh);
+        // 4. Spawn one child per non-empty surface
+        commands.entity(entity).with_children(|parent| {
+            for (part, part_data) in parts {
+                if part_data.indices.is_empty() {
+                    continue;
+                }
 
-        // 7. Mark as computed
-        commands.entity(entity).insert(TextMeshComputed);
+                let mesh = part_data.into_mesh();
+                let aabb = mesh.compute_aabb().unwrap_or_default();
+
+                parent.spawn((
+                    part,
+                    aabb,
+                    Mesh3d(meshes.add(mesh)),
+                    MeshMaterial3d(materials.get(part).clone()),
+                    Transform::default(),
+                    Visibility::default(),
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ));
+            }
+        });
+
+        // 5. Mark as computed
+        commands.entity(entity).insert(TextMeshPartsComputed);
     }
 }
 
@@ -196,145 +484,435 @@ type TextMeshGlyphsQuery<'w, 's> = Query<
         Entity,
         &'static TextMeshGlyphs,
         &'static MeshMaterial3d<StandardMaterial>,
+        Option<&'static mut GlyphPool>,
+    ),
+    (
+        Or<(Changed<TextMeshGlyphs>, Without<TextMeshGlyphsComputed>)>,
+        Without<TextMeshCounter>,
+        Without<TextMeshDeferred>,
+        Without<GlyphMesh>,
+    ),
+>;
+
+type GlyphChildQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut GlyphMesh,
+        &'static mut Transform,
+        &'static mut Mesh3d,
+        Option<&'static GlyphShape>,
+        &'static mut MeshMaterial3d<StandardMaterial>,
+        Option<&'static mut GlyphSlotMaterial>,
     ),
-    Or<(Changed<TextMeshGlyphs>, Without<TextMeshGlyphsComputed>)>,
 >;
 
 /// System to generate per-character mesh entities for [`TextMeshGlyphs`] components.
 ///
 /// This system spawns a separate child entity for each character in the text,
-/// allowing for per-character styling, animations, and interactions.
+/// allowing for per-character styling, animations, and interactions. When the text
+/// changes, glyphs of characters that are still there keep their entity and components
+/// and are only moved, switching material only when the slot or override of their new
+/// position gives them another one. Glyphs of removed characters are hidden and reused,
+/// with their mesh assets, for characters inserted by this or later edits. Glyphs of
+/// the same character share one mesh asset.
+#[allow(clippy::too_many_arguments)]
 pub fn update_glyph_meshes(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     font_assets: Res<Assets<FontMesh>>,
-    query: TextMeshGlyphsQuery,
+    mut query: TextMeshGlyphsQuery,
     children_query: Query<&Children>,
-    glyph_query: Query<Entity, With<GlyphMesh>>,
+    mut glyph_query: GlyphChildQuery,
+    parked_query: Query<(), With<ParkedGlyph>>,
+    mut ready: MessageWriter<GlyphMeshesReady>,
 ) {
-    for (entity, text_glyphs, default_material) in query.iter() {
-        // 1. Try to get the font data
-        let Some(font_asset) = font_assets.get(&text_glyphs.font) else {
-            // Font not loaded yet, skip this frame
-            continue;
-        };
-
-        // 2. Parse font directly (no caching needed as parsing is lightweight)
-        let Ok(face) = fontmesh::Face::parse(&font_asset.data, 0) else {
-            // Failed to parse font, skip this entity
+    for (entity, text_glyphs, default_material, pool) in query.iter_mut() {
+        // 1. Parse the font and its fallbacks (no caching needed as parsing is lightweight)
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_glyphs.font, &text_glyphs.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
             continue;
         };
+        let style = &text_glyphs.style;
 
-        // 3. Despawn existing glyph children
-        if let Ok(children) = children_query.get(entity) {
-            for child in children.iter() {
-                if glyph_query.contains(child) {
-                    commands.entity(child).despawn();
-                }
+        // 2. Lay out the glyphs line by line
+        let line_step = calculate_line_step(&fonts, style);
+        let mut laid_out = Vec::new();
+        let mut char_index = 0;
+        for (line_index, (line, ends_paragraph)) in paragraph_lines(&text_glyphs.text).enumerate() {
+            let line_origin = line_offset(line_index, line_step);
+            for glyph in line_glyphs(line, ends_paragraph, &fonts, style) {
+                let glyph_mesh = GlyphMesh {
+                    char_index: char_index + glyph.index,
+                    line_index,
+                    character: glyph.character,
+                    origin: line_origin + Vec3::new(glyph.x, glyph.y, 0.0),
+                };
+                laid_out.push((glyph_mesh, glyph));
             }
+            // Account for newline character in char_index
+            char_index += line.chars().count() + 1;
         }
 
-        // 4. Calculate line widths for justification
-        let line_height =
-            fontmesh::ascender(&face) - fontmesh::descender(&face) + fontmesh::line_gap(&face);
-        let lines: Vec<&str> = text_glyphs.text.split('\n').collect();
-
-        let line_widths: Vec<f32> = lines
+        // 3. Match the existing glyph children to the new glyphs by character
+        let mut existing: Vec<(Entity, char, usize)> = children_query
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| {
+                let (glyph, ..) = glyph_query.get(child).ok()?;
+                Some((child, glyph.character, glyph.char_index))
+            })
+            .collect();
+        existing.sort_by_key(|&(_, _, char_index)| char_index);
+        let old: Vec<char> = existing
             .iter()
-            .map(|line| calculate_line_width(line, &face))
+            .map(|&(_, character, _)| character)
             .collect();
+        let new: Vec<char> = laid_out.iter().map(|(glyph, _)| glyph.character).collect();
+        let matches = match_glyphs(&old, &new);
+
+        // 4. Hide glyphs of removed characters in the pool
+        let font = text_glyphs.font.id();
+        let mut new_pool = None;
+        let pool = match pool {
+            Some(pool) => pool.into_inner(),
+            None => new_pool.insert(GlyphPool::new(font, style)),
+        };
+        let same_source = pool.generated_with(font, style);
+        pool.restyle(font, style);
+        pool.retain(|parked| parked_query.contains(parked));
+        let mut kept = vec![false; existing.len()];
+        for &old_index in matches.iter().flatten() {
+            kept[old_index] = true;
+        }
+        for (&(child, ..), kept) in existing.iter().zip(&kept) {
+            if let (false, Ok((_, _, mesh, shape, ..))) = (kept, glyph_query.get(child)) {
+                let shape = shape.copied().filter(|_| same_source);
+                pool.park(&mut commands, child, shape, mesh);
+            }
+        }
 
-        // 5. Spawn glyph entities
-        let mut char_index = 0;
+        // 5. Move the kept glyphs, changing only meshes whose glyph changed
+        let shapes: Vec<ShapeKey> = laid_out
+            .iter()
+            .map(|(_, glyph)| GlyphShape::of(glyph).key(style))
+            .collect();
+        let mut inserted = Vec::new();
+        let mut glyph_count = 0;
+        for ((mut glyph_mesh, glyph), matched) in laid_out.into_iter().zip(matches) {
+            let Some(child) = matched.map(|old_index| existing[old_index].0) else {
+                inserted.push((glyph_mesh, glyph));
+                continue;
+            };
+            let Ok((mut old_glyph, mut transform, mut mesh, old_shape, mut material, slot)) =
+                glyph_query.get_mut(child)
+            else {
+                continue;
+            };
+
+            // Follow the slot or override of the new position, unless the material was
+            // replaced since the text gave it
+            let slot_material = text_glyphs
+                .glyph_material(glyph_mesh.char_index, glyph.character)
+                .unwrap_or(&default_material.0);
+            if let Some(mut slot) = slot.filter(|slot| slot.0 == material.id()) {
+                if slot.0 != slot_material.id() {
+                    slot.0 = slot_material.id();
+                    material.0 = slot_material.clone();
+                }
+            }
 
-        commands.entity(entity).with_children(|parent| {
-            for (line_index, line) in lines.iter().enumerate() {
-                let line_width = line_widths[line_index];
-                let mut cursor_x =
-                    calculate_justification_offset(text_glyphs.style.justify, line_width);
-                let cursor_y = -(line_index as f32) * line_height;
-
-                for ch in line.chars() {
-                    let advance = get_glyph_advance(ch, &face);
-
-                    // Skip whitespace but still count it
-                    if ch.is_whitespace() {
-                        cursor_x += advance;
-                        char_index += 1;
+            let shape = GlyphShape::of(&glyph);
+            let fitting = old_shape
+                .filter(|old_shape| same_source && old_shape.fits(&shape, style))
+                .copied();
+            let pivot = match fitting {
+                Some(old_shape) => old_shape.pivot,
+                None => match pool.mesh(&mut meshes, &glyph, style) {
+                    Some(shared) => {
+                        mesh.0 = shared.mesh;
+                        commands
+                            .entity(child)
+                            .insert((shared.aabb, shape.with_pivot(shared.pivot)));
+                        shared.pivot
+                    }
+                    None => {
+                        pool.park(&mut commands, child, None, &mesh);
                         continue;
                     }
+                },
+            };
+            glyph_count += 1;
+
+            // Keep offsets from animations or user code on top of the new origin
+            glyph_mesh.origin += pivot;
+            if old_glyph.origin != glyph_mesh.origin {
+                transform.translation += glyph_mesh.origin - old_glyph.origin;
+            }
+            if (old_glyph.char_index, old_glyph.line_index, old_glyph.origin)
+                != (
+                    glyph_mesh.char_index,
+                    glyph_mesh.line_index,
+                    glyph_mesh.origin,
+                )
+            {
+                *old_glyph = glyph_mesh;
+            }
+        }
 
-                    // Generate mesh for this character using pure function
-                    let mesh_res = fontmesh::char_to_mesh_3d(
-                        &face,
-                        ch,
-                        text_glyphs.style.depth,
-                        text_glyphs.style.subdivision,
-                    );
-
-                    if let Ok(glyph_mesh_data) = mesh_res {
-                        let vertices: Vec<_> = glyph_mesh_data
-                            .vertices
-                            .iter()
-                            .map(|v| [v.x, v.y, v.z])
-                            .collect();
-
-                        let normals: Vec<_> = glyph_mesh_data
-                            .normals
-                            .iter()
-                            .map(|n| [n.x, n.y, n.z])
-                            .collect();
-
-                        let mesh = create_mesh_from_data(
-                            vertices,
-                            normals,
-                            glyph_mesh_data.indices.clone(),
-                        );
-                        let mesh_handle = meshes.add(mesh);
-
-                        // Spawn glyph entity as child
-                        parent.spawn((
-                            GlyphMesh {
-                                char_index,
-                                line_index,
-                                character: ch,
-                            },
-                            Mesh3d(mesh_handle),
-                            default_material.clone(),
-                            Transform::from_xyz(cursor_x, cursor_y, 0.0),
-                            Visibility::default(),
-                            InheritedVisibility::default(),
-                            ViewVisibility::default(),
-                        ));
-                    }
+        // 6. Show glyphs for inserted characters
+        for (glyph_mesh, glyph) in inserted {
+            let material = text_glyphs
+                .glyph_material(glyph_mesh.char_index, glyph.character)
+                .unwrap_or(&default_material.0)
+                .clone();
+            let shown = pool.place(
+                &mut commands,
+                &mut meshes,
+                entity,
+                glyph_mesh,
+                &glyph,
+                style,
+                material,
+            );
+            glyph_count += usize::from(shown);
+        }
+        pool.release_unused(shapes, style);
 
-                    cursor_x += advance;
-                    char_index += 1;
-                }
+        // 7. Mark as computed
+        ready.write(GlyphMeshesReady {
+            entity,
+            glyph_count,
+        });
+        let mut entity = commands.entity(entity);
+        entity.insert(TextMeshGlyphsComputed);
+        if let Some(pool) = new_pool {
+            entity.insert(pool);
+        }
+    }
+}
 
-                // Account for newline character in char_index
-                char_index += 1;
-            }
+/// System that moves the glyphs of [`TextMeshGlyphs`] by the anchor of their style.
+///
+/// Runs for every [`GlyphMeshesReady`] message, once the glyphs are laid out. Like the
+/// single mesh of a [`TextMesh`], the glyphs are anchored by the bounds of their meshes.
+pub fn anchor_glyph_meshes(
+    mut commands: Commands,
+    mut ready: MessageReader<GlyphMeshesReady>,
+    texts: Query<(&TextMeshGlyphs, &Children)>,
+    mut glyphs: Query<(&mut GlyphMesh, &mut Transform, &Aabb), Without<ParkedGlyph>>,
+) {
+    for GlyphMeshesReady { entity, .. } in ready.read() {
+        let Ok((text_glyphs, children)) = texts.get(*entity) else {
+            continue;
+        };
+
+        // 1. Bound the glyphs where they were laid out
+        let mut bounds: Option<(Vec3, Vec3)> = None;
+        for (glyph, _, aabb) in glyphs.iter_many(children) {
+            let min = glyph.origin + Vec3::from(aabb.min());
+            let max = glyph.origin + Vec3::from(aabb.max());
+            bounds = Some(bounds.map_or((min, max), |(lo, hi)| (lo.min(min), hi.max(max))));
+        }
+        let offset = bounds.map_or(Vec3::ZERO, |(min, max)| {
+            calculate_anchor_offset(text_glyphs.style.anchor, min, max)
         });
 
-        // 6. Mark as computed
-        commands.entity(entity).insert(TextMeshGlyphsComputed);
+        // 2. Move them, remembering by how much for in-place appends
+        let mut iter = glyphs.iter_many_mut(children);
+        while let Some((mut glyph, mut transform, _)) = iter.fetch_next() {
+            glyph.origin += offset;
+            transform.translation += offset;
+        }
+        commands.entity(*entity).insert(GlyphAnchorOffset(offset));
     }
 }
 
+/// Children that the generation systems spawn or move.
+type GeneratedChild = Or<(
+    With<GlyphMesh>,
+    With<TextMeshPart>,
+    With<TextMeshSectionMesh>,
+    With<TextMeshGlowShell>,
+    With<TextMeshWhitespaceMarkers>,
+    With<TextCursor3dQuad>,
+    With<TextSelection3dQuads>,
+    With<TextTableCell>,
+    With<TextTableRules>,
+    With<MarqueeBulb>,
+)>;
+
+/// Generated children whose transform or parent changed.
+type MovedGeneratedChild = (
+    GeneratedChild,
+    With<ChildOf>,
+    Or<(Changed<Transform>, Changed<ChildOf>)>,
+);
+
+/// System that computes the [`GlobalTransform`] of children the generation systems
+/// spawned or moved after transform propagation.
+///
+/// Only added when generation runs in [`PostUpdate`], after
+/// [`TransformSystems::Propagate`](bevy::transform::TransformSystems::Propagate), so new
+/// and re-anchored glyphs are drawn in place on the frame they change instead of at the
+/// origin.
+pub(crate) fn sync_generated_transforms(
+    helper: TransformHelper,
+    mut children: Query<(Entity, &mut GlobalTransform), MovedGeneratedChild>,
+) {
+    for (entity, mut global_transform) in children.iter_mut() {
+        if let Ok(computed) = helper.compute_global_transform(entity) {
+            *global_transform = computed;
+        }
+    }
+}
+
+/// Pairs each new glyph with the index of an old glyph of the same character to reuse.
+///
+/// Characters kept in the same order, around the inserted and removed ones, are paired
+/// through the longest common subsequence of the two texts. Edits too large to compare
+/// cheaply reuse only the unchanged start and end.
+fn match_glyphs(old: &[char], new: &[char]) -> Vec<Option<usize>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut matches: Vec<Option<usize>> = (0..prefix).map(Some).collect();
+    matches.resize(new.len() - suffix, None);
+    matches.extend((old.len() - suffix..old.len()).map(Some));
+    if old_middle.is_empty()
+        || new_middle.is_empty()
+        || old_middle.len() * new_middle.len() > MAX_GLYPH_DIFF
+    {
+        return matches;
+    }
+
+    // Longest common subsequence lengths of every pair of suffixes
+    let width = new_middle.len() + 1;
+    let mut lengths = vec![0u32; (old_middle.len() + 1) * width];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lengths[i * width + j] = if old_middle[i] == new_middle[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() && j < new_middle.len() {
+        if old_middle[i] == new_middle[j] {
+            matches[prefix + j] = Some(prefix + i);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+/// Largest number of character pairs [`match_glyphs`] compares between the changed
+/// parts of two texts.
+const MAX_GLYPH_DIFF: usize = 1 << 20;
+
+/// Shows one glyph entity per visible character of a line of [`TextMeshGlyphs`],
+/// reusing entities parked in `pool`.
+///
+/// `char_index` is the index of the first character of the line in the whole text.
+/// Returns the number of glyphs shown.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn spawn_line_glyphs(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    pool: &mut GlyphPool,
+    parent: Entity,
+    line: &str,
+    ends_paragraph: bool,
+    line_index: usize,
+    char_index: usize,
+    line_step: Vec3,
+    fonts: &FontChain,
+    text_glyphs: &TextMeshGlyphs,
+    material: &MeshMaterial3d<StandardMaterial>,
+) -> usize {
+    let style = &text_glyphs.style;
+    let line_origin = line_offset(line_index, line_step);
+    let mut shown = 0;
+
+    for glyph in line_glyphs(line, ends_paragraph, fonts, style) {
+        let glyph_mesh = GlyphMesh {
+            char_index: char_index + glyph.index,
+            line_index,
+            character: glyph.character,
+            origin: line_origin + Vec3::new(glyph.x, glyph.y, 0.0),
+        };
+        let material = text_glyphs
+            .glyph_material(glyph_mesh.char_index, glyph.character)
+            .unwrap_or(&material.0)
+            .clone();
+        shown += usize::from(pool.place(
+            commands, meshes, parent, glyph_mesh, &glyph, style, material,
+        ));
+    }
+    shown
+}
+
+/// The mesh of one glyph around the pivot of the style, if it has any geometry, and
+/// the offset of the pivot from the glyph origin.
+pub(crate) fn build_glyph_mesh(glyph: &LineGlyph, style: &TextMeshStyle) -> Option<(Mesh, Vec3)> {
+    let mut glyph_mesh_data = GlyphGeometry::new(glyph.face, glyph.source, style)?;
+    glyph_mesh_data.handcraft(style, glyph.character, glyph.index);
+    let pivot = glyph_mesh_data.pivot(style.glyph_pivot).extend(0.0);
+
+    let mut data = TextMeshData {
+        vertices: glyph_mesh_data
+            .vertices
+            .iter()
+            .map(|v| (*v - pivot).to_array())
+            .collect(),
+        normals: glyph_mesh_data
+            .normals
+            .iter()
+            .map(|n| [n.x, n.y, n.z])
+            .collect(),
+        uvs: if style.has_uvs() {
+            glyph_uvs(&glyph_mesh_data)
+        } else {
+            Vec::new()
+        },
+        indices: glyph_mesh_data.indices,
+        ..default()
+    };
+    if style.generate_tangents {
+        data.generate_tangents();
+    }
+    Some((data.into_mesh(), pivot))
+}
+
 /// Helper function to generate a mesh for a single character.
 ///
 /// This can be used to create individual glyph meshes outside of the system,
-/// for example when you need to update a specific character's material.
+/// for example when you need to update a specific character's material. The
+/// [`GlyphMeshes`](crate::GlyphMeshes) resource does the same with every option of
+/// [`TextMeshStyle`], and hands out one shared handle per glyph instead of a new mesh.
 pub fn generate_glyph_mesh(
     face: &fontmesh::Face,
     character: char,
     depth: f32,
     subdivision: u8,
 ) -> Option<Mesh> {
-    let mesh_res = fontmesh::char_to_mesh_3d(face, character, depth, subdivision);
-
-    mesh_res.ok().map(|glyph_mesh_data| {
+    GlyphGeometry::extruded(face, character, depth, subdivision).map(|glyph_mesh_data| {
         let vertices: Vec<_> = glyph_mesh_data
             .vertices
             .iter()
@@ -0,0 +1,615 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::{TextMesh, TextMeshBundle};
+use crate::core::fallback::FontChain;
+use crate::core::layout::layout_text_in;
+use crate::core::mesh::TextMeshData;
+use crate::core::style::{JustifyText, TextMeshStyle};
+use crate::FontMesh;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Lays out text in rows and columns, like a leaderboard or a stat screen.
+///
+/// Every cell of [`rows`](Self::rows) becomes a [`TextMesh`] child with a
+/// [`TextTableCell`] marker. Once the cells are generated they are moved into place:
+/// the table grows right and down from the entity's origin, every column is as wide as
+/// its widest cell or a fixed width, every row as tall as its tallest cell, and cells
+/// are aligned within their column by [`TextTableColumn::align`], or lined up on their
+/// decimal separator in [`numeric`](TextTableColumn::numeric) columns. Cells are
+/// measured by their line boxes, so cells of one row share a baseline whatever their
+/// letters. Optional [`TableRules`] draw lines between rows and columns.
+///
+/// Only the cells whose text changed are regenerated when the table is edited. The
+/// measured table is stored in a [`TextTableLayout`] on the entity. Tables are meant
+/// for horizontal text; text in a fixed-width column that is too long overflows it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TextTable {
+///             font: asset_server.load("fonts/font.ttf"),
+///             columns: vec![
+///                 TextTableColumn::fixed(1.5),
+///                 TextTableColumn::auto(),
+///                 TextTableColumn::auto().with_align(JustifyText::Right),
+///             ],
+///             rules: Some(TableRules::default()),
+///             ..default()
+///         }
+///         .with_row(["#", "Player", "Score"])
+///         .with_row(["1", "Ada", "12,400"])
+///         .with_row(["2", "Grace", "9,150"]),
+///         Transform::from_xyz(-4.0, 2.0, 0.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+#[require(Transform, Visibility)]
+pub struct TextTable {
+    /// Text of the cells, row by row. Rows may have different numbers of cells.
+    pub rows: Vec<Vec<String>>,
+    /// Width and alignment of the columns, from the left. Columns without an entry are
+    /// as wide as their widest cell and left-aligned.
+    pub columns: Vec<TextTableColumn>,
+    /// Font of the cells.
+    pub font: Handle<FontMesh>,
+    /// Style of the cells. Its justification is replaced by the column's alignment.
+    pub style: TextMeshStyle,
+    /// Material of the cells. The default handle gets the material of
+    /// [`FontMeshSettings`](crate::FontMeshSettings), like any other text.
+    pub material: Handle<StandardMaterial>,
+    /// Space between the text of a cell and the edges of its cell, horizontally and
+    /// vertically.
+    pub padding: Vec2,
+    /// Lines between and around the cells, or `None` for none.
+    pub rules: Option<TableRules>,
+}
+
+impl Default for TextTable {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            columns: Vec::new(),
+            font: Handle::default(),
+            style: TextMeshStyle::default(),
+            material: Handle::default(),
+            padding: Vec2::new(0.4, 0.1),
+            rules: None,
+        }
+    }
+}
+
+impl TextTable {
+    /// Add a row of cells below the existing ones.
+    pub fn with_row<T: Into<String>>(mut self, cells: impl IntoIterator<Item = T>) -> Self {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Width and alignment of a column of a [`TextTable`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextTableColumn {
+    /// Width of the column's text, without the table's padding.
+    pub width: ColumnWidth,
+    /// How cells are aligned within the column. In a column with a
+    /// [`decimal`](Self::decimal) separator, this aligns the block of lined-up numbers.
+    pub align: JustifyText,
+    /// Decimal separator the cells are lined up on, like the `.` in prices or the `,`
+    /// in European notation, or `None` to align whole cells.
+    ///
+    /// The first separator of every cell sits at the same position, and cells without
+    /// one line up as if it followed their last character, so `12`, `3.5`, and `0.25`
+    /// share their units digit. The cells use
+    /// [`tabular_figures`](TextMeshStyle::tabular_figures), so their digits line up too
+    /// in fonts with proportional figures. Only the first line of a cell is aligned.
+    pub decimal: Option<char>,
+}
+
+impl TextTableColumn {
+    /// A left-aligned column as wide as its widest cell.
+    pub fn auto() -> Self {
+        Self::default()
+    }
+
+    /// A left-aligned column of a fixed width.
+    pub fn fixed(width: f32) -> Self {
+        Self {
+            width: ColumnWidth::Fixed(width),
+            ..default()
+        }
+    }
+
+    /// A right-aligned column of numbers lined up on their `separator`, as wide as
+    /// its widest number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_fontmesh::prelude::*;
+    /// let prices = TextTableColumn::numeric('.');
+    /// assert_eq!(prices.decimal, Some('.'));
+    /// assert_eq!(prices.align, JustifyText::Right);
+    /// ```
+    pub fn numeric(separator: char) -> Self {
+        Self {
+            decimal: Some(separator),
+            align: JustifyText::Right,
+            ..default()
+        }
+    }
+
+    /// Align the cells of the column with `align`.
+    pub fn with_align(mut self, align: JustifyText) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// How wide a [`TextTableColumn`] is.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ColumnWidth {
+    /// As wide as the widest cell of the column.
+    #[default]
+    Auto,
+    /// A fixed width, in the table's local units.
+    Fixed(f32),
+}
+
+/// Lines drawn between and around the cells of a [`TextTable`].
+///
+/// The lines are flat quads facing the front, in a [`TextTableRules`] child of the
+/// table, centered on the edges of the cells.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct TableRules {
+    /// Thickness of the lines. Default: `0.03`.
+    pub thickness: f32,
+    /// Draw lines between rows. Default: `true`.
+    pub between_rows: bool,
+    /// Draw lines between columns. Default: `true`.
+    pub between_columns: bool,
+    /// Draw a frame around the table. Default: `false`.
+    pub border: bool,
+    /// Material of the lines. The default handle uses the material of the cells.
+    pub material: Handle<StandardMaterial>,
+}
+
+impl Default for TableRules {
+    fn default() -> Self {
+        Self {
+            thickness: 0.03,
+            between_rows: true,
+            between_columns: true,
+            border: false,
+            material: Handle::default(),
+        }
+    }
+}
+
+/// A cell of a [`TextTable`], spawned as a child of the table entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct TextTableCell {
+    /// Row of the cell, from the top.
+    pub row: usize,
+    /// Column of the cell, from the left.
+    pub column: usize,
+}
+
+/// Marker for the lines of a [`TextTable`] with [`TableRules`].
+///
+/// Spawned as a child of the table entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextTableRules;
+
+/// Measured columns and rows of a [`TextTable`], in the table's local space.
+///
+/// Inserted and kept up to date by the plugin whenever the cells are laid out, to
+/// place highlights or backplates behind rows and cells.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct TextTableLayout {
+    /// Width of every column, without padding.
+    pub column_widths: Vec<f32>,
+    /// Height of every row, without padding.
+    pub row_heights: Vec<f32>,
+    /// Padding of the table when it was laid out.
+    pub padding: Vec2,
+}
+
+impl TextTableLayout {
+    /// Width and height of the whole table, including padding.
+    pub fn size(&self) -> Vec2 {
+        let padding = self.padding * 2.0;
+        Vec2::new(
+            self.column_widths
+                .iter()
+                .map(|width| width + padding.x)
+                .sum(),
+            self.row_heights
+                .iter()
+                .map(|height| height + padding.y)
+                .sum(),
+        )
+    }
+
+    /// Box of a cell, including padding, or `None` if it is outside the table.
+    pub fn cell_rect(&self, row: usize, column: usize) -> Option<Rect> {
+        let (x, width) = slot(&self.column_widths, column, self.padding.x)?;
+        let (y, height) = slot(&self.row_heights, row, self.padding.y)?;
+        Some(Rect::new(x, -y - height, x + width, -y))
+    }
+
+    /// Box of a whole row, including padding, or `None` if it is outside the table.
+    pub fn row_rect(&self, row: usize) -> Option<Rect> {
+        let (y, height) = slot(&self.row_heights, row, self.padding.y)?;
+        Some(Rect::new(0.0, -y - height, self.size().x, -y))
+    }
+}
+
+/// Distance of the slot at `index` from the start of the table, and its size.
+fn slot(sizes: &[f32], index: usize, padding: f32) -> Option<(f32, f32)> {
+    let size = sizes.get(index)? + padding * 2.0;
+    let start = sizes[..index].iter().map(|size| size + padding * 2.0).sum();
+    Some((start, size))
+}
+
+type TextTableCellQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TextTableCell,
+        &'static mut TextMesh,
+        &'static mut MeshMaterial3d<StandardMaterial>,
+    ),
+>;
+
+/// System that spawns, updates, and despawns the cells of changed [`TextTable`]s.
+pub fn update_text_table_cells(
+    mut commands: Commands,
+    tables: Query<(Entity, &TextTable, Option<&Children>), Changed<TextTable>>,
+    mut cells: TextTableCellQuery,
+    rules: Query<(), With<TextTableRules>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextTable>,
+) {
+    // 1. Despawn the cells and lines of entities that are no longer tables
+    for entity in removed.read() {
+        let Ok(children) = children.get(entity) else {
+            continue;
+        };
+        for child in children.iter() {
+            if cells.contains(child) || rules.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        commands.entity(entity).remove::<TextTableLayout>();
+    }
+
+    for (entity, table, children) in tables.iter() {
+        let mut existing: HashMap<(usize, usize), Entity> = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| {
+                let (cell, ..) = cells.get(child).ok()?;
+                Some(((cell.row, cell.column), child))
+            })
+            .collect();
+
+        // 2. Edit the cells that exist, touching only those whose content changed
+        for (row, texts) in table.rows.iter().enumerate() {
+            for (column, text) in texts.iter().enumerate() {
+                let text_mesh = TextMesh {
+                    text: text.clone(),
+                    font: table.font.clone(),
+                    style: cell_style(table, column),
+                    ..default()
+                };
+                let Some(cell) = existing.remove(&(row, column)) else {
+                    commands.spawn((
+                        TextMeshBundle {
+                            text_mesh,
+                            material: MeshMaterial3d(table.material.clone()),
+                            ..default()
+                        },
+                        TextTableCell { row, column },
+                        ChildOf(entity),
+                    ));
+                    continue;
+                };
+                let Ok((_, mut old, mut material)) = cells.get_mut(cell) else {
+                    continue;
+                };
+                if old.text != text_mesh.text
+                    || old.font != text_mesh.font
+                    || old.style != text_mesh.style
+                {
+                    *old = text_mesh;
+                }
+                if table.material.id() != AssetId::default() && material.0 != table.material {
+                    material.0 = table.material.clone();
+                }
+            }
+        }
+
+        // 3. Despawn the cells that are gone
+        for cell in existing.into_values() {
+            commands.entity(cell).despawn();
+        }
+    }
+}
+
+/// Style of the cells of `column`, justified like the column is aligned, with
+/// tabular figures in numeric columns.
+fn cell_style(table: &TextTable, column: usize) -> TextMeshStyle {
+    let column = table.columns.get(column).copied().unwrap_or_default();
+    TextMeshStyle {
+        justify: column.align,
+        tabular_figures: table.style.tabular_figures || column.decimal.is_some(),
+        ..table.style.clone()
+    }
+}
+
+/// Width of the first line of `text` up to its first `separator`, or of the whole line
+/// if it has none, measured from the start of the line.
+fn integer_width(fonts: &FontChain, text: &str, style: &TextMeshStyle, separator: char) -> f32 {
+    let layout = layout_text_in(fonts, text, style);
+    let Some(line) = layout.lines.first() else {
+        return 0.0;
+    };
+    layout.glyphs[line.glyphs.clone()]
+        .iter()
+        .find(|glyph| glyph.character == separator)
+        .map_or(line.width, |glyph| glyph.position.x - line.start)
+}
+
+/// Left edge, right edge, top, and bottom of the line boxes of a cell.
+fn cell_extent(bounds: &TextMeshBounds) -> (f32, f32, f32, f32) {
+    bounds
+        .line_rects
+        .iter()
+        .map(|rect| (rect.min.x, rect.max.x, rect.max.y, rect.min.y))
+        .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1), a.2.max(b.2), a.3.min(b.3)))
+        .unwrap_or((bounds.min.x, bounds.max.x, bounds.max.y, bounds.min.y))
+}
+
+type TableCellQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TextTableCell,
+        &'static TextMesh,
+        Ref<'static, TextMeshBounds>,
+        &'static mut Transform,
+        &'static MeshMaterial3d<StandardMaterial>,
+    ),
+>;
+
+type TableRulesQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Mesh3d,
+        &'static mut MeshMaterial3d<StandardMaterial>,
+    ),
+    (With<TextTableRules>, Without<TextTableCell>),
+>;
+
+/// System that moves the cells of [`TextTable`]s into their rows and columns and builds
+/// their [`TableRules`].
+///
+/// Runs after mesh generation, so cells are placed by their current bounds.
+pub fn layout_text_tables(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    tables: Query<(Entity, Ref<TextTable>, &Children)>,
+    mut cells: TableCellQuery,
+    mut rules: TableRulesQuery,
+) {
+    for (entity, table, children) in tables.iter() {
+        // 1. Wait until every cell is generated, and only redo changed tables
+        let mut changed = table.is_changed();
+        let mut generated = Vec::new();
+        let mut cell_material = None;
+        for child in children.iter() {
+            if let Ok((&cell, _, bounds, _, material)) = cells.get(child) {
+                changed |= bounds.is_changed();
+                generated.push((child, cell, cell_extent(&bounds)));
+                cell_material.get_or_insert_with(|| material.0.clone());
+            }
+        }
+        let cell_count: usize = table.rows.iter().map(Vec::len).sum();
+        if !changed || generated.len() != cell_count {
+            continue;
+        }
+
+        // Numbers are measured up to their separator to line them up on it
+        let measured: Vec<_> = generated
+            .into_iter()
+            .map(|(child, cell, extent)| {
+                let decimal = table
+                    .columns
+                    .get(cell.column)
+                    .and_then(|column| column.decimal);
+                let integer = decimal.and_then(|separator| {
+                    let (_, text_mesh, bounds, ..) = cells.get(child).ok()?;
+                    let style = &text_mesh.style;
+                    let fonts = FontChain::for_style(&font_assets, &text_mesh.font, style)?;
+                    // The first line may start right of the cell's left edge, when a
+                    // longer line below it is justified the same way
+                    let (left, ..) = extent;
+                    let line_start = bounds.line_rects.first().map_or(left, |rect| rect.min.x);
+                    let integer = integer_width(&fonts, &text_mesh.text, style, separator);
+                    Some(integer + line_start - left)
+                });
+                (child, cell, extent, integer)
+            })
+            .collect();
+
+        // 2. Size the columns and rows, and the parts of numbers around their separator
+        let column_count = table
+            .rows
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(table.columns.len());
+        let mut column_widths = vec![0.0f32; column_count];
+        let mut numbers = vec![(0.0f32, 0.0f32); column_count];
+        let mut row_heights = vec![0.0f32; table.rows.len()];
+        for &(_, cell, (left, right, top, bottom), integer) in &measured {
+            let width = right - left;
+            if let Some(integer) = integer {
+                let (integers, fractions) = &mut numbers[cell.column];
+                *integers = integers.max(integer);
+                *fractions = fractions.max(width - integer);
+            }
+            column_widths[cell.column] = column_widths[cell.column].max(width);
+            row_heights[cell.row] = row_heights[cell.row].max(top - bottom);
+        }
+        for (width, &(integers, fractions)) in column_widths.iter_mut().zip(&numbers) {
+            *width = width.max(integers + fractions);
+        }
+        for (width, column) in column_widths.iter_mut().zip(&table.columns) {
+            if let ColumnWidth::Fixed(fixed) = column.width {
+                *width = fixed;
+            }
+        }
+        let layout = TextTableLayout {
+            column_widths,
+            row_heights,
+            padding: table.padding,
+        };
+
+        // 3. Move every cell into place, aligned within its column
+        for &(child, cell, (left, right, top, _), integer) in &measured {
+            let (Some(slot), Ok((_, _, _, mut transform, _))) = (
+                layout.cell_rect(cell.row, cell.column),
+                cells.get_mut(child),
+            ) else {
+                continue;
+            };
+
+            // Numbers are aligned as one block, with their separators at the same spot
+            let (block, indent) = match integer {
+                Some(integer) => {
+                    let (integers, fractions) = numbers[cell.column];
+                    (integers + fractions, integers - integer)
+                }
+                None => (right - left, 0.0),
+            };
+            let free = layout.column_widths[cell.column] - block;
+            let align = table
+                .columns
+                .get(cell.column)
+                .map(|column| column.align)
+                .unwrap_or_default();
+            let offset = match align {
+                JustifyText::Left | JustifyText::Justified => 0.0,
+                JustifyText::Center => free * 0.5,
+                JustifyText::Right => free,
+            };
+            let x = slot.min.x + table.padding.x + offset + indent - left;
+            let y = slot.max.y - table.padding.y - top;
+            if transform.translation.truncate() != Vec2::new(x, y) {
+                transform.translation.x = x;
+                transform.translation.y = y;
+            }
+        }
+
+        // 4. Draw the rules
+        let existing = children.iter().find(|&child| rules.contains(child));
+        match &table.rules {
+            Some(table_rules) => {
+                let mesh = meshes.add(rules_data(&layout, table_rules).into_mesh());
+                let material = if table_rules.material.id() == AssetId::default() {
+                    cell_material.unwrap_or_default()
+                } else {
+                    table_rules.material.clone()
+                };
+                match existing.and_then(|child| rules.get_mut(child).ok()) {
+                    Some((mut old_mesh, mut old_material)) => {
+                        old_mesh.0 = mesh;
+                        if old_material.0 != material {
+                            old_material.0 = material;
+                        }
+                    }
+                    None => {
+                        commands.entity(entity).with_child((
+                            TextTableRules,
+                            Mesh3d(mesh),
+                            MeshMaterial3d(material),
+                            Transform::default(),
+                        ));
+                    }
+                }
+            }
+            None => {
+                if let Some(child) = existing {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        commands.entity(entity).insert(layout);
+    }
+}
+
+/// Geometry of the lines of a laid-out table.
+fn rules_data(layout: &TextTableLayout, rules: &TableRules) -> TextMeshData {
+    let size = layout.size();
+    let half = rules.thickness * 0.5;
+    let mut data = TextMeshData::default();
+
+    // Edges between slots, and the outer edges if the table has a border
+    let edges = |sizes: &[f32], padding: f32, between: bool| -> Vec<f32> {
+        let mut edges = Vec::new();
+        let mut position = 0.0;
+        for (index, size) in sizes.iter().enumerate() {
+            if index == 0 && rules.border || index > 0 && between {
+                edges.push(position);
+            }
+            position += size + padding * 2.0;
+        }
+        if rules.border && !sizes.is_empty() {
+            edges.push(position);
+        }
+        edges
+    };
+    for x in edges(
+        &layout.column_widths,
+        layout.padding.x,
+        rules.between_columns,
+    ) {
+        add_quad(
+            &mut data,
+            Vec2::new(x - half, -size.y - half),
+            Vec2::new(x + half, half),
+        );
+    }
+    for y in edges(&layout.row_heights, layout.padding.y, rules.between_rows) {
+        add_quad(
+            &mut data,
+            Vec2::new(-half, -y - half),
+            Vec2::new(size.x + half, -y + half),
+        );
+    }
+    data
+}
+
+/// An axis-aligned rectangle facing the front.
+fn add_quad(data: &mut TextMeshData, min: Vec2, max: Vec2) {
+    let first = data.vertices.len() as u32;
+    for corner in [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)] {
+        data.vertices.push([corner.x, corner.y, 0.0]);
+        data.normals.push([0.0, 0.0, 1.0]);
+    }
+    data.indices
+        .extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+}
@@ -0,0 +1,230 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::layout::vertical_extent;
+use crate::core::layout::{calculate_line_height, paragraph_lines};
+use crate::core::mesh::{
+    build_line_data, line_colors, lines_anchor_offset, stack_lines, TextMeshData,
+};
+use crate::lazy::TextMeshDeferred;
+use crate::path::TextPath;
+use crate::system::{update_aabb, TextMeshComputed, TextMeshReady};
+use crate::ticker::TextTicker;
+use crate::FontMesh;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy::tasks::{futures::check_ready, AsyncComputeTaskPool, Task, TaskPool};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Opt-in marker that moves [`TextMesh`] generation off the main schedule.
+///
+/// Entities with this component have their mesh tessellated in a task on the
+/// [`AsyncComputeTaskPool`]. While the task runs, the entity carries a
+/// [`TextMeshTask`] component and keeps displaying its previous mesh; the new mesh is
+/// swapped in on the first frame after the task completes.
+///
+/// This is most useful for long paragraphs or high subdivision values, where building
+/// the mesh would otherwise cause a visible frame hitch.
+///
+/// The task parses one font and tessellates one line at a time, and yields between them
+/// once it has run for about 8 ms. On WebAssembly, where tasks run on the browser's main
+/// thread, it waits for the event loop when it yields, so the page keeps rendering and
+/// handling input while large fonts are parsed and long text is built.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "A very long paragraph...".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshAsync,
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextMeshAsync;
+
+/// In-flight background generation task for a [`TextMeshAsync`] entity.
+///
+/// Present while a new mesh is being generated and removed once it has been applied.
+/// Query `With<TextMeshTask>` to find text that is still pending. Editing the
+/// [`TextMesh`] while a task is running cancels it and starts a new one.
+#[derive(Component)]
+pub struct TextMeshTask(Task<Option<(TextMeshData, TextMeshBounds)>>);
+
+type TextMeshAsyncQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static TextMesh),
+    (
+        With<TextMeshAsync>,
+        Without<TextPath>,
+        Without<TextTicker>,
+        Without<TextMeshDeferred>,
+        Or<(Changed<TextMesh>, Without<TextMeshComputed>)>,
+    ),
+>;
+
+/// System that starts background generation tasks for [`TextMeshAsync`] entities.
+pub fn spawn_text_mesh_tasks(
+    mut commands: Commands,
+    font_assets: Res<Assets<FontMesh>>,
+    query: TextMeshAsyncQuery,
+) {
+    // Worlds without the task pool plugin (e.g. secondary sub-apps) still get a pool
+    let pool = AsyncComputeTaskPool::get_or_init(TaskPool::default);
+
+    for (entity, text_mesh) in query.iter() {
+        // The task must own everything it reads, including every fallback font. The
+        // font data is shared with the assets rather than copied for every edit.
+        let Some(data) = std::iter::once(&text_mesh.font)
+            .chain(&text_mesh.style.fallback_fonts)
+            .map(|handle| {
+                font_assets
+                    .get(handle)
+                    .map(|asset| (Arc::clone(&asset.data), asset.face_index))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            // Fonts not loaded yet, skip this frame
+            continue;
+        };
+        let text = text_mesh.text.clone();
+        let style = text_mesh.style.clone();
+        let colors = text_mesh.vertex_colors();
+        let gradient = text_mesh.gradient;
+
+        let task = pool.spawn(async move {
+            let mut budget = YieldBudget::new();
+            let mut data = data.iter();
+            let (primary, index) = data.next()?;
+            let mut fonts = FontChain::new(fontmesh::Face::parse(primary, *index).ok()?);
+            for (data, index) in data {
+                budget.tick().await;
+                fonts.push_fallback(data, *index);
+            }
+            budget.tick().await;
+            let fonts = fonts.with_style(&style);
+
+            let mut lines = Vec::new();
+            for (line, ends_paragraph) in paragraph_lines(&text) {
+                budget.tick().await;
+                lines.push(build_line_data(line, ends_paragraph, &fonts, &style));
+            }
+
+            let line_height = calculate_line_height(&fonts, &style);
+            let line_step = style.orientation.line_step(line_height);
+            let anchor_offset = lines_anchor_offset(&lines, line_step, style.anchor);
+            let bounds = TextMeshBounds::from_lines(
+                &lines,
+                style.orientation,
+                line_height,
+                anchor_offset,
+                vertical_extent(&fonts, &style),
+            );
+            let mut data = stack_lines(&lines, line_step, anchor_offset);
+            if !colors.is_empty() {
+                data.colors = line_colors(text.split('\n').zip(&lines), &colors);
+            }
+            if let Some(gradient) = gradient {
+                gradient.apply(&mut data);
+            }
+            Some((data, bounds))
+        });
+
+        // Replacing an existing task drops it, which cancels the outdated generation
+        commands
+            .entity(entity)
+            .insert((TextMeshTask(task), TextMeshComputed));
+    }
+}
+
+/// System that applies the meshes of finished [`TextMeshTask`]s.
+pub fn apply_text_mesh_tasks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(Entity, &mut TextMeshTask, &mut Mesh3d)>,
+    mut ready: MessageWriter<TextMeshReady>,
+) {
+    for (entity, mut task, mut mesh_handle) in query.iter_mut() {
+        let Some(result) = check_ready(&mut task.0) else {
+            continue;
+        };
+
+        // A failed parse leaves the previous mesh in place
+        if let Some((data, bounds)) = result {
+            let mesh = data.into_mesh();
+            update_aabb(&mut commands, entity, &mesh);
+            mesh_handle.0 = meshes.add(mesh);
+            commands.entity(entity).insert(bounds);
+            ready.write(TextMeshReady { entity });
+        }
+
+        commands.entity(entity).remove::<TextMeshTask>();
+    }
+}
+
+/// How long a generation task runs before it lets other work run, about half a frame
+/// at 60 Hz.
+const YIELD_BUDGET: Duration = Duration::from_millis(8);
+
+/// Time a generation task has run since it last yielded.
+struct YieldBudget {
+    since: Instant,
+}
+
+impl YieldBudget {
+    fn new() -> Self {
+        Self {
+            since: Instant::now(),
+        }
+    }
+
+    /// Yield between chunks of work once the task has used up its [`YIELD_BUDGET`].
+    ///
+    /// Short texts finish without yielding, so they don't wait a frame for the event loop.
+    async fn tick(&mut self) {
+        if self.since.elapsed() >= YIELD_BUDGET {
+            yield_now().await;
+            self.since = Instant::now();
+        }
+    }
+}
+
+/// Let other work run between chunks of a generation task.
+///
+/// Dropped tasks are cancelled at these points, so outdated generations stop early.
+/// Browser tasks are polled from the microtask queue, where the page can't render or
+/// handle input, so on WebAssembly the task waits for a zero-delay timer and resumes
+/// from the event loop instead.
+async fn yield_now() {
+    #[cfg(not(target_arch = "wasm32"))]
+    bevy::tasks::futures_lite::future::yield_now().await;
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let timer = js_sys::Promise::new(&mut |resolve, _reject| {
+            set_timeout(&resolve, 0);
+        });
+        let _ = wasm_bindgen_futures::JsFuture::from(timer).await;
+    }
+}
+
+// The global `setTimeout` exists in both windows and workers
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> wasm_bindgen::JsValue;
+}
@@ -0,0 +1,183 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::{TextMesh, TextMeshBundle};
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{RenderTarget, ScalingMode};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+
+/// Settings for [`spawn_text_texture`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextTextureSettings {
+    /// Width and height of the texture, in pixels.
+    pub size: UVec2,
+    /// Color of the texture behind the text. Default: transparent.
+    pub clear_color: Color,
+    /// Margin kept around the text, in mesh units.
+    pub padding: f32,
+    /// Render layer shared by the text and its camera, so other cameras don't draw the
+    /// text and the texture camera draws nothing else. Default: `31`.
+    pub render_layer: usize,
+}
+
+impl Default for TextTextureSettings {
+    fn default() -> Self {
+        Self {
+            size: UVec2::new(512, 128),
+            clear_color: Color::NONE,
+            padding: 0.1,
+            render_layer: 31,
+        }
+    }
+}
+
+/// A text rendered into an image by [`spawn_text_texture`].
+///
+/// Lives on the text entity, next to its [`TextMesh`]. Edit the text mesh to change
+/// what the texture shows; the camera is refitted whenever the text is regenerated.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TextTexture {
+    /// The image the text is rendered into.
+    pub image: Handle<Image>,
+    /// Margin kept around the text, in mesh units.
+    pub padding: f32,
+}
+
+/// Marker for the orthographic camera that renders a [`TextTexture`].
+///
+/// Spawned as a child of the text entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextTextureCamera;
+
+/// Render a text mesh into a new image, for signs, screens, and monitors in the world.
+///
+/// Spawns the text together with an orthographic camera that renders only the text into
+/// an image of [`TextTextureSettings::size`] pixels, and returns the image. The camera
+/// frames the text's [`TextMeshBounds`] plus the padding, keeping the image's aspect
+/// ratio, and follows along when the text changes. Use the image as the
+/// `base_color_texture` of a screen's material.
+///
+/// The text is drawn with `material` and lit by the lights on the same render layer,
+/// so an unlit material is usually the simplest choice. The camera renders before the
+/// main cameras, so the texture is up to date in the same frame. Despawning the text
+/// entity, found through its [`TextTexture`] component, stops the rendering.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup_monitor(
+///     mut commands: Commands,
+///     asset_server: Res<AssetServer>,
+///     mut images: ResMut<Assets<Image>>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut materials: ResMut<Assets<StandardMaterial>>,
+/// ) {
+///     let text_material = materials.add(StandardMaterial {
+///         base_color: Color::srgb(0.2, 1.0, 0.4),
+///         unlit: true,
+///         ..default()
+///     });
+///     let image = spawn_text_texture(
+///         &mut commands,
+///         &mut images,
+///         TextMesh {
+///             text: "SYSTEM ONLINE".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         text_material,
+///         &TextTextureSettings::default(),
+///     );
+///
+///     commands.spawn((
+///         Mesh3d(meshes.add(Rectangle::new(4.0, 1.0))),
+///         MeshMaterial3d(materials.add(StandardMaterial {
+///             base_color_texture: Some(image),
+///             unlit: true,
+///             ..default()
+///         })),
+///     ));
+/// }
+/// ```
+pub fn spawn_text_texture(
+    commands: &mut Commands,
+    images: &mut Assets<Image>,
+    text_mesh: TextMesh,
+    material: Handle<StandardMaterial>,
+    settings: &TextTextureSettings,
+) -> Handle<Image> {
+    let image = images.add(Image::new_target_texture(
+        settings.size.x.max(1),
+        settings.size.y.max(1),
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+    let layer = RenderLayers::layer(settings.render_layer);
+
+    commands
+        .spawn((
+            TextMeshBundle {
+                text_mesh,
+                material: MeshMaterial3d(material),
+                ..default()
+            },
+            TextTexture {
+                image: image.clone(),
+                padding: settings.padding,
+            },
+            layer.clone(),
+        ))
+        .with_child((
+            TextTextureCamera,
+            Camera3d::default(),
+            Camera {
+                target: RenderTarget::Image(image.clone().into()),
+                clear_color: ClearColorConfig::Custom(settings.clear_color),
+                order: -1,
+                ..default()
+            },
+            Projection::Orthographic(OrthographicProjection::default_3d()),
+            // Framed by `fit_text_texture_cameras` once the text has bounds
+            Transform::from_xyz(0.0, 0.0, 1.0),
+            layer,
+        ));
+
+    image
+}
+
+type TextTextureQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TextTexture,
+        &'static TextMeshBounds,
+        &'static Children,
+    ),
+    Or<(Changed<TextMeshBounds>, Changed<TextTexture>)>,
+>;
+
+/// System to frame the text of every [`TextTexture`] with its camera.
+pub fn fit_text_texture_cameras(
+    texts: TextTextureQuery,
+    mut cameras: Query<(&mut Projection, &mut Transform), With<TextTextureCamera>>,
+) {
+    for (texture, bounds, children) in texts.iter() {
+        let size = bounds.size().truncate() + Vec2::splat(texture.padding * 2.0);
+        for child in children.iter() {
+            let Ok((mut projection, mut transform)) = cameras.get_mut(child) else {
+                continue;
+            };
+            *projection = Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::AutoMin {
+                    min_width: size.x.max(f32::EPSILON),
+                    min_height: size.y.max(f32::EPSILON),
+                },
+                ..OrthographicProjection::default_3d()
+            });
+            // In front of the text, looking down -Z at its center
+            transform.translation = bounds.center().with_z(bounds.max.z + 1.0);
+        }
+    }
+}
@@ -0,0 +1,504 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::extrude::GlyphGeometry;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{get_char_advance, vertical_extent};
+use crate::core::mesh::{calculate_anchor_offset, glyph_uvs, TextMeshData};
+use crate::core::style::TextMeshStyle;
+use crate::system::{update_aabb, TextMeshComputed};
+use crate::FontMesh;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+/// Distance glyph outlines may reach past their advance box, in mesh units.
+///
+/// Glyphs are only tessellated once their advance box comes within this distance of
+/// the window, so overhanging strokes don't pop in at the edges.
+const OVERHANG: f32 = 0.25;
+
+/// How a [`TextTicker`] treats glyphs at the edges of its window.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub enum TickerEdge {
+    /// Cut glyphs off sharply at the window edges.
+    #[default]
+    Clip,
+    /// Cut glyphs off at the window edges and fade them out over `width` mesh units
+    /// before each edge.
+    ///
+    /// The fade is stored in the alpha of `Mesh::ATTRIBUTE_COLOR`, so the material
+    /// needs an alpha mode that blends, such as [`AlphaMode::Blend`].
+    Fade {
+        /// Width of the fade at each edge, in mesh units.
+        width: f32,
+    },
+}
+
+/// Scrolls a [`TextMesh`] horizontally through a fixed-width window, like a news ticker.
+///
+/// Add it next to a [`TextMesh`]. The text is laid out as a single line (line breaks
+/// count as spaces) and moves to the left at [`TextTicker::speed`], entering the window
+/// from its right edge. Geometry outside the window is clipped away, optionally with a
+/// fade at the edges, so the mesh never grows beyond the window however long the text
+/// is. Glyphs are tessellated when they first enter the window and reused until they
+/// leave it.
+///
+/// The window spans `width` mesh units and is placed by the style's
+/// [`TextAnchor`](crate::TextAnchor), using the font's ascender and descender for its
+/// height. Justification has no effect on tickers.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(
+/// #     mut commands: Commands,
+/// #     asset_server: Res<AssetServer>,
+/// #     mut materials: ResMut<Assets<StandardMaterial>>,
+/// # ) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "BREAKING: Local developer ships ticker text".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         // Blended so the faded edges show
+///         material: MeshMaterial3d(materials.add(StandardMaterial {
+///             alpha_mode: AlphaMode::Blend,
+///             ..default()
+///         })),
+///         ..default()
+///     },
+///     TextTicker {
+///         width: 8.0,
+///         edge: TickerEdge::Fade { width: 1.0 },
+///         ..default()
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct TextTicker {
+    /// Width of the visible window, in mesh units.
+    pub width: f32,
+    /// Scroll speed in mesh units per second. Negative values scroll to the right.
+    ///
+    /// Scrolling needs Bevy's [`Time`]; set [`TextTicker::scroll`] yourself without it.
+    pub speed: f32,
+    /// Distance the text has scrolled, in mesh units.
+    ///
+    /// At `0.0` the text starts at the right edge of the window.
+    pub scroll: f32,
+    /// Space between the end of the text and its next repetition, in mesh units.
+    pub gap: f32,
+    /// Repeat the text endlessly. Otherwise it scrolls through the window once.
+    pub looping: bool,
+    /// How glyphs are cut off at the window edges.
+    pub edge: TickerEdge,
+}
+
+impl Default for TextTicker {
+    fn default() -> Self {
+        Self {
+            width: 6.0,
+            speed: 1.0,
+            scroll: 0.0,
+            gap: 2.0,
+            looping: true,
+            edge: TickerEdge::Clip,
+        }
+    }
+}
+
+/// A visible character of a ticker line.
+struct TickerGlyph {
+    character: char,
+    /// Position of the glyph origin along the line.
+    x: f32,
+    advance: f32,
+}
+
+/// Tessellated ticker glyph, relative to its origin.
+struct TickerGlyphMesh {
+    data: TextMeshData,
+    min_x: f32,
+    max_x: f32,
+}
+
+/// Layout and glyph geometry of a [`TextTicker`], kept between frames.
+#[derive(Component)]
+pub(crate) struct TextTickerCache {
+    font: AssetId<FontMesh>,
+    text: String,
+    style: TextMeshStyle,
+    glyphs: Vec<TickerGlyph>,
+    line_width: f32,
+    ascender: f32,
+    descender: f32,
+    /// Geometry of the glyphs in or near the window, by index into `glyphs`.
+    meshes: HashMap<usize, TickerGlyphMesh>,
+    mesh: AssetId<Mesh>,
+}
+
+impl TextTickerCache {
+    /// Lay out the text of `text_mesh` as a single line.
+    fn new(text_mesh: &TextMesh, fonts: &FontChain) -> Self {
+        let style = &text_mesh.style;
+        let mut glyphs = Vec::new();
+        let mut cursor_x = 0.0;
+        let mut count = 0;
+        for ch in text_mesh.text.chars() {
+            let Some(advance) = get_char_advance(ch, fonts, style) else {
+                continue;
+            };
+            if !ch.is_whitespace() {
+                glyphs.push(TickerGlyph {
+                    character: ch,
+                    x: cursor_x,
+                    advance,
+                });
+            }
+            cursor_x += advance + style.letter_spacing;
+            count += 1;
+        }
+        // Letter spacing goes between characters, not after the last one
+        let line_width = cursor_x - if count > 0 { style.letter_spacing } else { 0.0 };
+
+        let (ascender, descender) = vertical_extent(fonts, style);
+        Self {
+            font: text_mesh.font.id(),
+            text: text_mesh.text.clone(),
+            style: style.clone(),
+            glyphs,
+            line_width,
+            ascender,
+            descender,
+            meshes: HashMap::default(),
+            mesh: AssetId::default(),
+        }
+    }
+
+    /// Whether the cached layout still matches `text_mesh`.
+    fn is_compatible(&self, text_mesh: &TextMesh) -> bool {
+        self.font == text_mesh.font.id()
+            && self.text == text_mesh.text
+            && self.style == text_mesh.style
+    }
+
+    /// Distance between two repetitions of the text.
+    fn period(&self, ticker: &TextTicker) -> f32 {
+        self.line_width + ticker.gap.max(0.0)
+    }
+
+    /// Build the visible part of the text, in window space with the window spanning
+    /// `0..width`, tessellating glyphs that entered the window.
+    fn assemble(&mut self, ticker: &TextTicker, fonts: &FontChain) -> TextMeshData {
+        let width = ticker.width.max(0.0);
+        let period = self.period(ticker);
+
+        // Repetitions of the text that overlap the window
+        let repetitions = if ticker.looping && period > 0.0 {
+            let first = ((ticker.scroll - width - self.line_width) / period).floor() as i64;
+            let last = (ticker.scroll / period).ceil() as i64;
+            first..=last
+        } else {
+            0..=0
+        };
+
+        let mut data = TextMeshData::default();
+        let mut used = HashSet::new();
+        for repetition in repetitions {
+            let start = width - ticker.scroll + repetition as f32 * period;
+            // Glyphs are sorted by position, so skip straight to the first visible one
+            let first = self
+                .glyphs
+                .partition_point(|glyph| start + glyph.x + glyph.advance + OVERHANG < 0.0);
+            for index in first..self.glyphs.len() {
+                let x = start + self.glyphs[index].x;
+                if x - OVERHANG > width {
+                    break;
+                }
+
+                let Some(mesh) = self.glyph_mesh(index, fonts) else {
+                    continue;
+                };
+                if x + mesh.min_x >= 0.0 && x + mesh.max_x <= width {
+                    append_glyph(&mut data, &mesh.data, x);
+                } else {
+                    clip_glyph(&mut data, &mesh.data, x, width);
+                }
+                used.insert(index);
+            }
+        }
+
+        // Forget glyphs that left the window
+        self.meshes.retain(|index, _| used.contains(index));
+        data
+    }
+
+    /// Geometry of the glyph at `index`, tessellating it on first use.
+    fn glyph_mesh(&mut self, index: usize, fonts: &FontChain) -> Option<&TickerGlyphMesh> {
+        if !self.meshes.contains_key(&index) {
+            let character = self.glyphs[index].character;
+            let mut geometry = fonts
+                .resolve(character, self.style.missing_glyph)
+                .and_then(|(face, source)| GlyphGeometry::new(face, source, &self.style))?;
+            geometry.handcraft(&self.style, character, index);
+            self.meshes
+                .insert(index, TickerGlyphMesh::new(&geometry, &self.style));
+        }
+        self.meshes.get(&index)
+    }
+}
+
+impl TickerGlyphMesh {
+    fn new(geometry: &GlyphGeometry, style: &TextMeshStyle) -> Self {
+        let (min_x, max_x) = geometry
+            .vertices
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(min, max), v| {
+                (min.min(v.x), max.max(v.x))
+            });
+        Self {
+            data: TextMeshData {
+                vertices: geometry.vertices.iter().map(|v| v.to_array()).collect(),
+                normals: geometry.normals.iter().map(|n| n.to_array()).collect(),
+                uvs: if style.has_uvs() {
+                    glyph_uvs(geometry)
+                } else {
+                    Vec::new()
+                },
+                indices: geometry.indices.clone(),
+                ..default()
+            },
+            min_x,
+            max_x,
+        }
+    }
+}
+
+/// Append a glyph that lies entirely inside the window, moved right by `x`.
+fn append_glyph(data: &mut TextMeshData, glyph: &TextMeshData, x: f32) {
+    let index_offset = data.vertices.len() as u32;
+    data.vertices
+        .extend(glyph.vertices.iter().map(|v| [v[0] + x, v[1], v[2]]));
+    data.normals.extend_from_slice(&glyph.normals);
+    data.uvs.extend_from_slice(&glyph.uvs);
+    data.indices
+        .extend(glyph.indices.iter().map(|i| i + index_offset));
+}
+
+/// A vertex being clipped, with its attributes.
+#[derive(Clone, Copy)]
+struct ClipVertex {
+    position: Vec3,
+    normal: Vec3,
+    uv: Vec2,
+}
+
+impl ClipVertex {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            position: self.position.lerp(other.position, t),
+            normal: self.normal.lerp(other.normal, t).normalize_or_zero(),
+            uv: self.uv.lerp(other.uv, t),
+        }
+    }
+}
+
+/// Append the part of a glyph, moved right by `x`, that lies inside `0..=width`.
+fn clip_glyph(data: &mut TextMeshData, glyph: &TextMeshData, x: f32, width: f32) {
+    let has_uvs = !glyph.uvs.is_empty();
+    let vertex = |i: u32| {
+        let i = i as usize;
+        let [vx, vy, vz] = glyph.vertices[i];
+        ClipVertex {
+            position: Vec3::new(vx + x, vy, vz),
+            normal: Vec3::from_array(glyph.normals[i]),
+            uv: glyph
+                .uvs
+                .get(i)
+                .map_or(Vec2::ZERO, |uv| Vec2::from_array(*uv)),
+        }
+    };
+
+    for triangle in glyph.indices.chunks_exact(3) {
+        let polygon = vec![
+            vertex(triangle[0]),
+            vertex(triangle[1]),
+            vertex(triangle[2]),
+        ];
+        let polygon = clip_polygon(polygon, |p| p.x);
+        let polygon = clip_polygon(polygon, |p| width - p.x);
+        if polygon.len() < 3 {
+            continue;
+        }
+
+        // The clipped triangle is convex, so fan it out from its first corner
+        let first = data.vertices.len() as u32;
+        for corner in &polygon {
+            data.vertices.push(corner.position.to_array());
+            data.normals.push(corner.normal.to_array());
+            if has_uvs {
+                data.uvs.push(corner.uv.to_array());
+            }
+        }
+        for i in 1..polygon.len() as u32 - 1 {
+            data.indices.extend([first, first + i, first + i + 1]);
+        }
+    }
+}
+
+/// Clip a convex polygon to the side where `distance` is non-negative.
+fn clip_polygon(polygon: Vec<ClipVertex>, distance: impl Fn(Vec3) -> f32) -> Vec<ClipVertex> {
+    if polygon.iter().all(|v| distance(v.position) >= 0.0) {
+        return polygon;
+    }
+
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (d_current, d_next) = (distance(current.position), distance(next.position));
+        if d_current >= 0.0 {
+            clipped.push(current);
+        }
+        if (d_current >= 0.0) != (d_next >= 0.0) {
+            clipped.push(current.lerp(next, d_current / (d_current - d_next)));
+        }
+    }
+    clipped
+}
+
+type TextTickerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMesh>,
+        &'static mut TextTicker,
+        &'static mut Mesh3d,
+        Option<&'static mut TextTickerCache>,
+        Option<&'static TextMeshBounds>,
+    ),
+>;
+
+/// System that scrolls [`TextTicker`]s and rebuilds the visible part of their text.
+pub fn update_text_tickers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    time: Option<Res<Time>>,
+    mut tickers: TextTickerQuery,
+    mut removed: RemovedComponents<TextTicker>,
+) {
+    // Text that stops being a ticker is laid out normally again
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<(TextTickerCache, TextMeshComputed)>();
+        }
+    }
+
+    let delta = time.map_or(0.0, |time| time.delta_secs());
+    for (entity, text_mesh, mut ticker, mut mesh_handle, cache, bounds) in tickers.iter_mut() {
+        // 1. Parse the font and its fallbacks (no caching needed as parsing is lightweight)
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            // Fonts not loaded yet or unparsable, skip this entity
+            continue;
+        };
+
+        // 2. Lay the line out again only when the text, font, or style changed
+        let rebuilt = !cache
+            .as_ref()
+            .is_some_and(|cache| cache.is_compatible(&text_mesh));
+        let mut new_cache = None;
+        let cache = match cache {
+            Some(cache) if !rebuilt => cache.into_inner(),
+            _ => new_cache.insert(TextTickerCache::new(&text_mesh, &fonts)),
+        };
+
+        // 3. Scroll, wrapping around so the position stays small
+        if ticker.speed != 0.0 && delta > 0.0 {
+            ticker.scroll += ticker.speed * delta;
+            let period = cache.period(&ticker);
+            if ticker.looping && period > 0.0 {
+                ticker.scroll = ticker.scroll.rem_euclid(period);
+            }
+        }
+        let unchanged = !ticker.is_changed()
+            && !rebuilt
+            && cache.mesh == mesh_handle.id()
+            && !text_mesh.is_changed();
+        if unchanged {
+            continue;
+        }
+
+        // 4. Build the visible glyphs, fading them at the edges
+        let mut data = cache.assemble(&ticker, &fonts);
+        let colors = match ticker.edge {
+            TickerEdge::Clip => None,
+            TickerEdge::Fade { width: fade } => Some(
+                data.vertices
+                    .iter()
+                    .map(|v| {
+                        let edge_distance = v[0].min(ticker.width - v[0]);
+                        let alpha = if fade > 0.0 {
+                            (edge_distance / fade).clamp(0.0, 1.0)
+                        } else {
+                            1.0
+                        };
+                        [1.0, 1.0, 1.0, alpha]
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
+        // 5. Place the window by the text anchor
+        let window_min = Vec3::new(0.0, cache.descender, 0.0);
+        let window_max = Vec3::new(ticker.width, cache.ascender, 0.0);
+        let offset = calculate_anchor_offset(text_mesh.style.anchor, window_min, window_max);
+        for vertex in &mut data.vertices {
+            vertex[0] += offset.x;
+            vertex[1] += offset.y;
+        }
+
+        // 6. The window is the extent of the text, whatever is scrolling through it
+        let window = TextMeshBounds {
+            min: window_min + offset,
+            max: window_max + offset,
+            line_rects: vec![Rect::from_corners(
+                (window_min + offset).truncate(),
+                (window_max + offset).truncate(),
+            )],
+        };
+        if bounds != Some(&window) {
+            commands.entity(entity).insert(window);
+        }
+
+        // 7. Write the mesh, reusing the ticker's own mesh asset
+        if text_mesh.style.generate_tangents {
+            data.generate_tangents();
+        }
+        let mut mesh = data.into_mesh();
+        if let Some(colors) = colors {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        }
+        update_aabb(&mut commands, entity, &mesh);
+        match meshes
+            .get_mut(&mesh_handle.0)
+            .filter(|_| cache.mesh == mesh_handle.id())
+        {
+            Some(existing) => *existing = mesh,
+            None => {
+                mesh_handle.0 = meshes.add(mesh);
+                cache.mesh = mesh_handle.id();
+            }
+        }
+
+        if let Some(new_cache) = new_cache {
+            commands.entity(entity).insert(new_cache);
+        }
+    }
+}
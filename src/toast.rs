@@ -0,0 +1,257 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::{TextMesh, TextMeshBundle};
+use crate::core::style::TextMeshStyle;
+use crate::lifetime::TextMeshExpiry;
+use crate::lifetime::TextMeshLifetime;
+use crate::plugin::FontMeshPluginConfig;
+use crate::{FontMesh, FontMeshSystems};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+
+/// Plugin that shows the toasts of [`Toast3dStack`]s.
+///
+/// Needs [`FontMeshPlugin`](crate::FontMeshPlugin) to generate the toast meshes and
+/// Bevy's [`Time`] to expire them. The toasts are updated in the schedule and set of the
+/// `FontMeshPlugin` or its [`FontMeshPluginConfig`], right before [`FontMeshSystems`], so
+/// pushed toasts are generated on the same frame. Add this plugin after the
+/// `FontMeshPlugin`; without one it runs in [`Update`].
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_fontmesh::prelude::*;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins((FontMeshPlugin, Toast3dPlugin))
+///     .run();
+/// ```
+pub struct Toast3dPlugin;
+
+impl Plugin for Toast3dPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Toast3dStack>()
+            .register_type::<Toast3d>();
+
+        let FontMeshPluginConfig { schedule, set } = FontMeshPluginConfig::of(app);
+
+        let mut system = update_toast_stacks
+            .run_if(resource_exists::<Time>)
+            .before(FontMeshSystems);
+        // Re-flowing moves transforms, so it has to run before propagation
+        if schedule == PostUpdate.intern() {
+            system = system.before(TransformSystems::Propagate);
+        }
+        if let Some(set) = set {
+            system = system.in_set(set);
+        }
+        app.add_systems(schedule, system);
+    }
+}
+
+/// A stack of short-lived notifications in world space.
+///
+/// Texts pushed with [`push`](Self::push) are spawned as children of this entity, one
+/// below the other from its origin down, oldest first. Each toast is a [`TextMesh`]
+/// with a [`TextMeshLifetime`], so it fades out on its own; the toasts below then slide
+/// up into the gap. Faded toasts stay hidden under the stack, to be reused for later
+/// pushes instead of spawning new entities. When more than
+/// [`max_toasts`](Self::max_toasts) are shown, the oldest starts fading right away.
+///
+/// Make the stack a child of a camera to keep the notifications in view, for example
+/// in a VR headset where screen-space UI isn't available.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn(Camera3d::default()).with_child((
+///         Toast3dStack {
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         // Top left of the view, in front of the camera
+///         Transform::from_xyz(-0.6, 0.35, -1.0).with_scale(Vec3::splat(0.05)),
+///     ));
+/// }
+///
+/// fn notify_saves(mut stacks: Query<&mut Toast3dStack>) {
+///     for mut stack in stacks.iter_mut() {
+///         stack.push("Game saved");
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+#[require(Transform, Visibility)]
+pub struct Toast3dStack {
+    /// Font of the toasts.
+    pub font: Handle<FontMesh>,
+    /// Style of the toasts.
+    pub style: TextMeshStyle,
+    /// Material of the toasts. The default handle gets the material of
+    /// [`FontMeshSettings`](crate::FontMeshSettings), like any other text.
+    pub material: Handle<StandardMaterial>,
+    /// Seconds a toast is shown, including its fade.
+    pub duration: f32,
+    /// Seconds over which a toast fades out at the end of its duration.
+    pub fade_out: f32,
+    /// Space between two toasts, in the stack's local units.
+    pub gap: f32,
+    /// Most toasts shown at once.
+    pub max_toasts: usize,
+    /// How quickly toasts slide into place, as the fraction of the remaining distance
+    /// covered per second. `f32::INFINITY` moves them at once.
+    pub reflow_speed: f32,
+    /// Texts waiting to be shown on the next update, in order. Only the last
+    /// [`max_toasts`](Self::max_toasts) of them are shown.
+    pub pending: Vec<String>,
+}
+
+impl Toast3dStack {
+    /// Queue `text` to be shown below the current toasts.
+    pub fn push(&mut self, text: impl Into<String>) {
+        self.pending.push(text.into());
+    }
+}
+
+impl Default for Toast3dStack {
+    fn default() -> Self {
+        Self {
+            font: Handle::default(),
+            style: TextMeshStyle::default(),
+            material: Handle::default(),
+            duration: 3.0,
+            fade_out: 0.5,
+            gap: 0.25,
+            max_toasts: 5,
+            reflow_speed: 10.0,
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// Marker for the text entities a [`Toast3dStack`] spawns, shown or hidden for reuse.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Toast3d {
+    /// Order in which the toasts were pushed, across all stacks.
+    pub sequence: u64,
+}
+
+/// Top and height of a toast, from its line boxes so every line counts as tall as the
+/// font.
+fn toast_extent(bounds: Option<&TextMeshBounds>) -> (f32, f32) {
+    let Some(bounds) = bounds else {
+        return (0.0, 0.0);
+    };
+    let (bottom, top) = bounds
+        .line_rects
+        .iter()
+        .map(|rect| (rect.min.y, rect.max.y))
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+        .unwrap_or((bounds.min.y, bounds.max.y));
+    (top, top - bottom)
+}
+
+type ToastQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Toast3d,
+        &'static mut Transform,
+        &'static mut TextMeshLifetime,
+        Option<&'static TextMeshBounds>,
+    ),
+>;
+
+/// System that shows the pushed toasts of [`Toast3dStack`]s and slides the shown ones
+/// into place.
+///
+/// Expired toasts are hidden rather than despawned, and shown again for later pushes.
+pub fn update_toast_stacks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut next_sequence: Local<u64>,
+    mut stacks: Query<(Entity, &mut Toast3dStack, Option<&Children>)>,
+    mut toasts: ToastQuery,
+    pooled: Query<(), (With<Toast3d>, Without<TextMeshLifetime>)>,
+) {
+    let blend = |speed: f32| 1.0 - (-speed * time.delta_secs()).exp();
+
+    for (stack_entity, mut stack, children) in stacks.iter_mut() {
+        let mut shown: Vec<Entity> = children
+            .map(|children| {
+                children
+                    .iter()
+                    .filter(|&child| toasts.contains(child))
+                    .collect()
+            })
+            .unwrap_or_default();
+        shown.sort_by_key(|&toast| toasts.get(toast).map_or(0, |(toast, ..)| toast.sequence));
+
+        // 1. Make room for the pushed toasts by fading out the oldest
+        let mut pending = std::mem::take(&mut stack.pending);
+        pending.drain(..pending.len().saturating_sub(stack.max_toasts));
+        let excess = (shown.len() + pending.len()).saturating_sub(stack.max_toasts);
+        for &toast in shown.iter().take(excess) {
+            if let Ok((_, _, mut lifetime, _)) = toasts.get_mut(toast) {
+                let fade_start = lifetime.duration - lifetime.fade_out;
+                lifetime.elapsed = lifetime.elapsed.max(fade_start);
+            }
+        }
+
+        // 2. Slide the shown toasts up to their slots, one below the other
+        let mut slot_top = 0.0;
+        for &toast in &shown {
+            let Ok((_, mut transform, _, bounds)) = toasts.get_mut(toast) else {
+                continue;
+            };
+            let (top, height) = toast_extent(bounds);
+            let target = slot_top - top;
+            let y = transform.translation.y;
+            transform.translation.y = if stack.reflow_speed.is_finite() {
+                y + (target - y) * blend(stack.reflow_speed)
+            } else {
+                target
+            };
+            slot_top -= height + stack.gap;
+        }
+
+        // 3. Show the pushed toasts below the others, reusing hidden ones first
+        let mut pool = children
+            .into_iter()
+            .flatten()
+            .filter(|&child| pooled.contains(*child));
+        for text in pending {
+            *next_sequence += 1;
+            let toast = match pool.next() {
+                Some(&toast) => toast,
+                None => commands.spawn_empty().id(),
+            };
+            commands.entity(toast).insert((
+                TextMeshBundle {
+                    text_mesh: TextMesh {
+                        text,
+                        font: stack.font.clone(),
+                        style: stack.style.clone(),
+                        ..default()
+                    },
+                    material: MeshMaterial3d(stack.material.clone()),
+                    transform: Transform::from_xyz(0.0, slot_top, 0.0),
+                    ..default()
+                },
+                TextMeshLifetime::new(stack.duration, stack.fade_out)
+                    .with_expiry(TextMeshExpiry::Hide),
+                Toast3d {
+                    sequence: *next_sequence,
+                },
+                ChildOf(stack_entity),
+            ));
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::component::TextMesh;
+use crate::system::TextMeshComputed;
+use bevy::prelude::*;
+
+/// Animates a [`TextMesh`] from its previous text to the new one when it changes.
+///
+/// Without it, edited text pops to its new mesh on the next frame. With it, the old mesh
+/// is kept on a [`TextMeshTransitionGhost`] entity next to the text that animates out
+/// while the regenerated text animates in, for polished scoreboards and menus. Editing
+/// the text again mid-transition starts over from the text's state before the first
+/// edit.
+///
+/// During the transition the text's [`Transform`] and material are animated and
+/// restored when it ends. Fading variants give the text and the ghost their own copies
+/// of the material, like [`TextMeshFade::Alpha`](crate::TextMeshFade::Alpha), and need
+/// Bevy's PBR materials. Needs Bevy's [`Time`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "Score: 0".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     // The old score slides up and out as the new one comes in from below
+///     TextMeshTransition::Slide {
+///         duration: 0.3,
+///         offset: Vec3::Y * 0.5,
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub enum TextMeshTransition {
+    /// Fade the old text out while the new text fades in.
+    CrossFade {
+        /// Seconds the transition takes.
+        duration: f32,
+    },
+    /// Move the old text away by `offset` while the new text moves in from the opposite
+    /// side, fading both.
+    Slide {
+        /// Seconds the transition takes.
+        duration: f32,
+        /// How far the old text moves, in the text's local space.
+        offset: Vec3,
+    },
+    /// Shrink the old text towards its origin while the new text grows from it.
+    Scale {
+        /// Seconds the transition takes.
+        duration: f32,
+    },
+}
+
+impl TextMeshTransition {
+    /// Seconds the transition takes.
+    pub fn duration(&self) -> f32 {
+        match *self {
+            Self::CrossFade { duration }
+            | Self::Slide { duration, .. }
+            | Self::Scale { duration } => duration,
+        }
+    }
+
+    /// Whether the transition fades the alpha of the texts.
+    fn fades(&self) -> bool {
+        !matches!(self, Self::Scale { .. })
+    }
+}
+
+/// The previous mesh of a [`TextMeshTransition`] text, animating out.
+///
+/// Spawned next to the text, under the same parent, and despawned when the transition
+/// ends or the text is despawned.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct TextMeshTransitionGhost {
+    /// The text the ghost belongs to.
+    pub source: Entity,
+}
+
+/// State of a running transition, on the text.
+#[derive(Component)]
+pub(crate) struct TransitionProgress {
+    elapsed: f32,
+    ghost: Entity,
+    /// Transform of the text before the transition.
+    start: Transform,
+    /// Material of the text before the transition.
+    material: Option<Handle<StandardMaterial>>,
+    /// Alpha of the material, once the text and the ghost have their own copies of it.
+    alpha: Option<f32>,
+}
+
+type ChangedTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Mesh3d,
+        &'static Transform,
+        &'static GlobalTransform,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+        Option<&'static ChildOf>,
+        Option<&'static TransitionProgress>,
+    ),
+    (
+        With<TextMeshTransition>,
+        With<TextMeshComputed>,
+        Changed<TextMesh>,
+    ),
+>;
+
+/// System that keeps the current mesh of edited [`TextMeshTransition`] texts on a
+/// [`TextMeshTransitionGhost`] before they are regenerated.
+pub fn start_text_mesh_transitions(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    texts: ChangedTextQuery,
+) {
+    for (entity, mesh, transform, global_transform, material, parent, progress) in texts.iter() {
+        // Edits usually rebuild the mesh in place, so the ghost needs a copy
+        let Some(old_mesh) = meshes.get(&mesh.0).cloned() else {
+            continue;
+        };
+
+        let (start, material) = match progress {
+            Some(progress) => {
+                commands.entity(progress.ghost).despawn();
+                (progress.start, progress.material.clone())
+            }
+            None => (*transform, material.map(|material| material.0.clone())),
+        };
+        if let Some(material) = &material {
+            commands
+                .entity(entity)
+                .insert(MeshMaterial3d(material.clone()));
+        }
+
+        let mut ghost = commands.spawn((
+            Mesh3d(meshes.add(old_mesh)),
+            start,
+            // Drawn in place on this frame, before its transform is propagated
+            *global_transform,
+            Visibility::default(),
+            TextMeshTransitionGhost { source: entity },
+        ));
+        if let Some(material) = &material {
+            ghost.insert(MeshMaterial3d(material.clone()));
+        }
+        if let Some(parent) = parent {
+            ghost.insert(ChildOf(parent.parent()));
+        }
+        let ghost = ghost.id();
+
+        commands.entity(entity).insert(TransitionProgress {
+            elapsed: 0.0,
+            ghost,
+            start,
+            material,
+            alpha: None,
+        });
+    }
+}
+
+type TransitionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static TextMeshTransition>,
+        &'static mut TransitionProgress,
+        &'static mut Transform,
+        Option<&'static mut MeshMaterial3d<StandardMaterial>>,
+    ),
+>;
+
+type GhostQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static TextMeshTransitionGhost,
+        &'static mut Transform,
+        Option<&'static mut MeshMaterial3d<StandardMaterial>>,
+    ),
+    Without<TransitionProgress>,
+>;
+
+/// System that animates [`TextMeshTransition`]s and ends them.
+///
+/// Runs before transform propagation, so the text and its ghost are drawn in place on
+/// the same frame.
+pub fn update_text_mesh_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut texts: TransitionQuery,
+    mut ghosts: GhostQuery,
+) {
+    let delta = time.delta_secs();
+    for (entity, transition, mut progress, mut transform, material) in texts.iter_mut() {
+        progress.elapsed += delta;
+        let t = match transition {
+            Some(transition) if transition.duration() > 0.0 => {
+                (progress.elapsed / transition.duration()).min(1.0)
+            }
+            // Removing the component ends the transition
+            _ => 1.0,
+        };
+        let ghost = ghosts.get_mut(progress.ghost).ok();
+
+        // 1. Put the text back the way it was once the transition is over, or once
+        //    something else despawned the ghost
+        let (Some(transition), Some((_, _, mut ghost_transform, ghost_material))) =
+            (transition.filter(|_| t < 1.0), ghost)
+        else {
+            *transform = progress.start;
+            if let (Some(mut material), Some(original)) = (material, &progress.material) {
+                material.0 = original.clone();
+            }
+            if let Ok(mut ghost) = commands.get_entity(progress.ghost) {
+                ghost.despawn();
+            }
+            commands.entity(entity).remove::<TransitionProgress>();
+            continue;
+        };
+
+        // 2. Move and scale the text and its ghost
+        let start = progress.start;
+        *transform = start;
+        *ghost_transform = start;
+        match *transition {
+            TextMeshTransition::CrossFade { .. } => {}
+            TextMeshTransition::Slide { offset, .. } => {
+                let offset = start.rotation * (start.scale * offset);
+                transform.translation -= offset * (1.0 - t);
+                ghost_transform.translation += offset * t;
+            }
+            TextMeshTransition::Scale { .. } => {
+                transform.scale = start.scale * t;
+                ghost_transform.scale = start.scale * (1.0 - t);
+            }
+        }
+
+        // 3. Fade them, each with its own copy of the material
+        let (Some(materials), Some(mut material), Some(mut ghost_material)) =
+            (materials.as_deref_mut(), material, ghost_material)
+        else {
+            continue;
+        };
+        if !transition.fades() {
+            continue;
+        }
+        let alpha = match progress.alpha {
+            Some(alpha) => alpha,
+            None => {
+                let mut copy = progress
+                    .material
+                    .as_ref()
+                    .and_then(|material| materials.get(material))
+                    .cloned()
+                    .unwrap_or_default();
+                if copy.alpha_mode == AlphaMode::Opaque {
+                    copy.alpha_mode = AlphaMode::Blend;
+                }
+                let alpha = copy.base_color.alpha();
+                material.0 = materials.add(copy.clone());
+                ghost_material.0 = materials.add(copy);
+                progress.alpha = Some(alpha);
+                alpha
+            }
+        };
+        if let Some(copy) = materials.get_mut(&material.0) {
+            copy.base_color.set_alpha(alpha * t);
+        }
+        if let Some(copy) = materials.get_mut(&ghost_material.0) {
+            copy.base_color.set_alpha(alpha * (1.0 - t));
+        }
+    }
+
+    // Ghosts of despawned texts go with them
+    for (ghost, source, ..) in ghosts.iter() {
+        if !texts.contains(source.source) {
+            commands.entity(ghost).despawn();
+        }
+    }
+}
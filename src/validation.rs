@@ -0,0 +1,246 @@
+use crate::component::{TextMesh, TextMesh2d, TextMeshGlyphs};
+use crate::core::style::{TextAnchor, TextMeshStyle};
+use crate::placeholder::FontWaits;
+use crate::FontMesh;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use std::fmt;
+
+/// Resource that enables checks for common text misconfigurations.
+///
+/// While present, text entities are checked for problems that would otherwise produce
+/// silently wrong or missing output, and each problem found is written as a
+/// [`TextMeshWarning`] message. Each problem is reported once, and again only after the
+/// offending component changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(FontMeshPlugin)
+///         .insert_resource(TextMeshValidation::default())
+///         .add_systems(Update, print_text_warnings)
+///         .run();
+/// }
+///
+/// fn print_text_warnings(mut warnings: MessageReader<TextMeshWarning>) {
+///     for warning in warnings.read() {
+///         eprintln!("{warning}");
+///     }
+/// }
+/// ```
+///
+/// Fonts are reported as not loaded after [`FontLoadTimeout::timeout`](crate::FontLoadTimeout::timeout), the same time
+/// after which placeholders are shown.
+#[derive(Resource, Clone, Debug)]
+pub struct TextMeshValidation {
+    /// Largest `subdivision × visible characters` of a single text before it is reported.
+    ///
+    /// Curve subdivision multiplies the vertex count of every glyph, so high values
+    /// that look fine on a short title become expensive on a paragraph.
+    pub subdivision_budget: usize,
+}
+
+impl Default for TextMeshValidation {
+    fn default() -> Self {
+        Self {
+            subdivision_budget: 10_000,
+        }
+    }
+}
+
+/// A problem found by [`TextMeshValidation`].
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct TextMeshWarning {
+    /// The text entity with the problem.
+    pub entity: Entity,
+    /// What is wrong.
+    pub kind: TextMeshWarningKind,
+}
+
+/// The kinds of problems reported as [`TextMeshWarning`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextMeshWarningKind {
+    /// A [`TextMeshGlyphs`] entity has no `MeshMaterial3d<StandardMaterial>` component,
+    /// because it was removed after the component required it, so its glyphs are
+    /// invisible.
+    MissingGlyphMaterial,
+    /// The font or one of the fallback fonts has not loaded, or can't be parsed, so the
+    /// text is not generated.
+    FontNotLoaded {
+        /// The font that is missing.
+        font: AssetId<FontMesh>,
+        /// Whether the font failed to load or to parse, as opposed to the font still not being available after
+        /// [`FontLoadTimeout::timeout`](crate::FontLoadTimeout::timeout).
+        failed: bool,
+    },
+    /// A [`TextAnchor::Custom`] pivot lies outside the text bounds.
+    PivotOutOfRange {
+        /// The pivot.
+        pivot: Vec2,
+    },
+    /// The subdivision is likely too high for the amount of text.
+    SubdivisionTooHigh {
+        /// The style's subdivision.
+        subdivision: u8,
+        /// Number of visible characters in the text.
+        glyphs: usize,
+    },
+}
+
+impl fmt::Display for TextMeshWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "text entity {}: ", self.entity)?;
+        match &self.kind {
+            TextMeshWarningKind::MissingGlyphMaterial => write!(
+                f,
+                "TextMeshGlyphs has no MeshMaterial3d<StandardMaterial> component, so its \
+                 glyphs are invisible; insert a material"
+            ),
+            TextMeshWarningKind::FontNotLoaded { font, failed: true } => write!(
+                f,
+                "font {font} failed to load or parse; check the asset path and that the file is a \
+                 TrueType font"
+            ),
+            TextMeshWarningKind::FontNotLoaded {
+                font,
+                failed: false,
+            } => write!(
+                f,
+                "font {font} is still not loaded; make sure the handle comes from \
+                 AssetServer::load or Assets<FontMesh>::add and is kept alive"
+            ),
+            TextMeshWarningKind::PivotOutOfRange { pivot } => write!(
+                f,
+                "custom anchor pivot {pivot} is outside 0..=1, which places the text \
+                 outside its own bounds; use values between 0.0 and 1.0"
+            ),
+            TextMeshWarningKind::SubdivisionTooHigh {
+                subdivision,
+                glyphs,
+            } => write!(
+                f,
+                "subdivision {subdivision} for {glyphs} characters is likely more detail \
+                 than needed; lower TextMeshStyle::subdivision for long text"
+            ),
+        }
+    }
+}
+
+/// Checks shared by every kind of text component.
+#[allow(clippy::too_many_arguments)]
+fn check_text(
+    entity: Entity,
+    text: &str,
+    style: &TextMeshStyle,
+    changed: bool,
+    validation: &TextMeshValidation,
+    waits: &FontWaits,
+    reported: &mut HashSet<(Entity, AssetId<FontMesh>)>,
+    warnings: &mut MessageWriter<TextMeshWarning>,
+) {
+    if changed {
+        if let TextAnchor::Custom(pivot) = style.anchor {
+            if !(0.0..=1.0).contains(&pivot.x) || !(0.0..=1.0).contains(&pivot.y) {
+                warnings.write(TextMeshWarning {
+                    entity,
+                    kind: TextMeshWarningKind::PivotOutOfRange { pivot },
+                });
+            }
+        }
+
+        // A curve tolerance replaces the subdivision
+        let glyphs = text.chars().filter(|ch| !ch.is_whitespace()).count();
+        if style.curve_tolerance.is_none()
+            && style.subdivision as usize * glyphs > validation.subdivision_budget
+        {
+            warnings.write(TextMeshWarning {
+                entity,
+                kind: TextMeshWarningKind::SubdivisionTooHigh {
+                    subdivision: style.subdivision,
+                    glyphs,
+                },
+            });
+        }
+    }
+
+    if let Some(wait) = waits.expired(entity) {
+        if reported.insert((entity, wait.font)) {
+            warnings.write(TextMeshWarning {
+                entity,
+                kind: TextMeshWarningKind::FontNotLoaded {
+                    font: wait.font,
+                    failed: wait.failed,
+                },
+            });
+        }
+    }
+}
+
+type GlyphTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshGlyphs>,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+    ),
+>;
+
+/// System that checks text entities and writes [`TextMeshWarning`]s.
+///
+/// The font loading timeout needs Bevy's [`Time`]; without it only failed loads are
+/// reported.
+pub fn validate_text_meshes(
+    validation: Res<TextMeshValidation>,
+    waits: Res<FontWaits>,
+    mut reported: Local<HashSet<(Entity, AssetId<FontMesh>)>>,
+    texts: Query<(Entity, Ref<TextMesh>)>,
+    texts_2d: Query<(Entity, Ref<TextMesh2d>)>,
+    glyph_texts: GlyphTextQuery,
+    mut warnings: MessageWriter<TextMeshWarning>,
+) {
+    let changed = validation.is_changed();
+
+    for (entity, text_glyphs, material) in glyph_texts.iter() {
+        // The default handle is a valid material, only a removed component leaves none
+        if (changed || text_glyphs.is_changed()) && material.is_none() {
+            warnings.write(TextMeshWarning {
+                entity,
+                kind: TextMeshWarningKind::MissingGlyphMaterial,
+            });
+        }
+    }
+
+    let mut check = |entity: Entity, text: &str, style: &TextMeshStyle, text_changed: bool| {
+        check_text(
+            entity,
+            text,
+            style,
+            changed || text_changed,
+            &validation,
+            &waits,
+            &mut reported,
+            &mut warnings,
+        );
+    };
+    for (entity, text_mesh) in texts.iter() {
+        let text_changed = text_mesh.is_changed();
+        check(entity, &text_mesh.text, &text_mesh.style, text_changed);
+    }
+    for (entity, text_mesh) in texts_2d.iter() {
+        let text_changed = text_mesh.is_changed();
+        check(entity, &text_mesh.text, &text_mesh.style, text_changed);
+    }
+    for (entity, text_glyphs, _) in glyph_texts.iter() {
+        let text_changed = text_glyphs.is_changed();
+        check(entity, &text_glyphs.text, &text_glyphs.style, text_changed);
+    }
+
+    // Forget texts that stopped waiting, because their font arrived or they were despawned
+    reported.retain(|&(entity, _)| waits.get(entity).is_some());
+}
@@ -0,0 +1,295 @@
+use crate::bounds::TextMeshBounds;
+use crate::component::TextMesh;
+use crate::core::fallback::FontChain;
+use crate::core::layout::{calculate_line_step, layout_text_in, line_whitespace, paragraph_lines};
+use crate::core::mesh::TextMeshData;
+use crate::core::style::TextMeshStyle;
+use crate::FontMesh;
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// Shows whitespace in a [`TextMesh`] as small flat markers, like the "show invisibles"
+/// mode of a text editor.
+///
+/// Put it on an entity with a [`TextMesh`] to get a [`TextMeshWhitespaceMarkers`] child
+/// with a middle dot for every space, an arrow for every tab, and a pilcrow at the end
+/// of every line followed by a line break. The markers lie on the front face of the
+/// text, follow it as it changes, and are despawned when the component is removed.
+/// Flip [`visible`](Self::visible) to toggle them at runtime, for debugging layout or in
+/// editor-like tools.
+///
+/// Text laid out along a [`TextPath`](crate::TextPath) has no markers.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_fontmesh::prelude::*;
+/// # fn example(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     TextMeshBundle {
+///         text_mesh: TextMesh {
+///             text: "fn main() {\n\tlet x = 1;\n}".to_string(),
+///             font: asset_server.load("fonts/font.ttf"),
+///             ..default()
+///         },
+///         ..default()
+///     },
+///     TextMeshWhitespace::default(),
+/// ));
+///
+/// fn toggle(keys: Res<ButtonInput<KeyCode>>, mut texts: Query<&mut TextMeshWhitespace>) {
+///     if keys.just_pressed(KeyCode::F3) {
+///         for mut whitespace in texts.iter_mut() {
+///             whitespace.visible = !whitespace.visible;
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TextMeshWhitespace {
+    /// Whether the markers are shown. Default: `true`.
+    pub visible: bool,
+    /// Material of the markers. The default handle uses the material of the text.
+    pub material: Handle<StandardMaterial>,
+}
+
+impl Default for TextMeshWhitespace {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            material: Handle::default(),
+        }
+    }
+}
+
+/// Marker for the whitespace markers of a [`TextMeshWhitespace`].
+///
+/// Spawned as a child of the text entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct TextMeshWhitespaceMarkers;
+
+type TextWhitespaceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, TextMeshWhitespace>,
+        Ref<'static, TextMesh>,
+        Ref<'static, TextMeshBounds>,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+        Option<&'static Children>,
+    ),
+>;
+
+/// System that builds the whitespace markers of text with a [`TextMeshWhitespace`].
+///
+/// Runs after mesh generation, so markers always match the current layout.
+pub fn update_text_whitespace_markers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    font_assets: Res<Assets<FontMesh>>,
+    texts: TextWhitespaceQuery,
+    markers: Query<(), With<TextMeshWhitespaceMarkers>>,
+    children: Query<&Children>,
+    mut removed: RemovedComponents<TextMeshWhitespace>,
+) {
+    // 1. Despawn the markers of text that no longer shows whitespace
+    for entity in removed.read() {
+        let Ok(children) = children.get(entity) else {
+            continue;
+        };
+        for child in children.iter() {
+            if markers.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+
+    // 2. Rebuild markers; the line cache edits meshes in place, so text changes count
+    for (entity, whitespace, text_mesh, bounds, text_material, children) in texts.iter() {
+        if !(whitespace.is_changed() || text_mesh.is_changed() || bounds.is_changed()) {
+            continue;
+        }
+        let existing =
+            children.and_then(|children| children.iter().find(|&child| markers.contains(child)));
+
+        // Hidden markers are rebuilt once they are shown again
+        if !whitespace.visible {
+            if let Some(child) = existing {
+                commands.entity(child).insert(Visibility::Hidden);
+            }
+            continue;
+        }
+        let Some(fonts) = FontChain::for_style(&font_assets, &text_mesh.font, &text_mesh.style)
+        else {
+            continue;
+        };
+        let Some(data) = whitespace_marker_data(&fonts, &text_mesh.text, &text_mesh.style, &bounds)
+        else {
+            continue;
+        };
+
+        let material = if whitespace.material.id() == AssetId::default() {
+            text_material
+                .map(|material| material.0.clone())
+                .unwrap_or_default()
+        } else {
+            whitespace.material.clone()
+        };
+        let markers = (
+            Mesh3d(meshes.add(data.into_mesh())),
+            MeshMaterial3d(material),
+            Visibility::Inherited,
+        );
+        match existing {
+            Some(child) => {
+                commands.entity(child).insert(markers);
+            }
+            None => {
+                commands.entity(entity).with_child((
+                    TextMeshWhitespaceMarkers,
+                    markers,
+                    Transform::default(),
+                ));
+            }
+        }
+    }
+}
+
+/// Geometry of the whitespace markers of `text`, placed like its anchored mesh.
+///
+/// Returns `None` for text without line boxes, such as text on a path.
+fn whitespace_marker_data(
+    fonts: &FontChain,
+    text: &str,
+    style: &TextMeshStyle,
+    bounds: &TextMeshBounds,
+) -> Option<TextMeshData> {
+    let layout = layout_text_in(fonts, text, style);
+    let ascender = layout.ascender;
+
+    // The mesh is anchored by its geometry, so take the offset from its first line box
+    let anchored = bounds.line_rects.first()?;
+    let offset = anchored.min - layout.line_rect(0)?.min;
+
+    let mut data = TextMeshData::default();
+    let line_step = calculate_line_step(fonts, style).truncate();
+    for (line_index, ((line, ends_paragraph), layout_line)) in
+        paragraph_lines(text).zip(&layout.lines).enumerate()
+    {
+        let line_origin = line_step * line_index as f32 + offset;
+        for (character, origin, advance) in line_whitespace(line, ends_paragraph, fonts, style) {
+            let origin = line_origin + origin;
+            match character {
+                '\t' => add_arrow(&mut data, origin, advance, ascender),
+                _ => add_dot(&mut data, origin, advance, ascender),
+            }
+        }
+
+        // Every line but the last ends in a line break
+        if line_index + 1 < layout.lines.len() {
+            let end = if layout.vertical {
+                Vec2::new(
+                    layout_line.baseline - PILCROW_WIDTH * ascender * 0.5,
+                    layout_line.start - layout_line.width - ascender,
+                )
+            } else {
+                Vec2::new(layout_line.start + layout_line.width, layout_line.baseline)
+            };
+            add_pilcrow(&mut data, end + offset, ascender);
+        }
+    }
+    Some(data)
+}
+
+/// Width of a pilcrow, as a fraction of the ascender.
+const PILCROW_WIDTH: f32 = 0.35;
+
+/// Height of the middle of markers above the baseline, as a fraction of the ascender.
+const MARKER_HEIGHT: f32 = 0.3;
+
+/// A middle dot centered in the space of a character at `origin`.
+fn add_dot(data: &mut TextMeshData, origin: Vec2, advance: f32, ascender: f32) {
+    let center = origin + Vec2::new(advance * 0.5, MARKER_HEIGHT * ascender);
+    let half_size = Vec2::splat(0.05 * ascender);
+    add_quad(data, center - half_size, center + half_size);
+}
+
+/// An arrow across the space of a tab at `origin`.
+fn add_arrow(data: &mut TextMeshData, origin: Vec2, advance: f32, ascender: f32) {
+    let y = origin.y + MARKER_HEIGHT * ascender;
+    let (start, end) = (origin.x + advance * 0.15, origin.x + advance * 0.85);
+    let head = (0.2 * ascender).min((end - start) * 0.5);
+    let thickness = 0.04 * ascender;
+    add_quad(
+        data,
+        Vec2::new(start, y - thickness * 0.5),
+        Vec2::new(end - head, y + thickness * 0.5),
+    );
+    add_polygon(
+        data,
+        &[
+            Vec2::new(end - head, y - head * 0.5),
+            Vec2::new(end, y),
+            Vec2::new(end - head, y + head * 0.5),
+        ],
+    );
+}
+
+/// A pilcrow standing on the baseline at `origin`.
+fn add_pilcrow(data: &mut TextMeshData, origin: Vec2, ascender: f32) {
+    let height = 0.7 * ascender;
+    let stem = 0.05 * ascender;
+    let right = origin.x + PILCROW_WIDTH * ascender;
+    let radius = 0.15 * ascender;
+    let left_stem = right - stem * 3.0;
+    add_quad(
+        data,
+        Vec2::new(left_stem, origin.y),
+        Vec2::new(left_stem + stem, origin.y + height),
+    );
+    add_quad(
+        data,
+        Vec2::new(right - stem, origin.y),
+        Vec2::new(right, origin.y + height),
+    );
+    add_quad(
+        data,
+        Vec2::new(left_stem, origin.y + height - stem),
+        Vec2::new(right, origin.y + height),
+    );
+
+    // Filled bowl on the left of the stems
+    let center = Vec2::new(left_stem, origin.y + height - radius);
+    let bowl: Vec<Vec2> = (0..=8)
+        .map(|step| {
+            let angle = PI * 0.5 + PI * step as f32 / 8.0;
+            center + Vec2::from_angle(angle) * radius
+        })
+        .collect();
+    add_polygon(data, &bowl);
+}
+
+/// An axis-aligned rectangle facing the front.
+fn add_quad(data: &mut TextMeshData, min: Vec2, max: Vec2) {
+    add_polygon(
+        data,
+        &[min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)],
+    );
+}
+
+/// A convex polygon facing the front, with its corners in counter-clockwise order.
+fn add_polygon(data: &mut TextMeshData, corners: &[Vec2]) {
+    let first = data.vertices.len() as u32;
+    for corner in corners {
+        data.vertices.push([corner.x, corner.y, 0.0]);
+        data.normals.push([0.0, 0.0, 1.0]);
+    }
+    for i in 1..corners.len().saturating_sub(1) as u32 {
+        data.indices.extend([first, first + i, first + i + 1]);
+    }
+}
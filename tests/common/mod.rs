@@ -0,0 +1,213 @@
+//! Headless app fixtures shared by the integration tests
+//!
+//! Included by each test crate's `main.rs` and used from its modules as `crate::common`.
+//! Not every test uses every helper.
+#![allow(dead_code)]
+
+use bevy::mesh::{Indices, MeshVertexAttributeId};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::fs;
+
+/// Path of the font every fixture loads.
+pub const TEST_FONT: &str = "assets/fonts/FiraMono-Medium.ttf";
+
+/// The raw bytes of [`TEST_FONT`].
+pub fn load_test_font_data() -> Vec<u8> {
+    fs::read(TEST_FONT)
+        .expect("Failed to load test font - make sure assets/fonts/FiraMono-Medium.ttf exists")
+}
+
+pub fn load_test_font() -> FontMesh {
+    FontMesh {
+        data: load_test_font_data().into(),
+        face_index: 0,
+    }
+}
+
+/// An app with only [`FontMeshPlugin`], plus a handle to the test font in it.
+///
+/// For tests that configure the app further before wrapping it in a [`TestApp`].
+pub fn app_with_font() -> (App, Handle<FontMesh>) {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin);
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(load_test_font());
+    (app, font)
+}
+
+/// A minimal app with only [`FontMeshPlugin`] and the test font loaded.
+///
+/// No window, renderer, or asset server is involved, so every frame runs the
+/// generation systems synchronously.
+pub struct TestApp {
+    pub app: App,
+    pub font: Handle<FontMesh>,
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let (app, font) = app_with_font();
+        Self { app, font }
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Run a single frame.
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    /// Run `frames` frames.
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    pub fn text_mesh(&self, text: &str, style: TextMeshStyle) -> TextMesh {
+        TextMesh {
+            text: text.to_string(),
+            font: self.font.clone(),
+            style,
+            ..default()
+        }
+    }
+
+    /// Spawn a [`TextMeshBundle`] in the test font.
+    pub fn spawn_text(&mut self, text: &str, style: TextMeshStyle) -> Entity {
+        let text_mesh = self.text_mesh(text, style);
+        self.world_mut()
+            .spawn(TextMeshBundle {
+                text_mesh,
+                ..default()
+            })
+            .id()
+    }
+
+    /// Spawn a [`TextMeshGlyphsBundle`] in the test font.
+    pub fn spawn_glyphs(&mut self, text: &str, style: TextMeshStyle) -> Entity {
+        let font = self.font.clone();
+        self.world_mut()
+            .spawn(TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: text.to_string(),
+                    font,
+                    style,
+                    ..default()
+                },
+                ..default()
+            })
+            .id()
+    }
+
+    /// Spawn a [`TextMeshPartsBundle`] in the test font.
+    pub fn spawn_parts(&mut self, text: &str, style: TextMeshStyle) -> Entity {
+        let font = self.font.clone();
+        self.world_mut()
+            .spawn(TextMeshPartsBundle {
+                text_parts: TextMeshParts {
+                    text: text.to_string(),
+                    font,
+                    style,
+                },
+                ..default()
+            })
+            .id()
+    }
+
+    /// Edit the [`TextMesh`] of `entity`.
+    pub fn edit_text(&mut self, entity: Entity, edit: impl FnOnce(&mut TextMesh)) {
+        edit(&mut self.world_mut().get_mut::<TextMesh>(entity).unwrap());
+    }
+
+    /// Id of the mesh asset displayed by `entity`.
+    pub fn mesh_id(&self, entity: Entity) -> AssetId<Mesh> {
+        self.world().get::<Mesh3d>(entity).unwrap().id()
+    }
+
+    /// The mesh displayed by `entity`.
+    pub fn mesh(&self, entity: Entity) -> &Mesh {
+        self.world()
+            .resource::<Assets<Mesh>>()
+            .get(self.mesh_id(entity))
+            .expect("entity has no generated mesh")
+    }
+
+    /// Number of mesh assets in the world.
+    pub fn mesh_count(&self) -> usize {
+        self.world().resource::<Assets<Mesh>>().len()
+    }
+
+    /// Vertex positions of the mesh displayed by `entity`.
+    pub fn positions(&self, entity: Entity) -> Vec<Vec3> {
+        float3_attribute(self.mesh(entity), Mesh::ATTRIBUTE_POSITION)
+    }
+
+    /// Vertex normals of the mesh displayed by `entity`.
+    pub fn normals(&self, entity: Entity) -> Vec<Vec3> {
+        float3_attribute(self.mesh(entity), Mesh::ATTRIBUTE_NORMAL)
+    }
+
+    /// Triangle indices of the mesh displayed by `entity`.
+    pub fn indices(&self, entity: Entity) -> Vec<u32> {
+        match self.mesh(entity).indices() {
+            Some(Indices::U32(indices)) => indices.clone(),
+            Some(Indices::U16(indices)) => indices.iter().map(|&i| i as u32).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Children of `entity` that have a `C` component, in spawn order.
+    pub fn children_with<C: Component>(&self, entity: Entity) -> Vec<Entity> {
+        self.world()
+            .get::<Children>(entity)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter(|&child| self.world().get::<C>(child).is_some())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The measured bounds of `entity`.
+    pub fn bounds(&self, entity: Entity) -> TextMeshBounds {
+        self.world()
+            .get::<TextMeshBounds>(entity)
+            .expect("entity has no bounds")
+            .clone()
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Values of a `Float32x3` attribute of `mesh`.
+pub fn float3_attribute(mesh: &Mesh, attribute: impl Into<MeshVertexAttributeId>) -> Vec<Vec3> {
+    mesh.attribute(attribute)
+        .and_then(|values| values.as_float3())
+        .expect("attribute is missing or not Float32x3")
+        .iter()
+        .map(|&value| Vec3::from_array(value))
+        .collect()
+}
+
+/// Smallest box around `points`.
+pub fn extent(points: &[Vec3]) -> (Vec3, Vec3) {
+    points.iter().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), &p| (min.min(p), max.max(p)),
+    )
+}
@@ -0,0 +1,40 @@
+//! Tests for the embedded default font
+#![cfg(feature = "default-font")]
+
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_default_handle_is_the_default_font() {
+    assert_eq!(FontMesh::default_handle(), Handle::<FontMesh>::default());
+    assert_eq!(TextMesh::default().font, FontMesh::default_handle());
+}
+
+#[test]
+fn test_text_without_font_uses_embedded_font() {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin);
+
+    let fonts = app.world().resource::<Assets<FontMesh>>();
+    let font = fonts.get(&FontMesh::default_handle()).unwrap();
+    assert!(font.glyph_metrics('A').is_some());
+    assert!(font.font_metrics().is_some());
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Hello, world!".to_string(),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    assert!(app.world().get::<TextMeshComputed>(entity).is_some());
+    let mesh = app.world().get::<Mesh3d>(entity).unwrap();
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    let mesh = meshes.get(mesh).unwrap();
+    assert!(mesh.count_vertices() > 0);
+}
@@ -0,0 +1,155 @@
+//! Tests for loading faces from TrueType collections
+
+use crate::common;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+/// Bundle the test font twice into a collection, the second face with twice the units
+/// per em, so its glyphs are half as large.
+fn build_collection(font: &[u8]) -> Vec<u8> {
+    let header_len = 12 + 2 * 4;
+    let face_len = font.len().next_multiple_of(4);
+    let mut data = Vec::new();
+    data.extend_from_slice(b"ttcf");
+    data.extend_from_slice(&1u16.to_be_bytes());
+    data.extend_from_slice(&0u16.to_be_bytes());
+    data.extend_from_slice(&2u32.to_be_bytes());
+    for face in 0..2 {
+        data.extend_from_slice(&((header_len + face * face_len) as u32).to_be_bytes());
+    }
+
+    for face in 0..2 {
+        let base = data.len();
+        data.extend_from_slice(font);
+        data.resize(base + face_len, 0);
+
+        // Table offsets count from the start of the file
+        let table_count = read_u16(font, 4) as usize;
+        for table in 0..table_count {
+            let record = base + 12 + table * 16;
+            let offset = read_u32(&data, record + 8) as usize + base;
+            data[record + 8..record + 12].copy_from_slice(&(offset as u32).to_be_bytes());
+            if &data[record..record + 4] == b"head" && face == 1 {
+                let units_per_em = read_u16(&data, offset + 18) * 2;
+                data[offset + 18..offset + 20].copy_from_slice(&units_per_em.to_be_bytes());
+            }
+        }
+    }
+    data
+}
+
+#[test]
+fn test_face_index_selects_a_face_of_the_collection() {
+    let collection = build_collection(&common::load_test_font_data());
+    let face = |face_index| FontMesh {
+        data: collection.clone().into(),
+        face_index,
+    };
+
+    assert_eq!(face(0).face_count(), 2);
+    let advance = |font: &FontMesh| font.glyph_metrics('A').unwrap().advance;
+    assert_eq!(advance(&face(1)), advance(&face(0)) / 2.0);
+
+    let width = |font: &FontMesh| layout_text(font, "Hello", &TextMeshStyle::default()).bounds;
+    assert!(width(&face(1)).width() < width(&face(0)).width());
+
+    // Indices past the end of the collection don't parse
+    assert!(face(2).font_metrics().is_none());
+}
+
+#[test]
+fn test_single_fonts_have_one_face() {
+    let font = FontMesh {
+        data: common::load_test_font_data().into(),
+        face_index: 0,
+    };
+    assert_eq!(font.face_count(), 1);
+}
+
+#[test]
+fn test_text_meshes_use_the_selected_face() {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin);
+    let collection = build_collection(&common::load_test_font_data());
+    let mut spawn = |face_index| {
+        let font = app
+            .world_mut()
+            .resource_mut::<Assets<FontMesh>>()
+            .add(FontMesh {
+                data: collection.clone().into(),
+                face_index,
+            });
+        app.world_mut()
+            .spawn(TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Hello".to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            })
+            .id()
+    };
+    let regular = spawn(0);
+    let small = spawn(1);
+    app.update();
+
+    let size = |entity| app.world().get::<TextMeshBounds>(entity).unwrap().size();
+    assert!(size(small).x < size(regular).x * 0.6);
+}
+
+#[test]
+fn test_loader_labels_every_face() {
+    let dir = std::env::temp_dir().join(format!("bevy_fontmesh_ttc_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("pair.ttc"),
+        build_collection(&common::load_test_font_data()),
+    )
+    .unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: dir.to_string_lossy().into_owned(),
+            ..default()
+        },
+    ))
+    .init_asset::<Mesh>()
+    .add_plugins(FontMeshPlugin);
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let first: Handle<FontMesh> = asset_server.load("pair.ttc");
+    let second: Handle<FontMesh> = asset_server.load("pair.ttc#1");
+    for _ in 0..500 {
+        app.update();
+        let loaded = |handle: &Handle<FontMesh>| {
+            matches!(asset_server.load_state(handle), LoadState::Loaded)
+        };
+        if loaded(&first) && loaded(&second) {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let _ = fs::remove_dir_all(&dir);
+
+    let fonts = app.world().resource::<Assets<FontMesh>>();
+    let (first, second) = (fonts.get(&first).unwrap(), fonts.get(&second).unwrap());
+    assert_eq!(first.face_index, 0);
+    assert_eq!(second.face_index, 1);
+    // The faces share the bytes of the file instead of copying them
+    assert!(Arc::ptr_eq(&first.data, &second.data));
+}
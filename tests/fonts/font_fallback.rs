@@ -0,0 +1,83 @@
+//! Tests for fallback font chains
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_text(
+    app: &mut App,
+    font: Handle<FontMesh>,
+    fallback_fonts: Vec<Handle<FontMesh>>,
+) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Fallback".to_string(),
+                font,
+                style: TextMeshStyle {
+                    fallback_fonts: fallback_fonts.into(),
+                    ..default()
+                },
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn vertex_count(app: &App, entity: Entity) -> usize {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .expect("Generated mesh should exist")
+        .count_vertices()
+}
+
+#[test]
+fn test_waits_for_fallback_fonts_to_load() {
+    let (mut app, font) = common::app_with_font();
+    let fallback = app.world().resource::<Assets<FontMesh>>().reserve_handle();
+    let entity = spawn_text(&mut app, font, vec![fallback.clone()]);
+
+    app.update();
+    assert!(
+        app.world().get::<TextMeshComputed>(entity).is_none(),
+        "Text should wait until every fallback font is loaded"
+    );
+
+    app.world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .insert(fallback.id(), common::load_test_font())
+        .unwrap();
+    app.update();
+
+    assert!(
+        app.world().get::<TextMeshComputed>(entity).is_some(),
+        "Text should be generated once the fallback font is loaded"
+    );
+}
+
+#[test]
+fn test_primary_font_takes_precedence() {
+    let (mut app, font) = common::app_with_font();
+    let invalid = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(FontMesh {
+            data: vec![0; 16].into(),
+            face_index: 0,
+        });
+
+    let plain = spawn_text(&mut app, font.clone(), Vec::new());
+    let with_fallback = spawn_text(&mut app, font, vec![invalid]);
+
+    app.update();
+
+    // Unparsable fallbacks are ignored and the primary font covers every character
+    assert_eq!(
+        vertex_count(&app, plain),
+        vertex_count(&app, with_fallback),
+        "Fallback fonts should not change text the primary font can render"
+    );
+}
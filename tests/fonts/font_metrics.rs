@@ -0,0 +1,191 @@
+//! Tests for font metrics API
+
+use crate::common;
+use bevy::math::Vec2;
+
+#[test]
+fn test_font_metrics_api() {
+    let font = common::load_test_font();
+
+    // Test font-level metrics
+    let metrics = font.font_metrics().expect("Should get font metrics");
+    assert!(metrics.ascender > 0.0, "Ascender should be positive");
+    assert!(metrics.descender < 0.0, "Descender should be negative");
+    assert!(metrics.line_height > 0.0, "Line height should be positive");
+    assert_eq!(
+        metrics.line_height,
+        metrics.ascender - metrics.descender + metrics.line_gap,
+        "Line height should equal ascender - descender + line_gap"
+    );
+
+    // Test glyph metrics
+    let glyph_m = font.glyph_metrics('M').expect("Should get metrics for 'M'");
+    assert!(glyph_m.advance > 0.0, "Advance should be positive");
+    assert!(glyph_m.has_outline, "'M' should have outline");
+
+    let glyph_space = font
+        .glyph_metrics(' ')
+        .expect("Should get metrics for space");
+    assert!(
+        glyph_space.advance > 0.0,
+        "Space advance should be positive"
+    );
+    assert!(!glyph_space.has_outline, "Space should not have outline");
+
+    // Test text width
+    let test_text = "Hello";
+    let width = font.text_width(test_text);
+    assert!(width > 0.0, "Text width should be positive");
+
+    // Verify width equals sum of advances
+    let manual_width: f32 = test_text
+        .chars()
+        .filter_map(|ch| font.glyph_metrics(ch))
+        .map(|m| m.advance)
+        .sum();
+    assert!(
+        (width - manual_width).abs() < 0.001,
+        "text_width() should equal sum of advances"
+    );
+
+    // Test char positions
+    let positions = font.char_positions(test_text);
+    assert_eq!(
+        positions.len(),
+        test_text.len(),
+        "Should have position for each character"
+    );
+
+    // First character should be at x=0
+    assert_eq!(positions[0].0, 0);
+    assert_eq!(positions[0].1, 0.0);
+
+    // Positions should be monotonically increasing
+    for i in 1..positions.len() {
+        assert!(
+            positions[i].1 >= positions[i - 1].1,
+            "Positions should increase"
+        );
+    }
+}
+
+#[test]
+fn test_glyph_metrics_missing_char() {
+    let font = common::load_test_font();
+
+    // Test with an emoji character unlikely to be in a monospace font
+    let result = font.glyph_metrics('😀');
+    // Note: Some fonts may include fallback glyphs, so we just verify the API works
+    // Either Some or None is acceptable - we're testing that it doesn't crash
+    let _ = result;
+}
+
+#[test]
+fn test_empty_text_width() {
+    let font = common::load_test_font();
+
+    assert_eq!(font.text_width(""), 0.0, "Empty text should have 0 width");
+    assert_eq!(
+        font.char_positions("").len(),
+        0,
+        "Empty text should have no positions"
+    );
+}
+
+#[test]
+fn test_glyph_contour_samples_are_evenly_spaced() {
+    let font = common::load_test_font();
+    let spacing = 0.02;
+
+    // '-' is a single rectangular contour
+    let samples = font.glyph_contour_samples('-', spacing);
+    assert!(samples.len() > 4, "Should sample the whole outline");
+
+    let gaps: Vec<f32> = samples
+        .iter()
+        .zip(samples.iter().cycle().skip(1))
+        .map(|((a, _), (b, _))| a.distance(*b))
+        .collect();
+    let longest = gaps.iter().copied().fold(0.0, f32::max);
+    assert!(
+        longest < spacing * 1.5,
+        "Samples should be about {spacing} apart, found a gap of {longest}"
+    );
+    for (_, tangent) in &samples {
+        assert!(
+            (tangent.length() - 1.0).abs() < 1e-4,
+            "Tangents should be unit length"
+        );
+    }
+}
+
+#[test]
+fn test_glyph_contour_samples_tangents_wind_around_fill() {
+    let font = common::load_test_font();
+    let samples = font.glyph_contour_samples('-', 0.02);
+
+    let center = samples.iter().map(|(p, _)| *p).sum::<Vec2>() / samples.len() as f32;
+    for (position, tangent) in &samples {
+        let outward = Vec2::new(tangent.y, -tangent.x);
+        assert!(
+            outward.dot(*position - center) >= 0.0,
+            "Outward normal at {position} should point away from the glyph"
+        );
+    }
+}
+
+#[test]
+fn test_glyph_contour_samples_empty_without_outline() {
+    let font = common::load_test_font();
+    assert!(font.glyph_contour_samples(' ', 0.02).is_empty());
+    assert!(font.glyph_contour_samples('\u{E000}', 0.02).is_empty());
+    assert!(font.glyph_contour_samples('A', 0.0).is_empty());
+}
+
+/// Signed area of a closed polyline, positive when it runs counter-clockwise.
+fn signed_area(contour: &[Vec2]) -> f32 {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum::<f32>()
+        * 0.5
+}
+
+#[test]
+fn test_glyph_contours_wind_around_fill() {
+    let font = common::load_test_font();
+
+    // 'o' has an outer contour and a hole
+    let contours = font.glyph_contours('o', 8);
+    assert_eq!(contours.len(), 2);
+    let mut areas: Vec<f32> = contours.iter().map(|c| signed_area(c)).collect();
+    areas.sort_by(f32::total_cmp);
+    assert!(areas[0] < 0.0, "The hole should run clockwise");
+    assert!(
+        areas[1] > 0.0,
+        "The outer contour should run counter-clockwise"
+    );
+    assert!(areas[1] > -areas[0]);
+
+    // Straight contours don't repeat their closing point
+    let dash = font.glyph_contours('-', 8);
+    assert_eq!(dash.len(), 1);
+    assert_eq!(dash[0].len(), 4);
+}
+
+#[test]
+fn test_glyph_contours_follow_subdivision() {
+    let font = common::load_test_font();
+    let points = |subdivision| -> usize {
+        font.glyph_contours('o', subdivision)
+            .iter()
+            .map(Vec::len)
+            .sum()
+    };
+    assert!(points(2) < points(8));
+    assert!(points(8) < points(16));
+
+    assert!(font.glyph_contours(' ', 8).is_empty());
+    assert!(font.glyph_contours('\u{E000}', 8).is_empty());
+}
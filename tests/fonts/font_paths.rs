@@ -0,0 +1,96 @@
+//! Tests for re-resolving font handles by asset path after loading saved text
+
+use crate::common;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+const FONT_PATH: &str = "fonts/FiraMono-Medium.ttf";
+
+/// An app with an asset server that loads from the crate's assets.
+fn asset_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .add_plugins(FontMeshPlugin);
+    app
+}
+
+fn paths(app: &App, entity: Entity) -> &TextMeshFontPaths {
+    app.world().get::<TextMeshFontPaths>(entity).unwrap()
+}
+
+#[test]
+fn test_paths_of_loaded_fonts_are_recorded() {
+    let mut app = asset_app();
+    let font: Handle<FontMesh> = app.world().resource::<AssetServer>().load(FONT_PATH);
+    let direct = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh::new("Save").font(font).style(TextMeshStyle {
+                    fallback_fonts: vec![direct.clone()].into(),
+                    ..default()
+                }),
+                ..default()
+            },
+            TextMeshFontPaths::default(),
+        ))
+        .id();
+    app.update();
+    assert_eq!(
+        *paths(&app, text),
+        TextMeshFontPaths {
+            font: Some(FONT_PATH.to_string()),
+            fallback_fonts: vec![None],
+        }
+    );
+
+    // A font without a path replaces the recorded one
+    app.world_mut().get_mut::<TextMesh>(text).unwrap().font = direct;
+    app.update();
+    assert_eq!(paths(&app, text).font, None);
+}
+
+#[test]
+fn test_saved_text_loads_its_font_by_path() {
+    let mut app = asset_app();
+    // As loaded from a savegame: the paths survived, the handle didn't
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh::new("Load"),
+                ..default()
+            },
+            TextMeshFontPaths {
+                font: Some(FONT_PATH.to_string()),
+                fallback_fonts: Vec::new(),
+            },
+        ))
+        .id();
+    app.update();
+
+    let font = app.world().get::<TextMesh>(text).unwrap().font.clone();
+    let asset_server = app.world().resource::<AssetServer>();
+    assert_eq!(
+        asset_server.get_path(&font).map(|path| path.to_string()),
+        Some(FONT_PATH.to_string())
+    );
+
+    // The text is generated once the font has loaded
+    for _ in 0..500 {
+        if app.world().get::<TextMeshBounds>(text).is_some() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+        app.update();
+    }
+    assert!(app.world().get::<TextMeshBounds>(text).is_some());
+    assert_eq!(paths(&app, text).font.as_deref(), Some(FONT_PATH));
+}
@@ -0,0 +1,143 @@
+//! Tests for placeholders shown when a font never becomes available
+
+use crate::common;
+use bevy::asset::uuid_handle;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+/// A font that is never loaded unless a test inserts it.
+const PENDING_FONT: Handle<FontMesh> = uuid_handle!("5b0c3f4e-8a51-4d1c-9d3e-6f2a7c1b9e40");
+
+fn setup() -> App {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin).init_resource::<Time>();
+    app
+}
+
+fn advance(app: &mut App, seconds: u64) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(seconds));
+    app.update();
+}
+
+fn spawn_text(app: &mut App, text: &str, font: Handle<FontMesh>) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn vertex_count(app: &App, entity: Entity) -> Option<usize> {
+    let handle = &app.world().get::<Mesh3d>(entity)?.0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle)?;
+    Some(mesh.count_vertices())
+}
+
+fn errors(app: &mut App) -> Vec<TextMeshError> {
+    app.world_mut()
+        .resource_mut::<Messages<TextMeshError>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_pending_font_shows_boxes_after_timeout() {
+    let mut app = setup();
+    let font = PENDING_FONT;
+    let entity = spawn_text(&mut app, "Hi there", font.clone());
+
+    app.update();
+    assert_eq!(vertex_count(&app, entity), None);
+    assert!(errors(&mut app).is_empty());
+
+    advance(&mut app, 11);
+    // One box of 6 quads per visible character
+    assert_eq!(vertex_count(&app, entity), Some(7 * 24));
+    assert_eq!(
+        errors(&mut app),
+        vec![TextMeshError::FontUnavailable {
+            entity,
+            font: font.id(),
+        }]
+    );
+
+    // Reported only once
+    advance(&mut app, 11);
+    assert!(errors(&mut app).is_empty());
+}
+
+#[test]
+fn test_unparsable_font_is_replaced_immediately() {
+    let mut app = setup();
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(FontMesh {
+            data: b"not a font".as_slice().into(),
+            face_index: 0,
+        });
+    let entity = spawn_text(&mut app, "A", font);
+
+    app.update();
+    assert_eq!(vertex_count(&app, entity), Some(24));
+    assert_eq!(errors(&mut app).len(), 1);
+}
+
+#[test]
+fn test_placeholder_font_is_used_when_set() {
+    let mut app = setup();
+    let placeholder = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+    app.insert_resource(FontLoadTimeout {
+        timeout: 1.0,
+        placeholder_font: Some(placeholder.clone()),
+    });
+    let pending = spawn_text(&mut app, "Hello", PENDING_FONT);
+    let reference = spawn_text(&mut app, "Hello", placeholder);
+
+    app.update();
+    advance(&mut app, 2);
+    assert!(vertex_count(&app, reference).is_some());
+    assert_eq!(vertex_count(&app, pending), vertex_count(&app, reference));
+}
+
+#[test]
+fn test_real_font_replaces_placeholder_once_loaded() {
+    let mut app = setup();
+    let font = PENDING_FONT;
+    let entity = spawn_text(&mut app, "Hi", font.clone());
+
+    app.update();
+    advance(&mut app, 11);
+    assert_eq!(vertex_count(&app, entity), Some(2 * 24));
+
+    app.world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .insert(font.id(), common::load_test_font())
+        .unwrap();
+    app.update();
+    assert!(app.world().get::<TextMeshComputed>(entity).is_some());
+    assert_ne!(vertex_count(&app, entity), Some(2 * 24));
+}
+
+#[test]
+fn test_without_settings_text_stays_empty() {
+    let mut app = setup();
+    app.world_mut().remove_resource::<FontLoadTimeout>();
+    let entity = spawn_text(&mut app, "Hi", PENDING_FONT);
+
+    app.update();
+    advance(&mut app, 60);
+    assert_eq!(vertex_count(&app, entity), None);
+}
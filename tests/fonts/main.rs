@@ -0,0 +1,14 @@
+//! Integration tests for loading, parsing, and falling back between fonts
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod default_font;
+mod font_collection;
+mod font_fallback;
+mod font_metrics;
+mod font_paths;
+mod font_timeout;
+mod ready;
+mod reload;
+mod woff;
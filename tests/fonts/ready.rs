@@ -0,0 +1,62 @@
+//! Tests for the messages sent when text meshes finish building
+
+use crate::common::TestApp;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn drain<M: Message>(test: &mut TestApp) -> Vec<M> {
+    test.world_mut()
+        .resource_mut::<Messages<M>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_text_mesh_ready_once_per_build() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("Ready", TextMeshStyle::default());
+    test.update();
+    assert_eq!(
+        drain::<TextMeshReady>(&mut test),
+        vec![TextMeshReady { entity: text }]
+    );
+
+    // Nothing is sent while the text is unchanged, and edits send it again
+    test.update();
+    assert!(drain::<TextMeshReady>(&mut test).is_empty());
+    test.edit_text(text, |text_mesh| text_mesh.text = "Set".to_string());
+    test.update();
+    assert_eq!(
+        drain::<TextMeshReady>(&mut test),
+        vec![TextMeshReady { entity: text }]
+    );
+}
+
+#[test]
+fn test_glyph_meshes_ready_counts_shown_glyphs() {
+    let mut test = TestApp::new();
+    let text = test.spawn_glyphs("a b", TextMeshStyle::default());
+    test.update();
+    assert_eq!(
+        drain::<GlyphMeshesReady>(&mut test),
+        vec![GlyphMeshesReady {
+            entity: text,
+            glyph_count: 2
+        }]
+    );
+
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "abc".to_string();
+    test.update();
+    assert_eq!(
+        drain::<GlyphMeshesReady>(&mut test),
+        vec![GlyphMeshesReady {
+            entity: text,
+            glyph_count: 3
+        }]
+    );
+    assert!(drain::<TextMeshReady>(&mut test).is_empty());
+}
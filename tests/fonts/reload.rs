@@ -0,0 +1,117 @@
+//! Tests for regenerating text when its font asset is modified
+
+use crate::common;
+use bevy::asset::AssetPlugin;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// An app with an asset server, like one that hot reloads fonts.
+fn asset_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .add_plugins(FontMeshPlugin);
+    app
+}
+
+fn drain<M: Message>(app: &mut App) -> Vec<M> {
+    app.world_mut()
+        .resource_mut::<Messages<M>>()
+        .drain()
+        .collect()
+}
+
+fn glyph_meshes(app: &App, entity: Entity) -> Vec<AssetId<Mesh>> {
+    app.world()
+        .get::<Children>(entity)
+        .unwrap()
+        .iter()
+        .filter_map(|child| app.world().get::<Mesh3d>(child))
+        .map(|mesh| mesh.id())
+        .collect()
+}
+
+#[test]
+fn test_modified_font_regenerates_its_texts() {
+    let mut app = asset_app();
+    let (reloaded, other) = {
+        let mut fonts = app.world_mut().resource_mut::<Assets<FontMesh>>();
+        (
+            fonts.add(common::load_test_font()),
+            fonts.add(common::load_test_font()),
+        )
+    };
+    let spawn_text = |app: &mut App, font: &Handle<FontMesh>| {
+        app.world_mut()
+            .spawn(TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Reload".to_string(),
+                    font: font.clone(),
+                    ..default()
+                },
+                ..default()
+            })
+            .id()
+    };
+    let text = spawn_text(&mut app, &reloaded);
+    let twin = spawn_text(&mut app, &reloaded);
+    let untouched = spawn_text(&mut app, &other);
+    let glyphs = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: "ab".to_string(),
+                font: reloaded.clone(),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+    app.update();
+    drain::<TextMeshReady>(&mut app);
+    drain::<GlyphMeshesReady>(&mut app);
+    let old_mesh = app.world().get::<Mesh3d>(text).unwrap().id();
+    let old_glyph_meshes = glyph_meshes(&app, glyphs);
+
+    // Nothing is regenerated while the fonts stay the same
+    app.update();
+    assert!(drain::<TextMeshReady>(&mut app).is_empty());
+
+    // Replacing the font asset, as a hot reload does, rebuilds the text using it
+    app.world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .insert(reloaded.id(), common::load_test_font())
+        .unwrap();
+    app.update();
+    app.update();
+    let mut ready: Vec<Entity> = drain::<TextMeshReady>(&mut app)
+        .into_iter()
+        .map(|ready| ready.entity)
+        .collect();
+    ready.sort();
+    let mut expected = vec![text, twin];
+    expected.sort();
+    assert_eq!(ready, expected);
+    assert!(!ready.contains(&untouched));
+
+    // Cached lines and shared meshes of the old font are not reused
+    let new_mesh = app.world().get::<Mesh3d>(text).unwrap().id();
+    assert_ne!(new_mesh, old_mesh);
+    assert_eq!(app.world().get::<Mesh3d>(twin).unwrap().id(), new_mesh);
+
+    // Every glyph gets a new mesh
+    assert_eq!(
+        drain::<GlyphMeshesReady>(&mut app),
+        [GlyphMeshesReady {
+            entity: glyphs,
+            glyph_count: 2
+        }]
+    );
+    let new_glyph_meshes = glyph_meshes(&app, glyphs);
+    assert_eq!(new_glyph_meshes.len(), 2);
+    assert!(new_glyph_meshes
+        .iter()
+        .all(|mesh| !old_glyph_meshes.contains(mesh)));
+}
@@ -0,0 +1,137 @@
+//! Tests for loading WOFF web fonts
+#![cfg(feature = "woff")]
+
+use crate::common;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+/// Wrap a font into a WOFF container, storing every table uncompressed.
+fn build_woff(font: &[u8]) -> Vec<u8> {
+    let table_count = read_u16(font, 4) as usize;
+    let header_len = 44 + table_count * 20;
+    let mut directory = Vec::new();
+    let mut tables = Vec::new();
+    for table in 0..table_count {
+        let record = 12 + table * 16;
+        let offset = read_u32(font, record + 8) as usize;
+        let length = read_u32(font, record + 12) as usize;
+        directory.extend_from_slice(&font[record..record + 4]);
+        directory.extend_from_slice(&((header_len + tables.len()) as u32).to_be_bytes());
+        directory.extend_from_slice(&(length as u32).to_be_bytes());
+        directory.extend_from_slice(&(length as u32).to_be_bytes());
+        directory.extend_from_slice(&font[record + 4..record + 8]);
+        tables.extend_from_slice(&font[offset..offset + length]);
+        tables.resize(tables.len().next_multiple_of(4), 0);
+    }
+
+    let mut data = Vec::new();
+    data.extend_from_slice(b"wOFF");
+    data.extend_from_slice(&font[0..4]);
+    data.extend_from_slice(&((header_len + tables.len()) as u32).to_be_bytes());
+    data.extend_from_slice(&(table_count as u16).to_be_bytes());
+    data.extend_from_slice(&0u16.to_be_bytes());
+    data.extend_from_slice(&(font.len() as u32).to_be_bytes());
+    data.extend_from_slice(&1u16.to_be_bytes());
+    data.extend_from_slice(&0u16.to_be_bytes());
+    // No metadata or private data blocks
+    data.extend_from_slice(&[0; 20]);
+    data.extend_from_slice(&directory);
+    data.extend_from_slice(&tables);
+    data
+}
+
+fn asset_app(dir: &Path) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: dir.to_string_lossy().into_owned(),
+            ..default()
+        },
+    ))
+    .init_asset::<Mesh>()
+    .add_plugins(FontMeshPlugin);
+    app
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("bevy_fontmesh_{name}_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn wait_for_load(app: &mut App, handle: &Handle<FontMesh>) -> LoadState {
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    for _ in 0..500 {
+        app.update();
+        match asset_server.load_state(handle) {
+            LoadState::Loading | LoadState::NotLoaded => {}
+            state => return state,
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    asset_server.load_state(handle)
+}
+
+#[test]
+fn test_woff_fonts_are_decompressed_on_load() {
+    let font = common::load_test_font_data();
+    let dir = temp_dir("woff");
+    fs::write(dir.join("font.woff"), build_woff(&font)).unwrap();
+
+    let mut app = asset_app(&dir);
+    let handle: Handle<FontMesh> = app.world().resource::<AssetServer>().load("font.woff");
+    let state = wait_for_load(&mut app, &handle);
+    let _ = fs::remove_dir_all(&dir);
+    assert!(matches!(state, LoadState::Loaded));
+
+    let fonts = app.world().resource::<Assets<FontMesh>>();
+    let loaded = fonts.get(&handle).unwrap();
+    assert_ne!(&loaded.data[..4], b"wOFF");
+    let original = FontMesh {
+        data: font.into(),
+        face_index: 0,
+    };
+    assert_eq!(
+        loaded.glyph_metrics('A').unwrap().advance,
+        original.glyph_metrics('A').unwrap().advance
+    );
+    assert_eq!(
+        loaded.font_metrics().unwrap().line_height,
+        original.font_metrics().unwrap().line_height
+    );
+}
+
+#[test]
+fn test_corrupt_web_fonts_fail_to_load() {
+    let dir = temp_dir("woff_corrupt");
+    let mut woff = build_woff(&common::load_test_font_data());
+    woff.truncate(woff.len() / 2);
+    fs::write(dir.join("broken.woff"), woff).unwrap();
+    fs::write(dir.join("broken.woff2"), b"wOF2 is not enough").unwrap();
+
+    let mut app = asset_app(&dir);
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let woff: Handle<FontMesh> = asset_server.load("broken.woff");
+    let woff2: Handle<FontMesh> = asset_server.load("broken.woff2");
+    let states = [
+        wait_for_load(&mut app, &woff),
+        wait_for_load(&mut app, &woff2),
+    ];
+    let _ = fs::remove_dir_all(&dir);
+    assert!(states
+        .iter()
+        .all(|state| matches!(state, LoadState::Failed(_))));
+}
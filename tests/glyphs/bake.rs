@@ -0,0 +1,99 @@
+//! Tests for baking bevels into normal maps
+
+use crate::common;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn bake(text: &str, bevel: Option<BevelStyle>) -> BakedTextMesh {
+    let mut fonts = Assets::<FontMesh>::default();
+    let font = fonts.add(common::load_test_font());
+    let text_mesh = TextMesh {
+        text: text.to_string(),
+        font,
+        style: TextMeshStyle {
+            depth: 0.2,
+            bevel,
+            ..default()
+        },
+        ..default()
+    };
+
+    bake_bevel_normal_map(&text_mesh, &fonts, &BevelBakeSettings::default())
+        .expect("Text should bake")
+}
+
+/// Decoded normal of the texel at `uv`.
+fn normal_at(image: &Image, uv: Vec2) -> Vec3 {
+    let size = image.size();
+    let x = ((uv.x * size.x as f32) as u32).min(size.x - 1);
+    let y = ((uv.y * size.y as f32) as u32).min(size.y - 1);
+    let texel = ((y * size.x + x) * 4) as usize;
+    let data = image.data.as_ref().unwrap();
+    Vec3::new(
+        data[texel] as f32 / 255.0,
+        data[texel + 1] as f32 / 255.0,
+        data[texel + 2] as f32 / 255.0,
+    ) * 2.0
+        - 1.0
+}
+
+#[test]
+fn test_baked_mesh_is_flat_with_uvs_and_tangents() {
+    let baked = bake("Bake", Some(BevelStyle::default()));
+
+    let positions = baked
+        .mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(VertexAttributeValues::as_float3)
+        .unwrap();
+    assert!(positions.iter().all(|p| p[2] == 0.0), "Mesh should be flat");
+
+    let Some(VertexAttributeValues::Float32x2(uvs)) = baked.mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+    else {
+        panic!("Mesh should have UVs");
+    };
+    assert_eq!(uvs.len(), positions.len());
+    assert!(uvs
+        .iter()
+        .all(|uv| (-1e-4..=1.0 + 1e-4).contains(&uv[0]) && (-1e-4..=1.0 + 1e-4).contains(&uv[1])));
+    assert!(baked.mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_some());
+}
+
+#[test]
+fn test_normal_map_matches_text_aspect() {
+    let baked = bake("WIDE", Some(BevelStyle::default()));
+    let size = baked.normal_map.size();
+    assert!(
+        size.x > size.y * 2,
+        "A single line of text should bake to a wide map, got {size}"
+    );
+}
+
+#[test]
+fn test_bevel_tilts_normals_outward() {
+    // '-' is a plain rectangle, so its left and right edges are bevelled straight sides
+    let baked = bake("-", Some(BevelStyle::default()));
+
+    let center = normal_at(&baked.normal_map, Vec2::new(0.5, 0.5));
+    assert!(
+        center.z > 0.95,
+        "The inset cap should face forward, got {center}"
+    );
+
+    let left = normal_at(&baked.normal_map, Vec2::new(0.002, 0.5));
+    let right = normal_at(&baked.normal_map, Vec2::new(0.998, 0.5));
+    assert!(left.x < -0.2, "Left bevel should lean left, got {left}");
+    assert!(right.x > 0.2, "Right bevel should lean right, got {right}");
+}
+
+#[test]
+fn test_no_bevel_bakes_flat_normals() {
+    let baked = bake("-", None);
+    let data = baked.normal_map.data.as_ref().unwrap();
+    assert!(
+        data.chunks_exact(4)
+            .all(|texel| texel == [128, 128, 255, 255]),
+        "Without a bevel every texel should be the flat normal"
+    );
+}
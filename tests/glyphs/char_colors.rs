@@ -0,0 +1,111 @@
+//! Tests for per-character vertex colors
+
+use crate::common::TestApp;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+const GREEN: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+const BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+const WHITE: [f32; 4] = [1.0; 4];
+
+fn colors(linear: &[[f32; 4]]) -> Option<Vec<Color>> {
+    Some(
+        linear
+            .iter()
+            .map(|&[r, g, b, a]| Color::linear_rgba(r, g, b, a))
+            .collect(),
+    )
+}
+
+fn spawn_colored(test: &mut TestApp, text: &str, char_colors: Option<Vec<Color>>) -> Entity {
+    let text_mesh = TextMesh {
+        char_colors,
+        ..test.text_mesh(text, TextMeshStyle::default())
+    };
+    test.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh,
+            ..default()
+        })
+        .id()
+}
+
+/// The vertex colors of `entity`'s mesh, checking there is one per vertex.
+fn vertex_colors(test: &TestApp, entity: Entity) -> Option<Vec<[f32; 4]>> {
+    let mesh = test.mesh(entity);
+    let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR)? {
+        VertexAttributeValues::Float32x4(colors) => colors.clone(),
+        other => panic!("Colors should be Float32x4, got {other:?}"),
+    };
+    assert_eq!(colors.len(), mesh.count_vertices());
+    Some(colors)
+}
+
+/// Positions of the vertices of `entity` with `color`.
+fn colored_positions(test: &TestApp, entity: Entity, color: [f32; 4]) -> Vec<Vec3> {
+    let colors = vertex_colors(test, entity).unwrap();
+    test.positions(entity)
+        .into_iter()
+        .zip(colors)
+        .filter(|(_, vertex)| *vertex == color)
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[test]
+fn test_colors_tint_their_characters() {
+    let mut test = TestApp::new();
+    // The line break takes up the third color
+    let entity = spawn_colored(&mut test, "AB\nCD", colors(&[RED, GREEN, WHITE, BLUE]));
+    let plain = spawn_colored(&mut test, "AB\nCD", None);
+    test.update();
+
+    let red = colored_positions(&test, entity, RED);
+    let green = colored_positions(&test, entity, GREEN);
+    let blue = colored_positions(&test, entity, BLUE);
+    let white = colored_positions(&test, entity, WHITE);
+    assert!(!red.is_empty() && !green.is_empty() && !blue.is_empty() && !white.is_empty());
+    let max_x = |positions: &[Vec3]| positions.iter().map(|p| p.x).fold(f32::MIN, f32::max);
+    let min_x = |positions: &[Vec3]| positions.iter().map(|p| p.x).fold(f32::MAX, f32::min);
+    let max_y = |positions: &[Vec3]| positions.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+    let min_y = |positions: &[Vec3]| positions.iter().map(|p| p.y).fold(f32::MAX, f32::min);
+
+    // "A" is left of "B" on the first line, "C" and "D" are below, and "D" is past the
+    // end of the colors
+    assert!(max_x(&red) < min_x(&green));
+    assert!(max_y(&blue) < min_y(&red));
+    assert!(max_x(&blue) < min_x(&white));
+    assert!(max_y(&white) < min_y(&green));
+
+    // Colored text doesn't share its mesh with the plain copy
+    assert_ne!(test.mesh_id(entity), test.mesh_id(plain));
+    assert!(vertex_colors(&test, plain).is_none());
+}
+
+#[test]
+fn test_colors_follow_edits() {
+    let mut test = TestApp::new();
+    let entity = spawn_colored(&mut test, "A\nB", colors(&[RED, WHITE, GREEN, BLUE]));
+    test.update();
+    let second_line = colored_positions(&test, entity, GREEN);
+
+    // Typing on the first line hands the next color to the second line
+    test.edit_text(entity, |text_mesh| text_mesh.text = "AA\nB".to_string());
+    test.update();
+    assert!(colored_positions(&test, entity, GREEN).is_empty());
+    assert_eq!(colored_positions(&test, entity, BLUE), second_line);
+
+    // Changing only the colors rebuilds them, and removing them drops the attribute
+    test.edit_text(entity, |text_mesh| {
+        text_mesh.char_colors = colors(&[BLUE]);
+    });
+    test.update();
+    assert!(!colored_positions(&test, entity, BLUE).is_empty());
+    assert!(colored_positions(&test, entity, RED).is_empty());
+
+    test.edit_text(entity, |text_mesh| text_mesh.char_colors = None);
+    test.update();
+    assert!(vertex_colors(&test, entity).is_none());
+}
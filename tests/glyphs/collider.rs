@@ -0,0 +1,127 @@
+//! Tests for collision geometry generation
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_text(app: &mut App, font: Handle<FontMesh>, text: &str) -> Entity {
+    app.world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: text.to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshCollider::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn test_text_collider_matches_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, font, "Hit");
+    app.update();
+
+    let data = app
+        .world()
+        .get::<TextColliderData>(entity)
+        .expect("Text should get collider data");
+    assert_eq!(data.shape, TextColliderShape::TriMesh);
+    assert!(!data.indices.is_empty());
+    assert!(data
+        .indices
+        .iter()
+        .flatten()
+        .all(|&i| (i as usize) < data.vertices.len()));
+
+    // Welding never adds vertices
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    assert!(data.vertices.len() <= mesh.count_vertices());
+}
+
+#[test]
+fn test_text_collider_follows_edits() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, font, "i");
+    app.update();
+    let before = app.world().get::<TextColliderData>(entity).unwrap().clone();
+
+    app.world_mut()
+        .get_mut::<TextMesh>(entity)
+        .unwrap()
+        .text
+        .push_str("iii");
+    app.update();
+
+    let after = app.world().get::<TextColliderData>(entity).unwrap();
+    assert!(
+        after.indices.len() > before.indices.len(),
+        "Collider should grow with the text"
+    );
+}
+
+#[test]
+fn test_glyph_colliders() {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "A B".to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshCollider {
+                shape: TextColliderShape::ConvexHull,
+            },
+        ))
+        .id();
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query::<(&GlyphMesh, &ChildOf, &TextColliderData)>();
+    let colliders: Vec<_> = query
+        .iter(app.world())
+        .filter(|(_, child_of, _)| child_of.parent() == entity)
+        .collect();
+    assert_eq!(
+        colliders.len(),
+        2,
+        "Each visible glyph should get a collider"
+    );
+    assert!(colliders
+        .iter()
+        .all(|(_, _, data)| data.shape == TextColliderShape::ConvexHull));
+}
+
+#[test]
+fn test_removing_collider_clears_data() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, font, "Gone");
+    app.update();
+    assert!(app.world().get::<TextColliderData>(entity).is_some());
+
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<TextMeshCollider>();
+    app.update();
+    assert!(app.world().get::<TextColliderData>(entity).is_none());
+}
+
+#[test]
+fn test_from_mesh_without_triangles() {
+    let mesh = Mesh::new(
+        bevy::mesh::PrimitiveTopology::TriangleList,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+    assert!(TextColliderData::from_mesh(&mesh, TextColliderShape::TriMesh).is_none());
+}
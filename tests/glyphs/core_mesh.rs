@@ -0,0 +1,32 @@
+//! Tests for the engine-agnostic core API
+
+use crate::common::{load_test_font, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::core::build_text_mesh;
+use bevy_fontmesh::prelude::*;
+use bevy_fontmesh::MeshSnapshot;
+
+#[test]
+fn test_core_mesh_matches_the_plugin() {
+    let style = TextMeshStyle {
+        depth: 0.2,
+        anchor: TextAnchor::Center,
+        justify: JustifyText::Center,
+        bevel: Some(BevelStyle::default()),
+        side_uvs: true,
+        ..default()
+    };
+    let text = "Baked\nahead";
+
+    let mut test = TestApp::new();
+    let entity = test.spawn_text(text, style.clone());
+    test.update();
+    let runtime = MeshSnapshot::from_mesh(test.mesh(entity));
+
+    let font = load_test_font();
+    let baked = build_text_mesh(&font, &[], text, &style).expect("The font should parse");
+    let baked = MeshSnapshot::from_mesh(&baked.into_mesh());
+
+    assert!(!baked.positions.is_empty());
+    assert_eq!(baked, runtime);
+}
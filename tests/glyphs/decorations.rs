@@ -0,0 +1,32 @@
+//! Tests for underline, strikethrough, and overline bars
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn decorated(decorations: TextDecorations) -> TextMeshStyle {
+    TextMeshStyle {
+        decorations,
+        ..default()
+    }
+}
+
+#[test]
+fn test_decorated_text_keeps_one_color_per_vertex() {
+    let mut test = TestApp::new();
+    let mut text_mesh = test.text_mesh(
+        "ab\ncd",
+        decorated(TextDecorations {
+            underline: true,
+            strikethrough: true,
+            ..default()
+        }),
+    );
+    text_mesh.char_colors = Some(vec![Color::WHITE, Color::BLACK]);
+    let text = test.world_mut().spawn(text_mesh).id();
+    test.update();
+
+    let mesh = test.mesh(text);
+    let colors = mesh.attribute(Mesh::ATTRIBUTE_COLOR).unwrap();
+    assert_eq!(colors.len(), mesh.count_vertices());
+}
@@ -0,0 +1,38 @@
+//! Tests for text engraved into a plaque
+
+use crate::common::{extent, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+const THICKNESS: f32 = 0.2;
+const DEPTH: f32 = 0.05;
+const MARGIN: f32 = 0.3;
+
+fn engraved_style() -> TextMeshStyle {
+    TextMeshStyle {
+        depth: THICKNESS,
+        render_mode: TextRenderMode::Engraved {
+            depth: DEPTH,
+            margin: MARGIN,
+        },
+        ..default()
+    }
+}
+
+#[test]
+fn test_plaque_follows_edited_lines() {
+    let mut test = TestApp::new();
+    let entity = test.spawn_text("AB", engraved_style());
+    test.update();
+    let (min, max) = extent(&test.positions(entity));
+
+    test.edit_text(entity, |text| text.text = "AB\nABCD".to_string());
+    test.update();
+    let (new_min, new_max) = extent(&test.positions(entity));
+    assert!(new_max.x - new_min.x > max.x - min.x + 0.5);
+    assert!(new_max.y - new_min.y > max.y - min.y + 0.5);
+
+    // The bounds are the plaque's
+    let bounds = test.bounds(entity);
+    assert!((bounds.size().x - (new_max.x - new_min.x)).abs() < 1e-3);
+}
@@ -0,0 +1,126 @@
+//! Tests for glow shells around text
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_glowing(app: &mut App, font: &Handle<FontMesh>, glow: TextMeshGlow) -> Entity {
+    app.world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "GLOW".to_string(),
+                    font: font.clone(),
+                    ..default()
+                },
+                ..default()
+            },
+            glow,
+        ))
+        .id()
+}
+
+fn shells(app: &mut App) -> Vec<(Entity, Entity)> {
+    let mut query = app
+        .world_mut()
+        .query_filtered::<(Entity, &ChildOf), With<TextMeshGlowShell>>();
+    query
+        .iter(app.world())
+        .map(|(entity, child_of)| (entity, child_of.parent()))
+        .collect()
+}
+
+fn mesh_of(app: &App, entity: Entity) -> Mesh {
+    let handle = app.world().get::<Mesh3d>(entity).unwrap();
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .unwrap()
+        .clone()
+}
+
+fn extent(mesh: &Mesh) -> (Vec3, Vec3) {
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap();
+    positions.iter().map(|&p| Vec3::from_array(p)).fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), p| (min.min(p), max.max(p)),
+    )
+}
+
+#[test]
+fn test_shell_surrounds_the_text() {
+    let (mut app, font) = common::app_with_font();
+    let material = Handle::<StandardMaterial>::default();
+    let text = spawn_glowing(
+        &mut app,
+        &font,
+        TextMeshGlow {
+            inflate: 0.05,
+            material: material.clone(),
+        },
+    );
+    app.update();
+
+    let shells = shells(&mut app);
+    assert_eq!(shells.len(), 1);
+    let (shell, parent) = shells[0];
+    assert_eq!(parent, text);
+    assert_eq!(
+        app.world()
+            .get::<MeshMaterial3d<StandardMaterial>>(shell)
+            .unwrap()
+            .0,
+        material
+    );
+
+    let (text_mesh, shell_mesh) = (mesh_of(&app, text), mesh_of(&app, shell));
+    assert_eq!(text_mesh.count_vertices(), shell_mesh.count_vertices());
+    let (text_min, text_max) = extent(&text_mesh);
+    let (shell_min, shell_max) = extent(&shell_mesh);
+    for axis in 0..3 {
+        assert!(shell_min[axis] < text_min[axis] - 0.01);
+        assert!(shell_max[axis] > text_max[axis] + 0.01);
+        assert!(shell_max[axis] <= text_max[axis] + 0.05 + 1e-4);
+    }
+}
+
+#[test]
+fn test_shell_follows_text_changes() {
+    let (mut app, font) = common::app_with_font();
+    let text = spawn_glowing(&mut app, &font, TextMeshGlow::default());
+    app.update();
+    let (shell, _) = shells(&mut app)[0];
+    let before = mesh_of(&app, shell).count_vertices();
+
+    app.world_mut().get_mut::<TextMesh>(text).unwrap().text = "GLOWING".to_string();
+    app.update();
+
+    // The same shell entity is reused
+    assert_eq!(shells(&mut app), vec![(shell, text)]);
+    let after = mesh_of(&app, shell);
+    assert!(after.count_vertices() > before);
+    assert_eq!(after.count_vertices(), mesh_of(&app, text).count_vertices());
+}
+
+#[test]
+fn test_removing_the_glow_despawns_the_shell() {
+    let (mut app, font) = common::app_with_font();
+    let text = spawn_glowing(&mut app, &font, TextMeshGlow::default());
+    app.update();
+    assert_eq!(shells(&mut app).len(), 1);
+
+    app.world_mut().entity_mut(text).remove::<TextMeshGlow>();
+    app.update();
+    assert!(shells(&mut app).is_empty());
+}
+
+#[test]
+fn test_additive_material_blends_additively() {
+    let material = TextMeshGlow::additive_material(Color::WHITE);
+    assert_eq!(material.alpha_mode, AlphaMode::Add);
+    assert!(material.unlit);
+}
@@ -0,0 +1,123 @@
+//! Tests for the per-glyph wave, bounce, and shake animations
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn setup(animation: impl Bundle) -> (App, Entity) {
+    let (mut app, font) = common::app_with_font();
+    app.init_resource::<Time>();
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "Hello".to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            animation,
+        ))
+        .id();
+
+    // Spawn the glyphs, then let the animation pick them up
+    app.update();
+    (app, entity)
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+/// Offset of every glyph from its origin, in character order.
+fn offsets(app: &mut App) -> Vec<Vec3> {
+    let mut query = app.world_mut().query::<(&GlyphMesh, &Transform)>();
+    let mut glyphs: Vec<_> = query
+        .iter(app.world())
+        .map(|(glyph, transform)| (glyph.char_index, transform.translation - glyph.origin))
+        .collect();
+    glyphs.sort_by_key(|(index, _)| *index);
+    glyphs.into_iter().map(|(_, offset)| offset).collect()
+}
+
+#[test]
+fn test_glyphs_start_at_origin() {
+    let (mut app, _) = setup(());
+    let offsets = offsets(&mut app);
+    assert_eq!(offsets.len(), 5);
+    assert!(offsets.iter().all(|offset| *offset == Vec3::ZERO));
+}
+
+#[test]
+fn test_wave_phase_runs_along_text() {
+    let wave = GlyphWave {
+        amplitude: 1.0,
+        period: 1.0,
+        wavelength: 4.0,
+    };
+    let (mut app, _) = setup(wave.clone());
+    advance(&mut app, 0.25);
+
+    let offsets = offsets(&mut app);
+    for (index, offset) in offsets.iter().enumerate() {
+        let expected = wave.offset(index, 0.25);
+        assert!(
+            offset.abs_diff_eq(expected, 1e-5),
+            "Glyph {index} should be at {expected}, got {offset}"
+        );
+    }
+    // A quarter period in, the first glyph is at the crest and the second a quarter wave behind
+    assert!((offsets[0].y - 1.0).abs() < 1e-5);
+    assert!(offsets[1].y.abs() < 1e-5);
+}
+
+#[test]
+fn test_bounce_stays_above_baseline() {
+    let bounce = GlyphBounce::default();
+    for index in 0..10 {
+        for step in 0..50 {
+            let offset = bounce.offset(index, step as f32 * 0.05);
+            assert!(offset.x == 0.0 && offset.z == 0.0);
+            assert!((0.0..=bounce.height + 1e-6).contains(&offset.y));
+        }
+    }
+    // The second glyph lags behind the first
+    assert!(bounce.offset(1, 0.1).y < bounce.offset(0, 0.1).y);
+}
+
+#[test]
+fn test_shake_is_bounded_and_deterministic() {
+    let shake = GlyphShake::default();
+    let mut distinct = 0;
+    for step in 0..100 {
+        let elapsed = (step as f32 + 0.5) / shake.rate;
+        let offset = shake.offset(3, elapsed);
+        assert!(offset.x.abs() <= shake.intensity && offset.y.abs() <= shake.intensity);
+        assert_eq!(offset.z, 0.0);
+        assert_eq!(offset, shake.offset(3, elapsed));
+        if offset != shake.offset(3, elapsed + 1.0 / shake.rate) {
+            distinct += 1;
+        }
+    }
+    assert!(distinct > 90, "The shake should change every step");
+}
+
+#[test]
+fn test_removing_animation_restores_origin() {
+    let (mut app, entity) = setup((GlyphWave::default(), GlyphShake::default()));
+    advance(&mut app, 0.3);
+    assert!(offsets(&mut app).iter().any(|offset| *offset != Vec3::ZERO));
+
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<(GlyphWave, GlyphShake)>();
+    advance(&mut app, 0.1);
+    assert!(offsets(&mut app).iter().all(|offset| *offset == Vec3::ZERO));
+}
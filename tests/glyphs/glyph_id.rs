@@ -0,0 +1,108 @@
+//! Tests for measuring and meshing glyphs by id
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use bevy_fontmesh::GlyphId;
+use std::collections::HashSet;
+
+fn glyph_id(font: &FontMesh, character: char) -> GlyphId {
+    let face = ttf_parser::Face::parse(&font.data, 0).unwrap();
+    face.glyph_index(character).unwrap()
+}
+
+fn positions(mesh: &Mesh) -> Vec<Vec3> {
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .iter()
+        .map(|&v| Vec3::from_array(v))
+        .collect()
+}
+
+#[test]
+fn test_metrics_by_id_match_metrics_by_char() {
+    let font = common::load_test_font();
+    for character in ['A', 'g', ' '] {
+        let by_char = font.glyph_metrics(character).unwrap();
+        let by_id = font
+            .glyph_metrics_by_id(glyph_id(&font, character))
+            .unwrap();
+        assert!((by_char.advance - by_id.advance).abs() < 1e-6);
+        assert_eq!(by_char.has_outline, by_id.has_outline, "{character:?}");
+    }
+}
+
+#[test]
+fn test_unknown_glyph_id() {
+    let font = common::load_test_font();
+    let face = ttf_parser::Face::parse(&font.data, 0).unwrap();
+    let past_end = GlyphId(face.number_of_glyphs());
+
+    assert!(font.glyph_metrics_by_id(past_end).is_none());
+    assert!(font
+        .glyph_by_id(past_end, &TextMeshStyle::default())
+        .is_none());
+}
+
+#[test]
+fn test_glyph_mesh_spans_glyph_box_and_depth() {
+    let font = common::load_test_font();
+    let face = ttf_parser::Face::parse(&font.data, 0).unwrap();
+    let id = glyph_id(&font, 'H');
+    let style = TextMeshStyle {
+        depth: 0.25,
+        ..default()
+    };
+    let mesh = font.glyph_by_id(id, &style).unwrap();
+
+    let positions = positions(&mesh);
+    let min = positions.iter().copied().fold(Vec3::MAX, Vec3::min);
+    let max = positions.iter().copied().fold(Vec3::MIN, Vec3::max);
+    let bbox = face.glyph_bounding_box(id).unwrap();
+    let units = face.units_per_em() as f32;
+    assert!((min.x - bbox.x_min as f32 / units).abs() < 1e-3);
+    assert!((max.y - bbox.y_max as f32 / units).abs() < 1e-3);
+    assert!((max.z - min.z - 0.25).abs() < 1e-4);
+    assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_none());
+
+    let with_uvs = font
+        .glyph_by_id(
+            id,
+            &TextMeshStyle {
+                side_uvs: true,
+                ..style
+            },
+        )
+        .unwrap();
+    assert!(with_uvs.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+}
+
+#[test]
+fn test_unmapped_glyphs_can_be_meshed() {
+    let font = common::load_test_font();
+    let face = ttf_parser::Face::parse(&font.data, 0).unwrap();
+    let mut mapped = HashSet::new();
+    for subtable in face.tables().cmap.unwrap().subtables {
+        subtable.codepoints(|codepoint| {
+            if let Some(id) = subtable.glyph_index(codepoint) {
+                mapped.insert(id);
+            }
+        });
+    }
+
+    // Glyph 0 is .notdef, which no character maps to either
+    let unmapped = (1..face.number_of_glyphs())
+        .map(GlyphId)
+        .find(|id| !mapped.contains(id) && face.glyph_bounding_box(*id).is_some())
+        .expect("The test font should have alternate glyphs");
+
+    let metrics = font.glyph_metrics_by_id(unmapped).unwrap();
+    let advance = face.glyph_hor_advance(unmapped).unwrap() as f32;
+    assert!(metrics.has_outline);
+    assert!((metrics.advance - advance / face.units_per_em() as f32).abs() < 1e-6);
+    assert!(font
+        .glyph_by_id(unmapped, &TextMeshStyle::default())
+        .is_some());
+}
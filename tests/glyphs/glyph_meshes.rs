@@ -0,0 +1,79 @@
+//! Tests for the cache of single-glyph meshes
+
+use crate::common;
+use crate::common::TestApp;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn glyph_mesh(test: &mut TestApp, character: char, style: &TextMeshStyle) -> Option<Handle<Mesh>> {
+    let font = test.font.clone();
+    test.world_mut()
+        .resource_scope(|world, mut glyph_meshes: Mut<GlyphMeshes>| {
+            world.resource_scope(|world, mut meshes: Mut<Assets<Mesh>>| {
+                let fonts = world.resource::<Assets<FontMesh>>();
+                glyph_meshes.get_or_create(&mut meshes, fonts, &font, character, style)
+            })
+        })
+}
+
+fn positions(test: &TestApp, mesh: &Handle<Mesh>) -> Vec<Vec3> {
+    let mesh = test.world().resource::<Assets<Mesh>>().get(mesh).unwrap();
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => {
+            positions.iter().map(|&p| Vec3::from(p)).collect()
+        }
+        _ => panic!("glyph mesh has no positions"),
+    }
+}
+
+#[test]
+fn test_glyph_meshes_share_one_handle_per_glyph_and_style() {
+    let mut test = TestApp::new();
+    let style = TextMeshStyle::default();
+
+    let first = glyph_mesh(&mut test, 'O', &style).expect("'O' has geometry");
+    let second = glyph_mesh(&mut test, 'O', &style).unwrap();
+    assert_eq!(first, second, "the same glyph should share its mesh");
+    assert_ne!(first, glyph_mesh(&mut test, 'B', &style).unwrap());
+
+    let deep = TextMeshStyle {
+        depth: 0.5,
+        ..default()
+    };
+    let deep_mesh = glyph_mesh(&mut test, 'O', &deep).unwrap();
+    assert_ne!(first, deep_mesh, "another style needs its own mesh");
+
+    let (min, max) = common::extent(&positions(&test, &deep_mesh));
+    assert!((max.z - min.z - 0.5).abs() < 1e-3);
+    assert!(
+        min.x >= -0.01 && min.y >= -0.05,
+        "the glyph sits at its origin"
+    );
+    assert!(max.x < 1.0 && max.y < 1.0, "the glyph is sized in em units");
+}
+
+#[test]
+fn test_glyph_meshes_return_none_without_geometry_or_font() {
+    let mut test = TestApp::new();
+    assert_eq!(glyph_mesh(&mut test, ' ', &TextMeshStyle::default()), None);
+
+    test.font = Handle::default();
+    assert_eq!(glyph_mesh(&mut test, 'A', &TextMeshStyle::default()), None);
+}
+
+#[test]
+fn test_glyph_meshes_free_meshes_nothing_holds() {
+    let mut test = TestApp::new();
+    let style = TextMeshStyle::default();
+
+    let mesh = glyph_mesh(&mut test, 'A', &style).unwrap();
+    test.update();
+    assert_eq!(glyph_mesh(&mut test, 'A', &style).unwrap(), mesh);
+
+    // Once nothing displays the mesh, the cache stops holding on to it
+    let id = mesh.id();
+    drop(mesh);
+    test.update();
+    assert_ne!(glyph_mesh(&mut test, 'A', &style).unwrap().id(), id);
+}
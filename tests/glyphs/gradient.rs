@@ -0,0 +1,141 @@
+//! Tests for vertex-color gradients across text
+
+use crate::common::TestApp;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_gradient(
+    test: &mut TestApp,
+    text: &str,
+    style: TextMeshStyle,
+    gradient: TextGradient,
+    char_colors: Option<Vec<Color>>,
+) -> Entity {
+    let text_mesh = TextMesh {
+        gradient: Some(gradient),
+        char_colors,
+        ..test.text_mesh(text, style)
+    };
+    test.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh,
+            ..default()
+        })
+        .id()
+}
+
+/// Every vertex of `entity` with its color.
+fn colored_vertices(test: &TestApp, entity: Entity) -> Vec<(Vec3, Vec4)> {
+    let colors = match test.mesh(entity).attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(colors)) => colors.clone(),
+        other => panic!("Expected Float32x4 colors, got {other:?}"),
+    };
+    test.positions(entity)
+        .into_iter()
+        .zip(colors.into_iter().map(Vec4::from_array))
+        .collect()
+}
+
+/// Check the colors of `entity` blend linearly from `start` to `end` along `coordinate`.
+fn assert_gradient(
+    test: &TestApp,
+    entity: Entity,
+    coordinate: impl Fn(Vec3) -> f32,
+    start: Vec4,
+    end: Vec4,
+) {
+    let vertices = colored_vertices(test, entity);
+    let (min, max) = vertices
+        .iter()
+        .map(|(position, _)| coordinate(*position))
+        .fold((f32::MAX, f32::MIN), |(min, max), x| {
+            (min.min(x), max.max(x))
+        });
+    for (position, color) in vertices {
+        let t = (coordinate(position) - min) / (max - min);
+        let expected = start.lerp(end, t);
+        assert!(color.abs_diff_eq(expected, 1e-4), "{color} != {expected}");
+    }
+}
+
+#[test]
+fn test_gradient_axes() {
+    let mut test = TestApp::new();
+    let style = TextMeshStyle {
+        depth: 0.5,
+        ..default()
+    };
+    let gradient = |axis| TextGradient::new(Color::linear_rgb(1.0, 0.0, 0.0), Color::BLACK, axis);
+    let horizontal = spawn_gradient(
+        &mut test,
+        "Fade",
+        style.clone(),
+        gradient(GradientAxis::Horizontal),
+        None,
+    );
+    let vertical = spawn_gradient(
+        &mut test,
+        "Two\nlines",
+        style.clone(),
+        gradient(GradientAxis::Vertical),
+        None,
+    );
+    let depth = spawn_gradient(
+        &mut test,
+        "Deep",
+        style,
+        gradient(GradientAxis::Depth),
+        None,
+    );
+    test.update();
+
+    let (red, black) = (Vec4::new(1.0, 0.0, 0.0, 1.0), Vec4::new(0.0, 0.0, 0.0, 1.0));
+    assert_gradient(&test, horizontal, |p| p.x, red, black);
+    assert_gradient(&test, vertical, |p| -p.y, red, black);
+    assert_gradient(&test, depth, |p| -p.z, red, black);
+}
+
+#[test]
+fn test_gradient_spans_edited_text_and_tints_char_colors() {
+    let mut test = TestApp::new();
+    let gradient = TextGradient::new(Color::WHITE, Color::BLACK, GradientAxis::Horizontal);
+    let entity = spawn_gradient(
+        &mut test,
+        "Short\nline",
+        TextMeshStyle::default(),
+        gradient,
+        None,
+    );
+    test.update();
+
+    // Lengthening one line stretches the gradient over the new bounds
+    test.edit_text(entity, |text_mesh| {
+        text_mesh.text = "Short\nmuch longer line".to_string()
+    });
+    test.update();
+    assert_gradient(&test, entity, |p| p.x, Vec4::ONE, Vec4::W);
+
+    // The gradient multiplies the per-character colors
+    let tinted = spawn_gradient(
+        &mut test,
+        "AB",
+        TextMeshStyle::default(),
+        gradient,
+        Some(vec![Color::linear_rgb(0.0, 1.0, 0.0)]),
+    );
+    test.update();
+    // "A" keeps only its green channel, and "B", without a char color, is plain gray
+    let vertices = colored_vertices(&test, tinted);
+    let green = |color: Vec4| color.x == 0.0 && color.z == 0.0;
+    let gray = |color: Vec4| color.x == color.y && color.y == color.z;
+    assert!(vertices
+        .iter()
+        .all(|&(_, color)| green(color) || gray(color)));
+    assert!(vertices
+        .iter()
+        .any(|&(_, color)| green(color) && color.y > 0.0));
+    assert!(vertices
+        .iter()
+        .any(|&(_, color)| gray(color) && color.x > 0.0));
+}
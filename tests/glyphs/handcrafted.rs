@@ -0,0 +1,124 @@
+//! Tests for the per-glyph variation of handcrafted text
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn handcrafted(seed: u32) -> TextMeshStyle {
+    TextMeshStyle {
+        handcrafted: Some(Handcrafted { seed, ..default() }),
+        ..default()
+    }
+}
+
+/// Vertex positions of a freshly generated text.
+fn positions(text: &str, style: TextMeshStyle) -> Vec<Vec3> {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .iter()
+        .map(|&v| Vec3::from_array(v))
+        .collect()
+}
+
+fn max_distance(a: &[Vec3], b: &[Vec3]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.distance(*b))
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_same_seed_gives_same_mesh() {
+    let first = positions("Hello", handcrafted(3));
+    let second = positions("Hello", handcrafted(3));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_variation_depends_on_seed() {
+    let plain = positions("Hello", TextMeshStyle::default());
+    let first = positions("Hello", handcrafted(3));
+    let second = positions("Hello", handcrafted(4));
+
+    assert!(max_distance(&plain, &first) > 1e-3);
+    assert!(max_distance(&first, &second) > 1e-3);
+    // Small enough to still read as the same text
+    assert!(max_distance(&plain, &first) < 0.2);
+}
+
+#[test]
+fn test_zero_amounts_keep_glyphs_unchanged() {
+    let plain = positions("Hello", TextMeshStyle::default());
+    let style = TextMeshStyle {
+        handcrafted: Some(Handcrafted {
+            seed: 3,
+            rotation: 0.0,
+            scale: 0.0,
+            depth: 0.0,
+        }),
+        ..default()
+    };
+    assert!(max_distance(&plain, &positions("Hello", style)) < 1e-5);
+}
+
+#[test]
+fn test_earlier_lines_unaffected_by_later_edits() {
+    let first_line = positions("Sign", handcrafted(9)).len();
+    let before = positions("Sign\nOpen", handcrafted(9));
+    let after = positions("Sign\nClosed", handcrafted(9));
+
+    // Compare relative to the first vertex, as anchoring may move the whole block
+    let relative = |positions: &[Vec3]| -> Vec<Vec3> {
+        positions[..first_line]
+            .iter()
+            .map(|v| *v - positions[0])
+            .collect()
+    };
+    assert!(max_distance(&relative(&before), &relative(&after)) < 1e-5);
+}
+
+#[test]
+fn test_flat_text_stays_flat() {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn(TextMesh2dBundle {
+            text_mesh: TextMesh2d {
+                text: "Flat".to_string(),
+                font,
+                style: handcrafted(1),
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let handle = &app.world().get::<Mesh2d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap();
+    assert!(positions.iter().all(|v| v[2] == 0.0));
+}
@@ -0,0 +1,118 @@
+//! Tests for distance-based levels of detail
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_camera(test: &mut TestApp, position: Vec3) -> Entity {
+    test.world_mut()
+        .spawn((
+            Camera::default(),
+            GlobalTransform::from_translation(position),
+        ))
+        .id()
+}
+
+fn move_camera(test: &mut TestApp, camera: Entity, position: Vec3) {
+    *test.world_mut().get_mut::<GlobalTransform>(camera).unwrap() =
+        GlobalTransform::from_translation(position);
+}
+
+fn spawn_lod_text(test: &mut TestApp, text: &str) -> Entity {
+    let entity = test.spawn_text(
+        text,
+        TextMeshStyle {
+            subdivision: 20,
+            ..default()
+        },
+    );
+    test.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshLod::new([(10.0, 6), (30.0, 1)]));
+    entity
+}
+
+fn lod_meshes(test: &TestApp, entity: Entity) -> Vec<AssetId<Mesh>> {
+    test.world()
+        .get::<TextMeshLodMeshes>(entity)
+        .unwrap()
+        .meshes
+        .iter()
+        .map(Handle::id)
+        .collect()
+}
+
+#[test]
+fn test_levels_have_fewer_triangles() {
+    let mut test = TestApp::new();
+    let text = spawn_lod_text(&mut test, "Sos");
+    test.update();
+
+    let levels = lod_meshes(&test, text);
+    assert_eq!(levels.len(), 3);
+    assert_eq!(levels[0], test.mesh_id(text));
+    let meshes = test.world().resource::<Assets<Mesh>>();
+    let triangles: Vec<usize> = levels
+        .iter()
+        .map(|&id| meshes.get(id).unwrap().indices().unwrap().len() / 3)
+        .collect();
+    assert!(
+        triangles[0] > triangles[1] && triangles[1] > triangles[2],
+        "Expected fewer triangles per level, got {triangles:?}"
+    );
+}
+
+#[test]
+fn test_camera_distance_selects_level() {
+    let mut test = TestApp::new();
+    let camera = spawn_camera(&mut test, Vec3::new(0.0, 0.0, 5.0));
+    let text = spawn_lod_text(&mut test, "Far");
+    test.update();
+    let levels = lod_meshes(&test, text);
+    assert_eq!(test.mesh_id(text), levels[0]);
+
+    move_camera(&mut test, camera, Vec3::new(0.0, 0.0, 20.0));
+    test.update();
+    assert_eq!(test.mesh_id(text), levels[1]);
+
+    move_camera(&mut test, camera, Vec3::new(0.0, 0.0, 100.0));
+    test.update();
+    assert_eq!(test.mesh_id(text), levels[2]);
+
+    // Back up close, and no regeneration along the way
+    move_camera(&mut test, camera, Vec3::new(0.0, 0.0, 1.0));
+    test.update();
+    assert_eq!(test.mesh_id(text), levels[0]);
+    assert_eq!(lod_meshes(&test, text), levels);
+}
+
+#[test]
+fn test_text_edits_regenerate_levels() {
+    let mut test = TestApp::new();
+    spawn_camera(&mut test, Vec3::new(0.0, 0.0, 50.0));
+    let text = spawn_lod_text(&mut test, "Old");
+    test.update();
+    let old = lod_meshes(&test, text);
+    assert_eq!(test.mesh_id(text), old[2]);
+
+    test.edit_text(text, |text_mesh| text_mesh.text = "New text".to_string());
+    test.update();
+    let new = lod_meshes(&test, text);
+    assert!(old.iter().all(|level| !new.contains(level)));
+    assert_eq!(test.mesh_id(text), new[2]);
+
+    // Removing the levels shows the full mesh again
+    test.world_mut().entity_mut(text).remove::<TextMeshLod>();
+    test.update();
+    assert_eq!(test.mesh_id(text), new[0]);
+    assert!(test.world().get::<TextMeshLodMeshes>(text).is_none());
+}
+
+#[test]
+fn test_select_picks_farthest_reached_level() {
+    let lod = TextMeshLod::new([(30.0, 1), (10.0, 6)]);
+    assert_eq!(lod.select(5.0), 0);
+    assert_eq!(lod.select(10.0), 2);
+    assert_eq!(lod.select(45.0), 1);
+    assert_eq!(TextMeshLod::default().select(100.0), 0);
+}
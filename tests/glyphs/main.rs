@@ -0,0 +1,30 @@
+//! Integration tests for the generated geometry: meshes, glyph entities, and their surfaces
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod bake;
+mod char_colors;
+mod collider;
+mod core_mesh;
+mod decorations;
+mod engraved;
+mod glow;
+mod glyph_animation;
+mod glyph_id;
+mod glyph_meshes;
+mod gradient;
+mod handcrafted;
+mod lod;
+mod optimize;
+mod parallel;
+mod render_mode;
+mod shared_mesh;
+mod side_uvs;
+mod silhouette;
+mod snapshot;
+mod stress;
+mod synthetic;
+mod tangents;
+mod texture;
+mod variations;
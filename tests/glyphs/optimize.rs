@@ -0,0 +1,64 @@
+//! Tests for welding the vertices of generated meshes
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Corners of every triangle, in a stable order.
+fn triangles(test: &TestApp, entity: Entity) -> Vec<[f32; 9]> {
+    let positions = test.positions(entity);
+    let indices: Vec<usize> = test.mesh(entity).indices().unwrap().iter().collect();
+    let mut triangles: Vec<[f32; 9]> = indices
+        .chunks(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|corner| positions[triangle[corner]].to_array());
+            [a[0], a[1], a[2], b[0], b[1], b[2], c[0], c[1], c[2]]
+        })
+        .collect();
+    triangles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    triangles
+}
+
+#[test]
+fn test_optimize_welds_vertices_without_changing_the_triangles() {
+    let mut test = TestApp::new();
+    let styles = [
+        TextMeshStyle::default(),
+        TextMeshStyle {
+            bevel: Some(BevelStyle::default()),
+            side_uvs: true,
+            ..default()
+        },
+    ];
+    for style in styles {
+        let optimized = TextMeshStyle {
+            optimize: true,
+            ..style.clone()
+        };
+        let plain = test.spawn_text("Hello world\nwelded", style);
+        let welded = test.spawn_text("Hello world\nwelded", optimized);
+        test.update();
+
+        let (before, after) = (test.positions(plain).len(), test.positions(welded).len());
+        assert!(
+            after < before,
+            "{after} vertices should be fewer than {before}"
+        );
+        assert_eq!(triangles(&test, plain), triangles(&test, welded));
+        assert_eq!(test.bounds(plain), test.bounds(welded));
+
+        // Only identical vertices are merged, so the normals of every position survive
+        let normals_at = |entity| {
+            let mut vertices: Vec<[f32; 6]> = test
+                .positions(entity)
+                .iter()
+                .zip(test.normals(entity))
+                .map(|(p, n)| [p.x, p.y, p.z, n.x, n.y, n.z])
+                .collect();
+            vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            vertices.dedup();
+            vertices
+        };
+        assert_eq!(normals_at(plain), normals_at(welded));
+    }
+}
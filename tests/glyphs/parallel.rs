@@ -0,0 +1,25 @@
+//! Tests for tessellating the glyphs of long lines in parallel
+
+use crate::common::load_test_font;
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPoolBuilder};
+use bevy_fontmesh::core::build_text_mesh;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_parallel_tessellation_matches_sequential() {
+    let font = load_test_font();
+    let line = "The quick brown fox jumps over the lazy dog. ".repeat(4);
+    let text = format!("{line}\nShort");
+    let style = TextMeshStyle {
+        bevel: Some(BevelStyle::default()),
+        handcrafted: Some(Handcrafted::default()),
+        ..default()
+    };
+    let sequential = build_text_mesh(&font, &[], &text, &style).unwrap();
+
+    ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(4).build());
+    let parallel = build_text_mesh(&font, &[], &text, &style).unwrap();
+    assert!(!parallel.vertices.is_empty());
+    assert_eq!(sequential, parallel);
+}
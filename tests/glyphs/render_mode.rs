@@ -0,0 +1,121 @@
+//! Tests for outline-only text
+
+use crate::common::{extent, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn outline_style(width: f32, depth: f32) -> TextMeshStyle {
+    TextMeshStyle {
+        depth,
+        render_mode: TextRenderMode::Outline { width },
+        ..default()
+    }
+}
+
+/// Total area of the triangles facing `+Z` at the front of the text.
+fn front_area(test: &TestApp, entity: Entity) -> f32 {
+    let positions = test.positions(entity);
+    let normals = test.normals(entity);
+    test.indices(entity)
+        .chunks_exact(3)
+        .filter(|triangle| triangle.iter().all(|&i| normals[i as usize].z > 0.99))
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| positions[triangle[k] as usize]);
+            (b - a).cross(c - a).length() * 0.5
+        })
+        .sum()
+}
+
+/// Whether a front-facing triangle covers `point` in the XY plane.
+fn covers(test: &TestApp, entity: Entity, point: Vec2) -> bool {
+    let positions = test.positions(entity);
+    let normals = test.normals(entity);
+    test.indices(entity)
+        .chunks_exact(3)
+        .filter(|triangle| triangle.iter().all(|&i| normals[i as usize].z > 0.99))
+        .any(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| positions[triangle[k] as usize].truncate());
+            let sides = [(a, b), (b, c), (c, a)].map(|(p, q)| (q - p).perp_dot(point - p));
+            sides.iter().all(|&s| s >= 0.0) || sides.iter().all(|&s| s <= 0.0)
+        })
+}
+
+#[test]
+fn test_outline_leaves_the_glyph_hollow() {
+    let mut test = TestApp::new();
+    // The hyphen is a plain rectangle, so its band is easy to measure
+    let filled = test.spawn_text("-", TextMeshStyle::default());
+    let outlined = test.spawn_text("-", outline_style(0.01, 0.1));
+    test.update();
+
+    let (min, max) = extent(&test.positions(filled));
+    let size = (max - min).truncate();
+    let center = ((min + max) * 0.5).truncate();
+    assert!(covers(&test, filled, center));
+    assert!(!covers(&test, outlined, center));
+
+    // A band of width w along the perimeter covers about perimeter * w
+    let perimeter = 2.0 * (size.x + size.y);
+    let area = front_area(&test, outlined);
+    assert!(
+        (area - perimeter * 0.01).abs() < perimeter * 0.01 * 0.1,
+        "Expected a band area near {}, got {area}",
+        perimeter * 0.01
+    );
+    assert!(area < front_area(&test, filled));
+}
+
+#[test]
+fn test_outline_is_extruded_with_walls_on_both_sides() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("-", outline_style(0.02, 0.3));
+    test.update();
+
+    let positions = test.positions(text);
+    let (min, max) = extent(&positions);
+    assert!((max.z - min.z - 0.3).abs() < 1e-4);
+
+    // Walls face away from the band: out of the glyph and into its hollow middle
+    let center = ((min + max) * 0.5).truncate();
+    let normals = test.normals(text);
+    let walls: Vec<(Vec3, Vec3)> = positions
+        .iter()
+        .zip(&normals)
+        .filter(|(_, normal)| normal.z.abs() < 0.01)
+        .map(|(&p, &n)| (p, n))
+        .collect();
+    let facing_center = |(p, n): &(Vec3, Vec3)| n.truncate().dot(center - p.truncate()) > 0.0;
+    assert!(walls.iter().any(facing_center));
+    assert!(walls.iter().any(|wall| !facing_center(wall)));
+}
+
+#[test]
+fn test_outline_counters_get_their_own_ring() {
+    let mut test = TestApp::new();
+    let filled = test.spawn_text("o", TextMeshStyle::default());
+    let outlined = test.spawn_text("o", outline_style(0.02, 0.0));
+    test.update();
+
+    // Find where the counter starts, scanning the filled glyph along its middle
+    let (min, max) = extent(&test.positions(filled));
+    let y = (min.y + max.y) * 0.5;
+    let counter = (0..1000)
+        .map(|step| min.x + step as f32 * 0.001)
+        .find(|&x| x > min.x + 0.01 && !covers(&test, filled, Vec2::new(x, y)))
+        .expect("The o should have a counter");
+    assert!(counter - min.x > 0.05, "Stroke of the o should be thick");
+
+    // Text is aligned to the edge of its mesh, so the outline sits half a stroke to the
+    // right of the filled glyph; measure from the middle of its outer band
+    let (outline_min, _) = extent(&test.positions(outlined));
+    let contour = outline_min.x + 0.01;
+    let at = |offset: f32| covers(&test, outlined, Vec2::new(contour + offset, y));
+    let counter = counter - min.x;
+
+    // Bands on the outer contour and on the counter, empty between them and inside
+    assert!(at(0.0));
+    assert!(!at(counter * 0.5));
+    assert!(at(counter - 0.005));
+    assert!(at(counter + 0.005));
+    assert!(!at((max.x - min.x) * 0.5));
+}
@@ -0,0 +1,115 @@
+//! Tests for sharing mesh assets between texts with identical content
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, text: &str) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font: font.clone(),
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn mesh_id(app: &App, entity: Entity) -> AssetId<Mesh> {
+    app.world().get::<Mesh3d>(entity).unwrap().id()
+}
+
+#[test]
+fn test_identical_texts_share_one_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let labels: Vec<Entity> = (0..200)
+        .map(|_| spawn_text(&mut app, &font, "HP"))
+        .collect();
+    app.update();
+
+    let first = mesh_id(&app, labels[0]);
+    assert!(labels.iter().all(|&label| mesh_id(&app, label) == first));
+    assert!(app.world().resource::<Assets<Mesh>>().get(first).is_some());
+
+    // Texts spawned later pick up the existing mesh too
+    let late = spawn_text(&mut app, &font, "HP");
+    app.update();
+    assert_eq!(mesh_id(&app, late), first);
+    assert!(app.world().get::<TextMeshComputed>(late).is_some());
+}
+
+#[test]
+fn test_different_content_gets_separate_meshes() {
+    let (mut app, font) = common::app_with_font();
+    let hp = spawn_text(&mut app, &font, "HP");
+    let mp = spawn_text(&mut app, &font, "MP");
+    let deep = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "HP".to_string(),
+                font: font.clone(),
+                style: TextMeshStyle {
+                    depth: 0.5,
+                    ..default()
+                },
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    assert_ne!(mesh_id(&app, hp), mesh_id(&app, mp));
+    assert_ne!(mesh_id(&app, hp), mesh_id(&app, deep));
+}
+
+#[test]
+fn test_editing_a_shared_text_leaves_the_others_alone() {
+    let (mut app, font) = common::app_with_font();
+    let first = spawn_text(&mut app, &font, "HP");
+    let second = spawn_text(&mut app, &font, "HP");
+    app.update();
+    let shared = mesh_id(&app, first);
+    let vertex_count = app
+        .world()
+        .resource::<Assets<Mesh>>()
+        .get(shared)
+        .unwrap()
+        .count_vertices();
+
+    for entity in [first, second] {
+        app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "HP 100".to_string();
+        app.update();
+
+        assert_ne!(mesh_id(&app, entity), shared);
+    }
+    // Both edited texts end up sharing again
+    assert_eq!(mesh_id(&app, first), mesh_id(&app, second));
+
+    // Going back to the old content picks up a mesh with the old geometry
+    app.world_mut().get_mut::<TextMesh>(first).unwrap().text = "HP".to_string();
+    app.update();
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    let mesh = meshes.get(mesh_id(&app, first)).unwrap();
+    assert_eq!(mesh.count_vertices(), vertex_count);
+    let other = meshes.get(mesh_id(&app, second)).unwrap();
+    assert!(other.count_vertices() > vertex_count);
+}
+
+#[test]
+fn test_meshes_of_despawned_texts_are_released() {
+    let (mut app, font) = common::app_with_font();
+    let label = spawn_text(&mut app, &font, "HP");
+    app.update();
+    let mesh = mesh_id(&app, label);
+
+    // Once nothing displays the mesh, the plugin stops holding on to it
+    app.world_mut().despawn(label);
+    app.update();
+    let label = spawn_text(&mut app, &font, "HP");
+    app.update();
+    assert_ne!(mesh_id(&app, label), mesh);
+}
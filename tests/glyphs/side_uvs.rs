@@ -0,0 +1,120 @@
+//! Tests for side wall UV generation
+
+use crate::common;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+const DEPTH: f32 = 0.4;
+
+fn style(side_uvs: bool) -> TextMeshStyle {
+    TextMeshStyle {
+        depth: DEPTH,
+        side_uvs,
+        ..default()
+    }
+}
+
+/// The UVs of `entity`'s mesh, checking there is one per vertex.
+fn mesh_uvs(app: &App, entity: Entity) -> Option<Vec<[f32; 2]>> {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0)? {
+        VertexAttributeValues::Float32x2(uvs) => uvs.clone(),
+        other => panic!("Unexpected UV format {other:?}"),
+    };
+    assert_eq!(
+        uvs.len(),
+        mesh.count_vertices(),
+        "Expected one UV per vertex"
+    );
+    Some(uvs)
+}
+
+fn spawn_text(app: &mut App, font: Handle<FontMesh>, text: &str, side_uvs: bool) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                style: style(side_uvs),
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+#[test]
+fn test_uvs_are_opt_in() {
+    let (mut app, font) = common::app_with_font();
+    let plain = spawn_text(&mut app, font.clone(), "Hi", false);
+    let textured = spawn_text(&mut app, font, "Hi", true);
+
+    app.update();
+
+    assert!(mesh_uvs(&app, plain).is_none());
+    assert!(mesh_uvs(&app, textured).is_some());
+}
+
+#[test]
+fn test_side_uvs_follow_contour_and_depth() {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshPartsBundle {
+            text_parts: TextMeshParts {
+                text: "O".to_string(),
+                font,
+                style: style(true),
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query::<(&TextMeshPart, &Mesh3d, &ChildOf)>();
+    let sides = query
+        .iter(app.world())
+        .find(|(part, _, child_of)| **part == TextMeshPart::Sides && child_of.parent() == entity)
+        .map(|(_, mesh, _)| mesh.0.clone())
+        .expect("Sides should be spawned");
+    let mesh = app.world().resource::<Assets<Mesh>>().get(&sides).unwrap();
+    let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) else {
+        panic!("Sides should have UVs");
+    };
+
+    let (min_u, max_u) = uvs.iter().fold((f32::MAX, f32::MIN), |(min, max), uv| {
+        (min.min(uv[0]), max.max(uv[0]))
+    });
+    let (min_v, max_v) = uvs.iter().fold((f32::MAX, f32::MIN), |(min, max), uv| {
+        (min.min(uv[1]), max.max(uv[1]))
+    });
+
+    assert!(min_u.abs() < 1e-5, "Arc length should start at 0");
+    // The outer contour of an 'O' runs all the way around the letter
+    assert!(
+        max_u > 1.0,
+        "Expected the arc length to cover the rim, got {max_u}"
+    );
+    assert!(min_v.abs() < 1e-5 && (max_v - DEPTH).abs() < 1e-5);
+}
+
+#[test]
+fn test_uvs_survive_line_edits() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, font, "one\ntwo", true);
+    app.update();
+
+    app.world_mut()
+        .get_mut::<TextMesh>(entity)
+        .unwrap()
+        .text
+        .push_str("\nthree");
+    app.update();
+
+    assert!(mesh_uvs(&app, entity).is_some());
+}
@@ -0,0 +1,349 @@
+//! Tests for per-line text silhouettes
+
+use crate::common;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Generate the silhouette of `text` with the given style.
+fn silhouette(text: &str, style: TextMeshStyle) -> TextSilhouette {
+    let (mut app, font) = common::app_with_font();
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMesh2d {
+                text: text.to_string(),
+                font,
+                style,
+            },
+            TextSilhouette::default(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world().get::<TextSilhouette>(entity).unwrap().clone()
+}
+
+fn area(polygon: &[Vec2]) -> f32 {
+    (0..polygon.len())
+        .map(|i| polygon[i].perp_dot(polygon[(i + 1) % polygon.len()]))
+        .sum::<f32>()
+        * 0.5
+}
+
+#[test]
+fn test_counters_are_dropped() {
+    let silhouette = silhouette("o", TextMeshStyle::default());
+
+    assert_eq!(silhouette.lines.len(), 1);
+    assert_eq!(
+        silhouette.lines[0].polygons.len(),
+        1,
+        "The hole in 'o' should not produce a polygon"
+    );
+    assert!(area(&silhouette.lines[0].polygons[0]) > 0.0);
+}
+
+#[test]
+fn test_one_silhouette_per_line() {
+    let silhouette = silhouette("ab\n\nc", TextMeshStyle::default());
+
+    assert_eq!(silhouette.lines.len(), 3);
+    assert_eq!(silhouette.lines[0].polygons.len(), 2);
+    assert!(silhouette.lines[1].polygons.is_empty());
+    assert_eq!(silhouette.lines[2].polygons.len(), 1);
+
+    // Later lines sit below earlier ones
+    let top = |line: &LineSilhouette| {
+        line.polygons
+            .iter()
+            .flatten()
+            .map(|p| p.y)
+            .fold(f32::MIN, f32::max)
+    };
+    assert!(top(&silhouette.lines[2]) < top(&silhouette.lines[0]));
+}
+
+#[test]
+fn test_overlapping_glyphs_are_merged() {
+    let separate = silhouette("--", TextMeshStyle::default());
+    assert_eq!(separate.lines[0].polygons.len(), 2);
+
+    // Pull the hyphens into each other
+    let merged = silhouette(
+        "--",
+        TextMeshStyle {
+            letter_spacing: -0.3,
+            ..default()
+        },
+    );
+    assert_eq!(
+        merged.lines[0].polygons.len(),
+        1,
+        "Overlapping glyphs should form a single outline"
+    );
+}
+
+#[test]
+fn test_silhouette_uses_anchor() {
+    let silhouette = silhouette(
+        "Hi",
+        TextMeshStyle {
+            anchor: TextAnchor::Center,
+            ..default()
+        },
+    );
+
+    let (min, max) = silhouette
+        .polygons()
+        .flatten()
+        .fold((Vec2::MAX, Vec2::MIN), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+    let center = (min + max) * 0.5;
+    assert!(
+        center.length() < 1e-4,
+        "Expected centered outline, got {center}"
+    );
+}
+
+/// Generate the layout of an extruded `text` with the given depth.
+fn layout(text: &str, depth: f32) -> TextMeshLayout {
+    let (mut app, font) = common::app_with_font();
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: text.to_string(),
+                    font,
+                    style: TextMeshStyle { depth, ..default() },
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshLayout::default(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world().get::<TextMeshLayout>(entity).unwrap().clone()
+}
+
+#[test]
+fn test_closest_point_on_front_face() {
+    let layout = layout("I", 0.5);
+    let (min, max) = layout
+        .silhouette
+        .polygons()
+        .flatten()
+        .fold((Vec2::MAX, Vec2::MIN), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+    let center = (min + max) * 0.5;
+
+    let (point, normal) = layout
+        .closest_point_on_text(center.extend(1.0))
+        .expect("Text should have a surface");
+    assert_eq!(normal, Vec3::Z);
+    assert!((point - center.extend(0.0)).length() < 1e-4);
+}
+
+#[test]
+fn test_closest_point_on_side_wall() {
+    // The hyphen is a plain rectangle
+    let layout = layout("-", 0.5);
+    let max_x = layout
+        .silhouette
+        .polygons()
+        .flatten()
+        .map(|p| p.x)
+        .fold(f32::MIN, f32::max);
+    let (min_y, max_y) = layout
+        .silhouette
+        .polygons()
+        .flatten()
+        .fold((f32::MAX, f32::MIN), |(min, max), p| {
+            (min.min(p.y), max.max(p.y))
+        });
+
+    // Beside the middle of the bar, halfway into the extrusion
+    let query = Vec3::new(max_x + 1.0, (min_y + max_y) * 0.5, -0.25);
+    let (point, normal) = layout.closest_point_on_text(query).unwrap();
+
+    assert!(
+        (point.x - max_x).abs() < 1e-4,
+        "Expected a point on the right wall"
+    );
+    assert!(
+        (point.z + 0.25).abs() < 1e-4,
+        "Depth should be preserved on walls"
+    );
+    assert!(
+        normal.x > 0.99,
+        "Wall normal should point outward, got {normal}"
+    );
+}
+
+#[test]
+fn test_empty_layout_has_no_surface() {
+    let layout = layout(" ", 0.5);
+    assert!(layout.closest_point_on_text(Vec3::ZERO).is_none());
+}
+
+#[test]
+fn test_ray_cast_hits_front_and_walls() {
+    let layout = layout("-", 0.5);
+    let bounds = layout.bvh.bounds().expect("Text should have polygons");
+    let center = bounds.center();
+
+    // Straight down onto the front face
+    let hit = layout
+        .ray_cast(Ray3d::new(center.extend(2.0), Dir3::NEG_Z))
+        .expect("Ray should hit the front face");
+    assert!((hit.distance - 2.0).abs() < 1e-4);
+    assert_eq!(hit.normal, Vec3::Z);
+    assert_eq!(hit.line, 0);
+
+    // From the side, halfway into the extrusion
+    let hit = layout
+        .ray_cast(Ray3d::new(
+            Vec3::new(bounds.max.x + 1.0, center.y, -0.25),
+            Dir3::NEG_X,
+        ))
+        .expect("Ray should hit the right wall");
+    assert!((hit.point.x - bounds.max.x).abs() < 1e-4);
+    assert!(hit.normal.x > 0.99);
+
+    // Beside the text, and pointing away from it
+    assert!(layout
+        .ray_cast(Ray3d::new(
+            Vec3::new(bounds.max.x + 1.0, center.y, 2.0),
+            Dir3::NEG_Z
+        ))
+        .is_none());
+    assert!(layout
+        .ray_cast(Ray3d::new(center.extend(2.0), Dir3::Z))
+        .is_none());
+}
+
+#[test]
+fn test_long_text_queries_match_brute_force() {
+    let paragraph = (0..40)
+        .map(|line| format!("line {line} of a long paragraph"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let layout = layout(&paragraph, 0.2);
+    let polygon_count = layout.silhouette.polygons().count();
+    assert!(polygon_count > 500);
+    assert!(layout.bvh.node_count() < polygon_count);
+
+    // Every polygon is reachable through the hierarchy
+    let everything = layout.bvh.polygons_in(layout.bvh.bounds().unwrap());
+    assert_eq!(everything.len(), polygon_count);
+
+    // A ray along line 17 hits its leftmost glyph, not any of the other lines
+    let left = |polygon: &Vec<Vec2>| polygon.iter().map(|p| p.x).fold(f32::MAX, f32::min);
+    let glyph = layout.silhouette.lines[17]
+        .polygons
+        .iter()
+        .min_by(|a, b| left(a).total_cmp(&left(b)))
+        .unwrap();
+    let (min_y, max_y) = glyph.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+        (min.min(p.y), max.max(p.y))
+    });
+    let start = Vec3::new(
+        layout.bvh.bounds().unwrap().min.x - 1.0,
+        (min_y + max_y) * 0.5,
+        -0.1,
+    );
+    let hit = layout.ray_cast(Ray3d::new(start, Dir3::X)).unwrap();
+    assert_eq!(hit.line, 17);
+    assert!(hit.normal.x < -0.5);
+    assert!(hit.point.x >= left(glyph) - 1e-4);
+
+    // Closest points agree with the nearest of the lines searched one by one
+    for query in [
+        Vec3::new(1.0, -3.0, 0.5),
+        Vec3::new(-2.0, 1.0, -0.1),
+        Vec3::new(4.0, -20.0, 1.0),
+    ] {
+        let (point, _) = layout.closest_point_on_text(query).unwrap();
+        let brute_force = layout
+            .silhouette
+            .lines
+            .iter()
+            .map(|line| {
+                TextMeshLayout::new(
+                    TextSilhouette {
+                        lines: vec![line.clone()],
+                    },
+                    layout.depth,
+                )
+            })
+            .filter_map(|line| line.closest_point_on_text(query))
+            .map(|(point, _)| point.distance(query))
+            .fold(f32::MAX, f32::min);
+        assert!((point.distance(query) - brute_force).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_line_at_finds_the_line_under_a_point() {
+    let layout = layout("-\n-", 0.5);
+    let center = |line: usize| {
+        let polygon = &layout.silhouette.lines[line].polygons[0];
+        polygon.iter().sum::<Vec2>() / polygon.len() as f32
+    };
+
+    assert_eq!(layout.line_at(center(0)), Some(0));
+    assert_eq!(layout.line_at(center(1)), Some(1));
+    assert_eq!(layout.line_at((center(0) + center(1)) * 0.5), None);
+}
+
+#[test]
+fn test_line_boxes_hold_their_lines_and_make_stripes() {
+    let layout = layout("ab\nlonger line\nc", 0.5);
+    assert_eq!(layout.lines.len(), 3);
+
+    // Every line box contains the outline of its line and has the same height
+    for (line, silhouette) in layout.lines.iter().zip(&layout.silhouette.lines) {
+        let rect = line.rect();
+        assert!((rect.height() - layout.lines[0].rect().height()).abs() < 1e-4);
+        for point in silhouette.polygons.iter().flatten() {
+            assert!(
+                rect.inflate(1e-3).contains(*point),
+                "{point} outside {rect:?}"
+            );
+        }
+    }
+    let widest = layout.lines[1].rect();
+
+    // Zebra stripes cover the first and third line, as wide as the longest line
+    let mesh = layout.stripes_mesh(&LineStripes::default());
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("Expected Float32x3 positions");
+    };
+    let positions: Vec<Vec3> = positions.iter().map(|&p| Vec3::from_array(p)).collect();
+    assert_eq!(positions.len(), 8);
+    assert!(positions.iter().all(|p| (p.z + 0.51).abs() < 1e-5));
+    for (stripe, line) in positions
+        .chunks(4)
+        .zip([&layout.lines[0], &layout.lines[2]])
+    {
+        let (min, max) = stripe
+            .iter()
+            .fold((Vec3::MAX, Vec3::MIN), |(min, max), &p| {
+                (min.min(p), max.max(p))
+            });
+        assert!((min.x - widest.min.x).abs() < 1e-4 && (max.x - widest.max.x).abs() < 1e-4);
+        assert!((min.y - line.rect().min.y).abs() < 1e-4);
+        assert!((max.y - line.rect().max.y).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,82 @@
+//! Golden snapshot tests for generated meshes
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use bevy_fontmesh::{assert_mesh_snapshot, MeshSnapshot, MeshSnapshotMismatch};
+
+fn generate(text: &str, style: TextMeshStyle) -> Mesh {
+    let mut test = TestApp::new();
+    let entity = test.spawn_text(text, style);
+    test.update();
+    test.mesh(entity).clone()
+}
+
+#[test]
+fn test_text_matches_golden() {
+    let mesh = generate(
+        "Hi",
+        TextMeshStyle {
+            subdivision: 4,
+            ..default()
+        },
+    );
+    assert_mesh_snapshot(&mesh, "tests/snapshots/hi.mesh", 1e-4);
+}
+
+#[test]
+fn test_bevelled_text_matches_golden() {
+    let mesh = generate(
+        "I",
+        TextMeshStyle {
+            depth: 0.2,
+            subdivision: 2,
+            bevel: Some(BevelStyle {
+                segments: 1,
+                profile: BevelProfile::Chamfer,
+                ..default()
+            }),
+            side_uvs: true,
+            ..default()
+        },
+    );
+    assert_mesh_snapshot(&mesh, "tests/snapshots/bevelled_i.mesh", 1e-4);
+}
+
+#[test]
+fn test_snapshot_round_trips_through_text() {
+    let mesh = generate("A", TextMeshStyle::default());
+    let snapshot = MeshSnapshot::from_mesh(&mesh);
+    let parsed = MeshSnapshot::parse(&snapshot.to_string()).unwrap();
+
+    assert_eq!(parsed.indices, snapshot.indices);
+    assert!(snapshot.compare(&parsed, 1e-5).is_ok());
+    assert!(MeshSnapshot::parse("not a snapshot").is_err());
+}
+
+#[test]
+fn test_compare_reports_differences() {
+    let golden = MeshSnapshot::from_mesh(&generate("A", TextMeshStyle::default()));
+
+    // Small offsets stay within the tolerance, larger ones don't
+    let mut moved = golden.clone();
+    moved.positions[3].x += 0.00005;
+    assert!(moved.compare(&golden, 1e-4).is_ok());
+    moved.positions[3].x += 0.01;
+    assert!(matches!(
+        moved.compare(&golden, 1e-4),
+        Err(MeshSnapshotMismatch::Vertex { vertex: 3, .. })
+    ));
+
+    let deeper = MeshSnapshot::from_mesh(&generate(
+        "A",
+        TextMeshStyle {
+            depth: 0.5,
+            ..default()
+        },
+    ));
+    assert!(deeper.compare(&golden, 1e-4).is_err());
+
+    let other = MeshSnapshot::from_mesh(&generate("B", TextMeshStyle::default()));
+    assert!(other.compare(&golden, 1e-4).is_err());
+}
@@ -0,0 +1,56 @@
+//! Tests for the benchmark workloads of the stress module
+
+use crate::common::{app_with_font, load_test_font};
+use bevy::prelude::*;
+use bevy_fontmesh::stress::StressScene;
+
+#[test]
+fn test_labels_differ_and_have_their_length() {
+    let scene = StressScene::new(3, 8);
+    let texts: Vec<String> = (0..3).map(|index| scene.label_text(index)).collect();
+    assert!(texts.iter().all(|text| text.chars().count() == 8));
+    assert_ne!(texts[0], texts[1]);
+    assert_ne!(texts[1], texts[2]);
+
+    // Labels fill a square grid
+    let scene = StressScene::new(4, 8).spacing(Vec2::new(10.0, 2.0));
+    assert_eq!(scene.label_position(0), Vec3::ZERO);
+    assert_eq!(scene.label_position(1), Vec3::new(10.0, 0.0, 0.0));
+    assert_eq!(scene.label_position(3), Vec3::new(10.0, -2.0, 0.0));
+}
+
+#[test]
+fn test_measure_counts_more_triangles_at_higher_subdivision() {
+    let font = load_test_font();
+    let coarse = StressScene::new(10, 12).subdivision(2).measure(&font);
+    let fine = StressScene::new(10, 12).subdivision(20).measure(&font);
+
+    assert_eq!(coarse.labels, 10);
+    assert_eq!(coarse.glyphs, 120);
+    assert_eq!(coarse.frames, 0);
+    assert!(coarse.triangles > 0);
+    assert!(fine.triangles > coarse.triangles);
+    assert!(fine.vertices > coarse.vertices);
+}
+
+#[test]
+fn test_run_generates_every_label_in_the_app() {
+    let (mut app, font) = app_with_font();
+    let scene = StressScene::new(5, 6).depth(0.2);
+    let stats = scene.run(&mut app, font, 10);
+
+    assert_eq!(stats.labels, 5);
+    assert_eq!(stats.glyphs, 30);
+    assert!(stats.frames >= 1);
+    assert!(stats.triangles > 0);
+
+    // The labels stay in the world, and match what the core functions build
+    let labels = app
+        .world_mut()
+        .query::<&bevy_fontmesh::TextMesh>()
+        .iter(app.world())
+        .count();
+    assert_eq!(labels, 5);
+    let measured = scene.measure(&load_test_font());
+    assert_eq!(measured.triangles, stats.triangles);
+}
@@ -0,0 +1,69 @@
+//! Tests for faux bold and faux italic glyphs
+
+use crate::common::{extent, load_test_font, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::core::{build_text_mesh, TextMeshData};
+use bevy_fontmesh::prelude::*;
+
+fn build(text: &str, style: TextMeshStyle) -> TextMeshData {
+    let style = TextMeshStyle {
+        anchor: TextAnchor::BaselineLeft,
+        ..style
+    };
+    build_text_mesh(&load_test_font(), &[], text, &style).unwrap()
+}
+
+fn bounds(data: &TextMeshData) -> (Vec3, Vec3) {
+    let points: Vec<Vec3> = data
+        .vertices
+        .iter()
+        .copied()
+        .map(Vec3::from_array)
+        .collect();
+    extent(&points)
+}
+
+#[test]
+fn test_synthetic_styles_apply_to_every_glyph_path() {
+    let style = TextMeshStyle {
+        synthetic_bold: 0.02,
+        synthetic_italic: 0.2,
+        ..default()
+    };
+    let mut test = TestApp::new();
+    let glyphs = test.spawn_glyphs("l", style.clone());
+    let upright_glyphs = test.spawn_glyphs("l", TextMeshStyle::default());
+    test.update();
+
+    // Per-character glyphs get the same outlines as the merged mesh
+    let glyph = test.children_with::<GlyphMesh>(glyphs)[0];
+    let upright = test.children_with::<GlyphMesh>(upright_glyphs)[0];
+    let (min, max) = extent(&test.positions(glyph));
+    let (upright_min, upright_max) = extent(&test.positions(upright));
+    assert!(max.x - min.x > upright_max.x - upright_min.x + 0.04);
+
+    // Outlined and engraved text is reshaped as well
+    for render_mode in [
+        TextRenderMode::Outline { width: 0.02 },
+        TextRenderMode::Engraved {
+            depth: 0.05,
+            margin: 0.1,
+        },
+    ] {
+        let (min, max) = bounds(&build(
+            "l",
+            TextMeshStyle {
+                render_mode,
+                ..default()
+            },
+        ));
+        let (synthetic_min, synthetic_max) = bounds(&build(
+            "l",
+            TextMeshStyle {
+                render_mode,
+                ..style.clone()
+            },
+        ));
+        assert!(synthetic_max.x - synthetic_min.x > max.x - min.x + 0.04);
+    }
+}
@@ -0,0 +1,101 @@
+//! Tests for tangent generation
+
+use crate::common::{float3_attribute, TestApp};
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn style() -> TextMeshStyle {
+    TextMeshStyle {
+        depth: 0.3,
+        bevel: Some(BevelStyle::default()),
+        generate_tangents: true,
+        ..default()
+    }
+}
+
+/// The tangents of `mesh`, checking there is one per vertex.
+fn tangents(mesh: &Mesh) -> Option<Vec<Vec4>> {
+    let tangents = match mesh.attribute(Mesh::ATTRIBUTE_TANGENT)? {
+        VertexAttributeValues::Float32x4(tangents) => tangents,
+        other => panic!("Tangents should be Float32x4, got {other:?}"),
+    };
+    assert_eq!(tangents.len(), mesh.count_vertices());
+    Some(tangents.iter().map(|&t| Vec4::from_array(t)).collect())
+}
+
+/// Check every tangent is a unit vector on the surface with a valid handedness.
+fn assert_tangent_frames(mesh: &Mesh) {
+    let tangents = tangents(mesh).expect("The mesh should have tangents");
+    let normals = float3_attribute(mesh, Mesh::ATTRIBUTE_NORMAL);
+    for (tangent, normal) in tangents.iter().zip(&normals) {
+        let direction = tangent.truncate();
+        assert!(
+            (direction.length() - 1.0).abs() < 1e-3,
+            "{tangent} is not unit length"
+        );
+        assert!(
+            direction.dot(*normal).abs() < 1e-3,
+            "{tangent} is not perpendicular to {normal}"
+        );
+        assert!(tangent.w == 1.0 || tangent.w == -1.0);
+    }
+}
+
+#[test]
+fn test_tangents_follow_the_caps() {
+    let mut test = TestApp::new();
+    // Without a bevel, only cap vertices face straight forward or back
+    let entity = test.spawn_text(
+        "Normal",
+        TextMeshStyle {
+            bevel: None,
+            ..style()
+        },
+    );
+    test.update();
+
+    let mesh = test.mesh(entity);
+    assert!(mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_some());
+    assert_tangent_frames(mesh);
+
+    // Caps are mapped to the glyph plane, so `u` runs along +X on both of them, and the
+    // back cap's flipped normal flips the bitangent
+    let tangents = tangents(mesh).unwrap();
+    let normals = test.normals(entity);
+    for (tangent, normal) in tangents.iter().zip(&normals) {
+        if normal.z > 0.99 {
+            assert!(tangent.abs_diff_eq(Vec4::new(1.0, 0.0, 0.0, 1.0), 1e-3));
+        } else if normal.z < -0.99 {
+            assert!(tangent.abs_diff_eq(Vec4::new(1.0, 0.0, 0.0, -1.0), 1e-3));
+        }
+    }
+}
+
+#[test]
+fn test_tangents_are_opt_in() {
+    let mut test = TestApp::new();
+    let plain = test.spawn_text("Plain", TextMeshStyle::default());
+    test.update();
+    assert!(tangents(test.mesh(plain)).is_none());
+}
+
+#[test]
+fn test_tangents_survive_edits_and_glyph_mode() {
+    let mut test = TestApp::new();
+    let entity = test.spawn_text("First\nline", style());
+    test.update();
+
+    // Only the second line is rebuilt
+    test.edit_text(entity, |text_mesh| {
+        text_mesh.text = "First\nedited line".to_string()
+    });
+    test.update();
+    assert_tangent_frames(test.mesh(entity));
+
+    let glyphs = test.spawn_glyphs("Ab", style());
+    test.update();
+    for glyph in test.children_with::<GlyphMesh>(glyphs) {
+        assert_tangent_frames(test.mesh(glyph));
+    }
+}
@@ -0,0 +1,136 @@
+//! Tests for rendering text into textures
+
+use crate::common;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{RenderTarget, ScalingMode};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn setup() -> (App, Handle<FontMesh>) {
+    let (mut app, font) = common::app_with_font();
+    app.init_resource::<Assets<Image>>();
+    (app, font)
+}
+
+fn spawn(app: &mut App, font: &Handle<FontMesh>, settings: &TextTextureSettings) -> Handle<Image> {
+    let text_mesh = TextMesh {
+        text: "ONLINE".to_string(),
+        font: font.clone(),
+        ..default()
+    };
+    let world = app.world_mut();
+    let image = world.resource_scope(|world, mut images: Mut<Assets<Image>>| {
+        let mut commands = world.commands();
+        spawn_text_texture(
+            &mut commands,
+            &mut images,
+            text_mesh,
+            Handle::default(),
+            settings,
+        )
+    });
+    world.flush();
+    image
+}
+
+fn camera(app: &mut App) -> (Camera, Projection, Transform, RenderLayers, Entity) {
+    let mut query = app.world_mut().query_filtered::<(
+        &Camera,
+        &Projection,
+        &Transform,
+        &RenderLayers,
+        &ChildOf,
+    ), With<TextTextureCamera>>();
+    let (camera, projection, transform, layers, child_of) = query.single(app.world()).unwrap();
+    (
+        camera.clone(),
+        projection.clone(),
+        *transform,
+        layers.clone(),
+        child_of.parent(),
+    )
+}
+
+#[test]
+fn test_texture_image_has_the_requested_size() {
+    let (mut app, font) = setup();
+    let settings = TextTextureSettings {
+        size: UVec2::new(256, 64),
+        ..default()
+    };
+    let image = spawn(&mut app, &font, &settings);
+
+    let images = app.world().resource::<Assets<Image>>();
+    assert_eq!(images.get(&image).unwrap().size(), UVec2::new(256, 64));
+}
+
+#[test]
+fn test_camera_renders_the_text_layer_into_the_image() {
+    let (mut app, font) = setup();
+    let settings = TextTextureSettings::default();
+    let image = spawn(&mut app, &font, &settings);
+    app.update();
+
+    let (camera, _, _, layers, text) = camera(&mut app);
+    match &camera.target {
+        RenderTarget::Image(target) => assert_eq!(target.handle, image),
+        target => panic!("unexpected render target {target:?}"),
+    }
+    assert_eq!(layers, RenderLayers::layer(settings.render_layer));
+    assert_eq!(
+        app.world().get::<RenderLayers>(text),
+        Some(&RenderLayers::layer(settings.render_layer))
+    );
+    assert_eq!(app.world().get::<TextTexture>(text).unwrap().image, image);
+}
+
+#[test]
+fn test_camera_frames_the_text_bounds() {
+    let (mut app, font) = setup();
+    let settings = TextTextureSettings {
+        padding: 0.25,
+        ..default()
+    };
+    spawn(&mut app, &font, &settings);
+    app.update();
+
+    let (_, projection, transform, _, text) = camera(&mut app);
+    let bounds = app.world().get::<TextMeshBounds>(text).unwrap().clone();
+    let Projection::Orthographic(projection) = projection else {
+        panic!("texture camera should be orthographic");
+    };
+    let ScalingMode::AutoMin {
+        min_width,
+        min_height,
+    } = projection.scaling_mode
+    else {
+        panic!("unexpected scaling mode {:?}", projection.scaling_mode);
+    };
+
+    assert_eq!(min_width, bounds.size().x + 0.5);
+    assert_eq!(min_height, bounds.size().y + 0.5);
+    assert_eq!(transform.translation.truncate(), bounds.center().truncate());
+    assert!(transform.translation.z > bounds.max.z);
+}
+
+#[test]
+fn test_camera_follows_text_changes() {
+    let (mut app, font) = setup();
+    spawn(&mut app, &font, &TextTextureSettings::default());
+    app.update();
+    let (_, before, ..) = camera(&mut app);
+
+    let (.., text) = camera(&mut app);
+    app.world_mut().get_mut::<TextMesh>(text).unwrap().text = "SYSTEM ONLINE".to_string();
+    app.update();
+
+    let (_, after, ..) = camera(&mut app);
+    let width = |projection: &Projection| match projection {
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::AutoMin { min_width, .. },
+            ..
+        }) => *min_width,
+        _ => 0.0,
+    };
+    assert!(width(&after) > width(&before));
+}
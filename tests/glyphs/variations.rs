@@ -0,0 +1,100 @@
+//! Tests for variable font axes in text styles
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn bold() -> TextMeshStyle {
+    TextMeshStyle {
+        variations: vec![(Tag::from_bytes(b"wght"), 700.0)],
+        ..default()
+    }
+}
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, style: TextMeshStyle) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Variable".to_string(),
+                font: font.clone(),
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn mesh_handle(app: &App, entity: Entity) -> Handle<Mesh> {
+    app.world().get::<Mesh3d>(entity).unwrap().0.clone()
+}
+
+fn positions(app: &App, entity: Entity) -> Vec<[f32; 3]> {
+    let handle = mesh_handle(app, entity);
+    let mesh = app.world().resource::<Assets<Mesh>>().get(&handle).unwrap();
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .to_vec()
+}
+
+#[test]
+fn test_static_fonts_ignore_variations() {
+    let (mut app, font) = common::app_with_font();
+    let plain = spawn_text(&mut app, &font, TextMeshStyle::default());
+    let varied = spawn_text(&mut app, &font, bold());
+    app.update();
+
+    assert!(app.world().get::<TextMeshComputed>(varied).is_some());
+    assert_eq!(positions(&app, plain), positions(&app, varied));
+}
+
+#[test]
+fn test_changing_variations_rebuilds_the_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, TextMeshStyle::default());
+    app.update();
+    let before = mesh_handle(&app, entity);
+
+    app.world_mut()
+        .get_mut::<TextMesh>(entity)
+        .unwrap()
+        .style
+        .variations = bold().variations;
+    app.update();
+
+    assert_ne!(before.id(), mesh_handle(&app, entity).id());
+}
+
+#[test]
+fn test_texts_with_different_variations_do_not_share_meshes() {
+    let (mut app, font) = common::app_with_font();
+    let plain = spawn_text(&mut app, &font, TextMeshStyle::default());
+    let varied = spawn_text(&mut app, &font, bold());
+    let also_varied = spawn_text(&mut app, &font, bold());
+    app.update();
+
+    assert_ne!(mesh_handle(&app, plain), mesh_handle(&app, varied));
+    assert_eq!(mesh_handle(&app, varied), mesh_handle(&app, also_varied));
+}
+
+#[test]
+fn test_layout_accepts_variations() {
+    let font = common::load_test_font();
+    let plain = layout_text(&font, "Variable", &TextMeshStyle::default());
+    let varied = layout_text(&font, "Variable", &bold());
+    assert_eq!(plain.glyphs.len(), varied.glyphs.len());
+    assert_eq!(plain.bounds, varied.bounds);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_variations_serialize_as_axis_names() {
+    let style = bold();
+    let json = serde_json::to_string(&style).unwrap();
+    assert!(json.contains("\"wght\""));
+
+    let restored: TextMeshStyle = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.variations, style.variations);
+}
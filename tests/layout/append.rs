@@ -0,0 +1,206 @@
+//! Tests for appending lines to text
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn top_left() -> TextMeshStyle {
+    TextMeshStyle {
+        anchor: TextAnchor::TopLeft,
+        ..default()
+    }
+}
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, text: &str) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font: font.clone(),
+                style: top_left(),
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn spawn_glyphs(app: &mut App, font: &Handle<FontMesh>, text: &str) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: text.to_string(),
+                font: font.clone(),
+                style: top_left(),
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn push_lines(app: &mut App, entity: Entity, lines: &[&str]) {
+    let mut append = app.world_mut().get_mut::<TextMeshAppend>(entity).unwrap();
+    for line in lines {
+        append.push_line(*line);
+    }
+}
+
+fn positions(app: &App, entity: Entity) -> Vec<[f32; 3]> {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .to_vec()
+}
+
+/// Glyph children of `entity` as (entity, char index, line index, character, origin).
+fn glyphs(app: &mut App, entity: Entity) -> Vec<(Entity, usize, usize, char, Vec3)> {
+    let mut glyphs: Vec<_> = app
+        .world_mut()
+        .query::<(Entity, &GlyphMesh, &ChildOf)>()
+        .iter(app.world())
+        .filter(|(_, _, child_of)| child_of.parent() == entity)
+        .map(|(glyph_entity, glyph, _)| {
+            (
+                glyph_entity,
+                glyph.char_index,
+                glyph.line_index,
+                glyph.character,
+                glyph.origin,
+            )
+        })
+        .collect();
+    glyphs.sort_by_key(|glyph| glyph.1);
+    glyphs
+}
+
+fn without_entities(glyphs: &[(Entity, usize, usize, char, Vec3)]) -> Vec<(usize, usize, char)> {
+    glyphs
+        .iter()
+        .map(|&(_, char_index, line_index, character, _)| (char_index, line_index, character))
+        .collect()
+}
+
+fn assert_origins_match(
+    a: &[(Entity, usize, usize, char, Vec3)],
+    b: &[(Entity, usize, usize, char, Vec3)],
+) {
+    assert_eq!(without_entities(a), without_entities(b));
+    for (a, b) in a.iter().zip(b) {
+        assert!(a.4.abs_diff_eq(b.4, 1e-4), "{} != {}", a.4, b.4);
+    }
+}
+
+#[test]
+fn test_pushed_lines_are_appended_to_text() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, "");
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshAppend::default());
+    app.update();
+
+    push_lines(&mut app, entity, &["first", "second\nthird"]);
+    app.update();
+
+    let text_mesh = app.world().get::<TextMesh>(entity).unwrap();
+    assert_eq!(text_mesh.text, "first\nsecond\nthird");
+    assert!(app
+        .world()
+        .get::<TextMeshAppend>(entity)
+        .unwrap()
+        .pending()
+        .is_empty());
+}
+
+#[test]
+fn test_max_lines_drops_oldest_lines() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, "one\ntwo");
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshAppend::with_max_lines(3));
+    app.update();
+
+    push_lines(&mut app, entity, &["three", "four", "five"]);
+    app.update();
+
+    let text_mesh = app.world().get::<TextMesh>(entity).unwrap();
+    assert_eq!(text_mesh.text, "three\nfour\nfive");
+}
+
+#[test]
+fn test_trimmed_mesh_matches_fresh_text() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, "alpha\nbeta\ngamma");
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshAppend::with_max_lines(3));
+    app.update();
+
+    push_lines(&mut app, entity, &["delta"]);
+    app.update();
+
+    let fresh = spawn_text(&mut app, &font, "beta\ngamma\ndelta");
+    app.update();
+
+    let appended = positions(&app, entity);
+    let expected = positions(&app, fresh);
+    assert_eq!(appended.len(), expected.len());
+    for (a, b) in appended.iter().zip(&expected) {
+        assert!(Vec3::from(*a).abs_diff_eq(Vec3::from(*b), 1e-4));
+    }
+}
+
+#[test]
+fn test_glyph_append_keeps_existing_glyph_entities() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_glyphs(&mut app, &font, "ab\ncd");
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshAppend::default());
+    app.update();
+    let before = glyphs(&mut app, entity);
+
+    push_lines(&mut app, entity, &["ef"]);
+    app.update();
+    let after = glyphs(&mut app, entity);
+
+    assert_eq!(after.len(), before.len() + 2);
+    assert_eq!(&after[..before.len()], &before[..]);
+
+    let fresh = spawn_glyphs(&mut app, &font, "ab\ncd\nef");
+    app.update();
+    assert_origins_match(&after, &glyphs(&mut app, fresh));
+}
+
+#[test]
+fn test_glyph_trimming_moves_remaining_glyphs_up() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_glyphs(&mut app, &font, "ab\ncd");
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshAppend::with_max_lines(2));
+    app.update();
+    let before = glyphs(&mut app, entity);
+
+    push_lines(&mut app, entity, &["ef"]);
+    app.update();
+    let after = glyphs(&mut app, entity);
+
+    // "cd" survives as the same entities
+    assert_eq!(after.len(), 4);
+    assert_eq!(after[0].0, before[2].0);
+    assert_eq!(after[1].0, before[3].0);
+    assert_eq!(
+        app.world().get::<TextMeshGlyphs>(entity).unwrap().text,
+        "cd\nef"
+    );
+
+    let fresh = spawn_glyphs(&mut app, &font, "cd\nef");
+    app.update();
+    assert_origins_match(&after, &glyphs(&mut app, fresh));
+}
@@ -0,0 +1,205 @@
+//! Tests for aligning separate texts on a common baseline
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Spawn "H", whose bottom edge sits exactly on the baseline.
+fn spawn_h(
+    app: &mut App,
+    font: &Handle<FontMesh>,
+    anchor: TextAnchor,
+    transform: Transform,
+    group: TextBaselineGroup,
+) -> Entity {
+    app.world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "H".to_string(),
+                    font: font.clone(),
+                    style: TextMeshStyle {
+                        anchor,
+                        ..default()
+                    },
+                    ..default()
+                },
+                transform,
+                ..default()
+            },
+            group,
+        ))
+        .id()
+}
+
+/// Height of the baseline of an "H" in its parent's space.
+fn baseline(app: &App, entity: Entity) -> f32 {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    let bottom = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .iter()
+        .map(|v| v[1])
+        .fold(f32::MAX, f32::min);
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    transform.transform_point(Vec3::Y * bottom).y
+}
+
+#[test]
+fn test_members_follow_the_leader_baseline() {
+    let (mut app, font) = common::app_with_font();
+    let label = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        TextBaselineGroup::new(7),
+    );
+    let value = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::Center,
+        Transform::from_xyz(2.0, 0.0, 0.0).with_scale(Vec3::splat(2.0)),
+        TextBaselineGroup::new(7),
+    );
+    app.update();
+
+    // The leader stays put
+    assert!((baseline(&app, label) - 1.0).abs() < 1e-3);
+    assert!((baseline(&app, value) - 1.0).abs() < 1e-3);
+    assert_eq!(
+        app.world().get::<Transform>(value).unwrap().translation.x,
+        2.0
+    );
+}
+
+#[test]
+fn test_groups_are_independent() {
+    let (mut app, font) = common::app_with_font();
+    let first = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    let second = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, -3.0, 0.0),
+        TextBaselineGroup::new(1),
+    );
+    app.update();
+
+    assert!((baseline(&app, first) - 1.0).abs() < 1e-3);
+    assert!((baseline(&app, second) + 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_line_grid_snaps_the_shared_baseline() {
+    let (mut app, font) = common::app_with_font();
+    let label = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, 1.3, 0.0),
+        TextBaselineGroup::new(0).with_line_grid(0.5),
+    );
+    let value = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::TopLeft,
+        Transform::from_xyz(3.0, 1.3, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    app.update();
+
+    assert!((baseline(&app, label) - 1.5).abs() < 1e-3);
+    assert!((baseline(&app, value) - 1.5).abs() < 1e-3);
+}
+
+#[test]
+fn test_lead_passes_on_when_the_leader_leaves() {
+    let (mut app, font) = common::app_with_font();
+    let label = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    let value = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(2.0, 0.0, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    let unit = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(4.0, 0.0, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    app.update();
+    assert!((baseline(&app, unit) - 1.0).abs() < 1e-3);
+
+    // With the label gone, moving the value moves the rest of the group
+    app.world_mut().despawn(label);
+    app.update();
+    app.world_mut()
+        .get_mut::<Transform>(value)
+        .unwrap()
+        .translation
+        .y = 4.0;
+    app.update();
+    assert!((baseline(&app, unit) - 4.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_2d_texts_are_aligned() {
+    let (mut app, font) = common::app_with_font();
+    let label = spawn_h(
+        &mut app,
+        &font,
+        TextAnchor::BottomLeft,
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        TextBaselineGroup::new(0),
+    );
+    let value = app
+        .world_mut()
+        .spawn((
+            TextMesh2dBundle {
+                text_mesh: TextMesh2d {
+                    text: "H".to_string(),
+                    font: font.clone(),
+                    style: TextMeshStyle {
+                        anchor: TextAnchor::TopRight,
+                        ..default()
+                    },
+                },
+                ..default()
+            },
+            TextBaselineGroup::new(0),
+        ))
+        .id();
+    app.update();
+
+    let handle = &app.world().get::<Mesh2d>(value).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    let bottom = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .iter()
+        .map(|v| v[1])
+        .fold(f32::MAX, f32::min);
+    let y = app.world().get::<Transform>(value).unwrap().translation.y;
+    assert!((y + bottom - baseline(&app, label)).abs() < 1e-3);
+}
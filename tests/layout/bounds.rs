@@ -0,0 +1,141 @@
+//! Tests for the measured bounds of generated text
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, text: &str, style: TextMeshStyle) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font: font.clone(),
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn bounds(app: &App, entity: Entity) -> TextMeshBounds {
+    app.world()
+        .get::<TextMeshBounds>(entity)
+        .expect("Generated text should have bounds")
+        .clone()
+}
+
+#[test]
+fn test_bounds_match_the_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(
+        &mut app,
+        &font,
+        "Hello\nWorld",
+        TextMeshStyle {
+            anchor: TextAnchor::Center,
+            ..default()
+        },
+    );
+    app.update();
+
+    let bounds = bounds(&app, entity);
+    let aabb = app.world().get::<Aabb>(entity).unwrap();
+    assert!((bounds.min - Vec3::from(aabb.min())).length() < 1e-4);
+    assert!((bounds.max - Vec3::from(aabb.max())).length() < 1e-4);
+    assert!(bounds.center().truncate().length() < 1e-4);
+}
+
+#[test]
+fn test_line_rects_have_uniform_height() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(
+        &mut app,
+        &font,
+        "ace\nHg\n",
+        TextMeshStyle {
+            justify: JustifyText::Center,
+            ..default()
+        },
+    );
+    app.update();
+
+    let rects = bounds(&app, entity).line_rects;
+    let metrics = common::load_test_font().font_metrics().unwrap();
+    assert_eq!(rects.len(), 3);
+    for rect in &rects {
+        assert!((rect.height() - (metrics.ascender - metrics.descender)).abs() < 1e-4);
+        // Centered lines share their middle
+        assert!((rect.center().x - rects[0].center().x).abs() < 1e-4);
+    }
+    let line_height = rects[0].min.y - rects[1].min.y;
+    assert!((line_height - metrics.line_height).abs() < 1e-4);
+
+    // The empty last line has no width
+    assert_eq!(rects[2].width(), 0.0);
+    assert!(rects[1].width() < rects[0].width());
+}
+
+#[test]
+fn test_bounds_follow_text_edits() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, "Hi", TextMeshStyle::default());
+    app.update();
+    let before = bounds(&app, entity);
+
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "Hi there".to_string();
+    app.update();
+    let after = bounds(&app, entity);
+    assert!(after.max.x > before.max.x);
+    assert!(after.line_rects[0].width() > before.line_rects[0].width());
+
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "Hi\nthere".to_string();
+    app.update();
+    assert_eq!(bounds(&app, entity).line_rects.len(), 2);
+}
+
+#[test]
+fn test_shared_and_2d_texts_get_bounds() {
+    let (mut app, font) = common::app_with_font();
+    let first = spawn_text(&mut app, &font, "HP", TextMeshStyle::default());
+    let second = spawn_text(&mut app, &font, "HP", TextMeshStyle::default());
+    let flat = app
+        .world_mut()
+        .spawn(TextMesh2dBundle {
+            text_mesh: TextMesh2d {
+                text: "HP".to_string(),
+                font: font.clone(),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let bounds_3d = bounds(&app, first);
+    assert_eq!(bounds(&app, second), bounds_3d);
+
+    let bounds_2d = bounds(&app, flat);
+    assert_eq!(bounds_2d.line_rects, bounds_3d.line_rects);
+    assert_eq!(bounds_2d.size().z, 0.0);
+    assert!(bounds_3d.size().z > 0.0);
+}
+
+#[test]
+fn test_text_on_a_path_has_no_line_rects() {
+    let (mut app, font) = common::app_with_font();
+    let entity = spawn_text(&mut app, &font, "Around", TextMeshStyle::default());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(TextPath::new(vec![
+            Vec3::new(-3.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+        ]));
+    app.update();
+
+    let bounds = bounds(&app, entity);
+    assert!(bounds.line_rects.is_empty());
+    assert!(bounds.size().x > 0.0);
+}
@@ -0,0 +1,89 @@
+//! Tests for carets and selection highlights of in-world text editors
+
+use crate::common::{extent, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_editor(test: &mut TestApp, text: &str, style: TextMeshStyle) -> Entity {
+    let entity = test.spawn_text(text, style);
+    test.world_mut()
+        .entity_mut(entity)
+        .insert((TextCursor3d::default(), TextSelection3d::default()));
+    test.update();
+    entity
+}
+
+fn child<C: Component>(test: &TestApp, entity: Entity) -> Entity {
+    test.children_with::<C>(entity)[0]
+}
+
+#[test]
+fn test_caret_follows_its_index_and_the_anchored_text() {
+    let mut test = TestApp::new();
+    let style = TextMeshStyle {
+        anchor: TextAnchor::Center,
+        ..default()
+    };
+    let entity = spawn_editor(&mut test, "ab\ncd", style);
+    let caret = child::<TextCursor3dQuad>(&test, entity);
+    let bounds = test.bounds(entity);
+    let first_line = bounds.line_rects[0];
+
+    // In front of the text, at the start of the anchored first line
+    let transform = *test.world().get::<Transform>(caret).unwrap();
+    assert!((transform.translation.x - first_line.min.x).abs() < 1e-4);
+    assert!((transform.translation.y - first_line.center().y).abs() < 1e-4);
+    assert!(transform.translation.z > bounds.max.z);
+    assert!((transform.scale.y - first_line.height()).abs() < 1e-4);
+    assert_eq!(transform.scale.x, TextCursor3d::default().width);
+
+    // The end of the text ends the second line
+    test.world_mut()
+        .get_mut::<TextCursor3d>(entity)
+        .unwrap()
+        .byte_index = 5;
+    test.update();
+    let second_line = bounds.line_rects[1];
+    let transform = *test.world().get::<Transform>(caret).unwrap();
+    assert!((transform.translation.x - second_line.max.x).abs() < 1e-3);
+    assert!((transform.translation.y - second_line.center().y).abs() < 1e-4);
+
+    test.world_mut().entity_mut(entity).remove::<TextCursor3d>();
+    test.update();
+    assert!(test.world().get_entity(caret).is_err());
+}
+
+#[test]
+fn test_selection_highlights_the_lines_it_covers() {
+    let mut test = TestApp::new();
+    let entity = spawn_editor(&mut test, "ab\ncd", TextMeshStyle::default());
+    let highlight = child::<TextSelection3dQuads>(&test, entity);
+    assert!(test.positions(highlight).is_empty());
+
+    test.world_mut()
+        .get_mut::<TextSelection3d>(entity)
+        .unwrap()
+        .range = 1..4;
+    test.update();
+    let bounds = test.bounds(entity);
+    let positions = test.positions(highlight);
+    assert_eq!(positions.len(), 8, "a quad on each line");
+
+    // From `b` to the end of the first line, and over `c` on the second
+    let (first_min, first_max) = extent(&positions[..4]);
+    assert!((first_max.x - bounds.line_rects[0].max.x).abs() < 1e-4);
+    assert!(first_min.x > bounds.line_rects[0].min.x);
+    assert!((first_max.y - bounds.line_rects[0].max.y).abs() < 1e-4);
+    let (second_min, second_max) = extent(&positions[4..]);
+    assert!((second_min.x - bounds.line_rects[1].min.x).abs() < 1e-4);
+    assert!(second_max.x < bounds.line_rects[1].max.x);
+
+    // Behind the text
+    let z = test
+        .world()
+        .get::<Transform>(highlight)
+        .unwrap()
+        .translation
+        .z;
+    assert!(z < bounds.min.z);
+}
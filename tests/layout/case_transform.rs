@@ -0,0 +1,58 @@
+//! Tests for drawing text in another letter case
+
+use crate::common::{extent, float3_attribute, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn cased(transform_case: CaseTransform) -> TextMeshStyle {
+    TextMeshStyle {
+        transform_case,
+        anchor: TextAnchor::BaselineLeft,
+        ..default()
+    }
+}
+
+const SMALL_CAPS: CaseTransform = CaseTransform::SmallCaps { scale: 0.7 };
+
+#[test]
+fn test_small_caps_in_every_text_component() {
+    let mut test = TestApp::new();
+    let glyphs = test.spawn_glyphs("Bb", cased(SMALL_CAPS));
+    let font = test.font.clone();
+    let flat = test
+        .world_mut()
+        .spawn(TextMesh2d {
+            text: "b".into(),
+            font: font.clone(),
+            style: cased(SMALL_CAPS),
+        })
+        .id();
+    let full_flat = test
+        .world_mut()
+        .spawn(TextMesh2d {
+            text: "B".into(),
+            font: font.clone(),
+            style: cased(CaseTransform::None),
+        })
+        .id();
+    test.update();
+
+    // The capital and the small capital don't share a mesh
+    let children = test.children_with::<GlyphMesh>(glyphs);
+    assert_eq!(children.len(), 2);
+    assert_ne!(test.mesh_id(children[0]), test.mesh_id(children[1]));
+    let height = |points: Vec<Vec3>| {
+        let (min, max) = extent(&points);
+        max.y - min.y
+    };
+    let (small, full) = (test.positions(children[1]), test.positions(children[0]));
+    assert!((height(small) - height(full) * 0.7).abs() < 1e-4);
+
+    let flat_positions = |entity| {
+        let mesh = test.world().get::<Mesh2d>(entity).unwrap();
+        let meshes = test.world().resource::<Assets<Mesh>>();
+        float3_attribute(meshes.get(mesh).unwrap(), Mesh::ATTRIBUTE_POSITION)
+    };
+    let (small, full) = (flat_positions(flat), flat_positions(full_flat));
+    assert!((height(small) - height(full) * 0.7).abs() < 1e-4);
+}
@@ -0,0 +1,71 @@
+//! Tests for sizing text in mesh units with the style's font size
+
+use crate::common;
+use crate::common::{extent, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-4, "{a} != {b}");
+}
+
+#[test]
+fn test_font_size_scales_the_layout() {
+    let font = common::load_test_font();
+    let small = layout_text(&font, "Hi there\nWorld", &TextMeshStyle::default());
+    let style = TextMeshStyle {
+        font_size: 2.5,
+        ..default()
+    };
+    let large = layout_text(&font, "Hi there\nWorld", &style);
+
+    assert_close(large.line_height, small.line_height * 2.5);
+    assert_close(large.ascender, small.ascender * 2.5);
+    assert_close(large.bounds.width(), small.bounds.width() * 2.5);
+    assert_close(large.bounds.height(), small.bounds.height() * 2.5);
+    for (small, large) in small.glyphs.iter().zip(&large.glyphs) {
+        assert_close(large.position.x, small.position.x * 2.5);
+        assert_close(large.position.y, small.position.y * 2.5);
+        assert_close(large.advance, small.advance * 2.5);
+    }
+
+    // Spacing is given in font units and keeps its size
+    let spaced = |font_size| TextMeshStyle {
+        font_size,
+        letter_spacing: 0.5,
+        ..default()
+    };
+    let width = |style: &TextMeshStyle| layout_text(&font, "ab", style).lines[0].width;
+    assert_close(width(&spaced(2.0)) - 0.5, (width(&spaced(1.0)) - 0.5) * 2.0);
+}
+
+#[test]
+fn test_font_size_scales_the_glyphs_but_not_their_depth() {
+    let mut test = TestApp::new();
+    for bevel in [None, Some(BevelStyle::default())] {
+        let style = |font_size| TextMeshStyle {
+            font_size,
+            depth: 0.2,
+            bevel,
+            ..default()
+        };
+        let small = test.spawn_text("Hello", style(1.0));
+        let large = test.spawn_text("Hello", style(3.0));
+        test.update();
+
+        let (small_bounds, large_bounds) = (test.bounds(small), test.bounds(large));
+        assert_close(large_bounds.size().x, small_bounds.size().x * 3.0);
+        assert_close(large_bounds.size().y, small_bounds.size().y * 3.0);
+        assert_close(large_bounds.size().z, small_bounds.size().z);
+        assert_close(
+            large_bounds.line_rects[0].height(),
+            small_bounds.line_rects[0].height() * 3.0,
+        );
+
+        // The vertices match the bounds, and the front and back caps still face along Z
+        let (min, max) = extent(&test.positions(large));
+        assert_close(max.z - min.z, 0.2);
+        assert_close(max.x - min.x, large_bounds.size().x);
+        assert!(test.normals(large).contains(&Vec3::Z));
+    }
+}
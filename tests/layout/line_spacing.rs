@@ -0,0 +1,64 @@
+//! Tests for line spacing and line height overrides
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Generate two lines of text with the given style and return the height of its bounds.
+fn height(style: TextMeshStyle) -> f32 {
+    let (mut app, font) = common::app_with_font();
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "x\nx".to_string(),
+                font,
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let aabb = app
+        .world()
+        .get::<Aabb>(entity)
+        .expect("Text should have bounds");
+    aabb.half_extents.y * 2.0
+}
+
+#[test]
+fn test_line_spacing_scales_line_height() {
+    let natural = common::load_test_font().font_metrics().unwrap().line_height;
+    let single = height(TextMeshStyle::default());
+    let double = height(TextMeshStyle {
+        line_spacing: 2.0,
+        ..default()
+    });
+
+    assert!(
+        (double - single - natural).abs() < 1e-4,
+        "Doubling the spacing should add one line height, got {single} -> {double}"
+    );
+}
+
+#[test]
+fn test_line_height_override_is_absolute() {
+    let natural = common::load_test_font().font_metrics().unwrap().line_height;
+    let single = height(TextMeshStyle::default());
+    let overridden = height(TextMeshStyle {
+        line_height_override: Some(natural + 0.5),
+        // Ignored while an override is set
+        line_spacing: 3.0,
+        ..default()
+    });
+
+    assert!(
+        (overridden - single - 0.5).abs() < 1e-4,
+        "Expected the override to add 0.5, got {single} -> {overridden}"
+    );
+}
@@ -0,0 +1,24 @@
+//! Integration tests for laying out text: lines, spacing, alignment, and rich text
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod append;
+mod baseline;
+mod bounds;
+mod caret;
+mod case_transform;
+mod font_size;
+mod line_spacing;
+mod missing_glyph;
+mod overflow;
+mod sections;
+mod shaping;
+mod spacing;
+mod spans;
+mod stylistic_sets;
+mod table;
+mod text_layout;
+mod text_path;
+mod vertical;
+mod whitespace;
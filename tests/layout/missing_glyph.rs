@@ -0,0 +1,87 @@
+//! Tests for the missing glyph policy
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+// Not covered by the test font
+const UNMAPPED: char = '\u{4E2D}';
+
+/// Generate `text` with the given policy and return its vertex count and width.
+fn generate(text: &str, missing_glyph: MissingGlyphPolicy) -> (usize, f32) {
+    let (mut app, font) = common::app_with_font();
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                style: TextMeshStyle {
+                    missing_glyph,
+                    ..default()
+                },
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let world = app.world();
+    let handle = &world.get::<Mesh3d>(entity).unwrap().0;
+    let vertices = world
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .expect("Generated mesh should exist")
+        .count_vertices();
+    let aabb = world.get::<Aabb>(entity).expect("Text should have bounds");
+    (vertices, aabb.half_extents.x * 2.0)
+}
+
+#[test]
+fn test_skip_drops_unmapped_characters() {
+    let plain = generate("ab", MissingGlyphPolicy::Skip);
+    let missing = generate(&format!("a{UNMAPPED}b"), MissingGlyphPolicy::Skip);
+
+    assert_eq!(plain.0, missing.0, "Unmapped glyph should add no geometry");
+    assert!(
+        (plain.1 - missing.1).abs() < 1e-4,
+        "Unmapped glyph should take up no space"
+    );
+}
+
+#[test]
+fn test_tofu_draws_notdef_box() {
+    let plain = generate("ab", MissingGlyphPolicy::Tofu);
+    let missing = generate(&format!("a{UNMAPPED}b"), MissingGlyphPolicy::Tofu);
+
+    assert!(missing.0 > plain.0, "Tofu should add geometry");
+    assert!(missing.1 > plain.1, "Tofu should take up space");
+}
+
+#[test]
+fn test_replacement_char_draws_glyph() {
+    let plain = generate("ab", MissingGlyphPolicy::ReplacementChar);
+    let missing = generate(
+        &format!("a{UNMAPPED}b"),
+        MissingGlyphPolicy::ReplacementChar,
+    );
+
+    assert!(missing.0 > plain.0, "Replacement glyph should add geometry");
+    assert!(
+        missing.1 > plain.1,
+        "Replacement glyph should take up space"
+    );
+}
+
+#[test]
+fn test_policy_ignores_mapped_characters() {
+    assert_eq!(
+        generate("Hello", MissingGlyphPolicy::Skip),
+        generate("Hello", MissingGlyphPolicy::Tofu),
+        "Policies should only affect unmapped characters"
+    );
+}
@@ -0,0 +1,20 @@
+//! Tests for cutting off lines that overflow their limits
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_overflowing_text_mesh_stays_within_its_width() {
+    let mut test = TestApp::new();
+    let style = TextMeshStyle {
+        overflow: TextOverflow::Truncate,
+        max_width: Some(2.0),
+        ..default()
+    };
+    let entity = test.spawn_text("A very long name indeed", style);
+    test.update();
+    let bounds = test.bounds(entity);
+    assert!(bounds.size().x <= 2.0 + 1e-4);
+    assert!(bounds.line_rects[0].width() <= 2.0 + 1e-4);
+}
@@ -0,0 +1,182 @@
+//! Tests for rich text sections
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Spawn `sections`, run one update, and return the section children by section index.
+fn generate(
+    app: &mut App,
+    sections: Vec<TextMeshSection>,
+) -> Vec<(TextMeshSectionMesh, Aabb, Handle<StandardMaterial>)> {
+    generate_styled(app, sections, TextMeshStyle::default())
+}
+
+/// Like [`generate`], laying the sections out with `style`.
+fn generate_styled(
+    app: &mut App,
+    sections: Vec<TextMeshSection>,
+    style: TextMeshStyle,
+) -> Vec<(TextMeshSectionMesh, Aabb, Handle<StandardMaterial>)> {
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshSectionsBundle {
+            sections: TextMeshSections { sections, style },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let mut query = app.world_mut().query::<(
+        &TextMeshSectionMesh,
+        &Aabb,
+        &MeshMaterial3d<StandardMaterial>,
+        &ChildOf,
+    )>();
+    let mut children: Vec<_> = query
+        .iter(app.world())
+        .filter(|(.., child_of)| child_of.parent() == entity)
+        .map(|(marker, aabb, material, _)| (*marker, *aabb, material.0.clone()))
+        .collect();
+    children.sort_by_key(|(marker, ..)| marker.section_index);
+    children
+}
+
+#[test]
+fn test_one_child_per_section() {
+    let (mut app, font) = common::app_with_font();
+    let mut materials = Assets::<StandardMaterial>::default();
+    let red = materials.add(Color::srgb(1.0, 0.0, 0.0));
+    let blue = materials.add(Color::srgb(0.0, 0.0, 1.0));
+
+    let children = generate(
+        &mut app,
+        vec![
+            TextMeshSection::new("ab", font.clone(), red.clone()),
+            TextMeshSection::new(" ", font.clone(), red.clone()),
+            TextMeshSection::new("cd", font, blue.clone()),
+        ],
+    );
+
+    // The whitespace-only section has no geometry
+    let indices: Vec<usize> = children
+        .iter()
+        .map(|(marker, ..)| marker.section_index)
+        .collect();
+    assert_eq!(indices, vec![0, 2]);
+    assert_eq!(children[0].2, red);
+    assert_eq!(children[1].2, blue);
+
+    // Sections follow each other on the same line
+    let (_, first, _) = &children[0];
+    let (_, second, _) = &children[1];
+    assert!(second.min().x > first.max().x);
+}
+
+#[test]
+fn test_section_scale_and_depth() {
+    let (mut app, font) = common::app_with_font();
+
+    let children = generate(
+        &mut app,
+        vec![
+            TextMeshSection::new("ab", font.clone(), Handle::default()),
+            TextMeshSection {
+                depth: Some(0.5),
+                scale: 2.0,
+                ..TextMeshSection::new("ab", font, Handle::default())
+            },
+        ],
+    );
+
+    let (_, small, _) = &children[0];
+    let (_, large, _) = &children[1];
+    let ratio = large.half_extents.x / small.half_extents.x;
+    assert!(
+        (ratio - 2.0).abs() < 1e-3,
+        "Expected twice the width, got {ratio}"
+    );
+    assert!(
+        (large.half_extents.z * 2.0 - 0.5).abs() < 1e-4,
+        "Depth should not be scaled"
+    );
+    assert!((small.half_extents.z * 2.0 - TextMeshStyle::default().depth).abs() < 1e-4);
+}
+
+#[test]
+fn test_newlines_across_sections() {
+    let (mut app, font) = common::app_with_font();
+
+    let children = generate(
+        &mut app,
+        vec![
+            TextMeshSection::new(
+                "top
+",
+                font.clone(),
+                Handle::default(),
+            ),
+            TextMeshSection::new("bottom", font, Handle::default()),
+        ],
+    );
+
+    let (_, top, _) = &children[0];
+    let (_, bottom, _) = &children[1];
+    assert!(
+        bottom.max().y < top.min().y + 0.1,
+        "Second section should start a new line"
+    );
+}
+
+#[test]
+fn test_sections_follow_the_line_layout() {
+    let (mut app, font) = common::app_with_font();
+    let sections = |first: &str| {
+        vec![
+            TextMeshSection::new(first, font.clone(), Handle::default()),
+            TextMeshSection {
+                scale: 1.5,
+                ..TextMeshSection::new("cd\nend", font.clone(), Handle::default())
+            },
+        ]
+    };
+    let width = |children: &[(TextMeshSectionMesh, Aabb, Handle<StandardMaterial>)]| {
+        children[1].1.max().x - children[0].1.min().x
+    };
+
+    // Justification stretches the first line across both sections to the full width
+    let natural = width(&generate(&mut app, sections("a b ")));
+    let justified = generate_styled(
+        &mut app,
+        sections("a b "),
+        TextMeshStyle {
+            justify: JustifyText::Justified,
+            max_width: Some(10.0),
+            ..default()
+        },
+    );
+    assert!(
+        width(&justified) > 9.0 && natural < 9.0,
+        "Expected the line to be justified to 10, got {} from {natural}",
+        width(&justified)
+    );
+
+    // An overflowing line is cut off with an ellipsis, even across sections
+    let cut = generate_styled(
+        &mut app,
+        vec![
+            TextMeshSection::new("abcdefgh", font.clone(), Handle::default()),
+            TextMeshSection::new("ijkl", font.clone(), Handle::default()),
+        ],
+        TextMeshStyle {
+            overflow: TextOverflow::Ellipsis,
+            max_width: Some(2.0),
+            ..default()
+        },
+    );
+    assert_eq!(cut.len(), 1, "The second section should be cut off");
+    let extent = cut[0].1.max().x - cut[0].1.min().x;
+    assert!(extent <= 2.0, "Expected at most 2 wide, got {extent}");
+}
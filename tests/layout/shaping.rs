@@ -0,0 +1,27 @@
+//! Tests for OpenType shaping with rustybuzz
+#![cfg(feature = "shaping")]
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_ligatures_spawn_one_glyph_entity() {
+    let (mut app, font) = common::app_with_font();
+    app.world_mut().spawn(TextMeshGlyphsBundle {
+        text_glyphs: TextMeshGlyphs {
+            text: "ffl".to_string(),
+            font,
+            ..default()
+        },
+        ..default()
+    });
+    app.update();
+
+    let mut query = app.world_mut().query::<&GlyphMesh>();
+    let glyphs: Vec<char> = query
+        .iter(app.world())
+        .map(|glyph| glyph.character)
+        .collect();
+    assert_eq!(glyphs, vec!['f']);
+}
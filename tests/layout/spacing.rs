@@ -0,0 +1,150 @@
+//! Tests for letter and word spacing
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Generate `text` with the given spacing and return the width of its bounds.
+fn width(text: &str, letter_spacing: f32, word_spacing: f32) -> f32 {
+    width_with_style(
+        text,
+        TextMeshStyle {
+            letter_spacing,
+            word_spacing,
+            ..default()
+        },
+    )
+}
+
+/// Generate `text` with the given style and return the width of its bounds.
+fn width_with_style(text: &str, style: TextMeshStyle) -> f32 {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let aabb = app
+        .world()
+        .get::<Aabb>(entity)
+        .expect("Text should have bounds");
+    aabb.half_extents.x * 2.0
+}
+
+#[test]
+fn test_letter_spacing_goes_between_characters() {
+    let plain = width("abc", 0.0, 0.0);
+    let tracked = width("abc", 0.25, 0.0);
+
+    // Two gaps between three characters
+    assert!(
+        (tracked - plain - 0.5).abs() < 1e-4,
+        "Expected width to grow by 0.5, got {plain} -> {tracked}"
+    );
+}
+
+#[test]
+fn test_word_spacing_only_affects_whitespace() {
+    assert!(
+        (width("ab", 0.0, 0.5) - width("ab", 0.0, 0.0)).abs() < 1e-4,
+        "Word spacing should not affect text without whitespace"
+    );
+
+    let plain = width("a b", 0.0, 0.0);
+    let spaced = width("a b", 0.0, 0.5);
+    assert!(
+        (spaced - plain - 0.5).abs() < 1e-4,
+        "Expected width to grow by 0.5, got {plain} -> {spaced}"
+    );
+}
+
+#[test]
+fn test_glyph_entities_use_spacing() {
+    let (mut app, font) = common::app_with_font();
+
+    let spawn = |app: &mut App, letter_spacing: f32| {
+        app.world_mut()
+            .spawn(TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "a b".to_string(),
+                    font: font.clone(),
+                    style: TextMeshStyle {
+                        letter_spacing,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            })
+            .id()
+    };
+    let plain = spawn(&mut app, 0.0);
+    let tracked = spawn(&mut app, 0.25);
+
+    app.update();
+
+    let last_glyph_x = |app: &mut App, parent: Entity| {
+        let mut query = app
+            .world_mut()
+            .query::<(&GlyphMesh, &ChildOf, &Transform)>();
+        query
+            .iter(app.world())
+            .filter(|(glyph, child_of, _)| child_of.parent() == parent && glyph.character == 'b')
+            .map(|(_, _, transform)| transform.translation.x)
+            .next()
+            .expect("Glyph 'b' should be spawned")
+    };
+
+    // 'b' comes after two letter gaps
+    let offset = last_glyph_x(&mut app, tracked) - last_glyph_x(&mut app, plain);
+    assert!(
+        (offset - 0.5).abs() < 1e-4,
+        "Expected 'b' to move by 0.5, got {offset}"
+    );
+}
+
+/// Ideographic space, which the test font has no glyph for.
+const MISSING_SPACE: char = '\u{3000}';
+
+#[test]
+fn test_missing_whitespace_uses_font_space_width() {
+    let font = common::load_test_font();
+    assert!(font.glyph_metrics(MISSING_SPACE).is_none());
+
+    let missing = width(&format!("a{MISSING_SPACE}b"), 0.0, 0.0);
+    let space = width("a b", 0.0, 0.0);
+    assert!(
+        (missing - space).abs() < 1e-4,
+        "Expected the width of a space, got {missing} instead of {space}"
+    );
+}
+
+#[test]
+fn test_whitespace_advance_overrides_missing_whitespace() {
+    let space = common::load_test_font().glyph_metrics(' ').unwrap().advance;
+    let style = |whitespace_advance| TextMeshStyle {
+        whitespace_advance,
+        ..default()
+    };
+
+    let missing = width_with_style(&format!("a{MISSING_SPACE}b"), style(Some(1.5)));
+    let default = width_with_style(&format!("a{MISSING_SPACE}b"), style(None));
+    assert!(
+        (missing - default - (1.5 - space)).abs() < 1e-4,
+        "Expected the gap to be 1.5, got {default} -> {missing}"
+    );
+
+    // Whitespace the font has a glyph for keeps its own width
+    assert!((width_with_style("a b", style(Some(1.5))) - width("a b", 0.0, 0.0)).abs() < 1e-4);
+}
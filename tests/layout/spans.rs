@@ -0,0 +1,119 @@
+//! Tests for the `Text3d` and `TextSpan3d` hierarchy
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// Sections generated for `root`, after one update.
+fn sections(test: &mut TestApp, root: Entity) -> Vec<TextMeshSection> {
+    test.update();
+    test.world()
+        .get::<TextMeshSections>(root)
+        .expect("Text3d roots should get sections")
+        .sections
+        .clone()
+}
+
+#[test]
+fn test_spans_become_sections_in_depth_first_order() {
+    let mut test = TestApp::new();
+    let mut materials = Assets::<StandardMaterial>::default();
+    let white = materials.add(Color::WHITE);
+    let red = materials.add(Color::srgb(1.0, 0.0, 0.0));
+    let font = TextFont3d {
+        font: test.font.clone(),
+        depth: Some(0.3),
+        ..default()
+    };
+
+    let root = test
+        .world_mut()
+        .spawn((
+            Text3d::new("Score: "),
+            font.clone(),
+            MeshMaterial3d(white.clone()),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                TextSpan3d::new("90"),
+                TextFont3d {
+                    scale: 1.5,
+                    ..font.clone()
+                },
+                MeshMaterial3d(red.clone()),
+            ))
+            .with_child(TextSpan3d::new("00"));
+            root.spawn(TextSpan3d::new(" pts"));
+        })
+        .id();
+
+    let sections = sections(&mut test, root);
+    let runs: Vec<(&str, f32, Option<f32>, &Handle<StandardMaterial>)> = sections
+        .iter()
+        .map(|s| (s.text.as_str(), s.scale, s.depth, &s.material))
+        .collect();
+    // Nested spans inherit from the span above them, others from the root
+    assert_eq!(
+        runs,
+        [
+            ("Score: ", 1.0, Some(0.3), &white),
+            ("90", 1.5, Some(0.3), &red),
+            ("00", 1.5, Some(0.3), &red),
+            (" pts", 1.0, Some(0.3), &white),
+        ]
+    );
+    assert!(sections.iter().all(|section| section.font == test.font));
+
+    // The sections render like any other
+    test.update();
+    assert_eq!(test.children_with::<TextMeshSectionMesh>(root).len(), 4);
+    assert!(test.world().get::<Transform>(root).is_some());
+}
+
+#[test]
+fn test_span_edits_update_the_text() {
+    let mut test = TestApp::new();
+    let font = TextFont3d {
+        font: test.font.clone(),
+        ..default()
+    };
+    let root = test
+        .world_mut()
+        .spawn((Text3d::new("Hello"), font, Text3dStyle::default()))
+        .id();
+    let span = test
+        .world_mut()
+        .spawn((TextSpan3d::new(" world"), ChildOf(root)))
+        .id();
+    let texts = |sections: Vec<TextMeshSection>| -> Vec<String> {
+        sections.into_iter().map(|section| section.text).collect()
+    };
+    assert_eq!(texts(sections(&mut test, root)), ["Hello", " world"]);
+
+    // Roots without a material use the plugin's default one
+    let material = test.world().get::<TextMeshSections>(root).unwrap().sections[0]
+        .material
+        .clone();
+    assert_eq!(material, FontMeshSettings::DEFAULT_MATERIAL);
+
+    // Unrelated frames leave the generated meshes alone
+    test.update();
+    let meshes = test.children_with::<TextMeshSectionMesh>(root);
+    test.run_frames(3);
+    assert_eq!(test.children_with::<TextMeshSectionMesh>(root), meshes);
+
+    test.world_mut().get_mut::<TextSpan3d>(span).unwrap().0 = " there".to_string();
+    assert_eq!(texts(sections(&mut test, root)), ["Hello", " there"]);
+
+    test.world_mut()
+        .get_mut::<Text3dStyle>(root)
+        .unwrap()
+        .0
+        .depth = 0.5;
+    test.update();
+    let style = &test.world().get::<TextMeshSections>(root).unwrap().style;
+    assert_eq!(style.depth, 0.5);
+
+    test.world_mut().despawn(span);
+    assert_eq!(texts(sections(&mut test, root)), ["Hello"]);
+}
@@ -0,0 +1,164 @@
+//! Tests for stylistic set selection
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn style(stylistic_sets: Vec<StylisticSet>) -> TextMeshStyle {
+    TextMeshStyle {
+        stylistic_sets,
+        ..default()
+    }
+}
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, text: &str, style: TextMeshStyle) -> Entity {
+    app.world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font: font.clone(),
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn positions(app: &App, handle: &Handle<Mesh>) -> Vec<[f32; 3]> {
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .to_vec()
+}
+
+fn text_positions(app: &App, entity: Entity) -> Vec<[f32; 3]> {
+    positions(app, &app.world().get::<Mesh3d>(entity).unwrap().0)
+}
+
+#[test]
+fn test_set_tags() {
+    assert_eq!(
+        StylisticSet::Alternates.tag(),
+        Some(Tag::from_bytes(b"salt"))
+    );
+    assert_eq!(
+        StylisticSet::Numbered(1).tag(),
+        Some(Tag::from_bytes(b"ss01"))
+    );
+    assert_eq!(
+        StylisticSet::Numbered(20).tag(),
+        Some(Tag::from_bytes(b"ss20"))
+    );
+    assert_eq!(StylisticSet::Numbered(0).tag(), None);
+    assert_eq!(StylisticSet::Numbered(21).tag(), None);
+
+    assert_eq!(
+        StylisticSet::from_tag(Tag::from_bytes(b"ss07")),
+        Some(StylisticSet::Numbered(7))
+    );
+    // Tabular figures are a style setting of their own
+    assert_eq!(StylisticSet::from_tag(Tag::from_bytes(b"tnum")), None);
+    assert_eq!(StylisticSet::from_tag(Tag::from_bytes(b"ss21")), None);
+    assert_eq!(StylisticSet::from_tag(Tag::from_bytes(b"liga")), None);
+}
+
+#[test]
+fn test_font_lists_its_stylistic_sets() {
+    let font = common::load_test_font();
+    assert_eq!(
+        font.stylistic_sets(),
+        vec![(StylisticSet::Alternates, None)]
+    );
+}
+
+#[test]
+fn test_alternates_replace_covered_glyphs_only() {
+    let (mut app, font) = common::app_with_font();
+    let plain_a = spawn_text(&mut app, &font, "a", style(vec![]));
+    let alternate_a = spawn_text(&mut app, &font, "a", style(vec![StylisticSet::Alternates]));
+    let plain_b = spawn_text(&mut app, &font, "b", style(vec![]));
+    let alternate_b = spawn_text(&mut app, &font, "b", style(vec![StylisticSet::Alternates]));
+    app.update();
+
+    assert_ne!(
+        text_positions(&app, plain_a),
+        text_positions(&app, alternate_a)
+    );
+    assert_eq!(
+        text_positions(&app, plain_b),
+        text_positions(&app, alternate_b)
+    );
+}
+
+#[test]
+fn test_sets_missing_from_the_font_are_ignored() {
+    let (mut app, font) = common::app_with_font();
+    let plain = spawn_text(&mut app, &font, "al", style(vec![]));
+    let varied = spawn_text(
+        &mut app,
+        &font,
+        "al",
+        style(vec![StylisticSet::Numbered(3), StylisticSet::Numbered(42)]),
+    );
+    app.update();
+
+    assert!(app.world().get::<TextMeshComputed>(varied).is_some());
+    assert_eq!(text_positions(&app, plain), text_positions(&app, varied));
+}
+
+#[test]
+fn test_layout_advances_by_the_alternate_glyphs() {
+    let font = common::load_test_font();
+    let plain = layout_text(&font, "al", &TextMeshStyle::default());
+    let varied = layout_text(&font, "al", &style(vec![StylisticSet::Alternates]));
+
+    // The alternate `a` is wider, and the layout still reports the character
+    assert_eq!(varied.glyphs[0].glyph, Some('a'));
+    assert!(varied.glyphs[0].advance > plain.glyphs[0].advance);
+    assert_eq!(varied.glyphs[1].position.x, varied.glyphs[0].advance);
+}
+
+#[test]
+fn test_sections_override_the_block_sets() {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshSectionsBundle {
+            sections: TextMeshSections {
+                sections: vec![
+                    TextMeshSection::new("a", font.clone(), Handle::default()),
+                    TextMeshSection {
+                        stylistic_sets: Some(Vec::new()),
+                        ..TextMeshSection::new("a", font.clone(), Handle::default())
+                    },
+                ],
+                style: style(vec![StylisticSet::Alternates]),
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query::<(&TextMeshSectionMesh, &Mesh3d, &ChildOf)>();
+    let mut meshes: Vec<_> = query
+        .iter(app.world())
+        .filter(|(.., child_of)| child_of.parent() == entity)
+        .map(|(marker, mesh, _)| (marker.section_index, mesh.0.clone()))
+        .collect();
+    meshes.sort_by_key(|(index, _)| *index);
+    assert_eq!(meshes.len(), 2);
+
+    // The first section keeps the block's alternate `a`, the second opts out of it
+    let plain = spawn_text(&mut app, &font, "a", style(vec![]));
+    let alternate = spawn_text(&mut app, &font, "a", style(vec![StylisticSet::Alternates]));
+    app.update();
+    let (plain, alternate) = (text_positions(&app, plain), text_positions(&app, alternate));
+    assert_ne!(plain.len(), alternate.len());
+    assert_eq!(positions(&app, &meshes[0].1).len(), alternate.len());
+    assert_eq!(positions(&app, &meshes[1].1).len(), plain.len());
+}
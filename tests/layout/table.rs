@@ -0,0 +1,235 @@
+//! Tests for text tables
+
+use crate::common::TestApp;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_table(test: &mut TestApp, table: TextTable) -> Entity {
+    let table = TextTable {
+        font: test.font.clone(),
+        ..table
+    };
+    let entity = test.world_mut().spawn(table).id();
+    test.update();
+    entity
+}
+
+fn leaderboard() -> TextTable {
+    TextTable {
+        columns: vec![
+            TextTableColumn::auto(),
+            TextTableColumn::fixed(6.0),
+            TextTableColumn::auto().with_align(JustifyText::Right),
+        ],
+        ..default()
+    }
+    .with_row(["#", "Player", "Score"])
+    .with_row(["1", "Ada", "12400"])
+    .with_row(["10", "Grace", "9"])
+}
+
+/// The cell at `row` and `column`.
+fn cell(test: &TestApp, table: Entity, row: usize, column: usize) -> Entity {
+    test.children_with::<TextTableCell>(table)
+        .into_iter()
+        .find(|&child| {
+            test.world().get::<TextTableCell>(child) == Some(&TextTableCell { row, column })
+        })
+        .expect("cell should exist")
+}
+
+/// Line box of a cell in the table's space.
+fn cell_box(test: &TestApp, table: Entity, row: usize, column: usize) -> Rect {
+    let cell = cell(test, table, row, column);
+    let offset = test
+        .world()
+        .get::<Transform>(cell)
+        .unwrap()
+        .translation
+        .truncate();
+    let rect = test.bounds(cell).line_rects[0];
+    Rect::from_corners(rect.min + offset, rect.max + offset)
+}
+
+#[test]
+fn test_cells_line_up_in_aligned_columns() {
+    let mut test = TestApp::new();
+    let table = spawn_table(&mut test, leaderboard());
+    assert_eq!(test.children_with::<TextTableCell>(table).len(), 9);
+    let layout = test.world().get::<TextTableLayout>(table).unwrap().clone();
+    assert_eq!(layout.column_widths.len(), 3);
+    assert_eq!(layout.column_widths[1], 6.0);
+
+    let boxes: Vec<Vec<Rect>> = (0..3)
+        .map(|row| {
+            (0..3)
+                .map(|column| cell_box(&test, table, row, column))
+                .collect()
+        })
+        .collect();
+    for row in 0..3 {
+        // Left-aligned columns share their left edge, right-aligned ones their right edge
+        assert!((boxes[row][0].min.x - boxes[0][0].min.x).abs() < 1e-4);
+        assert!((boxes[row][1].min.x - boxes[0][1].min.x).abs() < 1e-4);
+        assert!((boxes[row][2].max.x - boxes[0][2].max.x).abs() < 1e-4);
+
+        // Cells of a row share their line box, and sit inside their cell
+        for column in 0..3 {
+            assert!((boxes[row][column].max.y - boxes[row][0].max.y).abs() < 1e-4);
+            let slot = layout.cell_rect(row, column).unwrap();
+            assert!(slot.contains(boxes[row][column].center()));
+        }
+        if row > 0 {
+            assert!(boxes[row][0].max.y <= boxes[row - 1][0].min.y);
+        }
+    }
+
+    // The fixed column is wider than its text, and the next column starts after it
+    assert!(boxes[0][1].width() < 6.0);
+    assert!(boxes[0][2].min.x >= boxes[0][1].min.x + 6.0);
+    assert!(boxes[2][2].width() < boxes[1][2].width());
+}
+
+#[test]
+fn test_editing_a_table_touches_only_changed_cells() {
+    let mut test = TestApp::new();
+    let table = spawn_table(
+        &mut test,
+        TextTable {
+            rules: Some(TableRules {
+                border: true,
+                ..default()
+            }),
+            ..leaderboard()
+        },
+    );
+
+    // Two lines between columns and rows, and four around the table
+    let rules = test.children_with::<TextTableRules>(table)[0];
+    assert_eq!(test.positions(rules).len(), 8 * 4);
+
+    // Only the edited cell is regenerated
+    let edited = cell(&test, table, 1, 2);
+    test.world_mut()
+        .resource_mut::<Messages<TextMeshReady>>()
+        .clear();
+    test.world_mut().get_mut::<TextTable>(table).unwrap().rows[1][2] = "99999".to_string();
+    test.update();
+    let ready: Vec<TextMeshReady> = test
+        .world_mut()
+        .resource_mut::<Messages<TextMeshReady>>()
+        .drain()
+        .collect();
+    assert_eq!(ready, [TextMeshReady { entity: edited }]);
+
+    // Dropping a row despawns its cells and a row of lines
+    test.world_mut()
+        .get_mut::<TextTable>(table)
+        .unwrap()
+        .rows
+        .pop();
+    test.update();
+    assert_eq!(test.children_with::<TextTableCell>(table).len(), 6);
+    assert_eq!(test.positions(rules).len(), 7 * 4);
+
+    // Removing the table despawns everything it spawned
+    test.world_mut().entity_mut(table).remove::<TextTable>();
+    test.update();
+    assert!(test.children_with::<TextTableCell>(table).is_empty());
+    assert!(test.world().get_entity(rules).is_err());
+    assert!(test.world().get::<TextTableLayout>(table).is_none());
+}
+
+#[test]
+fn test_numeric_columns_line_up_on_the_separator() {
+    let mut test = TestApp::new();
+    let prices = ["12", "3.5", "0.25", "100.125"];
+    let mut table = TextTable {
+        columns: vec![TextTableColumn::auto(), TextTableColumn::numeric('.')],
+        ..default()
+    };
+    for price in prices {
+        table = table.with_row(["Item", price]);
+    }
+    let table = spawn_table(&mut test, table);
+
+    // Where the separator of every price is, or would follow its last digit
+    let font = test
+        .world()
+        .resource::<Assets<FontMesh>>()
+        .get(&test.font)
+        .unwrap();
+    let style = test
+        .world()
+        .get::<TextMesh>(cell(&test, table, 0, 1))
+        .unwrap()
+        .style
+        .clone();
+    assert!(style.tabular_figures);
+    let separators: Vec<f32> = prices
+        .iter()
+        .enumerate()
+        .map(|(row, price)| {
+            let layout = layout_text(font, price, &style);
+            let line = &layout.lines[0];
+            let integer = layout
+                .glyphs
+                .iter()
+                .find(|glyph| glyph.character == '.')
+                .map_or(line.width, |glyph| glyph.position.x - line.start);
+            cell_box(&test, table, row, 1).min.x + integer
+        })
+        .collect();
+    for separator in &separators {
+        assert!((separator - separators[0]).abs() < 1e-4);
+    }
+
+    // The column fits the longest whole and fractional parts, and the widest number
+    // ends at its right edge
+    let layout = test.world().get::<TextTableLayout>(table).unwrap();
+    let right_edge = layout.cell_rect(0, 1).unwrap().max.x - layout.padding.x;
+    assert!((cell_box(&test, table, 3, 1).max.x - right_edge).abs() < 1e-4);
+    assert!(cell_box(&test, table, 0, 1).max.x < right_edge);
+}
+
+#[test]
+fn test_multi_line_numbers_line_up_their_first_line() {
+    let mut test = TestApp::new();
+    // The first line of the second cell is shorter than the line below it
+    let prices = ["12.75", "3.5\n1000.25"];
+    let mut table = TextTable {
+        columns: vec![TextTableColumn::numeric('.')],
+        ..default()
+    };
+    for price in prices {
+        table = table.with_row([price]);
+    }
+    let table = spawn_table(&mut test, table);
+
+    let font = test
+        .world()
+        .resource::<Assets<FontMesh>>()
+        .get(&test.font)
+        .unwrap();
+    let style = test
+        .world()
+        .get::<TextMesh>(cell(&test, table, 0, 0))
+        .unwrap()
+        .style
+        .clone();
+    let separators: Vec<f32> = prices
+        .iter()
+        .enumerate()
+        .map(|(row, price)| {
+            let layout = layout_text(font, price, &style);
+            let line = &layout.lines[0];
+            let separator = layout.glyphs[line.glyphs.clone()]
+                .iter()
+                .find(|glyph| glyph.character == '.')
+                .unwrap();
+            cell_box(&test, table, row, 0).min.x + separator.position.x - line.start
+        })
+        .collect();
+    assert!((separators[0] - separators[1]).abs() < 1e-4);
+}
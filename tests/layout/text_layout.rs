@@ -0,0 +1,45 @@
+//! Tests for the standalone layout API
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_layout_matches_glyph_entities() {
+    let (mut app, font) = common::app_with_font();
+    let style = TextMeshStyle {
+        letter_spacing: 0.1,
+        justify: JustifyText::Right,
+        ..default()
+    };
+    let text = "Score: 42\nBest 99";
+    app.world_mut().spawn(TextMeshGlyphsBundle {
+        text_glyphs: TextMeshGlyphs {
+            text: text.to_string(),
+            font,
+            style: style.clone(),
+            ..default()
+        },
+        ..default()
+    });
+    app.update();
+
+    let layout = layout_text(&common::load_test_font(), text, &style);
+    let mut glyphs: Vec<GlyphMesh> = app
+        .world_mut()
+        .query::<&GlyphMesh>()
+        .iter(app.world())
+        .cloned()
+        .collect();
+    glyphs.sort_by_key(|glyph| glyph.char_index);
+
+    // Glyph entities are moved by the anchor on top of the layout
+    assert_eq!(glyphs.len(), layout.glyphs.len());
+    let anchor = glyphs[0].origin.truncate() - layout.glyphs[0].position;
+    for (entity, placed) in glyphs.iter().zip(&layout.glyphs) {
+        assert_eq!(entity.character, placed.character);
+        assert_eq!(entity.char_index, placed.char_index);
+        assert_eq!(entity.line_index, placed.line_index);
+        assert!((entity.origin.truncate() - anchor - placed.position).length() < 1e-4);
+    }
+}
@@ -0,0 +1,161 @@
+//! Tests for laying text out along a path
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::math::curve::FunctionCurve;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::f32::consts::TAU;
+
+fn setup(text: &str, path: Option<TextPath>) -> (App, Entity) {
+    let (mut app, font) = common::app_with_font();
+
+    let mut entity = app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: text.to_string(),
+            font,
+            style: TextMeshStyle {
+                anchor: TextAnchor::BottomCenter,
+                ..default()
+            },
+            ..default()
+        },
+        ..default()
+    });
+    if let Some(path) = path {
+        entity.insert(path);
+    }
+    let entity = entity.id();
+
+    app.update();
+    (app, entity)
+}
+
+fn starting_at(path: TextPath, start: f32) -> TextPath {
+    TextPath { start, ..path }
+}
+
+fn bounds(app: &App, entity: Entity) -> Aabb {
+    *app.world()
+        .get::<Aabb>(entity)
+        .expect("Text should have bounds")
+}
+
+fn positions(app: &App, entity: Entity) -> Vec<Vec3> {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    let mesh = app.world().resource::<Assets<Mesh>>().get(handle).unwrap();
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|attribute| attribute.as_float3())
+        .expect("Mesh should have positions")
+        .iter()
+        .map(|p| Vec3::from_array(*p))
+        .collect()
+}
+
+#[test]
+fn test_straight_path_matches_plain_layout() {
+    let (plain_app, plain) = setup("Path", None);
+    let (path_app, on_path) = setup(
+        "Path",
+        Some(starting_at(
+            TextPath::new(vec![Vec3::new(-10.0, 0.0, 0.0), Vec3::new(10.0, 0.0, 0.0)]),
+            10.0,
+        )),
+    );
+
+    let plain = bounds(&plain_app, plain);
+    let on_path = bounds(&path_app, on_path);
+    assert!(
+        (Vec3::from(plain.center) - Vec3::from(on_path.center)).length() < 1e-4
+            && (Vec3::from(plain.half_extents) - Vec3::from(on_path.half_extents)).length() < 1e-4,
+        "A straight path through the origin should not move the text: {plain:?} vs {on_path:?}"
+    );
+}
+
+#[test]
+fn test_vertical_path_rotates_text() {
+    let (plain_app, plain) = setup("Path", None);
+    let (path_app, on_path) = setup(
+        "Path",
+        Some(starting_at(
+            TextPath::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)]),
+            5.0,
+        )),
+    );
+
+    let plain = bounds(&plain_app, plain);
+    let on_path = bounds(&path_app, on_path);
+    assert!(
+        on_path.half_extents.y > on_path.half_extents.x,
+        "Text running up the Y axis should be taller than wide: {on_path:?}"
+    );
+    assert!(
+        (on_path.half_extents.y - plain.half_extents.x).abs() < 1e-3,
+        "Rotating by a quarter turn should keep the text length: {plain:?} vs {on_path:?}"
+    );
+}
+
+#[test]
+fn test_text_follows_circle() {
+    let radius = 4.0;
+    let circle = FunctionCurve::new(Interval::UNIT, move |t: f32| {
+        // Clockwise, so the glyphs stand on the outside of the circle
+        let angle = -TAU * t;
+        Vec3::new(angle.cos(), angle.sin(), 0.0) * radius
+    });
+    let path = TextPath::from_curve(&circle, 256);
+    let length = path.length();
+    assert!((length - TAU * radius).abs() < 0.01);
+
+    let (app, entity) = setup("ROUND AND ROUND", Some(starting_at(path, length * 0.5)));
+
+    let line_height = common::load_test_font().font_metrics().unwrap().line_height;
+    for position in positions(&app, entity) {
+        let distance = position.truncate().length();
+        assert!(
+            distance > radius - 0.1 && distance < radius + line_height,
+            "Glyphs should sit on the outside of the circle, got a vertex at {distance}"
+        );
+    }
+}
+
+#[test]
+fn test_transform_at_extends_ends() {
+    let path = TextPath::new(vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0)]);
+    assert!((path.length() - 2.0).abs() < 1e-6);
+
+    let before = path.transform_at(-1.0);
+    assert!(before.translation.abs_diff_eq(Vec3::NEG_X, 1e-6));
+
+    let corner = path.transform_at(1.5);
+    assert!(corner
+        .translation
+        .abs_diff_eq(Vec3::new(1.0, 0.5, 0.0), 1e-6));
+    assert!((corner.rotation * Vec3::X).abs_diff_eq(Vec3::Y, 1e-6));
+    assert!((corner.rotation * Vec3::Z).abs_diff_eq(Vec3::Z, 1e-6));
+
+    let after = path.transform_at(3.0);
+    assert!(after
+        .translation
+        .abs_diff_eq(Vec3::new(1.0, 2.0, 0.0), 1e-6));
+}
+
+#[test]
+fn test_removing_path_restores_straight_text() {
+    let (plain_app, plain) = setup("Path", None);
+    let (mut app, entity) = setup(
+        "Path",
+        Some(TextPath::new(vec![Vec3::ZERO, Vec3::new(0.0, 10.0, 0.0)])),
+    );
+
+    app.world_mut().entity_mut(entity).remove::<TextPath>();
+    app.update();
+    app.update();
+
+    let plain = bounds(&plain_app, plain);
+    let restored = bounds(&app, entity);
+    assert!(
+        (Vec3::from(plain.half_extents) - Vec3::from(restored.half_extents)).length() < 1e-4,
+        "Removing the path should lay the text out straight again: {plain:?} vs {restored:?}"
+    );
+}
@@ -0,0 +1,109 @@
+//! Tests for vertical text layout
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn vertical(orientation: TextOrientation) -> TextMeshStyle {
+    TextMeshStyle {
+        orientation,
+        ..default()
+    }
+}
+
+#[test]
+fn test_vertical_text_mesh_is_tall() {
+    let (mut app, font) = common::app_with_font();
+    let spawn = |app: &mut App, orientation| {
+        app.world_mut()
+            .spawn(TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "EXIT".to_string(),
+                    font: font.clone(),
+                    style: vertical(orientation),
+                    ..default()
+                },
+                ..default()
+            })
+            .id()
+    };
+    let horizontal = spawn(&mut app, TextOrientation::Horizontal);
+    let vertical = spawn(&mut app, TextOrientation::VerticalRightToLeft);
+    app.update();
+
+    let bounds = |entity| app.world().get::<TextMeshBounds>(entity).unwrap().clone();
+    let (horizontal, vertical) = (bounds(horizontal), bounds(vertical));
+    assert!(horizontal.size().x > horizontal.size().y);
+    assert!(vertical.size().y > vertical.size().x);
+
+    // The column box covers the glyphs
+    let rect = vertical.line_rects[0];
+    assert!(rect.height() > rect.width());
+    assert!(rect.min.x <= vertical.min.x && rect.max.x >= vertical.max.x);
+    assert!(rect.min.y <= vertical.min.y && rect.max.y >= vertical.max.y);
+}
+
+#[test]
+fn test_editing_vertical_text_matches_a_fresh_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let spawn = |app: &mut App, text: &str| {
+        app.world_mut()
+            .spawn((
+                TextMeshBundle {
+                    text_mesh: TextMesh {
+                        text: text.to_string(),
+                        font: font.clone(),
+                        style: vertical(TextOrientation::VerticalRightToLeft),
+                        ..default()
+                    },
+                    ..default()
+                },
+                TextMeshCachePolicy::Unique,
+            ))
+            .id()
+    };
+    let edited = spawn(&mut app, "AB\nCD");
+    app.update();
+    app.world_mut().get_mut::<TextMesh>(edited).unwrap().text = "AB\nXYZ".to_string();
+    let fresh = spawn(&mut app, "AB\nXYZ");
+    app.update();
+
+    let positions = |entity| {
+        let mesh = app.world().get::<Mesh3d>(entity).unwrap();
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        meshes
+            .get(mesh)
+            .unwrap()
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .unwrap()
+            .as_float3()
+            .unwrap()
+            .to_vec()
+    };
+    assert_eq!(positions(edited), positions(fresh));
+}
+
+#[test]
+fn test_glyph_entities_follow_the_columns() {
+    let (mut app, font) = common::app_with_font();
+    app.world_mut().spawn(TextMeshGlyphsBundle {
+        text_glyphs: TextMeshGlyphs {
+            text: "AB\nC".to_string(),
+            font,
+            style: vertical(TextOrientation::VerticalRightToLeft),
+            ..default()
+        },
+        ..default()
+    });
+    app.update();
+
+    let mut query = app.world_mut().query::<&GlyphMesh>();
+    let mut glyphs: Vec<GlyphMesh> = query.iter(app.world()).cloned().collect();
+    glyphs.sort_by_key(|glyph| glyph.char_index);
+    let [a, b, c] = &glyphs[..] else {
+        panic!("expected three glyphs, got {}", glyphs.len());
+    };
+    assert!(b.origin.y < a.origin.y);
+    assert!(c.origin.x < a.origin.x);
+    assert_eq!(c.origin.y, a.origin.y);
+}
@@ -0,0 +1,96 @@
+//! Tests for visible whitespace markers
+
+use crate::common::{extent, TestApp};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_with_markers(test: &mut TestApp, text: &str) -> Entity {
+    let entity = test.spawn_text(text, TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshWhitespace::default());
+    test.update();
+    entity
+}
+
+fn markers(test: &TestApp, entity: Entity) -> Option<Entity> {
+    test.children_with::<TextMeshWhitespaceMarkers>(entity)
+        .first()
+        .copied()
+}
+
+#[test]
+fn test_markers_sit_in_the_whitespace_of_their_lines() {
+    let mut test = TestApp::new();
+    let entity = spawn_with_markers(&mut test, "a b\nc");
+    let markers = markers(&test, entity).unwrap();
+    let bounds = test.bounds(entity);
+    let first_line = bounds.line_rects[0];
+
+    // A dot for the space and a pilcrow after the first line, which has 4 + 21 corners
+    let positions = test.positions(markers);
+    assert_eq!(positions.len(), 25);
+    let (dot_min, dot_max) = extent(&positions[..4]);
+    let (pilcrow_min, pilcrow_max) = extent(&positions[4..]);
+    assert!(first_line.contains(dot_min.truncate()) && first_line.contains(dot_max.truncate()));
+    assert!(pilcrow_min.x >= first_line.max.x - 1e-4);
+    assert!(pilcrow_min.y >= first_line.min.y && pilcrow_max.y <= first_line.max.y);
+
+    // The dot is in the gap between "a" and "b"
+    let text = test.positions(entity);
+    let left = text
+        .iter()
+        .filter(|p| p.y > first_line.min.y && p.x < dot_min.x);
+    let right = text
+        .iter()
+        .filter(|p| p.y > first_line.min.y && p.x > dot_max.x);
+    assert!(left.count() > 0 && right.count() > 0);
+    assert!(!text
+        .iter()
+        .any(|p| p.x > dot_min.x && p.x < dot_max.x && p.y > first_line.min.y));
+
+    // Tabs get an arrow, and the last line no pilcrow
+    test.edit_text(entity, |text_mesh| text_mesh.text = "a\tb".to_string());
+    test.update();
+    assert_eq!(test.positions(markers).len(), 7);
+}
+
+#[test]
+fn test_markers_toggle_and_go_with_the_component() {
+    let mut test = TestApp::new();
+    let entity = spawn_with_markers(&mut test, "a b");
+    let markers = markers(&test, entity).unwrap();
+    let visibility = |test: &TestApp| *test.world().get::<Visibility>(markers).unwrap();
+    assert_eq!(visibility(&test), Visibility::Inherited);
+
+    let toggle = |test: &mut TestApp, visible| {
+        test.world_mut()
+            .get_mut::<TextMeshWhitespace>(entity)
+            .unwrap()
+            .visible = visible;
+        test.update();
+    };
+    toggle(&mut test, false);
+    assert_eq!(visibility(&test), Visibility::Hidden);
+    toggle(&mut test, true);
+    assert_eq!(visibility(&test), Visibility::Inherited);
+
+    // The markers use the text's material unless given one
+    let text_material = test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .id();
+    let marker_material = test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(markers)
+        .unwrap()
+        .id();
+    assert_eq!(marker_material, text_material);
+
+    test.world_mut()
+        .entity_mut(entity)
+        .remove::<TextMeshWhitespace>();
+    test.update();
+    assert!(test.world().get_entity(markers).is_err());
+}
@@ -0,0 +1,50 @@
+//! Tests for the builder-style text spawning API
+
+use crate::common;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_builder_fills_in_the_text_and_style() {
+    let text_mesh = TextMesh::new("Hello")
+        .depth(0.2)
+        .subdivision(4)
+        .anchor(TextAnchor::Center)
+        .justify(JustifyText::Center)
+        .char_colors([Color::WHITE]);
+
+    assert_eq!(text_mesh.text, "Hello");
+    assert_eq!(
+        text_mesh.style,
+        TextMeshStyle {
+            depth: 0.2,
+            subdivision: 4,
+            anchor: TextAnchor::Center,
+            justify: JustifyText::Center,
+            ..default()
+        }
+    );
+    assert_eq!(text_mesh.char_colors, Some(vec![Color::WHITE]));
+}
+
+#[test]
+fn test_spawned_text_is_generated() {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            commands
+                .spawn_text_mesh(TextMesh::new("Hi").font(font.clone()))
+                .insert(Transform::from_xyz(1.0, 0.0, 0.0))
+                .id()
+        })
+        .unwrap();
+    app.update();
+
+    assert!(app.world().get::<TextMeshComputed>(entity).is_some());
+    assert_eq!(
+        app.world().get::<Transform>(entity).unwrap().translation.x,
+        1.0
+    );
+}
@@ -0,0 +1,106 @@
+//! Tests for opting texts out of the shared mesh cache
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_text(app: &mut App, font: &Handle<FontMesh>, policy: TextMeshCachePolicy) -> Entity {
+    app.world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "HP".to_string(),
+                    font: font.clone(),
+                    ..default()
+                },
+                ..default()
+            },
+            policy,
+        ))
+        .id()
+}
+
+fn mesh_id(app: &App, entity: Entity) -> AssetId<Mesh> {
+    app.world().get::<Mesh3d>(entity).unwrap().id()
+}
+
+#[test]
+fn test_shared_policy_shares_meshes() {
+    let (mut app, font) = common::app_with_font();
+    let first = spawn_text(&mut app, &font, TextMeshCachePolicy::Shared);
+    let second = spawn_text(&mut app, &font, TextMeshCachePolicy::default());
+    app.update();
+
+    assert_eq!(mesh_id(&app, first), mesh_id(&app, second));
+}
+
+#[test]
+fn test_unique_and_uncached_texts_get_their_own_meshes() {
+    let (mut app, font) = common::app_with_font();
+    let shared = spawn_text(&mut app, &font, TextMeshCachePolicy::Shared);
+    let unique = spawn_text(&mut app, &font, TextMeshCachePolicy::Unique);
+    let uncached = spawn_text(&mut app, &font, TextMeshCachePolicy::NoCache);
+    // Texts spawned after the opted-out ones don't pick up their meshes either
+    app.update();
+    let late = spawn_text(&mut app, &font, TextMeshCachePolicy::Shared);
+    app.update();
+
+    let ids = [shared, unique, uncached].map(|entity| mesh_id(&app, entity));
+    assert_ne!(ids[0], ids[1]);
+    assert_ne!(ids[0], ids[2]);
+    assert_ne!(ids[1], ids[2]);
+    assert_eq!(mesh_id(&app, late), ids[0]);
+
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    let vertices = ids.map(|id| meshes.get(id).unwrap().count_vertices());
+    assert!(vertices.iter().all(|&count| count == vertices[0]));
+}
+
+#[test]
+fn test_unique_texts_are_edited_in_place() {
+    let (mut app, font) = common::app_with_font();
+    let unique = spawn_text(&mut app, &font, TextMeshCachePolicy::Unique);
+    let uncached = spawn_text(&mut app, &font, TextMeshCachePolicy::NoCache);
+    app.update();
+    let before = [unique, uncached].map(|entity| mesh_id(&app, entity));
+
+    for entity in [unique, uncached] {
+        app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "HP\n100".to_string();
+    }
+    app.update();
+
+    // Only the line cache keeps the mesh asset, an uncached text builds a new one
+    assert_eq!(mesh_id(&app, unique), before[0]);
+    assert_ne!(mesh_id(&app, uncached), before[1]);
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    assert_eq!(
+        meshes.get(mesh_id(&app, unique)).unwrap().count_vertices(),
+        meshes
+            .get(mesh_id(&app, uncached))
+            .unwrap()
+            .count_vertices()
+    );
+}
+
+#[test]
+fn test_changing_the_policy_regenerates_the_mesh() {
+    let (mut app, font) = common::app_with_font();
+    let first = spawn_text(&mut app, &font, TextMeshCachePolicy::Shared);
+    let second = spawn_text(&mut app, &font, TextMeshCachePolicy::Shared);
+    app.update();
+    let shared = mesh_id(&app, first);
+
+    app.world_mut()
+        .entity_mut(second)
+        .insert(TextMeshCachePolicy::Unique);
+    app.update();
+    assert_ne!(mesh_id(&app, second), shared);
+    assert_eq!(mesh_id(&app, first), shared);
+
+    // Opting back in shares the mesh again
+    app.world_mut()
+        .entity_mut(second)
+        .insert(TextMeshCachePolicy::Shared);
+    app.update();
+    assert_eq!(mesh_id(&app, second), shared);
+}
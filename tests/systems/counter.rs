@@ -0,0 +1,78 @@
+//! Tests for number counters that swap shared digit meshes
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn set_value(test: &mut TestApp, entity: Entity, value: i64) {
+    test.world_mut()
+        .get_mut::<TextMeshCounter>(entity)
+        .unwrap()
+        .value = value;
+    test.update();
+}
+
+fn characters(test: &TestApp, entity: Entity) -> String {
+    test.children_with::<GlyphMesh>(entity)
+        .iter()
+        .map(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().character)
+        .collect()
+}
+
+#[test]
+fn test_counter_swaps_digit_meshes_without_tessellating() {
+    let mut test = TestApp::new();
+    let entity = test.spawn_glyphs("", TextMeshStyle::default());
+    test.world_mut().entity_mut(entity).insert(TextMeshCounter {
+        value: 1234,
+        format: CounterFormat {
+            separator: Some(','),
+            ..default()
+        },
+    });
+    test.update();
+    assert_eq!(
+        test.world().get::<TextMeshGlyphs>(entity).unwrap().text,
+        "1,234"
+    );
+    assert_eq!(characters(&test, entity), "1,234");
+    let before = test.children_with::<GlyphMesh>(entity);
+    let four = test.mesh_id(before[4]);
+    let meshes = test.mesh_count();
+
+    // The same entities swap to the shared meshes of their new digits
+    set_value(&mut test, entity, 4321);
+    assert_eq!(characters(&test, entity), "4,321");
+    assert_eq!(test.children_with::<GlyphMesh>(entity), before);
+    assert_eq!(test.mesh_id(before[0]), four);
+    assert_eq!(test.mesh_count(), meshes);
+
+    // Shorter values despawn the glyphs they don't need
+    set_value(&mut test, entity, -7);
+    assert_eq!(characters(&test, entity), "-7");
+    assert_eq!(test.children_with::<GlyphMesh>(entity).len(), 2);
+}
+
+#[test]
+fn test_removed_counter_hands_text_back_to_glyph_generation() {
+    let mut test = TestApp::new();
+    let entity = test.spawn_glyphs("", TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(entity)
+        .insert(TextMeshCounter::new(42));
+    test.update();
+    assert_eq!(characters(&test, entity), "42");
+
+    test.world_mut()
+        .entity_mut(entity)
+        .remove::<TextMeshCounter>();
+    test.update();
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(entity)
+        .unwrap()
+        .text = "4x2".to_string();
+    test.update();
+    assert_eq!(characters(&test, entity), "42x");
+    let glyphs = test.children_with::<GlyphMesh>(entity);
+    assert_ne!(test.mesh_id(glyphs[0]), test.mesh_id(glyphs[1]));
+}
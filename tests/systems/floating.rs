@@ -0,0 +1,162 @@
+//! Tests for text that floats away and despawns itself
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn floating_app() -> TestApp {
+    let mut test = TestApp::new();
+    test.world_mut().init_resource::<Time>();
+    test.world_mut().init_resource::<Assets<StandardMaterial>>();
+    test.app.finish();
+    test
+}
+
+fn advance(test: &mut TestApp, seconds: f32) {
+    test.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(seconds));
+    test.update();
+}
+
+fn spawn_floating(test: &mut TestApp, floating: FloatingText3d) -> Entity {
+    let font = test.font.clone();
+    test.world_mut()
+        .spawn(FloatingText3d { font, ..floating })
+        .id()
+}
+
+#[test]
+fn test_floating_text_rises_grows_and_despawns() {
+    let mut test = floating_app();
+    let entity = spawn_floating(
+        &mut test,
+        FloatingText3d {
+            text: "-42".to_string(),
+            velocity: Vec3::new(0.0, 2.0, 0.0),
+            scale: 2.0,
+            ..default()
+        },
+    );
+    test.update();
+
+    // It shows its text with the default material
+    let text_mesh = test.world().get::<TextMesh>(entity).unwrap();
+    assert_eq!(text_mesh.text, "-42");
+    assert!(test.world().get::<Mesh3d>(entity).is_some());
+    let material = test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap();
+    assert_eq!(material.id(), FontMeshSettings::DEFAULT_MATERIAL.id());
+
+    // Halfway through, it has risen and grown halfway
+    advance(&mut test, 0.5);
+    let transform = *test.world().get::<Transform>(entity).unwrap();
+    assert!((transform.translation.y - 1.0).abs() < 1e-3);
+    assert!((transform.scale.x - 1.5).abs() < 1e-3);
+
+    // After its lifetime, it's gone
+    advance(&mut test, 0.6);
+    assert!(test.world().get_entity(entity).is_err());
+}
+
+#[test]
+fn test_editing_floating_text_keeps_its_countdown() {
+    let mut test = floating_app();
+    let entity = spawn_floating(
+        &mut test,
+        FloatingText3d {
+            text: "10".to_string(),
+            lifetime: 2.0,
+            ..default()
+        },
+    );
+    test.update();
+    advance(&mut test, 1.0);
+
+    test.world_mut()
+        .get_mut::<FloatingText3d>(entity)
+        .unwrap()
+        .text = "20".to_string();
+    advance(&mut test, 0.0);
+
+    assert_eq!(test.world().get::<TextMesh>(entity).unwrap().text, "20");
+    let lifetime = test.world().get::<TextMeshLifetime>(entity).unwrap();
+    assert!((lifetime.elapsed - 1.0).abs() < 1e-3);
+    let material = test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap();
+    assert_eq!(material.id(), FontMeshSettings::DEFAULT_MATERIAL.id());
+}
+
+#[test]
+fn test_floating_text_faces_the_camera() {
+    let mut test = floating_app();
+    let camera = Transform::from_xyz(5.0, 2.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+    test.world_mut()
+        .spawn((Camera::default(), GlobalTransform::from(camera)));
+    let entity = spawn_floating(
+        &mut test,
+        FloatingText3d {
+            text: "+5".to_string(),
+            ..default()
+        },
+    );
+    assert!(test.world().get::<TextBillboard>(entity).is_some());
+    test.update();
+
+    // The front of the text points back at the camera, upright on screen
+    let rotation = test.world().get::<Transform>(entity).unwrap().rotation;
+    assert!(rotation.abs_diff_eq(camera.rotation, 1e-4));
+    let front = rotation * Vec3::Z;
+    assert!(front.dot(camera.translation.normalize()) > 0.999);
+
+    // A turned parent is undone
+    let turn = Quat::from_rotation_y(1.0);
+    test.world_mut()
+        .entity_mut(entity)
+        .insert(GlobalTransform::from_rotation(turn * rotation));
+    test.update();
+    let rotation = test.world().get::<Transform>(entity).unwrap().rotation;
+    assert!((turn * rotation).abs_diff_eq(camera.rotation, 1e-4));
+}
+
+#[test]
+fn test_only_shown_edits_rebuild_floating_text() {
+    let mut test = floating_app();
+    let entity = spawn_floating(
+        &mut test,
+        FloatingText3d {
+            text: "10".to_string(),
+            lifetime: 2.0,
+            ..default()
+        },
+    );
+    test.update();
+    let changed = |test: &TestApp| {
+        test.world()
+            .entity(entity)
+            .get_ref::<TextMesh>()
+            .unwrap()
+            .last_changed()
+    };
+    let built = changed(&test);
+
+    // Edits of the motion don't touch the text
+    test.world_mut()
+        .get_mut::<FloatingText3d>(entity)
+        .unwrap()
+        .velocity = Vec3::X;
+    advance(&mut test, 0.1);
+    assert_eq!(changed(&test), built);
+
+    test.world_mut()
+        .get_mut::<FloatingText3d>(entity)
+        .unwrap()
+        .text = "20".to_string();
+    advance(&mut test, 0.1);
+    assert_ne!(changed(&test), built);
+}
@@ -0,0 +1,519 @@
+//! Tests for the generation systems, driven frame by frame in a headless app
+
+use crate::common::{extent, TestApp};
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[test]
+fn test_text_mesh_has_valid_attributes() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("Mesh", TextMeshStyle::default());
+    test.update();
+
+    assert!(test.world().get::<TextMeshComputed>(text).is_some());
+    let positions = test.positions(text);
+    let normals = test.normals(text);
+    let indices = test.indices(text);
+    assert!(!positions.is_empty());
+    assert_eq!(positions.len(), normals.len());
+    assert!(!indices.is_empty());
+    assert_eq!(indices.len() % 3, 0);
+    assert!(indices.iter().all(|&i| (i as usize) < positions.len()));
+    assert!(normals
+        .iter()
+        .all(|normal| (normal.length() - 1.0).abs() < 1e-3));
+    assert!(positions.iter().all(|position| position.is_finite()));
+
+    // No texture coordinates unless the style asks for them
+    assert!(test.mesh(text).attribute(Mesh::ATTRIBUTE_UV_0).is_none());
+}
+
+#[test]
+fn test_mesh_spans_the_extrusion_depth() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text(
+        "Deep",
+        TextMeshStyle {
+            depth: 0.4,
+            ..default()
+        },
+    );
+    test.update();
+
+    let (min, max) = extent(&test.positions(text));
+    assert!((max.z - min.z - 0.4).abs() < 1e-4);
+}
+
+#[test]
+fn test_aabb_matches_the_generated_mesh() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("Bounds", TextMeshStyle::default());
+    test.update();
+
+    let aabb = test.world().get::<Aabb>(text).unwrap();
+    let (min, max) = extent(&test.positions(text));
+    assert!((Vec3::from(aabb.min()) - min).length() < 1e-4);
+    assert!((Vec3::from(aabb.max()) - max).length() < 1e-4);
+
+    // Longer text grows the bounds on the next frame
+    test.edit_text(text, |text_mesh| {
+        text_mesh.text = "Bounds and more".to_string()
+    });
+    test.update();
+    let aabb = test.world().get::<Aabb>(text).unwrap();
+    assert!(Vec3::from(aabb.max()).x > max.x);
+}
+
+#[test]
+fn test_layout_bounds_follow_lines_and_anchor() {
+    let mut test = TestApp::new();
+    let single = test.spawn_text("Line", TextMeshStyle::default());
+    let double = test.spawn_text("Line\nLine", TextMeshStyle::default());
+    let centered = test.spawn_text(
+        "Line\nLine",
+        TextMeshStyle {
+            anchor: TextAnchor::Center,
+            ..default()
+        },
+    );
+    test.update();
+
+    let single = test.bounds(single);
+    let double = test.bounds(double);
+    assert_eq!(single.line_rects.len(), 1);
+    assert_eq!(double.line_rects.len(), 2);
+    assert!(double.size().y > single.size().y * 1.5);
+    assert!((double.size().x - single.size().x).abs() < 1e-4);
+
+    // The default top-left anchor puts the text below and right of the origin
+    assert!(double.min.x.abs() < 1e-4 && double.max.y.abs() < 1e-4);
+    let center = test.bounds(centered).center();
+    assert!(center.truncate().length() < 1e-4);
+}
+
+#[test]
+fn test_baseline_anchors_sit_on_the_first_baseline() {
+    let mut test = TestApp::new();
+    let anchored = |anchor, font_size| TextMeshStyle {
+        anchor,
+        font_size,
+        ..default()
+    };
+    let small = test.spawn_text("HI", anchored(TextAnchor::BaselineLeft, 1.0));
+    let large = test.spawn_text("HI", anchored(TextAnchor::BaselineLeft, 2.0));
+    let lines = test.spawn_text("HI\nHI", anchored(TextAnchor::BaselineLeft, 1.0));
+    let center = test.spawn_text("HI", anchored(TextAnchor::BaselineCenter, 1.0));
+    let right = test.spawn_text("HI", anchored(TextAnchor::BaselineRight, 1.0));
+    test.update();
+
+    // Letters without descenders rest on the origin, whatever their size
+    for text in [small, large] {
+        let bounds = test.bounds(text);
+        assert!(bounds.min.x.abs() < 1e-4 && bounds.min.y.abs() < 1e-3);
+    }
+    assert!(test.bounds(large).max.y > test.bounds(small).max.y * 1.5);
+
+    // More lines grow downwards from the first baseline
+    let lines = test.bounds(lines);
+    assert!((lines.max.y - test.bounds(small).max.y).abs() < 1e-4);
+    assert!(lines.min.y < -test.bounds(small).max.y);
+
+    assert!(test.bounds(center).center().x.abs() < 1e-4);
+    assert!(test.bounds(right).max.x.abs() < 1e-4);
+    assert!(test.bounds(right).min.y.abs() < 1e-3);
+}
+
+#[test]
+fn test_glyph_children_per_visible_character() {
+    let mut test = TestApp::new();
+    let text = test.spawn_glyphs("ab c\nd", TextMeshStyle::default());
+    test.update();
+
+    let glyphs = test.children_with::<GlyphMesh>(text);
+    assert_eq!(glyphs.len(), 4);
+    let characters: Vec<char> = glyphs
+        .iter()
+        .map(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().character)
+        .collect();
+    assert_eq!(characters, vec!['a', 'b', 'c', 'd']);
+
+    // Editing the text replaces the children
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "xy".to_string();
+    test.update();
+    let glyphs = test.children_with::<GlyphMesh>(text);
+    assert_eq!(glyphs.len(), 2);
+    let mut remaining = test.world_mut().query::<&GlyphMesh>();
+    assert_eq!(remaining.iter(test.world()).count(), 2);
+}
+
+#[test]
+fn test_glyph_edits_keep_untouched_glyph_entities() {
+    #[derive(Component)]
+    struct Tagged;
+
+    let mut test = TestApp::new();
+    let text = test.spawn_glyphs("cat", TextMeshStyle::default());
+    test.update();
+    let before = test.children_with::<GlyphMesh>(text);
+    let (c, a, t) = (before[0], before[1], before[2]);
+    let c_mesh = test.mesh_id(c);
+    test.world_mut().entity_mut(a).insert(Tagged);
+    test.world_mut()
+        .get_mut::<Transform>(a)
+        .unwrap()
+        .translation
+        .y += 1.0;
+
+    // Inserting a letter only spawns its glyph and moves the ones after it
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "coat".to_string();
+    test.update();
+    let after = test.children_with::<GlyphMesh>(text);
+    assert_eq!(after.len(), 4);
+    assert!(after.contains(&c) && after.contains(&a) && after.contains(&t));
+    assert_eq!(test.mesh_id(c), c_mesh);
+    assert!(test.world().get::<Tagged>(a).is_some());
+
+    let fresh = test.spawn_glyphs("coat", TextMeshStyle::default());
+    test.update();
+    let glyph = |entity| test.world().get::<GlyphMesh>(entity).unwrap().clone();
+    let fresh_a = test.children_with::<GlyphMesh>(fresh)[2];
+    assert_eq!(glyph(a).char_index, 2);
+    assert_eq!(glyph(a).origin, glyph(fresh_a).origin);
+    // The offset on top of the origin is kept
+    assert_eq!(
+        test.world().get::<Transform>(a).unwrap().translation,
+        glyph(fresh_a).origin + Vec3::Y
+    );
+
+    // A new style rebuilds the meshes of the same entities
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .style
+        .depth = 0.5;
+    test.update();
+    assert_eq!(test.children_with::<GlyphMesh>(text).len(), 4);
+    assert!(test.world().get_entity(c).is_ok());
+    assert_ne!(test.mesh_id(c), c_mesh);
+}
+
+#[test]
+fn test_glyph_edits_reuse_removed_glyph_entities() {
+    let mut test = TestApp::new();
+    let text = test.spawn_glyphs("cat", TextMeshStyle::default());
+    test.update();
+    let sorted = |mut entities: Vec<Entity>| {
+        entities.sort();
+        entities
+    };
+    let children = |test: &TestApp| sorted(test.world().get::<Children>(text).unwrap().to_vec());
+    let before = children(&test);
+    let c = test.world().get::<Children>(text).unwrap()[0];
+    let c_mesh = test.mesh_id(c);
+    let set_text = |test: &mut TestApp, value: &str| {
+        test.world_mut()
+            .get_mut::<TextMeshGlyphs>(text)
+            .unwrap()
+            .text = value.to_string();
+        test.update();
+    };
+
+    // A removed glyph is hidden and leaves the children instead of being despawned
+    set_text(&mut test, "at");
+    assert_eq!(children(&test).len(), 2);
+    assert!(!children(&test).contains(&c));
+    assert_eq!(test.world().get::<Visibility>(c), Some(&Visibility::Hidden));
+
+    // Bringing the character back shows it again with its mesh
+    set_text(&mut test, "cat");
+    assert_eq!(sorted(test.children_with::<GlyphMesh>(text)), before);
+    assert_eq!(test.mesh_id(c), c_mesh);
+    assert_eq!(
+        test.world().get::<Visibility>(c),
+        Some(&Visibility::Inherited)
+    );
+
+    // Another character takes over the entity with the mesh of its own shape
+    set_text(&mut test, "at");
+    set_text(&mut test, "bat");
+    assert_eq!(sorted(test.children_with::<GlyphMesh>(text)), before);
+    assert_eq!(test.world().get::<GlyphMesh>(c).unwrap().character, 'b');
+    assert_ne!(test.mesh_id(c), c_mesh);
+    let fresh = test.spawn_glyphs("bat", TextMeshStyle::default());
+    test.update();
+    let b = test.children_with::<GlyphMesh>(fresh)[0];
+    assert_eq!(test.positions(c), test.positions(b));
+}
+
+#[test]
+fn test_repeated_glyphs_share_one_mesh() {
+    let mut test = TestApp::new();
+    test.update();
+    let meshes = test.mesh_count();
+    let text = test.spawn_glyphs("aaaaaa", TextMeshStyle::default());
+    test.update();
+
+    // Every glyph of the same character draws the same mesh asset
+    let glyphs = test.children_with::<GlyphMesh>(text);
+    assert_eq!(glyphs.len(), 6);
+    let a_mesh = test.mesh_id(glyphs[0]);
+    assert!(glyphs.iter().all(|&glyph| test.mesh_id(glyph) == a_mesh));
+    assert_eq!(test.mesh_count(), meshes + 1);
+
+    // Inserted and appended glyphs pick up the shared mesh too
+    let mut entity = test.world_mut().entity_mut(text);
+    entity.insert(TextMeshAppend::default());
+    entity.get_mut::<TextMeshGlyphs>().unwrap().text = "aabaaaa".to_string();
+    test.update();
+    test.world_mut()
+        .get_mut::<TextMeshAppend>(text)
+        .unwrap()
+        .push_line("aa");
+    test.update();
+    let glyphs = test.children_with::<GlyphMesh>(text);
+    assert_eq!(glyphs.len(), 9);
+    let character = |glyph| test.world().get::<GlyphMesh>(glyph).unwrap().character;
+    for glyph in glyphs {
+        assert_eq!(test.mesh_id(glyph) == a_mesh, character(glyph) == 'a');
+    }
+
+    // Handcrafted glyphs vary per character, so they don't share
+    let handcrafted = test.spawn_glyphs(
+        "aa",
+        TextMeshStyle {
+            handcrafted: Some(Handcrafted::default()),
+            ..default()
+        },
+    );
+    test.update();
+    let glyphs = test.children_with::<GlyphMesh>(handcrafted);
+    assert_ne!(test.mesh_id(glyphs[0]), test.mesh_id(glyphs[1]));
+}
+
+#[test]
+fn test_glyph_pivot_recenters_glyph_meshes() {
+    let mut test = TestApp::new();
+    let pivoted = |glyph_pivot| TextMeshStyle {
+        glyph_pivot,
+        ..default()
+    };
+    let plain = test.spawn_glyphs("cat", TextMeshStyle::default());
+    let centered = test.spawn_glyphs("cat", pivoted(GlyphPivot::Center));
+    let cornered = test.spawn_glyphs("cat", pivoted(GlyphPivot::Custom(Vec2::ZERO)));
+    test.update();
+    let placed = |test: &TestApp, glyph: Entity| {
+        let translation = test.world().get::<Transform>(glyph).unwrap().translation;
+        let origin = test.world().get::<GlyphMesh>(glyph).unwrap().origin;
+        assert_eq!(translation, origin);
+        extent(&test.positions(glyph))
+    };
+
+    for ((plain, centered), cornered) in test
+        .children_with::<GlyphMesh>(plain)
+        .into_iter()
+        .zip(test.children_with::<GlyphMesh>(centered))
+        .zip(test.children_with::<GlyphMesh>(cornered))
+    {
+        // The meshes are around their pivots, and the glyphs stay where they were
+        let (min, max) = placed(&test, centered);
+        assert!(((min + max).truncate() * 0.5).length() < 1e-4);
+        let (corner, _) = placed(&test, cornered);
+        assert!(corner.truncate().length() < 1e-4);
+
+        let (plain_min, _) = placed(&test, plain);
+        let origin = |glyph| test.world().get::<GlyphMesh>(glyph).unwrap().origin;
+        assert!((origin(centered) + min - origin(plain) - plain_min).length() < 1e-4);
+        assert!((origin(cornered) + corner - origin(plain) - plain_min).length() < 1e-4);
+    }
+
+    // Moved glyphs keep their pivot
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(centered)
+        .unwrap()
+        .text = "coat".to_string();
+    let fresh = test.spawn_glyphs("coat", pivoted(GlyphPivot::Center));
+    test.update();
+    let origins = |test: &TestApp, text| -> Vec<Vec3> {
+        let mut glyphs = test.children_with::<GlyphMesh>(text);
+        glyphs.sort_by_key(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().char_index);
+        glyphs
+            .into_iter()
+            .map(|glyph| test.world().get::<Transform>(glyph).unwrap().translation)
+            .collect()
+    };
+    let (moved, fresh) = (origins(&test, centered), origins(&test, fresh));
+    assert_eq!(moved.len(), 4);
+    for (moved, fresh) in moved.iter().zip(&fresh) {
+        assert!((*moved - *fresh).length() < 1e-4);
+    }
+}
+
+#[test]
+fn test_glyph_meshes_follow_the_text_anchor() {
+    let mut test = TestApp::new();
+    let anchored = |anchor| TextMeshStyle {
+        anchor,
+        ..default()
+    };
+    let glyphs = test.spawn_glyphs("Hello\nthere", anchored(TextAnchor::Center));
+    let mesh = test.spawn_text("Hello\nthere", anchored(TextAnchor::Center));
+    test.update();
+    let glyph_extent = |test: &TestApp, text| {
+        let points: Vec<Vec3> = test
+            .children_with::<GlyphMesh>(text)
+            .into_iter()
+            .flat_map(|glyph| {
+                let translation = test.world().get::<Transform>(glyph).unwrap().translation;
+                test.positions(glyph)
+                    .into_iter()
+                    .map(move |p| p + translation)
+            })
+            .collect();
+        extent(&points)
+    };
+
+    // The glyphs are centered as a whole, like the single mesh
+    let (min, max) = glyph_extent(&test, glyphs);
+    assert!(((min + max).truncate() * 0.5).length() < 1e-4);
+    let (mesh_min, mesh_max) = extent(&test.positions(mesh));
+    assert!((min - mesh_min).truncate().length() < 1e-3);
+    assert!((max - mesh_max).truncate().length() < 1e-3);
+
+    // Edits and anchor changes keep the glyphs anchored
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .text = "Hello there\nworld".to_string();
+    test.update();
+    let (min, max) = glyph_extent(&test, glyphs);
+    assert!(((min + max).truncate() * 0.5).length() < 1e-4);
+
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .style
+        .anchor = TextAnchor::BottomRight;
+    test.update();
+    let (min, max) = glyph_extent(&test, glyphs);
+    assert!(max.x.abs() < 1e-4 && min.y.abs() < 1e-4);
+}
+
+#[test]
+fn test_parked_glyphs_lose_their_other_components() {
+    #[derive(Component)]
+    struct Highlighted;
+
+    let mut test = TestApp::new();
+    let text = test.spawn_glyphs("cat", TextMeshStyle::default());
+    test.update();
+    let c = test.world().get::<Children>(text).unwrap()[0];
+    test.world_mut().entity_mut(c).insert(Highlighted);
+
+    // Parking strips what was added to the glyph, so a reuse doesn't inherit it
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "at".to_string();
+    test.update();
+    assert!(test.world().get::<Highlighted>(c).is_none());
+    assert!(test.world().get::<GlyphMesh>(c).is_none());
+    assert!(test.world().get::<ChildOf>(c).is_none());
+
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "cat".to_string();
+    test.update();
+    assert!(test.world().get::<GlyphMesh>(c).is_some());
+    assert!(test.world().get::<Highlighted>(c).is_none());
+
+    // Parked glyphs go away with their text
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(text)
+        .unwrap()
+        .text = "at".to_string();
+    test.update();
+    test.world_mut().entity_mut(text).despawn();
+    assert!(test.world().get_entity(c).is_err());
+}
+
+#[test]
+fn test_part_children_per_surface() {
+    let mut test = TestApp::new();
+    let text = test.spawn_parts("Parts", TextMeshStyle::default());
+    test.run_frames(3);
+
+    let parts: Vec<TextMeshPart> = test
+        .children_with::<TextMeshPart>(text)
+        .into_iter()
+        .map(|part| *test.world().get::<TextMeshPart>(part).unwrap())
+        .collect();
+    assert_eq!(
+        parts,
+        vec![TextMeshPart::Front, TextMeshPart::Back, TextMeshPart::Sides]
+    );
+}
+
+#[test]
+fn test_unchanged_text_is_not_regenerated() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("Still", TextMeshStyle::default());
+    test.update();
+    let mesh = test.mesh_id(text);
+    let count = test.mesh_count();
+
+    test.run_frames(5);
+    assert_eq!(test.mesh_id(text), mesh);
+    assert_eq!(test.mesh_count(), count);
+}
+
+#[test]
+fn test_cache_reuses_meshes() {
+    let mut test = TestApp::new();
+    let texts: Vec<Entity> = (0..10)
+        .map(|_| test.spawn_text("Same", TextMeshStyle::default()))
+        .collect();
+    test.update();
+    assert_eq!(test.mesh_count(), 1);
+
+    // A style change gives one text a mesh of its own
+    test.edit_text(texts[0], |text_mesh| text_mesh.style.depth = 0.5);
+    test.update();
+    assert_eq!(test.mesh_count(), 2);
+    assert_ne!(test.mesh_id(texts[0]), test.mesh_id(texts[1]));
+
+    // Changing it back picks up the shared mesh again instead of generating another
+    test.edit_text(texts[0], |text_mesh| text_mesh.style.depth = 0.1);
+    test.update();
+    assert_eq!(test.mesh_id(texts[0]), test.mesh_id(texts[1]));
+    assert_eq!(test.mesh_count(), 2);
+}
+
+#[test]
+fn test_line_edits_keep_the_mesh_asset() {
+    let mut test = TestApp::new();
+    let text = test.spawn_text("first\nsecond", TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(text)
+        .insert(TextMeshCachePolicy::Unique);
+    test.update();
+    let mesh = test.mesh_id(text);
+
+    test.edit_text(text, |text_mesh| {
+        text_mesh.text = "first\nchanged".to_string()
+    });
+    test.update();
+    assert_eq!(test.mesh_id(text), mesh);
+
+    // The edited mesh matches a mesh generated from scratch
+    let fresh = test.spawn_text("first\nchanged", TextMeshStyle::default());
+    test.update();
+    assert_eq!(test.positions(text), test.positions(fresh));
+}
@@ -0,0 +1,137 @@
+//! Tests for editable text input fields
+
+use crate::common::TestApp;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn input_app() -> TestApp {
+    let mut test = TestApp::new();
+    test.app.add_message::<KeyboardInput>();
+    test
+}
+
+fn spawn_input(test: &mut TestApp, input: TextMeshInput) -> Entity {
+    let entity = test.spawn_text("", TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(entity)
+        .insert((input, TextCursor3d::default()));
+    test.update();
+    entity
+}
+
+fn press(test: &mut TestApp, key: Key) {
+    let text = match &key {
+        Key::Character(text) => Some(text.clone()),
+        Key::Space => Some(" ".into()),
+        _ => None,
+    };
+    test.world_mut().write_message(KeyboardInput {
+        key_code: KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified),
+        logical_key: key,
+        state: ButtonState::Pressed,
+        text,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+    test.update();
+}
+
+fn type_text(test: &mut TestApp, text: &str) {
+    for character in text.chars() {
+        match character {
+            ' ' => press(test, Key::Space),
+            _ => press(test, Key::Character(character.to_string().into())),
+        }
+    }
+}
+
+fn text(test: &TestApp, entity: Entity) -> String {
+    test.world().get::<TextMesh>(entity).unwrap().text.clone()
+}
+
+#[test]
+fn test_focused_input_types_and_edits_its_text() {
+    let mut test = input_app();
+    let entity = spawn_input(
+        &mut test,
+        TextMeshInput {
+            focused: true,
+            ..default()
+        },
+    );
+    type_text(&mut test, "héllo wrld");
+    assert_eq!(text(&test, entity), "héllo wrld");
+    assert!(test.world().get::<Mesh3d>(entity).is_some());
+
+    // Back up over `rld`, fix the word, and jump around the line
+    for _ in 0..3 {
+        press(&mut test, Key::ArrowLeft);
+    }
+    type_text(&mut test, "o");
+    assert_eq!(text(&test, entity), "héllo world");
+    press(&mut test, Key::Home);
+    press(&mut test, Key::Delete);
+    press(&mut test, Key::ArrowRight);
+    press(&mut test, Key::Backspace);
+    assert_eq!(text(&test, entity), "llo world");
+    press(&mut test, Key::End);
+    type_text(&mut test, "!");
+    assert_eq!(text(&test, entity), "llo world!");
+
+    // The caret follows the cursor
+    let input = test.world().get::<TextMeshInput>(entity).unwrap();
+    assert_eq!(input.cursor, input.value.len());
+    let cursor = test.world().get::<TextCursor3d>(entity).unwrap();
+    assert_eq!(cursor.byte_index, input.cursor);
+}
+
+#[test]
+fn test_input_submits_limits_and_ignores_keys_without_focus() {
+    let mut test = input_app();
+    let entity = spawn_input(
+        &mut test,
+        TextMeshInput {
+            value: "ab".to_string(),
+            cursor: 2,
+            focused: true,
+            max_chars: Some(3),
+            ..default()
+        },
+    );
+    assert_eq!(text(&test, entity), "ab");
+    type_text(&mut test, "cd");
+    assert_eq!(text(&test, entity), "abc");
+
+    press(&mut test, Key::Enter);
+    let submitted: Vec<TextMeshInputSubmitted> = test
+        .world_mut()
+        .resource_mut::<Messages<TextMeshInputSubmitted>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        submitted,
+        [TextMeshInputSubmitted {
+            entity,
+            value: "abc".to_string()
+        }]
+    );
+    assert_eq!(text(&test, entity), "abc", "single lines don't break");
+
+    // Multiline inputs break lines instead
+    test.world_mut()
+        .get_mut::<TextMeshInput>(entity)
+        .unwrap()
+        .multiline = true;
+    press(&mut test, Key::Backspace);
+    press(&mut test, Key::Enter);
+    assert_eq!(text(&test, entity), "ab\n");
+
+    test.world_mut()
+        .get_mut::<TextMeshInput>(entity)
+        .unwrap()
+        .focused = false;
+    type_text(&mut test, "x");
+    assert_eq!(text(&test, entity), "ab\n");
+}
@@ -0,0 +1,140 @@
+//! Tests for putting off the generation of offscreen text
+
+use crate::common::TestApp;
+use bevy::camera::primitives::Frustum;
+use bevy::camera::CameraProjection;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+/// A camera at the origin looking down -Z, with its frustum already computed.
+fn spawn_camera(test: &mut TestApp) -> Entity {
+    let projection = PerspectiveProjection::default();
+    // The view is the world, so clip space follows from the projection alone
+    let clip_from_world = projection.get_clip_from_view();
+    test.world_mut()
+        .spawn((
+            Camera::default(),
+            Frustum::from_clip_from_world(&clip_from_world),
+        ))
+        .id()
+}
+
+fn spawn_lazy_text(test: &mut TestApp, text: &str, translation: Vec3) -> Entity {
+    let entity = test.spawn_text(text, TextMeshStyle::default());
+    test.world_mut().entity_mut(entity).insert((
+        TextMeshLazy::default(),
+        GlobalTransform::from_translation(translation),
+    ));
+    entity
+}
+
+fn move_to(test: &mut TestApp, entity: Entity, translation: Vec3) {
+    test.world_mut()
+        .entity_mut(entity)
+        .insert(GlobalTransform::from_translation(translation));
+}
+
+#[test]
+fn test_offscreen_text_waits_until_it_nears_the_view() {
+    let mut test = TestApp::new();
+    spawn_camera(&mut test);
+    let visible = spawn_lazy_text(&mut test, "Shop", Vec3::new(0.0, 0.0, -10.0));
+    let behind = spawn_lazy_text(&mut test, "Inn", Vec3::new(0.0, 0.0, 10.0));
+    test.update();
+
+    // Only the text in front of the camera is generated
+    assert!(test.world().get::<TextMeshComputed>(visible).is_some());
+    assert!(test.world().get::<TextMeshDeferred>(visible).is_none());
+    assert!(test.world().get::<TextMeshComputed>(behind).is_none());
+    assert!(test.world().get::<TextMeshDeferred>(behind).is_some());
+
+    // Edits are held back while the text stays out of view
+    test.world_mut().get_mut::<TextMesh>(behind).unwrap().text = "Tavern".to_string();
+    test.update();
+    assert!(test.world().get::<TextMeshComputed>(behind).is_none());
+
+    // Within the margin of the view, the text is generated
+    move_to(&mut test, behind, Vec3::new(0.0, 0.0, -10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshComputed>(behind).is_some());
+    assert!(test.world().get::<TextMeshDeferred>(behind).is_none());
+
+    // Leaving the view keeps the mesh that was already generated
+    move_to(&mut test, visible, Vec3::new(0.0, 0.0, 10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshComputed>(visible).is_some());
+    assert!(test.world().get::<TextMeshDeferred>(visible).is_none());
+}
+
+#[test]
+fn test_lazy_text_generates_without_cameras() {
+    let mut test = TestApp::new();
+    let text = spawn_lazy_text(&mut test, "Sign", Vec3::new(0.0, 0.0, 10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshComputed>(text).is_some());
+
+    // An inactive camera does not hold text back either
+    let camera = spawn_camera(&mut test);
+    test.world_mut()
+        .get_mut::<Camera>(camera)
+        .unwrap()
+        .is_active = false;
+    test.world_mut().get_mut::<TextMesh>(text).unwrap().text = "Post".to_string();
+    test.update();
+    assert!(test.world().get::<TextMeshDeferred>(text).is_none());
+}
+
+#[test]
+fn test_offscreen_glyphs_wait_until_they_near_the_view() {
+    let mut test = TestApp::new();
+    spawn_camera(&mut test);
+    let glyphs = test.spawn_glyphs("Inn", TextMeshStyle::default());
+    test.world_mut().entity_mut(glyphs).insert((
+        TextMeshLazy::default(),
+        GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+    ));
+    test.update();
+
+    // No glyph entities are spawned while the text is out of view
+    assert!(test.world().get::<TextMeshDeferred>(glyphs).is_some());
+    assert!(test.children_with::<GlyphMesh>(glyphs).is_empty());
+
+    move_to(&mut test, glyphs, Vec3::new(0.0, 0.0, -10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshDeferred>(glyphs).is_none());
+    assert_eq!(test.children_with::<GlyphMesh>(glyphs).len(), 3);
+}
+
+#[test]
+fn test_offscreen_sections_wait_until_they_near_the_view() {
+    let mut test = TestApp::new();
+    spawn_camera(&mut test);
+    let font = test.font.clone();
+    let sections = test
+        .world_mut()
+        .spawn((
+            TextMeshSectionsBundle {
+                sections: TextMeshSections {
+                    sections: vec![
+                        TextMeshSection::new("Big", font.clone(), default()),
+                        TextMeshSection::new("\nsmall", font, default()),
+                    ],
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshLazy::default(),
+        ))
+        .id();
+    move_to(&mut test, sections, Vec3::new(0.0, 0.0, 10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshDeferred>(sections).is_some());
+    assert!(test
+        .children_with::<TextMeshSectionMesh>(sections)
+        .is_empty());
+
+    move_to(&mut test, sections, Vec3::new(0.0, 0.0, -10.0));
+    test.update();
+    assert!(test.world().get::<TextMeshDeferred>(sections).is_none());
+    assert_eq!(test.children_with::<TextMeshSectionMesh>(sections).len(), 2);
+}
@@ -0,0 +1,147 @@
+//! Tests for text that fades out and despawns itself
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn lifetime_app() -> TestApp {
+    let mut test = TestApp::new();
+    test.world_mut().init_resource::<Time>();
+    test.world_mut().init_resource::<Assets<StandardMaterial>>();
+    test.app.finish();
+    test
+}
+
+fn advance(test: &mut TestApp, seconds: f32) {
+    test.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(seconds));
+    test.update();
+}
+
+fn material(test: &TestApp, entity: Entity) -> (AssetId<StandardMaterial>, StandardMaterial) {
+    let handle = &test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .0;
+    let materials = test.world().resource::<Assets<StandardMaterial>>();
+    (handle.id(), materials.get(handle).unwrap().clone())
+}
+
+#[test]
+fn test_alpha_fade_only_touches_the_fading_text() {
+    let mut test = lifetime_app();
+    let fading = test.spawn_text("Bye", TextMeshStyle::default());
+    let staying = test.spawn_text("Hi", TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(fading)
+        .insert(TextMeshLifetime::new(1.0, 0.5));
+    test.update();
+    let shared = FontMeshSettings::DEFAULT_MATERIAL.id();
+
+    // Before the fade, the text keeps its material
+    advance(&mut test, 0.25);
+    assert_eq!(material(&test, fading).0, shared);
+
+    // Halfway through the fade, it has a translucent copy of its own
+    advance(&mut test, 0.5);
+    let (id, faded) = material(&test, fading);
+    assert_ne!(id, shared);
+    assert_eq!(faded.alpha_mode, AlphaMode::Blend);
+    assert!((faded.base_color.alpha() - 0.5).abs() < 1e-3);
+    let (id, untouched) = material(&test, staying);
+    assert_eq!(id, shared);
+    assert_eq!(untouched.base_color.alpha(), 1.0);
+
+    // Restarting the countdown brings it back
+    test.world_mut()
+        .get_mut::<TextMeshLifetime>(fading)
+        .unwrap()
+        .elapsed = 0.0;
+    advance(&mut test, 0.1);
+    assert_eq!(material(&test, fading).1.base_color.alpha(), 1.0);
+
+    advance(&mut test, 1.0);
+    assert!(test.world().get_entity(fading).is_err());
+    assert!(test.world().get_entity(staying).is_ok());
+}
+
+#[test]
+fn test_scale_fade_despawns_glyphs_with_the_text() {
+    let mut test = lifetime_app();
+    let text = test.spawn_glyphs("Pop", TextMeshStyle::default());
+    test.world_mut().entity_mut(text).insert((
+        TextMeshLifetime::new(2.0, 1.0).with_fade(TextMeshFade::Scale),
+        Transform::from_scale(Vec3::splat(2.0)),
+    ));
+    test.update();
+    let glyphs = test.children_with::<GlyphMesh>(text);
+    assert_eq!(glyphs.len(), 3);
+
+    advance(&mut test, 1.5);
+    let scale = test.world().get::<Transform>(text).unwrap().scale;
+    assert!(scale.abs_diff_eq(Vec3::ONE, 1e-3), "Scale was {scale}");
+
+    advance(&mut test, 0.5);
+    assert!(test.world().get_entity(text).is_err());
+    assert!(glyphs
+        .iter()
+        .all(|&glyph| test.world().get_entity(glyph).is_err()));
+}
+
+#[test]
+fn test_hidden_text_gets_its_fade_undone() {
+    let mut test = lifetime_app();
+    let alpha = test.spawn_text("Bye", TextMeshStyle::default());
+    let scale = test.spawn_text("Pop", TextMeshStyle::default());
+    let hide = |fade| {
+        TextMeshLifetime::new(1.0, 0.5)
+            .with_fade(fade)
+            .with_expiry(TextMeshExpiry::Hide)
+    };
+    test.world_mut()
+        .entity_mut(alpha)
+        .insert(hide(TextMeshFade::Alpha));
+    test.world_mut().entity_mut(scale).insert((
+        hide(TextMeshFade::Scale),
+        Transform::from_scale(Vec3::splat(2.0)),
+    ));
+    test.update();
+    let shared = FontMeshSettings::DEFAULT_MATERIAL.id();
+
+    advance(&mut test, 0.75);
+    assert_ne!(material(&test, alpha).0, shared);
+    advance(&mut test, 0.5);
+
+    for text in [alpha, scale] {
+        assert!(test.world().get::<TextMeshLifetime>(text).is_none());
+        assert_eq!(
+            test.world().get::<Visibility>(text),
+            Some(&Visibility::Hidden)
+        );
+    }
+    assert_eq!(material(&test, alpha).0, shared);
+    let restored = test.world().get::<Transform>(scale).unwrap().scale;
+    assert_eq!(restored, Vec3::splat(2.0));
+}
+
+#[test]
+fn test_lifetime_opacity() {
+    let mut lifetime = TextMeshLifetime::new(3.0, 1.0);
+    assert_eq!(lifetime.opacity(), 1.0);
+    lifetime.elapsed = 2.5;
+    assert!((lifetime.opacity() - 0.5).abs() < 1e-6);
+    assert!(!lifetime.is_expired());
+    lifetime.elapsed = 3.0;
+    assert_eq!(lifetime.opacity(), 0.0);
+    assert!(lifetime.is_expired());
+
+    // Without a fade, the text stays fully visible until it is despawned
+    let abrupt = TextMeshLifetime {
+        elapsed: 2.9,
+        ..TextMeshLifetime::new(3.0, 0.0)
+    };
+    assert_eq!(abrupt.opacity(), 1.0);
+}
@@ -0,0 +1,27 @@
+//! Integration tests for the plugin's components and systems, driven frame by frame in a headless app
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod builder;
+mod cache_policy;
+mod counter;
+mod floating;
+mod generation;
+mod input;
+mod lazy;
+mod lifetime;
+mod marquee;
+mod materials;
+mod multi_world;
+mod picking;
+mod reflection;
+mod replay;
+mod reveal;
+mod scheduling;
+mod style_root;
+mod text_2d;
+mod ticker;
+mod toast;
+mod transition;
+mod validation;
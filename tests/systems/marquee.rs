@@ -0,0 +1,122 @@
+//! Tests for the marquee lights preset
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn setup_app() -> (App, Handle<FontMesh>, [Handle<StandardMaterial>; 2]) {
+    let (mut app, font) = common::app_with_font();
+    app.init_resource::<Time>();
+
+    let mut materials = Assets::<StandardMaterial>::default();
+    let lit = materials.add(Color::WHITE);
+    let unlit = materials.add(Color::BLACK);
+    (app, font, [lit, unlit])
+}
+
+fn spawn_marquee(app: &mut App, font: Handle<FontMesh>, marquee: TextMeshMarquee) -> Entity {
+    app.world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "OK".to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            marquee,
+        ))
+        .id()
+}
+
+/// Materials of the bulbs of `entity`, in bulb order.
+fn bulb_materials(app: &mut App, entity: Entity) -> Vec<Handle<StandardMaterial>> {
+    let mut query = app
+        .world_mut()
+        .query::<(&MarqueeBulb, &ChildOf, &MeshMaterial3d<StandardMaterial>)>();
+    let mut bulbs: Vec<_> = query
+        .iter(app.world())
+        .filter(|(_, child_of, _)| child_of.parent() == entity)
+        .map(|(bulb, _, material)| (bulb.index, material.0.clone()))
+        .collect();
+    bulbs.sort_by_key(|(index, _)| *index);
+    bulbs.into_iter().map(|(_, material)| material).collect()
+}
+
+#[test]
+fn test_bulbs_follow_spacing() {
+    let (mut app, font, [lit, unlit]) = setup_app();
+    let marquee = TextMeshMarquee {
+        lit,
+        unlit,
+        ..default()
+    };
+    let coarse = spawn_marquee(
+        &mut app,
+        font.clone(),
+        TextMeshMarquee {
+            spacing: 0.1,
+            ..marquee.clone()
+        },
+    );
+    let fine = spawn_marquee(
+        &mut app,
+        font,
+        TextMeshMarquee {
+            spacing: 0.05,
+            ..marquee
+        },
+    );
+
+    app.update();
+
+    let coarse = bulb_materials(&mut app, coarse).len();
+    let fine = bulb_materials(&mut app, fine).len();
+    assert!(coarse > 0, "Bulbs should be spawned");
+    let ratio = fine as f32 / coarse as f32;
+    assert!(
+        (ratio - 2.0).abs() < 0.2,
+        "Halving the spacing should double the bulbs, got {coarse} -> {fine}"
+    );
+}
+
+#[test]
+fn test_chase_moves_forward() {
+    let chase = MarqueeBlink::Chase {
+        period: 1.0,
+        spacing: 3,
+    };
+
+    let lit =
+        |elapsed: f32| -> Vec<usize> { (0..6).filter(|&i| chase.is_lit(i, elapsed)).collect() };
+    assert_eq!(lit(0.0), vec![0, 3]);
+    assert_eq!(lit(1.0), vec![1, 4]);
+    assert_eq!(lit(2.0), vec![2, 5]);
+    assert_eq!(lit(3.0), vec![0, 3]);
+}
+
+#[test]
+fn test_bulbs_blink_over_time() {
+    let (mut app, font, [lit, unlit]) = setup_app();
+    let entity = spawn_marquee(
+        &mut app,
+        font,
+        TextMeshMarquee {
+            lit: lit.clone(),
+            unlit: unlit.clone(),
+            blink: MarqueeBlink::Flash { period: 1.0 },
+            ..default()
+        },
+    );
+
+    app.update();
+    assert!(bulb_materials(&mut app, entity).iter().all(|m| *m == lit));
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(1.5));
+    app.update();
+    assert!(bulb_materials(&mut app, entity).iter().all(|m| *m == unlit));
+}
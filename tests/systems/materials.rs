@@ -0,0 +1,317 @@
+//! Tests for plugin-wide default materials and per-glyph material slots
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn material_app() -> TestApp {
+    let mut test = TestApp::new();
+    test.world_mut().init_resource::<Assets<StandardMaterial>>();
+    test.app.finish();
+    test
+}
+
+fn material(test: &TestApp, entity: Entity) -> AssetId<StandardMaterial> {
+    test.world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .id()
+}
+
+#[test]
+fn test_text_without_material_gets_the_default() {
+    let mut test = material_app();
+    let text = test.spawn_text("Plain", TextMeshStyle::default());
+    let explicit = test
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(Color::BLACK);
+    let styled = test.spawn_text("Styled", TextMeshStyle::default());
+    test.world_mut()
+        .entity_mut(styled)
+        .insert(MeshMaterial3d(explicit.clone()));
+    test.update();
+
+    let default_material = FontMeshSettings::DEFAULT_MATERIAL.id();
+    assert_eq!(material(&test, text), default_material);
+    assert_eq!(material(&test, styled), explicit.id());
+    let materials = test.world().resource::<Assets<StandardMaterial>>();
+    assert_eq!(
+        materials.get(default_material).unwrap().base_color,
+        Color::WHITE
+    );
+
+    // Glyph mode without a material generates glyphs with the default one
+    let text_mesh = test.text_mesh("ab", TextMeshStyle::default());
+    let glyphs = test
+        .world_mut()
+        .spawn(TextMeshGlyphs {
+            text: text_mesh.text,
+            font: text_mesh.font,
+            style: text_mesh.style,
+            ..default()
+        })
+        .id();
+    test.update();
+    let children = test.children_with::<GlyphMesh>(glyphs);
+    assert_eq!(children.len(), 2);
+    assert!(children
+        .iter()
+        .all(|&glyph| material(&test, glyph) == default_material));
+}
+
+#[test]
+fn test_bare_text_components_bring_what_they_need() {
+    let mut test = material_app();
+    let text_mesh = test.text_mesh("Bare", TextMeshStyle::default());
+    let text = test.world_mut().spawn(text_mesh).id();
+    let text_mesh = test.text_mesh("ab", TextMeshStyle::default());
+    let glyphs = test
+        .world_mut()
+        .spawn(TextMeshGlyphs {
+            text: text_mesh.text,
+            font: text_mesh.font,
+            ..default()
+        })
+        .id();
+    let font = test.font.clone();
+    let flat = test
+        .world_mut()
+        .spawn(TextMesh2d {
+            text: "Flat".to_string(),
+            font,
+            ..default()
+        })
+        .id();
+    test.update();
+
+    // A flat text gets a 2D material slot and transform like the others
+    assert!(test
+        .world()
+        .get::<MeshMaterial2d<ColorMaterial>>(flat)
+        .is_some());
+    assert!(test.world().get::<GlobalTransform>(flat).is_some());
+
+    // The mesh is generated and shown with the default material
+    let default_material = FontMeshSettings::DEFAULT_MATERIAL.id();
+    assert!(test.mesh(text).count_vertices() > 0);
+    assert_eq!(material(&test, text), default_material);
+    for entity in [text, glyphs] {
+        assert!(test.world().get::<GlobalTransform>(entity).is_some());
+        assert!(test.world().get::<InheritedVisibility>(entity).is_some());
+    }
+    let children = test.children_with::<GlyphMesh>(glyphs);
+    assert_eq!(children.len(), 2);
+    assert!(children
+        .iter()
+        .all(|&glyph| material(&test, glyph) == default_material));
+}
+
+#[test]
+fn test_factory_derives_glyph_materials() {
+    let mut test = material_app();
+    let (gold, red) = {
+        let mut materials = test.world_mut().resource_mut::<Assets<StandardMaterial>>();
+        (
+            materials.add(Color::srgb(1.0, 0.8, 0.2)),
+            materials.add(Color::srgb(0.9, 0.1, 0.1)),
+        )
+    };
+    let capitals = red.clone();
+    test.world_mut()
+        .insert_resource(FontMeshSettings::new(gold.clone()).with_material_factory(
+            move |target, _| {
+                target
+                    .glyph?
+                    .character
+                    .is_uppercase()
+                    .then(|| capitals.clone())
+            },
+        ));
+
+    let merged = test.spawn_text("Merged", TextMeshStyle::default());
+    let glyphs = test.spawn_glyphs("aB", TextMeshStyle::default());
+    test.run_frames(2);
+
+    assert_eq!(material(&test, merged), gold.id());
+    let children = test.children_with::<GlyphMesh>(glyphs);
+    let materials: Vec<_> = children
+        .iter()
+        .map(|&glyph| material(&test, glyph))
+        .collect();
+    assert_eq!(materials, vec![gold.id(), red.id()]);
+
+    // Regenerated glyphs ask the factory again
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .text = "Cd".to_string();
+    test.update();
+    let children = test.children_with::<GlyphMesh>(glyphs);
+    assert_eq!(material(&test, children[0]), red.id());
+    assert_eq!(material(&test, children[1]), gold.id());
+}
+
+#[test]
+fn test_glyph_material_slots_by_character_class() {
+    let mut test = material_app();
+    let (gold, red, silver) = {
+        let mut materials = test.world_mut().resource_mut::<Assets<StandardMaterial>>();
+        (
+            materials.add(Color::srgb(1.0, 0.8, 0.2)),
+            materials.add(Color::srgb(0.9, 0.1, 0.1)),
+            materials.add(Color::srgb(0.8, 0.8, 0.8)),
+        )
+    };
+    let glyphs = test.spawn_glyphs("a1B.", TextMeshStyle::default());
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .materials = GlyphMaterials::default()
+        .with(CharClass::Digit, gold.clone())
+        .with(CharClass::Chars("B".to_string()), red.clone())
+        .with(CharClass::Alphabetic, silver.clone());
+    test.run_frames(2);
+
+    // The first matching slot wins, and other glyphs use the material of the text
+    let materials = |test: &TestApp| -> Vec<_> {
+        test.children_with::<GlyphMesh>(glyphs)
+            .iter()
+            .map(|&glyph| material(test, glyph))
+            .collect()
+    };
+    let text_material = material(&test, glyphs);
+    assert_eq!(
+        materials(&test),
+        vec![silver.id(), gold.id(), red.id(), text_material]
+    );
+
+    // Inserted glyphs get their slot too
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .text = "a12B.".to_string();
+    test.update();
+    let glyph = test
+        .children_with::<GlyphMesh>(glyphs)
+        .into_iter()
+        .find(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().character == '2')
+        .unwrap();
+    assert_eq!(material(&test, glyph), gold.id());
+}
+
+#[test]
+fn test_material_overrides_by_character_range() {
+    let mut test = material_app();
+    let (gold, silver) = {
+        let mut materials = test.world_mut().resource_mut::<Assets<StandardMaterial>>();
+        (
+            materials.add(Color::srgb(1.0, 0.8, 0.2)),
+            materials.add(Color::srgb(0.8, 0.8, 0.8)),
+        )
+    };
+    let glyphs = test.spawn_glyphs("let x = 1;", TextMeshStyle::default());
+    let mut text_glyphs = test.world_mut().get_mut::<TextMeshGlyphs>(glyphs).unwrap();
+    text_glyphs.materials = GlyphMaterials::default().with(CharClass::Alphabetic, silver.clone());
+    text_glyphs.material_overrides = vec![(0..3, gold.clone()), (2..9, silver.clone())];
+    test.run_frames(2);
+
+    // The keyword gets its override over the slots, the rest keeps falling back
+    let materials = |test: &TestApp| -> Vec<(char, AssetId<StandardMaterial>)> {
+        let mut children = test.children_with::<GlyphMesh>(glyphs);
+        children.sort_by_key(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().char_index);
+        children
+            .iter()
+            .map(|&glyph| {
+                let character = test.world().get::<GlyphMesh>(glyph).unwrap().character;
+                (character, material(test, glyph))
+            })
+            .collect()
+    };
+    let text_material = material(&test, glyphs);
+    assert_eq!(
+        materials(&test),
+        vec![
+            ('l', gold.id()),
+            ('e', gold.id()),
+            ('t', gold.id()),
+            ('x', silver.id()),
+            ('=', silver.id()),
+            ('1', silver.id()),
+            (';', text_material),
+        ]
+    );
+
+    // Inserted glyphs get the override of their range when they are spawned
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .text = "lnet x = 1;".to_string();
+    test.update();
+    assert_eq!(materials(&test)[1], ('n', gold.id()));
+}
+
+#[test]
+fn test_kept_glyphs_follow_their_material_overrides() {
+    let mut test = material_app();
+    let (gold, red) = {
+        let mut materials = test.world_mut().resource_mut::<Assets<StandardMaterial>>();
+        (
+            materials.add(Color::srgb(1.0, 0.8, 0.2)),
+            materials.add(Color::srgb(0.9, 0.1, 0.1)),
+        )
+    };
+    let glyphs = test.spawn_glyphs("abc", TextMeshStyle::default());
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .material_overrides = vec![(0..2, gold.clone())];
+    test.run_frames(2);
+    let text_material = material(&test, glyphs);
+    let glyph_of = |test: &TestApp, character| {
+        test.children_with::<GlyphMesh>(glyphs)
+            .into_iter()
+            .find(|&glyph| test.world().get::<GlyphMesh>(glyph).unwrap().character == character)
+            .unwrap()
+    };
+    let (a, b, c) = (
+        glyph_of(&test, 'a'),
+        glyph_of(&test, 'b'),
+        glyph_of(&test, 'c'),
+    );
+    assert_eq!(material(&test, b), gold.id());
+
+    // Shifted out of the range by an insertion, `b` keeps its entity but not its material
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .text = "xabc".to_string();
+    test.update();
+    assert_eq!(glyph_of(&test, 'b'), b);
+    assert_eq!(material(&test, a), gold.id());
+    assert_eq!(material(&test, b), text_material);
+
+    // Kept glyphs also follow edited overrides, unless their material was replaced
+    test.world_mut()
+        .get_mut::<MeshMaterial3d<StandardMaterial>>(a)
+        .unwrap()
+        .0 = red.clone();
+    test.world_mut()
+        .get_mut::<TextMeshGlyphs>(glyphs)
+        .unwrap()
+        .material_overrides = vec![(2..4, gold.clone())];
+    test.update();
+    assert_eq!(material(&test, a), red.id());
+    assert_eq!(material(&test, b), gold.id());
+    assert_eq!(material(&test, c), gold.id());
+}
+
+#[test]
+fn test_without_settings_materials_are_left_alone() {
+    let mut test = material_app();
+    test.world_mut().remove_resource::<FontMeshSettings>();
+    let text = test.spawn_text("Plain", TextMeshStyle::default());
+    test.update();
+
+    assert_eq!(material(&test, text), AssetId::default());
+}
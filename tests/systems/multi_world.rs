@@ -0,0 +1,82 @@
+//! Tests for running the plugin in secondary worlds and sub-apps
+
+use crate::common;
+use bevy::app::AppLabel;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+#[derive(AppLabel, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct PreviewApp;
+
+/// Build an app with the plugin but no asset server, like an editor preview world.
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin);
+    app
+}
+
+fn spawn_text(world: &mut World, text: &str) -> Entity {
+    let font = world
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+
+    world
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id()
+}
+
+fn vertex_count(world: &World, entity: Entity) -> usize {
+    let handle = &world
+        .get::<Mesh3d>(entity)
+        .expect("Entity should have Mesh3d")
+        .0;
+    world
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .expect("Generated mesh should be in this world's assets")
+        .count_vertices()
+}
+
+#[test]
+fn test_world_without_asset_server() {
+    let mut app = headless_app();
+    let entity = spawn_text(app.world_mut(), "Hello");
+
+    app.update();
+
+    assert!(
+        app.world().get::<TextMeshComputed>(entity).is_some(),
+        "Text should be processed"
+    );
+    assert!(
+        vertex_count(app.world(), entity) > 0,
+        "Mesh should have vertices"
+    );
+}
+
+#[test]
+fn test_sub_app_runs_independently() {
+    let mut app = headless_app();
+    let main_entity = spawn_text(app.world_mut(), "Game");
+
+    let mut preview = headless_app();
+    let preview_entity = spawn_text(preview.world_mut(), "Preview text");
+    app.insert_sub_app(PreviewApp, std::mem::take(preview.main_mut()));
+
+    app.update();
+
+    let preview_world = app.sub_app(PreviewApp).world();
+    assert!(vertex_count(app.world(), main_entity) > 0);
+    assert!(vertex_count(preview_world, preview_entity) > 0);
+
+    // Each world keeps its own mesh assets
+    assert_eq!(app.world().resource::<Assets<Mesh>>().len(), 1);
+    assert_eq!(preview_world.resource::<Assets<Mesh>>().len(), 1);
+}
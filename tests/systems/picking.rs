@@ -0,0 +1,117 @@
+//! Tests for per-glyph pointer events
+#![cfg(feature = "picking")]
+
+use crate::common;
+use bevy::camera::NormalizedRenderTarget;
+use bevy::picking::backend::HitData;
+use bevy::picking::events::{Click, Over, Pointer};
+use bevy::picking::pointer::{Location, PointerButton, PointerId};
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Clicked(Vec<(Entity, char, usize)>);
+
+fn setup() -> (App, Entity) {
+    let (mut app, font) = common::app_with_font();
+    app.init_resource::<Clicked>();
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshGlyphsBundle {
+            text_glyphs: TextMeshGlyphs {
+                text: "ab".to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .observe(
+            |click: On<GlyphPointer<Click>>, mut clicked: ResMut<Clicked>| {
+                clicked
+                    .0
+                    .push((click.entity, click.glyph.character, click.glyph.char_index));
+            },
+        )
+        .id();
+    app.update();
+    (app, entity)
+}
+
+fn glyph_entity(app: &mut App, character: char) -> Entity {
+    let mut query = app.world_mut().query::<(Entity, &GlyphMesh)>();
+    query
+        .iter(app.world())
+        .find(|(_, glyph)| glyph.character == character)
+        .map(|(entity, _)| entity)
+        .expect("Glyph should be spawned")
+}
+
+fn location() -> Location {
+    Location {
+        target: NormalizedRenderTarget::None {
+            width: 100,
+            height: 100,
+        },
+        position: Vec2::ZERO,
+    }
+}
+
+fn hit() -> HitData {
+    HitData::new(Entity::PLACEHOLDER, 1.0, None, None)
+}
+
+#[test]
+fn test_glyphs_are_pickable() {
+    let (mut app, _) = setup();
+    let glyph = glyph_entity(&mut app, 'a');
+    assert!(app.world().get::<Pickable>(glyph).is_some());
+}
+
+#[test]
+fn test_click_reports_glyph() {
+    let (mut app, entity) = setup();
+    let glyph = glyph_entity(&mut app, 'b');
+
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        location(),
+        Click {
+            button: PointerButton::Primary,
+            hit: hit(),
+            duration: Duration::ZERO,
+        },
+        glyph,
+    ));
+
+    // The glyph event is triggered through commands
+    app.world_mut().flush();
+    let clicked = &app.world().resource::<Clicked>().0;
+    assert_eq!(
+        clicked.as_slice(),
+        &[(entity, 'b', 1)],
+        "Exactly one click on 'b' should reach the text entity"
+    );
+}
+
+#[test]
+fn test_events_on_text_entity_are_ignored() {
+    let (mut app, entity) = setup();
+    let over = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let counter = over.clone();
+    app.world_mut()
+        .entity_mut(entity)
+        .observe(move |_: On<GlyphPointer<Over>>| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        });
+
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        location(),
+        Over { hit: hit() },
+        entity,
+    ));
+    app.world_mut().flush();
+    assert_eq!(over.load(std::sync::atomic::Ordering::Relaxed), 0);
+}
@@ -0,0 +1,124 @@
+//! Tests for reflecting text components and saving them in scenes
+
+use crate::common::TestApp;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy::scene::ron;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::{DynamicScene, DynamicSceneBuilder};
+use bevy_fontmesh::prelude::*;
+use serde::de::DeserializeSeed;
+use std::any::TypeId;
+
+fn styled() -> TextMeshStyle {
+    TextMeshStyle {
+        depth: 0.4,
+        subdivision: 8,
+        anchor: TextAnchor::BottomRight,
+        justify: JustifyText::Center,
+        stylistic_sets: vec![StylisticSet::Alternates],
+        tabular_figures: true,
+        letter_spacing: 0.05,
+        ..default()
+    }
+}
+
+#[test]
+fn test_style_types_are_registered() {
+    let test = TestApp::new();
+    let registry = test.world().resource::<AppTypeRegistry>().read();
+    for type_id in [
+        TypeId::of::<TextMesh>(),
+        TypeId::of::<TextMeshGlyphs>(),
+        TypeId::of::<GlyphMesh>(),
+        TypeId::of::<TextMeshStyle>(),
+        TypeId::of::<TextAnchor>(),
+        TypeId::of::<JustifyText>(),
+    ] {
+        assert!(registry.contains(type_id));
+    }
+
+    // A style read back through reflection is the same style
+    let style = styled();
+    let registration = registry.get(TypeId::of::<TextMeshStyle>()).unwrap();
+    let serialized = ron::to_string(&TypedReflectSerializer::new(&style, &registry)).unwrap();
+    let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+    let value = TypedReflectDeserializer::new(registration, &registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(TextMeshStyle::from_reflect(&*value), Some(style));
+}
+
+#[test]
+fn test_text_round_trips_through_a_scene_file() {
+    // Scenes only keep id handles, like the one of the embedded font
+    let mut test = TestApp::new();
+    let text = test
+        .world_mut()
+        .spawn(TextMesh {
+            text: "Save me".to_string(),
+            style: styled(),
+            ..default()
+        })
+        .id();
+    let glyphs = test
+        .world_mut()
+        .spawn(TextMeshGlyphs {
+            text: "Glyphs".to_string(),
+            style: styled(),
+            ..default()
+        })
+        .id();
+    let glyph = test
+        .world_mut()
+        .spawn(GlyphMesh {
+            char_index: 2,
+            line_index: 1,
+            character: 'y',
+            origin: Vec3::new(1.0, -2.0, 0.0),
+        })
+        .id();
+
+    // Save the text components to a .scn.ron string
+    let scene = DynamicSceneBuilder::from_world(test.world())
+        .deny_all()
+        .allow_component::<TextMesh>()
+        .allow_component::<TextMeshGlyphs>()
+        .allow_component::<GlyphMesh>()
+        .extract_entities([text, glyphs, glyph].into_iter())
+        .build();
+    let registry = test.world().resource::<AppTypeRegistry>().clone();
+    let serialized = scene.serialize(&registry.read()).unwrap();
+    assert!(serialized.contains("Save me"));
+    assert!(serialized.contains("BottomRight"));
+
+    // Load it into another world
+    let mut loaded = TestApp::new();
+    let scene: DynamicScene = {
+        let registry = registry.read();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap()
+    };
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(loaded.world_mut(), &mut entity_map)
+        .unwrap();
+
+    let loaded_text = loaded.world().get::<TextMesh>(entity_map[&text]).unwrap();
+    assert_eq!(loaded_text.text, "Save me");
+    assert_eq!(loaded_text.style, styled());
+    let loaded_glyphs = loaded
+        .world()
+        .get::<TextMeshGlyphs>(entity_map[&glyphs])
+        .unwrap();
+    assert_eq!(loaded_glyphs.text, "Glyphs");
+    assert_eq!(loaded_glyphs.style, styled());
+    let loaded_glyph = loaded.world().get::<GlyphMesh>(entity_map[&glyph]).unwrap();
+    assert_eq!((loaded_glyph.char_index, loaded_glyph.character), (2, 'y'));
+    assert_eq!(loaded_glyph.origin, Vec3::new(1.0, -2.0, 0.0));
+}
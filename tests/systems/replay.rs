@@ -0,0 +1,136 @@
+//! Tests for recording and playing back text state
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+/// App with a manually advanced clock.
+fn setup_app() -> (App, Handle<FontMesh>) {
+    let (mut app, font) = common::app_with_font();
+    app.init_resource::<Time>();
+    (app, font)
+}
+
+fn advance(app: &mut App, seconds: u64) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(seconds));
+    app.update();
+}
+
+/// Record a text that is spawned at 0s, edited at 1s, and removed at 2s.
+fn record_stream() -> TextReplayStream {
+    let (mut app, font) = setup_app();
+    app.insert_resource(TextMeshRecorder::default());
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "100".to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "250".to_string();
+    advance(&mut app, 1);
+
+    // Touching the component without changing it records nothing
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap();
+    app.update();
+
+    app.world_mut().entity_mut(entity).despawn();
+    advance(&mut app, 1);
+
+    app.world_mut()
+        .remove_resource::<TextMeshRecorder>()
+        .unwrap()
+        .stream
+}
+
+fn replayed_texts(app: &mut App) -> Vec<String> {
+    app.world_mut()
+        .query_filtered::<&TextMesh, With<ReplayedText>>()
+        .iter(app.world())
+        .map(|text_mesh| text_mesh.text.clone())
+        .collect()
+}
+
+#[test]
+fn test_records_only_changes() {
+    let stream = record_stream();
+
+    let events = stream.events();
+    assert_eq!(
+        events.len(),
+        3,
+        "Spawn, edit, and removal should be recorded"
+    );
+    assert_eq!(events[0].time, 0.0);
+    assert_eq!(events[1].time, 1.0);
+    assert_eq!(events[2].time, 2.0);
+    assert!(
+        events[2].state.is_none(),
+        "Last event should be the removal"
+    );
+    assert_eq!(stream.fonts().len(), 1, "Fonts should be stored once");
+
+    let texts = |time| -> Vec<String> {
+        stream
+            .state_at(time)
+            .values()
+            .map(|snapshot| snapshot.text.clone())
+            .collect()
+    };
+    assert_eq!(texts(0.5), ["100"]);
+    assert_eq!(texts(1.5), ["250"]);
+    assert!(texts(2.5).is_empty());
+}
+
+#[test]
+fn test_playback_reconstructs_text() {
+    let stream = record_stream();
+    let (mut app, _) = setup_app();
+    app.insert_resource(TextMeshPlayback::new(stream, 0.0));
+
+    app.update();
+    assert_eq!(replayed_texts(&mut app), ["100"]);
+
+    let entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ReplayedText>>()
+        .single(app.world())
+        .unwrap();
+    assert!(
+        app.world().get::<TextMeshComputed>(entity).is_some(),
+        "Replayed text should be generated in the same frame"
+    );
+
+    app.world_mut().resource_mut::<TextMeshPlayback>().time = 1.0;
+    app.update();
+    assert_eq!(replayed_texts(&mut app), ["250"]);
+
+    app.world_mut().resource_mut::<TextMeshPlayback>().time = 2.0;
+    app.update();
+    assert!(replayed_texts(&mut app).is_empty());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_stream_serialization_round_trip() {
+    let stream = record_stream();
+
+    let json = serde_json::to_string(&stream).unwrap();
+    let restored: TextReplayStream = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(stream.events(), restored.events());
+    assert!(
+        restored.fonts().iter().all(|font| font.handle.is_none()),
+        "Handles are not serialized"
+    );
+}
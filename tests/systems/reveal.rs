@@ -0,0 +1,124 @@
+//! Tests for wipe and dissolve reveals of text
+
+use crate::common;
+use crate::common::TestApp;
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn spawn_revealed(test: &mut TestApp, text: &str, reveal: TextMeshReveal) -> Entity {
+    let entity = test.spawn_text(text, TextMeshStyle::default());
+    test.world_mut().entity_mut(entity).insert(reveal);
+    test.update();
+    entity
+}
+
+fn set_progress(test: &mut TestApp, entity: Entity, progress: f32) {
+    test.world_mut()
+        .get_mut::<TextMeshReveal>(entity)
+        .unwrap()
+        .set_progress(progress);
+    test.update();
+}
+
+/// Corners of every drawn triangle of `entity`.
+fn triangles(test: &TestApp, entity: Entity) -> Vec<[Vec3; 3]> {
+    let positions = test.positions(entity);
+    test.indices(entity)
+        .chunks_exact(3)
+        .map(|triangle| [0, 1, 2].map(|corner| positions[triangle[corner] as usize]))
+        .collect()
+}
+
+/// The [`ATTRIBUTE_GLYPH_SEED`] of `entity`.
+fn glyph_seeds(test: &TestApp, entity: Entity) -> Vec<f32> {
+    match test.mesh(entity).attribute(ATTRIBUTE_GLYPH_SEED) {
+        Some(VertexAttributeValues::Float32(seeds)) => seeds.clone(),
+        other => panic!("Expected Float32 glyph seeds, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_wipe_clips_at_the_moving_plane() {
+    let mut test = TestApp::new();
+    let plain = test.spawn_text("W", TextMeshStyle::default());
+    let entity = spawn_revealed(
+        &mut test,
+        "W",
+        TextMeshReveal::Wipe {
+            direction: Vec2::X,
+            progress: 0.0,
+        },
+    );
+    assert!(test.indices(entity).is_empty());
+    assert_ne!(test.mesh_id(entity), test.mesh_id(plain));
+
+    // Halfway, the triangles crossing the middle are cut at it
+    set_progress(&mut test, entity, 0.5);
+    let full = triangles(&test, plain);
+    let (min, max) = common::extent(&test.positions(plain));
+    let middle = (min.x + max.x) / 2.0;
+    let half = triangles(&test, entity);
+    assert!(!half.is_empty());
+    assert!(half
+        .iter()
+        .flatten()
+        .all(|corner| corner.x <= middle + 1e-4));
+    assert!(half
+        .iter()
+        .flatten()
+        .any(|corner| (corner.x - middle).abs() < 1e-4));
+    assert!(full.iter().any(|triangle| {
+        triangle.iter().any(|corner| corner.x < middle)
+            && triangle.iter().any(|corner| corner.x > middle)
+    }));
+
+    // Fully revealed, it draws every triangle, and removing the reveal shares the full
+    // mesh again
+    set_progress(&mut test, entity, 1.0);
+    assert_eq!(triangles(&test, entity), full);
+    test.world_mut()
+        .entity_mut(entity)
+        .remove::<TextMeshReveal>();
+    test.update();
+    assert_eq!(test.mesh_id(entity), test.mesh_id(plain));
+}
+
+#[test]
+fn test_dissolve_shows_whole_glyphs_past_their_threshold() {
+    let mut test = TestApp::new();
+    let reveal = TextMeshReveal::Dissolve {
+        seed: 7,
+        progress: 0.5,
+    };
+    let entity = spawn_revealed(&mut test, "Dissolving text", reveal);
+    let twin = spawn_revealed(&mut test, "Dissolving text", reveal);
+
+    // Every drawn triangle belongs to a glyph whose threshold is below the progress
+    let seeds = glyph_seeds(&test, entity);
+    assert_eq!(seeds.len(), test.positions(entity).len());
+    let indices = test.indices(entity);
+    assert!(!indices.is_empty());
+    assert!(indices.iter().all(|&index| seeds[index as usize] < 0.5));
+    assert!(seeds.iter().any(|&seed| seed >= 0.5));
+
+    // Vertices of one glyph share its threshold, and the same seed dissolves alike
+    let mut thresholds = seeds.clone();
+    thresholds.dedup();
+    assert!(thresholds.len() > 1 && thresholds.len() < seeds.len());
+    assert_eq!(test.indices(twin), indices);
+
+    // Editing the text dissolves the new mesh
+    let plain = test.spawn_text("Other text", TextMeshStyle::default());
+    test.edit_text(entity, |text_mesh| {
+        text_mesh.text = "Other text".to_string()
+    });
+    test.update();
+    assert_ne!(test.positions(entity), test.positions(twin));
+    assert!(test
+        .indices(entity)
+        .iter()
+        .all(|&index| glyph_seeds(&test, entity)[index as usize] < 0.5));
+    set_progress(&mut test, entity, 1.0);
+    assert_eq!(test.indices(entity), test.indices(plain));
+}
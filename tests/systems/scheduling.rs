@@ -0,0 +1,149 @@
+//! Tests for generation schedule placement and same-frame bounds
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::camera::visibility::VisibilitySystems;
+use bevy::ecs::schedule::InternedSystemSet;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn app_with_text(plugin: impl Plugin, text: &str) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(plugin);
+
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: text.to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    (app, entity)
+}
+
+/// Records whether the text had bounds when the visibility bounds systems ran.
+#[derive(Resource, Default)]
+struct BoundsSeen(Vec<Option<Aabb>>);
+
+fn record_bounds(mut seen: ResMut<BoundsSeen>, query: Query<&Aabb, With<TextMesh>>) {
+    seen.0.push(query.iter().next().copied());
+}
+
+#[test]
+fn test_post_update_bounds_ready_before_visibility() {
+    let (mut app, _) = app_with_text(FontMeshPlugin.in_schedule(PostUpdate), "Hello");
+    app.init_resource::<BoundsSeen>().add_systems(
+        PostUpdate,
+        record_bounds.in_set(VisibilitySystems::CalculateBounds),
+    );
+
+    app.update();
+
+    let seen = &app.world().resource::<BoundsSeen>().0;
+    assert_eq!(seen.len(), 1);
+    assert!(
+        seen[0].is_some(),
+        "Aabb should exist on the same frame the mesh is generated"
+    );
+}
+
+#[test]
+fn test_aabb_follows_text_changes() {
+    let (mut app, entity) = app_with_text(FontMeshPlugin, "a");
+    app.update();
+    let short = *app.world().get::<Aabb>(entity).expect("Aabb should be set");
+
+    app.world_mut().get_mut::<TextMesh>(entity).unwrap().text = "aaaaaaaa".to_string();
+    app.update();
+    let long = *app.world().get::<Aabb>(entity).expect("Aabb should be set");
+
+    assert!(
+        long.half_extents.x > short.half_extents.x,
+        "Aabb should grow with the text"
+    );
+}
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+struct Presentation;
+
+fn edit_text(mut query: Query<&mut TextMesh>) {
+    for mut text_mesh in query.iter_mut() {
+        text_mesh.text = "aaaaaaaa".to_string();
+    }
+}
+
+/// Width of the text after its first frame, with generation nested in [`Presentation`].
+fn first_frame_width(presentation: impl IntoScheduleConfigs<InternedSystemSet, ()>) -> f32 {
+    let (mut app, entity) = app_with_text(FontMeshPlugin.in_set(Presentation), "a");
+    app.configure_sets(Update, presentation)
+        .add_systems(Update, edit_text);
+    app.update();
+    app.world()
+        .get::<Aabb>(entity)
+        .expect("Aabb should be set")
+        .half_extents
+        .x
+}
+
+#[test]
+fn test_generation_follows_the_ordering_of_its_parent_set() {
+    // Only generation ordered after the edit sees the new text on the first frame
+    let before_edit = first_frame_width(Presentation.before(edit_text));
+    let after_edit = first_frame_width(Presentation.after(edit_text));
+    assert!(after_edit > before_edit);
+}
+
+#[test]
+fn test_update_schedule_leaves_transform_propagation_unordered() {
+    // Propagation doesn't run in `Update`, so the plugin adds no ordering that could
+    // conflict with an app's own placement of the set there
+    let (mut app, entity) = app_with_text(FontMeshPlugin, "Hello");
+    app.configure_sets(
+        Update,
+        VisibilitySystems::CalculateBounds.before(TransformSystems::Propagate),
+    );
+
+    app.update();
+    assert!(app.world().get::<Aabb>(entity).is_some());
+}
+
+#[test]
+fn test_post_update_glyphs_are_placed_on_their_first_frame() {
+    let mut app = App::new();
+    app.add_plugins((TransformPlugin, FontMeshPlugin.in_schedule(PostUpdate)));
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshGlyphs {
+                text: "Hi".to_string(),
+                font,
+                ..default()
+            },
+            Transform::from_xyz(10.0, 5.0, 0.0),
+        ))
+        .id();
+
+    // Glyphs are spawned after propagation, but are drawn where they belong right away
+    app.update();
+    let children = app.world().get::<Children>(text).unwrap().to_vec();
+    assert_eq!(children.len(), 2);
+    for child in children {
+        let transform = app.world().get::<Transform>(child).unwrap();
+        let global_transform = app.world().get::<GlobalTransform>(child).unwrap();
+        let expected = Vec3::new(10.0, 5.0, 0.0) + transform.translation;
+        assert!((global_transform.translation() - expected).length() < 1e-5);
+    }
+}
@@ -0,0 +1,267 @@
+//! Tests for style inheritance from a text style root
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn root_style() -> TextMeshStyle {
+    TextMeshStyle {
+        depth: 0.05,
+        subdivision: 7,
+        ..default()
+    }
+}
+
+/// Spawn a root with a text child and a glyph grandchild.
+fn setup() -> (App, Entity, Entity, Entity) {
+    let (mut app, font) = common::app_with_font();
+
+    let root = app
+        .world_mut()
+        .spawn(TextStyleRoot {
+            font: Some(font),
+            style: root_style(),
+        })
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Play".to_string(),
+                    ..default()
+                },
+                ..default()
+            },
+            ChildOf(root),
+        ))
+        .id();
+    let glyphs = app
+        .world_mut()
+        .spawn((
+            TextMeshGlyphsBundle {
+                text_glyphs: TextMeshGlyphs {
+                    text: "Quit".to_string(),
+                    ..default()
+                },
+                ..default()
+            },
+            ChildOf(text),
+        ))
+        .id();
+
+    app.update();
+    (app, root, text, glyphs)
+}
+
+#[test]
+fn test_descendants_inherit_font_and_style() {
+    let (app, root, text, glyphs) = setup();
+    let root = app.world().get::<TextStyleRoot>(root).unwrap();
+
+    let text_mesh = app.world().get::<TextMesh>(text).unwrap();
+    assert_eq!(text_mesh.style, root_style());
+    assert_eq!(Some(&text_mesh.font), root.font.as_ref());
+    assert!(
+        app.world().get::<TextMeshComputed>(text).is_some(),
+        "Text should generate in the same frame it inherits its font"
+    );
+
+    let text_glyphs = app.world().get::<TextMeshGlyphs>(glyphs).unwrap();
+    assert_eq!(text_glyphs.style, root_style());
+    assert_eq!(Some(&text_glyphs.font), root.font.as_ref());
+}
+
+#[test]
+fn test_root_changes_propagate() {
+    let (mut app, root, text, glyphs) = setup();
+
+    app.world_mut()
+        .get_mut::<TextStyleRoot>(root)
+        .unwrap()
+        .style
+        .depth = 0.3;
+    app.update();
+
+    assert_eq!(app.world().get::<TextMesh>(text).unwrap().style.depth, 0.3);
+    assert_eq!(
+        app.world()
+            .get::<TextMeshGlyphs>(glyphs)
+            .unwrap()
+            .style
+            .depth,
+        0.3
+    );
+}
+
+#[test]
+fn test_override_keeps_own_style() {
+    let (mut app, root, text, _) = setup();
+    app.world_mut().entity_mut(text).insert(TextStyleOverride);
+    app.world_mut()
+        .get_mut::<TextMesh>(text)
+        .unwrap()
+        .style
+        .depth = 1.0;
+
+    app.world_mut()
+        .get_mut::<TextStyleRoot>(root)
+        .unwrap()
+        .style
+        .depth = 0.3;
+    app.update();
+    assert_eq!(app.world().get::<TextMesh>(text).unwrap().style.depth, 1.0);
+
+    // Removing the override picks the root style up again, apart from the text's own depth
+    app.world_mut()
+        .get_mut::<TextStyleRoot>(root)
+        .unwrap()
+        .style
+        .subdivision = 12;
+    app.world_mut()
+        .entity_mut(text)
+        .remove::<TextStyleOverride>();
+    app.update();
+    let style = &app.world().get::<TextMesh>(text).unwrap().style;
+    assert_eq!(style.depth, 1.0);
+    assert_eq!(style.subdivision, 12);
+}
+
+#[test]
+fn test_own_fields_win_over_the_root() {
+    let (mut app, root, _, _) = setup();
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Back".to_string(),
+                    style: TextMeshStyle {
+                        letter_spacing: 0.2,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            },
+            ChildOf(root),
+        ))
+        .id();
+    app.update();
+
+    let style = &app.world().get::<TextMesh>(text).unwrap().style;
+    assert_eq!(style.letter_spacing, 0.2);
+    assert_eq!(style.depth, root_style().depth);
+    assert_eq!(style.subdivision, root_style().subdivision);
+
+    // Root changes still reach the inherited fields only
+    {
+        let mut root = app.world_mut().get_mut::<TextStyleRoot>(root).unwrap();
+        root.style.depth = 0.3;
+        root.style.letter_spacing = 0.5;
+    }
+    app.update();
+    let style = &app.world().get::<TextMesh>(text).unwrap().style;
+    assert_eq!(style.depth, 0.3);
+    assert_eq!(style.letter_spacing, 0.2);
+
+    // A field the text changes after inheriting it becomes its own
+    app.world_mut()
+        .get_mut::<TextMesh>(text)
+        .unwrap()
+        .style
+        .subdivision = 3;
+    app.world_mut()
+        .get_mut::<TextStyleRoot>(root)
+        .unwrap()
+        .style
+        .subdivision = 20;
+    app.update();
+    assert_eq!(
+        app.world().get::<TextMesh>(text).unwrap().style.subdivision,
+        3
+    );
+}
+
+/// Spawn a second root with its own style.
+fn other_root(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn(TextStyleRoot {
+            font: None,
+            style: TextMeshStyle {
+                depth: 0.9,
+                subdivision: 2,
+                ..default()
+            },
+        })
+        .id()
+}
+
+#[test]
+fn test_reparented_text_takes_the_new_root() {
+    let (mut app, _, text, glyphs) = setup();
+    let other = other_root(&mut app);
+    app.update();
+
+    app.world_mut().entity_mut(text).insert(ChildOf(other));
+    app.update();
+
+    let style = &app.world().get::<TextMesh>(text).unwrap().style;
+    assert_eq!((style.depth, style.subdivision), (0.9, 2));
+    // The grandchild moved along with its parent without being touched itself
+    let style = &app.world().get::<TextMeshGlyphs>(glyphs).unwrap().style;
+    assert_eq!((style.depth, style.subdivision), (0.9, 2));
+}
+
+#[test]
+fn test_text_under_a_reparented_ancestor_takes_the_new_root() {
+    let (mut app, root, _, _) = setup();
+    let group = app.world_mut().spawn(ChildOf(root)).id();
+    let text = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Audio".to_string(),
+                    ..default()
+                },
+                ..default()
+            },
+            ChildOf(group),
+        ))
+        .id();
+    let other = other_root(&mut app);
+    app.update();
+    assert_eq!(
+        app.world().get::<TextMesh>(text).unwrap().style,
+        root_style()
+    );
+
+    app.world_mut().entity_mut(group).insert(ChildOf(other));
+    app.update();
+    let style = &app.world().get::<TextMesh>(text).unwrap().style;
+    assert_eq!((style.depth, style.subdivision), (0.9, 2));
+}
+
+#[test]
+fn test_nearest_root_wins() {
+    let (mut app, _, text, glyphs) = setup();
+    let inner_style = TextMeshStyle {
+        depth: 0.8,
+        ..default()
+    };
+    app.world_mut().entity_mut(text).insert(TextStyleRoot {
+        font: None,
+        style: inner_style.clone(),
+    });
+    app.update();
+
+    assert_eq!(
+        app.world().get::<TextMesh>(text).unwrap().style,
+        inner_style
+    );
+    assert_eq!(
+        app.world().get::<TextMeshGlyphs>(glyphs).unwrap().style,
+        inner_style
+    );
+}
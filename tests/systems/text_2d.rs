@@ -0,0 +1,107 @@
+//! Tests for flat 2D text meshes
+
+use crate::common;
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+
+fn positions(app: &App, handle: &Handle<Mesh>) -> Vec<[f32; 3]> {
+    let mesh = app
+        .world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .expect("Generated mesh should exist");
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|attribute| attribute.as_float3())
+        .expect("Mesh should have positions")
+        .to_vec()
+}
+
+#[test]
+fn test_text_mesh_2d_generates_flat_mesh() {
+    let (mut app, font) = common::app_with_font();
+
+    let entity = app
+        .world_mut()
+        .spawn(TextMesh2dBundle {
+            text_mesh: TextMesh2d {
+                text: "Hello\n2D".to_string(),
+                font,
+                style: TextMeshStyle {
+                    depth: 0.5,
+                    ..default()
+                },
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let world = app.world();
+    assert!(
+        world.get::<TextMesh2dComputed>(entity).is_some(),
+        "Text should be processed"
+    );
+    assert!(
+        world.get::<Aabb>(entity).is_some(),
+        "Text should have bounds"
+    );
+
+    let handle = world.get::<Mesh2d>(entity).unwrap().0.clone();
+    let positions = positions(&app, &handle);
+    assert!(!positions.is_empty(), "Mesh should have vertices");
+    assert!(
+        positions.iter().all(|p| p[2] == 0.0),
+        "2D text should ignore depth and lie in the z = 0 plane"
+    );
+}
+
+#[test]
+fn test_text_mesh_2d_matches_3d_layout() {
+    let (mut app, font) = common::app_with_font();
+    let style = TextMeshStyle {
+        anchor: TextAnchor::Center,
+        justify: JustifyText::Center,
+        ..default()
+    };
+
+    let flat = app
+        .world_mut()
+        .spawn(TextMesh2dBundle {
+            text_mesh: TextMesh2d {
+                text: "Layout\nshared".to_string(),
+                font: font.clone(),
+                style: style.clone(),
+            },
+            ..default()
+        })
+        .id();
+    let extruded = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Layout\nshared".to_string(),
+                font,
+                style,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let flat_aabb = *app.world().get::<Aabb>(flat).unwrap();
+    let extruded_aabb = *app.world().get::<Aabb>(extruded).unwrap();
+
+    // Same anchoring and justification, so the footprints line up in X and Y
+    assert!(
+        (flat_aabb.min().xy() - extruded_aabb.min().xy()).length() < 1e-3,
+        "2D and 3D text should share the same layout"
+    );
+    assert!(
+        (flat_aabb.max().xy() - extruded_aabb.max().xy()).length() < 1e-3,
+        "2D and 3D text should share the same layout"
+    );
+}
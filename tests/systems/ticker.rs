@@ -0,0 +1,163 @@
+//! Tests for scrolling ticker text
+
+use crate::common;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+const HEADLINE: &str = "Markets rally as fonts finally render everywhere";
+
+fn setup(ticker: TextTicker) -> (App, Entity) {
+    let (mut app, font) = common::app_with_font();
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: HEADLINE.to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            ticker,
+        ))
+        .id();
+    (app, entity)
+}
+
+fn mesh(app: &App, entity: Entity) -> &Mesh {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    app.world().resource::<Assets<Mesh>>().get(handle).unwrap()
+}
+
+fn x_range(mesh: &Mesh) -> (f32, f32) {
+    mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap()
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), v| {
+            (min.min(v[0]), max.max(v[0]))
+        })
+}
+
+#[test]
+fn test_geometry_is_clipped_to_window() {
+    let (mut app, entity) = setup(TextTicker {
+        width: 3.0,
+        speed: 0.0,
+        scroll: 10.0,
+        ..default()
+    });
+    app.update();
+
+    let mesh = mesh(&app, entity);
+    assert!(mesh.count_vertices() > 0);
+    let (min, max) = x_range(mesh);
+    assert!(min >= -1e-4 && max <= 3.0 + 1e-4, "x range {min}..{max}");
+    // The text overflows both edges, so glyphs are cut right at them
+    assert!(min.abs() < 1e-4 && (max - 3.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_text_enters_from_the_right() {
+    let (mut app, entity) = setup(TextTicker {
+        width: 3.0,
+        speed: 0.0,
+        scroll: 0.0,
+        looping: false,
+        ..default()
+    });
+    app.update();
+    assert_eq!(mesh(&app, entity).count_vertices(), 0);
+
+    app.world_mut()
+        .get_mut::<TextTicker>(entity)
+        .unwrap()
+        .scroll = 1.0;
+    app.update();
+    let (min, _) = x_range(mesh(&app, entity));
+    assert!(
+        min >= 2.0 - 1e-4,
+        "text should start near the right edge, got {min}"
+    );
+}
+
+#[test]
+fn test_ticker_scrolls_with_time_and_wraps() {
+    let (mut app, entity) = setup(TextTicker {
+        speed: 2.0,
+        gap: 1.0,
+        ..default()
+    });
+    app.init_resource::<Time>();
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+    let scroll = app.world().get::<TextTicker>(entity).unwrap().scroll;
+    assert!((scroll - 2.0).abs() < 1e-4);
+
+    // A long time later the position has wrapped around the text
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1000));
+    app.update();
+    let width = common::load_test_font().text_width(HEADLINE);
+    let scroll = app.world().get::<TextTicker>(entity).unwrap().scroll;
+    assert!((0.0..width + 1.0).contains(&scroll), "scroll {scroll}");
+}
+
+#[test]
+fn test_fade_writes_vertex_alpha() {
+    let (mut app, entity) = setup(TextTicker {
+        width: 4.0,
+        speed: 0.0,
+        scroll: 10.0,
+        edge: TickerEdge::Fade { width: 1.0 },
+        ..default()
+    });
+    app.update();
+
+    let mesh = mesh(&app, entity);
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .unwrap()
+        .as_float3()
+        .unwrap();
+    let Some(bevy::mesh::VertexAttributeValues::Float32x4(colors)) =
+        mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+    else {
+        panic!("Faded ticker should have vertex colors");
+    };
+    for (position, color) in positions.iter().zip(colors) {
+        let edge_distance = position[0].min(4.0 - position[0]);
+        let expected = edge_distance.clamp(0.0, 1.0);
+        assert!((color[3] - expected).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_removing_ticker_restores_full_text() {
+    let (mut app, entity) = setup(TextTicker {
+        width: 3.0,
+        speed: 0.0,
+        scroll: 10.0,
+        ..default()
+    });
+    app.update();
+
+    app.world_mut().entity_mut(entity).remove::<TextTicker>();
+    app.update();
+    app.update();
+
+    assert!(app.world().get::<TextMeshComputed>(entity).is_some());
+    let (min, max) = x_range(mesh(&app, entity));
+    assert!(
+        max - min > 10.0,
+        "expected the whole line, got {min}..{max}"
+    );
+}
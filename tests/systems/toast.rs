@@ -0,0 +1,170 @@
+//! Tests for stacks of transient notifications
+
+use crate::common;
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn toast_app() -> (TestApp, Entity) {
+    let mut test = TestApp::new();
+    test.app.add_plugins(Toast3dPlugin);
+    test.world_mut().init_resource::<Time>();
+    let font = test.font.clone();
+    let stack = test
+        .world_mut()
+        .spawn(Toast3dStack {
+            font,
+            duration: 1.0,
+            fade_out: 0.25,
+            gap: 0.5,
+            max_toasts: 2,
+            reflow_speed: f32::INFINITY,
+            ..default()
+        })
+        .id();
+    (test, stack)
+}
+
+fn push(test: &mut TestApp, stack: Entity, text: &str) {
+    test.world_mut()
+        .get_mut::<Toast3dStack>(stack)
+        .unwrap()
+        .push(text);
+}
+
+/// Advance the clock by `seconds`, then run a few frames without time passing so the
+/// toasts are generated and placed.
+fn advance(test: &mut TestApp, seconds: f32) {
+    let mut step = Duration::from_secs_f32(seconds);
+    for _ in 0..3 {
+        test.world_mut().resource_mut::<Time>().advance_by(step);
+        test.update();
+        step = Duration::ZERO;
+    }
+}
+
+/// The shown toasts of `stack`, oldest first, with their text and height in the stack.
+fn toasts(test: &TestApp, stack: Entity) -> Vec<(Entity, String, f32)> {
+    let mut toasts: Vec<_> = test
+        .children_with::<Toast3d>(stack)
+        .into_iter()
+        // Hidden toasts waiting to be reused have no lifetime
+        .filter(|&toast| test.world().get::<TextMeshLifetime>(toast).is_some())
+        .map(|toast| {
+            let world = test.world();
+            let sequence = world.get::<Toast3d>(toast).unwrap().sequence;
+            let text = world.get::<TextMesh>(toast).unwrap().text.clone();
+            let y = world.get::<Transform>(toast).unwrap().translation.y;
+            (sequence, (toast, text, y))
+        })
+        .collect();
+    toasts.sort_by_key(|(sequence, _)| *sequence);
+    toasts.into_iter().map(|(_, toast)| toast).collect()
+}
+
+#[test]
+fn test_toasts_stack_and_reflow() {
+    let (mut test, stack) = toast_app();
+    push(&mut test, stack, "First");
+    advance(&mut test, 0.0);
+    advance(&mut test, 0.5);
+    push(&mut test, stack, "Second");
+    advance(&mut test, 0.0);
+
+    let shown = toasts(&test, stack);
+    let texts: Vec<_> = shown.iter().map(|(_, text, _)| text.as_str()).collect();
+    assert_eq!(texts, ["First", "Second"]);
+
+    // Every line box is as tall as the font, so the toasts are one box and a gap apart
+    let bounds = test.world().get::<TextMeshBounds>(shown[0].0).unwrap();
+    let line = bounds.line_rects[0];
+    assert!((shown[0].2 + line.max.y).abs() < 1e-4);
+    let spacing = line.max.y - line.min.y + 0.5;
+    assert!((shown[0].2 - shown[1].2 - spacing).abs() < 1e-4);
+
+    // Once the first expires, the second moves up into its place
+    advance(&mut test, 0.6);
+    let shown = toasts(&test, stack);
+    assert_eq!(shown.len(), 1);
+    assert_eq!(shown[0].1, "Second");
+    assert!((shown[0].2 + line.max.y).abs() < 1e-4);
+}
+
+#[test]
+fn test_full_stack_fades_out_the_oldest() {
+    let (mut test, stack) = toast_app();
+    push(&mut test, stack, "A");
+    push(&mut test, stack, "B");
+    advance(&mut test, 0.0);
+
+    push(&mut test, stack, "C");
+    advance(&mut test, 0.0);
+    let shown = toasts(&test, stack);
+    assert_eq!(shown.len(), 3);
+    let lifetime = |toast: Entity| test.world().get::<TextMeshLifetime>(toast).unwrap();
+    assert_eq!(lifetime(shown[0].0).elapsed, 0.75);
+    assert_eq!(lifetime(shown[1].0).elapsed, 0.0);
+
+    // Pushing more than fit at once only keeps the newest
+    push(&mut test, stack, "D");
+    push(&mut test, stack, "E");
+    push(&mut test, stack, "F");
+    advance(&mut test, 0.0);
+    let texts: Vec<_> = toasts(&test, stack)
+        .into_iter()
+        .map(|(_, text, _)| text)
+        .collect();
+    assert_eq!(texts, ["A", "B", "C", "E", "F"]);
+}
+
+#[test]
+fn test_expired_toasts_are_reused() {
+    let (mut test, stack) = toast_app();
+    push(&mut test, stack, "First");
+    advance(&mut test, 0.0);
+    let first = toasts(&test, stack)[0].0;
+
+    // The expired toast stays hidden under the stack
+    advance(&mut test, 1.1);
+    assert!(toasts(&test, stack).is_empty());
+    assert_eq!(
+        test.world().get::<Visibility>(first),
+        Some(&Visibility::Hidden)
+    );
+
+    push(&mut test, stack, "Again");
+    advance(&mut test, 0.0);
+    let shown = toasts(&test, stack);
+    assert_eq!(shown.len(), 1);
+    assert_eq!(shown[0].0, first, "The hidden toast should be reused");
+    assert_eq!(shown[0].1, "Again");
+    assert_eq!(
+        test.world().get::<Visibility>(first),
+        Some(&Visibility::Inherited)
+    );
+}
+
+#[test]
+fn test_toasts_follow_the_font_plugin_schedule() {
+    let mut app = App::new();
+    app.add_plugins((Toast3dPlugin, FontMeshPlugin.in_schedule(PostUpdate)))
+        .init_resource::<Time>();
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(common::load_test_font());
+    let stack = app
+        .world_mut()
+        .spawn(Toast3dStack { font, ..default() })
+        .id();
+    app.world_mut()
+        .get_mut::<Toast3dStack>(stack)
+        .unwrap()
+        .push("Saved");
+
+    // Pushed in `PostUpdate` before generation, so the mesh is built on the same frame
+    app.update();
+    let toast = app.world().get::<Children>(stack).unwrap()[0];
+    assert!(app.world().get::<TextMeshBounds>(toast).is_some());
+}
@@ -0,0 +1,135 @@
+//! Tests for animated transitions between old and new text
+
+use crate::common::TestApp;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+fn transition_app() -> TestApp {
+    let mut test = TestApp::new();
+    test.world_mut().init_resource::<Time>();
+    test.world_mut().init_resource::<Assets<StandardMaterial>>();
+    test.app.finish();
+    test
+}
+
+fn advance(test: &mut TestApp, seconds: f32) {
+    test.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(seconds));
+    test.update();
+}
+
+fn spawn_transitioning(test: &mut TestApp, transition: TextMeshTransition) -> Entity {
+    let entity = test.spawn_text("Old", TextMeshStyle::default());
+    test.world_mut().entity_mut(entity).insert(transition);
+    advance(test, 0.0);
+    entity
+}
+
+fn ghosts(test: &mut TestApp) -> Vec<Entity> {
+    test.world_mut()
+        .query_filtered::<Entity, With<TextMeshTransitionGhost>>()
+        .iter(test.world())
+        .collect()
+}
+
+fn alpha(test: &TestApp, entity: Entity) -> (AssetId<StandardMaterial>, f32) {
+    let handle = &test
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .0;
+    let materials = test.world().resource::<Assets<StandardMaterial>>();
+    let alpha = materials.get(handle).unwrap().base_color.alpha();
+    (handle.id(), alpha)
+}
+
+#[test]
+fn test_cross_fade_keeps_the_old_mesh_until_it_faded() {
+    let mut test = transition_app();
+    let entity = spawn_transitioning(&mut test, TextMeshTransition::CrossFade { duration: 1.0 });
+    let old_positions = test.positions(entity);
+    let shared = FontMeshSettings::DEFAULT_MATERIAL.id();
+
+    test.edit_text(entity, |text_mesh| text_mesh.text = "New".to_string());
+    advance(&mut test, 0.25);
+
+    // The ghost shows the old text while the new text fades in over it
+    let ghost = ghosts(&mut test)[0];
+    assert_eq!(test.positions(ghost), old_positions);
+    assert_ne!(test.positions(entity), old_positions);
+    let (text_material, text_alpha) = alpha(&test, entity);
+    let (ghost_material, ghost_alpha) = alpha(&test, ghost);
+    assert!(text_material != shared && ghost_material != shared);
+    assert!((text_alpha - 0.25).abs() < 1e-4);
+    assert!((ghost_alpha - 0.75).abs() < 1e-4);
+    assert_eq!(
+        test.world()
+            .get::<TextMeshTransitionGhost>(ghost)
+            .unwrap()
+            .source,
+        entity
+    );
+
+    // Afterwards only the new text is left, with its own material again
+    advance(&mut test, 1.0);
+    assert!(ghosts(&mut test).is_empty());
+    assert_eq!(alpha(&test, entity), (shared, 1.0));
+}
+
+#[test]
+fn test_slide_moves_in_local_space_and_ends_with_the_text() {
+    let mut test = transition_app();
+    let parent = test.world_mut().spawn(Transform::default()).id();
+    let entity = spawn_transitioning(
+        &mut test,
+        TextMeshTransition::Slide {
+            duration: 1.0,
+            offset: Vec3::Y,
+        },
+    );
+    let start = Transform::from_xyz(1.0, 0.0, 0.0).with_scale(Vec3::splat(2.0));
+    test.world_mut()
+        .entity_mut(entity)
+        .insert((start, ChildOf(parent)));
+    advance(&mut test, 0.0);
+
+    test.edit_text(entity, |text_mesh| text_mesh.text = "New".to_string());
+    advance(&mut test, 0.25);
+    let ghost = ghosts(&mut test)[0];
+    let translation = |entity| test.world().get::<Transform>(entity).unwrap().translation;
+    assert!(translation(entity).abs_diff_eq(Vec3::new(1.0, -1.5, 0.0), 1e-4));
+    assert!(translation(ghost).abs_diff_eq(Vec3::new(1.0, 0.5, 0.0), 1e-4));
+    assert_eq!(test.world().get::<ChildOf>(ghost).unwrap().parent(), parent);
+
+    // Despawning the text mid-transition takes the ghost along
+    test.world_mut().entity_mut(entity).despawn();
+    advance(&mut test, 0.25);
+    assert!(ghosts(&mut test).is_empty());
+}
+
+#[test]
+fn test_scale_restarts_on_another_edit() {
+    let mut test = transition_app();
+    let entity = spawn_transitioning(&mut test, TextMeshTransition::Scale { duration: 1.0 });
+    let scale = |test: &TestApp| test.world().get::<Transform>(entity).unwrap().scale;
+
+    test.edit_text(entity, |text_mesh| text_mesh.text = "Newer".to_string());
+    advance(&mut test, 0.5);
+    assert!(scale(&test).abs_diff_eq(Vec3::splat(0.5), 1e-4));
+
+    test.edit_text(entity, |text_mesh| text_mesh.text = "Newest".to_string());
+    advance(&mut test, 0.25);
+    assert_eq!(ghosts(&mut test).len(), 1);
+    assert!(scale(&test).abs_diff_eq(Vec3::splat(0.25), 1e-4));
+
+    // Scaling leaves the material alone, and the text ends at its original scale
+    assert_eq!(
+        alpha(&test, entity).0,
+        FontMeshSettings::DEFAULT_MATERIAL.id()
+    );
+    advance(&mut test, 1.0);
+    assert_eq!(scale(&test), Vec3::ONE);
+    assert!(ghosts(&mut test).is_empty());
+}
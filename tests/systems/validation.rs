@@ -0,0 +1,224 @@
+//! Tests for the opt-in validation pass
+
+use crate::common;
+use bevy::asset::uuid_handle;
+use bevy::ecs::message::Messages;
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use std::time::Duration;
+
+/// A font that is never loaded.
+const PENDING_FONT: Handle<FontMesh> = uuid_handle!("0e7d2a61-3c94-4b8f-a5d2-91f4e6c8b372");
+
+fn setup() -> (App, Handle<FontMesh>) {
+    let (mut app, font) = common::app_with_font();
+    app.insert_resource(TextMeshValidation::default());
+    (app, font)
+}
+
+fn warnings(app: &mut App) -> Vec<TextMeshWarningKind> {
+    app.world_mut()
+        .resource_mut::<Messages<TextMeshWarning>>()
+        .drain()
+        .map(|warning| warning.kind)
+        .collect()
+}
+
+#[test]
+fn test_valid_text_has_no_warnings() {
+    let (mut app, font) = setup();
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "Hello".to_string(),
+            font,
+            ..default()
+        },
+        ..default()
+    });
+
+    app.update();
+    assert!(warnings(&mut app).is_empty());
+}
+
+#[test]
+fn test_glyphs_without_material_are_reported() {
+    let (mut app, font) = setup();
+    // Without settings, bare glyphs keep the default material, which is drawn
+    app.world_mut().remove_resource::<FontMeshSettings>();
+    let glyphs = || TextMeshGlyphs {
+        text: "Hi".to_string(),
+        font: font.clone(),
+        ..default()
+    };
+    app.world_mut().spawn(glyphs());
+    app.update();
+    assert!(warnings(&mut app).is_empty());
+
+    // Only removing the required material leaves the glyphs without one
+    let entity = app.world_mut().spawn(glyphs()).id();
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<MeshMaterial3d<StandardMaterial>>();
+
+    app.update();
+    let reported = app
+        .world_mut()
+        .resource_mut::<Messages<TextMeshWarning>>()
+        .drain()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reported,
+        vec![TextMeshWarning {
+            entity,
+            kind: TextMeshWarningKind::MissingGlyphMaterial,
+        }]
+    );
+
+    // Reported once, not every frame
+    app.update();
+    assert!(warnings(&mut app).is_empty());
+}
+
+#[test]
+fn test_custom_pivot_out_of_range_is_reported() {
+    let (mut app, font) = setup();
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "Hi".to_string(),
+            font,
+            style: TextMeshStyle {
+                anchor: TextAnchor::Custom(Vec2::new(0.5, 50.0)),
+                ..default()
+            },
+            ..default()
+        },
+        ..default()
+    });
+
+    app.update();
+    assert_eq!(
+        warnings(&mut app),
+        vec![TextMeshWarningKind::PivotOutOfRange {
+            pivot: Vec2::new(0.5, 50.0)
+        }]
+    );
+}
+
+#[test]
+fn test_high_subdivision_on_long_text_is_reported() {
+    let (mut app, font) = setup();
+    let style = TextMeshStyle {
+        subdivision: 200,
+        ..default()
+    };
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "Title".to_string(),
+            font: font.clone(),
+            style: style.clone(),
+            ..default()
+        },
+        ..default()
+    });
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "word ".repeat(20),
+            font,
+            style,
+            ..default()
+        },
+        ..default()
+    });
+
+    app.update();
+    assert_eq!(
+        warnings(&mut app),
+        vec![TextMeshWarningKind::SubdivisionTooHigh {
+            subdivision: 200,
+            glyphs: 80,
+        }]
+    );
+}
+
+#[test]
+fn test_font_that_never_loads_is_reported_after_timeout() {
+    let (mut app, _) = setup();
+    app.init_resource::<Time>();
+    let font = PENDING_FONT;
+    app.world_mut().spawn(TextMeshBundle {
+        text_mesh: TextMesh {
+            text: "Hi".to_string(),
+            font: font.clone(),
+            ..default()
+        },
+        ..default()
+    });
+
+    app.update();
+    assert!(warnings(&mut app).is_empty());
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(11));
+    app.update();
+    assert_eq!(
+        warnings(&mut app),
+        vec![TextMeshWarningKind::FontNotLoaded {
+            font: font.id(),
+            failed: false,
+        }]
+    );
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(11));
+    app.update();
+    assert!(warnings(&mut app).is_empty());
+}
+
+#[test]
+fn test_font_timeout_is_shared_with_placeholders() {
+    let (mut app, _) = setup();
+    app.init_resource::<Time>()
+        .insert_resource(FontLoadTimeout {
+            timeout: 1.0,
+            placeholder_font: None,
+        });
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Hi".to_string(),
+                font: PENDING_FONT,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    app.update();
+
+    // The warning and the placeholder both follow the one timeout
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(2));
+    app.update();
+    assert_eq!(
+        warnings(&mut app),
+        vec![TextMeshWarningKind::FontNotLoaded {
+            font: PENDING_FONT.id(),
+            failed: false,
+        }]
+    );
+    let errors: Vec<TextMeshError> = app
+        .world_mut()
+        .resource_mut::<Messages<TextMeshError>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        errors,
+        vec![TextMeshError::FontUnavailable {
+            entity,
+            font: PENDING_FONT.id(),
+        }]
+    );
+}
@@ -0,0 +1,149 @@
+//! Browser tests for text generation on WebAssembly
+//!
+//! Requires `wasm-bindgen-test-runner` (`cargo install wasm-bindgen-cli`), then:
+//! `cargo test --target wasm32-unknown-unknown --test wasm`
+#![cfg(target_arch = "wasm32")]
+
+use bevy::prelude::*;
+use bevy_fontmesh::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// No filesystem in the browser, so the test font is embedded
+const FONT_DATA: &[u8] = include_bytes!("../assets/fonts/FiraMono-Medium.ttf");
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> JsValue;
+}
+
+/// Return to the browser event loop so pending tasks and timers can run.
+async fn next_tick() {
+    let timer = js_sys::Promise::new(&mut |resolve, _reject| {
+        set_timeout(&resolve, 0);
+    });
+    wasm_bindgen_futures::JsFuture::from(timer).await.unwrap();
+}
+
+fn setup_app() -> (App, Handle<FontMesh>) {
+    let mut app = App::new();
+    app.add_plugins(FontMeshPlugin);
+    let font = app
+        .world_mut()
+        .resource_mut::<Assets<FontMesh>>()
+        .add(FontMesh {
+            data: FONT_DATA.into(),
+            face_index: 0,
+        });
+    (app, font)
+}
+
+fn vertex_count(app: &App, entity: Entity) -> usize {
+    let handle = &app.world().get::<Mesh3d>(entity).unwrap().0;
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .map_or(0, |mesh| mesh.count_vertices())
+}
+
+#[wasm_bindgen_test]
+fn test_sync_generation() {
+    let (mut app, font) = setup_app();
+    let entity = app
+        .world_mut()
+        .spawn(TextMeshBundle {
+            text_mesh: TextMesh {
+                text: "Hello, web!".to_string(),
+                font,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    assert!(vertex_count(&app, entity) > 0, "Mesh should have vertices");
+}
+
+#[wasm_bindgen_test]
+async fn test_async_generation_does_not_block() {
+    let (mut app, font) = setup_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text: "Line one\nLine two\nLine three".to_string(),
+                    font,
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshAsync,
+        ))
+        .id();
+
+    // The update must return with the task still pending instead of blocking on it
+    app.update();
+    assert!(
+        app.world().get::<TextMeshTask>(entity).is_some(),
+        "Generation should run as a task"
+    );
+
+    for _ in 0..100 {
+        if app.world().get::<TextMeshTask>(entity).is_none() {
+            break;
+        }
+        next_tick().await;
+        app.update();
+    }
+
+    assert!(
+        app.world().get::<TextMeshTask>(entity).is_none(),
+        "Task should complete once the event loop runs"
+    );
+    assert!(vertex_count(&app, entity) > 0, "Mesh should have vertices");
+}
+
+#[wasm_bindgen_test]
+async fn test_long_generation_yields_to_the_event_loop() {
+    let (mut app, font) = setup_app();
+    let text = vec!["The quick brown fox jumps over the lazy dog"; 200].join("\n");
+    let entity = app
+        .world_mut()
+        .spawn((
+            TextMeshBundle {
+                text_mesh: TextMesh {
+                    text,
+                    font,
+                    style: TextMeshStyle {
+                        subdivision: 40,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            },
+            TextMeshAsync,
+        ))
+        .id();
+    app.update();
+
+    // A task that never yielded would finish before this timer fires
+    next_tick().await;
+    app.update();
+    assert!(
+        app.world().get::<TextMeshTask>(entity).is_some(),
+        "Long generation should yield before it finishes"
+    );
+
+    while app.world().get::<TextMeshTask>(entity).is_some() {
+        next_tick().await;
+        app.update();
+    }
+    assert!(vertex_count(&app, entity) > 0, "Mesh should have vertices");
+}